@@ -670,4 +670,80 @@ describe('analyzeSpectrum', () => {
       expect(result).toBeDefined();
     });
   });
+
+  describe('Spectral Shape Descriptors and Options', () => {
+    it('should return the extended descriptors from the native result', async () => {
+      const audioBuffer = generateSineWave(440, 44100, 2205);
+      mockAnalyzeSpectrum.mockResolvedValueOnce({
+        centroid: 450.0,
+        rolloff: 460.0,
+        tilt: -3.0,
+        spread: 35.0,
+        skewness: 4.2,
+        kurtosis: 30.0,
+        flatness: 0.01,
+        entropy: 0.2,
+        crestFactor: 180.0,
+        rolloffLow: 450.0,
+        rolloffHigh: 460.0,
+      });
+
+      const result = await analyzeSpectrum(audioBuffer, 44100);
+
+      expect(result).toEqual({
+        centroid: 450.0,
+        rolloff: 460.0,
+        tilt: -3.0,
+        spread: 35.0,
+        skewness: 4.2,
+        kurtosis: 30.0,
+        flatness: 0.01,
+        entropy: 0.2,
+        crestFactor: 180.0,
+        rolloffLow: 450.0,
+        rolloffHigh: 460.0,
+      });
+    });
+
+    it('should pass FFT size, averaging and rolloff thresholds through', async () => {
+      const audioBuffer = new Float32Array(4000).fill(0.1);
+      mockAnalyzeSpectrum.mockResolvedValueOnce({ centroid: 0, rolloff: 0, tilt: 0 });
+
+      const options = {
+        fftSize: 1024,
+        averageFrames: false,
+        rolloffLowThreshold: 0.5,
+        rolloffHighThreshold: 0.99,
+      };
+      await analyzeSpectrum(audioBuffer, 16000, options);
+
+      expect(mockAnalyzeSpectrum).toHaveBeenCalledWith(expect.any(Array), 16000, options);
+    });
+
+    it('should reject invalid FFT sizes', async () => {
+      const audioBuffer = new Float32Array(1024);
+
+      for (const fftSize of [1000, 32, 16384, -1, 512.5]) {
+        await expect(analyzeSpectrum(audioBuffer, 44100, { fftSize })).rejects.toThrow(
+          ValidationError
+        );
+      }
+      expect(mockAnalyzeSpectrum).not.toHaveBeenCalled();
+    });
+
+    it('should reject invalid rolloff thresholds', async () => {
+      const audioBuffer = new Float32Array(1024);
+
+      await expect(
+        analyzeSpectrum(audioBuffer, 44100, { rolloffLowThreshold: 0.96 })
+      ).rejects.toThrow('Rolloff thresholds must satisfy');
+      await expect(
+        analyzeSpectrum(audioBuffer, 44100, { rolloffHighThreshold: 1.2 })
+      ).rejects.toThrow(ValidationError);
+      await expect(
+        analyzeSpectrum(audioBuffer, 44100, { rolloffLowThreshold: 0 })
+      ).rejects.toThrow(ValidationError);
+      expect(mockAnalyzeSpectrum).not.toHaveBeenCalled();
+    });
+  });
 });
@@ -0,0 +1,141 @@
+// Tests for analyzeVibrato function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import { analyzeVibrato } from '../src/analyzeVibrato';
+import type { VibratoOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    analyzeVibrato: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('analyzeVibrato', () => {
+  const mockVibrato = LoqaExpoDspModule.analyzeVibrato as jest.MockedFunction<
+    typeof LoqaExpoDspModule.analyzeVibrato
+  >;
+
+  // Four frames every 10 ms, starting 0.5 s into the recording
+  const track = {
+    pitchTrack: new Float32Array([220, 225, 220, 215]),
+    timestamps: new Float32Array([0.5, 0.51, 0.52, 0.53]),
+  };
+
+  const nativeResult = {
+    vibratoRatio: 0.8,
+    rateHz: 5.5,
+    extentCents: 50,
+    rateJitter: 0.02,
+    extentJitter: 0.03,
+    onsetDelay: 0.25,
+    segments: [
+      {
+        start: 0.25,
+        end: 2,
+        rateHz: 5.5,
+        extentCents: 50,
+        rateJitter: 0.02,
+        extentJitter: 0.03,
+        onsetDelay: 0.25,
+      },
+    ],
+    windowTimes: [0.99, 1],
+    windowRateHz: [null, 5.5],
+    windowExtentCents: [null, 50],
+    windowRateJitter: [null, 0.02],
+    windowExtentJitter: [null, 0.03],
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should derive the frame rate from timestamps and send defaults', async () => {
+    mockVibrato.mockResolvedValueOnce(nativeResult);
+
+    await analyzeVibrato(track);
+
+    expect(mockVibrato).toHaveBeenCalledWith([220, 225, 220, 215], {
+      frameRate: expect.any(Number),
+      minRateHz: 4,
+      maxRateHz: 8,
+      minExtentCents: 15,
+      maxExtentCents: 200,
+      minCycles: 2,
+      windowSeconds: 1,
+      windowHopFrames: 1,
+    });
+    expect(mockVibrato.mock.calls[0][1].frameRate).toBeCloseTo(100, 3);
+  });
+
+  it('should put segment and window times on the track timestamps', async () => {
+    mockVibrato.mockResolvedValueOnce(nativeResult);
+
+    const vibrato = await analyzeVibrato(track);
+
+    expect(vibrato.rate).toBe(5.5);
+    expect(vibrato.onsetDelay).toBe(0.25);
+    expect(vibrato.segments[0].start).toBeCloseTo(0.75, 5);
+    expect(vibrato.segments[0]).toMatchObject({ rate: 5.5, extent: 50, onsetDelay: 0.25 });
+    expect(vibrato.windowTimes).toBeInstanceOf(Float32Array);
+    expect(vibrato.windowTimes[1]).toBeCloseTo(1.5, 5);
+    expect(vibrato.windowRate).toEqual([null, 5.5]);
+  });
+
+  it('should use an explicit frame rate for tracks without timestamps', async () => {
+    mockVibrato.mockResolvedValueOnce({ ...nativeResult, rateHz: null, segments: [] });
+
+    const vibrato = await analyzeVibrato(
+      { pitchTrack: [220, 220] },
+      { frameRate: 62.5, windowDuration: 0 }
+    );
+
+    expect(mockVibrato.mock.calls[0][1]).toMatchObject({ frameRate: 62.5, windowSeconds: 0 });
+    expect(vibrato.rate).toBeNull();
+    expect(vibrato.segments).toEqual([]);
+  });
+
+  it('should reject invalid tracks and options before calling native', async () => {
+    await expect(analyzeVibrato({ pitchTrack: [] })).rejects.toThrow(ValidationError);
+    await expect(analyzeVibrato({ pitchTrack: [220, 220] })).rejects.toThrow(ValidationError);
+
+    const misaligned = { ...track, timestamps: new Float32Array(3) };
+    await expect(analyzeVibrato(misaligned)).rejects.toThrow(ValidationError);
+
+    const negative = { ...track, pitchTrack: new Float32Array([220, -1, 0, 220]) };
+    await expect(analyzeVibrato(negative)).rejects.toThrow(ValidationError);
+
+    const invalidOptions: VibratoOptions[] = [
+      { frameRate: 0 },
+      { frameRate: 20 },
+      { minRate: 0 },
+      { minRate: 8, maxRate: 8 },
+      { minExtent: 0 },
+      { maxExtent: 10 },
+      { minCycles: 1 },
+      { minCycles: 2.5 },
+      { windowDuration: -1 },
+      { windowHopFrames: 0 },
+    ];
+    for (const options of invalidOptions) {
+      await expect(analyzeVibrato(track, options)).rejects.toThrow(ValidationError);
+    }
+
+    expect(mockVibrato).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockVibrato.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(analyzeVibrato(track)).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,104 @@
+// Tests for calculateCPP function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { calculateCPP } from '../src/calculateCPP';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { CPPOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    calculateCPP: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('calculateCPP', () => {
+  const mockCalculateCPP = LoqaExpoDspModule.calculateCPP as jest.MockedFunction<
+    typeof LoqaExpoDspModule.calculateCPP
+  >;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send Praat defaults and return the native result', async () => {
+    mockCalculateCPP.mockResolvedValueOnce({ cpp: 24.1, cpps: 14.2, f0: 150, frameCount: 109 });
+
+    const result = await calculateCPP(new Float32Array(16000), { sampleRate: 16000 });
+
+    expect(mockCalculateCPP).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 2048,
+      hopSize: 128,
+      minFrequency: 60,
+      maxFrequency: 330,
+      timeSmoothing: 0.02,
+      quefrencySmoothing: 0.0005,
+    });
+    expect(result).toEqual({ cpp: 24.1, cpps: 14.2, f0: 150, frameCount: 109 });
+  });
+
+  it('should pass custom options through', async () => {
+    mockCalculateCPP.mockResolvedValueOnce({ cpp: 20, cpps: 20, f0: 200, frameCount: 10 });
+
+    await calculateCPP(new Float32Array(8192), {
+      sampleRate: 44100,
+      frameSize: 4096,
+      hopSize: 512,
+      minFrequency: 80,
+      maxFrequency: 500,
+      timeSmoothing: 0,
+      quefrencySmoothing: 0,
+    });
+
+    expect(mockCalculateCPP).toHaveBeenCalledWith(expect.any(Array), 44100, {
+      frameSize: 4096,
+      hopSize: 512,
+      minFrequency: 80,
+      maxFrequency: 500,
+      timeSmoothing: 0,
+      quefrencySmoothing: 0,
+    });
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, frameSize: 1000 },
+      { sampleRate: 16000, hopSize: 0 },
+      { sampleRate: 16000, minFrequency: 0 },
+      { sampleRate: 16000, minFrequency: 400, maxFrequency: 300 },
+      { sampleRate: 16000, maxFrequency: 9000 },
+      // A 20 Hz period (800 samples) does not fit in half a 1024-sample frame
+      { sampleRate: 16000, frameSize: 1024, minFrequency: 20 },
+      { sampleRate: 16000, timeSmoothing: -1 },
+      { sampleRate: 16000, quefrencySmoothing: NaN },
+    ] as CPPOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(calculateCPP(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame
+    await expect(calculateCPP(new Float32Array(2048), { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockCalculateCPP).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockCalculateCPP.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(calculateCPP(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+});
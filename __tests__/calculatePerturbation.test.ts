@@ -0,0 +1,123 @@
+// Tests for calculateJitter and calculateShimmer functions
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { calculateJitter, calculateShimmer } from '../src/calculatePerturbation';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { PerturbationOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    calculateJitter: jest.fn(),
+    calculateShimmer: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('calculateJitter / calculateShimmer', () => {
+  const mockCalculateJitter = LoqaExpoDspModule.calculateJitter as jest.MockedFunction<
+    typeof LoqaExpoDspModule.calculateJitter
+  >;
+  const mockCalculateShimmer = LoqaExpoDspModule.calculateShimmer as jest.MockedFunction<
+    typeof LoqaExpoDspModule.calculateShimmer
+  >;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send the default pitch range and pass jitter through', async () => {
+    mockCalculateJitter.mockResolvedValueOnce({
+      local: 0.012,
+      localAbsolute: 0.00006,
+      rap: 0.006,
+      ppq5: 0.007,
+      ddp: 0.018,
+      meanPeriod: 0.005,
+      periodCount: 40,
+    });
+
+    const result = await calculateJitter(new Float32Array(8192), { sampleRate: 16000 });
+
+    expect(mockCalculateJitter).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      minFrequency: 75,
+      maxFrequency: 500,
+    });
+    expect(result.local).toBe(0.012);
+    expect(result.ddp).toBe(0.018);
+    expect(result.periodCount).toBe(40);
+  });
+
+  it('should report undefined shimmer measures as null', async () => {
+    mockCalculateShimmer.mockResolvedValueOnce({
+      local: 0.05,
+      localDb: 0.45,
+      apq3: 0.02,
+      apq5: 0.03,
+      apq11: null,
+      dda: 0.06,
+      meanAmplitude: 0.4,
+      periodCount: 6,
+    });
+
+    const result = await calculateShimmer(new Float32Array(4096), {
+      sampleRate: 44100,
+      minFrequency: 100,
+      maxFrequency: 300,
+    });
+
+    expect(mockCalculateShimmer).toHaveBeenCalledWith(expect.any(Array), 44100, {
+      minFrequency: 100,
+      maxFrequency: 300,
+    });
+    expect(result.apq11).toBeNull();
+    expect(result.localDb).toBe(0.45);
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 0 },
+      { sampleRate: 96000 },
+      { sampleRate: 16000, minFrequency: 0 },
+      { sampleRate: 16000, minFrequency: 300, maxFrequency: 200 },
+      { sampleRate: 16000, maxFrequency: Infinity },
+    ] as PerturbationOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(calculateJitter(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first pitch tracking frame
+    await expect(calculateShimmer(new Float32Array(2048), { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    const corrupted = new Float32Array(4096);
+    corrupted[10] = NaN;
+    await expect(calculateShimmer(corrupted, { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockCalculateJitter).not.toHaveBeenCalled();
+    expect(mockCalculateShimmer).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockCalculateJitter.mockRejectedValueOnce(new Error('too few periods'));
+    mockCalculateShimmer.mockRejectedValueOnce(new Error('too few periods'));
+
+    await expect(calculateJitter(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+    await expect(calculateShimmer(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+});
@@ -2,14 +2,17 @@
 import { describe, it, expect, jest, beforeEach } from '@jest/globals';
 
 import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
-import { computeFFT } from '../src/computeFFT';
+import { computeComplexFFT, computeFFT, inverseFFT } from '../src/computeFFT';
 import { ValidationError, NativeModuleError } from '../src/errors';
+import type { FFTOptions } from '../src/types';
 
 // Mock the native module
 jest.mock('../src/LoqaExpoDspModule', () => ({
   __esModule: true,
   default: {
     computeFFT: jest.fn(),
+    computeComplexFFT: jest.fn(),
+    inverseFFT: jest.fn(),
   },
 }));
 
@@ -168,11 +171,14 @@ describe('computeFFT', () => {
 
     it('should accept all window types', async () => {
       const audioBuffer = new Float32Array(512);
-      const windowTypes: ('hanning' | 'hamming' | 'blackman' | 'none')[] = [
+      const windowTypes: NonNullable<FFTOptions['windowType']>[] = [
         'hanning',
         'hamming',
         'blackman',
         'none',
+        'kaiser',
+        'flattop',
+        'gaussian',
       ];
 
       for (const windowType of windowTypes) {
@@ -487,6 +493,65 @@ describe('computeFFT', () => {
     });
   });
 
+  describe('Window Parameter, Normalization and Sample Rate', () => {
+    const mockResponse = {
+      magnitude: new Array(257).fill(0.5),
+      frequencies: new Array(257).fill(0),
+    };
+
+    it('should pass windowParameter, normalization and sampleRate to native module', async () => {
+      mockComputeFFT.mockResolvedValueOnce(mockResponse);
+
+      await computeFFT(new Float32Array(512), {
+        windowType: 'kaiser',
+        windowParameter: 6,
+        normalization: 'psd',
+        sampleRate: 16000,
+      });
+
+      expect(mockComputeFFT).toHaveBeenCalledWith(
+        expect.any(Array),
+        expect.objectContaining({
+          windowType: 'kaiser',
+          windowParameter: 6,
+          normalization: 'psd',
+          sampleRate: 16000,
+        })
+      );
+    });
+
+    it('should default to amplitude normalization at 44100 Hz', async () => {
+      mockComputeFFT.mockResolvedValueOnce(mockResponse);
+
+      await computeFFT(new Float32Array(512));
+
+      expect(mockComputeFFT).toHaveBeenCalledWith(
+        expect.any(Array),
+        expect.objectContaining({
+          windowParameter: 0,
+          normalization: 'amplitude',
+          sampleRate: 44100,
+        })
+      );
+    });
+
+    it('should reject invalid options before calling native module', async () => {
+      const invalidOptions = [
+        { windowType: 'triangle' },
+        { windowParameter: -1 },
+        { windowParameter: NaN },
+        { normalization: 'db' },
+        { sampleRate: 0 },
+        { sampleRate: 44100.5 },
+      ] as FFTOptions[];
+
+      for (const options of invalidOptions) {
+        await expect(computeFFT(new Float32Array(512), options)).rejects.toThrow(ValidationError);
+      }
+      expect(mockComputeFFT).not.toHaveBeenCalled();
+    });
+  });
+
   describe('Cross-Platform Behavior (AC1, AC5)', () => {
     it('should validate inputs consistently before native call', async () => {
       // This test ensures validation happens in TypeScript layer,
@@ -537,3 +602,69 @@ describe('computeFFT', () => {
     });
   });
 });
+
+describe('computeComplexFFT and inverseFFT', () => {
+  const mockComputeComplexFFT = LoqaExpoDspModule.computeComplexFFT as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computeComplexFFT
+  >;
+  const mockInverseFFT = LoqaExpoDspModule.inverseFFT as jest.MockedFunction<
+    typeof LoqaExpoDspModule.inverseFFT
+  >;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should pass the buffer through and return interleaved bins', async () => {
+    const buffer = generateSineWave(440, 16000, 1000);
+    mockComputeComplexFFT.mockResolvedValueOnce({
+      bins: new Array(1026).fill(0.25),
+      fftSize: 1024,
+    });
+
+    const spectrum = await computeComplexFFT(buffer);
+
+    // fftSize 0 lets the native side fit the buffer
+    expect(mockComputeComplexFFT).toHaveBeenCalledWith(expect.any(Array), { fftSize: 0 });
+    expect(spectrum.bins).toBeInstanceOf(Float32Array);
+    expect(spectrum.bins.length).toBe(1026);
+    expect(spectrum.fftSize).toBe(1024);
+  });
+
+  it('should round-trip bins through inverseFFT', async () => {
+    const spectrumBins = new Float32Array(514);
+    mockInverseFFT.mockResolvedValueOnce(new Array(512).fill(0.1));
+
+    const samples = await inverseFFT(spectrumBins);
+
+    expect(mockInverseFFT).toHaveBeenCalledWith(Array.from(spectrumBins));
+    expect(samples).toBeInstanceOf(Float32Array);
+    expect(samples.length).toBe(512);
+  });
+
+  it('should reject malformed input before calling native', async () => {
+    await expect(computeComplexFFT(new Float32Array(0))).rejects.toThrow(ValidationError);
+    await expect(computeComplexFFT(new Float32Array(512), { fftSize: 1000 })).rejects.toThrow(
+      ValidationError
+    );
+    // Odd length, a non power of 2 size (300 bins) and non-finite values
+    const malformed = [
+      new Float32Array(513),
+      new Float32Array(600),
+      [NaN, ...new Array(129).fill(0)],
+    ];
+    for (const bins of malformed) {
+      await expect(inverseFFT(bins)).rejects.toThrow(ValidationError);
+    }
+    expect(mockComputeComplexFFT).not.toHaveBeenCalled();
+    expect(mockInverseFFT).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockComputeComplexFFT.mockRejectedValueOnce(new Error('native failure'));
+    await expect(computeComplexFFT(new Float32Array(512))).rejects.toThrow(NativeModuleError);
+
+    mockInverseFFT.mockRejectedValueOnce(new Error('native failure'));
+    await expect(inverseFFT(new Float32Array(514))).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,117 @@
+// Tests for computeIntensity function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { computeIntensity } from '../src/computeIntensity';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { IntensityOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    computeIntensity: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('computeIntensity', () => {
+  const mockComputeIntensity = LoqaExpoDspModule.computeIntensity as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computeIntensity
+  >;
+
+  const nativeResult = {
+    rms: [0.5, 0.25],
+    intensityDb: [-6.02, -12.04],
+    peakDb: [-3, -9],
+    timestamps: [0, 0.032],
+    meanDb: -8.06,
+    minDb: -12.04,
+    maxDb: -6.02,
+    dynamicRangeDb: 6.02,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and convert the contours to Float32Array', async () => {
+    mockComputeIntensity.mockResolvedValueOnce(nativeResult);
+
+    const result = await computeIntensity(new Float32Array(4096), { sampleRate: 16000 });
+
+    expect(mockComputeIntensity).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 2048,
+      hopSize: 512,
+      calibrationOffset: 0,
+      floorDb: -120,
+    });
+    expect(result.intensityDb).toBeInstanceOf(Float32Array);
+    expect(Array.from(result.rms)).toEqual([0.5, 0.25]);
+    expect(result.timestamps).toHaveLength(2);
+    expect(result.meanDb).toBe(-8.06);
+    expect(result.dynamicRangeDb).toBe(6.02);
+  });
+
+  it('should pass calibration and framing through', async () => {
+    mockComputeIntensity.mockResolvedValueOnce(nativeResult);
+
+    // Frame sizes need not be powers of two; buffers may exceed 16384 samples
+    await computeIntensity(new Float32Array(32000), {
+      sampleRate: 16000,
+      frameSize: 400,
+      hopSize: 160,
+      calibrationOffset: 94,
+      floorDb: -90,
+    });
+
+    expect(mockComputeIntensity).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 400,
+      hopSize: 160,
+      calibrationOffset: 94,
+      floorDb: -90,
+    });
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, frameSize: 0 },
+      { sampleRate: 16000, frameSize: 10.5 },
+      { sampleRate: 16000, hopSize: 0 },
+      { sampleRate: 16000, frameSize: 1024, hopSize: 2048 },
+      { sampleRate: 16000, calibrationOffset: NaN },
+      { sampleRate: 16000, floorDb: -Infinity },
+    ] as IntensityOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(computeIntensity(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame
+    await expect(computeIntensity(new Float32Array(2048), { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    const corrupted = new Float32Array(4096);
+    corrupted[10] = Infinity;
+    await expect(computeIntensity(corrupted, { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockComputeIntensity).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockComputeIntensity.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(computeIntensity(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+});
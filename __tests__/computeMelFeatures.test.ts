@@ -0,0 +1,142 @@
+// Tests for computeMelSpectrogram and computeMFCC functions
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { computeMelSpectrogram, computeMFCC } from '../src/computeMelFeatures';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { MFCCOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    computeMelSpectrogram: jest.fn(),
+    computeMFCC: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('computeMelSpectrogram / computeMFCC', () => {
+  const mockComputeMelSpectrogram = LoqaExpoDspModule.computeMelSpectrogram as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computeMelSpectrogram
+  >;
+  const mockComputeMFCC = LoqaExpoDspModule.computeMFCC as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computeMFCC
+  >;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send mel defaults and return the matrix as Float32Arrays', async () => {
+    const buffer = new Float32Array(4096).fill(0.1);
+    mockComputeMelSpectrogram.mockResolvedValueOnce({
+      values: new Array(4 * 40).fill(-60),
+      timestamps: [0, 0.032, 0.064, 0.096],
+      frameCount: 4,
+      featureCount: 40,
+    });
+
+    const result = await computeMelSpectrogram(buffer, { sampleRate: 16000 });
+
+    expect(mockComputeMelSpectrogram).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 2048,
+      hopSize: 512,
+      windowType: 'hanning',
+      windowParameter: 0,
+      nMels: 40,
+      fmin: 0,
+      fmax: 0,
+      melScale: 'slaney',
+    });
+    expect(result.values).toBeInstanceOf(Float32Array);
+    expect(result.values.length).toBe(result.frameCount * result.featureCount);
+    expect(result.timestamps).toBeInstanceOf(Float32Array);
+  });
+
+  it('should send MFCC options on top of the filterbank options', async () => {
+    mockComputeMFCC.mockResolvedValueOnce({
+      values: new Array(2 * 39).fill(0),
+      timestamps: [0, 0.016],
+      frameCount: 2,
+      featureCount: 39,
+    });
+
+    const result = await computeMFCC(new Float32Array(1024), {
+      sampleRate: 16000,
+      frameSize: 512,
+      hopSize: 256,
+      nMels: 26,
+      fmin: 20,
+      fmax: 8000,
+      melScale: 'htk',
+      lifter: 22,
+      deltaOrder: 2,
+    });
+
+    expect(mockComputeMFCC).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 512,
+      hopSize: 256,
+      windowType: 'hanning',
+      windowParameter: 0,
+      nMels: 26,
+      fmin: 20,
+      fmax: 8000,
+      melScale: 'htk',
+      nMfcc: 13,
+      lifter: 22,
+      deltaOrder: 2,
+      deltaWidth: 2,
+    });
+    expect(result.featureCount).toBe(39);
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, frameSize: 1000 },
+      { sampleRate: 16000, hopSize: 4096 },
+      { sampleRate: 16000, windowType: 'triangle' },
+      { sampleRate: 16000, nMels: 0 },
+      { sampleRate: 16000, nMels: 300 },
+      { sampleRate: 16000, fmin: -1 },
+      { sampleRate: 16000, fmin: 4000, fmax: 3000 },
+      { sampleRate: 16000, fmax: 9000 },
+      { sampleRate: 16000, melScale: 'bark' },
+      { sampleRate: 16000, nMfcc: 0 },
+      { sampleRate: 16000, nMels: 20, nMfcc: 21 },
+      { sampleRate: 16000, lifter: -1 },
+      { sampleRate: 16000, deltaOrder: 3 },
+      { sampleRate: 16000, deltaWidth: 0 },
+    ] as MFCCOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(computeMFCC(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame
+    await expect(
+      computeMelSpectrogram(new Float32Array(2048), { sampleRate: 16000 })
+    ).rejects.toThrow(ValidationError);
+
+    expect(mockComputeMFCC).not.toHaveBeenCalled();
+    expect(mockComputeMelSpectrogram).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockComputeMelSpectrogram.mockRejectedValueOnce(new Error('native failure'));
+    mockComputeMFCC.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(
+      computeMelSpectrogram(new Float32Array(4096), { sampleRate: 16000 })
+    ).rejects.toThrow(NativeModuleError);
+    await expect(computeMFCC(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+});
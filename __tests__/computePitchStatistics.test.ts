@@ -0,0 +1,135 @@
+// Tests for computePitchStatistics function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import { computePitchStatistics } from '../src/computePitchStatistics';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    computePitchStatistics: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('computePitchStatistics', () => {
+  const mockStatistics = LoqaExpoDspModule.computePitchStatistics as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computePitchStatistics
+  >;
+
+  const track = {
+    pitchTrack: new Float32Array([200, 0, 200, 400]),
+    voicedProbabilities: new Float32Array([0.9, 0.1, 0.9, 0.1]),
+  };
+
+  const nativeResult = {
+    frameCount: 4,
+    voicedFrameCount: 3,
+    voicedRatio: 0.75,
+    meanHz: 210.5,
+    medianHz: 200,
+    stdHz: 60.2,
+    p5Hz: 200,
+    p95Hz: 400,
+    meanSemitones: 12.8,
+    medianSemitones: 12,
+    stdSemitones: 3.5,
+    p5Semitones: 12,
+    p95Semitones: 24,
+    rangeSemitones: 12,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send plain arrays with defaults and group the summaries', async () => {
+    mockStatistics.mockResolvedValueOnce(nativeResult);
+
+    const stats = await computePitchStatistics(track);
+
+    expect(mockStatistics).toHaveBeenCalledWith([200, 0, 200, 400], expect.any(Array), {
+      referenceHz: 100,
+      weightByVoicing: true,
+    });
+    expect(mockStatistics.mock.calls[0][1]).toHaveLength(4);
+    expect(stats).toEqual({
+      frameCount: 4,
+      voicedFrameCount: 3,
+      voicedRatio: 0.75,
+      hz: { mean: 210.5, median: 200, std: 60.2, p5: 200, p95: 400 },
+      semitones: { mean: 12.8, median: 12, std: 3.5, p5: 12, p95: 24 },
+      rangeSemitones: 12,
+    });
+  });
+
+  it('should pass the reference and weighting through', async () => {
+    mockStatistics.mockResolvedValueOnce(nativeResult);
+
+    await computePitchStatistics(track, { referenceFrequency: 440, weightByVoicing: false });
+
+    expect(mockStatistics.mock.calls[0][2]).toEqual({
+      referenceHz: 440,
+      weightByVoicing: false,
+    });
+  });
+
+  it('should return null summaries for a track without voiced frames', async () => {
+    mockStatistics.mockResolvedValueOnce({
+      ...nativeResult,
+      voicedFrameCount: 0,
+      voicedRatio: 0,
+      meanHz: null,
+      medianHz: null,
+      stdHz: null,
+      p5Hz: null,
+      p95Hz: null,
+      meanSemitones: null,
+      medianSemitones: null,
+      stdSemitones: null,
+      p5Semitones: null,
+      p95Semitones: null,
+      rangeSemitones: null,
+    });
+
+    const stats = await computePitchStatistics(track);
+
+    expect(stats.hz).toBeNull();
+    expect(stats.semitones).toBeNull();
+    expect(stats.rangeSemitones).toBeNull();
+  });
+
+  it('should reject invalid tracks and options before calling native', async () => {
+    const empty = { pitchTrack: new Float32Array(0), voicedProbabilities: new Float32Array(0) };
+    await expect(computePitchStatistics(empty)).rejects.toThrow(ValidationError);
+
+    const misaligned = { ...track, voicedProbabilities: new Float32Array(3) };
+    await expect(computePitchStatistics(misaligned)).rejects.toThrow(ValidationError);
+
+    const negative = { ...track, pitchTrack: new Float32Array([200, -1, 0, 200]) };
+    await expect(computePitchStatistics(negative)).rejects.toThrow(ValidationError);
+
+    const probability = { ...track, voicedProbabilities: new Float32Array([0.9, 1.5, 0.9, 0.1]) };
+    await expect(computePitchStatistics(probability)).rejects.toThrow(ValidationError);
+
+    for (const referenceFrequency of [0, -100, NaN, Infinity]) {
+      await expect(computePitchStatistics(track, { referenceFrequency })).rejects.toThrow(
+        ValidationError
+      );
+    }
+
+    expect(mockStatistics).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockStatistics.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(computePitchStatistics(track)).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,112 @@
+// Tests for computeSpectrogram function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { computeSpectrogram } from '../src/computeSpectrogram';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { SpectrogramOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    computeSpectrogram: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('computeSpectrogram', () => {
+  const mockComputeSpectrogram = LoqaExpoDspModule.computeSpectrogram as jest.MockedFunction<
+    typeof LoqaExpoDspModule.computeSpectrogram
+  >;
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and return the matrix as Float32Arrays', async () => {
+    const buffer = new Float32Array(4096).fill(0.1);
+    mockComputeSpectrogram.mockResolvedValueOnce({
+      values: new Array(4 * 1025).fill(-60),
+      timestamps: [0, 0.032, 0.064, 0.096],
+      frameCount: 4,
+      binCount: 1025,
+    });
+
+    const result = await computeSpectrogram(buffer, { sampleRate: 16000 });
+
+    expect(mockComputeSpectrogram).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      frameSize: 2048,
+      hopSize: 512,
+      windowType: 'hanning',
+      windowParameter: 0,
+      scaling: 'db',
+      dbFloor: -120,
+    });
+    expect(result.values).toBeInstanceOf(Float32Array);
+    expect(result.values.length).toBe(result.frameCount * result.binCount);
+    expect(result.timestamps).toBeInstanceOf(Float32Array);
+    expect(result.frameCount).toBe(4);
+  });
+
+  it('should accept buffers longer than the single-frame limit', async () => {
+    mockComputeSpectrogram.mockResolvedValueOnce({
+      values: [],
+      timestamps: [],
+      frameCount: 0,
+      binCount: 513,
+    });
+
+    await computeSpectrogram(new Float32Array(48000), {
+      sampleRate: 48000,
+      frameSize: 1024,
+      hopSize: 256,
+      scaling: 'linear',
+    });
+
+    expect(mockComputeSpectrogram).toHaveBeenCalledTimes(1);
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, frameSize: 1000 },
+      { sampleRate: 16000, frameSize: 32 },
+      { sampleRate: 16000, hopSize: 0 },
+      { sampleRate: 16000, frameSize: 512, hopSize: 1024 },
+      { sampleRate: 16000, windowType: 'triangle' },
+      { sampleRate: 16000, windowParameter: -1 },
+      { sampleRate: 16000, scaling: 'log' },
+      { sampleRate: 16000, dbFloor: -Infinity },
+    ] as SpectrogramOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(computeSpectrogram(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame and be finite
+    await expect(computeSpectrogram(new Float32Array(2048), { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+    const withNaN = new Float32Array(4096);
+    withNaN[100] = NaN;
+    await expect(computeSpectrogram(withNaN, { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockComputeSpectrogram).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockComputeSpectrogram.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(computeSpectrogram(new Float32Array(4096), { sampleRate: 16000 })).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+});
@@ -4,6 +4,7 @@ import { describe, it, expect, jest, beforeEach } from '@jest/globals';
 import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
 import { detectPitch } from '../src/detectPitch';
 import { ValidationError, NativeModuleError } from '../src/errors';
+import type { PitchAlgorithm } from '../src/types';
 
 // Mock the native module
 jest.mock('../src/LoqaExpoDspModule', () => ({
@@ -283,6 +284,48 @@ describe('detectPitch', () => {
         })
       );
     });
+
+    it('should pass the algorithm option through when specified', async () => {
+      // Arrange
+      const audioBuffer = new Float32Array(2048);
+      mockDetectPitch.mockResolvedValueOnce({
+        frequency: 1046.5,
+        confidence: 0.9,
+        isVoiced: true,
+        voicedProbability: 0.95,
+      });
+
+      // Act
+      await detectPitch(audioBuffer, 44100, {
+        minFrequency: 500,
+        maxFrequency: 1500,
+        algorithm: 'pyin',
+      });
+
+      // Assert
+      expect(mockDetectPitch).toHaveBeenCalledWith(expect.any(Array), 44100, {
+        minFrequency: 500,
+        maxFrequency: 1500,
+        algorithm: 'pyin',
+      });
+    });
+
+    it('should omit the algorithm option when not specified', async () => {
+      // Arrange
+      const audioBuffer = new Float32Array(2048);
+      mockDetectPitch.mockResolvedValueOnce({
+        frequency: 150.0,
+        confidence: 0.8,
+        isVoiced: true,
+      });
+
+      // Act
+      await detectPitch(audioBuffer, 44100);
+
+      // Assert
+      const options = mockDetectPitch.mock.calls[0][2];
+      expect(options).not.toHaveProperty('algorithm');
+    });
   });
 
   describe('Validation Error Tests', () => {
@@ -399,6 +442,26 @@ describe('detectPitch', () => {
         detectPitch(audioBuffer, 44100, { minFrequency: 200, maxFrequency: 200 })
       ).rejects.toThrow(NativeModuleError);
     });
+
+    it('should throw NativeModuleError when maxFrequency exceeds Nyquist', async () => {
+      // Arrange
+      const audioBuffer = new Float32Array(2048);
+
+      // Act & Assert
+      await expect(
+        detectPitch(audioBuffer, 16000, { minFrequency: 80, maxFrequency: 9000 })
+      ).rejects.toThrow('maxFrequency must not exceed the Nyquist frequency');
+    });
+
+    it('should throw NativeModuleError for an unknown algorithm', async () => {
+      // Arrange
+      const audioBuffer = new Float32Array(2048);
+
+      // Act & Assert
+      await expect(
+        detectPitch(audioBuffer, 44100, { algorithm: 'crepe' as PitchAlgorithm })
+      ).rejects.toThrow('Unknown pitch algorithm');
+    });
   });
 
   describe('Native Module Error Handling', () => {
@@ -0,0 +1,127 @@
+// Tests for detectVoiceActivity function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { detectVoiceActivity } from '../src/detectVoiceActivity';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { VoiceActivityOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    detectVoiceActivity: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('detectVoiceActivity', () => {
+  const mockDetectVoiceActivity = LoqaExpoDspModule.detectVoiceActivity as jest.MockedFunction<
+    typeof LoqaExpoDspModule.detectVoiceActivity
+  >;
+
+  const nativeResult = {
+    timestamps: [0, 0.032, 0.064],
+    energyDb: [-80, -20, -22],
+    zcr: [0, 440, 460],
+    voicedProbabilities: [0, 0.9, 0.85],
+    isSpeech: [false, true, true],
+    segments: [{ start: 512, end: 3072 }],
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and convert the features to Float32Array', async () => {
+    mockDetectVoiceActivity.mockResolvedValueOnce(nativeResult);
+
+    const result = await detectVoiceActivity(new Float32Array(4096), { sampleRate: 16000 });
+
+    expect(mockDetectVoiceActivity).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      minFrequency: 75,
+      maxFrequency: 500,
+      frameSize: 2048,
+      hopSize: 512,
+      energyThresholdDb: -50,
+      zcrThreshold: 3000,
+      voicingThreshold: 0.5,
+      hangoverTime: 0.2,
+    });
+    expect(result.energyDb).toBeInstanceOf(Float32Array);
+    expect(Array.from(result.voicedProbabilities)).toEqual([0, 0.9, 0.85].map(Math.fround));
+    expect(result.isSpeech).toEqual([false, true, true]);
+    expect(result.segments).toEqual([{ start: 512, end: 3072 }]);
+  });
+
+  it('should pass thresholds and framing through', async () => {
+    mockDetectVoiceActivity.mockResolvedValueOnce({ ...nativeResult, segments: [] });
+
+    const result = await detectVoiceActivity(new Float32Array(32000), {
+      sampleRate: 16000,
+      frameSize: 1024,
+      hopSize: 160,
+      energyThresholdDb: -40,
+      zcrThreshold: 2500,
+      voicingThreshold: 0.3,
+      hangoverTime: 0,
+    });
+
+    expect(mockDetectVoiceActivity).toHaveBeenCalledWith(
+      expect.any(Array),
+      16000,
+      expect.objectContaining({
+        frameSize: 1024,
+        hopSize: 160,
+        energyThresholdDb: -40,
+        zcrThreshold: 2500,
+        voicingThreshold: 0.3,
+        hangoverTime: 0,
+      })
+    );
+    expect(result.segments).toEqual([]);
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 4000 },
+      { sampleRate: 16000, minFrequency: 500, maxFrequency: 75 },
+      { sampleRate: 16000, frameSize: 10.5 },
+      { sampleRate: 16000, frameSize: 1024, hopSize: 2048 },
+      { sampleRate: 16000, energyThresholdDb: NaN },
+      { sampleRate: 16000, zcrThreshold: -1 },
+      { sampleRate: 16000, voicingThreshold: 1.5 },
+      { sampleRate: 16000, hangoverTime: Infinity },
+    ] as VoiceActivityOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(detectVoiceActivity(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame
+    await expect(
+      detectVoiceActivity(new Float32Array(2048), { sampleRate: 16000 })
+    ).rejects.toThrow(ValidationError);
+
+    const corrupted = new Float32Array(4096);
+    corrupted[10] = NaN;
+    await expect(detectVoiceActivity(corrupted, { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockDetectVoiceActivity).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockDetectVoiceActivity.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(
+      detectVoiceActivity(new Float32Array(4096), { sampleRate: 16000 })
+    ).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,123 @@
+// Tests for estimateVocalTractLength function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { estimateVocalTractLength } from '../src/estimateVocalTractLength';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { VocalTractLengthOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    estimateVocalTractLength: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('estimateVocalTractLength', () => {
+  const mockEstimate = LoqaExpoDspModule.estimateVocalTractLength as jest.MockedFunction<
+    typeof LoqaExpoDspModule.estimateVocalTractLength
+  >;
+
+  const nativeResult = {
+    vtlCm: 17.4,
+    spacingVtlCm: 17.8,
+    formantSpacingHz: 1005.7,
+    f1: 510,
+    f2: 1490,
+    f3: 2520,
+    f4: 3480,
+    confidence: 0.82,
+    frameCount: 40,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and return the estimates', async () => {
+    mockEstimate.mockResolvedValueOnce(nativeResult);
+
+    const result = await estimateVocalTractLength(new Float32Array(4096), { sampleRate: 16000 });
+
+    expect(mockEstimate).toHaveBeenCalledWith(expect.any(Array), 16000, {
+      minFrequency: 75,
+      maxFrequency: 500,
+      frameSize: 2048,
+      hopSize: 512,
+      lpcOrder: 0,
+      minFormantConfidence: 0.3,
+      speedOfSound: 35000,
+    });
+    expect(result).toEqual(nativeResult);
+  });
+
+  it('should pass formant settings through', async () => {
+    mockEstimate.mockResolvedValueOnce(nativeResult);
+
+    await estimateVocalTractLength(new Float32Array(8000), {
+      sampleRate: 16000,
+      minFrequency: 150,
+      maxFrequency: 600,
+      lpcOrder: 14,
+      minFormantConfidence: 0.5,
+      speedOfSound: 34300,
+    });
+
+    expect(mockEstimate).toHaveBeenCalledWith(
+      expect.any(Array),
+      16000,
+      expect.objectContaining({
+        minFrequency: 150,
+        maxFrequency: 600,
+        lpcOrder: 14,
+        minFormantConfidence: 0.5,
+        speedOfSound: 34300,
+      })
+    );
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const buffer = new Float32Array(4096);
+    const invalidOptions = [
+      { sampleRate: 4000 },
+      { sampleRate: 16000, minFrequency: 0 },
+      { sampleRate: 16000, frameSize: 0 },
+      { sampleRate: 16000, frameSize: 1024, hopSize: 2048 },
+      { sampleRate: 16000, lpcOrder: 4 },
+      { sampleRate: 16000, lpcOrder: 12.5 },
+      { sampleRate: 16000, minFormantConfidence: -0.1 },
+      { sampleRate: 16000, speedOfSound: 0 },
+    ] as VocalTractLengthOptions[];
+
+    for (const options of invalidOptions) {
+      await expect(estimateVocalTractLength(buffer, options)).rejects.toThrow(ValidationError);
+    }
+
+    // The buffer must extend past the first frame
+    await expect(
+      estimateVocalTractLength(new Float32Array(2048), { sampleRate: 16000 })
+    ).rejects.toThrow(ValidationError);
+
+    const corrupted = new Float32Array(4096);
+    corrupted[10] = NaN;
+    await expect(estimateVocalTractLength(corrupted, { sampleRate: 16000 })).rejects.toThrow(
+      ValidationError
+    );
+
+    expect(mockEstimate).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockEstimate.mockRejectedValueOnce(new Error('no voiced frame had three confident formants'));
+
+    await expect(
+      estimateVocalTractLength(new Float32Array(4096), { sampleRate: 16000 })
+    ).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -515,6 +515,35 @@ describe('extractFormants', () => {
       // Assert
       expect(result.confidence).toBe(0.92);
     });
+
+    it('should return F4/F5, bandwidths and per-formant confidences', async () => {
+      // Arrange
+      const audioBuffer = new Float32Array(2048);
+      mockExtractFormants.mockResolvedValueOnce({
+        f1: 730,
+        f2: 1090,
+        f3: 2440,
+        f4: 3400,
+        f5: 4200,
+        b1: 60,
+        b2: 90,
+        b3: 120,
+        b4: 180,
+        b5: 250,
+        confidence: 0.9,
+        confidences: [0.92, 0.9, 0.88, 0.82, 0.75],
+      });
+
+      // Act
+      const result = await extractFormants(audioBuffer, 16000);
+
+      // Assert
+      expect([result.f4, result.f5]).toEqual([3400, 4200]);
+      expect([result.b1, result.b2, result.b3, result.b4, result.b5]).toEqual([
+        60, 90, 120, 180, 250,
+      ]);
+      expect(result.confidences).toEqual([0.92, 0.9, 0.88, 0.82, 0.75]);
+    });
   });
 
   describe('Cross-Platform Consistency Tests (AC5)', () => {
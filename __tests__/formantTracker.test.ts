@@ -0,0 +1,154 @@
+// Tests for the formant tracker API
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import {
+  createFormantTracker,
+  freeFormantTracker,
+  resetFormantTracker,
+  trackFormants,
+} from '../src/formantTracker';
+import type { FormantTrackerConfig, FormantTrackerHandle } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    createFormantTracker: jest.fn(),
+    trackFormants: jest.fn(),
+    resetFormantTracker: jest.fn(),
+    freeFormantTracker: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('formantTracker', () => {
+  const mockCreate = LoqaExpoDspModule.createFormantTracker as jest.MockedFunction<
+    typeof LoqaExpoDspModule.createFormantTracker
+  >;
+  const mockTrack = LoqaExpoDspModule.trackFormants as jest.MockedFunction<
+    typeof LoqaExpoDspModule.trackFormants
+  >;
+  const mockFree = LoqaExpoDspModule.freeFormantTracker as jest.MockedFunction<
+    typeof LoqaExpoDspModule.freeFormantTracker
+  >;
+
+  const tracker: FormantTrackerHandle = {
+    id: 'ft_1',
+    config: {
+      sampleRate: 16000,
+      frameSize: 400,
+      hopSize: 160,
+      lpcOrder: 0,
+      ceilingFrequency: 5500,
+      continuityWeight: 1,
+      minConfidence: 0.3,
+      silenceThreshold: -50,
+    },
+  };
+
+  // A silent frame, then a vowel whose F4 drops out in the last frame
+  const nativeTrack = {
+    frequencies: [0, 0, 0, 0, 500, 1500, 2500, 3500, 510, 1490, 2510, 0],
+    bandwidths: [0, 0, 0, 0, 60, 80, 100, 150, 62, 81, 98, 0],
+    confidences: [0, 0.9, 0.85],
+    reliable: [false, true, true],
+    timestamps: [0, 0.01, 0.02],
+    frameCount: 3,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and return a handle with the resolved config', async () => {
+    mockCreate.mockResolvedValueOnce('ft_1');
+
+    const handle = await createFormantTracker({ sampleRate: 16000 });
+
+    expect(mockCreate).toHaveBeenCalledWith({
+      sampleRate: 16000,
+      frameSize: 400,
+      hopSize: 160,
+      lpcOrder: 0,
+      ceilingHz: 5500,
+      continuityWeight: 1,
+      minConfidence: 0.3,
+      silenceDb: -50,
+    });
+    expect(handle).toEqual(tracker);
+  });
+
+  it('should reject invalid config before calling native', async () => {
+    const invalidConfigs = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, frameSize: 100 },
+      { sampleRate: 16000, frameSize: 400.5 },
+      { sampleRate: 16000, hopSize: 0 },
+      { sampleRate: 16000, frameSize: 400, hopSize: 401 },
+      { sampleRate: 16000, lpcOrder: 4 },
+      { sampleRate: 16000, ceilingFrequency: 2000 },
+      { sampleRate: 8000, ceilingFrequency: 5000 },
+      { sampleRate: 16000, continuityWeight: -1 },
+      { sampleRate: 16000, minConfidence: 1.5 },
+      { sampleRate: 16000, silenceThreshold: 10 },
+    ] as FormantTrackerConfig[];
+
+    for (const config of invalidConfigs) {
+      await expect(createFormantTracker(config)).rejects.toThrow(ValidationError);
+    }
+
+    expect(mockCreate).not.toHaveBeenCalled();
+  });
+
+  it('should split the native matrices into time-aligned formant arrays', async () => {
+    mockTrack.mockResolvedValueOnce(nativeTrack);
+
+    const track = await trackFormants(tracker, new Float32Array(720));
+
+    expect(mockTrack).toHaveBeenCalledWith('ft_1', expect.any(Array));
+    expect(mockTrack.mock.calls[0][1]).toHaveLength(720);
+    expect(track.frameCount).toBe(3);
+    expect(track.f1).toEqual(new Float32Array([0, 500, 510]));
+    expect(track.f2).toEqual(new Float32Array([0, 1500, 1490]));
+    expect(track.f4).toEqual(new Float32Array([0, 3500, 0]));
+    expect(track.b3).toEqual(new Float32Array([0, 100, 98]));
+    expect(track.reliable).toEqual([false, true, true]);
+    expect(track.timestamps).toBeInstanceOf(Float32Array);
+    expect(track.confidence[1]).toBeCloseTo(0.9, 5);
+  });
+
+  it('should reject invalid buffers and wrap native errors', async () => {
+    const invalidHandle = { id: '', config: tracker.config } as FormantTrackerHandle;
+    await expect(trackFormants(invalidHandle, new Float32Array(720))).rejects.toThrow(
+      ValidationError
+    );
+    await expect(trackFormants(tracker, new Float32Array(400))).rejects.toThrow(ValidationError);
+
+    const corrupted = new Float32Array(720);
+    corrupted[5] = NaN;
+    await expect(trackFormants(tracker, corrupted)).rejects.toThrow(ValidationError);
+    expect(mockTrack).not.toHaveBeenCalled();
+
+    mockTrack.mockRejectedValueOnce(new Error('Invalid formant tracker ID: ft_1'));
+    await expect(trackFormants(tracker, new Float32Array(720))).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+
+  it('should reset and free the native tracker', async () => {
+    await resetFormantTracker(tracker);
+    await freeFormantTracker(tracker);
+
+    expect(LoqaExpoDspModule.resetFormantTracker).toHaveBeenCalledWith('ft_1');
+    expect(mockFree).toHaveBeenCalledWith('ft_1');
+
+    mockFree.mockRejectedValueOnce(new Error('native failure'));
+    await expect(freeFormantTracker(tracker)).rejects.toThrow(NativeModuleError);
+  });
+});
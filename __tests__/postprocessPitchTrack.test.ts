@@ -0,0 +1,124 @@
+// Tests for postprocessPitchTrack function
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import { postprocessPitchTrack } from '../src/postprocessPitchTrack';
+import type { PitchPostprocessOptions } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    postprocessPitchTrack: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('postprocessPitchTrack', () => {
+  const mockPostprocess = LoqaExpoDspModule.postprocessPitchTrack as jest.MockedFunction<
+    typeof LoqaExpoDspModule.postprocessPitchTrack
+  >;
+
+  const track = {
+    pitchTrack: new Float32Array([200, 400, 0, 200]),
+    voicedProbabilities: new Float32Array([0.9, 0.9, 0.2, 0.9]),
+    timestamps: new Float32Array([0, 0.032, 0.064, 0.096]),
+  };
+
+  const nativeResult = {
+    pitchTrack: [200, 200, 200, 200],
+    voicedProbabilities: [0.9, 0.9, 0.2, 0.9],
+    timestamps: [0, 0.032, 0.064, 0.096],
+    frameCount: 4,
+    voicedFrameCount: 4,
+    medianPitch: 200,
+    meanPitch: 200,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send plain arrays with defaults and convert the result', async () => {
+    mockPostprocess.mockResolvedValueOnce(nativeResult);
+
+    const result = await postprocessPitchTrack(track);
+
+    expect(mockPostprocess).toHaveBeenCalledWith(
+      [200, 400, 0, 200],
+      expect.any(Array),
+      expect.any(Array),
+      {
+        correctOctaveJumps: true,
+        octaveWindow: 11,
+        smoothing: 1,
+        smoothingWindow: 5,
+        maxGapFrames: 5,
+      }
+    );
+    expect(result.pitchTrack).toBeInstanceOf(Float32Array);
+    expect(Array.from(result.pitchTrack)).toEqual([200, 200, 200, 200]);
+    expect(result.timestamps).toHaveLength(4);
+    expect(result.voicedFrameCount).toBe(4);
+    expect(result.medianPitch).toBe(200);
+  });
+
+  it('should map the smoothing filter and pass the windows through', async () => {
+    mockPostprocess.mockResolvedValue(nativeResult);
+
+    await postprocessPitchTrack(track, {
+      correctOctaveJumps: false,
+      smoothing: 'movingAverage',
+      smoothingWindow: 7,
+      maxGapFrames: 0,
+    });
+    await postprocessPitchTrack(track, { smoothing: 'none', octaveWindow: 3 });
+
+    expect(mockPostprocess.mock.calls[0][3]).toEqual({
+      correctOctaveJumps: false,
+      octaveWindow: 11,
+      smoothing: 2,
+      smoothingWindow: 7,
+      maxGapFrames: 0,
+    });
+    expect(mockPostprocess.mock.calls[1][3]).toMatchObject({ smoothing: 0, octaveWindow: 3 });
+  });
+
+  it('should reject invalid tracks and options before calling native', async () => {
+    const empty = { pitchTrack: [], voicedProbabilities: [], timestamps: [] };
+    await expect(postprocessPitchTrack(empty as never)).rejects.toThrow(ValidationError);
+
+    const misaligned = { ...track, timestamps: new Float32Array(3) };
+    await expect(postprocessPitchTrack(misaligned)).rejects.toThrow(ValidationError);
+
+    const negative = { ...track, pitchTrack: new Float32Array([200, -1, 0, 200]) };
+    await expect(postprocessPitchTrack(negative)).rejects.toThrow(ValidationError);
+
+    const invalidOptions = [
+      { octaveWindow: 1 },
+      { octaveWindow: 10 },
+      { smoothingWindow: 0 },
+      { smoothingWindow: 1003 },
+      { smoothing: 'gaussian' },
+      { smoothing: 'toString' },
+      { maxGapFrames: -1 },
+      { maxGapFrames: 2.5 },
+    ] as PitchPostprocessOptions[];
+    for (const options of invalidOptions) {
+      await expect(postprocessPitchTrack(track, options)).rejects.toThrow(ValidationError);
+    }
+
+    expect(mockPostprocess).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockPostprocess.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(postprocessPitchTrack(track)).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,116 @@
+// Tests for pushSamples and flushVoiceAnalyzer functions
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import type { VoiceAnalyzerHandle } from '../src/types';
+import { flushVoiceAnalyzer, pushSamples } from '../src/voiceAnalyzer';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    pushSamples: jest.fn(),
+    flushVoiceAnalyzer: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('pushSamples', () => {
+  const mockPushSamples = LoqaExpoDspModule.pushSamples as jest.MockedFunction<
+    typeof LoqaExpoDspModule.pushSamples
+  >;
+  const mockFlush = LoqaExpoDspModule.flushVoiceAnalyzer as jest.MockedFunction<
+    typeof LoqaExpoDspModule.flushVoiceAnalyzer
+  >;
+
+  const analyzer: VoiceAnalyzerHandle = {
+    id: 'analyzer_1',
+    config: { sampleRate: 16000, frameSize: 1024, hopSize: 256 },
+  };
+
+  const nativeFrames = {
+    frequencies: [220, 0],
+    confidences: [0.9, 0.1],
+    isVoiced: [true, false],
+    voicedProbabilities: [0.95, 0.05],
+    timestamps: [0.016, 0.032],
+    frameCount: 2,
+  };
+
+  const noFrames = {
+    frequencies: [],
+    confidences: [],
+    isVoiced: [],
+    voicedProbabilities: [],
+    timestamps: [],
+    frameCount: 0,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send the chunk as a plain array and convert frames to Float32Array', async () => {
+    mockPushSamples.mockResolvedValueOnce(nativeFrames);
+
+    const result = await pushSamples(analyzer, new Float32Array(333));
+
+    expect(mockPushSamples).toHaveBeenCalledWith('analyzer_1', expect.any(Array));
+    expect(mockPushSamples.mock.calls[0][1]).toHaveLength(333);
+    expect(result.frequencies).toBeInstanceOf(Float32Array);
+    expect(Array.from(result.frequencies)).toEqual([220, 0]);
+    expect(result.isVoiced).toEqual([true, false]);
+    expect(result.timestamps).toHaveLength(2);
+    expect(result.frameCount).toBe(2);
+  });
+
+  it('should accept chunks shorter than a frame, including empty ones', async () => {
+    mockPushSamples.mockResolvedValue(noFrames);
+
+    const short = await pushSamples(analyzer, [0.1, -0.1, 0.2]);
+    const empty = await pushSamples(analyzer, []);
+
+    expect(mockPushSamples).toHaveBeenCalledTimes(2);
+    expect(short.frameCount).toBe(0);
+    expect(empty.frequencies).toHaveLength(0);
+  });
+
+  it('should reject invalid input before calling native', async () => {
+    const invalidHandle = { id: '', config: analyzer.config } as VoiceAnalyzerHandle;
+    await expect(pushSamples(invalidHandle, new Float32Array(128))).rejects.toThrow(
+      ValidationError
+    );
+
+    const corrupted = new Float32Array(128);
+    corrupted[5] = NaN;
+    await expect(pushSamples(analyzer, corrupted)).rejects.toThrow(ValidationError);
+
+    expect(mockPushSamples).not.toHaveBeenCalled();
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockPushSamples.mockRejectedValueOnce(new Error('Analyzer not found'));
+
+    await expect(pushSamples(analyzer, new Float32Array(128))).rejects.toThrow(NativeModuleError);
+  });
+
+  it('should flush the remaining frames', async () => {
+    mockFlush.mockResolvedValueOnce(nativeFrames);
+
+    const tail = await flushVoiceAnalyzer(analyzer);
+
+    expect(mockFlush).toHaveBeenCalledWith('analyzer_1');
+    expect(Array.from(tail.voicedProbabilities)).toEqual([
+      expect.closeTo(0.95, 6),
+      expect.closeTo(0.05, 6),
+    ]);
+
+    mockFlush.mockRejectedValueOnce(new Error('native failure'));
+    await expect(flushVoiceAnalyzer(analyzer)).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -0,0 +1,198 @@
+// Tests for the tuner API
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import { createTuner, freeTuner, pushTunerSamples, resetTuner } from '../src/tuner';
+import type { TunerConfig, TunerHandle } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    createTuner: jest.fn(),
+    pushTunerSamples: jest.fn(),
+    resetTuner: jest.fn(),
+    freeTuner: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('tuner', () => {
+  const mockCreate = LoqaExpoDspModule.createTuner as jest.MockedFunction<
+    typeof LoqaExpoDspModule.createTuner
+  >;
+  const mockPush = LoqaExpoDspModule.pushTunerSamples as jest.MockedFunction<
+    typeof LoqaExpoDspModule.pushTunerSamples
+  >;
+  const mockFree = LoqaExpoDspModule.freeTuner as jest.MockedFunction<
+    typeof LoqaExpoDspModule.freeTuner
+  >;
+
+  const tuner: TunerHandle = {
+    id: 'tn_1',
+    config: {
+      sampleRate: 16000,
+      minFrequency: 60,
+      maxFrequency: 1500,
+      frameSize: 2048,
+      hopSize: 512,
+      referenceFrequency: 440,
+      temperament: new Array(12).fill(0),
+      stabilityFrames: 8,
+    },
+  };
+
+  const voicedReading = {
+    frameCount: 2,
+    timestamp: 0.032,
+    isVoiced: true,
+    frequency: 296,
+    confidence: 0.95,
+    midiNote: 62,
+    pitchClass: 2,
+    octave: 4,
+    cents: 13.7,
+    targetFrequency: 293.66,
+    stability: 0.5,
+  };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and return a handle with the resolved config', async () => {
+    mockCreate.mockResolvedValueOnce('tn_1');
+
+    const handle = await createTuner({ sampleRate: 16000 });
+
+    expect(mockCreate).toHaveBeenCalledWith({
+      sampleRate: 16000,
+      minFrequency: 60,
+      maxFrequency: 1500,
+      frameSize: 2048,
+      hopSize: 512,
+      referenceHz: 440,
+      temperamentCents: new Array(12).fill(0),
+      stabilityFrames: 8,
+    });
+    expect(handle).toEqual(tuner);
+  });
+
+  it('should pass the reference pitch and temperament through', async () => {
+    mockCreate.mockResolvedValueOnce('tn_2');
+    const meantone = [10.3, -13.7, 3.4, 20.5, -3.4, 13.7, -10.3, 6.8, -17.1, 0, 17.1, -6.8];
+
+    const handle = await createTuner({
+      sampleRate: 48000,
+      referenceFrequency: 415,
+      temperament: meantone,
+      stabilityFrames: 4,
+    });
+
+    expect(mockCreate.mock.calls[0][0]).toMatchObject({
+      referenceHz: 415,
+      temperamentCents: meantone,
+      stabilityFrames: 4,
+    });
+    expect(handle.config.temperament).toEqual(meantone);
+  });
+
+  it('should reject invalid config before calling native', async () => {
+    const invalidConfigs = [
+      { sampleRate: 0 },
+      { sampleRate: 16000, minFrequency: 0 },
+      { sampleRate: 16000, minFrequency: 500, maxFrequency: 400 },
+      { sampleRate: 16000, frameSize: 10.5 },
+      { sampleRate: 16000, frameSize: 1024, hopSize: 2048 },
+      { sampleRate: 16000, referenceFrequency: 0 },
+      { sampleRate: 16000, referenceFrequency: NaN },
+      { sampleRate: 16000, temperament: [0, 0, 0] },
+      { sampleRate: 16000, temperament: [60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] },
+      { sampleRate: 16000, temperament: [NaN, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] },
+      { sampleRate: 16000, stabilityFrames: 0 },
+      { sampleRate: 16000, stabilityFrames: 2.5 },
+    ] as TunerConfig[];
+
+    for (const config of invalidConfigs) {
+      await expect(createTuner(config)).rejects.toThrow(ValidationError);
+    }
+
+    expect(mockCreate).not.toHaveBeenCalled();
+  });
+
+  it('should name the note of a voiced reading', async () => {
+    mockPush.mockResolvedValueOnce(voicedReading);
+
+    const reading = await pushTunerSamples(tuner, new Float32Array(512));
+
+    expect(mockPush).toHaveBeenCalledWith('tn_1', expect.any(Array));
+    expect(mockPush.mock.calls[0][1]).toHaveLength(512);
+    expect(reading.noteName).toBe('D');
+    expect(reading.octave).toBe(4);
+    expect(reading.midiNote).toBe(62);
+    expect(reading.cents).toBe(13.7);
+    expect(reading.targetFrequency).toBe(293.66);
+    expect(reading.stability).toBe(0.5);
+  });
+
+  it('should clear the note fields of an unvoiced reading', async () => {
+    mockPush.mockResolvedValueOnce({
+      ...voicedReading,
+      isVoiced: false,
+      frequency: 0,
+      confidence: 0,
+      midiNote: 0,
+      pitchClass: 0,
+      octave: 0,
+      cents: 0,
+      targetFrequency: 0,
+      stability: 0,
+    });
+
+    const reading = await pushTunerSamples(tuner, []);
+
+    expect(reading).toMatchObject({
+      isVoiced: false,
+      frequency: null,
+      midiNote: null,
+      noteName: null,
+      octave: null,
+      cents: null,
+      targetFrequency: null,
+      stability: 0,
+    });
+  });
+
+  it('should reject invalid pushes and wrap native errors', async () => {
+    const invalidHandle = { id: '', config: tuner.config } as TunerHandle;
+    await expect(pushTunerSamples(invalidHandle, new Float32Array(128))).rejects.toThrow(
+      ValidationError
+    );
+
+    const corrupted = new Float32Array(128);
+    corrupted[5] = Infinity;
+    await expect(pushTunerSamples(tuner, corrupted)).rejects.toThrow(ValidationError);
+    expect(mockPush).not.toHaveBeenCalled();
+
+    mockPush.mockRejectedValueOnce(new Error('Invalid tuner ID: tn_1'));
+    await expect(pushTunerSamples(tuner, new Float32Array(128))).rejects.toThrow(
+      NativeModuleError
+    );
+  });
+
+  it('should reset and free the native tuner', async () => {
+    await resetTuner(tuner);
+    await freeTuner(tuner);
+
+    expect(LoqaExpoDspModule.resetTuner).toHaveBeenCalledWith('tn_1');
+    expect(mockFree).toHaveBeenCalledWith('tn_1');
+
+    mockFree.mockRejectedValueOnce(new Error('native failure'));
+    await expect(freeTuner(tuner)).rejects.toThrow(NativeModuleError);
+  });
+});
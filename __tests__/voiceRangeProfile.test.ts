@@ -0,0 +1,168 @@
+// Tests for the voice range profile API
+import { describe, it, expect, jest, beforeEach } from '@jest/globals';
+
+import LoqaExpoDspModule from '../src/LoqaExpoDspModule';
+import { ValidationError, NativeModuleError } from '../src/errors';
+import {
+  addVoiceRangeFrames,
+  createVoiceRangeProfile,
+  freeVoiceRangeProfile,
+  getVoiceRangeProfile,
+  mergeVoiceRangeProfiles,
+} from '../src/voiceRangeProfile';
+import type { VoiceRangeProfileConfig, VoiceRangeProfileHandle } from '../src/types';
+
+// Mock the native module
+jest.mock('../src/LoqaExpoDspModule', () => ({
+  __esModule: true,
+  default: {
+    createVoiceRangeProfile: jest.fn(),
+    addVoiceRangeFrames: jest.fn(),
+    mergeVoiceRangeProfiles: jest.fn(),
+    getVoiceRangeProfile: jest.fn(),
+    resetVoiceRangeProfile: jest.fn(),
+    freeVoiceRangeProfile: jest.fn(),
+  },
+}));
+
+// Mock the utils module to prevent logging during tests
+jest.mock('../src/utils', () => ({
+  logDebug: jest.fn(),
+}));
+
+describe('voiceRangeProfile', () => {
+  const mockCreate = LoqaExpoDspModule.createVoiceRangeProfile as jest.MockedFunction<
+    typeof LoqaExpoDspModule.createVoiceRangeProfile
+  >;
+  const mockAdd = LoqaExpoDspModule.addVoiceRangeFrames as jest.MockedFunction<
+    typeof LoqaExpoDspModule.addVoiceRangeFrames
+  >;
+  const mockMerge = LoqaExpoDspModule.mergeVoiceRangeProfiles as jest.MockedFunction<
+    typeof LoqaExpoDspModule.mergeVoiceRangeProfiles
+  >;
+  const mockGet = LoqaExpoDspModule.getVoiceRangeProfile as jest.MockedFunction<
+    typeof LoqaExpoDspModule.getVoiceRangeProfile
+  >;
+  const mockFree = LoqaExpoDspModule.freeVoiceRangeProfile as jest.MockedFunction<
+    typeof LoqaExpoDspModule.freeVoiceRangeProfile
+  >;
+
+  const config: Required<VoiceRangeProfileConfig> = {
+    referenceFrequency: 440,
+    minMidiNote: 24,
+    maxMidiNote: 108,
+    minDb: 40,
+    maxDb: 120,
+    dbStep: 1,
+    minCellFrames: 3,
+  };
+  const profile: VoiceRangeProfileHandle = { id: 'vr_1', config };
+
+  beforeEach(() => {
+    jest.clearAllMocks();
+  });
+
+  it('should send defaults and return a handle with the resolved config', async () => {
+    mockCreate.mockResolvedValueOnce('vr_1');
+
+    const handle = await createVoiceRangeProfile();
+
+    expect(mockCreate).toHaveBeenCalledWith({
+      referenceHz: 440,
+      minMidiNote: 24,
+      maxMidiNote: 108,
+      minDb: 40,
+      maxDb: 120,
+      dbStep: 1,
+      minCellFrames: 3,
+    });
+    expect(handle).toEqual(profile);
+  });
+
+  it('should reject invalid configs before calling native', async () => {
+    const invalidConfigs: VoiceRangeProfileConfig[] = [
+      { referenceFrequency: 0 },
+      { referenceFrequency: NaN },
+      { minMidiNote: -1 },
+      { maxMidiNote: 128 },
+      { minMidiNote: 60, maxMidiNote: 59 },
+      { minMidiNote: 60.5 },
+      { minDb: 90, maxDb: 90 },
+      { maxDb: Infinity },
+      { dbStep: 0 },
+      { dbStep: 0.01 },
+      { minCellFrames: 0 },
+      { minCellFrames: 1.5 },
+    ];
+    for (const invalid of invalidConfigs) {
+      await expect(createVoiceRangeProfile(invalid)).rejects.toThrow(ValidationError);
+    }
+
+    expect(mockCreate).not.toHaveBeenCalled();
+  });
+
+  it('should send plain arrays and validate frames', async () => {
+    mockAdd.mockResolvedValueOnce(undefined);
+
+    await addVoiceRangeFrames(profile, new Float32Array([220, 0]), new Float32Array([70, 30]));
+
+    expect(mockAdd).toHaveBeenCalledWith('vr_1', [220, 0], [70, 30]);
+
+    await expect(addVoiceRangeFrames(profile, [220, 0], [70])).rejects.toThrow(ValidationError);
+    await expect(addVoiceRangeFrames(profile, [-1], [70])).rejects.toThrow(ValidationError);
+    await expect(addVoiceRangeFrames(profile, [220], [NaN])).rejects.toThrow(ValidationError);
+    await expect(addVoiceRangeFrames({ id: '' } as never, [220], [70])).rejects.toThrow(
+      ValidationError
+    );
+    expect(mockAdd).toHaveBeenCalledTimes(1);
+  });
+
+  it('should merge only profiles with the same configuration', async () => {
+    mockMerge.mockResolvedValueOnce(undefined);
+
+    await mergeVoiceRangeProfiles(profile, { id: 'vr_2', config: { ...config } });
+    expect(mockMerge).toHaveBeenCalledWith('vr_1', 'vr_2');
+
+    const other = { id: 'vr_3', config: { ...config, dbStep: 2 } };
+    await expect(mergeVoiceRangeProfiles(profile, other)).rejects.toThrow(ValidationError);
+    expect(mockMerge).toHaveBeenCalledTimes(1);
+  });
+
+  it('should convert the native profile', async () => {
+    mockGet.mockResolvedValueOnce({
+      counts: [0, 4, 3, 0],
+      rowCount: 2,
+      columnCount: 2,
+      minMidiNote: 60,
+      minDb: 40,
+      dbStep: 1,
+      softDb: [41, 40],
+      loudDb: [42, 41],
+      dynamicRangeDb: [1, 1],
+      lowestMidiNote: 60,
+      highestMidiNote: 61,
+      lowestHz: 261.63,
+      highestHz: 277.18,
+      coveredCells: 2,
+      area: 2,
+      frameCount: 7,
+    });
+
+    const vrp = await getVoiceRangeProfile(profile);
+
+    expect(mockGet).toHaveBeenCalledWith('vr_1');
+    expect(vrp.counts).toBeInstanceOf(Uint32Array);
+    expect(Array.from(vrp.counts)).toEqual([0, 4, 3, 0]);
+    expect(vrp.lowestFrequency).toBe(261.63);
+    expect(vrp.highestFrequency).toBe(277.18);
+    expect(vrp.area).toBe(2);
+  });
+
+  it('should wrap native errors in NativeModuleError', async () => {
+    mockGet.mockRejectedValueOnce(new Error('Profile not found'));
+    mockFree.mockRejectedValueOnce(new Error('native failure'));
+
+    await expect(getVoiceRangeProfile(profile)).rejects.toThrow(NativeModuleError);
+    await expect(freeVoiceRangeProfile(profile)).rejects.toThrow(NativeModuleError);
+  });
+});
@@ -2,8 +2,15 @@ package com.loqalabs.loqaexpodsp
 
 import expo.modules.kotlin.modules.Module
 import expo.modules.kotlin.modules.ModuleDefinition
+import com.loqalabs.loqaexpodsp.RustJNI.FormantTrackResult
+import com.loqalabs.loqaexpodsp.RustJNI.PitchStatisticsResult
+import com.loqalabs.loqaexpodsp.RustJNI.PitchTrackResult
 import com.loqalabs.loqaexpodsp.RustJNI.RustBridge
+import com.loqalabs.loqaexpodsp.RustJNI.StreamFramesResult
+import com.loqalabs.loqaexpodsp.RustJNI.TunerReading
+import com.loqalabs.loqaexpodsp.RustJNI.VibratoResult
 import com.loqalabs.loqaexpodsp.RustJNI.VoiceAnalyzerConfig
+import com.loqalabs.loqaexpodsp.RustJNI.VoiceRangeProfileResult
 
 /**
  * LoqaExpoDspModule provides Expo Module API for accessing Rust DSP functions.
@@ -15,6 +22,18 @@ import com.loqalabs.loqaexpodsp.RustJNI.VoiceAnalyzerConfig
  * - analyzeSpectrum: Spectral feature analysis
  * - calculateHNR: Harmonics-to-Noise Ratio
  * - calculateH1H2: H1-H2 amplitude difference
+ * - calculateJitter / calculateShimmer: Praat-compatible perturbation measures
+ * - calculateCPP: Cepstral peak prominence (CPP and smoothed CPPS)
+ * - computeIntensity: Framewise RMS, peak and calibrated intensity contours
+ * - detectVoiceActivity: Speech segments from energy, zero-crossing rate and pYIN voicing
+ * - estimateVocalTractLength: Vocal tract length in cm from the formant spacing of voiced frames
+ * - postprocessPitchTrack: Octave-jump correction, gap interpolation and smoothing of pitch tracks
+ * - computePitchStatistics: Hz and semitone pitch statistics weighted by voiced probability
+ * - analyzeVibrato: Vibrato rate, extent, regularity and onset from pitch tracks
+ * - pushSamples / flushVoiceAnalyzer: Live pitch frames from audio chunks of any size
+ * - createTuner / pushTunerSamples: Nearest note, cents deviation and stability of live audio
+ * - createVoiceRangeProfile / addVoiceRangeFrames: Semitone x dB voice range profile of a session
+ * - createFormantTracker / trackFormants: F1-F4 contours with continuity across frames
  *
  * All functions run on background threads automatically via Expo's AsyncFunction.
  * Results are returned via Promises for async/await support in JavaScript/TypeScript.
@@ -22,7 +41,8 @@ import com.loqalabs.loqaexpodsp.RustJNI.VoiceAnalyzerConfig
  * v0.4.0 CHANGES:
  * - detectPitch now uses pYIN algorithm with min/max frequency parameters
  * - detectPitch returns voicedProbability in addition to isVoiced
- * - extractFormants returns confidence instead of bandwidths
+ * - extractFormants returns confidence instead of bandwidths (bandwidths and F4/F5
+ *   are reported again through the wrapper's own LPC analysis)
  */
 class LoqaExpoDspModule : Module() {
   // Module definition for Expo Modules API
@@ -30,6 +50,11 @@ class LoqaExpoDspModule : Module() {
     // Module name that JavaScript will use to require this module
     Name("LoqaExpoDsp")
 
+    // Forward Rust diagnostics (validation errors, caught panics) to Logcat
+    OnCreate {
+      RustBridge.setLogger(RustBridge.logcatLogger)
+    }
+
     // ============================================================================
     // Async Function: computeFFT
     // ============================================================================
@@ -41,15 +66,21 @@ class LoqaExpoDspModule : Module() {
      * Expo automatically runs this on a background thread.
      *
      * @param buffer Audio samples as FloatArray
-     * @param options Map with optional keys: "fftSize" (Int), "windowType" (String), "sampleRate" (Double)
+     * @param options Map with optional keys: "fftSize" (Int), "windowType" (String),
+     *                "windowParameter" (Double), "normalization" (String),
+     *                "includePhase" (Boolean), "sampleRate" (Number)
      * @return Map with keys: "magnitude" (FloatArray), "frequencies" (FloatArray)
+     *         and "phase" (FloatArray) when includePhase is true
      * @throws Exception with error code "VALIDATION_ERROR" or "FFT_ERROR"
      */
     AsyncFunction("computeFFT") { buffer: FloatArray, options: Map<String, Any?> ->
       // Extract options with defaults
-      val fftSize = (options["fftSize"] as? Int) ?: buffer.size
+      val fftSize = (options["fftSize"] as? Number)?.toInt() ?: buffer.size
       val windowTypeString = (options["windowType"] as? String) ?: "hanning"
-      val sampleRate = (options["sampleRate"] as? Double) ?: 44100.0
+      val windowParameter = (options["windowParameter"] as? Number)?.toFloat() ?: 0f
+      val normalizationString = (options["normalization"] as? String) ?: "amplitude"
+      val includePhase = (options["includePhase"] as? Boolean) ?: false
+      val sampleRate = (options["sampleRate"] as? Number)?.toInt() ?: 44100
 
       // Validate buffer is not empty (AC2)
       if (buffer.isEmpty()) {
@@ -66,30 +97,50 @@ class LoqaExpoDspModule : Module() {
         throw Exception("VALIDATION_ERROR: FFT size must be between 256 and 8192, got $fftSize")
       }
 
-      // Map windowType string to integer (none=0, hanning=1, hamming=2, blackman=3)
+      // Map windowType string to the Rust LoqaWindowType value
       val windowType: Int = when (windowTypeString.lowercase()) {
-        "none" -> 0
-        "hanning" -> 1
-        "hamming" -> 2
-        "blackman" -> 3
-        else -> throw Exception("VALIDATION_ERROR: Invalid window type '$windowTypeString'. Must be one of: none, hanning, hamming, blackman")
+        "none" -> RustBridge.WINDOW_NONE
+        "hanning" -> RustBridge.WINDOW_HANNING
+        "hamming" -> RustBridge.WINDOW_HAMMING
+        "blackman" -> RustBridge.WINDOW_BLACKMAN
+        "kaiser" -> RustBridge.WINDOW_KAISER
+        "flattop" -> RustBridge.WINDOW_FLAT_TOP
+        "gaussian" -> RustBridge.WINDOW_GAUSSIAN
+        else -> throw Exception("VALIDATION_ERROR: Invalid window type '$windowTypeString'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+      }
+
+      val normalization: Int = when (normalizationString.lowercase()) {
+        "amplitude" -> RustBridge.FFT_NORMALIZATION_AMPLITUDE
+        "psd" -> RustBridge.FFT_NORMALIZATION_PSD
+        else -> throw Exception("VALIDATION_ERROR: Invalid normalization '$normalizationString'. Must be one of: amplitude, psd")
       }
 
       try {
         // Call Rust FFT function via JNI (AC3)
         // JNI handles FloatArray marshalling automatically (AC4)
-        val magnitude = RustBridge.computeFFT(buffer, fftSize, windowType)
+        val result = RustBridge.computeFFT(
+          buffer,
+          sampleRate,
+          fftSize,
+          windowType,
+          windowParameter,
+          normalization,
+          includePhase
+        )
 
-        // Build frequencies array: freq[i] = (sampleRate / fftSize) * i (AC5)
-        val frequencies = FloatArray(magnitude.size) { i ->
-          (sampleRate / fftSize * i).toFloat()
+        // Return result map (AC5); frequencies are the bin centers computed in Rust
+        if (includePhase) {
+          mapOf(
+            "magnitude" to result.magnitude,
+            "phase" to result.phase,
+            "frequencies" to result.frequencies
+          )
+        } else {
+          mapOf(
+            "magnitude" to result.magnitude,
+            "frequencies" to result.frequencies
+          )
         }
-
-        // Return result map (AC5)
-        mapOf(
-          "magnitude" to magnitude,
-          "frequencies" to frequencies
-        )
       } catch (e: RuntimeException) {
         // Catch JNI/Rust errors and reject with FFT_ERROR code (AC6)
         throw Exception("FFT_ERROR: ${e.message}", e)
@@ -99,6 +150,259 @@ class LoqaExpoDspModule : Module() {
       }
     }
 
+    // ============================================================================
+    // Async Functions: computeComplexFFT / inverseFFT
+    // ============================================================================
+
+    /**
+     * Computes the unwindowed, unnormalized complex FFT.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param options Map with optional key: "fftSize" (Int, 0 = fit the buffer)
+     * @return Map with keys: "bins" (FloatArray, interleaved re/im) and "fftSize" (Int)
+     * @throws Exception with error code "FFT_ERROR"
+     */
+    AsyncFunction("computeComplexFFT") { buffer: FloatArray, options: Map<String, Any?> ->
+      val fftSize = (options["fftSize"] as? Number)?.toInt() ?: 0
+
+      try {
+        val bins = RustBridge.computeComplexFFT(buffer, fftSize)
+        mapOf(
+          "bins" to bins,
+          "fftSize" to bins.size - 2
+        )
+      } catch (e: Exception) {
+        throw Exception("FFT_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Computes the inverse FFT of interleaved one-sided bins.
+     *
+     * @param bins Interleaved re/im pairs, fftSize / 2 + 1 of them
+     * @return FloatArray of fftSize samples
+     * @throws Exception with error code "FFT_ERROR"
+     */
+    AsyncFunction("inverseFFT") { bins: FloatArray ->
+      try {
+        RustBridge.inverseFFT(bins)
+      } catch (e: Exception) {
+        throw Exception("FFT_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // Async Function: computeSpectrogram
+    // ============================================================================
+
+    /**
+     * Computes the spectrogram of a whole buffer in one native call.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "frameSize" (Int), "hopSize" (Int),
+     *                "windowType" (String), "windowParameter" (Double),
+     *                "scaling" (String: "linear", "power" or "db"), "dbFloor" (Double)
+     * @return Map with keys: "values" (FloatArray, row-major), "timestamps" (FloatArray),
+     *         "frameCount" (Int), "binCount" (Int)
+     * @throws Exception with error code "VALIDATION_ERROR" or "SPECTROGRAM_ERROR"
+     */
+    AsyncFunction("computeSpectrogram") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val windowTypeString = (options["windowType"] as? String) ?: "hanning"
+      val windowParameter = (options["windowParameter"] as? Number)?.toFloat() ?: 0f
+      val scalingString = (options["scaling"] as? String) ?: "db"
+      val dbFloor = (options["dbFloor"] as? Number)?.toFloat() ?: -120f
+
+      val windowType: Int = when (windowTypeString.lowercase()) {
+        "none" -> RustBridge.WINDOW_NONE
+        "hanning" -> RustBridge.WINDOW_HANNING
+        "hamming" -> RustBridge.WINDOW_HAMMING
+        "blackman" -> RustBridge.WINDOW_BLACKMAN
+        "kaiser" -> RustBridge.WINDOW_KAISER
+        "flattop" -> RustBridge.WINDOW_FLAT_TOP
+        "gaussian" -> RustBridge.WINDOW_GAUSSIAN
+        else -> throw Exception("VALIDATION_ERROR: Invalid window type '$windowTypeString'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+      }
+
+      val scaling: Int = when (scalingString.lowercase()) {
+        "linear" -> RustBridge.SPECTROGRAM_SCALING_LINEAR
+        "power" -> RustBridge.SPECTROGRAM_SCALING_POWER
+        "db" -> RustBridge.SPECTROGRAM_SCALING_DB
+        else -> throw Exception("VALIDATION_ERROR: Invalid scaling '$scalingString'. Must be one of: linear, power, db")
+      }
+
+      try {
+        val result = RustBridge.computeSpectrogram(
+          buffer,
+          sampleRate,
+          frameSize,
+          hopSize,
+          windowType,
+          windowParameter,
+          scaling,
+          dbFloor
+        )
+
+        mapOf(
+          "values" to result.values,
+          "timestamps" to result.timestamps,
+          "frameCount" to result.frameCount,
+          "binCount" to result.binCount
+        )
+      } catch (e: Exception) {
+        throw Exception("SPECTROGRAM_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // Async Function: computeMelSpectrogram
+    // ============================================================================
+
+    /**
+     * Computes the log-mel spectrogram (dB) of a whole buffer in one native call.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "frameSize" (Int), "hopSize" (Int),
+     *                "windowType" (String), "windowParameter" (Double), "nMels" (Int),
+     *                "fmin" (Double), "fmax" (Double, 0 = Nyquist),
+     *                "melScale" (String: "htk" or "slaney")
+     * @return Map with keys: "values" (FloatArray, row-major), "timestamps" (FloatArray),
+     *         "frameCount" (Int), "featureCount" (Int, nMels)
+     * @throws Exception with error code "VALIDATION_ERROR" or "MEL_SPECTROGRAM_ERROR"
+     */
+    AsyncFunction("computeMelSpectrogram") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val windowTypeString = (options["windowType"] as? String) ?: "hanning"
+      val windowParameter = (options["windowParameter"] as? Number)?.toFloat() ?: 0f
+      val nMels = (options["nMels"] as? Number)?.toInt() ?: 40
+      val fmin = (options["fmin"] as? Number)?.toFloat() ?: 0f
+      val fmax = (options["fmax"] as? Number)?.toFloat() ?: 0f
+      val melScaleString = (options["melScale"] as? String) ?: "slaney"
+
+      val windowType: Int = when (windowTypeString.lowercase()) {
+        "none" -> RustBridge.WINDOW_NONE
+        "hanning" -> RustBridge.WINDOW_HANNING
+        "hamming" -> RustBridge.WINDOW_HAMMING
+        "blackman" -> RustBridge.WINDOW_BLACKMAN
+        "kaiser" -> RustBridge.WINDOW_KAISER
+        "flattop" -> RustBridge.WINDOW_FLAT_TOP
+        "gaussian" -> RustBridge.WINDOW_GAUSSIAN
+        else -> throw Exception("VALIDATION_ERROR: Invalid window type '$windowTypeString'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+      }
+
+      val melScale: Int = when (melScaleString.lowercase()) {
+        "htk" -> RustBridge.MEL_SCALE_HTK
+        "slaney" -> RustBridge.MEL_SCALE_SLANEY
+        else -> throw Exception("VALIDATION_ERROR: Invalid mel scale '$melScaleString'. Must be one of: htk, slaney")
+      }
+
+      try {
+        val result = RustBridge.computeLogMelSpectrogram(
+          buffer,
+          sampleRate,
+          frameSize,
+          hopSize,
+          windowType,
+          windowParameter,
+          nMels,
+          fmin,
+          fmax,
+          melScale
+        )
+
+        mapOf(
+          "values" to result.values,
+          "timestamps" to result.timestamps,
+          "frameCount" to result.frameCount,
+          "featureCount" to result.featureCount
+        )
+      } catch (e: Exception) {
+        throw Exception("MEL_SPECTROGRAM_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // Async Function: computeMFCC
+    // ============================================================================
+
+    /**
+     * Computes MFCCs (plus optional deltas) of a whole buffer in one native call.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "frameSize" (Int), "hopSize" (Int),
+     *                "windowType" (String), "windowParameter" (Double), "nMels" (Int),
+     *                "fmin" (Double), "fmax" (Double, 0 = Nyquist),
+     *                "melScale" (String: "htk" or "slaney"),
+     *                "nMfcc" (Int), "lifter" (Double), "deltaOrder" (Int), "deltaWidth" (Int)
+     * @return Map with keys: "values" (FloatArray, row-major), "timestamps" (FloatArray),
+     *         "frameCount" (Int), "featureCount" (Int, nMfcc * (1 + deltaOrder))
+     * @throws Exception with error code "VALIDATION_ERROR" or "MFCC_ERROR"
+     */
+    AsyncFunction("computeMFCC") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val windowTypeString = (options["windowType"] as? String) ?: "hanning"
+      val windowParameter = (options["windowParameter"] as? Number)?.toFloat() ?: 0f
+      val nMels = (options["nMels"] as? Number)?.toInt() ?: 40
+      val fmin = (options["fmin"] as? Number)?.toFloat() ?: 0f
+      val fmax = (options["fmax"] as? Number)?.toFloat() ?: 0f
+      val melScaleString = (options["melScale"] as? String) ?: "slaney"
+      val nMfcc = (options["nMfcc"] as? Number)?.toInt() ?: 13
+      val lifter = (options["lifter"] as? Number)?.toFloat() ?: 0f
+      val deltaOrder = (options["deltaOrder"] as? Number)?.toInt() ?: 0
+      val deltaWidth = (options["deltaWidth"] as? Number)?.toInt() ?: 2
+
+      val windowType: Int = when (windowTypeString.lowercase()) {
+        "none" -> RustBridge.WINDOW_NONE
+        "hanning" -> RustBridge.WINDOW_HANNING
+        "hamming" -> RustBridge.WINDOW_HAMMING
+        "blackman" -> RustBridge.WINDOW_BLACKMAN
+        "kaiser" -> RustBridge.WINDOW_KAISER
+        "flattop" -> RustBridge.WINDOW_FLAT_TOP
+        "gaussian" -> RustBridge.WINDOW_GAUSSIAN
+        else -> throw Exception("VALIDATION_ERROR: Invalid window type '$windowTypeString'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+      }
+
+      val melScale: Int = when (melScaleString.lowercase()) {
+        "htk" -> RustBridge.MEL_SCALE_HTK
+        "slaney" -> RustBridge.MEL_SCALE_SLANEY
+        else -> throw Exception("VALIDATION_ERROR: Invalid mel scale '$melScaleString'. Must be one of: htk, slaney")
+      }
+
+      try {
+        val result = RustBridge.computeMfcc(
+          buffer,
+          sampleRate,
+          frameSize,
+          hopSize,
+          windowType,
+          windowParameter,
+          nMels,
+          fmin,
+          fmax,
+          melScale,
+          nMfcc,
+          lifter,
+          deltaOrder,
+          deltaWidth
+        )
+
+        mapOf(
+          "values" to result.values,
+          "timestamps" to result.timestamps,
+          "frameCount" to result.frameCount,
+          "featureCount" to result.featureCount
+        )
+      } catch (e: Exception) {
+        throw Exception("MFCC_ERROR: ${e.message}", e)
+      }
+    }
+
     // ============================================================================
     // Async Function: detectPitch
     // ============================================================================
@@ -115,7 +419,8 @@ class LoqaExpoDspModule : Module() {
      *
      * @param buffer Audio samples as FloatArray
      * @param sampleRate Sample rate in Hz (Int)
-     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double)
+     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double),
+     *                "algorithm" (String: "auto", "pyin", "yin" or "autocorr")
      * @return Map with keys: "frequency" (Float or null), "confidence" (Float), "isVoiced" (Boolean), "voicedProbability" (Float)
      * @throws Exception with error code "PITCH_ERROR"
      */
@@ -124,6 +429,14 @@ class LoqaExpoDspModule : Module() {
         // Extract options with defaults for human voice range
         val minFrequency = (options["minFrequency"] as? Double)?.toFloat() ?: 80.0f
         val maxFrequency = (options["maxFrequency"] as? Double)?.toFloat() ?: 400.0f
+        val algorithm = when (val name = options["algorithm"] as? String) {
+          null -> null
+          "auto" -> RustBridge.PITCH_ALGORITHM_AUTO
+          "pyin" -> RustBridge.PITCH_ALGORITHM_PYIN
+          "yin" -> RustBridge.PITCH_ALGORITHM_YIN
+          "autocorr" -> RustBridge.PITCH_ALGORITHM_AUTOCORR
+          else -> throw Exception("VALIDATION_ERROR: Unknown pitch algorithm '$name'")
+        }
 
         // Validate buffer is not empty (AC3)
         if (buffer.isEmpty()) {
@@ -135,8 +448,13 @@ class LoqaExpoDspModule : Module() {
           throw Exception("VALIDATION_ERROR: Sample rate must be between 8000 and 48000 Hz, got $sampleRate")
         }
 
-        // Call Rust pYIN pitch detection via JNI with min/max frequency (v0.4.0)
-        val result = RustBridge.detectPitch(buffer, sampleRate, minFrequency, maxFrequency)
+        // Call Rust pitch detection via JNI with min/max frequency; an explicit
+        // algorithm runs through the analyzer's per-algorithm detectors
+        val result = if (algorithm == null) {
+          RustBridge.detectPitch(buffer, sampleRate, minFrequency, maxFrequency)
+        } else {
+          RustBridge.detectPitch(buffer, sampleRate, minFrequency, maxFrequency, algorithm)
+        }
 
         // Convert PitchResult to Map for TypeScript
         // frequency is null if not voiced or 0.0
@@ -165,13 +483,11 @@ class LoqaExpoDspModule : Module() {
      *
      * Expo automatically runs this on a background thread.
      *
-     * v0.4.0 BREAKING CHANGE:
-     * - Now returns confidence score instead of bandwidths
-     *
      * @param buffer Audio samples as FloatArray
      * @param sampleRate Sample rate in Hz (Int)
      * @param options Map with optional keys: "lpcOrder" (Int)
-     * @return Map with keys: "f1" (Float), "f2" (Float), "f3" (Float), "confidence" (Float)
+     * @return Map with keys: "f1"-"f5" (Float), "b1"-"b5" (bandwidths, Float),
+     *         "confidence" (mean F1-F3 confidence, Float), "confidences" (F1-F5, List<Float>)
      * @throws Exception with error code "FORMANTS_ERROR"
      */
     AsyncFunction("extractFormants") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
@@ -194,12 +510,19 @@ class LoqaExpoDspModule : Module() {
         val result = RustBridge.extractFormants(buffer, sampleRate, lpcOrder)
 
         // Convert FormantsResult to Map for TypeScript
-        // v0.4.0: Now returns confidence instead of bandwidths
         mapOf(
           "f1" to result.f1,
           "f2" to result.f2,
           "f3" to result.f3,
-          "confidence" to result.confidence
+          "f4" to result.f4,
+          "f5" to result.f5,
+          "b1" to result.bandwidths[0],
+          "b2" to result.bandwidths[1],
+          "b3" to result.bandwidths[2],
+          "b4" to result.bandwidths[3],
+          "b5" to result.bandwidths[4],
+          "confidence" to result.confidence,
+          "confidences" to result.confidences.toList()
         )
       } catch (e: RuntimeException) {
         // Catch JNI/Rust errors and throw with FORMANTS_ERROR code
@@ -215,19 +538,27 @@ class LoqaExpoDspModule : Module() {
     // ============================================================================
 
     /**
-     * Analyzes spectral features (centroid, rolloff, tilt).
+     * Analyzes spectral features (centroid, rolloff, tilt and shape descriptors).
      *
      * Implemented in Story 4.2.
      * Expo automatically runs this on a background thread.
      *
      * @param buffer Audio samples as FloatArray
      * @param sampleRate Sample rate in Hz (Int)
-     * @param options Map with optional configuration
-     * @return Map with keys: "centroid" (Float), "rolloff" (Float), "tilt" (Float)
+     * @param options Map with optional keys: "fftSize" (Int), "averageFrames" (Boolean),
+     *                "rolloffLowThreshold" (Double), "rolloffHighThreshold" (Double)
+     * @return Map with keys: "centroid", "rolloff", "tilt", "spread", "skewness", "kurtosis",
+     *         "flatness", "entropy", "crestFactor", "rolloffLow", "rolloffHigh" (all Float)
      * @throws Exception with error code "SPECTRUM_ERROR"
      */
     AsyncFunction("analyzeSpectrum") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
       try {
+        // Extract options with defaults (fftSize 0 = fitted to the buffer)
+        val fftSize = (options["fftSize"] as? Number)?.toInt() ?: 0
+        val averageFrames = options["averageFrames"] as? Boolean ?: true
+        val rolloffLowThreshold = (options["rolloffLowThreshold"] as? Double)?.toFloat() ?: 0.85f
+        val rolloffHighThreshold = (options["rolloffHighThreshold"] as? Double)?.toFloat() ?: 0.95f
+
         // Validate buffer is not empty (AC3)
         if (buffer.isEmpty()) {
           throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
@@ -240,7 +571,14 @@ class LoqaExpoDspModule : Module() {
 
         // Call Rust spectral analysis via JNI (AC1, AC2)
         // JNI handles FloatArray marshalling automatically (AC5)
-        val result = RustBridge.analyzeSpectrum(buffer, sampleRate)
+        val result = RustBridge.analyzeSpectrum(
+          buffer,
+          sampleRate,
+          fftSize,
+          averageFrames,
+          rolloffLowThreshold,
+          rolloffHighThreshold
+        )
 
         // Return result map with spectral features (AC4)
         result
@@ -344,207 +682,644 @@ class LoqaExpoDspModule : Module() {
     }
 
     // ============================================================================
-    // VoiceAnalyzer Streaming API (v0.3.0)
+    // Async Function: calculateJitter
     // ============================================================================
 
     /**
-     * Creates a new VoiceAnalyzer instance with configuration.
+     * Calculates Praat-compatible jitter from the voiced periods of a buffer.
      *
-     * @param config Map with keys: "sampleRate" (Int), optional: "minFrequency", "maxFrequency", "frameSize", "hopSize"
-     * @return Map with keys: "id" (String), "config" (Map)
-     * @throws Exception with error code "ANALYZER_ERROR"
+     * Relative measures are fractions and meanPeriod is in seconds.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double)
+     * @return Map with keys: "local", "localAbsolute", "rap", "ppq5", "ddp", "meanPeriod" (Float, or null
+     *         when undefined) and "periodCount" (Int)
+     * @throws Exception with error code "VALIDATION_ERROR" or "JITTER_ERROR"
      */
-    AsyncFunction("createVoiceAnalyzer") { config: Map<String, Any?> ->
+    AsyncFunction("calculateJitter") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
       try {
-        // Extract config with defaults
-        val sampleRate = (config["sampleRate"] as? Int)
-          ?: throw Exception("VALIDATION_ERROR: sampleRate is required")
-        val minFrequency = (config["minFrequency"] as? Double)?.toFloat() ?: 80.0f
-        val maxFrequency = (config["maxFrequency"] as? Double)?.toFloat() ?: 400.0f
-        val frameSize = (config["frameSize"] as? Int) ?: 2048
-        val hopSize = (config["hopSize"] as? Int) ?: (frameSize / 4)
+        // Extract options with defaults
+        val minFrequency = (options["minFrequency"] as? Double)?.toFloat() ?: 75.0f
+        val maxFrequency = (options["maxFrequency"] as? Double)?.toFloat() ?: 500.0f
+
+        // Validate buffer is not empty
+        if (buffer.isEmpty()) {
+          throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
+        }
 
         // Validate sample rate
         if (sampleRate < 8000 || sampleRate > 48000) {
           throw Exception("VALIDATION_ERROR: Sample rate must be between 8000 and 48000 Hz, got $sampleRate")
         }
 
-        // Create config
-        val analyzerConfig = VoiceAnalyzerConfig(
-          sampleRate = sampleRate,
-          minFrequency = minFrequency,
-          maxFrequency = maxFrequency,
-          frameSize = frameSize,
-          hopSize = hopSize
-        )
-
-        // Create analyzer
-        val handle = RustBridge.createVoiceAnalyzer(analyzerConfig)
+        // Validate frequency range
+        if (minFrequency <= 0.0f || minFrequency >= maxFrequency) {
+          throw Exception("VALIDATION_ERROR: minFrequency must be positive and below maxFrequency")
+        }
 
-        // Store handle and return ID
-        val id = storeAnalyzer(handle, analyzerConfig)
+        // Call Rust jitter calculation via JNI
+        val result = RustBridge.calculateJitter(buffer, sampleRate, minFrequency, maxFrequency)
 
+        // Convert JitterResult to Map for TypeScript (undefined measures become null)
         mapOf(
-          "id" to id,
-          "config" to mapOf(
-            "sampleRate" to sampleRate,
-            "minFrequency" to minFrequency,
-            "maxFrequency" to maxFrequency,
-            "frameSize" to frameSize,
-            "hopSize" to hopSize
-          )
+          "local" to measureOrNull(result.local),
+          "localAbsolute" to measureOrNull(result.localAbsolute),
+          "rap" to measureOrNull(result.rap),
+          "ppq5" to measureOrNull(result.ppq5),
+          "ddp" to measureOrNull(result.ddp),
+          "meanPeriod" to measureOrNull(result.meanPeriod),
+          "periodCount" to result.periodCount
         )
       } catch (e: RuntimeException) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+        throw Exception("JITTER_ERROR: ${e.message}", e)
       } catch (e: Exception) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+        throw Exception("JITTER_ERROR: ${e.message}", e)
       }
     }
 
+    // ============================================================================
+    // Async Function: calculateShimmer
+    // ============================================================================
+
     /**
-     * Process audio through VoiceAnalyzer and return aggregated results.
+     * Calculates Praat-compatible shimmer from the peak amplitudes of the voiced periods of a buffer.
+     *
+     * Relative measures are fractions and localDb is in dB.
      *
-     * @param analyzerId Analyzer ID from createVoiceAnalyzer
      * @param buffer Audio samples as FloatArray
-     * @return VoiceAnalyzerResult map with frames and statistics
-     * @throws Exception with error code "ANALYZER_ERROR"
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double)
+     * @return Map with keys: "local", "localDb", "apq3", "apq5", "apq11", "dda", "meanAmplitude" (Float, or null
+     *         when undefined) and "periodCount" (Int)
+     * @throws Exception with error code "VALIDATION_ERROR" or "SHIMMER_ERROR"
      */
-    AsyncFunction("analyzeClip") { analyzerId: String, buffer: FloatArray ->
+    AsyncFunction("calculateShimmer") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
       try {
-        // Get analyzer handle
-        val (handle, config) = getAnalyzer(analyzerId)
-          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
+        // Extract options with defaults
+        val minFrequency = (options["minFrequency"] as? Double)?.toFloat() ?: 75.0f
+        val maxFrequency = (options["maxFrequency"] as? Double)?.toFloat() ?: 500.0f
 
+        // Validate buffer is not empty
         if (buffer.isEmpty()) {
           throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
         }
 
-        // Process audio through analyzer
-        val frames = RustBridge.processAudioWithAnalyzer(handle, buffer)
-
-        // Calculate aggregate statistics
-        val voicedFrames = frames.filter { it.isVoiced }
-        val voicedFrequencies = voicedFrames
-          .filter { it.frequency > 0 }
-          .map { it.frequency }
-
-        // Convert frames to maps
-        val frameMaps = frames.map { frame ->
-          mapOf(
-            "frequency" to if (frame.isVoiced && frame.frequency > 0) frame.frequency else null,
-            "confidence" to frame.confidence,
-            "isVoiced" to frame.isVoiced,
-            "voicedProbability" to frame.voicedProbability
-          )
-        }
-
-        // Calculate statistics
-        val medianPitch: Float? = if (voicedFrequencies.isEmpty()) null else {
-          val sorted = voicedFrequencies.sorted()
-          val mid = sorted.size / 2
-          if (sorted.size % 2 == 0) {
-            (sorted[mid - 1] + sorted[mid]) / 2
-          } else {
-            sorted[mid]
-          }
-        }
-
-        val meanPitch: Float? = if (voicedFrequencies.isEmpty()) null else {
-          voicedFrequencies.sum() / voicedFrequencies.size
-        }
-
-        val pitchStdDev: Float? = if (voicedFrequencies.size < 2 || meanPitch == null) null else {
-          val variance = voicedFrequencies.map { (it - meanPitch) * (it - meanPitch) }.sum() / voicedFrequencies.size
-          kotlin.math.sqrt(variance)
+        // Validate sample rate
+        if (sampleRate < 8000 || sampleRate > 48000) {
+          throw Exception("VALIDATION_ERROR: Sample rate must be between 8000 and 48000 Hz, got $sampleRate")
         }
 
-        val meanConfidence: Float? = if (voicedFrames.isEmpty()) null else {
-          voicedFrames.map { it.confidence }.sum() / voicedFrames.size
+        // Validate frequency range
+        if (minFrequency <= 0.0f || minFrequency >= maxFrequency) {
+          throw Exception("VALIDATION_ERROR: minFrequency must be positive and below maxFrequency")
         }
 
-        val meanVoicedProbability: Float = if (frames.isEmpty()) 0f else {
-          frames.map { it.voicedProbability }.sum() / frames.size
-        }
+        // Call Rust shimmer calculation via JNI
+        val result = RustBridge.calculateShimmer(buffer, sampleRate, minFrequency, maxFrequency)
 
+        // Convert ShimmerResult to Map for TypeScript (undefined measures become null)
         mapOf(
-          "frames" to frameMaps,
-          "frameCount" to frames.size,
-          "voicedFrameCount" to voicedFrames.size,
-          "medianPitch" to medianPitch,
-          "meanPitch" to meanPitch,
-          "pitchStdDev" to pitchStdDev,
-          "meanConfidence" to meanConfidence,
-          "meanVoicedProbability" to meanVoicedProbability
+          "local" to measureOrNull(result.local),
+          "localDb" to measureOrNull(result.localDb),
+          "apq3" to measureOrNull(result.apq3),
+          "apq5" to measureOrNull(result.apq5),
+          "apq11" to measureOrNull(result.apq11),
+          "dda" to measureOrNull(result.dda),
+          "meanAmplitude" to measureOrNull(result.meanAmplitude),
+          "periodCount" to result.periodCount
         )
       } catch (e: RuntimeException) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+        throw Exception("SHIMMER_ERROR: ${e.message}", e)
       } catch (e: Exception) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+        throw Exception("SHIMMER_ERROR: ${e.message}", e)
       }
     }
 
+    // ============================================================================
+    // Async Function: calculateCPP
+    // ============================================================================
+
     /**
-     * Process audio with HMM-smoothed Viterbi decoding for globally optimal pitch track (v0.5.0).
-     *
-     * Unlike analyzeClip which treats frames independently, this uses Viterbi decoding
-     * to reduce octave errors (from ~8-12% to ≤3%) and produce smoother contours.
+     * Calculates cepstral peak prominence (CPP) and smoothed CPP (CPPS) of a whole buffer.
      *
-     * @param analyzerId Analyzer ID from createVoiceAnalyzer
      * @param buffer Audio samples as FloatArray
-     * @return PitchTrack map with pitchTrack, voicedProbabilities, timestamps, and statistics
-     * @throws Exception with error code "ANALYZER_ERROR"
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "frameSize" (Int), "hopSize" (Int),
+     *                "minFrequency" (Double), "maxFrequency" (Double),
+     *                "timeSmoothing" (Double, seconds), "quefrencySmoothing" (Double, seconds)
+     * @return Map with keys: "cpp" (Float, dB), "cpps" (Float, dB), "f0" (Float, Hz),
+     *         "frameCount" (Int)
+     * @throws Exception with error code "VALIDATION_ERROR" or "CPP_ERROR"
      */
-    AsyncFunction("processBuffer") { analyzerId: String, buffer: FloatArray ->
-      try {
-        // Get analyzer handle
-        val (handle, config) = getAnalyzer(analyzerId)
-          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
-
-        if (buffer.isEmpty()) {
-          throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
-        }
-
-        // Process buffer with Viterbi decoding
-        val track = RustBridge.processBufferWithAnalyzer(handle, buffer)
-
-        // Calculate aggregate statistics
-        val voicedPitches = track.pitchTrack.filter { it > 0 }
-        val voicedCount = voicedPitches.size
-
-        // Median pitch
-        val medianPitch: Float? = if (voicedPitches.isEmpty()) null else {
-          val sorted = voicedPitches.sorted()
-          val mid = sorted.size / 2
-          if (sorted.size % 2 == 0) {
-            (sorted[mid - 1] + sorted[mid]) / 2
-          } else {
-            sorted[mid]
-          }
-        }
+    AsyncFunction("calculateCPP") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 128
+      val minFrequency = (options["minFrequency"] as? Number)?.toFloat() ?: 60.0f
+      val maxFrequency = (options["maxFrequency"] as? Number)?.toFloat() ?: 330.0f
+      val timeSmoothing = (options["timeSmoothing"] as? Number)?.toFloat() ?: 0.02f
+      val quefrencySmoothing = (options["quefrencySmoothing"] as? Number)?.toFloat() ?: 0.0005f
+
+      if (buffer.size <= frameSize) {
+        throw Exception("VALIDATION_ERROR: Buffer must be longer than frameSize ($frameSize), got ${buffer.size}")
+      }
 
-        // Mean pitch
-        val meanPitch: Float? = if (voicedPitches.isEmpty()) null else {
-          voicedPitches.sum() / voicedPitches.size
-        }
+      try {
+        val result = RustBridge.calculateCpp(
+          buffer,
+          sampleRate,
+          frameSize,
+          hopSize,
+          minFrequency,
+          maxFrequency,
+          timeSmoothing,
+          quefrencySmoothing
+        )
 
         mapOf(
-          "pitchTrack" to track.pitchTrack.toList(),
-          "voicedProbabilities" to track.voicedProbabilities.toList(),
-          "timestamps" to track.timestamps.toList(),
-          "frameCount" to track.pitchTrack.size,
-          "voicedFrameCount" to voicedCount,
-          "medianPitch" to medianPitch,
-          "meanPitch" to meanPitch
+          "cpp" to result.cpp,
+          "cpps" to result.cpps,
+          "f0" to result.f0,
+          "frameCount" to result.frameCount
         )
-      } catch (e: RuntimeException) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
       } catch (e: Exception) {
-        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+        throw Exception("CPP_ERROR: ${e.message}", e)
       }
     }
 
-    /**
-     * Reset analyzer state for reuse with new audio.
+    // ============================================================================
+    // Async Function: computeIntensity
+    // ============================================================================
+
+    /**
+     * Computes framewise RMS, peak and intensity contours of a whole buffer.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "frameSize" (Int), "hopSize" (Int),
+     *                "calibrationOffset" (Double, dB), "floorDb" (Double, dBFS)
+     * @return Map with keys: "rms", "intensityDb", "peakDb", "timestamps" (FloatArray),
+     *         "meanDb", "minDb", "maxDb", "dynamicRangeDb" (Float)
+     * @throws Exception with error code "VALIDATION_ERROR" or "INTENSITY_ERROR"
+     */
+    AsyncFunction("computeIntensity") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val calibrationOffset = (options["calibrationOffset"] as? Number)?.toFloat() ?: 0f
+      val floorDb = (options["floorDb"] as? Number)?.toFloat() ?: -120f
+
+      if (buffer.size <= frameSize) {
+        throw Exception("VALIDATION_ERROR: Buffer must be longer than frameSize ($frameSize), got ${buffer.size}")
+      }
+
+      try {
+        val result = RustBridge.computeIntensity(
+          buffer,
+          sampleRate,
+          frameSize,
+          hopSize,
+          calibrationOffset,
+          floorDb
+        )
+
+        mapOf(
+          "rms" to result.rms,
+          "intensityDb" to result.intensityDb,
+          "peakDb" to result.peakDb,
+          "timestamps" to result.timestamps,
+          "meanDb" to result.meanDb,
+          "minDb" to result.minDb,
+          "maxDb" to result.maxDb,
+          "dynamicRangeDb" to result.dynamicRangeDb
+        )
+      } catch (e: Exception) {
+        throw Exception("INTENSITY_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // Async Function: detectVoiceActivity
+    // ============================================================================
+
+    /**
+     * Detects speech frames and segments of a whole buffer.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double),
+     *                "frameSize" (Int), "hopSize" (Int), "energyThresholdDb" (Double, dBFS),
+     *                "zcrThreshold" (Double, crossings/s), "voicingThreshold" (Double),
+     *                "hangoverTime" (Double, seconds)
+     * @return Map with keys: "timestamps", "energyDb", "zcr", "voicedProbabilities" (FloatArray),
+     *         "isSpeech" (BooleanArray), "segments" (List of Maps with "start" and "end")
+     * @throws Exception with error code "VALIDATION_ERROR" or "VAD_ERROR"
+     */
+    AsyncFunction("detectVoiceActivity") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val minFrequency = (options["minFrequency"] as? Number)?.toFloat() ?: 75.0f
+      val maxFrequency = (options["maxFrequency"] as? Number)?.toFloat() ?: 500.0f
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val energyThresholdDb = (options["energyThresholdDb"] as? Number)?.toFloat() ?: -50.0f
+      val zcrThreshold = (options["zcrThreshold"] as? Number)?.toFloat() ?: 3000.0f
+      val voicingThreshold = (options["voicingThreshold"] as? Number)?.toFloat() ?: 0.5f
+      val hangoverTime = (options["hangoverTime"] as? Number)?.toFloat() ?: 0.2f
+
+      if (buffer.size <= frameSize) {
+        throw Exception("VALIDATION_ERROR: Buffer must be longer than frameSize ($frameSize), got ${buffer.size}")
+      }
+
+      try {
+        val result = RustBridge.detectVoiceActivity(
+          buffer,
+          sampleRate,
+          minFrequency,
+          maxFrequency,
+          frameSize,
+          hopSize,
+          energyThresholdDb,
+          zcrThreshold,
+          voicingThreshold,
+          hangoverTime
+        )
+
+        mapOf(
+          "timestamps" to result.timestamps,
+          "energyDb" to result.energyDb,
+          "zcr" to result.zcr,
+          "voicedProbabilities" to result.voicedProbabilities,
+          "isSpeech" to result.isSpeech,
+          "segments" to result.segments.toList().chunked(2).map { (start, end) ->
+            mapOf("start" to start, "end" to end)
+          }
+        )
+      } catch (e: Exception) {
+        throw Exception("VAD_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // Async Function: estimateVocalTractLength
+    // ============================================================================
+
+    /**
+     * Estimates vocal tract length from F1-F4 of the voiced frames of a buffer.
+     *
+     * @param buffer Audio samples as FloatArray
+     * @param sampleRate Sample rate in Hz (Int)
+     * @param options Map with optional keys: "minFrequency" (Double), "maxFrequency" (Double),
+     *                "frameSize" (Int), "hopSize" (Int), "lpcOrder" (Int, 0 = default),
+     *                "minFormantConfidence" (Double), "speedOfSound" (Double, cm/s)
+     * @return Map with keys: "vtlCm", "spacingVtlCm", "formantSpacingHz", "f1"-"f4" (Float, Hz),
+     *         "confidence" (Float), "frameCount" (Int)
+     * @throws Exception with error code "VALIDATION_ERROR" or "VTL_ERROR"
+     */
+    AsyncFunction("estimateVocalTractLength") { buffer: FloatArray, sampleRate: Int, options: Map<String, Any?> ->
+      val minFrequency = (options["minFrequency"] as? Number)?.toFloat() ?: 75.0f
+      val maxFrequency = (options["maxFrequency"] as? Number)?.toFloat() ?: 500.0f
+      val frameSize = (options["frameSize"] as? Number)?.toInt() ?: 2048
+      val hopSize = (options["hopSize"] as? Number)?.toInt() ?: 512
+      val lpcOrder = (options["lpcOrder"] as? Number)?.toInt() ?: 0
+      val minFormantConfidence = (options["minFormantConfidence"] as? Number)?.toFloat() ?: 0.3f
+      val speedOfSound = (options["speedOfSound"] as? Number)?.toFloat() ?: 35000.0f
+
+      if (buffer.size <= frameSize) {
+        throw Exception("VALIDATION_ERROR: Buffer must be longer than frameSize ($frameSize), got ${buffer.size}")
+      }
+
+      try {
+        val result = RustBridge.estimateVocalTractLength(
+          buffer,
+          sampleRate,
+          minFrequency,
+          maxFrequency,
+          frameSize,
+          hopSize,
+          lpcOrder,
+          minFormantConfidence,
+          speedOfSound
+        )
+
+        mapOf(
+          "vtlCm" to result.vtlCm,
+          "spacingVtlCm" to result.spacingVtlCm,
+          "formantSpacingHz" to result.formantSpacingHz,
+          "f1" to result.f1,
+          "f2" to result.f2,
+          "f3" to result.f3,
+          "f4" to result.f4,
+          "confidence" to result.confidence,
+          "frameCount" to result.frameCount
+        )
+      } catch (e: Exception) {
+        throw Exception("VTL_ERROR: ${e.message}", e)
+      }
+    }
+
+    // ============================================================================
+    // VoiceAnalyzer Streaming API (v0.3.0)
+    // ============================================================================
+
+    /**
+     * Creates a new VoiceAnalyzer instance with configuration.
+     *
+     * @param config Map with keys: "sampleRate" (Int), optional: "minFrequency", "maxFrequency", "frameSize", "hopSize"
+     * @return Map with keys: "id" (String), "config" (Map)
+     * @throws Exception with error code "ANALYZER_ERROR"
+     */
+    AsyncFunction("createVoiceAnalyzer") { config: Map<String, Any?> ->
+      try {
+        // Extract config with defaults
+        val sampleRate = (config["sampleRate"] as? Int)
+          ?: throw Exception("VALIDATION_ERROR: sampleRate is required")
+        val minFrequency = (config["minFrequency"] as? Double)?.toFloat() ?: 80.0f
+        val maxFrequency = (config["maxFrequency"] as? Double)?.toFloat() ?: 400.0f
+        val frameSize = (config["frameSize"] as? Int) ?: 2048
+        val hopSize = (config["hopSize"] as? Int) ?: (frameSize / 4)
+
+        // Validate sample rate
+        if (sampleRate < 8000 || sampleRate > 48000) {
+          throw Exception("VALIDATION_ERROR: Sample rate must be between 8000 and 48000 Hz, got $sampleRate")
+        }
+
+        // Create config
+        val analyzerConfig = VoiceAnalyzerConfig(
+          sampleRate = sampleRate,
+          minFrequency = minFrequency,
+          maxFrequency = maxFrequency,
+          frameSize = frameSize,
+          hopSize = hopSize
+        )
+
+        // Create analyzer
+        val handle = RustBridge.createVoiceAnalyzer(analyzerConfig)
+
+        // Store handle and return ID
+        val id = storeAnalyzer(handle, analyzerConfig)
+
+        mapOf(
+          "id" to id,
+          "config" to mapOf(
+            "sampleRate" to sampleRate,
+            "minFrequency" to minFrequency,
+            "maxFrequency" to maxFrequency,
+            "frameSize" to frameSize,
+            "hopSize" to hopSize
+          )
+        )
+      } catch (e: RuntimeException) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      } catch (e: Exception) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Process audio through VoiceAnalyzer and return aggregated results.
+     *
+     * @param analyzerId Analyzer ID from createVoiceAnalyzer
+     * @param buffer Audio samples as FloatArray
+     * @return VoiceAnalyzerResult map with frames and statistics
+     * @throws Exception with error code "ANALYZER_ERROR"
+     */
+    AsyncFunction("analyzeClip") { analyzerId: String, buffer: FloatArray ->
+      try {
+        // Get analyzer handle
+        val (handle, config) = getAnalyzer(analyzerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
+
+        if (buffer.isEmpty()) {
+          throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
+        }
+
+        // Process audio through analyzer
+        val frames = RustBridge.processAudioWithAnalyzer(handle, buffer)
+
+        // Calculate aggregate statistics
+        val voicedFrames = frames.filter { it.isVoiced }
+
+        // Convert frames to maps
+        val frameMaps = frames.map { frame ->
+          mapOf(
+            "frequency" to if (frame.isVoiced && frame.frequency > 0) frame.frequency else null,
+            "confidence" to frame.confidence,
+            "isVoiced" to frame.isVoiced,
+            "voicedProbability" to frame.voicedProbability
+          )
+        }
+
+        // Unweighted statistics over the voiced frames, computed in Rust; the
+        // population std is only reported from two voiced frames on
+        val frequencies = FloatArray(frames.size) { i ->
+          if (frames[i].isVoiced) frames[i].frequency.coerceAtLeast(0f) else 0f
+        }
+        val stats = if (frames.isEmpty()) null else RustBridge.pitchTrackStatistics(
+          frequencies,
+          FloatArray(frames.size) { i -> frames[i].voicedProbability },
+          weightByVoicing = false
+        )
+        val medianPitch = stats?.let { measureOrNull(it.medianHz) }
+        val meanPitch = stats?.let { measureOrNull(it.meanHz) }
+        val pitchStdDev = stats?.takeIf { it.voicedFrameCount >= 2 }?.let { measureOrNull(it.stdHz) }
+
+        val meanConfidence: Float? = if (voicedFrames.isEmpty()) null else {
+          voicedFrames.map { it.confidence }.sum() / voicedFrames.size
+        }
+
+        val meanVoicedProbability: Float = if (frames.isEmpty()) 0f else {
+          frames.map { it.voicedProbability }.sum() / frames.size
+        }
+
+        mapOf(
+          "frames" to frameMaps,
+          "frameCount" to frames.size,
+          "voicedFrameCount" to voicedFrames.size,
+          "medianPitch" to medianPitch,
+          "meanPitch" to meanPitch,
+          "pitchStdDev" to pitchStdDev,
+          "meanConfidence" to meanConfidence,
+          "meanVoicedProbability" to meanVoicedProbability
+        )
+      } catch (e: RuntimeException) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      } catch (e: Exception) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Process audio with HMM-smoothed Viterbi decoding for globally optimal pitch track (v0.5.0).
+     *
+     * Unlike analyzeClip which treats frames independently, this uses Viterbi decoding
+     * to reduce octave errors (from ~8-12% to ≤3%) and produce smoother contours.
+     *
+     * @param analyzerId Analyzer ID from createVoiceAnalyzer
+     * @param buffer Audio samples as FloatArray
+     * @return PitchTrack map with pitchTrack, voicedProbabilities, timestamps, and statistics
+     * @throws Exception with error code "ANALYZER_ERROR"
+     */
+    AsyncFunction("processBuffer") { analyzerId: String, buffer: FloatArray ->
+      try {
+        // Get analyzer handle
+        val (handle, config) = getAnalyzer(analyzerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
+
+        if (buffer.isEmpty()) {
+          throw Exception("VALIDATION_ERROR: Buffer cannot be empty")
+        }
+
+        // Process buffer with Viterbi decoding
+        val track = RustBridge.processBufferWithAnalyzer(handle, buffer)
+        pitchTrackToMap(track)
+      } catch (e: RuntimeException) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      } catch (e: Exception) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Corrects octave jumps, fills short unvoiced gaps and smooths a pitch track.
+     *
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced), e.g. from processBuffer
+     * @param voicedProbabilities Voiced probability per frame (same length)
+     * @param timestamps Frame starts in seconds (same length)
+     * @param options Map with optional keys: "correctOctaveJumps" (Boolean), "octaveWindow" (Int),
+     *                "smoothing" (Int: 0 none, 1 median, 2 moving average), "smoothingWindow" (Int),
+     *                "maxGapFrames" (Int)
+     * @return PitchTrack map with the same keys as processBuffer
+     * @throws Exception with error code "VALIDATION_ERROR" or "PITCH_TRACK_ERROR"
+     */
+    AsyncFunction("postprocessPitchTrack") { pitchTrack: FloatArray, voicedProbabilities: FloatArray, timestamps: FloatArray, options: Map<String, Any?> ->
+      if (voicedProbabilities.size != pitchTrack.size || timestamps.size != pitchTrack.size) {
+        throw Exception("VALIDATION_ERROR: pitchTrack, voicedProbabilities and timestamps must have equal lengths")
+      }
+
+      try {
+        val track = RustBridge.postprocessPitchTrack(
+          PitchTrackResult(pitchTrack, voicedProbabilities, timestamps),
+          correctOctaveJumps = options["correctOctaveJumps"] as? Boolean ?: true,
+          octaveWindow = (options["octaveWindow"] as? Number)?.toInt() ?: 11,
+          smoothing = (options["smoothing"] as? Number)?.toInt() ?: 1,
+          smoothingWindow = (options["smoothingWindow"] as? Number)?.toInt() ?: 5,
+          maxGapFrames = (options["maxGapFrames"] as? Number)?.toInt() ?: 5
+        )
+        pitchTrackToMap(track)
+      } catch (e: Exception) {
+        throw Exception("PITCH_TRACK_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Summarizes a pitch track in Hz and semitones, weighting frames by voiced probability.
+     *
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced), e.g. from processBuffer
+     * @param voicedProbabilities Voiced probability per frame in [0, 1] (same length)
+     * @param options Map with optional keys: "referenceHz" (Float), "weightByVoicing" (Boolean)
+     * @return Map with keys: "frameCount", "voicedFrameCount" (Int), "voicedRatio" (Float),
+     *         "meanHz", "medianHz", "stdHz", "p5Hz", "p95Hz", "meanSemitones", "medianSemitones",
+     *         "stdSemitones", "p5Semitones", "p95Semitones", "rangeSemitones" (Float, or null
+     *         without weighted voiced frames)
+     * @throws Exception with error code "VALIDATION_ERROR" or "PITCH_TRACK_ERROR"
+     */
+    AsyncFunction("computePitchStatistics") { pitchTrack: FloatArray, voicedProbabilities: FloatArray, options: Map<String, Any?> ->
+      if (voicedProbabilities.size != pitchTrack.size) {
+        throw Exception("VALIDATION_ERROR: pitchTrack and voicedProbabilities must have equal lengths")
+      }
+
+      try {
+        val stats = RustBridge.pitchTrackStatistics(
+          pitchTrack,
+          voicedProbabilities,
+          referenceHz = (options["referenceHz"] as? Number)?.toFloat() ?: 100f,
+          weightByVoicing = options["weightByVoicing"] as? Boolean ?: true
+        )
+        pitchStatisticsToMap(stats)
+      } catch (e: Exception) {
+        throw Exception("PITCH_TRACK_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Finds vibrato in a pitch track and measures its rate, extent, regularity and onset.
+     *
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced), e.g. from processBuffer
+     * @param options Map with keys: "frameRate" (Float, frames per second), and optional
+     *                "minRateHz", "maxRateHz", "minExtentCents", "maxExtentCents",
+     *                "windowSeconds" (Float), "minCycles", "windowHopFrames" (Int)
+     * @return Map with keys: "vibratoRatio" (Float), "rateHz", "extentCents", "rateJitter",
+     *         "extentJitter", "onsetDelay" (Float, or null without vibrato), "segments" (List
+     *         of Maps with "start", "end", "rateHz", "extentCents", "rateJitter",
+     *         "extentJitter", "onsetDelay"), "windowTimes" (List of Float), "windowRateHz",
+     *         "windowExtentCents", "windowRateJitter", "windowExtentJitter" (List of Float or
+     *         null); times in seconds from the first frame
+     * @throws Exception with error code "VALIDATION_ERROR" or "VIBRATO_ERROR"
+     */
+    AsyncFunction("analyzeVibrato") { pitchTrack: FloatArray, options: Map<String, Any?> ->
+      val frameRate = (options["frameRate"] as? Number)?.toFloat()
+        ?: throw Exception("VALIDATION_ERROR: frameRate is required")
+
+      try {
+        val result = RustBridge.analyzeVibrato(
+          pitchTrack,
+          frameRate,
+          minRateHz = (options["minRateHz"] as? Number)?.toFloat() ?: 4f,
+          maxRateHz = (options["maxRateHz"] as? Number)?.toFloat() ?: 8f,
+          minExtentCents = (options["minExtentCents"] as? Number)?.toFloat() ?: 15f,
+          maxExtentCents = (options["maxExtentCents"] as? Number)?.toFloat() ?: 200f,
+          minCycles = (options["minCycles"] as? Number)?.toInt() ?: 2,
+          windowSeconds = (options["windowSeconds"] as? Number)?.toFloat() ?: 1f,
+          windowHopFrames = (options["windowHopFrames"] as? Number)?.toInt() ?: 1
+        )
+        vibratoToMap(result)
+      } catch (e: Exception) {
+        throw Exception("VIBRATO_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Push a chunk of live audio (any size) through the analyzer's stream.
+     *
+     * Samples are buffered natively between pushes, so frames start every hopSize
+     * samples and timestamps run on from the previous push. The stream is created
+     * on the first push; resetVoiceAnalyzer and flushVoiceAnalyzer restart it.
+     *
+     * @param analyzerId Analyzer ID from createVoiceAnalyzer
+     * @param buffer Audio samples as FloatArray (may be shorter than a frame)
+     * @return Map with keys: "frequencies", "confidences", "isVoiced", "voicedProbabilities",
+     *         "timestamps" (lists, one entry per completed frame), "frameCount" (Int)
+     * @throws Exception with error code "ANALYZER_ERROR"
+     */
+    AsyncFunction("pushSamples") { analyzerId: String, buffer: FloatArray ->
+      try {
+        val stream = getStream(analyzerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
+
+        streamFramesToMap(RustBridge.pushToStreamAnalyzer(stream, buffer))
+      } catch (e: Exception) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Analyse the buffered tail of the analyzer's stream and restart it at timestamp 0.
+     *
+     * @param analyzerId Analyzer ID from createVoiceAnalyzer
+     * @return Same map as pushSamples, holding the zero-padded tail frames
+     * @throws Exception with error code "ANALYZER_ERROR"
+     */
+    AsyncFunction("flushVoiceAnalyzer") { analyzerId: String ->
+      try {
+        val stream = getStream(analyzerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
+
+        streamFramesToMap(RustBridge.flushStreamAnalyzer(stream))
+      } catch (e: Exception) {
+        throw Exception("ANALYZER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Reset analyzer state for reuse with new audio.
      *
      * @param analyzerId Analyzer ID from createVoiceAnalyzer
      * @throws Exception with error code "ANALYZER_ERROR"
@@ -555,6 +1330,7 @@ class LoqaExpoDspModule : Module() {
           ?: throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
 
         RustBridge.resetVoiceAnalyzer(handle)
+        resetStream(analyzerId)
         null
       } catch (e: RuntimeException) {
         throw Exception("ANALYZER_ERROR: ${e.message}", e)
@@ -576,16 +1352,325 @@ class LoqaExpoDspModule : Module() {
         throw Exception("VALIDATION_ERROR: Invalid analyzer ID: $analyzerId")
       }
     }
+
+    // ============================================================================
+    // Tuner API
+    // ============================================================================
+
+    /**
+     * Creates a tuner for live audio.
+     *
+     * @param config Map with keys: "sampleRate", "minFrequency", "maxFrequency", "frameSize",
+     *        "hopSize", "referenceHz", "temperamentCents" (12 offsets, C first), "stabilityFrames"
+     * @return Tuner ID (String)
+     * @throws Exception with error code "TUNER_ERROR"
+     */
+    AsyncFunction("createTuner") { config: Map<String, Any?> ->
+      try {
+        val sampleRate = (config["sampleRate"] as? Number)?.toInt()
+          ?: throw Exception("VALIDATION_ERROR: sampleRate is required")
+        val analyzerConfig = VoiceAnalyzerConfig(
+          sampleRate = sampleRate,
+          minFrequency = (config["minFrequency"] as? Number)?.toFloat() ?: 60.0f,
+          maxFrequency = (config["maxFrequency"] as? Number)?.toFloat() ?: 1500.0f,
+          frameSize = (config["frameSize"] as? Number)?.toInt() ?: 2048,
+          hopSize = (config["hopSize"] as? Number)?.toInt() ?: 512
+        )
+        val referenceHz = (config["referenceHz"] as? Number)?.toFloat() ?: 440.0f
+        val temperamentCents = (config["temperamentCents"] as? List<*>)
+          ?.map { (it as Number).toFloat() }
+          ?.toFloatArray()
+          ?: FloatArray(12)
+        val stabilityFrames = (config["stabilityFrames"] as? Number)?.toInt() ?: 8
+
+        val handle = RustBridge.createTuner(
+          analyzerConfig,
+          referenceHz,
+          temperamentCents,
+          stabilityFrames
+        )
+        storeTuner(handle)
+      } catch (e: Exception) {
+        throw Exception("TUNER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Push a chunk of live audio (any size) through a tuner.
+     *
+     * @param tunerId Tuner ID from createTuner
+     * @param buffer Audio samples as FloatArray (may be shorter than a frame)
+     * @return Map with keys: "frameCount", "timestamp", "isVoiced", "frequency", "confidence",
+     *         "midiNote", "pitchClass", "octave", "cents", "targetFrequency", "stability"
+     * @throws Exception with error code "TUNER_ERROR"
+     */
+    AsyncFunction("pushTunerSamples") { tunerId: String, buffer: FloatArray ->
+      try {
+        val handle = getTuner(tunerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid tuner ID: $tunerId")
+
+        tunerReadingToMap(RustBridge.pushToTuner(handle, buffer))
+      } catch (e: Exception) {
+        throw Exception("TUNER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Discard a tuner's buffered audio and reading.
+     *
+     * @param tunerId Tuner ID from createTuner
+     * @throws Exception with error code "TUNER_ERROR"
+     */
+    AsyncFunction("resetTuner") { tunerId: String ->
+      try {
+        val handle = getTuner(tunerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid tuner ID: $tunerId")
+
+        RustBridge.resetTuner(handle)
+        null
+      } catch (e: Exception) {
+        throw Exception("TUNER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Free a tuner.
+     *
+     * @param tunerId Tuner ID from createTuner
+     * @throws Exception with error code "TUNER_ERROR"
+     */
+    AsyncFunction("freeTuner") { tunerId: String ->
+      if (removeTuner(tunerId)) {
+        null
+      } else {
+        throw Exception("VALIDATION_ERROR: Invalid tuner ID: $tunerId")
+      }
+    }
+
+    // ============================================================================
+    // Voice Range Profile API
+    // ============================================================================
+
+    /**
+     * Creates an empty voice range profile (phonetogram).
+     *
+     * @param config Map with keys: "referenceHz", "minMidiNote", "maxMidiNote", "minDb",
+     *        "maxDb", "dbStep", "minCellFrames"
+     * @return Profile ID (String)
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("createVoiceRangeProfile") { config: Map<String, Any?> ->
+      try {
+        val handle = RustBridge.createVoiceRangeProfile(
+          referenceHz = (config["referenceHz"] as? Number)?.toFloat() ?: 440.0f,
+          minMidiNote = (config["minMidiNote"] as? Number)?.toInt() ?: 24,
+          maxMidiNote = (config["maxMidiNote"] as? Number)?.toInt() ?: 108,
+          minDb = (config["minDb"] as? Number)?.toFloat() ?: 40.0f,
+          maxDb = (config["maxDb"] as? Number)?.toFloat() ?: 120.0f,
+          dbStep = (config["dbStep"] as? Number)?.toFloat() ?: 1.0f,
+          minCellFrames = (config["minCellFrames"] as? Number)?.toInt() ?: 3
+        )
+        storeProfile(handle)
+      } catch (e: Exception) {
+        throw Exception("VOICE_RANGE_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Add framewise pitch and intensity (same frame grid) to a voice range profile.
+     *
+     * @param profileId Profile ID from createVoiceRangeProfile
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param intensityDb Level per frame in dB
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("addVoiceRangeFrames") { profileId: String, pitchTrack: FloatArray, intensityDb: FloatArray ->
+      try {
+        val handle = getProfile(profileId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid profile ID: $profileId")
+
+        RustBridge.addVoiceRangeFrames(handle, pitchTrack, intensityDb)
+        null
+      } catch (e: Exception) {
+        throw Exception("VOICE_RANGE_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Add the counts of one voice range profile to another with the same configuration.
+     *
+     * @param targetId Profile ID that receives the counts
+     * @param sourceId Profile ID whose counts are added (left unchanged)
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("mergeVoiceRangeProfiles") { targetId: String, sourceId: String ->
+      try {
+        val target = getProfile(targetId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid profile ID: $targetId")
+        val source = getProfile(sourceId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid profile ID: $sourceId")
+
+        RustBridge.mergeVoiceRangeProfiles(target, source)
+        null
+      } catch (e: Exception) {
+        throw Exception("VOICE_RANGE_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Read the grid and sustainable range of a voice range profile.
+     *
+     * @param profileId Profile ID from createVoiceRangeProfile
+     * @return Map with keys: "counts", "rowCount", "columnCount", "minMidiNote", "minDb",
+     *         "dbStep", "softDb", "loudDb", "dynamicRangeDb", "lowestMidiNote",
+     *         "highestMidiNote", "lowestHz", "highestHz", "coveredCells", "area", "frameCount"
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("getVoiceRangeProfile") { profileId: String ->
+      try {
+        val handle = getProfile(profileId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid profile ID: $profileId")
+
+        voiceRangeProfileToMap(RustBridge.getVoiceRangeProfile(handle))
+      } catch (e: Exception) {
+        throw Exception("VOICE_RANGE_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Empty a voice range profile's grid.
+     *
+     * @param profileId Profile ID from createVoiceRangeProfile
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("resetVoiceRangeProfile") { profileId: String ->
+      try {
+        val handle = getProfile(profileId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid profile ID: $profileId")
+
+        RustBridge.resetVoiceRangeProfile(handle)
+        null
+      } catch (e: Exception) {
+        throw Exception("VOICE_RANGE_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Free a voice range profile.
+     *
+     * @param profileId Profile ID from createVoiceRangeProfile
+     * @throws Exception with error code "VOICE_RANGE_ERROR"
+     */
+    AsyncFunction("freeVoiceRangeProfile") { profileId: String ->
+      if (removeProfile(profileId)) {
+        null
+      } else {
+        throw Exception("VALIDATION_ERROR: Invalid profile ID: $profileId")
+      }
+    }
+
+    // ============================================================================
+    // Formant Tracker API
+    // ============================================================================
+
+    /**
+     * Creates a formant tracker with continuity of F1-F4 across frames.
+     *
+     * @param config Map with keys: "sampleRate", "frameSize", "hopSize", "lpcOrder",
+     *        "ceilingHz", "continuityWeight", "minConfidence", "silenceDb"
+     * @return Formant tracker ID (String)
+     * @throws Exception with error code "FORMANT_TRACKER_ERROR"
+     */
+    AsyncFunction("createFormantTracker") { config: Map<String, Any?> ->
+      try {
+        val sampleRate = (config["sampleRate"] as? Number)?.toInt()
+          ?: throw Exception("VALIDATION_ERROR: sampleRate is required")
+
+        val handle = RustBridge.createFormantTracker(
+          sampleRate = sampleRate,
+          frameSize = (config["frameSize"] as? Number)?.toInt() ?: (sampleRate / 40),
+          hopSize = (config["hopSize"] as? Number)?.toInt() ?: (sampleRate / 100),
+          lpcOrder = (config["lpcOrder"] as? Number)?.toInt() ?: 0,
+          ceilingHz = (config["ceilingHz"] as? Number)?.toFloat() ?: 5500.0f,
+          continuityWeight = (config["continuityWeight"] as? Number)?.toFloat() ?: 1.0f,
+          minConfidence = (config["minConfidence"] as? Number)?.toFloat() ?: 0.3f,
+          silenceDb = (config["silenceDb"] as? Number)?.toFloat() ?: -50.0f
+        )
+        storeFormantTracker(handle)
+      } catch (e: Exception) {
+        throw Exception("FORMANT_TRACKER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Track F1-F4 over the frames of a buffer, continuing from the previous buffer.
+     *
+     * @param trackerId Formant tracker ID from createFormantTracker
+     * @param buffer Audio samples as FloatArray (longer than frameSize)
+     * @return Map with keys: "frequencies", "bandwidths" (frameCount x 4, row-major),
+     *         "confidences", "reliable", "timestamps", "frameCount"
+     * @throws Exception with error code "FORMANT_TRACKER_ERROR"
+     */
+    AsyncFunction("trackFormants") { trackerId: String, buffer: FloatArray ->
+      try {
+        val handle = getFormantTracker(trackerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid formant tracker ID: $trackerId")
+
+        formantTrackToMap(RustBridge.trackFormants(handle, buffer))
+      } catch (e: Exception) {
+        throw Exception("FORMANT_TRACKER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Make a formant tracker forget the previous buffer.
+     *
+     * @param trackerId Formant tracker ID from createFormantTracker
+     * @throws Exception with error code "FORMANT_TRACKER_ERROR"
+     */
+    AsyncFunction("resetFormantTracker") { trackerId: String ->
+      try {
+        val handle = getFormantTracker(trackerId)
+          ?: throw Exception("VALIDATION_ERROR: Invalid formant tracker ID: $trackerId")
+
+        RustBridge.resetFormantTracker(handle)
+        null
+      } catch (e: Exception) {
+        throw Exception("FORMANT_TRACKER_ERROR: ${e.message}", e)
+      }
+    }
+
+    /**
+     * Free a formant tracker.
+     *
+     * @param trackerId Formant tracker ID from createFormantTracker
+     * @throws Exception with error code "FORMANT_TRACKER_ERROR"
+     */
+    AsyncFunction("freeFormantTracker") { trackerId: String ->
+      if (removeFormantTracker(trackerId)) {
+        null
+      } else {
+        throw Exception("VALIDATION_ERROR: Invalid formant tracker ID: $trackerId")
+      }
+    }
   }
 
   // ============================================================================
-  // Analyzer Storage (Thread-safe)
+  // Analyzer, Tuner, Profile and Formant Tracker Storage (Thread-safe)
   // ============================================================================
 
   companion object {
     private val analyzers = mutableMapOf<String, Pair<Long, VoiceAnalyzerConfig>>()
+    // Streaming analyzer handles by analyzer ID, created on the first push
+    private val streams = mutableMapOf<String, Long>()
+    private val tuners = mutableMapOf<String, Long>()
+    private val profiles = mutableMapOf<String, Long>()
+    private val formantTrackers = mutableMapOf<String, Long>()
     private val analyzerLock = Any()
     private var nextAnalyzerId = 1
+    private var nextTunerId = 1
+    private var nextProfileId = 1
+    private var nextFormantTrackerId = 1
 
     fun storeAnalyzer(handle: Long, config: VoiceAnalyzerConfig): String {
       synchronized(analyzerLock) {
@@ -607,10 +1692,229 @@ class LoqaExpoDspModule : Module() {
         val entry = analyzers.remove(id)
         if (entry != null) {
           RustBridge.freeVoiceAnalyzer(entry.first)
+          streams.remove(id)?.let { RustBridge.freeStreamAnalyzer(it) }
           return true
         }
         return false
       }
     }
+
+    fun getStream(id: String): Long? {
+      synchronized(analyzerLock) {
+        val (_, config) = analyzers[id] ?: return null
+        return streams.getOrPut(id) { RustBridge.createStreamAnalyzer(config) }
+      }
+    }
+
+    fun resetStream(id: String) {
+      synchronized(analyzerLock) {
+        streams[id]?.let { RustBridge.resetStreamAnalyzer(it) }
+      }
+    }
+
+    fun storeTuner(handle: Long): String {
+      synchronized(analyzerLock) {
+        val id = "tn_$nextTunerId"
+        nextTunerId++
+        tuners[id] = handle
+        return id
+      }
+    }
+
+    fun getTuner(id: String): Long? {
+      synchronized(analyzerLock) {
+        return tuners[id]
+      }
+    }
+
+    fun removeTuner(id: String): Boolean {
+      synchronized(analyzerLock) {
+        val handle = tuners.remove(id) ?: return false
+        RustBridge.freeTuner(handle)
+        return true
+      }
+    }
+
+    fun storeProfile(handle: Long): String {
+      synchronized(analyzerLock) {
+        val id = "vr_$nextProfileId"
+        nextProfileId++
+        profiles[id] = handle
+        return id
+      }
+    }
+
+    fun getProfile(id: String): Long? {
+      synchronized(analyzerLock) {
+        return profiles[id]
+      }
+    }
+
+    fun removeProfile(id: String): Boolean {
+      synchronized(analyzerLock) {
+        val handle = profiles.remove(id) ?: return false
+        RustBridge.freeVoiceRangeProfile(handle)
+        return true
+      }
+    }
+
+    fun storeFormantTracker(handle: Long): String {
+      synchronized(analyzerLock) {
+        val id = "ft_$nextFormantTrackerId"
+        nextFormantTrackerId++
+        formantTrackers[id] = handle
+        return id
+      }
+    }
+
+    fun getFormantTracker(id: String): Long? {
+      synchronized(analyzerLock) {
+        return formantTrackers[id]
+      }
+    }
+
+    fun removeFormantTracker(id: String): Boolean {
+      synchronized(analyzerLock) {
+        val handle = formantTrackers.remove(id) ?: return false
+        RustBridge.freeFormantTracker(handle)
+        return true
+      }
+    }
   }
 }
+
+/**
+ * Converts a pitch track to the map returned to JS, with statistics over its voiced frames.
+ */
+private fun pitchTrackToMap(track: PitchTrackResult): Map<String, Any?> {
+  // Unweighted, computed in Rust so iOS gives the same values
+  val stats = if (track.pitchTrack.isEmpty()) null else {
+    RustBridge.pitchTrackStatistics(track.pitchTrack, track.voicedProbabilities, weightByVoicing = false)
+  }
+
+  return mapOf(
+    "pitchTrack" to track.pitchTrack.toList(),
+    "voicedProbabilities" to track.voicedProbabilities.toList(),
+    "timestamps" to track.timestamps.toList(),
+    "frameCount" to track.pitchTrack.size,
+    "voicedFrameCount" to track.pitchTrack.count { it > 0 },
+    "medianPitch" to stats?.let { measureOrNull(it.medianHz) },
+    "meanPitch" to stats?.let { measureOrNull(it.meanHz) }
+  )
+}
+
+/**
+ * Converts pitch statistics to the map returned to JS; NaN measures become null.
+ */
+private fun pitchStatisticsToMap(stats: PitchStatisticsResult): Map<String, Any?> = mapOf(
+  "frameCount" to stats.frameCount,
+  "voicedFrameCount" to stats.voicedFrameCount,
+  "voicedRatio" to stats.voicedRatio,
+  "meanHz" to measureOrNull(stats.meanHz),
+  "medianHz" to measureOrNull(stats.medianHz),
+  "stdHz" to measureOrNull(stats.stdHz),
+  "p5Hz" to measureOrNull(stats.p5Hz),
+  "p95Hz" to measureOrNull(stats.p95Hz),
+  "meanSemitones" to measureOrNull(stats.meanSemitones),
+  "medianSemitones" to measureOrNull(stats.medianSemitones),
+  "stdSemitones" to measureOrNull(stats.stdSemitones),
+  "p5Semitones" to measureOrNull(stats.p5Semitones),
+  "p95Semitones" to measureOrNull(stats.p95Semitones),
+  "rangeSemitones" to measureOrNull(stats.rangeSemitones)
+)
+
+/**
+ * Converts a vibrato analysis to the map returned to JS; NaN measures become null.
+ */
+private fun vibratoToMap(result: VibratoResult): Map<String, Any?> = mapOf(
+  "vibratoRatio" to result.vibratoRatio,
+  "rateHz" to measureOrNull(result.rateHz),
+  "extentCents" to measureOrNull(result.extentCents),
+  "rateJitter" to measureOrNull(result.rateJitter),
+  "extentJitter" to measureOrNull(result.extentJitter),
+  "onsetDelay" to measureOrNull(result.onsetDelay),
+  "segments" to result.segments.toList().chunked(7).map { segment ->
+    mapOf(
+      "start" to segment[0],
+      "end" to segment[1],
+      "rateHz" to segment[2],
+      "extentCents" to segment[3],
+      "rateJitter" to segment[4],
+      "extentJitter" to segment[5],
+      "onsetDelay" to segment[6]
+    )
+  },
+  "windowTimes" to result.windowTimes.toList(),
+  "windowRateHz" to result.windowRate.map { measureOrNull(it) },
+  "windowExtentCents" to result.windowExtent.map { measureOrNull(it) },
+  "windowRateJitter" to result.windowRateJitter.map { measureOrNull(it) },
+  "windowExtentJitter" to result.windowExtentJitter.map { measureOrNull(it) }
+)
+
+/**
+ * Converts a tuner reading to the map returned to JS.
+ */
+private fun tunerReadingToMap(reading: TunerReading): Map<String, Any> = mapOf(
+  "frameCount" to reading.frameCount,
+  "timestamp" to reading.timestamp,
+  "isVoiced" to reading.isVoiced,
+  "frequency" to reading.frequency,
+  "confidence" to reading.confidence,
+  "midiNote" to reading.midiNote,
+  "pitchClass" to reading.pitchClass,
+  "octave" to reading.octave,
+  "cents" to reading.cents,
+  "targetFrequency" to reading.targetFrequency,
+  "stability" to reading.stability
+)
+
+/**
+ * Converts a voice range profile to the map returned to JS; unsustained notes become null.
+ */
+private fun voiceRangeProfileToMap(profile: VoiceRangeProfileResult): Map<String, Any?> = mapOf(
+  "counts" to profile.counts.toList(),
+  "rowCount" to profile.rowCount,
+  "columnCount" to profile.columnCount,
+  "minMidiNote" to profile.minMidiNote,
+  "minDb" to profile.minDb,
+  "dbStep" to profile.dbStep,
+  "softDb" to profile.softDb.map { measureOrNull(it) },
+  "loudDb" to profile.loudDb.map { measureOrNull(it) },
+  "dynamicRangeDb" to profile.dynamicRangeDb.map { measureOrNull(it) },
+  "lowestMidiNote" to profile.lowestMidiNote.takeIf { it >= 0 },
+  "highestMidiNote" to profile.highestMidiNote.takeIf { it >= 0 },
+  "lowestHz" to measureOrNull(profile.lowestHz),
+  "highestHz" to measureOrNull(profile.highestHz),
+  "coveredCells" to profile.coveredCells,
+  "area" to profile.area,
+  "frameCount" to profile.frameCount
+)
+
+/**
+ * Converts a formant track to the map returned to JS; missing formants stay 0.
+ */
+private fun formantTrackToMap(track: FormantTrackResult): Map<String, Any> = mapOf(
+  "frequencies" to track.frequencies.toList(),
+  "bandwidths" to track.bandwidths.toList(),
+  "confidences" to track.confidences.toList(),
+  "reliable" to track.reliable.toList(),
+  "timestamps" to track.timestamps.toList(),
+  "frameCount" to track.timestamps.size
+)
+
+/**
+ * Converts the frames of a stream push or flush to the map returned to JS.
+ */
+private fun streamFramesToMap(frames: StreamFramesResult): Map<String, Any> = mapOf(
+  "frequencies" to frames.frequencies.toList(),
+  "confidences" to frames.confidences.toList(),
+  "isVoiced" to frames.isVoiced.toList(),
+  "voicedProbabilities" to frames.voicedProbabilities.toList(),
+  "timestamps" to frames.timestamps.toList(),
+  "frameCount" to frames.timestamps.size
+)
+
+/**
+ * Maps NaN (how Rust reports an undefined measure) to null for the JS bridge.
+ */
+private fun measureOrNull(value: Float): Float? = if (value.isNaN()) null else value
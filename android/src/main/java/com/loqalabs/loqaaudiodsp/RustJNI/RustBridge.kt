@@ -1,5 +1,8 @@
 package com.loqalabs.loqaexpodsp.RustJNI
 
+import android.util.Log
+import java.nio.FloatBuffer
+
 /**
  * PitchResult data class matching Rust #[repr(C)] PitchResultFFI struct.
  * Returned by value from nativeDetectPitch JNI function.
@@ -16,19 +19,47 @@ data class PitchResult(
 )
 
 /**
- * FormantsResult data class matching Rust #[repr(C)] FormantResultFFI struct.
- * Returned by value from nativeExtractFormants JNI function.
+ * FormantsResult data class returned by nativeExtractFormants.
+ *
+ * Matches the fields of Rust's FormantsResultFFI (loqa_extract_formants_ex). A formant
+ * that was not found is 0.0 with zero confidence.
  *
- * v0.4.0 BREAKING CHANGE:
- * - Removed bw1, bw2, bw3 bandwidth fields
- * - Added confidence score (0-1) indicating reliability of detection
+ * @property confidence Mean F1-F3 confidence [0.0, 1.0]
+ * @property bandwidths Bandwidths of F1-F5 in Hz
+ * @property confidences Confidence of each of F1-F5 [0.0, 1.0]
  */
 data class FormantsResult(
     val f1: Float,
     val f2: Float,
     val f3: Float,
-    val confidence: Float
-)
+    val confidence: Float,
+    val f4: Float,
+    val f5: Float,
+    val bandwidths: FloatArray,
+    val confidences: FloatArray
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as FormantsResult
+        return f1 == other.f1 && f2 == other.f2 && f3 == other.f3 &&
+            confidence == other.confidence && f4 == other.f4 && f5 == other.f5 &&
+            bandwidths.contentEquals(other.bandwidths) &&
+            confidences.contentEquals(other.confidences)
+    }
+
+    override fun hashCode(): Int {
+        var result = f1.hashCode()
+        result = 31 * result + f2.hashCode()
+        result = 31 * result + f3.hashCode()
+        result = 31 * result + confidence.hashCode()
+        result = 31 * result + f4.hashCode()
+        result = 31 * result + f5.hashCode()
+        result = 31 * result + bandwidths.contentHashCode()
+        result = 31 * result + confidences.contentHashCode()
+        return result
+    }
+}
 
 /**
  * HNRResult data class matching Rust #[repr(C)] HNRResult struct.
@@ -59,6 +90,262 @@ data class H1H2Result(
     val f0: Float
 )
 
+/**
+ * JitterResult data class returned by nativeCalculateJitter.
+ *
+ * Praat jitter measures over the voiced periods of a buffer. Relative measures
+ * are fractions (0.01 = 1 %); a measure is NaN when no run of consecutive valid
+ * periods is long enough for it (2 for local, 3 for RAP/DDP, 5 for PPQ5).
+ */
+data class JitterResult(
+    val local: Float,
+    val localAbsolute: Float,
+    val rap: Float,
+    val ppq5: Float,
+    val ddp: Float,
+    val meanPeriod: Float,
+    val periodCount: Int
+)
+
+/**
+ * ShimmerResult data class returned by nativeCalculateShimmer.
+ *
+ * Praat shimmer measures from the peak amplitudes of the same periods as
+ * JitterResult; localDb is in dB, the other relative measures are fractions.
+ */
+data class ShimmerResult(
+    val local: Float,
+    val localDb: Float,
+    val apq3: Float,
+    val apq5: Float,
+    val apq11: Float,
+    val dda: Float,
+    val meanAmplitude: Float,
+    val periodCount: Int
+)
+
+/**
+ * CppResult data class returned by nativeCalculateCpp.
+ *
+ * Cepstral peak prominence in dB, averaged over every frame:
+ * - cpp: from the unsmoothed cepstra
+ * - cpps: from cepstra smoothed across time and quefrency (more robust on connected speech)
+ * Lower values indicate a breathier, less periodic voice.
+ */
+data class CppResult(
+    val cpp: Float,
+    val cpps: Float,
+    val f0: Float,
+    val frameCount: Int
+)
+
+/**
+ * IntensityResult data class returned by nativeComputeIntensity.
+ *
+ * Levels are dBFS plus the requested calibration offset; summary statistics
+ * cover the intensity contour, with an energy-averaged mean.
+ *
+ * @property rms Linear RMS per frame (full scale = 1.0), uncalibrated
+ * @property intensityDb RMS level per frame in dB
+ * @property peakDb Absolute peak level per frame in dB
+ * @property timestamps Frame starts in seconds (same grid as PitchTrack.timestamps)
+ */
+data class IntensityResult(
+    val rms: FloatArray,
+    val intensityDb: FloatArray,
+    val peakDb: FloatArray,
+    val timestamps: FloatArray,
+    val meanDb: Float,
+    val minDb: Float,
+    val maxDb: Float,
+    val dynamicRangeDb: Float
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as IntensityResult
+        return rms.contentEquals(other.rms) &&
+            intensityDb.contentEquals(other.intensityDb) &&
+            peakDb.contentEquals(other.peakDb) &&
+            timestamps.contentEquals(other.timestamps) &&
+            meanDb == other.meanDb &&
+            minDb == other.minDb &&
+            maxDb == other.maxDb &&
+            dynamicRangeDb == other.dynamicRangeDb
+    }
+
+    override fun hashCode(): Int {
+        var result = rms.contentHashCode()
+        result = 31 * result + intensityDb.contentHashCode()
+        result = 31 * result + peakDb.contentHashCode()
+        result = 31 * result + timestamps.contentHashCode()
+        result = 31 * result + meanDb.hashCode()
+        result = 31 * result + minDb.hashCode()
+        result = 31 * result + maxDb.hashCode()
+        result = 31 * result + dynamicRangeDb.hashCode()
+        return result
+    }
+}
+
+/**
+ * VoiceActivityResult data class returned by nativeDetectVoiceActivity.
+ *
+ * @property timestamps Frame starts in seconds (same grid as PitchTrack.timestamps)
+ * @property energyDb RMS level per frame in dBFS
+ * @property zcr Zero crossings per second per frame
+ * @property voicedProbabilities pYIN voicing probability per frame
+ * @property isSpeech Speech decision per frame, after hangover
+ * @property segments Flat [start, end) sample pairs of the merged speech segments
+ */
+data class VoiceActivityResult(
+    val timestamps: FloatArray,
+    val energyDb: FloatArray,
+    val zcr: FloatArray,
+    val voicedProbabilities: FloatArray,
+    val isSpeech: BooleanArray,
+    val segments: IntArray
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as VoiceActivityResult
+        return timestamps.contentEquals(other.timestamps) &&
+            energyDb.contentEquals(other.energyDb) &&
+            zcr.contentEquals(other.zcr) &&
+            voicedProbabilities.contentEquals(other.voicedProbabilities) &&
+            isSpeech.contentEquals(other.isSpeech) &&
+            segments.contentEquals(other.segments)
+    }
+
+    override fun hashCode(): Int {
+        var result = timestamps.contentHashCode()
+        result = 31 * result + energyDb.contentHashCode()
+        result = 31 * result + zcr.contentHashCode()
+        result = 31 * result + voicedProbabilities.contentHashCode()
+        result = 31 * result + isSpeech.contentHashCode()
+        result = 31 * result + segments.contentHashCode()
+        return result
+    }
+}
+
+/**
+ * VocalTractLengthResult data class returned by nativeEstimateVocalTractLength.
+ *
+ * Vocal tract length in cm from F1-F4 of the voiced frames, as medians over frames:
+ * - vtlCm: uniform-tube regression (primary estimate)
+ * - spacingVtlCm: average formant spacing
+ * f1-f4 are the median formants in Hz (0 if a formant was never confident).
+ */
+data class VocalTractLengthResult(
+    val vtlCm: Float,
+    val spacingVtlCm: Float,
+    val formantSpacingHz: Float,
+    val f1: Float,
+    val f2: Float,
+    val f3: Float,
+    val f4: Float,
+    val confidence: Float,
+    val frameCount: Int
+)
+
+/**
+ * FFTResult data class for the windowed FFT returned by nativeComputeFFT.
+ *
+ * All arrays have fftSize / 2 + 1 elements.
+ *
+ * @property magnitude Magnitudes scaled per the requested normalization
+ *           (0 = amplitude, 1 = power spectral density)
+ * @property phase Phase per bin in radians (empty unless includePhase was set)
+ * @property frequencies Bin-center frequencies in Hz
+ */
+data class FFTResult(
+    val magnitude: FloatArray,
+    val phase: FloatArray,
+    val frequencies: FloatArray
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as FFTResult
+        return magnitude.contentEquals(other.magnitude) &&
+            phase.contentEquals(other.phase) &&
+            frequencies.contentEquals(other.frequencies)
+    }
+
+    override fun hashCode(): Int {
+        var result = magnitude.contentHashCode()
+        result = 31 * result + phase.contentHashCode()
+        result = 31 * result + frequencies.contentHashCode()
+        return result
+    }
+}
+
+/**
+ * SpectrogramResult data class returned by nativeComputeSpectrogram.
+ *
+ * @property values Row-major frameCount x binCount matrix; frame i starts at index i * binCount
+ * @property timestamps Frame starts in seconds (same grid as PitchTrack.timestamps)
+ * @property frameCount Number of frames
+ * @property binCount Bins per frame (frameSize / 2 + 1)
+ */
+data class SpectrogramResult(
+    val values: FloatArray,
+    val timestamps: FloatArray,
+    val frameCount: Int,
+    val binCount: Int
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as SpectrogramResult
+        return values.contentEquals(other.values) &&
+            timestamps.contentEquals(other.timestamps) &&
+            frameCount == other.frameCount &&
+            binCount == other.binCount
+    }
+
+    override fun hashCode(): Int {
+        var result = values.contentHashCode()
+        result = 31 * result + timestamps.contentHashCode()
+        result = 31 * result + frameCount
+        result = 31 * result + binCount
+        return result
+    }
+}
+
+/**
+ * FeatureMatrix data class returned by nativeComputeLogMelSpectrogram and nativeComputeMfcc.
+ *
+ * @property values Row-major frameCount x featureCount matrix; frame i starts at index i * featureCount
+ * @property timestamps Frame starts in seconds (same grid as PitchTrack.timestamps)
+ * @property frameCount Number of frames
+ * @property featureCount Values per frame
+ */
+data class FeatureMatrix(
+    val values: FloatArray,
+    val timestamps: FloatArray,
+    val frameCount: Int,
+    val featureCount: Int
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as FeatureMatrix
+        return values.contentEquals(other.values) &&
+            timestamps.contentEquals(other.timestamps) &&
+            frameCount == other.frameCount &&
+            featureCount == other.featureCount
+    }
+
+    override fun hashCode(): Int {
+        var result = values.contentHashCode()
+        result = 31 * result + timestamps.contentHashCode()
+        result = 31 * result + frameCount
+        result = 31 * result + featureCount
+        return result
+    }
+}
+
 /**
  * PitchTrack data class for HMM-smoothed Viterbi decoding results (v0.5.0).
  *
@@ -91,6 +378,273 @@ data class PitchTrackResult(
     }
 }
 
+/**
+ * PitchStatisticsResult data class returned by nativePitchTrackStatistics.
+ *
+ * Summaries of the voiced frames (pitch > 0), each weighted by its voiced
+ * probability unless weighting was turned off. Semitones are relative to the
+ * requested reference. Hz and semitone values are NaN when no voiced frame had
+ * a positive weight.
+ */
+data class PitchStatisticsResult(
+    val frameCount: Int,
+    val voicedFrameCount: Int,
+    val voicedRatio: Float,
+    val meanHz: Float,
+    val medianHz: Float,
+    val stdHz: Float,
+    val p5Hz: Float,
+    val p95Hz: Float,
+    val meanSemitones: Float,
+    val medianSemitones: Float,
+    val stdSemitones: Float,
+    val p5Semitones: Float,
+    val p95Semitones: Float,
+    val rangeSemitones: Float
+)
+
+/**
+ * VibratoResult data class returned by nativeAnalyzeVibrato.
+ *
+ * Times are seconds from the first frame of the analysed track. Summary
+ * measures and window values are NaN where no vibrato was found.
+ *
+ * @property segments Flat groups of 7 values per vibrato segment: start, end,
+ *   rate (Hz), extent (± cents), rate jitter, extent jitter, onset delay (s)
+ * @property windowTimes Time of each sliding window's last frame
+ * @property windowRate Vibrato rate per window in Hz
+ * @property windowExtent Vibrato extent per window in ± cents
+ * @property windowRateJitter Rate jitter per window (0.01 = 1 %)
+ * @property windowExtentJitter Extent jitter per window (0.01 = 1 %)
+ * @property vibratoRatio Seconds of vibrato over seconds of voiced frames
+ */
+data class VibratoResult(
+    val segments: FloatArray,
+    val windowTimes: FloatArray,
+    val windowRate: FloatArray,
+    val windowExtent: FloatArray,
+    val windowRateJitter: FloatArray,
+    val windowExtentJitter: FloatArray,
+    val vibratoRatio: Float,
+    val rateHz: Float,
+    val extentCents: Float,
+    val rateJitter: Float,
+    val extentJitter: Float,
+    val onsetDelay: Float
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as VibratoResult
+        return segments.contentEquals(other.segments) &&
+            windowTimes.contentEquals(other.windowTimes) &&
+            windowRate.contentEquals(other.windowRate) &&
+            windowExtent.contentEquals(other.windowExtent) &&
+            windowRateJitter.contentEquals(other.windowRateJitter) &&
+            windowExtentJitter.contentEquals(other.windowExtentJitter) &&
+            vibratoRatio == other.vibratoRatio &&
+            rateHz == other.rateHz &&
+            extentCents == other.extentCents &&
+            rateJitter == other.rateJitter &&
+            extentJitter == other.extentJitter &&
+            onsetDelay == other.onsetDelay
+    }
+
+    override fun hashCode(): Int {
+        var result = segments.contentHashCode()
+        result = 31 * result + windowTimes.contentHashCode()
+        result = 31 * result + windowRate.contentHashCode()
+        result = 31 * result + windowExtent.contentHashCode()
+        result = 31 * result + windowRateJitter.contentHashCode()
+        result = 31 * result + windowExtentJitter.contentHashCode()
+        result = 31 * result + vibratoRatio.hashCode()
+        result = 31 * result + rateHz.hashCode()
+        result = 31 * result + extentCents.hashCode()
+        result = 31 * result + rateJitter.hashCode()
+        result = 31 * result + extentJitter.hashCode()
+        result = 31 * result + onsetDelay.hashCode()
+        return result
+    }
+}
+
+/**
+ * StreamFramesResult data class returned by nativeStreamAnalyzerPush and nativeStreamAnalyzerFlush.
+ *
+ * Holds the frames completed by one push (possibly none) in stream order.
+ *
+ * @property frequencies Pitch per frame in Hz (0.0 = unvoiced)
+ * @property confidences Confidence per frame [0.0, 1.0]
+ * @property isVoiced Voicing decision per frame
+ * @property voicedProbabilities Voiced probability per frame [0.0, 1.0]
+ * @property timestamps Frame starts in seconds since the stream began
+ */
+data class StreamFramesResult(
+    val frequencies: FloatArray,
+    val confidences: FloatArray,
+    val isVoiced: BooleanArray,
+    val voicedProbabilities: FloatArray,
+    val timestamps: FloatArray
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as StreamFramesResult
+        return frequencies.contentEquals(other.frequencies) &&
+            confidences.contentEquals(other.confidences) &&
+            isVoiced.contentEquals(other.isVoiced) &&
+            voicedProbabilities.contentEquals(other.voicedProbabilities) &&
+            timestamps.contentEquals(other.timestamps)
+    }
+
+    override fun hashCode(): Int {
+        var result = frequencies.contentHashCode()
+        result = 31 * result + confidences.contentHashCode()
+        result = 31 * result + isVoiced.contentHashCode()
+        result = 31 * result + voicedProbabilities.contentHashCode()
+        result = 31 * result + timestamps.contentHashCode()
+        return result
+    }
+}
+
+/**
+ * TunerReading data class returned by nativeTunerPush.
+ *
+ * The latest reading of a tuner; frameCount is 0 when the push completed no frame
+ * and the reading is the previous one. The note fields (midiNote, pitchClass 0 = C
+ * to 11 = B, octave, cents, targetFrequency) are only meaningful when isVoiced.
+ */
+data class TunerReading(
+    val frameCount: Int,
+    val timestamp: Float,
+    val isVoiced: Boolean,
+    val frequency: Float,
+    val confidence: Float,
+    val midiNote: Int,
+    val pitchClass: Int,
+    val octave: Int,
+    val cents: Float,
+    val targetFrequency: Float,
+    val stability: Float
+)
+
+/**
+ * VoiceRangeProfileResult data class returned by nativeVrpGet.
+ *
+ * A voice range profile (phonetogram): frame counts in a grid of one row per
+ * semitone and one column per dB step. A cell is sustained once it holds the
+ * configured minimum number of frames; per-note levels are NaN and
+ * lowestMidiNote/highestMidiNote -1 where no cell is sustained.
+ *
+ * @property counts Row-major rowCount x columnCount frame counts; row r is MIDI
+ *           note minMidiNote + r, column c starts at minDb + c * dbStep
+ * @property softDb Per note: lower edge of the lowest sustained cell in dB
+ * @property loudDb Per note: upper edge of the highest sustained cell in dB
+ * @property dynamicRangeDb Per note: loudDb - softDb
+ * @property area Sustained cells times dbStep in semitone-dB
+ * @property frameCount Frames binned into the grid so far
+ */
+data class VoiceRangeProfileResult(
+    val counts: IntArray,
+    val softDb: FloatArray,
+    val loudDb: FloatArray,
+    val dynamicRangeDb: FloatArray,
+    val rowCount: Int,
+    val columnCount: Int,
+    val minMidiNote: Int,
+    val minDb: Float,
+    val dbStep: Float,
+    val lowestMidiNote: Int,
+    val highestMidiNote: Int,
+    val lowestHz: Float,
+    val highestHz: Float,
+    val coveredCells: Int,
+    val area: Float,
+    val frameCount: Int
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as VoiceRangeProfileResult
+        return counts.contentEquals(other.counts) &&
+            softDb.contentEquals(other.softDb) &&
+            loudDb.contentEquals(other.loudDb) &&
+            dynamicRangeDb.contentEquals(other.dynamicRangeDb) &&
+            rowCount == other.rowCount &&
+            columnCount == other.columnCount &&
+            minMidiNote == other.minMidiNote &&
+            minDb == other.minDb &&
+            dbStep == other.dbStep &&
+            lowestMidiNote == other.lowestMidiNote &&
+            highestMidiNote == other.highestMidiNote &&
+            lowestHz.equals(other.lowestHz) &&
+            highestHz.equals(other.highestHz) &&
+            coveredCells == other.coveredCells &&
+            area == other.area &&
+            frameCount == other.frameCount
+    }
+
+    override fun hashCode(): Int {
+        var result = counts.contentHashCode()
+        result = 31 * result + softDb.contentHashCode()
+        result = 31 * result + loudDb.contentHashCode()
+        result = 31 * result + dynamicRangeDb.contentHashCode()
+        result = 31 * result + rowCount
+        result = 31 * result + columnCount
+        result = 31 * result + minMidiNote
+        result = 31 * result + minDb.hashCode()
+        result = 31 * result + dbStep.hashCode()
+        result = 31 * result + lowestMidiNote
+        result = 31 * result + highestMidiNote
+        result = 31 * result + lowestHz.hashCode()
+        result = 31 * result + highestHz.hashCode()
+        result = 31 * result + coveredCells
+        result = 31 * result + area.hashCode()
+        result = 31 * result + frameCount
+        return result
+    }
+}
+
+/**
+ * FormantTrackResult data class returned by nativeFormantTrackerProcessBuffer.
+ *
+ * F1-F4 per frame on the same frame grid as a pitch track. Formants are chosen for
+ * continuity across frames; a missing formant is 0.0, and frames flagged unreliable
+ * (silent, missing one of F1-F3 or low confidence) should not be read.
+ *
+ * @property frequencies Row-major frameCount x 4 matrix of F1-F4 in Hz
+ * @property bandwidths Bandwidths of the same formants in Hz
+ * @property confidences Mean F1-F3 confidence per frame [0.0, 1.0]
+ * @property reliable Whether each frame's formants can be used
+ * @property timestamps Frame starts in seconds from buffer start
+ */
+data class FormantTrackResult(
+    val frequencies: FloatArray,
+    val bandwidths: FloatArray,
+    val confidences: FloatArray,
+    val reliable: BooleanArray,
+    val timestamps: FloatArray
+) {
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (javaClass != other?.javaClass) return false
+        other as FormantTrackResult
+        return frequencies.contentEquals(other.frequencies) &&
+            bandwidths.contentEquals(other.bandwidths) &&
+            confidences.contentEquals(other.confidences) &&
+            reliable.contentEquals(other.reliable) &&
+            timestamps.contentEquals(other.timestamps)
+    }
+
+    override fun hashCode(): Int {
+        var result = frequencies.contentHashCode()
+        result = 31 * result + bandwidths.contentHashCode()
+        result = 31 * result + confidences.contentHashCode()
+        result = 31 * result + reliable.contentHashCode()
+        result = 31 * result + timestamps.contentHashCode()
+        return result
+    }
+}
+
 /**
  * VoiceAnalyzerConfig data class for VoiceAnalyzer initialization.
  *
@@ -108,6 +662,16 @@ data class VoiceAnalyzerConfig(
     val hopSize: Int = 512
 )
 
+/**
+ * Receives diagnostics forwarded from the Rust library.
+ *
+ * May be called from any thread that calls into Rust. [level] is one of
+ * RustBridge.LOG_LEVEL_ERROR..LOG_LEVEL_TRACE.
+ */
+fun interface RustLogger {
+    fun log(level: Int, message: String)
+}
+
 /**
  * RustBridge provides JNI bindings to the Rust loqa-voice-dsp library.
  *
@@ -117,10 +681,13 @@ data class VoiceAnalyzerConfig(
  * - Providing Kotlin wrapper functions with error handling
  *
  * Memory Management Notes:
- * - JNI automatically handles FloatArray marshalling for primitive arrays
- * - Structs (PitchResult, FormantsResult) are returned by value (no heap allocation)
- * - No manual memory management needed (simpler than iOS FFI)
- * - All native calls are wrapped in try-catch for error handling
+ * - The Rust side (rust/src/android.rs, built with the `android` cargo feature)
+ *   copies FloatArray inputs and borrows direct FloatBuffer inputs in place
+ * - Results are constructed as the data classes above, so no manual freeing is needed
+ * - VoiceAnalyzer, streaming analyzer, tuner and voice range profile handles are
+ *   the only native allocations and must be freed
+ * - Invalid input raises IllegalArgumentException, analysis failures RuntimeException;
+ *   all native calls are wrapped in try-catch for error handling
  *
  * Implementation Status:
  * - Story 1.4: Placeholder JNI function declarations
@@ -141,6 +708,58 @@ object RustBridge {
         }
     }
 
+    // Log levels (match LoqaLogLevel in rust/src/logging.rs)
+    const val LOG_LEVEL_OFF = 0
+    const val LOG_LEVEL_ERROR = 1
+    const val LOG_LEVEL_WARN = 2
+    const val LOG_LEVEL_INFO = 3
+    const val LOG_LEVEL_DEBUG = 4
+    const val LOG_LEVEL_TRACE = 5
+
+    // Pitch algorithms (match LoqaPitchAlgorithm in rust/src/lib.rs)
+    const val PITCH_ALGORITHM_AUTO = 0
+    const val PITCH_ALGORITHM_PYIN = 1
+    const val PITCH_ALGORITHM_YIN = 2
+    const val PITCH_ALGORITHM_AUTOCORR = 3
+
+    // FFT windows and normalization (match LoqaWindowType / LoqaFftNormalization in rust/src/lib.rs)
+    const val WINDOW_NONE = 0
+    const val WINDOW_HANNING = 1
+    const val WINDOW_HAMMING = 2
+    const val WINDOW_BLACKMAN = 3
+    const val WINDOW_KAISER = 4
+    const val WINDOW_FLAT_TOP = 5
+    const val WINDOW_GAUSSIAN = 6
+    const val FFT_NORMALIZATION_AMPLITUDE = 0
+    const val FFT_NORMALIZATION_PSD = 1
+
+    // Spectrogram scaling (matches LoqaSpectrogramScaling in rust/src/lib.rs)
+    const val SPECTROGRAM_SCALING_LINEAR = 0
+    const val SPECTROGRAM_SCALING_POWER = 1
+    const val SPECTROGRAM_SCALING_DB = 2
+
+    // Mel scale (matches LoqaMelScale in rust/src/lib.rs)
+    const val MEL_SCALE_HTK = 0
+    const val MEL_SCALE_SLANEY = 1
+
+    /** Sample rate assumed by the computeFFT overload without one */
+    const val DEFAULT_FFT_SAMPLE_RATE = 44100
+
+    private const val LOG_TAG = "LoqaVoiceDSP"
+
+    /**
+     * RustLogger that writes Rust diagnostics to Logcat under the "LoqaVoiceDSP" tag.
+     */
+    val logcatLogger = RustLogger { level, message ->
+        when (level) {
+            LOG_LEVEL_ERROR -> Log.e(LOG_TAG, message)
+            LOG_LEVEL_WARN -> Log.w(LOG_TAG, message)
+            LOG_LEVEL_INFO -> Log.i(LOG_TAG, message)
+            LOG_LEVEL_DEBUG -> Log.d(LOG_TAG, message)
+            else -> Log.v(LOG_TAG, message)
+        }
+    }
+
     // ============================================================================
     // External JNI Function Declarations (C ABI from Rust)
     // ============================================================================
@@ -151,82 +770,275 @@ object RustBridge {
      * Implemented in Story 2.3. Maps to Rust function:
      * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeFFT
      *
-     * This external function is resolved by JNI to the Rust implementation in lib.rs.
-     * The Rust function delegates to compute_fft_rust with a default sample rate of 44100 Hz.
+     * This external function is resolved by JNI to the Rust implementation in android.rs.
+     * The Rust function shares its validation and windowed FFT with loqa_compute_fft_ex.
      *
-     * @param buffer Input audio samples as FloatArray (JNI auto-converts to *const f32)
-     * @param fftSize FFT size (must be power of 2, range 256-8192)
-     * @param windowType Window function type (0=none, 1=hanning, 2=hamming, 3=blackman) - ignored in v0.1.0
-     * @return Magnitude spectrum as FloatArray (length = fftSize / 2 + 1)
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (used for bin frequencies and PSD scaling)
+     * @param fftSize Power of two in 64..8192, or 0 for the smallest one holding the buffer
+     * @param windowType Window function (0=none, 1=hanning, 2=hamming, 3=blackman,
+     *        4=kaiser, 5=flat-top, 6=gaussian)
+     * @param windowParam Kaiser beta or Gaussian sigma (0 = default), ignored otherwise
+     * @param normalization 0 = amplitude, 1 = power spectral density
+     * @param includePhase Fill FFTResult.phase
+     * @return FFTResult with fftSize / 2 + 1 bins
      */
     external fun nativeComputeFFT(
         buffer: FloatArray,
+        sampleRate: Int,
         fftSize: Int,
-        windowType: Int
-    ): FloatArray
+        windowType: Int,
+        windowParam: Float,
+        normalization: Int,
+        includePhase: Boolean
+    ): FFTResult
 
     /**
-     * JNI native function for pitch detection.
-     *
-     * Implemented in Story 3.1/3.3. Maps to Rust function:
-     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch
+     * JNI native function for the unwindowed complex FFT.
      *
-     * This external function is resolved by JNI to the Rust implementation in lib.rs.
-     * The Rust function uses pYIN algorithm for pitch detection (v0.4.0).
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeComplexFFT
      *
-     * v0.4.0 CHANGES:
-     * - Added minFrequency and maxFrequency parameters
-     * - Returns voicedProbability in PitchResult
-     * - Uses pYIN algorithm with HMM smoothing
+     * Shares its validation and transform with loqa_compute_fft_complex.
      *
-     * @param buffer Input audio samples as FloatArray (JNI auto-converts to *const f32)
-     * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param minFrequency Minimum detectable frequency in Hz (default: 80)
-     * @param maxFrequency Maximum detectable frequency in Hz (default: 400)
-     * @return PitchResult struct with frequency, confidence, isVoiced, and voicedProbability
+     * @param buffer Input audio samples (truncated or zero-padded to fftSize)
+     * @param fftSize Power of two in 64..8192, or 0 for the smallest one holding the buffer
+     * @return Interleaved bins [re0, im0, re1, im1, ...] (fftSize + 2 floats)
      */
-    external fun nativeDetectPitch(
-        buffer: FloatArray,
-        sampleRate: Int,
-        minFrequency: Float,
-        maxFrequency: Float
-    ): PitchResult
+    external fun nativeComputeComplexFFT(buffer: FloatArray, fftSize: Int): FloatArray
 
     /**
-     * JNI native function for formant extraction.
+     * JNI native function for the inverse FFT.
      *
-     * Implemented in Story 3.2/3.3. Maps to Rust function:
-     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeExtractFormants
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeInverseFFT
      *
-     * This external function is resolved by JNI to the Rust implementation in lib.rs.
-     * The Rust function uses LPC analysis for formant extraction.
+     * Shares its validation and transform with loqa_compute_ifft.
      *
-     * v0.4.0 BREAKING CHANGE:
-     * - FormantsResult now returns confidence instead of bandwidths (bw1, bw2, bw3)
+     * @param bins Interleaved bins as returned by nativeComputeComplexFFT
+     * @return fftSize time-domain samples
+     */
+    external fun nativeInverseFFT(bins: FloatArray): FloatArray
+
+    /**
+     * JNI native function for the spectrogram of a whole buffer.
      *
-     * @param buffer Input audio samples as FloatArray (JNI auto-converts to *const f32)
-     * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param lpcOrder LPC order (0 for default: sampleRate / 1000 + 2)
-     * @return FormantsResult struct with f1, f2, f3 and confidence
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeSpectrogram
+     *
+     * Shares its validation and STFT with loqa_compute_spectrogram.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz (used for the timestamps)
+     * @param frameSize Samples per frame, power of two in 64..8192
+     * @param hopSize Samples between frame starts, in 1..frameSize
+     * @param windowType Window function (see nativeComputeFFT)
+     * @param windowParam Kaiser beta or Gaussian sigma (0 = default), ignored otherwise
+     * @param scaling SPECTROGRAM_SCALING_LINEAR, _POWER or _DB
+     * @param dbFloor Lowest value for SPECTROGRAM_SCALING_DB, ignored otherwise
+     * @return SpectrogramResult with all frames in one array
      */
-    external fun nativeExtractFormants(
+    external fun nativeComputeSpectrogram(
         buffer: FloatArray,
         sampleRate: Int,
-        lpcOrder: Int
-    ): FormantsResult
+        frameSize: Int,
+        hopSize: Int,
+        windowType: Int,
+        windowParam: Float,
+        scaling: Int,
+        dbFloor: Float
+    ): SpectrogramResult
 
     /**
-     * Placeholder JNI function for spectral analysis.
+     * JNI native function for the log-mel spectrogram of a whole buffer.
      *
-     * This will be implemented in Story 4.1/4.2 to call Rust's analyze_spectrum_rust.
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeLogMelSpectrogram
      *
-     * @param buffer Input audio samples as FloatArray
+     * Shares its validation and filterbank with loqa_compute_log_mel_spectrogram.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame, power of two in 64..8192
+     * @param hopSize Samples between frame starts, in 1..frameSize
+     * @param windowType Window function (see nativeComputeFFT)
+     * @param windowParam Kaiser beta or Gaussian sigma (0 = default), ignored otherwise
+     * @param nMels Number of mel bands, in 1..256
+     * @param fmin Lower edge of the first band in Hz
+     * @param fmax Upper edge of the last band in Hz (0 = Nyquist)
+     * @param melScale MEL_SCALE_HTK or MEL_SCALE_SLANEY
+     * @return FeatureMatrix with nMels values in dB per frame
+     */
+    external fun nativeComputeLogMelSpectrogram(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int,
+        hopSize: Int,
+        windowType: Int,
+        windowParam: Float,
+        nMels: Int,
+        fmin: Float,
+        fmax: Float,
+        melScale: Int
+    ): FeatureMatrix
+
+    /**
+     * JNI native function for MFCCs of a whole buffer.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeMfcc
+     *
+     * Shares its validation and computation with loqa_compute_mfcc.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame, power of two in 64..8192
+     * @param hopSize Samples between frame starts, in 1..frameSize
+     * @param windowType Window function (see nativeComputeFFT)
+     * @param windowParam Kaiser beta or Gaussian sigma (0 = default), ignored otherwise
+     * @param nMels Number of mel bands, in 1..256
+     * @param fmin Lower edge of the first band in Hz
+     * @param fmax Upper edge of the last band in Hz (0 = Nyquist)
+     * @param melScale MEL_SCALE_HTK or MEL_SCALE_SLANEY
+     * @param nMfcc Number of cepstral coefficients, in 1..nMels
+     * @param lifter Sinusoidal lifter length (0 = none)
+     * @param deltaOrder 0 = static, 1 = plus deltas, 2 = plus deltas and delta-deltas
+     * @param deltaWidth Frames on each side of the delta regression (>= 1)
+     * @return FeatureMatrix with nMfcc * (1 + deltaOrder) values per frame
+     */
+    external fun nativeComputeMfcc(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int,
+        hopSize: Int,
+        windowType: Int,
+        windowParam: Float,
+        nMels: Int,
+        fmin: Float,
+        fmax: Float,
+        melScale: Int,
+        nMfcc: Int,
+        lifter: Float,
+        deltaOrder: Int,
+        deltaWidth: Int
+    ): FeatureMatrix
+
+    /**
+     * JNI native function for pitch detection.
+     *
+     * Implemented in Story 3.1/3.3. Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch
+     *
+     * This external function is resolved by JNI to the Rust implementation in android.rs.
+     * The Rust function uses pYIN algorithm for pitch detection (v0.4.0).
+     *
+     * v0.4.0 CHANGES:
+     * - Added minFrequency and maxFrequency parameters
+     * - Returns voicedProbability in PitchResult
+     * - Uses pYIN algorithm with HMM smoothing
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz (default: 80)
+     * @param maxFrequency Maximum detectable frequency in Hz (default: 400)
+     * @return PitchResult struct with frequency, confidence, isVoiced, and voicedProbability
+     */
+    external fun nativeDetectPitch(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float
+    ): PitchResult
+
+    /**
+     * Zero-copy variant of nativeDetectPitch for real-time capture paths.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitchDirect
+     *
+     * @param buffer Direct FloatBuffer in native byte order; samples between
+     *               position() and limit() are analyzed in place
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz
+     * @param maxFrequency Maximum detectable frequency in Hz
+     * @return PitchResult struct with frequency, confidence, isVoiced, and voicedProbability
+     */
+    external fun nativeDetectPitchDirect(
+        buffer: FloatBuffer,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float
+    ): PitchResult
+
+    /**
+     * Pitch detection with an explicit algorithm.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitchWithAlgorithm
+     *
+     * The whole buffer is analyzed as one frame, so it must hold at least 512 samples
+     * and two periods of minFrequency.
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz
+     * @param maxFrequency Maximum detectable frequency in Hz (up to sampleRate / 2)
+     * @param algorithm One of the PITCH_ALGORITHM_* constants
+     * @return PitchResult struct with frequency, confidence, isVoiced, and voicedProbability
+     */
+    external fun nativeDetectPitchWithAlgorithm(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        algorithm: Int
+    ): PitchResult
+
+    /**
+     * JNI native function for formant extraction.
+     *
+     * Implemented in Story 3.2/3.3. Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeExtractFormants
+     *
+     * This external function is resolved by JNI to the Rust implementation in android.rs.
+     * The Rust function shares its LPC pole analysis with loqa_extract_formants_ex.
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param lpcOrder LPC order (0 for default: sampleRate / 1000 + 2)
+     * @return FormantsResult with F1-F5, their bandwidths and confidences
+     */
+    external fun nativeExtractFormants(
+        buffer: FloatArray,
+        sampleRate: Int,
+        lpcOrder: Int
+    ): FormantsResult
+
+    /**
+     * JNI native function for spectral analysis.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeSpectrum
+     *
+     * The Rust function shares its validation and analysis with analyze_spectrum_rust
+     * and returns a HashMap. Any buffer length is accepted.
+     *
+     * @param buffer Input audio samples as FloatArray
      * @param sampleRate Sample rate in Hz
-     * @return Map with keys: "centroid" (Float), "rolloff" (Float), "tilt" (Float)
+     * @param fftSize Power of two in 64..8192, or 0 for the smallest one holding the buffer
+     * @param averageFrames Average 50%-overlapping frames when the buffer is longer than fftSize
+     * @param rolloffLowThreshold Energy fraction for "rolloffLow" (e.g. 0.85)
+     * @param rolloffHighThreshold Energy fraction for "rolloffHigh" and "rolloff" (e.g. 0.95)
+     * @return Map with Float values for "centroid", "rolloff", "tilt", "spread", "skewness",
+     *         "kurtosis", "flatness", "entropy", "crestFactor", "rolloffLow" and "rolloffHigh"
      */
     external fun nativeAnalyzeSpectrum(
         buffer: FloatArray,
-        sampleRate: Int
+        sampleRate: Int,
+        fftSize: Int,
+        averageFrames: Boolean,
+        rolloffLowThreshold: Float,
+        rolloffHighThreshold: Float
     ): Map<String, Any>
 
     /**
@@ -235,10 +1047,10 @@ object RustBridge {
      * Maps to Rust function:
      * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateHNR
      *
-     * This external function is resolved by JNI to the Rust implementation in lib.rs.
+     * This external function is resolved by JNI to the Rust implementation in android.rs.
      * The Rust function uses Boersma's autocorrelation method for HNR calculation.
      *
-     * @param buffer Input audio samples as FloatArray (JNI auto-converts to *const f32)
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
      * @param sampleRate Sample rate in Hz (8000-48000)
      * @param minFreq Minimum F0 frequency to search (typically 75 Hz)
      * @param maxFreq Maximum F0 frequency to search (typically 500 Hz)
@@ -257,10 +1069,10 @@ object RustBridge {
      * Maps to Rust function:
      * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateH1H2
      *
-     * This external function is resolved by JNI to the Rust implementation in lib.rs.
+     * This external function is resolved by JNI to the Rust implementation in android.rs.
      * H1-H2 measures the difference between first and second harmonic amplitudes.
      *
-     * @param buffer Input audio samples as FloatArray (JNI auto-converts to *const f32)
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
      * @param sampleRate Sample rate in Hz (8000-48000)
      * @param f0 Fundamental frequency in Hz, or 0.0 to auto-detect
      * @return H1H2Result struct with h1h2, h1AmplitudeDb, h2AmplitudeDb, and f0
@@ -271,6 +1083,159 @@ object RustBridge {
         f0: Float
     ): H1H2Result
 
+    /**
+     * JNI native function for Praat-compatible jitter.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateJitter
+     *
+     * Glottal periods are picked from the waveform inside the voiced frames of
+     * the VoiceAnalyzer pitch track. Throws if fewer than 3 valid periods are found.
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pitch track in Hz
+     * @param maxFrequency Maximum F0 of the pitch track in Hz
+     * @return JitterResult with local, localAbsolute, rap, ppq5, ddp, meanPeriod and periodCount
+     */
+    external fun nativeCalculateJitter(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float
+    ): JitterResult
+
+    /**
+     * JNI native function for Praat-compatible shimmer.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateShimmer
+     *
+     * Uses the peak amplitudes of the same periods as nativeCalculateJitter.
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pitch track in Hz
+     * @param maxFrequency Maximum F0 of the pitch track in Hz
+     * @return ShimmerResult with local, localDb, apq3, apq5, apq11, dda, meanAmplitude and periodCount
+     */
+    external fun nativeCalculateShimmer(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float
+    ): ShimmerResult
+
+    /**
+     * JNI native function for cepstral peak prominence (CPP and CPPS).
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateCpp
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (power of 2, above two periods of minFrequency)
+     * @param hopSize Samples between frame starts (1..frameSize)
+     * @param minFrequency Lowest F0 of the cepstral peak search in Hz
+     * @param maxFrequency Highest F0 of the cepstral peak search in Hz
+     * @param timeSmoothing CPPS moving average across frames in seconds (0 = none)
+     * @param quefrencySmoothing CPPS moving average across quefrency in seconds (0 = none)
+     * @return CppResult with cpp and cpps in dB, the median cepstral F0 and the frame count
+     */
+    external fun nativeCalculateCpp(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int,
+        hopSize: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        timeSmoothing: Float,
+        quefrencySmoothing: Float
+    ): CppResult
+
+    /**
+     * JNI native function for framewise RMS, peak and intensity contours.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeIntensity
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (any positive length)
+     * @param hopSize Samples between frame starts (1..frameSize)
+     * @param calibrationOffset dB added to every dBFS level (0 = dBFS)
+     * @param floorDb Lowest level in dBFS, applied before the offset
+     * @return IntensityResult with the contours and their summary statistics
+     */
+    external fun nativeComputeIntensity(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int,
+        hopSize: Int,
+        calibrationOffset: Float,
+        floorDb: Float
+    ): IntensityResult
+
+    /**
+     * JNI native function for voice activity detection.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectVoiceActivity
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pYIN pass in Hz
+     * @param maxFrequency Maximum F0 of the pYIN pass in Hz
+     * @param frameSize Samples per frame
+     * @param hopSize Samples between frame starts
+     * @param energyThresholdDb Lowest RMS level of a speech frame in dBFS
+     * @param zcrThreshold Zero crossings per second marking unvoiced speech
+     * @param voicingThreshold pYIN voicing probability of a voiced frame (0-1)
+     * @param hangoverTime Seconds a speech decision is held after the last speech frame
+     * @return VoiceActivityResult with framewise features, decisions and speech segments
+     */
+    external fun nativeDetectVoiceActivity(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        frameSize: Int,
+        hopSize: Int,
+        energyThresholdDb: Float,
+        zcrThreshold: Float,
+        voicingThreshold: Float,
+        hangoverTime: Float
+    ): VoiceActivityResult
+
+    /**
+     * JNI native function for vocal tract length estimation.
+     *
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeEstimateVocalTractLength
+     *
+     * @param buffer Input audio samples as FloatArray (copied into Rust by JNI)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pYIN pass in Hz
+     * @param maxFrequency Maximum F0 of the pYIN pass in Hz
+     * @param frameSize Samples per frame
+     * @param hopSize Samples between frame starts
+     * @param lpcOrder LPC order of the formant analysis (0 = sampleRate / 1000 + 2)
+     * @param minFormantConfidence Minimum confidence of a formant to be used (0-1)
+     * @param speedOfSound Speed of sound in the vocal tract in cm/s
+     * @return VocalTractLengthResult with both estimates, median formants and a confidence
+     */
+    external fun nativeEstimateVocalTractLength(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        frameSize: Int,
+        hopSize: Int,
+        lpcOrder: Int,
+        minFormantConfidence: Float,
+        speedOfSound: Float
+    ): VocalTractLengthResult
+
     // ============================================================================
     // VoiceAnalyzer JNI Functions (v0.3.0 Streaming API)
     // ============================================================================
@@ -309,6 +1274,18 @@ object RustBridge {
         buffer: FloatArray
     ): Array<PitchResult>
 
+    /**
+     * Zero-copy variant of nativeVoiceAnalyzerProcessStream.
+     *
+     * @param handle Analyzer handle from nativeVoiceAnalyzerNew
+     * @param buffer Direct FloatBuffer in native byte order (position() to limit())
+     * @return Array of PitchResult for each frame
+     */
+    external fun nativeVoiceAnalyzerProcessStreamDirect(
+        handle: Long,
+        buffer: FloatBuffer
+    ): Array<PitchResult>
+
     /**
      * JNI native function to reset a VoiceAnalyzer's state.
      *
@@ -339,155 +1316,999 @@ object RustBridge {
         buffer: FloatArray
     ): PitchTrackResult
 
-    // ============================================================================
-    // Kotlin Wrapper Functions with Error Handling
-    // ============================================================================
-
     /**
-     * Computes FFT on audio buffer with error handling.
+     * JNI native function for pitch track post-processing.
      *
-     * Implemented in Story 2.3. This wrapper calls the native JNI function and
-     * provides Kotlin-friendly error handling.
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativePostprocessPitchTrack
      *
-     * @param buffer Input audio samples
-     * @param fftSize FFT size (power of 2, range 256-8192)
-     * @param windowType Window function type (0=none, 1=hanning, 2=hamming, 3=blackman) - ignored in v0.1.0
-     * @return Magnitude spectrum (length = fftSize / 2 + 1)
-     * @throws RuntimeException if JNI call fails
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param voicedProbabilities Voiced probability per frame (same length)
+     * @param timestamps Frame starts in seconds (same length)
+     * @param correctOctaveJumps Move frames an octave or two off their neighbours back
+     * @param octaveWindow Frames whose median is the octave reference (odd, 3-1001)
+     * @param smoothing 0 = none, 1 = median, 2 = moving average
+     * @param smoothingWindow Frames in the smoothing window (odd, 1-1001)
+     * @param maxGapFrames Longest unvoiced run filled by interpolation (0 = none)
+     * @return PitchTrackResult of the same length; probabilities and timestamps unchanged
      */
-    fun computeFFT(buffer: FloatArray, fftSize: Int, windowType: Int): FloatArray {
-        return try {
-            nativeComputeFFT(buffer, fftSize, windowType)
-        } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeComputeFFT failed", e)
-        }
-    }
+    external fun nativePostprocessPitchTrack(
+        pitchTrack: FloatArray,
+        voicedProbabilities: FloatArray,
+        timestamps: FloatArray,
+        correctOctaveJumps: Boolean,
+        octaveWindow: Int,
+        smoothing: Int,
+        smoothingWindow: Int,
+        maxGapFrames: Int
+    ): PitchTrackResult
 
     /**
-     * Detects pitch from audio buffer with error handling.
+     * JNI native function for pitch track statistics.
      *
-     * Implemented in Story 3.3. This wrapper calls the native JNI function and
-     * provides Kotlin-friendly error handling.
-     *
-     * v0.4.0 CHANGES:
-     * - Added minFrequency and maxFrequency parameters (with defaults for human voice)
-     * - Returns voicedProbability in PitchResult
-     * - Uses pYIN algorithm with HMM smoothing for better accuracy
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativePitchTrackStatistics
      *
-     * @param buffer Input audio samples
-     * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param minFrequency Minimum detectable frequency in Hz (default: 80)
-     * @param maxFrequency Maximum detectable frequency in Hz (default: 400)
-     * @return PitchResult with frequency, confidence, isVoiced, and voicedProbability
-     * @throws RuntimeException if JNI call fails
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param voicedProbabilities Voiced probability per frame in [0, 1] (same length)
+     * @param referenceHz Frequency of 0 semitones in Hz
+     * @param weightByVoicing Weight voiced frames by voiced probability
+     * @return PitchStatisticsResult with Hz and semitone summaries
      */
-    fun detectPitch(
-        buffer: FloatArray,
-        sampleRate: Int,
-        minFrequency: Float = 80.0f,
-        maxFrequency: Float = 400.0f
-    ): PitchResult {
-        return try {
-            nativeDetectPitch(buffer, sampleRate, minFrequency, maxFrequency)
-        } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeDetectPitch failed", e)
-        }
-    }
+    external fun nativePitchTrackStatistics(
+        pitchTrack: FloatArray,
+        voicedProbabilities: FloatArray,
+        referenceHz: Float,
+        weightByVoicing: Boolean
+    ): PitchStatisticsResult
 
     /**
-     * Extracts formants from audio buffer with error handling.
-     *
-     * Implemented in Story 3.3. This wrapper calls the native JNI function and
-     * provides Kotlin-friendly error handling.
+     * JNI native function for vibrato analysis of a pitch track.
      *
-     * v0.4.0 BREAKING CHANGE:
-     * - FormantsResult now returns confidence instead of bandwidths (bw1, bw2, bw3)
+     * Maps to Rust function:
+     * Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeVibrato
      *
-     * @param buffer Input audio samples
-     * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param lpcOrder LPC order (0 for default: sampleRate / 1000 + 2)
-     * @return FormantsResult with f1, f2, f3 and confidence
-     * @throws RuntimeException if JNI call fails
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param frameRate Pitch frames per second
+     * @param minRateHz Slowest vibrato rate in Hz
+     * @param maxRateHz Fastest vibrato rate in Hz (at most frameRate / 4)
+     * @param minExtentCents Smallest vibrato extent in ± cents
+     * @param maxExtentCents Largest vibrato extent in ± cents
+     * @param minCycles Fewest full cycles in a segment (>= 2)
+     * @param windowSeconds Sliding window length in seconds (0 = no windows)
+     * @param windowHopFrames Frames between window ends (>= 1)
+     * @return VibratoResult with summary, segments and sliding windows
      */
-    fun extractFormants(
-        buffer: FloatArray,
-        sampleRate: Int,
-        lpcOrder: Int
-    ): FormantsResult {
-        return try {
-            nativeExtractFormants(buffer, sampleRate, lpcOrder)
-        } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeExtractFormants failed", e)
-        }
-    }
+    external fun nativeAnalyzeVibrato(
+        pitchTrack: FloatArray,
+        frameRate: Float,
+        minRateHz: Float,
+        maxRateHz: Float,
+        minExtentCents: Float,
+        maxExtentCents: Float,
+        minCycles: Int,
+        windowSeconds: Float,
+        windowHopFrames: Int
+    ): VibratoResult
 
     /**
-     * Analyzes spectral features of audio buffer with error handling.
+     * JNI native function to create a streaming analyzer.
      *
-     * Note: This is a placeholder wrapper. Full implementation in Story 4.2.
+     * Unlike a VoiceAnalyzer, it buffers samples between pushes, so chunks of any
+     * size produce frames every hopSize samples with continuous timestamps.
+     * Returns a handle (Long) that must be freed with nativeStreamAnalyzerFree.
      *
-     * @param buffer Input audio samples
-     * @param sampleRate Sample rate in Hz
-     * @return Spectral analysis result
-     * @throws RuntimeException if JNI call fails
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz
+     * @param maxFrequency Maximum detectable frequency in Hz
+     * @param frameSize Frame size in samples
+     * @param hopSize Hop size in samples
+     * @return Handle to the streaming analyzer (Long pointer)
      */
-    fun analyzeSpectrum(buffer: FloatArray, sampleRate: Int): Map<String, Any> {
-        return try {
-            nativeAnalyzeSpectrum(buffer, sampleRate)
-        } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeAnalyzeSpectrum failed", e)
-        }
-    }
+    external fun nativeStreamAnalyzerNew(
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        frameSize: Int,
+        hopSize: Int
+    ): Long
 
     /**
-     * Calculates HNR (Harmonics-to-Noise Ratio) with error handling.
+     * JNI native function to push samples into a streaming analyzer.
+     *
+     * @param handle Analyzer handle from nativeStreamAnalyzerNew
+     * @param buffer Audio samples of any length
+     * @return StreamFramesResult with every frame the samples completed
+     */
+    external fun nativeStreamAnalyzerPush(
+        handle: Long,
+        buffer: FloatArray
+    ): StreamFramesResult
+
+    /**
+     * JNI native function to analyse the zero-padded tail of a stream.
+     *
+     * The next push starts a new stream at timestamp 0.
+     *
+     * @param handle Analyzer handle from nativeStreamAnalyzerNew
+     * @return StreamFramesResult with the tail frames
+     */
+    external fun nativeStreamAnalyzerFlush(handle: Long): StreamFramesResult
+
+    /**
+     * JNI native function to discard buffered samples and restart timestamps at 0.
+     *
+     * @param handle Analyzer handle from nativeStreamAnalyzerNew
+     */
+    external fun nativeStreamAnalyzerReset(handle: Long)
+
+    /**
+     * JNI native function to free a streaming analyzer.
+     *
+     * @param handle Analyzer handle from nativeStreamAnalyzerNew
+     */
+    external fun nativeStreamAnalyzerFree(handle: Long)
+
+    /**
+     * JNI native function to create a tuner.
+     *
+     * Audio is pushed in chunks of any size as with a streaming analyzer; each frame
+     * is matched to the nearest note of the temperament, tuned so A4 = referenceHz.
+     * Returns a handle (Long) that must be freed with nativeTunerFree.
+     *
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz
+     * @param maxFrequency Maximum detectable frequency in Hz
+     * @param frameSize Frame size in samples
+     * @param hopSize Hop size in samples
+     * @param referenceHz Frequency of A4 in Hz
+     * @param temperamentCents Offsets of C, C#, ..., B from equal temperament in cents
+     *        (12 values within ±50; all zeros is equal temperament)
+     * @param stabilityFrames Frames over which stability is measured, in 1..1000
+     * @return Handle to the tuner (Long pointer)
+     */
+    external fun nativeTunerNew(
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        frameSize: Int,
+        hopSize: Int,
+        referenceHz: Float,
+        temperamentCents: FloatArray,
+        stabilityFrames: Int
+    ): Long
+
+    /**
+     * JNI native function to push samples into a tuner.
+     *
+     * @param handle Tuner handle from nativeTunerNew
+     * @param buffer Audio samples of any length
+     * @return TunerReading after the frames the samples completed
+     */
+    external fun nativeTunerPush(
+        handle: Long,
+        buffer: FloatArray
+    ): TunerReading
+
+    /**
+     * JNI native function to discard a tuner's buffered samples and reading.
+     *
+     * @param handle Tuner handle from nativeTunerNew
+     */
+    external fun nativeTunerReset(handle: Long)
+
+    /**
+     * JNI native function to free a tuner.
+     *
+     * @param handle Tuner handle from nativeTunerNew
+     */
+    external fun nativeTunerFree(handle: Long)
+
+    /**
+     * JNI native function to create an empty voice range profile.
+     *
+     * Frames are binned by nearest note (A4 = referenceHz) and by intensity in
+     * dbStep columns from minDb to maxDb. Returns a handle (Long) that must be
+     * freed with nativeVrpFree.
+     *
+     * @param referenceHz Frequency of A4 in Hz
+     * @param minMidiNote MIDI note of the first row, in 0..127
+     * @param maxMidiNote MIDI note of the last row, in minMidiNote..127
+     * @param minDb Lower edge of the first level column in dB
+     * @param maxDb Upper end of the level range in dB
+     * @param dbStep Width of a level column in dB (at most 1000 columns)
+     * @param minCellFrames Frames a cell needs before it counts as sustained (>= 1)
+     * @return Handle to the profile (Long pointer)
+     */
+    external fun nativeVrpNew(
+        referenceHz: Float,
+        minMidiNote: Int,
+        maxMidiNote: Int,
+        minDb: Float,
+        maxDb: Float,
+        dbStep: Float,
+        minCellFrames: Int
+    ): Long
+
+    /**
+     * JNI native function to add framewise pitch and intensity to a profile.
+     *
+     * @param handle Profile handle from nativeVrpNew
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param intensityDb Level per frame in dB, on the same frame grid
+     */
+    external fun nativeVrpAddFrames(
+        handle: Long,
+        pitchTrack: FloatArray,
+        intensityDb: FloatArray
+    )
+
+    /**
+     * JNI native function to add the counts of one profile to another.
+     *
+     * @param handle Target profile handle from nativeVrpNew
+     * @param sourceHandle Source profile handle with the same configuration
+     */
+    external fun nativeVrpMerge(handle: Long, sourceHandle: Long)
+
+    /**
+     * JNI native function to read a profile's grid and sustainable range.
+     *
+     * @param handle Profile handle from nativeVrpNew
+     * @return VoiceRangeProfileResult snapshot
+     */
+    external fun nativeVrpGet(handle: Long): VoiceRangeProfileResult
+
+    /**
+     * JNI native function to empty a profile's grid.
+     *
+     * @param handle Profile handle from nativeVrpNew
+     */
+    external fun nativeVrpReset(handle: Long)
+
+    /**
+     * JNI native function to free a voice range profile.
+     *
+     * @param handle Profile handle from nativeVrpNew
+     */
+    external fun nativeVrpFree(handle: Long)
+
+    /**
+     * JNI native function to create a formant tracker.
+     *
+     * Each frame is analysed by LPC and F1-F4 are chosen among its resonances for
+     * continuity across the buffer by Viterbi decoding. Returns a handle (Long) that
+     * must be freed with nativeFormantTrackerFree.
+     *
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param frameSize LPC window in samples (10-100 ms)
+     * @param hopSize Samples between frame starts, in 1..frameSize
+     * @param lpcOrder LPC order in 8..24 (0 = sampleRate / 1000 + 2)
+     * @param ceilingHz Highest formant frequency in Hz, in 3000..7000 and below sampleRate / 2
+     * @param continuityWeight Weight of continuity against fit to neutral-vowel formants (>= 0)
+     * @param minConfidence Minimum mean F1-F3 confidence of a reliable frame, in 0..1
+     * @param silenceDb Frames below this RMS level in dBFS (<= 0) are unreliable
+     * @return Handle to the tracker (Long pointer)
+     */
+    external fun nativeFormantTrackerNew(
+        sampleRate: Int,
+        frameSize: Int,
+        hopSize: Int,
+        lpcOrder: Int,
+        ceilingHz: Float,
+        continuityWeight: Float,
+        minConfidence: Float,
+        silenceDb: Float
+    ): Long
+
+    /**
+     * JNI native function to track formants over the frames of a buffer.
+     *
+     * The last frame seeds the next buffer, so consecutive buffers join up.
+     *
+     * @param handle Tracker handle from nativeFormantTrackerNew
+     * @param buffer Audio samples; must be longer than frameSize
+     * @return FormantTrackResult with one entry (four for formants) per frame
+     */
+    external fun nativeFormantTrackerProcessBuffer(
+        handle: Long,
+        buffer: FloatArray
+    ): FormantTrackResult
+
+    /**
+     * JNI native function to make a tracker forget the previous buffer.
+     *
+     * @param handle Tracker handle from nativeFormantTrackerNew
+     */
+    external fun nativeFormantTrackerReset(handle: Long)
+
+    /**
+     * JNI native function to free a formant tracker.
+     *
+     * @param handle Tracker handle from nativeFormantTrackerNew
+     */
+    external fun nativeFormantTrackerFree(handle: Long)
+
+    /**
+     * JNI native function to route Rust diagnostics to a Kotlin logger.
+     *
+     * @param logger Receives (level, message); null stops logging (the default)
+     */
+    external fun nativeSetLogCallback(logger: RustLogger?)
+
+    /**
+     * JNI native function to set the most verbose Rust log level that is delivered.
+     *
+     * @param level LOG_LEVEL_OFF..LOG_LEVEL_TRACE (default: LOG_LEVEL_WARN)
+     * @throws IllegalArgumentException if level is out of range
+     */
+    external fun nativeSetLogLevel(level: Int)
+
+    // ============================================================================
+    // Kotlin Wrapper Functions with Error Handling
+    // ============================================================================
+
+    /**
+     * Routes Rust diagnostics to [logger] (e.g. [logcatLogger] or app telemetry).
+     *
+     * @param logger Receives (level, message); null disables forwarding
+     */
+    fun setLogger(logger: RustLogger?) {
+        try {
+            nativeSetLogCallback(logger)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeSetLogCallback failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Sets the most verbose Rust log level that is delivered to the logger.
+     *
+     * @param level LOG_LEVEL_OFF..LOG_LEVEL_TRACE (default: LOG_LEVEL_WARN)
+     * @throws RuntimeException if level is out of range
+     */
+    fun setLogLevel(level: Int) {
+        try {
+            nativeSetLogLevel(level)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeSetLogLevel failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes FFT on audio buffer with error handling.
+     *
+     * Implemented in Story 2.3. This wrapper calls the native JNI function and
+     * provides Kotlin-friendly error handling.
+     *
+     * @param buffer Input audio samples
+     * @param fftSize FFT size (power of 2, range 256-8192)
+     * @param windowType Window function type (0=none, 1=hanning, 2=hamming, 3=blackman)
+     * @return Amplitude spectrum at 44100 Hz (length = fftSize / 2 + 1)
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeFFT(buffer: FloatArray, fftSize: Int, windowType: Int): FloatArray {
+        return computeFFT(buffer, DEFAULT_FFT_SAMPLE_RATE, fftSize, windowType).magnitude
+    }
+
+    /**
+     * Computes a windowed FFT with phase and bin frequencies, with error handling.
+     *
+     * Magnitudes are compensated for the window's gain: with amplitude normalization
+     * an on-bin sine of amplitude A reads A whichever window is used.
+     *
+     * @param buffer Input audio samples (truncated or zero-padded to fftSize)
+     * @param sampleRate Sample rate in Hz
+     * @param fftSize Power of two in 64..8192, or 0 to fit the buffer
+     * @param windowType Window function (see nativeComputeFFT)
+     * @param windowParam Kaiser beta or Gaussian sigma (default: 0 = 8.6 / 0.4)
+     * @param normalization 0 = amplitude (default), 1 = power spectral density
+     * @param includePhase Fill FFTResult.phase (default: false)
+     * @return FFTResult with fftSize / 2 + 1 bins
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeFFT(
+        buffer: FloatArray,
+        sampleRate: Int,
+        fftSize: Int,
+        windowType: Int,
+        windowParam: Float = 0f,
+        normalization: Int = 0,
+        includePhase: Boolean = false
+    ): FFTResult {
+        return try {
+            nativeComputeFFT(
+                buffer,
+                sampleRate,
+                fftSize,
+                windowType,
+                windowParam,
+                normalization,
+                includePhase
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeFFT failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes the unwindowed complex FFT with error handling.
+     *
+     * @param buffer Input audio samples (truncated or zero-padded to fftSize)
+     * @param fftSize Power of two in 64..8192, or 0 to fit the buffer (default: 0)
+     * @return Interleaved bins [re0, im0, re1, im1, ...] (fftSize + 2 floats)
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeComplexFFT(buffer: FloatArray, fftSize: Int = 0): FloatArray {
+        return try {
+            nativeComputeComplexFFT(buffer, fftSize)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeComplexFFT failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes the inverse FFT of interleaved one-sided bins with error handling.
+     *
+     * @param bins Interleaved bins as returned by computeComplexFFT
+     * @return fftSize time-domain samples
+     * @throws RuntimeException if JNI call fails
+     */
+    fun inverseFFT(bins: FloatArray): FloatArray {
+        return try {
+            nativeInverseFFT(bins)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeInverseFFT failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes the spectrogram of a whole buffer with error handling.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param windowType Window function (default: WINDOW_HANNING)
+     * @param windowParam Kaiser beta or Gaussian sigma (default: 0 = 8.6 / 0.4)
+     * @param scaling Cell scaling (default: SPECTROGRAM_SCALING_DB)
+     * @param dbFloor Lowest dB value (default: -120)
+     * @return SpectrogramResult with all frames in one array
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeSpectrogram(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        windowType: Int = WINDOW_HANNING,
+        windowParam: Float = 0f,
+        scaling: Int = SPECTROGRAM_SCALING_DB,
+        dbFloor: Float = -120f
+    ): SpectrogramResult {
+        return try {
+            nativeComputeSpectrogram(
+                buffer,
+                sampleRate,
+                frameSize,
+                hopSize,
+                windowType,
+                windowParam,
+                scaling,
+                dbFloor
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeSpectrogram failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes the log-mel spectrogram of a whole buffer with error handling.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param windowType Window function (default: WINDOW_HANNING)
+     * @param windowParam Kaiser beta or Gaussian sigma (default: 0 = 8.6 / 0.4)
+     * @param nMels Number of mel bands (default: 40)
+     * @param fmin Lower edge of the first band in Hz (default: 0)
+     * @param fmax Upper edge of the last band in Hz (default: 0 = Nyquist)
+     * @param melScale Mel scale (default: MEL_SCALE_SLANEY)
+     * @return FeatureMatrix with nMels values in dB per frame
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeLogMelSpectrogram(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        windowType: Int = WINDOW_HANNING,
+        windowParam: Float = 0f,
+        nMels: Int = 40,
+        fmin: Float = 0f,
+        fmax: Float = 0f,
+        melScale: Int = MEL_SCALE_SLANEY
+    ): FeatureMatrix {
+        return try {
+            nativeComputeLogMelSpectrogram(
+                buffer,
+                sampleRate,
+                frameSize,
+                hopSize,
+                windowType,
+                windowParam,
+                nMels,
+                fmin,
+                fmax,
+                melScale
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeLogMelSpectrogram failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes MFCCs of a whole buffer with error handling.
+     *
+     * @param buffer Input audio samples; must be longer than frameSize
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param windowType Window function (default: WINDOW_HANNING)
+     * @param windowParam Kaiser beta or Gaussian sigma (default: 0 = 8.6 / 0.4)
+     * @param nMels Number of mel bands (default: 40)
+     * @param fmin Lower edge of the first band in Hz (default: 0)
+     * @param fmax Upper edge of the last band in Hz (default: 0 = Nyquist)
+     * @param melScale Mel scale (default: MEL_SCALE_SLANEY)
+     * @param nMfcc Number of cepstral coefficients (default: 13)
+     * @param lifter Sinusoidal lifter length (default: 0 = none)
+     * @param deltaOrder Appended delta orders (default: 0)
+     * @param deltaWidth Frames on each side of the delta regression (default: 2)
+     * @return FeatureMatrix with nMfcc * (1 + deltaOrder) values per frame
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeMfcc(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        windowType: Int = WINDOW_HANNING,
+        windowParam: Float = 0f,
+        nMels: Int = 40,
+        fmin: Float = 0f,
+        fmax: Float = 0f,
+        melScale: Int = MEL_SCALE_SLANEY,
+        nMfcc: Int = 13,
+        lifter: Float = 0f,
+        deltaOrder: Int = 0,
+        deltaWidth: Int = 2
+    ): FeatureMatrix {
+        return try {
+            nativeComputeMfcc(
+                buffer,
+                sampleRate,
+                frameSize,
+                hopSize,
+                windowType,
+                windowParam,
+                nMels,
+                fmin,
+                fmax,
+                melScale,
+                nMfcc,
+                lifter,
+                deltaOrder,
+                deltaWidth
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeMfcc failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Detects pitch from audio buffer with error handling.
+     *
+     * Implemented in Story 3.3. This wrapper calls the native JNI function and
+     * provides Kotlin-friendly error handling.
+     *
+     * v0.4.0 CHANGES:
+     * - Added minFrequency and maxFrequency parameters (with defaults for human voice)
+     * - Returns voicedProbability in PitchResult
+     * - Uses pYIN algorithm with HMM smoothing for better accuracy
+     *
+     * @param buffer Input audio samples
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz (default: 80)
+     * @param maxFrequency Maximum detectable frequency in Hz (default: 400)
+     * @return PitchResult with frequency, confidence, isVoiced, and voicedProbability
+     * @throws RuntimeException if JNI call fails
+     */
+    fun detectPitch(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float = 80.0f,
+        maxFrequency: Float = 400.0f
+    ): PitchResult {
+        return try {
+            nativeDetectPitch(buffer, sampleRate, minFrequency, maxFrequency)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeDetectPitch failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Detects pitch from a direct FloatBuffer without copying the samples.
+     *
+     * @param buffer Direct FloatBuffer in native byte order
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz (default: 80)
+     * @param maxFrequency Maximum detectable frequency in Hz (default: 400)
+     * @return PitchResult with frequency, confidence, isVoiced, and voicedProbability
+     * @throws RuntimeException if JNI call fails
+     */
+    fun detectPitch(
+        buffer: FloatBuffer,
+        sampleRate: Int,
+        minFrequency: Float = 80.0f,
+        maxFrequency: Float = 400.0f
+    ): PitchResult {
+        return try {
+            nativeDetectPitchDirect(buffer, sampleRate, minFrequency, maxFrequency)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeDetectPitchDirect failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Detects pitch with an explicit algorithm (pYIN, YIN or autocorrelation).
+     *
+     * @param buffer Input audio samples (at least 512)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum detectable frequency in Hz
+     * @param maxFrequency Maximum detectable frequency in Hz
+     * @param algorithm One of the PITCH_ALGORITHM_* constants
+     * @return PitchResult with frequency, confidence, isVoiced, and voicedProbability
+     * @throws RuntimeException if JNI call fails
+     */
+    fun detectPitch(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float,
+        maxFrequency: Float,
+        algorithm: Int
+    ): PitchResult {
+        return try {
+            nativeDetectPitchWithAlgorithm(buffer, sampleRate, minFrequency, maxFrequency, algorithm)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeDetectPitchWithAlgorithm failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Extracts formants from audio buffer with error handling.
+     *
+     * Implemented in Story 3.3. This wrapper calls the native JNI function and
+     * provides Kotlin-friendly error handling.
+     *
+     * @param buffer Input audio samples
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param lpcOrder LPC order (0 for default: sampleRate / 1000 + 2)
+     * @return FormantsResult with F1-F5, their bandwidths and confidences
+     * @throws RuntimeException if JNI call fails
+     */
+    fun extractFormants(
+        buffer: FloatArray,
+        sampleRate: Int,
+        lpcOrder: Int
+    ): FormantsResult {
+        return try {
+            nativeExtractFormants(buffer, sampleRate, lpcOrder)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeExtractFormants failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Analyzes spectral features of audio buffer with error handling.
+     *
+     * @param buffer Input audio samples (any length)
+     * @param sampleRate Sample rate in Hz
+     * @param fftSize Power of two in 64..8192, or 0 (default) to fit the buffer
+     * @param averageFrames Average overlapping frames of longer buffers (default: true)
+     * @param rolloffLowThreshold Energy fraction for "rolloffLow" (default: 0.85)
+     * @param rolloffHighThreshold Energy fraction for "rolloffHigh"/"rolloff" (default: 0.95)
+     * @return Spectral analysis result (see nativeAnalyzeSpectrum for the keys)
+     * @throws RuntimeException if JNI call fails
+     */
+    fun analyzeSpectrum(
+        buffer: FloatArray,
+        sampleRate: Int,
+        fftSize: Int = 0,
+        averageFrames: Boolean = true,
+        rolloffLowThreshold: Float = 0.85f,
+        rolloffHighThreshold: Float = 0.95f
+    ): Map<String, Any> {
+        return try {
+            nativeAnalyzeSpectrum(
+                buffer,
+                sampleRate,
+                fftSize,
+                averageFrames,
+                rolloffLowThreshold,
+                rolloffHighThreshold
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeAnalyzeSpectrum failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Calculates HNR (Harmonics-to-Noise Ratio) with error handling.
      *
      * HNR measures the ratio of harmonic to noise energy in voice:
      * - Higher HNR (18-25 dB): Clear, less breathy voice
      * - Lower HNR (12-18 dB): Softer, more breathy voice
      *
-     * @param buffer Input audio samples
+     * @param buffer Input audio samples
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFreq Minimum F0 frequency to search (default: 75 Hz)
+     * @param maxFreq Maximum F0 frequency to search (default: 500 Hz)
+     * @return HNRResult with hnr (dB), f0 (Hz), and isVoiced flag
+     * @throws RuntimeException if JNI call fails
+     */
+    fun calculateHNR(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFreq: Float = 75.0f,
+        maxFreq: Float = 500.0f
+    ): HNRResult {
+        return try {
+            nativeCalculateHNR(buffer, sampleRate, minFreq, maxFreq)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeCalculateHNR failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Calculates H1-H2 amplitude difference with error handling.
+     *
+     * H1-H2 measures the difference between first and second harmonic amplitudes:
+     * - Higher H1-H2 (>5 dB): Lighter, breathier vocal quality
+     * - Lower H1-H2 (<0 dB): Fuller, heavier vocal quality
+     *
+     * @param buffer Input audio samples
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param f0 Fundamental frequency in Hz, or 0.0 to auto-detect
+     * @return H1H2Result with h1h2, h1AmplitudeDb, h2AmplitudeDb, and f0
+     * @throws RuntimeException if JNI call fails
+     */
+    fun calculateH1H2(
+        buffer: FloatArray,
+        sampleRate: Int,
+        f0: Float = 0.0f
+    ): H1H2Result {
+        return try {
+            nativeCalculateH1H2(buffer, sampleRate, f0)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeCalculateH1H2 failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Calculates Praat-compatible jitter with error handling.
+     *
+     * @param buffer Input audio samples (must be longer than 2048 samples)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pitch track (default: 75 Hz)
+     * @param maxFrequency Maximum F0 of the pitch track (default: 500 Hz)
+     * @return JitterResult with relative measures as fractions and meanPeriod in seconds
+     * @throws RuntimeException if JNI call fails or too few periods are found
+     */
+    fun calculateJitter(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float = 75.0f,
+        maxFrequency: Float = 500.0f
+    ): JitterResult {
+        return try {
+            nativeCalculateJitter(buffer, sampleRate, minFrequency, maxFrequency)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeCalculateJitter failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Calculates Praat-compatible shimmer with error handling.
+     *
+     * @param buffer Input audio samples (must be longer than 2048 samples)
+     * @param sampleRate Sample rate in Hz (8000-48000)
+     * @param minFrequency Minimum F0 of the pitch track (default: 75 Hz)
+     * @param maxFrequency Maximum F0 of the pitch track (default: 500 Hz)
+     * @return ShimmerResult with relative measures as fractions and localDb in dB
+     * @throws RuntimeException if JNI call fails or too few periods are found
+     */
+    fun calculateShimmer(
+        buffer: FloatArray,
+        sampleRate: Int,
+        minFrequency: Float = 75.0f,
+        maxFrequency: Float = 500.0f
+    ): ShimmerResult {
+        return try {
+            nativeCalculateShimmer(buffer, sampleRate, minFrequency, maxFrequency)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeCalculateShimmer failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Calculates cepstral peak prominence (CPP and smoothed CPPS) with error handling.
+     *
+     * Defaults follow Praat's "Get CPPS": a 60-330 Hz peak search, 0.02 s time
+     * smoothing and 0.0005 s quefrency smoothing.
+     *
+     * @param buffer Input audio samples (must be longer than frameSize)
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 128)
+     * @param minFrequency Lowest F0 of the peak search (default: 60 Hz)
+     * @param maxFrequency Highest F0 of the peak search (default: 330 Hz)
+     * @param timeSmoothing CPPS smoothing across frames in seconds (default: 0.02)
+     * @param quefrencySmoothing CPPS smoothing across quefrency in seconds (default: 0.0005)
+     * @return CppResult with cpp and cpps in dB
+     * @throws RuntimeException if JNI call fails
+     */
+    fun calculateCpp(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int = 2048,
+        hopSize: Int = 128,
+        minFrequency: Float = 60.0f,
+        maxFrequency: Float = 330.0f,
+        timeSmoothing: Float = 0.02f,
+        quefrencySmoothing: Float = 0.0005f
+    ): CppResult {
+        return try {
+            nativeCalculateCpp(
+                buffer,
+                sampleRate,
+                frameSize,
+                hopSize,
+                minFrequency,
+                maxFrequency,
+                timeSmoothing,
+                quefrencySmoothing
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeCalculateCpp failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Computes framewise RMS, peak and intensity contours with error handling.
+     *
+     * With a calibrationOffset measured against a sound level meter (its dB SPL
+     * minus the dBFS reading for the same steady sound) levels read approximate dB SPL.
+     *
+     * @param buffer Input audio samples (must be longer than frameSize)
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param calibrationOffset dB added to every level (default: 0 = dBFS)
+     * @param floorDb Lowest level in dBFS (default: -120)
+     * @return IntensityResult with the contours and mean/min/max/dynamic range in dB
+     * @throws RuntimeException if JNI call fails
+     */
+    fun computeIntensity(
+        buffer: FloatArray,
+        sampleRate: Int,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        calibrationOffset: Float = 0f,
+        floorDb: Float = -120f
+    ): IntensityResult {
+        return try {
+            nativeComputeIntensity(buffer, sampleRate, frameSize, hopSize, calibrationOffset, floorDb)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeComputeIntensity failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Detects speech frames and segments with error handling.
+     *
+     * A frame is speech when it is loud enough and either voiced or has a high
+     * zero-crossing rate (unvoiced consonants); decisions are held for hangoverTime.
+     *
+     * @param buffer Input audio samples (must be longer than frameSize)
      * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param minFreq Minimum F0 frequency to search (default: 75 Hz)
-     * @param maxFreq Maximum F0 frequency to search (default: 500 Hz)
-     * @return HNRResult with hnr (dB), f0 (Hz), and isVoiced flag
+     * @param minFrequency Minimum F0 of the pYIN pass (default: 75 Hz)
+     * @param maxFrequency Maximum F0 of the pYIN pass (default: 500 Hz)
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param energyThresholdDb Energy gate in dBFS (default: -50)
+     * @param zcrThreshold Crossings per second marking unvoiced speech (default: 3000)
+     * @param voicingThreshold Voicing probability of a voiced frame (default: 0.5)
+     * @param hangoverTime Seconds a speech decision is held (default: 0.2)
+     * @return VoiceActivityResult with segments as flat [start, end) sample pairs
      * @throws RuntimeException if JNI call fails
      */
-    fun calculateHNR(
+    fun detectVoiceActivity(
         buffer: FloatArray,
         sampleRate: Int,
-        minFreq: Float = 75.0f,
-        maxFreq: Float = 500.0f
-    ): HNRResult {
+        minFrequency: Float = 75.0f,
+        maxFrequency: Float = 500.0f,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        energyThresholdDb: Float = -50.0f,
+        zcrThreshold: Float = 3000.0f,
+        voicingThreshold: Float = 0.5f,
+        hangoverTime: Float = 0.2f
+    ): VoiceActivityResult {
         return try {
-            nativeCalculateHNR(buffer, sampleRate, minFreq, maxFreq)
+            nativeDetectVoiceActivity(
+                buffer,
+                sampleRate,
+                minFrequency,
+                maxFrequency,
+                frameSize,
+                hopSize,
+                energyThresholdDb,
+                zcrThreshold,
+                voicingThreshold,
+                hangoverTime
+            )
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeCalculateHNR failed", e)
+            throw RuntimeException("JNI call to nativeDetectVoiceActivity failed: ${e.message}", e)
         }
     }
 
     /**
-     * Calculates H1-H2 amplitude difference with error handling.
+     * Estimates vocal tract length from the formants of voiced frames with error handling.
      *
-     * H1-H2 measures the difference between first and second harmonic amplitudes:
-     * - Higher H1-H2 (>5 dB): Lighter, breathier vocal quality
-     * - Lower H1-H2 (<0 dB): Fuller, heavier vocal quality
+     * Fits a uniform tube closed at the glottis to F1-F4 of every voiced frame and
+     * takes the median over frames; use it for speaker normalization.
      *
-     * @param buffer Input audio samples
+     * @param buffer Input audio samples (must be longer than frameSize)
      * @param sampleRate Sample rate in Hz (8000-48000)
-     * @param f0 Fundamental frequency in Hz, or 0.0 to auto-detect
-     * @return H1H2Result with h1h2, h1AmplitudeDb, h2AmplitudeDb, and f0
-     * @throws RuntimeException if JNI call fails
+     * @param minFrequency Minimum F0 of the pYIN pass (default: 75 Hz)
+     * @param maxFrequency Maximum F0 of the pYIN pass (default: 500 Hz)
+     * @param frameSize Samples per frame (default: 2048)
+     * @param hopSize Samples between frame starts (default: 512)
+     * @param lpcOrder LPC order (default: 0 = sampleRate / 1000 + 2)
+     * @param minFormantConfidence Minimum formant confidence (default: 0.3)
+     * @param speedOfSound Speed of sound in cm/s (default: 35000)
+     * @return VocalTractLengthResult with VTL in cm and a 0-1 confidence
+     * @throws RuntimeException if JNI call fails or no voiced frame had three formants
      */
-    fun calculateH1H2(
+    fun estimateVocalTractLength(
         buffer: FloatArray,
         sampleRate: Int,
-        f0: Float = 0.0f
-    ): H1H2Result {
+        minFrequency: Float = 75.0f,
+        maxFrequency: Float = 500.0f,
+        frameSize: Int = 2048,
+        hopSize: Int = 512,
+        lpcOrder: Int = 0,
+        minFormantConfidence: Float = 0.3f,
+        speedOfSound: Float = 35000.0f
+    ): VocalTractLengthResult {
         return try {
-            nativeCalculateH1H2(buffer, sampleRate, f0)
+            nativeEstimateVocalTractLength(
+                buffer,
+                sampleRate,
+                minFrequency,
+                maxFrequency,
+                frameSize,
+                hopSize,
+                lpcOrder,
+                minFormantConfidence,
+                speedOfSound
+            )
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeCalculateH1H2 failed", e)
+            throw RuntimeException(
+                "JNI call to nativeEstimateVocalTractLength failed: ${e.message}",
+                e
+            )
         }
     }
 
@@ -516,7 +2337,7 @@ object RustBridge {
             }
             handle
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeVoiceAnalyzerNew failed", e)
+            throw RuntimeException("JNI call to nativeVoiceAnalyzerNew failed: ${e.message}", e)
         }
     }
 
@@ -535,7 +2356,26 @@ object RustBridge {
         return try {
             nativeVoiceAnalyzerProcessStream(handle, buffer)
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeVoiceAnalyzerProcessStream failed", e)
+            throw RuntimeException("JNI call to nativeVoiceAnalyzerProcessStream failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Process a direct FloatBuffer through the VoiceAnalyzer without copying.
+     *
+     * @param handle Analyzer handle from createVoiceAnalyzer
+     * @param buffer Direct FloatBuffer in native byte order
+     * @return Array of PitchResult for each frame
+     * @throws RuntimeException if processing fails
+     */
+    fun processAudioWithAnalyzer(
+        handle: Long,
+        buffer: FloatBuffer
+    ): Array<PitchResult> {
+        return try {
+            nativeVoiceAnalyzerProcessStreamDirect(handle, buffer)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVoiceAnalyzerProcessStreamDirect failed: ${e.message}", e)
         }
     }
 
@@ -549,7 +2389,7 @@ object RustBridge {
         try {
             nativeVoiceAnalyzerReset(handle)
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeVoiceAnalyzerReset failed", e)
+            throw RuntimeException("JNI call to nativeVoiceAnalyzerReset failed: ${e.message}", e)
         }
     }
 
@@ -588,7 +2428,465 @@ object RustBridge {
         return try {
             nativeVoiceAnalyzerProcessBuffer(handle, buffer)
         } catch (e: Exception) {
-            throw RuntimeException("JNI call to nativeVoiceAnalyzerProcessBuffer failed", e)
+            throw RuntimeException("JNI call to nativeVoiceAnalyzerProcessBuffer failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Corrects octave jumps, fills short unvoiced gaps and smooths a pitch track.
+     *
+     * Octave correction runs first, then interpolation across gaps (in
+     * log-frequency), then smoothing within each voiced run. The result has the
+     * same frames as the input, with voicedProbabilities and timestamps unchanged.
+     *
+     * @param track PitchTrackResult, e.g. from processBufferWithAnalyzer
+     * @param correctOctaveJumps Whether to correct octave jumps (default: true)
+     * @param octaveWindow Frames whose median is the octave reference (default: 11)
+     * @param smoothing 0 = none, 1 = median, 2 = moving average (default: 1)
+     * @param smoothingWindow Frames in the smoothing window (default: 5)
+     * @param maxGapFrames Longest unvoiced run filled by interpolation (default: 5)
+     * @return Post-processed PitchTrackResult
+     * @throws RuntimeException if JNI call fails
+     */
+    fun postprocessPitchTrack(
+        track: PitchTrackResult,
+        correctOctaveJumps: Boolean = true,
+        octaveWindow: Int = 11,
+        smoothing: Int = 1,
+        smoothingWindow: Int = 5,
+        maxGapFrames: Int = 5
+    ): PitchTrackResult {
+        return try {
+            nativePostprocessPitchTrack(
+                track.pitchTrack,
+                track.voicedProbabilities,
+                track.timestamps,
+                correctOctaveJumps,
+                octaveWindow,
+                smoothing,
+                smoothingWindow,
+                maxGapFrames
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativePostprocessPitchTrack failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Summarizes a pitch track in Hz and semitones.
+     *
+     * Computed in Rust in double precision, so the same track gives the same
+     * statistics as on iOS.
+     *
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param voicedProbabilities Voiced probability per frame (same length)
+     * @param referenceHz Frequency of 0 semitones in Hz (default: 100)
+     * @param weightByVoicing Weight voiced frames by voiced probability (default: true)
+     * @return PitchStatisticsResult
+     * @throws RuntimeException if JNI call fails
+     */
+    fun pitchTrackStatistics(
+        pitchTrack: FloatArray,
+        voicedProbabilities: FloatArray,
+        referenceHz: Float = 100f,
+        weightByVoicing: Boolean = true
+    ): PitchStatisticsResult {
+        return try {
+            nativePitchTrackStatistics(pitchTrack, voicedProbabilities, referenceHz, weightByVoicing)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativePitchTrackStatistics failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Finds vibrato in a pitch track and measures its rate, extent and regularity.
+     *
+     * The last sliding window always ends at the last frame, so a live display
+     * can pass the recent frames of a stream and read the final window.
+     *
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param frameRate Pitch frames per second (sampleRate / hopSize)
+     * @param minRateHz Slowest vibrato rate in Hz (default: 4)
+     * @param maxRateHz Fastest vibrato rate in Hz (default: 8)
+     * @param minExtentCents Smallest vibrato extent in ± cents (default: 15)
+     * @param maxExtentCents Largest vibrato extent in ± cents (default: 200)
+     * @param minCycles Fewest full cycles in a segment (default: 2)
+     * @param windowSeconds Sliding window length in seconds (default: 1, 0 = no windows)
+     * @param windowHopFrames Frames between window ends (default: 1)
+     * @return VibratoResult
+     * @throws RuntimeException if JNI call fails
+     */
+    fun analyzeVibrato(
+        pitchTrack: FloatArray,
+        frameRate: Float,
+        minRateHz: Float = 4f,
+        maxRateHz: Float = 8f,
+        minExtentCents: Float = 15f,
+        maxExtentCents: Float = 200f,
+        minCycles: Int = 2,
+        windowSeconds: Float = 1f,
+        windowHopFrames: Int = 1
+    ): VibratoResult {
+        return try {
+            nativeAnalyzeVibrato(
+                pitchTrack,
+                frameRate,
+                minRateHz,
+                maxRateHz,
+                minExtentCents,
+                maxExtentCents,
+                minCycles,
+                windowSeconds,
+                windowHopFrames
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeAnalyzeVibrato failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Creates a streaming analyzer for pushes of any size.
+     *
+     * @param config VoiceAnalyzerConfig with sample rate, frequency range and framing
+     * @return Handle to the streaming analyzer (must be freed with freeStreamAnalyzer)
+     * @throws RuntimeException if creation fails
+     */
+    fun createStreamAnalyzer(config: VoiceAnalyzerConfig): Long {
+        return try {
+            nativeStreamAnalyzerNew(
+                config.sampleRate,
+                config.minFrequency,
+                config.maxFrequency,
+                config.frameSize,
+                config.hopSize
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeStreamAnalyzerNew failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Pushes samples into a streaming analyzer.
+     *
+     * Pushes to one handle must not overlap; each continues where the last ended.
+     *
+     * @param handle Handle from createStreamAnalyzer
+     * @param buffer Audio samples of any length (e.g. one microphone callback)
+     * @return StreamFramesResult with the frames the samples completed (possibly none)
+     * @throws RuntimeException if the push fails
+     */
+    fun pushToStreamAnalyzer(handle: Long, buffer: FloatArray): StreamFramesResult {
+        return try {
+            nativeStreamAnalyzerPush(handle, buffer)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeStreamAnalyzerPush failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Analyses the buffered tail of a stream and starts a new stream.
+     *
+     * @param handle Handle from createStreamAnalyzer
+     * @return StreamFramesResult with the zero-padded tail frames
+     * @throws RuntimeException if the flush fails
+     */
+    fun flushStreamAnalyzer(handle: Long): StreamFramesResult {
+        return try {
+            nativeStreamAnalyzerFlush(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeStreamAnalyzerFlush failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Discards the buffered samples of a stream and restarts timestamps at 0.
+     *
+     * @param handle Handle from createStreamAnalyzer
+     * @throws RuntimeException if reset fails
+     */
+    fun resetStreamAnalyzer(handle: Long) {
+        try {
+            nativeStreamAnalyzerReset(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeStreamAnalyzerReset failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Free a streaming analyzer.
+     *
+     * @param handle Handle from createStreamAnalyzer
+     */
+    fun freeStreamAnalyzer(handle: Long) {
+        try {
+            nativeStreamAnalyzerFree(handle)
+        } catch (e: Exception) {
+            // Log but don't throw - this is cleanup
+        }
+    }
+
+    /**
+     * Creates a tuner.
+     *
+     * @param config VoiceAnalyzerConfig with sample rate, frequency range and framing
+     * @param referenceHz Frequency of A4 in Hz
+     * @param temperamentCents Offsets of C to B from equal temperament in cents (12 values)
+     * @param stabilityFrames Frames over which stability is measured
+     * @return Handle to the tuner (must be freed with freeTuner)
+     * @throws RuntimeException if creation fails
+     */
+    fun createTuner(
+        config: VoiceAnalyzerConfig,
+        referenceHz: Float,
+        temperamentCents: FloatArray,
+        stabilityFrames: Int
+    ): Long {
+        return try {
+            nativeTunerNew(
+                config.sampleRate,
+                config.minFrequency,
+                config.maxFrequency,
+                config.frameSize,
+                config.hopSize,
+                referenceHz,
+                temperamentCents,
+                stabilityFrames
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeTunerNew failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Pushes samples into a tuner.
+     *
+     * Pushes to one handle must not overlap; each continues where the last ended.
+     *
+     * @param handle Handle from createTuner
+     * @param buffer Audio samples of any length (e.g. one microphone callback)
+     * @return TunerReading after the frames the samples completed
+     * @throws RuntimeException if the push fails
+     */
+    fun pushToTuner(handle: Long, buffer: FloatArray): TunerReading {
+        return try {
+            nativeTunerPush(handle, buffer)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeTunerPush failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Discards a tuner's buffered samples and reading.
+     *
+     * @param handle Handle from createTuner
+     * @throws RuntimeException if reset fails
+     */
+    fun resetTuner(handle: Long) {
+        try {
+            nativeTunerReset(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeTunerReset failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Free a tuner.
+     *
+     * @param handle Handle from createTuner
+     */
+    fun freeTuner(handle: Long) {
+        try {
+            nativeTunerFree(handle)
+        } catch (e: Exception) {
+            // Log but don't throw - this is cleanup
+        }
+    }
+
+    /**
+     * Creates an empty voice range profile.
+     *
+     * @param referenceHz Frequency of A4 in Hz
+     * @param minMidiNote MIDI note of the first row
+     * @param maxMidiNote MIDI note of the last row
+     * @param minDb Lower edge of the first level column in dB
+     * @param maxDb Upper end of the level range in dB
+     * @param dbStep Width of a level column in dB
+     * @param minCellFrames Frames a cell needs before it counts as sustained
+     * @return Handle to the profile (must be freed with freeVoiceRangeProfile)
+     * @throws RuntimeException if creation fails
+     */
+    fun createVoiceRangeProfile(
+        referenceHz: Float = 440f,
+        minMidiNote: Int = 24,
+        maxMidiNote: Int = 108,
+        minDb: Float = 40f,
+        maxDb: Float = 120f,
+        dbStep: Float = 1f,
+        minCellFrames: Int = 3
+    ): Long {
+        return try {
+            nativeVrpNew(referenceHz, minMidiNote, maxMidiNote, minDb, maxDb, dbStep, minCellFrames)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVrpNew failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Adds framewise pitch and intensity to a voice range profile.
+     *
+     * @param handle Handle from createVoiceRangeProfile
+     * @param pitchTrack Pitch per frame in Hz (0 = unvoiced)
+     * @param intensityDb Level per frame in dB, on the same frame grid
+     * @throws RuntimeException if the frames are invalid
+     */
+    fun addVoiceRangeFrames(handle: Long, pitchTrack: FloatArray, intensityDb: FloatArray) {
+        try {
+            nativeVrpAddFrames(handle, pitchTrack, intensityDb)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVrpAddFrames failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Adds the counts of one voice range profile to another.
+     *
+     * @param handle Handle of the target profile
+     * @param sourceHandle Handle of a profile created with the same configuration
+     * @throws RuntimeException if the configurations differ
+     */
+    fun mergeVoiceRangeProfiles(handle: Long, sourceHandle: Long) {
+        try {
+            nativeVrpMerge(handle, sourceHandle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVrpMerge failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Reads the grid and sustainable range of a voice range profile.
+     *
+     * @param handle Handle from createVoiceRangeProfile
+     * @return VoiceRangeProfileResult snapshot
+     * @throws RuntimeException if the handle is invalid
+     */
+    fun getVoiceRangeProfile(handle: Long): VoiceRangeProfileResult {
+        return try {
+            nativeVrpGet(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVrpGet failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Empties a voice range profile's grid.
+     *
+     * @param handle Handle from createVoiceRangeProfile
+     * @throws RuntimeException if reset fails
+     */
+    fun resetVoiceRangeProfile(handle: Long) {
+        try {
+            nativeVrpReset(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeVrpReset failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Free a voice range profile.
+     *
+     * @param handle Handle from createVoiceRangeProfile
+     */
+    fun freeVoiceRangeProfile(handle: Long) {
+        try {
+            nativeVrpFree(handle)
+        } catch (e: Exception) {
+            // Log but don't throw - this is cleanup
+        }
+    }
+
+    /**
+     * Creates a formant tracker with continuity of F1-F4 across frames.
+     *
+     * @param sampleRate Sample rate in Hz
+     * @param frameSize LPC window in samples (default: 25 ms)
+     * @param hopSize Samples between frame starts (default: 10 ms)
+     * @param lpcOrder LPC order (0 = from the sample rate)
+     * @param ceilingHz Highest formant frequency in Hz
+     * @param continuityWeight Weight of continuity against fit to neutral-vowel formants
+     * @param minConfidence Minimum mean F1-F3 confidence of a reliable frame
+     * @param silenceDb Frames below this RMS level in dBFS are unreliable
+     * @return Handle to the tracker (must be freed with freeFormantTracker)
+     * @throws RuntimeException if creation fails
+     */
+    fun createFormantTracker(
+        sampleRate: Int,
+        frameSize: Int = sampleRate / 40,
+        hopSize: Int = sampleRate / 100,
+        lpcOrder: Int = 0,
+        ceilingHz: Float = 5500f,
+        continuityWeight: Float = 1f,
+        minConfidence: Float = 0.3f,
+        silenceDb: Float = -50f
+    ): Long {
+        return try {
+            nativeFormantTrackerNew(
+                sampleRate,
+                frameSize,
+                hopSize,
+                lpcOrder,
+                ceilingHz,
+                continuityWeight,
+                minConfidence,
+                silenceDb
+            )
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeFormantTrackerNew failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Tracks F1-F4 over the frames of a buffer, continuing from the previous one.
+     *
+     * @param handle Handle from createFormantTracker
+     * @param buffer Audio samples; must be longer than frameSize
+     * @return FormantTrackResult with per-frame formants and reliability
+     * @throws RuntimeException if the buffer is invalid
+     */
+    fun trackFormants(handle: Long, buffer: FloatArray): FormantTrackResult {
+        return try {
+            nativeFormantTrackerProcessBuffer(handle, buffer)
+        } catch (e: Exception) {
+            throw RuntimeException(
+                "JNI call to nativeFormantTrackerProcessBuffer failed: ${e.message}",
+                e
+            )
+        }
+    }
+
+    /**
+     * Makes a formant tracker forget the previous buffer.
+     *
+     * @param handle Handle from createFormantTracker
+     * @throws RuntimeException if reset fails
+     */
+    fun resetFormantTracker(handle: Long) {
+        try {
+            nativeFormantTrackerReset(handle)
+        } catch (e: Exception) {
+            throw RuntimeException("JNI call to nativeFormantTrackerReset failed: ${e.message}", e)
+        }
+    }
+
+    /**
+     * Free a formant tracker.
+     *
+     * @param handle Handle from createFormantTracker
+     */
+    fun freeFormantTracker(handle: Long) {
+        try {
+            nativeFormantTrackerFree(handle)
+        } catch (e: Exception) {
+            // Log but don't throw - this is cleanup
         }
     }
 }
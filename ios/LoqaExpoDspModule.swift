@@ -7,13 +7,20 @@ public class LoqaExpoDspModule: Module {
     // Module name that JavaScript will use to require this module
     Name("LoqaExpoDsp")
 
+    // Forward Rust diagnostics (validation errors, caught panics) to os_log
+    OnCreate {
+      installRustLogging()
+    }
+
     // MARK: - computeFFT
-    // Calls Rust FFT via RustBridge.computeFFTWrapper()
+    // Calls Rust windowed FFT via RustBridge.computeFFTExWrapper()
     AsyncFunction("computeFFT") { (buffer: [Float], options: [String: Any], promise: Promise) in
       do {
         // Extract options with defaults
         let fftSize = options["fftSize"] as? Int ?? buffer.count
         let sampleRate = options["sampleRate"] as? Int ?? 44100
+        let windowParameter = (options["windowParameter"] as? Double).map { Float($0) } ?? 0
+        let includePhase = options["includePhase"] as? Bool ?? false
 
         // Validate inputs
         guard !buffer.isEmpty else {
@@ -31,18 +38,48 @@ public class LoqaExpoDspModule: Module {
           return
         }
 
-        // Call Rust FFT function via wrapper (v0.4.0 API)
-        let (magnitude, frequencies) = try computeFFTWrapper(
+        let window: FFTWindow
+        switch options["windowType"] as? String ?? "hanning" {
+        case "none": window = .none
+        case "hanning": window = .hanning
+        case "hamming": window = .hamming
+        case "blackman": window = .blackman
+        case "kaiser": window = .kaiser
+        case "flattop": window = .flatTop
+        case "gaussian": window = .gaussian
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid window type '\(other)'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+          return
+        }
+
+        let normalization: FFTNormalization
+        switch options["normalization"] as? String ?? "amplitude" {
+        case "amplitude": normalization = .amplitude
+        case "psd": normalization = .psd
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid normalization '\(other)'. Must be one of: amplitude, psd")
+          return
+        }
+
+        // Call Rust windowed FFT via wrapper
+        let spectrum = try computeFFTExWrapper(
           buffer: buffer,
           sampleRate: sampleRate,
-          fftSize: fftSize
+          fftSize: fftSize,
+          window: window,
+          windowParameter: windowParameter,
+          normalization: normalization,
+          includePhase: includePhase
         )
 
-        // Return result dictionary
-        let result: [String: Any] = [
-          "magnitude": magnitude,
-          "frequencies": frequencies
+        // Return result dictionary (phase only when requested)
+        var result: [String: Any] = [
+          "magnitude": spectrum.magnitudes,
+          "frequencies": spectrum.frequencies
         ]
+        if let phases = spectrum.phases {
+          result["phase"] = phases
+        }
 
         promise.resolve(result)
       } catch let error as RustFFIError {
@@ -61,6 +98,242 @@ public class LoqaExpoDspModule: Module {
       }
     }
 
+    // MARK: - computeComplexFFT
+    // Calls Rust complex FFT via RustBridge.computeComplexFFTWrapper()
+    AsyncFunction("computeComplexFFT") { (buffer: [Float], options: [String: Any], promise: Promise) in
+      do {
+        let fftSize = options["fftSize"] as? Int ?? 0
+
+        let spectrum = try computeComplexFFTWrapper(buffer: buffer, fftSize: fftSize)
+
+        promise.resolve([
+          "bins": spectrum.bins,
+          "fftSize": spectrum.fftSize
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("FFT_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("FFT_ERROR", "Memory allocation failed in Rust FFT computation")
+        }
+      } catch {
+        promise.reject("FFT_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - inverseFFT
+    // Calls Rust inverse FFT via RustBridge.inverseFFTWrapper()
+    AsyncFunction("inverseFFT") { (bins: [Float], promise: Promise) in
+      do {
+        let samples = try inverseFFTWrapper(bins: bins)
+        promise.resolve(samples)
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("FFT_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("FFT_ERROR", "Memory allocation failed in Rust inverse FFT computation")
+        }
+      } catch {
+        promise.reject("FFT_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - computeSpectrogram
+    // Calls Rust STFT via RustBridge.computeSpectrogramWrapper()
+    AsyncFunction("computeSpectrogram") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        let frameSize = options["frameSize"] as? Int ?? 2048
+        let hopSize = options["hopSize"] as? Int ?? 512
+        let windowParameter = (options["windowParameter"] as? Double).map { Float($0) } ?? 0
+        let dbFloor = (options["dbFloor"] as? Double).map { Float($0) } ?? -120
+
+        let window: FFTWindow
+        switch options["windowType"] as? String ?? "hanning" {
+        case "none": window = .none
+        case "hanning": window = .hanning
+        case "hamming": window = .hamming
+        case "blackman": window = .blackman
+        case "kaiser": window = .kaiser
+        case "flattop": window = .flatTop
+        case "gaussian": window = .gaussian
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid window type '\(other)'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+          return
+        }
+
+        let scaling: SpectrogramScaling
+        switch options["scaling"] as? String ?? "db" {
+        case "linear": scaling = .linear
+        case "power": scaling = .power
+        case "db": scaling = .decibels
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid scaling '\(other)'. Must be one of: linear, power, db")
+          return
+        }
+
+        let spectrogram = try computeSpectrogramWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          frameSize: frameSize,
+          hopSize: hopSize,
+          window: window,
+          windowParameter: windowParameter,
+          scaling: scaling,
+          dbFloor: dbFloor
+        )
+
+        promise.resolve([
+          "values": spectrogram.values,
+          "timestamps": spectrogram.timestamps,
+          "frameCount": spectrogram.frameCount,
+          "binCount": spectrogram.binCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("SPECTROGRAM_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("SPECTROGRAM_ERROR", "Memory allocation failed in Rust spectrogram computation")
+        }
+      } catch {
+        promise.reject("SPECTROGRAM_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - computeMelSpectrogram
+    // Calls Rust mel filterbank via RustBridge.computeLogMelSpectrogramWrapper()
+    AsyncFunction("computeMelSpectrogram") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var melOptions = MelFilterbankOptions()
+        melOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        melOptions.hopSize = options["hopSize"] as? Int ?? 512
+        melOptions.windowParameter = (options["windowParameter"] as? Double).map { Float($0) } ?? 0
+        melOptions.nMels = options["nMels"] as? Int ?? 40
+        melOptions.fmin = (options["fmin"] as? Double).map { Float($0) } ?? 0
+        melOptions.fmax = (options["fmax"] as? Double).map { Float($0) } ?? 0
+
+        switch options["windowType"] as? String ?? "hanning" {
+        case "none": melOptions.window = .none
+        case "hanning": melOptions.window = .hanning
+        case "hamming": melOptions.window = .hamming
+        case "blackman": melOptions.window = .blackman
+        case "kaiser": melOptions.window = .kaiser
+        case "flattop": melOptions.window = .flatTop
+        case "gaussian": melOptions.window = .gaussian
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid window type '\(other)'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+          return
+        }
+
+        switch options["melScale"] as? String ?? "slaney" {
+        case "htk": melOptions.scale = .htk
+        case "slaney": melOptions.scale = .slaney
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid mel scale '\(other)'. Must be one of: htk, slaney")
+          return
+        }
+
+        let features = try computeLogMelSpectrogramWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          options: melOptions
+        )
+
+        promise.resolve([
+          "values": features.values,
+          "timestamps": features.timestamps,
+          "frameCount": features.frameCount,
+          "featureCount": features.featureCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("MEL_SPECTROGRAM_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("MEL_SPECTROGRAM_ERROR", "Memory allocation failed in Rust log-mel spectrogram computation")
+        }
+      } catch {
+        promise.reject("MEL_SPECTROGRAM_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - computeMFCC
+    // Calls Rust MFCC extraction via RustBridge.computeMFCCWrapper()
+    AsyncFunction("computeMFCC") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var melOptions = MelFilterbankOptions()
+        melOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        melOptions.hopSize = options["hopSize"] as? Int ?? 512
+        melOptions.windowParameter = (options["windowParameter"] as? Double).map { Float($0) } ?? 0
+        melOptions.nMels = options["nMels"] as? Int ?? 40
+        melOptions.fmin = (options["fmin"] as? Double).map { Float($0) } ?? 0
+        melOptions.fmax = (options["fmax"] as? Double).map { Float($0) } ?? 0
+        let nMfcc = options["nMfcc"] as? Int ?? 13
+        let lifter = (options["lifter"] as? Double).map { Float($0) } ?? 0
+        let deltaOrder = options["deltaOrder"] as? Int ?? 0
+        let deltaWidth = options["deltaWidth"] as? Int ?? 2
+
+        switch options["windowType"] as? String ?? "hanning" {
+        case "none": melOptions.window = .none
+        case "hanning": melOptions.window = .hanning
+        case "hamming": melOptions.window = .hamming
+        case "blackman": melOptions.window = .blackman
+        case "kaiser": melOptions.window = .kaiser
+        case "flattop": melOptions.window = .flatTop
+        case "gaussian": melOptions.window = .gaussian
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid window type '\(other)'. Must be one of: none, hanning, hamming, blackman, kaiser, flattop, gaussian")
+          return
+        }
+
+        switch options["melScale"] as? String ?? "slaney" {
+        case "htk": melOptions.scale = .htk
+        case "slaney": melOptions.scale = .slaney
+        case let other:
+          promise.reject("VALIDATION_ERROR", "Invalid mel scale '\(other)'. Must be one of: htk, slaney")
+          return
+        }
+
+        let features = try computeMFCCWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          options: melOptions,
+          nMfcc: nMfcc,
+          lifter: lifter,
+          deltaOrder: deltaOrder,
+          deltaWidth: deltaWidth
+        )
+
+        promise.resolve([
+          "values": features.values,
+          "timestamps": features.timestamps,
+          "frameCount": features.frameCount,
+          "featureCount": features.featureCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("MFCC_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("MFCC_ERROR", "Memory allocation failed in Rust MFCC computation")
+        }
+      } catch {
+        promise.reject("MFCC_ERROR", error.localizedDescription)
+      }
+    }
+
     // MARK: - detectPitch
     // Calls Rust pYIN pitch detection via RustBridge.detectPitchWrapper()
     // v0.4.0: Now supports min/max frequency and returns voicedProbability
@@ -69,6 +342,18 @@ public class LoqaExpoDspModule: Module {
         // Extract options with defaults for human voice
         let minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 80.0
         let maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 400.0
+        var algorithm: PitchAlgorithm? = nil
+        if let name = options["algorithm"] as? String {
+          switch name {
+          case "auto": algorithm = .auto
+          case "pyin": algorithm = .pyin
+          case "yin": algorithm = .yin
+          case "autocorr": algorithm = .autocorr
+          default:
+            promise.reject("VALIDATION_ERROR", "Unknown pitch algorithm '\(name)'")
+            return
+          }
+        }
 
         // Validate inputs (basic validation - detailed validation in RustBridge)
         guard !buffer.isEmpty else {
@@ -86,7 +371,8 @@ public class LoqaExpoDspModule: Module {
           buffer: buffer,
           sampleRate: sampleRate,
           minFrequency: minFrequency,
-          maxFrequency: maxFrequency
+          maxFrequency: maxFrequency,
+          algorithm: algorithm
         )
 
         // Build result dictionary matching PitchResult type
@@ -117,7 +403,6 @@ public class LoqaExpoDspModule: Module {
 
     // MARK: - extractFormants
     // Calls Rust LPC formant extraction via RustBridge.extractFormantsWrapper()
-    // v0.4.0: Now returns confidence instead of bandwidths
     AsyncFunction("extractFormants") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
       do {
         // Extract optional LPC order from options
@@ -135,19 +420,26 @@ public class LoqaExpoDspModule: Module {
         }
 
         // Call Rust formant extraction via wrapper (v0.4.0 API)
-        let (f1, f2, f3, confidence) = try extractFormantsWrapper(
+        let formants = try extractFormantsWrapper(
           buffer: buffer,
           sampleRate: sampleRate,
           lpcOrder: lpcOrder ?? 12
         )
 
         // Build result dictionary matching FormantsResult type
-        // v0.4.0 BREAKING CHANGE: confidence replaces bandwidths
         let result: [String: Any] = [
-          "f1": f1,
-          "f2": f2,
-          "f3": f3,
-          "confidence": confidence
+          "f1": formants.f1,
+          "f2": formants.f2,
+          "f3": formants.f3,
+          "f4": formants.f4,
+          "f5": formants.f5,
+          "b1": formants.bandwidths[0],
+          "b2": formants.bandwidths[1],
+          "b3": formants.bandwidths[2],
+          "b4": formants.bandwidths[3],
+          "b5": formants.bandwidths[4],
+          "confidence": formants.confidence,
+          "confidences": formants.confidences
         ]
 
         promise.resolve(result)
@@ -168,9 +460,15 @@ public class LoqaExpoDspModule: Module {
     }
 
     // MARK: - analyzeSpectrum
-    // Calls Rust spectral analysis via RustBridge.analyzeSpectrumWrapper()
+    // Calls Rust spectral analysis via RustBridge.analyzeSpectrumExWrapper()
     AsyncFunction("analyzeSpectrum") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
       do {
+        // Extract options with defaults (0 = FFT size fitted to the buffer)
+        let fftSize = options["fftSize"] as? Int ?? 0
+        let averageFrames = options["averageFrames"] as? Bool ?? true
+        let rolloffLowThreshold = (options["rolloffLowThreshold"] as? Double).map { Float($0) } ?? 0.85
+        let rolloffHighThreshold = (options["rolloffHighThreshold"] as? Double).map { Float($0) } ?? 0.95
+
         // Validate inputs (basic validation - detailed validation in RustBridge)
         guard !buffer.isEmpty else {
           promise.reject("VALIDATION_ERROR", "Buffer cannot be empty")
@@ -183,16 +481,28 @@ public class LoqaExpoDspModule: Module {
         }
 
         // Call Rust spectral analysis via wrapper
-        let (centroid, rolloff, tilt) = try analyzeSpectrumWrapper(
+        let features = try analyzeSpectrumExWrapper(
           buffer: buffer,
-          sampleRate: sampleRate
+          sampleRate: sampleRate,
+          fftSize: fftSize,
+          averageFrames: averageFrames,
+          rolloffLowThreshold: rolloffLowThreshold,
+          rolloffHighThreshold: rolloffHighThreshold
         )
 
         // Build result dictionary matching SpectrumResult type
         let result: [String: Any] = [
-          "centroid": centroid,
-          "rolloff": rolloff,
-          "tilt": tilt
+          "centroid": features.centroid,
+          "rolloff": features.rolloffHigh,
+          "tilt": features.tilt,
+          "spread": features.spread,
+          "skewness": features.skewness,
+          "kurtosis": features.kurtosis,
+          "flatness": features.flatness,
+          "entropy": features.entropy,
+          "crestFactor": features.crestFactor,
+          "rolloffLow": features.rolloffLow,
+          "rolloffHigh": features.rolloffHigh
         ]
 
         promise.resolve(result)
@@ -313,6 +623,248 @@ public class LoqaExpoDspModule: Module {
       }
     }
 
+    // MARK: - calculateJitter (Praat-compatible jitter from the voiced periods of a buffer)
+    // Calls Rust jitter calculation via RustBridge.calculateJitterWrapper()
+    AsyncFunction("calculateJitter") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        let minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 75.0
+        let maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 500.0
+
+        let result = try calculateJitterWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          minFrequency: minFrequency,
+          maxFrequency: maxFrequency
+        )
+
+        // Undefined measures are sent as null
+        promise.resolve([
+          "local": result.local ?? NSNull(),
+          "localAbsolute": result.localAbsolute ?? NSNull(),
+          "rap": result.rap ?? NSNull(),
+          "ppq5": result.ppq5 ?? NSNull(),
+          "ddp": result.ddp ?? NSNull(),
+          "meanPeriod": result.meanPeriod ?? NSNull(),
+          "periodCount": result.periodCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("JITTER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("JITTER_ERROR", "Memory allocation failed in Rust jitter calculation")
+        }
+      } catch {
+        promise.reject("JITTER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - calculateShimmer (Praat-compatible shimmer from the voiced periods of a buffer)
+    // Calls Rust shimmer calculation via RustBridge.calculateShimmerWrapper()
+    AsyncFunction("calculateShimmer") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        let minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 75.0
+        let maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 500.0
+
+        let result = try calculateShimmerWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          minFrequency: minFrequency,
+          maxFrequency: maxFrequency
+        )
+
+        // Undefined measures are sent as null
+        promise.resolve([
+          "local": result.local ?? NSNull(),
+          "localDb": result.localDb ?? NSNull(),
+          "apq3": result.apq3 ?? NSNull(),
+          "apq5": result.apq5 ?? NSNull(),
+          "apq11": result.apq11 ?? NSNull(),
+          "dda": result.dda ?? NSNull(),
+          "meanAmplitude": result.meanAmplitude ?? NSNull(),
+          "periodCount": result.periodCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("SHIMMER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("SHIMMER_ERROR", "Memory allocation failed in Rust shimmer calculation")
+        }
+      } catch {
+        promise.reject("SHIMMER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - calculateCPP (cepstral peak prominence, CPP and smoothed CPPS)
+    // Calls Rust CPP calculation via RustBridge.calculateCPPWrapper()
+    AsyncFunction("calculateCPP") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var cppOptions = CppOptions()
+        cppOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        cppOptions.hopSize = options["hopSize"] as? Int ?? 128
+        cppOptions.minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 60
+        cppOptions.maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 330
+        cppOptions.timeSmoothing = (options["timeSmoothing"] as? Double).map { Float($0) } ?? 0.02
+        cppOptions.quefrencySmoothing =
+          (options["quefrencySmoothing"] as? Double).map { Float($0) } ?? 0.0005
+
+        let result = try calculateCPPWrapper(buffer: buffer, sampleRate: sampleRate, options: cppOptions)
+
+        promise.resolve([
+          "cpp": result.cpp,
+          "cpps": result.cpps,
+          "f0": result.f0,
+          "frameCount": result.frameCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("CPP_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("CPP_ERROR", "Memory allocation failed in Rust CPP calculation")
+        }
+      } catch {
+        promise.reject("CPP_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - computeIntensity (framewise RMS, peak and calibrated intensity contours)
+    // Calls Rust intensity computation via RustBridge.computeIntensityWrapper()
+    AsyncFunction("computeIntensity") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var intensityOptions = IntensityOptions()
+        intensityOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        intensityOptions.hopSize = options["hopSize"] as? Int ?? 512
+        intensityOptions.calibrationOffset =
+          (options["calibrationOffset"] as? Double).map { Float($0) } ?? 0
+        intensityOptions.floorDb = (options["floorDb"] as? Double).map { Float($0) } ?? -120
+
+        let result = try computeIntensityWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          options: intensityOptions
+        )
+
+        promise.resolve([
+          "rms": result.rms,
+          "intensityDb": result.intensityDb,
+          "peakDb": result.peakDb,
+          "timestamps": result.timestamps,
+          "meanDb": result.meanDb,
+          "minDb": result.minDb,
+          "maxDb": result.maxDb,
+          "dynamicRangeDb": result.dynamicRangeDb
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("INTENSITY_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("INTENSITY_ERROR", "Memory allocation failed in Rust intensity computation")
+        }
+      } catch {
+        promise.reject("INTENSITY_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - detectVoiceActivity (energy, zero-crossing rate and pYIN voicing)
+    // Calls Rust voice activity detection via RustBridge.detectVoiceActivityWrapper()
+    AsyncFunction("detectVoiceActivity") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var vadOptions = VadOptions()
+        vadOptions.minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 75
+        vadOptions.maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 500
+        vadOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        vadOptions.hopSize = options["hopSize"] as? Int ?? 512
+        vadOptions.energyThresholdDb =
+          (options["energyThresholdDb"] as? Double).map { Float($0) } ?? -50
+        vadOptions.zcrThreshold = (options["zcrThreshold"] as? Double).map { Float($0) } ?? 3000
+        vadOptions.voicingThreshold =
+          (options["voicingThreshold"] as? Double).map { Float($0) } ?? 0.5
+        vadOptions.hangoverTime = (options["hangoverTime"] as? Double).map { Float($0) } ?? 0.2
+
+        let result = try detectVoiceActivityWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          options: vadOptions
+        )
+
+        promise.resolve([
+          "timestamps": result.timestamps,
+          "energyDb": result.energyDb,
+          "zcr": result.zcr,
+          "voicedProbabilities": result.voicedProbabilities,
+          "isSpeech": result.isSpeech,
+          "segments": result.segments.map { ["start": $0.start, "end": $0.end] }
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VAD_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VAD_ERROR", "Memory allocation failed in Rust voice activity detection")
+        }
+      } catch {
+        promise.reject("VAD_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - estimateVocalTractLength (formant spacing and uniform-tube regression)
+    // Calls Rust VTL estimation via RustBridge.estimateVocalTractLengthWrapper()
+    AsyncFunction("estimateVocalTractLength") { (buffer: [Float], sampleRate: Int, options: [String: Any], promise: Promise) in
+      do {
+        var vtlOptions = VtlOptions()
+        vtlOptions.minFrequency = (options["minFrequency"] as? Double).map { Float($0) } ?? 75
+        vtlOptions.maxFrequency = (options["maxFrequency"] as? Double).map { Float($0) } ?? 500
+        vtlOptions.frameSize = options["frameSize"] as? Int ?? 2048
+        vtlOptions.hopSize = options["hopSize"] as? Int ?? 512
+        vtlOptions.lpcOrder = options["lpcOrder"] as? Int ?? 0
+        vtlOptions.minFormantConfidence =
+          (options["minFormantConfidence"] as? Double).map { Float($0) } ?? 0.3
+        vtlOptions.speedOfSound = (options["speedOfSound"] as? Double).map { Float($0) } ?? 35000
+
+        let result = try estimateVocalTractLengthWrapper(
+          buffer: buffer,
+          sampleRate: sampleRate,
+          options: vtlOptions
+        )
+
+        promise.resolve([
+          "vtlCm": result.vtlCm,
+          "spacingVtlCm": result.spacingVtlCm,
+          "formantSpacingHz": result.formantSpacingHz,
+          "f1": result.f1,
+          "f2": result.f2,
+          "f3": result.f3,
+          "f4": result.f4,
+          "confidence": result.confidence,
+          "frameCount": result.frameCount
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VTL_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VTL_ERROR", "Memory allocation failed in Rust vocal tract length estimation")
+        }
+      } catch {
+        promise.reject("VTL_ERROR", error.localizedDescription)
+      }
+    }
+
     // MARK: - VoiceAnalyzer Streaming API (v0.3.0)
     // Stateful pitch tracking with HMM smoothing for analyzing longer audio clips
 
@@ -401,7 +953,6 @@ public class LoqaExpoDspModule: Module {
 
         // Calculate aggregate statistics
         let voicedFrames = frames.filter { $0.isVoiced }
-        let voicedFrequencies = voicedFrames.compactMap { $0.frequency > 0 ? $0.frequency : nil }
 
         // Convert frames to dictionaries
         let frameDicts: [[String: Any]] = frames.map { frame in
@@ -413,24 +964,16 @@ public class LoqaExpoDspModule: Module {
           ]
         }
 
-        // Calculate statistics
-        let medianPitch: Float? = voicedFrequencies.isEmpty ? nil : {
-          let sorted = voicedFrequencies.sorted()
-          let mid = sorted.count / 2
-          if sorted.count % 2 == 0 {
-            return (sorted[mid - 1] + sorted[mid]) / 2
-          } else {
-            return sorted[mid]
-          }
-        }()
-
-        let meanPitch: Float? = voicedFrequencies.isEmpty ? nil : voicedFrequencies.reduce(0, +) / Float(voicedFrequencies.count)
-
-        let pitchStdDev: Float? = voicedFrequencies.count < 2 ? nil : {
-          guard let mean = meanPitch else { return nil }
-          let variance = voicedFrequencies.reduce(0) { $0 + pow($1 - mean, 2) } / Float(voicedFrequencies.count)
-          return sqrt(variance)
-        }()
+        // Unweighted statistics over the voiced frames, computed in Rust; the
+        // population std is only reported from two voiced frames on
+        let stats: PitchStatistics? = frames.isEmpty ? nil : try pitchTrackStatisticsWrapper(
+          pitchTrack: frames.map { $0.isVoiced ? max($0.frequency, 0) : 0 },
+          voicedProbabilities: frames.map { $0.voicedProbability },
+          weightByVoicing: false
+        )
+        let medianPitch = stats?.medianHz
+        let meanPitch = stats?.meanHz
+        let pitchStdDev = (stats?.voicedFrameCount ?? 0) < 2 ? nil : stats?.stdHz
 
         let meanConfidence: Float? = voicedFrames.isEmpty ? nil : voicedFrames.reduce(0) { $0 + $1.confidence } / Float(voicedFrames.count)
 
@@ -482,38 +1025,7 @@ public class LoqaExpoDspModule: Module {
 
         // Process buffer with Viterbi decoding
         let track = try processBufferWithAnalyzer(analyzer: handle, samples: buffer)
-
-        // Calculate aggregate statistics
-        let voicedPitches = track.pitchTrack.filter { $0 > 0 }
-        let voicedCount = voicedPitches.count
-
-        // Median pitch
-        let medianPitch: Float? = voicedPitches.isEmpty ? nil : {
-          let sorted = voicedPitches.sorted()
-          let mid = sorted.count / 2
-          if sorted.count % 2 == 0 {
-            return (sorted[mid - 1] + sorted[mid]) / 2
-          } else {
-            return sorted[mid]
-          }
-        }()
-
-        // Mean pitch
-        let meanPitch: Float? = voicedPitches.isEmpty ? nil :
-            voicedPitches.reduce(0, +) / Float(voicedPitches.count)
-
-        // Build result
-        let result: [String: Any] = [
-          "pitchTrack": track.pitchTrack,
-          "voicedProbabilities": track.voicedProbabilities,
-          "timestamps": track.timestamps,
-          "frameCount": track.pitchTrack.count,
-          "voicedFrameCount": voicedCount,
-          "medianPitch": medianPitch ?? NSNull(),
-          "meanPitch": meanPitch ?? NSNull()
-        ]
-
-        promise.resolve(result)
+        promise.resolve(try LoqaExpoDspModule.pitchTrackToDictionary(track))
       } catch let error as RustFFIError {
         switch error {
         case .invalidInput(let message):
@@ -528,48 +1040,548 @@ public class LoqaExpoDspModule: Module {
       }
     }
 
-    // MARK: - resetVoiceAnalyzer
-    // Reset analyzer state for reuse with new audio
-    AsyncFunction("resetVoiceAnalyzer") { (analyzerId: String, promise: Promise) in
+    // MARK: - postprocessPitchTrack
+    // Octave-jump correction, gap interpolation and smoothing of a pitch track;
+    // voiced probabilities and timestamps are returned unchanged
+    AsyncFunction("postprocessPitchTrack") { (pitchTrack: [Float], voicedProbabilities: [Float], timestamps: [Float], options: [String: Any], promise: Promise) in
       do {
-        guard let handle = LoqaExpoDspModule.getAnalyzer(id: analyzerId) else {
-          promise.reject("VALIDATION_ERROR", "Invalid analyzer ID: \(analyzerId)")
-          return
+        let track = try postprocessPitchTrackWrapper(
+          track: PitchTrack(
+            pitchTrack: pitchTrack,
+            voicedProbabilities: voicedProbabilities,
+            timestamps: timestamps
+          ),
+          correctOctaveJumps: options["correctOctaveJumps"] as? Bool ?? true,
+          octaveWindow: options["octaveWindow"] as? Int ?? 11,
+          smoothing: options["smoothing"] as? Int ?? 1,
+          smoothingWindow: options["smoothingWindow"] as? Int ?? 5,
+          maxGapFrames: options["maxGapFrames"] as? Int ?? 5
+        )
+        promise.resolve(try LoqaExpoDspModule.pitchTrackToDictionary(track))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("PITCH_TRACK_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("PITCH_TRACK_ERROR", "Memory allocation failed during pitch track post-processing")
         }
+      } catch {
+        promise.reject("PITCH_TRACK_ERROR", error.localizedDescription)
+      }
+    }
 
-        try resetVoiceAnalyzerWrapper(analyzer: handle)
-        promise.resolve(nil)
+    // MARK: - computePitchStatistics
+    // Hz and semitone statistics of a pitch track, weighted by voiced probability;
+    // computed in Rust so Android returns identical values
+    AsyncFunction("computePitchStatistics") { (pitchTrack: [Float], voicedProbabilities: [Float], options: [String: Any], promise: Promise) in
+      do {
+        let stats = try pitchTrackStatisticsWrapper(
+          pitchTrack: pitchTrack,
+          voicedProbabilities: voicedProbabilities,
+          referenceHz: (options["referenceHz"] as? Double).map { Float($0) } ?? 100,
+          weightByVoicing: options["weightByVoicing"] as? Bool ?? true
+        )
+        promise.resolve(LoqaExpoDspModule.pitchStatisticsToDictionary(stats))
       } catch let error as RustFFIError {
         switch error {
         case .invalidInput(let message):
           promise.reject("VALIDATION_ERROR", message)
         case .computationFailed(let message):
-          promise.reject("ANALYZER_ERROR", message)
+          promise.reject("PITCH_TRACK_ERROR", message)
         case .memoryAllocationFailed:
-          promise.reject("ANALYZER_ERROR", "Memory allocation failed during reset")
+          promise.reject("PITCH_TRACK_ERROR", "Memory allocation failed during pitch track statistics")
         }
       } catch {
-        promise.reject("ANALYZER_ERROR", error.localizedDescription)
+        promise.reject("PITCH_TRACK_ERROR", error.localizedDescription)
       }
     }
 
-    // MARK: - freeVoiceAnalyzer
-    // Explicitly free an analyzer (also freed on module unload)
-    AsyncFunction("freeVoiceAnalyzer") { (analyzerId: String, promise: Promise) in
-      if LoqaExpoDspModule.removeAnalyzer(id: analyzerId) {
-        promise.resolve(nil)
+    // MARK: - analyzeVibrato
+    // Vibrato rate, extent, cycle regularity and onset of a pitch track, plus
+    // sliding windows whose last one ends at the last frame for live display
+    AsyncFunction("analyzeVibrato") { (pitchTrack: [Float], options: [String: Any], promise: Promise) in
+      guard let frameRate = (options["frameRate"] as? Double).map({ Float($0) }) else {
+        promise.reject("VALIDATION_ERROR", "frameRate is required")
+        return
+      }
+
+      do {
+        var vibratoOptions = VibratoOptions()
+        vibratoOptions.minRateHz = (options["minRateHz"] as? Double).map { Float($0) } ?? 4
+        vibratoOptions.maxRateHz = (options["maxRateHz"] as? Double).map { Float($0) } ?? 8
+        vibratoOptions.minExtentCents = (options["minExtentCents"] as? Double).map { Float($0) } ?? 15
+        vibratoOptions.maxExtentCents = (options["maxExtentCents"] as? Double).map { Float($0) } ?? 200
+        vibratoOptions.minCycles = options["minCycles"] as? Int ?? 2
+        vibratoOptions.windowSeconds = (options["windowSeconds"] as? Double).map { Float($0) } ?? 1
+        vibratoOptions.windowHopFrames = options["windowHopFrames"] as? Int ?? 1
+
+        let result = try analyzeVibratoWrapper(
+          pitchTrack: pitchTrack,
+          frameRate: frameRate,
+          options: vibratoOptions
+        )
+        promise.resolve(LoqaExpoDspModule.vibratoToDictionary(result))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VIBRATO_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VIBRATO_ERROR", "Memory allocation failed during vibrato analysis")
+        }
+      } catch {
+        promise.reject("VIBRATO_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - pushSamples
+    // Push a chunk of live audio (any size) through the analyzer's stream.
+    // Samples are buffered in Rust between pushes, so frames start every hopSize
+    // samples and timestamps run on from the previous push. The stream is created
+    // on the first push; resetVoiceAnalyzer and flushVoiceAnalyzer restart it.
+    AsyncFunction("pushSamples") { (analyzerId: String, buffer: [Float], promise: Promise) in
+      do {
+        guard let stream = try LoqaExpoDspModule.getStream(id: analyzerId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid analyzer ID: \(analyzerId)")
+          return
+        }
+
+        let frames = try pushToStreamAnalyzer(analyzer: stream, samples: buffer)
+        promise.resolve(LoqaExpoDspModule.streamFramesToDictionary(frames))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("ANALYZER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("ANALYZER_ERROR", "Memory allocation failed during streaming push")
+        }
+      } catch {
+        promise.reject("ANALYZER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - flushVoiceAnalyzer
+    // Analyse the buffered tail of the analyzer's stream and restart it at timestamp 0
+    AsyncFunction("flushVoiceAnalyzer") { (analyzerId: String, promise: Promise) in
+      do {
+        guard let stream = try LoqaExpoDspModule.getStream(id: analyzerId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid analyzer ID: \(analyzerId)")
+          return
+        }
+
+        let frames = try flushStreamAnalyzerWrapper(analyzer: stream)
+        promise.resolve(LoqaExpoDspModule.streamFramesToDictionary(frames))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("ANALYZER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("ANALYZER_ERROR", "Memory allocation failed during streaming flush")
+        }
+      } catch {
+        promise.reject("ANALYZER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - resetVoiceAnalyzer
+    // Reset analyzer state for reuse with new audio
+    AsyncFunction("resetVoiceAnalyzer") { (analyzerId: String, promise: Promise) in
+      do {
+        guard let handle = LoqaExpoDspModule.getAnalyzer(id: analyzerId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid analyzer ID: \(analyzerId)")
+          return
+        }
+
+        try resetVoiceAnalyzerWrapper(analyzer: handle)
+        LoqaExpoDspModule.resetStream(id: analyzerId)
+        promise.resolve(nil)
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("ANALYZER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("ANALYZER_ERROR", "Memory allocation failed during reset")
+        }
+      } catch {
+        promise.reject("ANALYZER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - freeVoiceAnalyzer
+    // Explicitly free an analyzer (also freed on module unload)
+    AsyncFunction("freeVoiceAnalyzer") { (analyzerId: String, promise: Promise) in
+      if LoqaExpoDspModule.removeAnalyzer(id: analyzerId) {
+        promise.resolve(nil)
       } else {
         promise.reject("VALIDATION_ERROR", "Invalid analyzer ID: \(analyzerId)")
       }
     }
+
+    // MARK: - Tuner API
+    // Nearest note, cents deviation and stability of live audio pushed in chunks of any size
+
+    // MARK: - createTuner
+    AsyncFunction("createTuner") { (config: [String: Any], promise: Promise) in
+      do {
+        guard let sampleRate = config["sampleRate"] as? Int else {
+          promise.reject("VALIDATION_ERROR", "sampleRate is required")
+          return
+        }
+        let analyzerConfig = VoiceAnalyzerConfig(
+          sampleRate: UInt32(clamping: sampleRate),
+          minFrequency: (config["minFrequency"] as? Double).map { Float($0) } ?? 60.0,
+          maxFrequency: (config["maxFrequency"] as? Double).map { Float($0) } ?? 1500.0,
+          frameSize: UInt32(clamping: config["frameSize"] as? Int ?? 2048),
+          hopSize: UInt32(clamping: config["hopSize"] as? Int ?? 512),
+          algorithm: .auto,
+          threshold: 0.1,
+          minConfidence: 0.5,
+          interpolate: true
+        )
+        let referenceHz = (config["referenceHz"] as? Double).map { Float($0) } ?? 440.0
+        let temperamentCents = (config["temperamentCents"] as? [Double])?.map { Float($0) }
+          ?? [Float](repeating: 0, count: 12)
+        let stabilityFrames = config["stabilityFrames"] as? Int ?? 8
+
+        let handle = try createTunerWrapper(
+          config: analyzerConfig,
+          referenceHz: referenceHz,
+          temperamentCents: temperamentCents,
+          stabilityFrames: stabilityFrames
+        )
+        promise.resolve(LoqaExpoDspModule.storeTuner(handle))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("TUNER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("TUNER_ERROR", "Memory allocation failed creating tuner")
+        }
+      } catch {
+        promise.reject("TUNER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - pushTunerSamples
+    // Push a chunk of live audio (any size) and return the latest reading
+    AsyncFunction("pushTunerSamples") { (tunerId: String, buffer: [Float], promise: Promise) in
+      do {
+        guard let tuner = LoqaExpoDspModule.getTuner(id: tunerId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid tuner ID: \(tunerId)")
+          return
+        }
+
+        let reading = try pushToTuner(tuner: tuner, samples: buffer)
+        promise.resolve([
+          "frameCount": reading.frameCount,
+          "timestamp": reading.timestamp,
+          "isVoiced": reading.isVoiced,
+          "frequency": reading.frequency,
+          "confidence": reading.confidence,
+          "midiNote": reading.midiNote,
+          "pitchClass": reading.pitchClass,
+          "octave": reading.octave,
+          "cents": reading.cents,
+          "targetFrequency": reading.targetFrequency,
+          "stability": reading.stability
+        ])
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("TUNER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("TUNER_ERROR", "Memory allocation failed during tuner push")
+        }
+      } catch {
+        promise.reject("TUNER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - resetTuner
+    // Discard the tuner's buffered audio and reading
+    AsyncFunction("resetTuner") { (tunerId: String, promise: Promise) in
+      guard let tuner = LoqaExpoDspModule.getTuner(id: tunerId) else {
+        promise.reject("VALIDATION_ERROR", "Invalid tuner ID: \(tunerId)")
+        return
+      }
+
+      resetTunerWrapper(tuner: tuner)
+      promise.resolve(nil)
+    }
+
+    // MARK: - freeTuner
+    AsyncFunction("freeTuner") { (tunerId: String, promise: Promise) in
+      if LoqaExpoDspModule.removeTuner(id: tunerId) {
+        promise.resolve(nil)
+      } else {
+        promise.reject("VALIDATION_ERROR", "Invalid tuner ID: \(tunerId)")
+      }
+    }
+
+    // MARK: - Voice Range Profile API
+    // Semitone x dB phonetogram accumulated over a session and merged across recordings
+
+    // MARK: - createVoiceRangeProfile
+    AsyncFunction("createVoiceRangeProfile") { (config: [String: Any], promise: Promise) in
+      do {
+        var options = VoiceRangeProfileOptions()
+        if let referenceHz = config["referenceHz"] as? Double {
+          options.referenceHz = Float(referenceHz)
+        }
+        if let minMidiNote = config["minMidiNote"] as? Int {
+          options.minMidiNote = minMidiNote
+        }
+        if let maxMidiNote = config["maxMidiNote"] as? Int {
+          options.maxMidiNote = maxMidiNote
+        }
+        if let minDb = config["minDb"] as? Double {
+          options.minDb = Float(minDb)
+        }
+        if let maxDb = config["maxDb"] as? Double {
+          options.maxDb = Float(maxDb)
+        }
+        if let dbStep = config["dbStep"] as? Double {
+          options.dbStep = Float(dbStep)
+        }
+        if let minCellFrames = config["minCellFrames"] as? Int {
+          options.minCellFrames = minCellFrames
+        }
+
+        let handle = try createVoiceRangeProfileWrapper(options: options)
+        promise.resolve(LoqaExpoDspModule.storeProfile(handle))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VOICE_RANGE_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VOICE_RANGE_ERROR", "Memory allocation failed creating voice range profile")
+        }
+      } catch {
+        promise.reject("VOICE_RANGE_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - addVoiceRangeFrames
+    // Add framewise pitch and intensity on the same frame grid
+    AsyncFunction("addVoiceRangeFrames") { (profileId: String, pitchTrack: [Float], intensityDb: [Float], promise: Promise) in
+      do {
+        guard let profile = LoqaExpoDspModule.getProfile(id: profileId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(profileId)")
+          return
+        }
+
+        try addVoiceRangeFramesWrapper(profile: profile, pitchTrack: pitchTrack, intensityDb: intensityDb)
+        promise.resolve(nil)
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VOICE_RANGE_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VOICE_RANGE_ERROR", "Memory allocation failed adding voice range frames")
+        }
+      } catch {
+        promise.reject("VOICE_RANGE_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - mergeVoiceRangeProfiles
+    // Add the counts of the source profile to the target; both must share their config
+    AsyncFunction("mergeVoiceRangeProfiles") { (targetId: String, sourceId: String, promise: Promise) in
+      do {
+        guard let target = LoqaExpoDspModule.getProfile(id: targetId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(targetId)")
+          return
+        }
+        guard let source = LoqaExpoDspModule.getProfile(id: sourceId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(sourceId)")
+          return
+        }
+
+        try mergeVoiceRangeProfilesWrapper(target: target, source: source)
+        promise.resolve(nil)
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VOICE_RANGE_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VOICE_RANGE_ERROR", "Memory allocation failed merging voice range profiles")
+        }
+      } catch {
+        promise.reject("VOICE_RANGE_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - getVoiceRangeProfile
+    // Grid, per-note dynamics and sustainable range
+    AsyncFunction("getVoiceRangeProfile") { (profileId: String, promise: Promise) in
+      do {
+        guard let profile = LoqaExpoDspModule.getProfile(id: profileId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(profileId)")
+          return
+        }
+
+        let snapshot = try getVoiceRangeProfileWrapper(profile: profile)
+        promise.resolve(LoqaExpoDspModule.voiceRangeProfileToDictionary(snapshot))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("VOICE_RANGE_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("VOICE_RANGE_ERROR", "Memory allocation failed reading voice range profile")
+        }
+      } catch {
+        promise.reject("VOICE_RANGE_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - resetVoiceRangeProfile
+    // Empty the profile's grid
+    AsyncFunction("resetVoiceRangeProfile") { (profileId: String, promise: Promise) in
+      guard let profile = LoqaExpoDspModule.getProfile(id: profileId) else {
+        promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(profileId)")
+        return
+      }
+
+      resetVoiceRangeProfileWrapper(profile: profile)
+      promise.resolve(nil)
+    }
+
+    // MARK: - freeVoiceRangeProfile
+    AsyncFunction("freeVoiceRangeProfile") { (profileId: String, promise: Promise) in
+      if LoqaExpoDspModule.removeProfile(id: profileId) {
+        promise.resolve(nil)
+      } else {
+        promise.reject("VALIDATION_ERROR", "Invalid profile ID: \(profileId)")
+      }
+    }
+
+    // MARK: - Formant Tracker API
+    // F1-F4 contours chosen for continuity across frames by Viterbi decoding
+
+    // MARK: - createFormantTracker
+    AsyncFunction("createFormantTracker") { (config: [String: Any], promise: Promise) in
+      do {
+        guard let sampleRate = config["sampleRate"] as? Int else {
+          promise.reject("VALIDATION_ERROR", "sampleRate is required")
+          return
+        }
+        var options = FormantTrackerOptions()
+        options.frameSize = config["frameSize"] as? Int
+        options.hopSize = config["hopSize"] as? Int
+        if let lpcOrder = config["lpcOrder"] as? Int {
+          options.lpcOrder = lpcOrder
+        }
+        if let ceilingHz = config["ceilingHz"] as? Double {
+          options.ceilingHz = Float(ceilingHz)
+        }
+        if let continuityWeight = config["continuityWeight"] as? Double {
+          options.continuityWeight = Float(continuityWeight)
+        }
+        if let minConfidence = config["minConfidence"] as? Double {
+          options.minConfidence = Float(minConfidence)
+        }
+        if let silenceDb = config["silenceDb"] as? Double {
+          options.silenceDb = Float(silenceDb)
+        }
+
+        let handle = try createFormantTrackerWrapper(sampleRate: sampleRate, options: options)
+        promise.resolve(LoqaExpoDspModule.storeFormantTracker(handle))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("FORMANT_TRACKER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("FORMANT_TRACKER_ERROR", "Memory allocation failed creating formant tracker")
+        }
+      } catch {
+        promise.reject("FORMANT_TRACKER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - trackFormants
+    // Track F1-F4 over the frames of a buffer, continuing from the previous buffer
+    AsyncFunction("trackFormants") { (trackerId: String, buffer: [Float], promise: Promise) in
+      do {
+        guard let tracker = LoqaExpoDspModule.getFormantTracker(id: trackerId) else {
+          promise.reject("VALIDATION_ERROR", "Invalid formant tracker ID: \(trackerId)")
+          return
+        }
+
+        let track = try trackFormantsWrapper(tracker: tracker, samples: buffer)
+        promise.resolve(LoqaExpoDspModule.formantTrackToDictionary(track))
+      } catch let error as RustFFIError {
+        switch error {
+        case .invalidInput(let message):
+          promise.reject("VALIDATION_ERROR", message)
+        case .computationFailed(let message):
+          promise.reject("FORMANT_TRACKER_ERROR", message)
+        case .memoryAllocationFailed:
+          promise.reject("FORMANT_TRACKER_ERROR", "Memory allocation failed during formant tracking")
+        }
+      } catch {
+        promise.reject("FORMANT_TRACKER_ERROR", error.localizedDescription)
+      }
+    }
+
+    // MARK: - resetFormantTracker
+    // Forget the previous buffer, so the next one starts a new recording
+    AsyncFunction("resetFormantTracker") { (trackerId: String, promise: Promise) in
+      guard let tracker = LoqaExpoDspModule.getFormantTracker(id: trackerId) else {
+        promise.reject("VALIDATION_ERROR", "Invalid formant tracker ID: \(trackerId)")
+        return
+      }
+
+      resetFormantTrackerWrapper(tracker: tracker)
+      promise.resolve(nil)
+    }
+
+    // MARK: - freeFormantTracker
+    AsyncFunction("freeFormantTracker") { (trackerId: String, promise: Promise) in
+      if LoqaExpoDspModule.removeFormantTracker(id: trackerId) {
+        promise.resolve(nil)
+      } else {
+        promise.reject("VALIDATION_ERROR", "Invalid formant tracker ID: \(trackerId)")
+      }
+    }
   }
 
   // MARK: - Analyzer Storage
-  // Thread-safe storage for VoiceAnalyzer handles
+  // Thread-safe storage for VoiceAnalyzer, tuner, voice range profile and formant tracker handles
 
   private static var analyzers: [String: VoiceAnalyzerHandle] = [:]
+  // Streaming analyzers by analyzer ID, created on the first push
+  private static var streams: [String: StreamAnalyzerHandle] = [:]
+  private static var tuners: [String: TunerHandle] = [:]
+  private static var profiles: [String: VoiceRangeProfileHandle] = [:]
+  private static var formantTrackers: [String: FormantTrackerHandle] = [:]
   private static let analyzerLock = NSLock()
   private static var nextAnalyzerId: Int = 1
+  private static var nextTunerId: Int = 1
+  private static var nextProfileId: Int = 1
+  private static var nextFormantTrackerId: Int = 1
 
   static func storeAnalyzer(_ handle: VoiceAnalyzerHandle) -> String {
     analyzerLock.lock()
@@ -593,8 +1605,221 @@ public class LoqaExpoDspModule: Module {
     defer { analyzerLock.unlock() }
 
     if analyzers.removeValue(forKey: id) != nil {
+      streams.removeValue(forKey: id)
       return true
     }
     return false
   }
+
+  static func getStream(id: String) throws -> StreamAnalyzerHandle? {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    guard let analyzer = analyzers[id] else {
+      return nil
+    }
+    if let stream = streams[id] {
+      return stream
+    }
+    let stream = try createStreamAnalyzerWrapper(config: analyzer.config)
+    streams[id] = stream
+    return stream
+  }
+
+  static func resetStream(id: String) {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    if let stream = streams[id] {
+      resetStreamAnalyzerWrapper(analyzer: stream)
+    }
+  }
+
+  static func storeTuner(_ handle: TunerHandle) -> String {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    let id = "tn_\(nextTunerId)"
+    nextTunerId += 1
+    tuners[id] = handle
+    return id
+  }
+
+  static func getTuner(id: String) -> TunerHandle? {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return tuners[id]
+  }
+
+  static func removeTuner(id: String) -> Bool {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return tuners.removeValue(forKey: id) != nil
+  }
+
+  static func storeProfile(_ handle: VoiceRangeProfileHandle) -> String {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    let id = "vr_\(nextProfileId)"
+    nextProfileId += 1
+    profiles[id] = handle
+    return id
+  }
+
+  static func getProfile(id: String) -> VoiceRangeProfileHandle? {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return profiles[id]
+  }
+
+  static func removeProfile(id: String) -> Bool {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return profiles.removeValue(forKey: id) != nil
+  }
+
+  static func storeFormantTracker(_ handle: FormantTrackerHandle) -> String {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    let id = "ft_\(nextFormantTrackerId)"
+    nextFormantTrackerId += 1
+    formantTrackers[id] = handle
+    return id
+  }
+
+  static func getFormantTracker(id: String) -> FormantTrackerHandle? {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return formantTrackers[id]
+  }
+
+  static func removeFormantTracker(id: String) -> Bool {
+    analyzerLock.lock()
+    defer { analyzerLock.unlock() }
+
+    return formantTrackers.removeValue(forKey: id) != nil
+  }
+
+  /// Converts a pitch track to the dictionary returned to JS, with statistics over its voiced frames
+  static func pitchTrackToDictionary(_ track: PitchTrack) throws -> [String: Any] {
+    // Unweighted, computed in Rust so Android gives the same values
+    let stats: PitchStatistics? = track.pitchTrack.isEmpty ? nil : try pitchTrackStatisticsWrapper(
+      pitchTrack: track.pitchTrack,
+      voicedProbabilities: track.voicedProbabilities,
+      weightByVoicing: false
+    )
+
+    return [
+      "pitchTrack": track.pitchTrack,
+      "voicedProbabilities": track.voicedProbabilities,
+      "timestamps": track.timestamps,
+      "frameCount": track.pitchTrack.count,
+      "voicedFrameCount": track.pitchTrack.filter { $0 > 0 }.count,
+      "medianPitch": stats?.medianHz ?? NSNull(),
+      "meanPitch": stats?.meanHz ?? NSNull()
+    ]
+  }
+
+  /// Converts pitch statistics to the dictionary returned to JS; nil measures become null
+  static func pitchStatisticsToDictionary(_ stats: PitchStatistics) -> [String: Any] {
+    return [
+      "frameCount": stats.frameCount,
+      "voicedFrameCount": stats.voicedFrameCount,
+      "voicedRatio": stats.voicedRatio,
+      "meanHz": stats.meanHz ?? NSNull(),
+      "medianHz": stats.medianHz ?? NSNull(),
+      "stdHz": stats.stdHz ?? NSNull(),
+      "p5Hz": stats.p5Hz ?? NSNull(),
+      "p95Hz": stats.p95Hz ?? NSNull(),
+      "meanSemitones": stats.meanSemitones ?? NSNull(),
+      "medianSemitones": stats.medianSemitones ?? NSNull(),
+      "stdSemitones": stats.stdSemitones ?? NSNull(),
+      "p5Semitones": stats.p5Semitones ?? NSNull(),
+      "p95Semitones": stats.p95Semitones ?? NSNull(),
+      "rangeSemitones": stats.rangeSemitones ?? NSNull()
+    ]
+  }
+
+  /// Converts a vibrato analysis to the dictionary returned to JS; nil measures become null
+  static func vibratoToDictionary(_ result: VibratoAnalysis) -> [String: Any] {
+    let values = { (values: [Float?]) -> [Any] in values.map { value -> Any in value ?? NSNull() } }
+    return [
+      "vibratoRatio": result.vibratoRatio,
+      "rateHz": result.rateHz ?? NSNull(),
+      "extentCents": result.extentCents ?? NSNull(),
+      "rateJitter": result.rateJitter ?? NSNull(),
+      "extentJitter": result.extentJitter ?? NSNull(),
+      "onsetDelay": result.onsetDelay ?? NSNull(),
+      "segments": result.segments.map { segment -> [String: Any] in
+        [
+          "start": segment.start,
+          "end": segment.end,
+          "rateHz": segment.rateHz,
+          "extentCents": segment.extentCents,
+          "rateJitter": segment.rateJitter,
+          "extentJitter": segment.extentJitter,
+          "onsetDelay": segment.onsetDelay
+        ]
+      },
+      "windowTimes": result.windowTimes,
+      "windowRateHz": values(result.windowRateHz),
+      "windowExtentCents": values(result.windowExtentCents),
+      "windowRateJitter": values(result.windowRateJitter),
+      "windowExtentJitter": values(result.windowExtentJitter)
+    ]
+  }
+
+  /// Converts a voice range profile to the dictionary returned to JS; unsustained notes become null
+  static func voiceRangeProfileToDictionary(_ profile: VoiceRangeProfile) -> [String: Any] {
+    let levels = { (values: [Float?]) -> [Any] in values.map { value -> Any in value ?? NSNull() } }
+    return [
+      "counts": profile.counts,
+      "rowCount": profile.rowCount,
+      "columnCount": profile.columnCount,
+      "minMidiNote": profile.minMidiNote,
+      "minDb": profile.minDb,
+      "dbStep": profile.dbStep,
+      "softDb": levels(profile.softDb),
+      "loudDb": levels(profile.loudDb),
+      "dynamicRangeDb": levels(profile.dynamicRangeDb),
+      "lowestMidiNote": profile.lowestMidiNote ?? NSNull(),
+      "highestMidiNote": profile.highestMidiNote ?? NSNull(),
+      "lowestHz": profile.lowestHz ?? NSNull(),
+      "highestHz": profile.highestHz ?? NSNull(),
+      "coveredCells": profile.coveredCells,
+      "area": profile.area,
+      "frameCount": profile.frameCount
+    ]
+  }
+
+  /// Converts a formant track to the dictionary returned to JS; missing formants stay 0
+  static func formantTrackToDictionary(_ track: FormantTrack) -> [String: Any] {
+    return [
+      "frequencies": track.frequencies,
+      "bandwidths": track.bandwidths,
+      "confidences": track.confidences,
+      "reliable": track.reliable,
+      "timestamps": track.timestamps,
+      "frameCount": track.timestamps.count
+    ]
+  }
+
+  /// Converts the frames of a stream push or flush to the dictionary returned to JS
+  static func streamFramesToDictionary(_ frames: StreamFrames) -> [String: Any] {
+    return [
+      "frequencies": frames.frequencies,
+      "confidences": frames.confidences,
+      "isVoiced": frames.isVoiced,
+      "voicedProbabilities": frames.voicedProbabilities,
+      "timestamps": frames.timestamps,
+      "frameCount": frames.timestamps.count
+    ]
+  }
 }
@@ -1,4 +1,5 @@
 import Foundation
+import os.log
 
 // MARK: - FFI Function Declarations
 // These functions are declared in loqa_voice_dsp.h (C header) for proper C ABI compatibility
@@ -12,6 +13,44 @@ import Foundation
 // - Function names changed from *_rust to loqa_*
 // - loqa_detect_pitch now takes min_frequency and max_frequency
 
+// MARK: - Logging
+
+private let rustLog = OSLog(subsystem: "com.loqalabs.loqaexpodsp", category: "RustDSP")
+
+/// Optional extra receiver for Rust diagnostics (e.g. app telemetry)
+private var rustLogHandler: ((LoqaLogLevel, String) -> Void)?
+private let rustLogHandlerLock = NSLock()
+
+/// Routes Rust diagnostics to os_log (visible in Xcode and Console.app for device builds)
+/// and, if given, to `handler`. Messages more verbose than `level` are dropped in Rust.
+/// NOTE: The C callback may run on any thread that calls into Rust
+public func installRustLogging(
+    level: LoqaLogLevel = LOQA_LOG_WARN,
+    handler: ((LoqaLogLevel, String) -> Void)? = nil
+) {
+    rustLogHandlerLock.lock()
+    rustLogHandler = handler
+    rustLogHandlerLock.unlock()
+
+    loqa_set_log_callback { level, message in
+        guard let message = message else { return }
+        let type: OSLogType
+        switch level {
+        case LOQA_LOG_ERROR: type = .error
+        case LOQA_LOG_WARN: type = .default
+        case LOQA_LOG_INFO: type = .info
+        default: type = .debug
+        }
+        os_log("%{public}s", log: rustLog, type: type, message)
+
+        rustLogHandlerLock.lock()
+        let handler = rustLogHandler
+        rustLogHandlerLock.unlock()
+        handler?(level, String(cString: message))
+    }
+    _ = loqa_set_log_level(level.rawValue)
+}
+
 // MARK: - Swift Result Types
 // These wrap the C structs for cleaner Swift API
 
@@ -28,21 +67,40 @@ public struct PitchResult {
         self.isVoiced = c.is_voiced
         self.voicedProbability = c.voiced_probability
     }
+
+    init(from c: PitchResultExFFI) {
+        self.frequency = c.frequency
+        self.confidence = c.confidence
+        self.isVoiced = c.is_voiced
+        self.voicedProbability = c.voiced_probability
+    }
 }
 
-/// FormantsResult for Swift API (wraps FormantResultFFI from C header)
-/// Note: v0.4.0 removed bandwidths, added confidence
+/// FormantsResult for Swift API (wraps FormantsResultFFI from C header)
+/// A formant that was not found is 0.0 with zero confidence
 public struct FormantsResult {
     public let f1: Float
     public let f2: Float
     public let f3: Float
+    public let f4: Float
+    public let f5: Float
+    /// Bandwidths of F1-F5 in Hz
+    public let bandwidths: [Float]
+    /// Mean F1-F3 confidence
     public let confidence: Float
+    /// Confidence of each of F1-F5
+    public let confidences: [Float]
 
-    init(from c: FormantResultFFI) {
+    init(from c: FormantsResultFFI) {
         self.f1 = c.f1
         self.f2 = c.f2
         self.f3 = c.f3
-        self.confidence = c.confidence
+        self.f4 = c.f4
+        self.f5 = c.f5
+        self.bandwidths = [c.bw1, c.bw2, c.bw3, c.bw4, c.bw5]
+        let c1 = c.confidence
+        self.confidences = [c1.0, c1.1, c1.2, c1.3, c1.4]
+        self.confidence = (c1.0 + c1.1 + c1.2) / 3
     }
 }
 
@@ -59,6 +117,37 @@ public struct SpectrumResult {
     }
 }
 
+/// SpectralFeatures for Swift API (wraps SpectralFeaturesExFFI from C header)
+public struct SpectralFeatures {
+    public let centroid: Float
+    public let spread: Float
+    public let skewness: Float
+    public let kurtosis: Float
+    public let flatness: Float
+    public let entropy: Float
+    public let crestFactor: Float
+    public let rolloffLow: Float
+    public let rolloffHigh: Float
+    public let tilt: Float
+    public let fftSize: Int
+    public let frameCount: Int
+
+    init(from c: SpectralFeaturesExFFI) {
+        self.centroid = c.centroid
+        self.spread = c.spread
+        self.skewness = c.skewness
+        self.kurtosis = c.kurtosis
+        self.flatness = c.flatness
+        self.entropy = c.entropy
+        self.crestFactor = c.crest_factor
+        self.rolloffLow = c.rolloff_low
+        self.rolloffHigh = c.rolloff_high
+        self.tilt = c.tilt
+        self.fftSize = Int(c.fft_size)
+        self.frameCount = Int(c.frame_count)
+    }
+}
+
 /// HNRResult for Swift API (wraps HNRResultFFI from C header)
 public struct HNRResult {
     public let hnr: Float
@@ -70,6 +159,12 @@ public struct HNRResult {
         self.f0 = c.f0
         self.isVoiced = c.is_voiced
     }
+
+    init(from c: HNRResultExFFI) {
+        self.hnr = c.hnr
+        self.f0 = c.f0
+        self.isVoiced = c.is_voiced
+    }
 }
 
 /// H1H2Result for Swift API (wraps H1H2ResultFFI from C header)
@@ -85,6 +180,13 @@ public struct H1H2Result {
         self.h2AmplitudeDb = c.h2_amplitude_db
         self.f0 = c.f0
     }
+
+    init(from c: H1H2ResultExFFI) {
+        self.h1h2 = c.h1h2
+        self.h1AmplitudeDb = c.h1_amplitude_db
+        self.h2AmplitudeDb = c.h2_amplitude_db
+        self.f0 = c.f0
+    }
 }
 
 // MARK: - Swift Wrapper Functions
@@ -115,6 +217,10 @@ public enum RustFFIError: Error, LocalizedError {
 
 /// Swift wrapper for FFT computation
 /// MEMORY SAFETY: Returns owned array; Rust memory is freed before return
+///
+/// Unwindowed magnitudes divided by fftSize (fftSize a power of 2 in 64...8192), as
+/// upstream's `loqa_compute_fft` returns them, computed from the panic-safe complex
+/// FFT. Use `computeFFTExWrapper` for windows and calibrated scaling.
 public func computeFFTWrapper(
     buffer: [Float],
     sampleRate: Int,
@@ -129,55 +235,408 @@ public func computeFFTWrapper(
         throw RustFFIError.invalidInput("FFT size must be a positive power of 2")
     }
 
-    // Call Rust function
-    var fftResult = buffer.withUnsafeBufferPointer { bufferPtr -> FFTResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            return FFTResultFFI(
-                success: false,
-                magnitudes_ptr: nil,
-                frequencies_ptr: nil,
-                length: 0,
-                sample_rate: 0
-            )
-        }
+    let spectrum = try computeComplexFFTWrapper(buffer: buffer, fftSize: fftSize)
+    let binCount = spectrum.bins.count / 2
+    let scale = 1 / Float(spectrum.fftSize)
+    let binWidth = Float(sampleRate) / Float(spectrum.fftSize)
 
-        return loqa_compute_fft(
-            baseAddress,
-            buffer.count,
-            UInt32(sampleRate),
-            fftSize
-        )
+    let magnitudes = (0..<binCount).map { bin in
+        hypot(spectrum.bins[2 * bin], spectrum.bins[2 * bin + 1]) * scale
+    }
+    let frequencies = (0..<binCount).map { Float($0) * binWidth }
+
+    return (magnitudes, frequencies)
+}
+
+/// FFT window function (raw values match LoqaWindowType in loqa_voice_dsp.h)
+public enum FFTWindow: UInt32 {
+    case none = 0       // Rectangular
+    case hanning = 1    // Good general-purpose window (default)
+    case hamming = 2
+    case blackman = 3
+    case kaiser = 4     // windowParameter = beta (0 = 8.6)
+    case flatTop = 5    // Accurate peak amplitudes between bins
+    case gaussian = 6   // windowParameter = sigma relative to half the window (0 = 0.4)
+}
+
+/// FFT magnitude scaling (raw values match LoqaFftNormalization in loqa_voice_dsp.h)
+public enum FFTNormalization: UInt32 {
+    case amplitude = 0  // Single-sided amplitude: a sine of amplitude A reads A
+    case psd = 1        // Single-sided power spectral density, units^2/Hz
+}
+
+/// Windowed FFT result for Swift API (copied out of FFTResultExFFI)
+public struct FFTSpectrum {
+    public let magnitudes: [Float]
+    /// Phase per bin in radians; nil unless requested
+    public let phases: [Float]?
+    public let frequencies: [Float]
+    public let fftSize: Int
+}
+
+/// Swift wrapper for `loqa_compute_fft_ex`
+/// MEMORY SAFETY: Returns owned arrays; Rust memory is freed before return
+/// - Parameters:
+///   - fftSize: Power of two in 64...8192, or 0 to fit the buffer
+///   - window: Window applied before the transform; magnitudes are compensated for its gain
+///   - windowParameter: Kaiser beta or Gaussian sigma (0 selects the default)
+///   - normalization: Amplitude or power spectral density scaling
+///   - includePhase: Also return the phase of each bin
+public func computeFFTExWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    fftSize: Int = 0,
+    window: FFTWindow = .hanning,
+    windowParameter: Float = 0,
+    normalization: FFTNormalization = .amplitude,
+    includePhase: Bool = false
+) throws -> FFTSpectrum {
+    // Input validation
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
+
+    guard sampleRate > 0 else {
+        throw RustFFIError.invalidInput("Sample rate must be positive")
+    }
+
+    guard fftSize >= 0 else {
+        throw RustFFIError.invalidInput("FFT size must be 0 (auto) or a power of 2")
+    }
+
+    var config = loqa_fft_config_default()
+    config.fft_size = UInt32(fftSize)
+    config.window = window.rawValue
+    config.window_param = windowParameter
+    config.normalization = normalization.rawValue
+    config.include_phase = includePhase
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_fft_ex(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
     }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_fft_result_ex(&cResult) }
 
-    // Check for failure
-    guard fftResult.success else {
-        throw RustFFIError.computationFailed("FFT computation failed")
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "FFT computation")
     }
 
-    guard let magPtr = fftResult.magnitudes_ptr,
-          let freqPtr = fftResult.frequencies_ptr else {
+    guard let magPtr = cResult.magnitudes_ptr,
+          let freqPtr = cResult.frequencies_ptr else {
         throw RustFFIError.memoryAllocationFailed
     }
 
-    // Copy to Swift arrays before freeing Rust memory
-    let magnitudes = Array(UnsafeBufferPointer(start: magPtr, count: fftResult.length))
-    let frequencies = Array(UnsafeBufferPointer(start: freqPtr, count: fftResult.length))
+    // Copy to Swift arrays before the deferred free
+    return FFTSpectrum(
+        magnitudes: Array(UnsafeBufferPointer(start: magPtr, count: cResult.length)),
+        phases: cResult.phases_ptr.map { Array(UnsafeBufferPointer(start: $0, count: cResult.length)) },
+        frequencies: Array(UnsafeBufferPointer(start: freqPtr, count: cResult.length)),
+        fftSize: Int(cResult.fft_size)
+    )
+}
+
+/// Swift wrapper for the unwindowed complex FFT
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+///
+/// - Parameters:
+///   - buffer: Audio samples; zero-padded or truncated to the FFT size
+///   - fftSize: Power of two in 64...8192, or 0 to fit the buffer
+/// - Returns: Interleaved bins `[re0, im0, re1, im1, ...]` and the FFT size used
+public func computeComplexFFTWrapper(
+    buffer: [Float],
+    fftSize: Int = 0
+) throws -> (bins: [Float], fftSize: Int) {
+    // Input validation
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
 
-    // Free Rust-allocated memory
-    loqa_free_fft_result(&fftResult)
+    guard fftSize >= 0 else {
+        throw RustFFIError.invalidInput("FFT size must be 0 (auto) or a power of 2")
+    }
 
-    return (magnitudes, frequencies)
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_fft_complex(bufferPtr.baseAddress, buffer.count, UInt32(fftSize))
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_complex_spectrum(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Complex FFT computation")
+    }
+
+    guard let binsPtr = cResult.bins_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to a Swift array before the deferred free
+    return (
+        bins: Array(UnsafeBufferPointer(start: binsPtr, count: 2 * cResult.bin_count)),
+        fftSize: Int(cResult.fft_size)
+    )
+}
+
+/// Swift wrapper for the inverse FFT of interleaved one-sided bins
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+///
+/// - Parameter bins: Interleaved `[re, im]` pairs, fftSize / 2 + 1 of them
+/// - Returns: fftSize time-domain samples
+public func inverseFFTWrapper(bins: [Float]) throws -> [Float] {
+    // Input validation
+    guard !bins.isEmpty && bins.count % 2 == 0 else {
+        throw RustFFIError.invalidInput("Bins must be non-empty interleaved real/imaginary pairs")
+    }
+
+    var cResult = bins.withUnsafeBufferPointer { binsPtr in
+        loqa_compute_ifft(binsPtr.baseAddress, bins.count / 2)
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_ifft_result(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Inverse FFT computation")
+    }
+
+    guard let samplesPtr = cResult.samples_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to a Swift array before the deferred free
+    return Array(UnsafeBufferPointer(start: samplesPtr, count: cResult.length))
+}
+
+/// Spectrogram cell scaling (raw values match LoqaSpectrogramScaling in loqa_voice_dsp.h)
+public enum SpectrogramScaling: UInt32 {
+    case linear = 0     // Window-compensated single-sided amplitude
+    case power = 1      // Squared amplitude
+    case decibels = 2   // 20 * log10(amplitude) dBFS, clamped below at dbFloor
+}
+
+/// Spectrogram result for Swift API (copied out of SpectrogramFFI)
+public struct Spectrogram {
+    /// Row-major frameCount x binCount matrix
+    public let values: [Float]
+    /// Frame starts in seconds, on the same grid as processBufferWrapper timestamps
+    public let timestamps: [Float]
+    public let frameCount: Int
+    public let binCount: Int
+}
+
+/// Swift wrapper for `loqa_compute_spectrogram`
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than frameSize
+///   - frameSize: Samples per frame, power of two in 64...8192
+///   - hopSize: Samples between frame starts, in 1...frameSize
+///   - window: Window applied to every frame; values are compensated for its gain
+///   - windowParameter: Kaiser beta or Gaussian sigma (0 selects the default)
+///   - scaling: Linear amplitude, power or dB
+///   - dbFloor: Lowest value for `.decibels` scaling
+public func computeSpectrogramWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    frameSize: Int = 2048,
+    hopSize: Int = 512,
+    window: FFTWindow = .hanning,
+    windowParameter: Float = 0,
+    scaling: SpectrogramScaling = .decibels,
+    dbFloor: Float = -120
+) throws -> Spectrogram {
+    // Input validation
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
+
+    guard sampleRate > 0 else {
+        throw RustFFIError.invalidInput("Sample rate must be positive")
+    }
+
+    guard frameSize > 0 && hopSize > 0 else {
+        throw RustFFIError.invalidInput("Frame size and hop size must be positive")
+    }
+
+    var config = loqa_spectrogram_config_default()
+    config.frame_size = UInt32(frameSize)
+    config.hop_size = UInt32(hopSize)
+    config.window = window.rawValue
+    config.window_param = windowParameter
+    config.scaling = scaling.rawValue
+    config.db_floor = dbFloor
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_spectrogram(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_spectrogram(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Spectrogram computation")
+    }
+
+    guard let valuesPtr = cResult.values_ptr,
+          let timesPtr = cResult.timestamps_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to Swift arrays before the deferred free
+    return Spectrogram(
+        values: Array(UnsafeBufferPointer(start: valuesPtr, count: cResult.frame_count * cResult.bin_count)),
+        timestamps: Array(UnsafeBufferPointer(start: timesPtr, count: cResult.frame_count)),
+        frameCount: cResult.frame_count,
+        binCount: cResult.bin_count
+    )
+}
+
+/// Mel scale and filter normalization (raw values match LoqaMelScale in loqa_voice_dsp.h)
+public enum MelScale: UInt32 {
+    case htk = 0      // 2595 * log10(1 + f / 700), unit-height filters
+    case slaney = 1   // Linear below 1 kHz, log above, area-normalized filters
+}
+
+/// Framewise feature matrix for Swift API (copied out of FeatureMatrixFFI)
+public struct FeatureMatrix {
+    /// Row-major frameCount x featureCount matrix
+    public let values: [Float]
+    /// Frame starts in seconds, on the same grid as processBufferWrapper timestamps
+    public let timestamps: [Float]
+    public let frameCount: Int
+    public let featureCount: Int
+}
+
+/// Framing and filterbank settings shared by the mel wrappers
+public struct MelFilterbankOptions {
+    public var frameSize: Int = 2048
+    public var hopSize: Int = 512
+    public var window: FFTWindow = .hanning
+    public var windowParameter: Float = 0
+    public var nMels: Int = 40
+    public var fmin: Float = 0
+    /// Upper edge of the last band in Hz (0 = Nyquist)
+    public var fmax: Float = 0
+    public var scale: MelScale = .slaney
+
+    public init() {}
+}
+
+/// Validates the buffer and builds a MelConfigFFI with the filterbank fields set
+private func melConfig(buffer: [Float], sampleRate: Int, options: MelFilterbankOptions) throws -> MelConfigFFI {
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
+
+    guard sampleRate > 0 else {
+        throw RustFFIError.invalidInput("Sample rate must be positive")
+    }
+
+    guard options.frameSize > 0 && options.hopSize > 0 && options.nMels > 0 else {
+        throw RustFFIError.invalidInput("Frame size, hop size and mel band count must be positive")
+    }
+
+    var config = loqa_mel_config_default()
+    config.frame_size = UInt32(options.frameSize)
+    config.hop_size = UInt32(options.hopSize)
+    config.window = options.window.rawValue
+    config.window_param = options.windowParameter
+    config.n_mels = UInt32(options.nMels)
+    config.fmin = options.fmin
+    config.fmax = options.fmax
+    config.mel_scale = options.scale.rawValue
+    return config
+}
+
+/// Copies a FeatureMatrixFFI into Swift arrays and frees it
+private func copyFeatureMatrix(_ cResult: inout FeatureMatrixFFI, operation: String) throws -> FeatureMatrix {
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_feature_matrix(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: operation)
+    }
+
+    guard let valuesPtr = cResult.values_ptr,
+          let timesPtr = cResult.timestamps_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to Swift arrays before the deferred free
+    return FeatureMatrix(
+        values: Array(UnsafeBufferPointer(start: valuesPtr, count: cResult.frame_count * cResult.feature_count)),
+        timestamps: Array(UnsafeBufferPointer(start: timesPtr, count: cResult.frame_count)),
+        frameCount: cResult.frame_count,
+        featureCount: cResult.feature_count
+    )
+}
+
+/// Swift wrapper for `loqa_compute_log_mel_spectrogram`
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: Framing and filterbank settings
+/// - Returns: nMels band energies in dB (floor -100 dB) per frame
+public func computeLogMelSpectrogramWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: MelFilterbankOptions = MelFilterbankOptions()
+) throws -> FeatureMatrix {
+    let config = try melConfig(buffer: buffer, sampleRate: sampleRate, options: options)
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_log_mel_spectrogram(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
+    return try copyFeatureMatrix(&cResult, operation: "Log-mel spectrogram computation")
+}
+
+/// Swift wrapper for `loqa_compute_mfcc`
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: Framing and filterbank settings
+///   - nMfcc: Cepstral coefficients per frame, in 1...nMels
+///   - lifter: Sinusoidal lifter length (0 = none)
+///   - deltaOrder: 0 = static, 1 = plus deltas, 2 = plus deltas and delta-deltas
+///   - deltaWidth: Frames on each side of the delta regression
+/// - Returns: nMfcc * (1 + deltaOrder) values per frame
+public func computeMFCCWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: MelFilterbankOptions = MelFilterbankOptions(),
+    nMfcc: Int = 13,
+    lifter: Float = 0,
+    deltaOrder: Int = 0,
+    deltaWidth: Int = 2
+) throws -> FeatureMatrix {
+    var config = try melConfig(buffer: buffer, sampleRate: sampleRate, options: options)
+
+    guard nMfcc > 0 && deltaOrder >= 0 && deltaWidth > 0 else {
+        throw RustFFIError.invalidInput("nMfcc and deltaWidth must be positive and deltaOrder non-negative")
+    }
+
+    config.n_mfcc = UInt32(nMfcc)
+    config.lifter = lifter
+    config.delta_order = UInt32(deltaOrder)
+    config.delta_width = UInt32(deltaWidth)
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_mfcc(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
+    return try copyFeatureMatrix(&cResult, operation: "MFCC computation")
 }
 
 // MARK: Pitch Detection Wrapper
 
 /// Swift wrapper for pitch detection with min/max frequency support
-/// MEMORY SAFETY: PitchResultFFI returned by value (no heap allocation, no cleanup needed)
+/// MEMORY SAFETY: PitchResultExFFI returned by value (no heap allocation, no cleanup needed)
+///
+/// Without an `algorithm`, runs YIN with an autocorrelation fallback via
+/// `loqa_detect_pitch_range`. Passing an `algorithm` runs that detector via
+/// `loqa_detect_pitch_ex`, which needs at least 512 samples. Rejected input is
+/// reported as `.invalidInput` with Rust's message.
 public func detectPitchWrapper(
     buffer: [Float],
     sampleRate: Int,
     minFrequency: Float = 80.0,
-    maxFrequency: Float = 400.0
+    maxFrequency: Float = 400.0,
+    algorithm: PitchAlgorithm? = nil
 ) throws -> (frequency: Float?, confidence: Float, isVoiced: Bool, voicedProbability: Float) {
     // Input validation
     guard !buffer.isEmpty else {
@@ -192,21 +651,20 @@ public func detectPitchWrapper(
         throw RustFFIError.invalidInput("Invalid frequency range: min must be positive and less than max")
     }
 
-    // Call Rust function - returns PitchResultFFI by value (no memory management needed)
-    let cResult = buffer.withUnsafeBufferPointer { bufferPtr -> PitchResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            // Return error result if buffer pointer is invalid
-            return PitchResultFFI(
-                success: false,
-                frequency: 0.0,
-                confidence: 0.0,
-                is_voiced: false,
-                voiced_probability: 0.0
+    // Call Rust function - returns PitchResultExFFI by value (no memory management needed)
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr -> PitchResultExFFI in
+        if let algorithm = algorithm {
+            return loqa_detect_pitch_ex(
+                bufferPtr.baseAddress,
+                buffer.count,
+                UInt32(sampleRate),
+                minFrequency,
+                maxFrequency,
+                algorithm.rawValue
             )
         }
-
-        return loqa_detect_pitch(
-            baseAddress,
+        return loqa_detect_pitch_range(
+            bufferPtr.baseAddress,
             buffer.count,
             UInt32(sampleRate),
             minFrequency,
@@ -214,9 +672,8 @@ public func detectPitchWrapper(
         )
     }
 
-    // Check for failure
     guard cResult.success else {
-        throw RustFFIError.computationFailed("Pitch detection failed")
+        throw rustFFIError(cResult.error_code, operation: "Pitch detection")
     }
 
     // Convert to Swift result
@@ -228,16 +685,32 @@ public func detectPitchWrapper(
     return (frequency, result.confidence, result.isVoiced, result.voicedProbability)
 }
 
+/// Maps an export's error code and the last error message to a `RustFFIError`:
+/// analysis failures and panics become `.computationFailed`, anything else
+/// (rejected input) `.invalidInput` with Rust's message
+private func rustFFIError(_ code: LoqaErrorCode, operation: String) -> RustFFIError {
+    let detail = loqa_last_error_message().map { String(cString: $0) }
+        ?? "error code \(code.rawValue)"
+    switch code {
+    case LOQA_ERR_ANALYSIS_FAILED, LOQA_ERR_PANIC:
+        return .computationFailed("\(operation) failed: \(detail)")
+    default:
+        return .invalidInput(detail)
+    }
+}
+
 // MARK: Formant Extraction Wrapper
 
 /// Swift wrapper for formant extraction
-/// MEMORY SAFETY: FormantResultFFI returned by value (no heap allocation, no cleanup needed)
-/// Note: v0.4.0 returns confidence instead of bandwidths
+/// MEMORY SAFETY: FormantsResultFFI returned by value (no heap allocation, no cleanup needed)
+///
+/// Runs `loqa_extract_formants_ex`, which reports F1-F5 with bandwidths and per-formant
+/// confidence. Rejected input is reported as `.invalidInput` with Rust's message.
 public func extractFormantsWrapper(
     buffer: [Float],
     sampleRate: Int,
     lpcOrder: Int = 12
-) throws -> (f1: Float, f2: Float, f3: Float, confidence: Float) {
+) throws -> FormantsResult {
     // Input validation
     guard !buffer.isEmpty else {
         throw RustFFIError.invalidInput("Buffer cannot be empty")
@@ -251,38 +724,51 @@ public func extractFormantsWrapper(
         throw RustFFIError.invalidInput("LPC order must be between 10 and 24")
     }
 
-    // Call Rust function - returns FormantResultFFI by value
-    let cResult = buffer.withUnsafeBufferPointer { bufferPtr -> FormantResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            return FormantResultFFI(success: false, f1: 0, f2: 0, f3: 0, confidence: 0)
-        }
-
-        return loqa_extract_formants(
-            baseAddress,
+    // Call Rust function - returns FormantsResultFFI by value
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_extract_formants_ex(
+            bufferPtr.baseAddress,
             buffer.count,
             UInt32(sampleRate),
-            lpcOrder
+            UInt32(lpcOrder)
         )
     }
 
-    // Check for failure
-    guard cResult.success else {
-        throw RustFFIError.computationFailed("Formant extraction failed")
+    guard cResult.error_code == LOQA_OK else {
+        throw rustFFIError(cResult.error_code, operation: "Formant extraction")
     }
 
-    let result = FormantsResult(from: cResult)
-    return (result.f1, result.f2, result.f3, result.confidence)
+    return FormantsResult(from: cResult)
 }
 
 // MARK: Spectrum Analysis Wrapper
 
-/// Swift wrapper for spectrum analysis
-/// MEMORY SAFETY: SpectralFeaturesFFI returned by value (no heap allocation, no cleanup needed)
-/// Note: This now requires FFT result as input per v0.4.0 API
+/// Swift wrapper for spectrum analysis (centroid, 95% rolloff, tilt)
+/// MEMORY SAFETY: SpectralFeaturesExFFI returned by value (no heap allocation, no cleanup needed)
+/// Accepts any buffer length; see `analyzeSpectrumExWrapper` for the other descriptors
 public func analyzeSpectrumWrapper(
     buffer: [Float],
     sampleRate: Int
 ) throws -> (centroid: Float, rolloff: Float, tilt: Float) {
+    let result = try analyzeSpectrumExWrapper(buffer: buffer, sampleRate: sampleRate)
+    return (result.centroid, result.rolloffHigh, result.tilt)
+}
+
+/// Swift wrapper for `loqa_analyze_spectrum_ex`
+/// MEMORY SAFETY: SpectralFeaturesExFFI returned by value (no heap allocation, no cleanup needed)
+/// - Parameters:
+///   - fftSize: Power of two in 64...8192, or 0 to fit the buffer
+///   - averageFrames: Average 50%-overlapping frames when the buffer is longer than the FFT
+///   - rolloffLowThreshold: Energy fraction for `rolloffLow`
+///   - rolloffHighThreshold: Energy fraction for `rolloffHigh`
+public func analyzeSpectrumExWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    fftSize: Int = 0,
+    averageFrames: Bool = true,
+    rolloffLowThreshold: Float = 0.85,
+    rolloffHighThreshold: Float = 0.95
+) throws -> SpectralFeatures {
     // Input validation
     guard !buffer.isEmpty else {
         throw RustFFIError.invalidInput("Buffer cannot be empty")
@@ -292,51 +778,31 @@ public func analyzeSpectrumWrapper(
         throw RustFFIError.invalidInput("Sample rate must be between 8000 and 48000 Hz")
     }
 
-    // First compute FFT
-    let fftSize = 2048  // Default FFT size for spectrum analysis
-    var fftResult = buffer.withUnsafeBufferPointer { bufferPtr -> FFTResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            return FFTResultFFI(
-                success: false,
-                magnitudes_ptr: nil,
-                frequencies_ptr: nil,
-                length: 0,
-                sample_rate: 0
-            )
-        }
-
-        return loqa_compute_fft(
-            baseAddress,
-            buffer.count,
-            UInt32(sampleRate),
-            fftSize
-        )
-    }
-
-    // Check FFT success
-    guard fftResult.success else {
-        throw RustFFIError.computationFailed("FFT computation failed for spectrum analysis")
+    guard fftSize >= 0 else {
+        throw RustFFIError.invalidInput("FFT size must be 0 (auto) or a power of 2")
     }
 
-    // Call spectrum analysis with FFT result
-    let cResult = loqa_analyze_spectrum(&fftResult)
+    var config = loqa_spectrum_config_default()
+    config.fft_size = UInt32(fftSize)
+    config.average_frames = averageFrames
+    config.rolloff_low_threshold = rolloffLowThreshold
+    config.rolloff_high_threshold = rolloffHighThreshold
 
-    // Free FFT memory
-    loqa_free_fft_result(&fftResult)
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_analyze_spectrum_ex(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
 
-    // Check for failure
     guard cResult.success else {
-        throw RustFFIError.computationFailed("Spectrum analysis failed")
+        throw rustFFIError(cResult.error_code, operation: "Spectrum analysis")
     }
 
-    let result = SpectrumResult(from: cResult)
-    return (result.centroid, result.rolloff, result.tilt)
+    return SpectralFeatures(from: cResult)
 }
 
 // MARK: HNR Wrapper
 
 /// Swift wrapper for HNR calculation
-/// MEMORY SAFETY: HNRResultFFI returned by value (no heap allocation, no cleanup needed)
+/// MEMORY SAFETY: HNRResultExFFI returned by value (no heap allocation, no cleanup needed)
 public func calculateHNRWrapper(
     buffer: [Float],
     sampleRate: Int,
@@ -356,14 +822,10 @@ public func calculateHNRWrapper(
         throw RustFFIError.invalidInput("Invalid frequency range")
     }
 
-    // Call Rust function - returns HNRResultFFI by value
-    let cResult = buffer.withUnsafeBufferPointer { bufferPtr -> HNRResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            return HNRResultFFI(success: false, hnr: 0, f0: 0, is_voiced: false)
-        }
-
-        return loqa_calculate_hnr(
-            baseAddress,
+    // Call Rust function - returns HNRResultExFFI by value
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_calculate_hnr_ex(
+            bufferPtr.baseAddress,
             buffer.count,
             UInt32(sampleRate),
             minFrequency,
@@ -371,9 +833,8 @@ public func calculateHNRWrapper(
         )
     }
 
-    // Check for failure
-    guard cResult.success else {
-        throw RustFFIError.computationFailed("HNR calculation failed")
+    guard cResult.error_code == LOQA_OK else {
+        throw rustFFIError(cResult.error_code, operation: "HNR calculation")
     }
 
     let result = HNRResult(from: cResult)
@@ -383,7 +844,7 @@ public func calculateHNRWrapper(
 // MARK: H1-H2 Wrapper
 
 /// Swift wrapper for H1-H2 calculation
-/// MEMORY SAFETY: H1H2ResultFFI returned by value (no heap allocation, no cleanup needed)
+/// MEMORY SAFETY: H1H2ResultExFFI returned by value (no heap allocation, no cleanup needed)
 /// f0: Pass nil or 0 for auto-detection, or provide known fundamental frequency
 public func calculateH1H2Wrapper(
     buffer: [Float],
@@ -402,77 +863,576 @@ public func calculateH1H2Wrapper(
     // Use 0 to signal auto-detection to Rust, or use provided f0
     let f0Value = f0 ?? 0.0
 
-    // Call Rust function - returns H1H2ResultFFI by value
-    let cResult = buffer.withUnsafeBufferPointer { bufferPtr -> H1H2ResultFFI in
-        guard let baseAddress = bufferPtr.baseAddress else {
-            return H1H2ResultFFI(success: false, h1h2: 0, h1_amplitude_db: 0, h2_amplitude_db: 0, f0: 0)
-        }
-
-        return loqa_calculate_h1h2(
-            baseAddress,
+    // Call Rust function - returns H1H2ResultExFFI by value
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_calculate_h1h2_ex(
+            bufferPtr.baseAddress,
             buffer.count,
             UInt32(sampleRate),
             f0Value
         )
     }
 
-    // Check for failure
-    guard cResult.success else {
-        throw RustFFIError.computationFailed("H1-H2 calculation failed")
+    guard cResult.error_code == LOQA_OK else {
+        throw rustFFIError(cResult.error_code, operation: "H1-H2 calculation")
     }
 
     let result = H1H2Result(from: cResult)
     return (result.h1h2, result.h1AmplitudeDb, result.h2AmplitudeDb, result.f0)
 }
 
-// MARK: - VoiceAnalyzer Wrapper (Streaming API)
+// MARK: Jitter and Shimmer Wrappers
+
+/// Praat jitter measures for Swift API; nil where Rust reports NaN (too few
+/// consecutive periods for that measure)
+public struct JitterResult {
+    public let local: Float?
+    public let localAbsolute: Float?
+    public let rap: Float?
+    public let ppq5: Float?
+    public let ddp: Float?
+    public let meanPeriod: Float?
+    public let periodCount: Int
+
+    init(from c: JitterResultFFI) {
+        self.local = measureOrNil(c.local)
+        self.localAbsolute = measureOrNil(c.local_absolute)
+        self.rap = measureOrNil(c.rap)
+        self.ppq5 = measureOrNil(c.ppq5)
+        self.ddp = measureOrNil(c.ddp)
+        self.meanPeriod = measureOrNil(c.mean_period)
+        self.periodCount = Int(c.period_count)
+    }
+}
 
-/// Pitch detection algorithm selection
-public enum PitchAlgorithm: UInt32 {
-    case auto = 0       // Auto-select best algorithm
-    case pyin = 1       // Probabilistic YIN (recommended)
-    case yin = 2        // Classic YIN
-    case autocorr = 3   // Autocorrelation
+/// Praat shimmer measures for Swift API; nil where Rust reports NaN
+public struct ShimmerResult {
+    public let local: Float?
+    public let localDb: Float?
+    public let apq3: Float?
+    public let apq5: Float?
+    public let apq11: Float?
+    public let dda: Float?
+    public let meanAmplitude: Float?
+    public let periodCount: Int
+
+    init(from c: ShimmerResultFFI) {
+        self.local = measureOrNil(c.local)
+        self.localDb = measureOrNil(c.local_db)
+        self.apq3 = measureOrNil(c.apq3)
+        self.apq5 = measureOrNil(c.apq5)
+        self.apq11 = measureOrNil(c.apq11)
+        self.dda = measureOrNil(c.dda)
+        self.meanAmplitude = measureOrNil(c.mean_amplitude)
+        self.periodCount = Int(c.period_count)
+    }
 }
 
-/// VoiceAnalyzer configuration for Swift
-public struct VoiceAnalyzerConfig {
-    public let sampleRate: UInt32
-    public let frameSize: UInt32
-    public let hopSize: UInt32
-    public let minFrequency: Float
-    public let maxFrequency: Float
-    public let algorithm: PitchAlgorithm
-    public let threshold: Float
-    public let minConfidence: Float
-    public let interpolate: Bool
+/// Undefined perturbation measures cross the FFI boundary as NaN
+private func measureOrNil(_ value: Float) -> Float? {
+    return value.isNaN ? nil : value
+}
 
-    public init(
-        sampleRate: UInt32,
-        minFrequency: Float = 80.0,
-        maxFrequency: Float = 400.0,
-        frameSize: UInt32 = 2048,
-        hopSize: UInt32 = 512,
-        algorithm: PitchAlgorithm = .auto,
-        threshold: Float = 0.1,
-        minConfidence: Float = 0.5,
-        interpolate: Bool = true
-    ) {
-        self.sampleRate = sampleRate
-        self.frameSize = frameSize
-        self.hopSize = hopSize
-        self.minFrequency = minFrequency
-        self.maxFrequency = maxFrequency
-        self.algorithm = algorithm
-        self.threshold = threshold
-        self.minConfidence = minConfidence
-        self.interpolate = interpolate
+/// Validates the input and builds the pitch tracking config for the whole-buffer pYIN
+/// wrappers (perturbation, VAD, vocal tract length)
+private func pitchTrackingConfig(
+    buffer: [Float],
+    sampleRate: Int,
+    minFrequency: Float,
+    maxFrequency: Float
+) throws -> AnalysisConfigFFI {
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
     }
 
-    /// Convert to C FFI struct (field order must match Rust struct exactly)
-    func toFFI() -> AnalysisConfigFFI {
-        return AnalysisConfigFFI(
-            sample_rate: sampleRate,
+    guard sampleRate >= 8000 && sampleRate <= 48000 else {
+        throw RustFFIError.invalidInput("Sample rate must be between 8000 and 48000 Hz")
+    }
+
+    guard minFrequency > 0 && maxFrequency > minFrequency else {
+        throw RustFFIError.invalidInput("Invalid frequency range")
+    }
+
+    var config = loqa_analysis_config_default()
+    config.sample_rate = UInt32(sampleRate)
+    config.min_frequency = minFrequency
+    config.max_frequency = maxFrequency
+    return config
+}
+
+/// Swift wrapper for `loqa_calculate_jitter`
+/// MEMORY SAFETY: JitterResultFFI returned by value (no heap allocation, no cleanup needed)
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than 2048 samples (the pitch tracking frame)
+///   - minFrequency: Lowest F0 of the pitch track in Hz
+///   - maxFrequency: Highest F0 of the pitch track in Hz
+/// - Returns: Relative measures as fractions, meanPeriod in seconds
+public func calculateJitterWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    minFrequency: Float = 75.0,
+    maxFrequency: Float = 500.0
+) throws -> JitterResult {
+    let config = try pitchTrackingConfig(
+        buffer: buffer,
+        sampleRate: sampleRate,
+        minFrequency: minFrequency,
+        maxFrequency: maxFrequency
+    )
+
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_calculate_jitter(bufferPtr.baseAddress, buffer.count, config)
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Jitter calculation")
+    }
+
+    return JitterResult(from: cResult)
+}
+
+/// Swift wrapper for `loqa_calculate_shimmer`
+/// MEMORY SAFETY: ShimmerResultFFI returned by value (no heap allocation, no cleanup needed)
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than 2048 samples (the pitch tracking frame)
+///   - minFrequency: Lowest F0 of the pitch track in Hz
+///   - maxFrequency: Highest F0 of the pitch track in Hz
+/// - Returns: Relative measures as fractions, localDb in dB
+public func calculateShimmerWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    minFrequency: Float = 75.0,
+    maxFrequency: Float = 500.0
+) throws -> ShimmerResult {
+    let config = try pitchTrackingConfig(
+        buffer: buffer,
+        sampleRate: sampleRate,
+        minFrequency: minFrequency,
+        maxFrequency: maxFrequency
+    )
+
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_calculate_shimmer(bufferPtr.baseAddress, buffer.count, config)
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Shimmer calculation")
+    }
+
+    return ShimmerResult(from: cResult)
+}
+
+// MARK: Cepstral Peak Prominence Wrapper
+
+/// Cepstral peak prominence for Swift API (wraps CppResultFFI from C header)
+public struct CppResult {
+    /// Mean CPP of the unsmoothed cepstra in dB
+    public let cpp: Float
+    /// Mean CPP of the time- and quefrency-smoothed cepstra in dB
+    public let cpps: Float
+    /// Median F0 of the smoothed cepstral peaks in Hz
+    public let f0: Float
+    public let frameCount: Int
+
+    init(from c: CppResultFFI) {
+        self.cpp = c.cpp
+        self.cpps = c.cpps
+        self.f0 = c.f0
+        self.frameCount = Int(c.frame_count)
+    }
+}
+
+/// Framing, peak search and smoothing settings for calculateCPPWrapper
+/// (defaults follow Praat's "Get CPPS")
+public struct CppOptions {
+    public var frameSize: Int = 2048
+    public var hopSize: Int = 128
+    public var minFrequency: Float = 60
+    public var maxFrequency: Float = 330
+    /// Moving average across frames for CPPS, in seconds (0 = none)
+    public var timeSmoothing: Float = 0.02
+    /// Moving average across quefrency for CPPS, in seconds (0 = none)
+    public var quefrencySmoothing: Float = 0.0005
+
+    public init() {}
+}
+
+/// Swift wrapper for `loqa_calculate_cpp`
+/// MEMORY SAFETY: CppResultFFI returned by value (no heap allocation, no cleanup needed)
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: Framing, F0 range and smoothing settings
+/// - Returns: CPP and CPPS in dB, averaged over every frame
+public func calculateCPPWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: CppOptions = CppOptions()
+) throws -> CppResult {
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
+
+    guard sampleRate > 0 else {
+        throw RustFFIError.invalidInput("Sample rate must be positive")
+    }
+
+    guard options.frameSize > 0 && options.hopSize > 0 else {
+        throw RustFFIError.invalidInput("Frame size and hop size must be positive")
+    }
+
+    var config = loqa_cpp_config_default()
+    config.frame_size = UInt32(options.frameSize)
+    config.hop_size = UInt32(options.hopSize)
+    config.min_frequency = options.minFrequency
+    config.max_frequency = options.maxFrequency
+    config.time_smoothing = options.timeSmoothing
+    config.quefrency_smoothing = options.quefrencySmoothing
+
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_calculate_cpp(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "CPP calculation")
+    }
+
+    return CppResult(from: cResult)
+}
+
+// MARK: Intensity Wrapper
+
+/// Intensity contour for Swift API (copied out of IntensityContourFFI)
+public struct IntensityContour {
+    /// Linear RMS per frame (full scale = 1.0), without calibration
+    public let rms: [Float]
+    /// RMS level per frame in dB (dBFS + calibration offset)
+    public let intensityDb: [Float]
+    /// Absolute peak level per frame in dB (dBFS + calibration offset)
+    public let peakDb: [Float]
+    /// Frame starts in seconds, on the same grid as processBufferWrapper timestamps
+    public let timestamps: [Float]
+    /// Energy-averaged intensity in dB
+    public let meanDb: Float
+    public let minDb: Float
+    public let maxDb: Float
+    public let dynamicRangeDb: Float
+}
+
+/// Framing and calibration settings for computeIntensityWrapper
+public struct IntensityOptions {
+    public var frameSize: Int = 2048
+    public var hopSize: Int = 512
+    /// dB added to every dBFS level; a sound level meter reading gives approximate dB SPL
+    public var calibrationOffset: Float = 0
+    /// Lowest level in dBFS, applied before the offset
+    public var floorDb: Float = -120
+
+    public init() {}
+}
+
+/// Swift wrapper for `loqa_compute_intensity`
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: Framing, calibration offset and floor
+/// - Returns: RMS, peak and intensity contours with summary statistics
+public func computeIntensityWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: IntensityOptions = IntensityOptions()
+) throws -> IntensityContour {
+    guard !buffer.isEmpty else {
+        throw RustFFIError.invalidInput("Buffer cannot be empty")
+    }
+
+    guard sampleRate > 0 else {
+        throw RustFFIError.invalidInput("Sample rate must be positive")
+    }
+
+    guard options.frameSize > 0 && options.hopSize > 0 else {
+        throw RustFFIError.invalidInput("Frame size and hop size must be positive")
+    }
+
+    var config = loqa_intensity_config_default()
+    config.frame_size = UInt32(options.frameSize)
+    config.hop_size = UInt32(options.hopSize)
+    config.calibration_offset = options.calibrationOffset
+    config.floor_db = options.floorDb
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_compute_intensity(bufferPtr.baseAddress, buffer.count, UInt32(sampleRate), config)
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_intensity_contour(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Intensity computation")
+    }
+
+    guard let rmsPtr = cResult.rms_ptr,
+          let intensityPtr = cResult.intensity_db_ptr,
+          let peakPtr = cResult.peak_db_ptr,
+          let timesPtr = cResult.timestamps_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to Swift arrays before the deferred free
+    let count = cResult.frame_count
+    return IntensityContour(
+        rms: Array(UnsafeBufferPointer(start: rmsPtr, count: count)),
+        intensityDb: Array(UnsafeBufferPointer(start: intensityPtr, count: count)),
+        peakDb: Array(UnsafeBufferPointer(start: peakPtr, count: count)),
+        timestamps: Array(UnsafeBufferPointer(start: timesPtr, count: count)),
+        meanDb: cResult.mean_db,
+        minDb: cResult.min_db,
+        maxDb: cResult.max_db,
+        dynamicRangeDb: cResult.dynamic_range_db
+    )
+}
+
+// MARK: Voice Activity Wrapper
+
+/// Speech segment as the half-open sample range start..<end
+public struct SpeechSegment {
+    public let start: Int
+    public let end: Int
+}
+
+/// Voice activity for Swift API (copied out of VadResultFFI)
+public struct VoiceActivity {
+    /// Frame starts in seconds, on the same grid as processBufferWrapper timestamps
+    public let timestamps: [Float]
+    /// RMS level per frame in dBFS
+    public let energyDb: [Float]
+    /// Zero crossings per second per frame
+    public let zcr: [Float]
+    /// pYIN voicing probability per frame
+    public let voicedProbabilities: [Float]
+    /// Speech decision per frame, after hangover
+    public let isSpeech: [Bool]
+    /// Merged speech segments in buffer order
+    public let segments: [SpeechSegment]
+}
+
+/// Pitch range, framing and decision thresholds for detectVoiceActivityWrapper
+public struct VadOptions {
+    public var minFrequency: Float = 75
+    public var maxFrequency: Float = 500
+    public var frameSize: Int = 2048
+    public var hopSize: Int = 512
+    /// Lowest RMS level of a speech frame in dBFS
+    public var energyThresholdDb: Float = -50
+    /// Zero crossings per second marking unvoiced speech
+    public var zcrThreshold: Float = 3000
+    /// pYIN voicing probability of a voiced frame (0-1)
+    public var voicingThreshold: Float = 0.5
+    /// Seconds a speech decision is held after the last speech frame
+    public var hangoverTime: Float = 0.2
+
+    public init() {}
+}
+
+/// Swift wrapper for `loqa_detect_voice_activity`
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: pYIN range, framing and decision thresholds
+/// - Returns: Framewise features and decisions plus the speech segments
+public func detectVoiceActivityWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: VadOptions = VadOptions()
+) throws -> VoiceActivity {
+    var analysis = try pitchTrackingConfig(
+        buffer: buffer,
+        sampleRate: sampleRate,
+        minFrequency: options.minFrequency,
+        maxFrequency: options.maxFrequency
+    )
+
+    guard options.frameSize > 0 && options.hopSize > 0 else {
+        throw RustFFIError.invalidInput("Frame size and hop size must be positive")
+    }
+    analysis.frame_size = UInt32(options.frameSize)
+    analysis.hop_size = UInt32(options.hopSize)
+
+    var config = loqa_vad_config_default()
+    config.energy_threshold_db = options.energyThresholdDb
+    config.zcr_threshold = options.zcrThreshold
+    config.voicing_threshold = options.voicingThreshold
+    config.hangover_time = options.hangoverTime
+
+    var cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_detect_voice_activity(bufferPtr.baseAddress, buffer.count, analysis, config)
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_voice_activity(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Voice activity detection")
+    }
+
+    guard let timesPtr = cResult.timestamps_ptr,
+          let energyPtr = cResult.energy_db_ptr,
+          let zcrPtr = cResult.zcr_ptr,
+          let probsPtr = cResult.voiced_probs_ptr,
+          let speechPtr = cResult.is_speech_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to Swift arrays before the deferred free; no speech leaves segments_ptr nil
+    let count = cResult.frame_count
+    let segments = UnsafeBufferPointer(start: cResult.segments_ptr, count: cResult.segment_count)
+    return VoiceActivity(
+        timestamps: Array(UnsafeBufferPointer(start: timesPtr, count: count)),
+        energyDb: Array(UnsafeBufferPointer(start: energyPtr, count: count)),
+        zcr: Array(UnsafeBufferPointer(start: zcrPtr, count: count)),
+        voicedProbabilities: Array(UnsafeBufferPointer(start: probsPtr, count: count)),
+        isSpeech: Array(UnsafeBufferPointer(start: speechPtr, count: count)),
+        segments: segments.map { SpeechSegment(start: $0.start, end: $0.end) }
+    )
+}
+
+// MARK: Vocal Tract Length Wrapper
+
+/// Vocal tract length for Swift API (wraps VtlResultFFI from C header)
+public struct VocalTractLength {
+    /// Uniform-tube regression estimate in cm (primary)
+    public let vtlCm: Float
+    /// Average formant spacing estimate in cm
+    public let spacingVtlCm: Float
+    /// Formant spacing of vtlCm in Hz
+    public let formantSpacingHz: Float
+    /// Median F1-F4 over the frames used in Hz (0 if a formant was never confident)
+    public let f1: Float
+    public let f2: Float
+    public let f3: Float
+    public let f4: Float
+    /// 0.0-1.0: formant confidence × agreement between frames × coverage
+    public let confidence: Float
+    public let frameCount: Int
+
+    init(from c: VtlResultFFI) {
+        self.vtlCm = c.vtl_cm
+        self.spacingVtlCm = c.spacing_vtl_cm
+        self.formantSpacingHz = c.formant_spacing_hz
+        self.f1 = c.f1
+        self.f2 = c.f2
+        self.f3 = c.f3
+        self.f4 = c.f4
+        self.confidence = c.confidence
+        self.frameCount = Int(c.frame_count)
+    }
+}
+
+/// Pitch range, framing and formant settings for estimateVocalTractLengthWrapper
+public struct VtlOptions {
+    public var minFrequency: Float = 75
+    public var maxFrequency: Float = 500
+    public var frameSize: Int = 2048
+    public var hopSize: Int = 512
+    /// LPC order of the formant analysis (0 = sampleRate / 1000 + 2)
+    public var lpcOrder: Int = 0
+    /// Minimum confidence of a formant to be used (0-1)
+    public var minFormantConfidence: Float = 0.3
+    /// Speed of sound in the vocal tract in cm/s
+    public var speedOfSound: Float = 35000
+
+    public init() {}
+}
+
+/// Swift wrapper for `loqa_estimate_vocal_tract_length`
+/// MEMORY SAFETY: VtlResultFFI returned by value (no heap allocation, no cleanup needed)
+/// - Parameters:
+///   - buffer: Audio samples; must be longer than options.frameSize
+///   - options: pYIN range, framing and formant settings
+/// - Returns: Vocal tract length in cm from the voiced frames' formant spacing
+public func estimateVocalTractLengthWrapper(
+    buffer: [Float],
+    sampleRate: Int,
+    options: VtlOptions = VtlOptions()
+) throws -> VocalTractLength {
+    var analysis = try pitchTrackingConfig(
+        buffer: buffer,
+        sampleRate: sampleRate,
+        minFrequency: options.minFrequency,
+        maxFrequency: options.maxFrequency
+    )
+
+    guard options.frameSize > 0 && options.hopSize > 0 else {
+        throw RustFFIError.invalidInput("Frame size and hop size must be positive")
+    }
+    analysis.frame_size = UInt32(options.frameSize)
+    analysis.hop_size = UInt32(options.hopSize)
+
+    guard options.lpcOrder >= 0 else {
+        throw RustFFIError.invalidInput("LPC order must be 0 (default) or positive")
+    }
+
+    var config = loqa_vtl_config_default()
+    config.lpc_order = UInt32(options.lpcOrder)
+    config.min_formant_confidence = options.minFormantConfidence
+    config.speed_of_sound = options.speedOfSound
+
+    let cResult = buffer.withUnsafeBufferPointer { bufferPtr in
+        loqa_estimate_vocal_tract_length(bufferPtr.baseAddress, buffer.count, analysis, config)
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Vocal tract length estimation")
+    }
+
+    return VocalTractLength(from: cResult)
+}
+
+// MARK: - VoiceAnalyzer Wrapper (Streaming API)
+
+/// Pitch detection algorithm selection
+public enum PitchAlgorithm: UInt32 {
+    case auto = 0       // Auto-select best algorithm
+    case pyin = 1       // Probabilistic YIN (recommended)
+    case yin = 2        // Classic YIN
+    case autocorr = 3   // Autocorrelation
+}
+
+/// VoiceAnalyzer configuration for Swift
+public struct VoiceAnalyzerConfig {
+    public let sampleRate: UInt32
+    public let frameSize: UInt32
+    public let hopSize: UInt32
+    public let minFrequency: Float
+    public let maxFrequency: Float
+    public let algorithm: PitchAlgorithm
+    public let threshold: Float
+    public let minConfidence: Float
+    public let interpolate: Bool
+
+    public init(
+        sampleRate: UInt32,
+        minFrequency: Float = 80.0,
+        maxFrequency: Float = 400.0,
+        frameSize: UInt32 = 2048,
+        hopSize: UInt32 = 512,
+        algorithm: PitchAlgorithm = .auto,
+        threshold: Float = 0.1,
+        minConfidence: Float = 0.5,
+        interpolate: Bool = true
+    ) {
+        self.sampleRate = sampleRate
+        self.frameSize = frameSize
+        self.hopSize = hopSize
+        self.minFrequency = minFrequency
+        self.maxFrequency = maxFrequency
+        self.algorithm = algorithm
+        self.threshold = threshold
+        self.minConfidence = minConfidence
+        self.interpolate = interpolate
+    }
+
+    /// Convert to C FFI struct (field order must match Rust struct exactly)
+    func toFFI() -> AnalysisConfigFFI {
+        return AnalysisConfigFFI(
+            sample_rate: sampleRate,
             frame_size: frameSize,
             hop_size: hopSize,
             min_frequency: minFrequency,
@@ -498,7 +1458,7 @@ public class VoiceAnalyzerHandle {
 
     deinit {
         if let ptr = pointer {
-            loqa_voice_analyzer_free(ptr)
+            loqa_voice_analyzer_free_ex(ptr)
             pointer = nil
         }
     }
@@ -511,7 +1471,7 @@ public class VoiceAnalyzerHandle {
     /// Invalidate the handle (for manual cleanup)
     func invalidate() {
         if let ptr = pointer {
-            loqa_voice_analyzer_free(ptr)
+            loqa_voice_analyzer_free_ex(ptr)
             pointer = nil
         }
     }
@@ -535,10 +1495,9 @@ public func createVoiceAnalyzerWrapper(
         throw RustFFIError.invalidInput("Frame size and hop size must be positive")
     }
 
-    // Create the analyzer
-    let ffiConfig = config.toFFI()
-    guard let pointer = loqa_voice_analyzer_new(ffiConfig) else {
-        throw RustFFIError.memoryAllocationFailed
+    // Create the analyzer; Rust reports the exact reason a config is rejected
+    guard let pointer = loqa_voice_analyzer_create(config.toFFI()) else {
+        throw rustFFIError(loqa_last_error_code(), operation: "VoiceAnalyzer creation")
     }
 
     return VoiceAnalyzerHandle(pointer: pointer, config: config)
@@ -565,12 +1524,13 @@ public func processAudioWithAnalyzer(
     let maxResults = max(1, (samples.count - frameSize) / hopSize + 1)
 
     // Allocate output buffer for results
-    var resultsBuffer = [PitchResultFFI](repeating: PitchResultFFI(
+    var resultsBuffer = [PitchResultExFFI](repeating: PitchResultExFFI(
         success: false,
         frequency: 0,
         confidence: 0,
         is_voiced: false,
-        voiced_probability: 0
+        voiced_probability: 0,
+        error_code: LOQA_OK
     ), count: maxResults)
 
     // Call Rust function
@@ -580,7 +1540,7 @@ public func processAudioWithAnalyzer(
         return resultsBuffer.withUnsafeMutableBufferPointer { resultsPtr -> Int in
             guard let resultsBase = resultsPtr.baseAddress else { return 0 }
 
-            return loqa_voice_analyzer_process_stream(
+            return loqa_voice_analyzer_process_stream_ex(
                 pointer,
                 samplesBase,
                 samples.count,
@@ -590,6 +1550,12 @@ public func processAudioWithAnalyzer(
         }
     }
 
+    // 0 results is also a valid answer for a buffer shorter than one frame
+    let code = loqa_last_error_code()
+    guard resultCount > 0 || code == LOQA_OK else {
+        throw rustFFIError(code, operation: "VoiceAnalyzer processing")
+    }
+
     // Convert FFI results to Swift
     var pitchResults: [PitchResult] = []
     for i in 0..<resultCount {
@@ -607,7 +1573,7 @@ public func resetVoiceAnalyzerWrapper(analyzer: VoiceAnalyzerHandle) throws {
     guard let pointer = analyzer.getPointer() else {
         throw RustFFIError.invalidInput("Analyzer handle is invalid")
     }
-    loqa_voice_analyzer_reset(pointer)
+    loqa_voice_analyzer_reset_ex(pointer)
 }
 
 /// Free the VoiceAnalyzer (called automatically on handle deinit)
@@ -663,7 +1629,8 @@ public func processBufferWithAnalyzer(
                 pitch_track_ptr: nil,
                 voiced_probs_ptr: nil,
                 timestamps_ptr: nil,
-                length: 0
+                length: 0,
+                error_code: LOQA_ERR_NULL_POINTER
             )
         }
         return loqa_voice_analyzer_process_buffer(pointer, samplesBase, samples.count)
@@ -671,7 +1638,9 @@ public func processBufferWithAnalyzer(
 
     // Check for success
     guard result.success else {
-        throw RustFFIError.computationFailed("Buffer processing with Viterbi decoding failed")
+        let detail = loqa_last_error_message().map { String(cString: $0) }
+            ?? "error code \(result.error_code.rawValue)"
+        throw RustFFIError.computationFailed("Buffer processing with Viterbi decoding failed: \(detail)")
     }
 
     // Copy to Swift arrays before freeing Rust memory
@@ -703,26 +1672,849 @@ public func processBufferWithAnalyzer(
     )
 }
 
+/// Corrects octave jumps, fills short unvoiced gaps and smooths a pitch track
+///
+/// Octave correction runs first, then interpolation across gaps (in log-frequency),
+/// then smoothing within each voiced run. The result has the same frames as the
+/// input, with voiced probabilities and timestamps unchanged.
+///
+/// - Parameters:
+///   - track: PitchTrack, e.g. from processBufferWithAnalyzer
+///   - correctOctaveJumps: Whether to correct octave jumps
+///   - octaveWindow: Frames whose median is the octave reference (odd, 3-1001)
+///   - smoothing: LoqaPitchSmoothing value (0 none, 1 median, 2 moving average)
+///   - smoothingWindow: Frames in the smoothing window (odd, 1-1001)
+///   - maxGapFrames: Longest unvoiced run filled by interpolation (0 = none)
+/// - Returns: Post-processed PitchTrack
+/// - Throws: RustFFIError if the arrays or settings are invalid
+public func postprocessPitchTrackWrapper(
+    track: PitchTrack,
+    correctOctaveJumps: Bool,
+    octaveWindow: Int,
+    smoothing: Int,
+    smoothingWindow: Int,
+    maxGapFrames: Int
+) throws -> PitchTrack {
+    let length = track.pitchTrack.count
+    guard length > 0 else {
+        throw RustFFIError.invalidInput("Pitch track cannot be empty")
+    }
+    guard track.voicedProbabilities.count == length, track.timestamps.count == length else {
+        throw RustFFIError.invalidInput(
+            "pitchTrack, voicedProbabilities and timestamps must have equal lengths"
+        )
+    }
+    guard maxGapFrames >= 0 else {
+        throw RustFFIError.invalidInput("maxGapFrames must be >= 0, got \(maxGapFrames)")
+    }
+
+    var config = loqa_pitch_postprocess_config_default()
+    config.correct_octave_jumps = correctOctaveJumps
+    config.octave_window = UInt32(clamping: octaveWindow)
+    config.smoothing = UInt32(clamping: smoothing)
+    config.smoothing_window = UInt32(clamping: smoothingWindow)
+    config.max_gap_frames = UInt32(clamping: maxGapFrames)
+
+    var cResult = track.pitchTrack.withUnsafeBufferPointer { pitchPtr in
+        track.voicedProbabilities.withUnsafeBufferPointer { probsPtr in
+            track.timestamps.withUnsafeBufferPointer { timesPtr in
+                loqa_postprocess_pitch_track(
+                    pitchPtr.baseAddress,
+                    probsPtr.baseAddress,
+                    timesPtr.baseAddress,
+                    length,
+                    config
+                )
+            }
+        }
+    }
+    defer { loqa_free_pitch_track(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Pitch track post-processing")
+    }
+
+    func copy(_ pointer: UnsafeMutablePointer<Float>?) -> [Float] {
+        guard let pointer = pointer, cResult.length > 0 else { return [] }
+        return Array(UnsafeBufferPointer(start: pointer, count: cResult.length))
+    }
+    return PitchTrack(
+        pitchTrack: copy(cResult.pitch_track_ptr),
+        voicedProbabilities: copy(cResult.voiced_probs_ptr),
+        timestamps: copy(cResult.timestamps_ptr)
+    )
+}
+
+/// Pitch track statistics for Swift API (wraps PitchStatsFFI from C header);
+/// Hz and semitone measures are nil where Rust reports NaN
+public struct PitchStatistics {
+    public let frameCount: Int
+    public let voicedFrameCount: Int
+    public let voicedRatio: Float
+    public let meanHz: Float?
+    public let medianHz: Float?
+    public let stdHz: Float?
+    public let p5Hz: Float?
+    public let p95Hz: Float?
+    public let meanSemitones: Float?
+    public let medianSemitones: Float?
+    public let stdSemitones: Float?
+    public let p5Semitones: Float?
+    public let p95Semitones: Float?
+    public let rangeSemitones: Float?
+
+    init(from c: PitchStatsFFI) {
+        self.frameCount = Int(c.frame_count)
+        self.voicedFrameCount = Int(c.voiced_frame_count)
+        self.voicedRatio = c.voiced_ratio
+        self.meanHz = measureOrNil(c.mean_hz)
+        self.medianHz = measureOrNil(c.median_hz)
+        self.stdHz = measureOrNil(c.std_hz)
+        self.p5Hz = measureOrNil(c.p5_hz)
+        self.p95Hz = measureOrNil(c.p95_hz)
+        self.meanSemitones = measureOrNil(c.mean_semitones)
+        self.medianSemitones = measureOrNil(c.median_semitones)
+        self.stdSemitones = measureOrNil(c.std_semitones)
+        self.p5Semitones = measureOrNil(c.p5_semitones)
+        self.p95Semitones = measureOrNil(c.p95_semitones)
+        self.rangeSemitones = measureOrNil(c.range_semitones)
+    }
+}
+
+/// Summarizes a pitch track in Hz and semitones
+///
+/// Voiced frames (pitch > 0) are weighted by voiced probability unless
+/// weightByVoicing is false. Computed in Rust in double precision, so the same
+/// track gives the same statistics as on Android.
+///
+/// MEMORY SAFETY: PitchStatsFFI returned by value (no heap allocation, no cleanup needed)
+///
+/// - Parameters:
+///   - pitchTrack: Pitch per frame in Hz (0 = unvoiced)
+///   - voicedProbabilities: Voiced probability per frame in [0, 1] (same length)
+///   - referenceHz: Frequency of 0 semitones in Hz
+///   - weightByVoicing: Weight voiced frames by voiced probability
+/// - Returns: PitchStatistics
+/// - Throws: RustFFIError if the arrays or settings are invalid
+public func pitchTrackStatisticsWrapper(
+    pitchTrack: [Float],
+    voicedProbabilities: [Float],
+    referenceHz: Float = 100,
+    weightByVoicing: Bool = true
+) throws -> PitchStatistics {
+    guard !pitchTrack.isEmpty else {
+        throw RustFFIError.invalidInput("Pitch track cannot be empty")
+    }
+    guard voicedProbabilities.count == pitchTrack.count else {
+        throw RustFFIError.invalidInput(
+            "pitchTrack and voicedProbabilities must have equal lengths"
+        )
+    }
+
+    var config = loqa_pitch_stats_config_default()
+    config.reference_hz = referenceHz
+    config.weight_by_voicing = weightByVoicing
+
+    let cResult = pitchTrack.withUnsafeBufferPointer { pitchPtr in
+        voicedProbabilities.withUnsafeBufferPointer { probsPtr in
+            loqa_pitch_track_statistics(
+                pitchPtr.baseAddress,
+                probsPtr.baseAddress,
+                pitchTrack.count,
+                config
+            )
+        }
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Pitch track statistics")
+    }
+    return PitchStatistics(from: cResult)
+}
+
+// MARK: - Vibrato Wrapper
+
+/// Vibrato detection ranges and sliding windows
+public struct VibratoOptions {
+    /// Slowest and fastest vibrato rate in Hz
+    public var minRateHz: Float = 4
+    public var maxRateHz: Float = 8
+    /// Smallest and largest vibrato extent in ± cents
+    public var minExtentCents: Float = 15
+    public var maxExtentCents: Float = 200
+    /// Fewest full cycles in a segment (>= 2)
+    public var minCycles: Int = 2
+    /// Sliding window length in seconds (0 = no windows)
+    public var windowSeconds: Float = 1
+    /// Frames between window ends (>= 1)
+    public var windowHopFrames: Int = 1
+
+    public init() {}
+}
+
+/// One stretch of regular vibrato; times in seconds from the first frame
+public struct VibratoSegment {
+    public let start: Float
+    public let end: Float
+    public let rateHz: Float
+    public let extentCents: Float
+    public let rateJitter: Float
+    public let extentJitter: Float
+    /// Seconds from the start of the voiced note to start
+    public let onsetDelay: Float
+}
+
+/// Vibrato for Swift API (copied out of VibratoFFI); summary measures and
+/// window values are nil where no vibrato was found
+public struct VibratoAnalysis {
+    public let vibratoRatio: Float
+    public let rateHz: Float?
+    public let extentCents: Float?
+    public let rateJitter: Float?
+    public let extentJitter: Float?
+    public let onsetDelay: Float?
+    public let segments: [VibratoSegment]
+    /// Time of each sliding window's last frame, in seconds from the first frame
+    public let windowTimes: [Float]
+    public let windowRateHz: [Float?]
+    public let windowExtentCents: [Float?]
+    public let windowRateJitter: [Float?]
+    public let windowExtentJitter: [Float?]
+}
+
+/// Finds vibrato in a pitch track and measures its rate, extent, regularity and onset
+///
+/// The last sliding window always ends at the last frame, so a live display
+/// can pass the recent frames of a stream and read the final window.
+///
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+///
+/// - Parameters:
+///   - pitchTrack: Pitch per frame in Hz (0 = unvoiced)
+///   - frameRate: Pitch frames per second (sampleRate / hopSize)
+///   - options: Detection ranges and sliding windows
+/// - Returns: VibratoAnalysis
+/// - Throws: RustFFIError if the track or settings are invalid
+public func analyzeVibratoWrapper(
+    pitchTrack: [Float],
+    frameRate: Float,
+    options: VibratoOptions = VibratoOptions()
+) throws -> VibratoAnalysis {
+    guard !pitchTrack.isEmpty else {
+        throw RustFFIError.invalidInput("Pitch track cannot be empty")
+    }
+
+    var config = loqa_vibrato_config_default()
+    config.frame_rate = frameRate
+    config.min_rate_hz = options.minRateHz
+    config.max_rate_hz = options.maxRateHz
+    config.min_extent_cents = options.minExtentCents
+    config.max_extent_cents = options.maxExtentCents
+    config.min_cycles = UInt32(clamping: options.minCycles)
+    config.window_seconds = options.windowSeconds
+    config.window_hop_frames = UInt32(clamping: options.windowHopFrames)
+
+    var cResult = pitchTrack.withUnsafeBufferPointer { pitchPtr in
+        loqa_analyze_vibrato(pitchPtr.baseAddress, pitchTrack.count, config)
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_vibrato(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Vibrato analysis")
+    }
+
+    // Copy to Swift arrays before the deferred free; no vibrato or windows leave the pointers nil
+    let count = cResult.window_count
+    let windowValues = { (ptr: UnsafeMutablePointer<Float>?) -> [Float?] in
+        UnsafeBufferPointer(start: ptr, count: count).map { measureOrNil($0) }
+    }
+    let segments = UnsafeBufferPointer(start: cResult.segments_ptr, count: cResult.segment_count)
+    return VibratoAnalysis(
+        vibratoRatio: cResult.vibrato_ratio,
+        rateHz: measureOrNil(cResult.rate_hz),
+        extentCents: measureOrNil(cResult.extent_cents),
+        rateJitter: measureOrNil(cResult.rate_jitter),
+        extentJitter: measureOrNil(cResult.extent_jitter),
+        onsetDelay: measureOrNil(cResult.onset_delay),
+        segments: segments.map {
+            VibratoSegment(
+                start: $0.start,
+                end: $0.end,
+                rateHz: $0.rate_hz,
+                extentCents: $0.extent_cents,
+                rateJitter: $0.rate_jitter,
+                extentJitter: $0.extent_jitter,
+                onsetDelay: $0.onset_delay
+            )
+        },
+        windowTimes: Array(UnsafeBufferPointer(start: cResult.window_times_ptr, count: count)),
+        windowRateHz: windowValues(cResult.window_rate_ptr),
+        windowExtentCents: windowValues(cResult.window_extent_ptr),
+        windowRateJitter: windowValues(cResult.window_rate_jitter_ptr),
+        windowExtentJitter: windowValues(cResult.window_extent_jitter_ptr)
+    )
+}
+
+// MARK: - Streaming Analyzer Wrapper (pushes of any size)
+
+/// Frames completed by one push or flush, in stream order
+public struct StreamFrames {
+    /// Pitch per frame in Hz (0.0 = unvoiced)
+    public let frequencies: [Float]
+    /// Confidence per frame [0.0, 1.0]
+    public let confidences: [Float]
+    /// Voicing decision per frame
+    public let isVoiced: [Bool]
+    /// Voiced probability per frame [0.0, 1.0]
+    public let voicedProbabilities: [Float]
+    /// Frame starts in seconds since the stream began
+    public let timestamps: [Float]
+
+    /// Copies the arrays out of a StreamFramesFFI (the caller frees it)
+    init(from c: StreamFramesFFI) {
+        let count = c.frame_count
+        func copy<T>(_ pointer: UnsafeMutablePointer<T>?) -> [T] {
+            guard let pointer = pointer, count > 0 else { return [] }
+            return Array(UnsafeBufferPointer(start: pointer, count: count))
+        }
+        frequencies = copy(c.frequencies_ptr)
+        confidences = copy(c.confidences_ptr)
+        isVoiced = copy(c.is_voiced_ptr)
+        voicedProbabilities = copy(c.voiced_probs_ptr)
+        timestamps = copy(c.timestamps_ptr)
+    }
+}
+
+/// Opaque handle to a streaming analyzer, which buffers samples between pushes
+/// MEMORY SAFETY: The pointer is owned by this class and freed on deinit
+public class StreamAnalyzerHandle {
+    private let pointer: UnsafeMutableRawPointer
+    public let config: VoiceAnalyzerConfig
+
+    init(pointer: UnsafeMutableRawPointer, config: VoiceAnalyzerConfig) {
+        self.pointer = pointer
+        self.config = config
+    }
+
+    deinit {
+        loqa_stream_analyzer_free(pointer)
+    }
+
+    /// Get the raw pointer for FFI calls
+    func getPointer() -> UnsafeMutableRawPointer {
+        return pointer
+    }
+}
+
+/// Creates a streaming analyzer with the framing and pitch range of `config`
+/// MEMORY SAFETY: Returns a handle that owns the Rust memory; freed on handle deinit
+public func createStreamAnalyzerWrapper(
+    config: VoiceAnalyzerConfig
+) throws -> StreamAnalyzerHandle {
+    guard let pointer = loqa_stream_analyzer_new(config.toFFI()) else {
+        throw rustFFIError(loqa_last_error_code(), operation: "Streaming analyzer creation")
+    }
+    return StreamAnalyzerHandle(pointer: pointer, config: config)
+}
+
+/// Pushes samples of any length (e.g. one microphone callback) into a stream
+///
+/// Frames start every `hopSize` samples from the first push and their
+/// timestamps run on across pushes, so no buffering is needed by the caller.
+/// Pushes to one handle must not overlap.
+///
+/// - Returns: The frames the samples completed (possibly none)
+/// - Throws: RustFFIError if the handle is invalid
+public func pushToStreamAnalyzer(
+    analyzer: StreamAnalyzerHandle,
+    samples: [Float]
+) throws -> StreamFrames {
+    var cResult = samples.withUnsafeBufferPointer { samplesPtr in
+        loqa_stream_analyzer_push(analyzer.getPointer(), samplesPtr.baseAddress, samples.count)
+    }
+    defer { loqa_free_stream_frames(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Streaming push")
+    }
+    return StreamFrames(from: cResult)
+}
+
+/// Analyses the zero-padded tail of a stream; the next push starts at timestamp 0
+public func flushStreamAnalyzerWrapper(analyzer: StreamAnalyzerHandle) throws -> StreamFrames {
+    var cResult = loqa_stream_analyzer_flush(analyzer.getPointer())
+    defer { loqa_free_stream_frames(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Streaming flush")
+    }
+    return StreamFrames(from: cResult)
+}
+
+/// Discards the buffered samples of a stream and restarts timestamps at 0
+public func resetStreamAnalyzerWrapper(analyzer: StreamAnalyzerHandle) {
+    loqa_stream_analyzer_reset(analyzer.getPointer())
+}
+
+// MARK: - Tuner Wrapper (nearest note, cents deviation and stability)
+
+/// Latest reading of a tuner; the note fields are only meaningful when `isVoiced`
+public struct TunerReading {
+    /// Frames analysed by the push; 0 means the reading is the previous one
+    public let frameCount: Int
+    /// Start of the frame read in seconds since the stream began
+    public let timestamp: Float
+    public let isVoiced: Bool
+    /// Refined pitch in Hz (0.0 if unvoiced)
+    public let frequency: Float
+    public let confidence: Float
+    /// MIDI note number of the nearest note (A4 = 69)
+    public let midiNote: Int
+    /// Pitch class of the nearest note, 0 (C) to 11 (B)
+    public let pitchClass: Int
+    /// Octave in scientific pitch notation (middle C is C4)
+    public let octave: Int
+    /// Deviation from the tuned note in cents; positive is sharp
+    public let cents: Float
+    /// Frequency the nearest note is tuned to in Hz
+    public let targetFrequency: Float
+    /// 0.0-1.0: how steadily the note has been held
+    public let stability: Float
+
+    init(from c: TunerReadingFFI) {
+        frameCount = Int(c.frame_count)
+        timestamp = c.timestamp
+        isVoiced = c.is_voiced
+        frequency = c.frequency
+        confidence = c.confidence
+        midiNote = Int(c.midi_note)
+        pitchClass = Int(c.pitch_class)
+        octave = Int(c.octave)
+        cents = c.cents
+        targetFrequency = c.target_frequency
+        stability = c.stability
+    }
+}
+
+/// Opaque handle to a tuner
+/// MEMORY SAFETY: The pointer is owned by this class and freed on deinit
+public class TunerHandle {
+    private let pointer: UnsafeMutableRawPointer
+
+    init(pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    deinit {
+        loqa_tuner_free(pointer)
+    }
+
+    /// Get the raw pointer for FFI calls
+    func getPointer() -> UnsafeMutableRawPointer {
+        return pointer
+    }
+}
+
+/// Creates a tuner with the framing and pitch range of `config`
+///
+/// - Parameters:
+///   - referenceHz: Frequency of A4 in Hz
+///   - temperamentCents: Offsets of C, C#, ..., B from equal temperament in cents
+///     (12 values within ±50; all zeros is equal temperament)
+///   - stabilityFrames: Frames over which stability is measured, in 1...1000
+/// MEMORY SAFETY: Returns a handle that owns the Rust memory; freed on handle deinit
+public func createTunerWrapper(
+    config: VoiceAnalyzerConfig,
+    referenceHz: Float,
+    temperamentCents: [Float],
+    stabilityFrames: Int
+) throws -> TunerHandle {
+    var tunerConfig = loqa_tuner_config_default()
+    tunerConfig.reference_hz = referenceHz
+    tunerConfig.stability_frames = UInt32(clamping: stabilityFrames)
+
+    // The C array imports as a tuple of 12 Floats
+    let slots = MemoryLayout.size(ofValue: tunerConfig.temperament_cents) / MemoryLayout<Float>.size
+    guard temperamentCents.count == slots else {
+        throw RustFFIError.invalidInput(
+            "temperamentCents must hold \(slots) offsets, got \(temperamentCents.count)"
+        )
+    }
+    withUnsafeMutableBytes(of: &tunerConfig.temperament_cents) { bytes in
+        let offsets = bytes.bindMemory(to: Float.self)
+        for (index, cents) in temperamentCents.enumerated() {
+            offsets[index] = cents
+        }
+    }
+
+    guard let pointer = loqa_tuner_new(config.toFFI(), tunerConfig) else {
+        throw rustFFIError(loqa_last_error_code(), operation: "Tuner creation")
+    }
+    return TunerHandle(pointer: pointer)
+}
+
+/// Pushes samples of any length (e.g. one microphone callback) into a tuner
+///
+/// Pushes to one handle must not overlap.
+///
+/// - Returns: The reading after the frames the samples completed
+/// - Throws: RustFFIError if the handle is invalid
+public func pushToTuner(tuner: TunerHandle, samples: [Float]) throws -> TunerReading {
+    let cResult = samples.withUnsafeBufferPointer { samplesPtr in
+        loqa_tuner_push(tuner.getPointer(), samplesPtr.baseAddress, samples.count)
+    }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Tuner push")
+    }
+    return TunerReading(from: cResult)
+}
+
+/// Discards a tuner's buffered samples and reading
+public func resetTunerWrapper(tuner: TunerHandle) {
+    loqa_tuner_reset(tuner.getPointer())
+}
+
+// MARK: - Voice Range Profile Wrapper (semitone x dB phonetogram)
+
+/// Grid and coverage threshold of a voice range profile
+public struct VoiceRangeProfileOptions {
+    /// Frequency of A4 in Hz
+    public var referenceHz: Float = 440
+    /// MIDI note of the first row, in 0...127
+    public var minMidiNote: Int = 24
+    /// MIDI note of the last row, in minMidiNote...127
+    public var maxMidiNote: Int = 108
+    /// Lower edge of the first level column in dB
+    public var minDb: Float = 40
+    /// Upper end of the level range in dB
+    public var maxDb: Float = 120
+    /// Width of a level column in dB (at most 1000 columns)
+    public var dbStep: Float = 1
+    /// Frames a cell needs before it counts as sustained
+    public var minCellFrames: Int = 3
+
+    public init() {}
+}
+
+/// Snapshot of a voice range profile (copied out of VrpFFI)
+public struct VoiceRangeProfile {
+    /// Row-major rowCount x columnCount frame counts; row r is MIDI note minMidiNote + r
+    public let counts: [Int]
+    public let rowCount: Int
+    /// Level columns; column c starts at minDb + c * dbStep
+    public let columnCount: Int
+    public let minMidiNote: Int
+    public let minDb: Float
+    public let dbStep: Float
+    /// Per note: lower edge of the lowest sustained cell in dB (nil if none)
+    public let softDb: [Float?]
+    /// Per note: upper edge of the highest sustained cell in dB (nil if none)
+    public let loudDb: [Float?]
+    /// Per note: loudDb - softDb (nil if the note is not sustained)
+    public let dynamicRangeDb: [Float?]
+    public let lowestMidiNote: Int?
+    public let highestMidiNote: Int?
+    /// Equal-tempered frequency of the lowest sustained note in Hz
+    public let lowestHz: Float?
+    /// Equal-tempered frequency of the highest sustained note in Hz
+    public let highestHz: Float?
+    public let coveredCells: Int
+    /// Sustained cells times dbStep in semitone-dB
+    public let area: Float
+    /// Frames binned into the grid so far
+    public let frameCount: Int
+}
+
+/// Opaque handle to a voice range profile
+/// MEMORY SAFETY: The pointer is owned by this class and freed on deinit
+public class VoiceRangeProfileHandle {
+    private let pointer: UnsafeMutableRawPointer
+
+    init(pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    deinit {
+        loqa_vrp_free(pointer)
+    }
+
+    /// Get the raw pointer for FFI calls
+    func getPointer() -> UnsafeMutableRawPointer {
+        return pointer
+    }
+}
+
+/// Creates an empty voice range profile
+/// MEMORY SAFETY: Returns a handle that owns the Rust memory; freed on handle deinit
+public func createVoiceRangeProfileWrapper(
+    options: VoiceRangeProfileOptions = VoiceRangeProfileOptions()
+) throws -> VoiceRangeProfileHandle {
+    // Negative notes would clamp to the valid note 0
+    guard options.minMidiNote >= 0 && options.maxMidiNote >= 0 else {
+        throw RustFFIError.invalidInput("MIDI notes must be in 0...127")
+    }
+
+    var config = loqa_vrp_config_default()
+    config.reference_hz = options.referenceHz
+    config.min_midi_note = UInt32(clamping: options.minMidiNote)
+    config.max_midi_note = UInt32(clamping: options.maxMidiNote)
+    config.min_db = options.minDb
+    config.max_db = options.maxDb
+    config.db_step = options.dbStep
+    config.min_cell_frames = UInt32(clamping: options.minCellFrames)
+
+    guard let pointer = loqa_vrp_new(config) else {
+        throw rustFFIError(loqa_last_error_code(), operation: "Voice range profile creation")
+    }
+    return VoiceRangeProfileHandle(pointer: pointer)
+}
+
+/// Adds framewise pitch (Hz, 0 = unvoiced) and intensity (dB) on the same frame grid
+///
+/// Unvoiced frames and frames outside the grid are skipped.
+/// - Throws: RustFFIError if the arrays differ in length or hold invalid values
+public func addVoiceRangeFramesWrapper(
+    profile: VoiceRangeProfileHandle,
+    pitchTrack: [Float],
+    intensityDb: [Float]
+) throws {
+    guard pitchTrack.count == intensityDb.count else {
+        throw RustFFIError.invalidInput(
+            "pitchTrack and intensityDb must have equal lengths, got \(pitchTrack.count) and \(intensityDb.count)"
+        )
+    }
+
+    let code = pitchTrack.withUnsafeBufferPointer { pitchPtr in
+        intensityDb.withUnsafeBufferPointer { intensityPtr in
+            loqa_vrp_add_frames(
+                profile.getPointer(),
+                pitchPtr.baseAddress,
+                intensityPtr.baseAddress,
+                pitchTrack.count
+            )
+        }
+    }
+    guard code == LOQA_OK else {
+        throw rustFFIError(code, operation: "Voice range profile update")
+    }
+}
+
+/// Adds the counts of `source` to `target`; both must share their options
+public func mergeVoiceRangeProfilesWrapper(
+    target: VoiceRangeProfileHandle,
+    source: VoiceRangeProfileHandle
+) throws {
+    let code = loqa_vrp_merge(target.getPointer(), source.getPointer())
+    guard code == LOQA_OK else {
+        throw rustFFIError(code, operation: "Voice range profile merge")
+    }
+}
+
+/// Reads the grid, per-note dynamics and sustainable range of a profile
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+public func getVoiceRangeProfileWrapper(
+    profile: VoiceRangeProfileHandle
+) throws -> VoiceRangeProfile {
+    var cResult = loqa_vrp_get(profile.getPointer())
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_vrp(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Voice range profile read")
+    }
+
+    guard let countsPtr = cResult.counts_ptr,
+          let softPtr = cResult.soft_db_ptr,
+          let loudPtr = cResult.loud_db_ptr,
+          let rangePtr = cResult.dynamic_range_db_ptr else {
+        throw RustFFIError.memoryAllocationFailed
+    }
+
+    // Copy to Swift arrays before the deferred free
+    let rows = cResult.row_count
+    let levels = { (ptr: UnsafeMutablePointer<Float>) -> [Float?] in
+        UnsafeBufferPointer(start: ptr, count: rows).map { measureOrNil($0) }
+    }
+    let note = { (midi: Int32) -> Int? in midi < 0 ? nil : Int(midi) }
+    return VoiceRangeProfile(
+        counts: UnsafeBufferPointer(start: countsPtr, count: rows * cResult.column_count)
+            .map { Int($0) },
+        rowCount: rows,
+        columnCount: cResult.column_count,
+        minMidiNote: Int(cResult.min_midi_note),
+        minDb: cResult.min_db,
+        dbStep: cResult.db_step,
+        softDb: levels(softPtr),
+        loudDb: levels(loudPtr),
+        dynamicRangeDb: levels(rangePtr),
+        lowestMidiNote: note(cResult.lowest_midi_note),
+        highestMidiNote: note(cResult.highest_midi_note),
+        lowestHz: measureOrNil(cResult.lowest_hz),
+        highestHz: measureOrNil(cResult.highest_hz),
+        coveredCells: Int(cResult.covered_cells),
+        area: cResult.area,
+        frameCount: Int(cResult.frame_count)
+    )
+}
+
+/// Empties a profile's grid
+public func resetVoiceRangeProfileWrapper(profile: VoiceRangeProfileHandle) {
+    loqa_vrp_reset(profile.getPointer())
+}
+
+// MARK: - Formant Tracker Wrapper (framewise LPC with Viterbi continuity of F1-F4)
+
+/// Framing, LPC order, ceiling and reliability limits of a formant tracker
+public struct FormantTrackerOptions {
+    /// LPC window in samples (10-100 ms; nil = 25 ms)
+    public var frameSize: Int?
+    /// Samples between frame starts, in 1...frameSize (nil = 10 ms)
+    public var hopSize: Int?
+    /// LPC order in 8...24 (0 = sampleRate / 1000 + 2)
+    public var lpcOrder: Int = 0
+    /// Highest formant frequency in Hz, in 3000...7000 and below sampleRate / 2
+    public var ceilingHz: Float = 5500
+    /// Weight of continuity against fit to neutral-vowel formants (>= 0)
+    public var continuityWeight: Float = 1
+    /// Minimum mean F1-F3 confidence of a reliable frame, in 0...1
+    public var minConfidence: Float = 0.3
+    /// Frames below this RMS level in dBFS (<= 0) are unreliable
+    public var silenceDb: Float = -50
+
+    public init() {}
+}
+
+/// Formant contours of one buffer (copied out of FormantTrackFFI)
+public struct FormantTrack {
+    /// Row-major frameCount x 4 F1-F4 in Hz (0.0 = missing)
+    public let frequencies: [Float]
+    /// Bandwidths of the same formants in Hz
+    public let bandwidths: [Float]
+    /// Mean F1-F3 confidence per frame [0.0, 1.0]
+    public let confidences: [Float]
+    /// False for silent, incomplete or low-confidence frames
+    public let reliable: [Bool]
+    /// Frame starts in seconds from buffer start
+    public let timestamps: [Float]
+
+    /// Copies the arrays out of a FormantTrackFFI (the caller frees it)
+    init(from c: FormantTrackFFI) {
+        let count = c.length
+        func copy<T>(_ pointer: UnsafeMutablePointer<T>?, _ length: Int) -> [T] {
+            guard let pointer = pointer, length > 0 else { return [] }
+            return Array(UnsafeBufferPointer(start: pointer, count: length))
+        }
+        frequencies = copy(c.frequencies_ptr, count * 4)
+        bandwidths = copy(c.bandwidths_ptr, count * 4)
+        confidences = copy(c.confidences_ptr, count)
+        reliable = copy(c.reliable_ptr, count)
+        timestamps = copy(c.timestamps_ptr, count)
+    }
+}
+
+/// Opaque handle to a formant tracker
+/// MEMORY SAFETY: The pointer is owned by this class and freed on deinit
+public class FormantTrackerHandle {
+    private let pointer: UnsafeMutableRawPointer
+
+    init(pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    deinit {
+        loqa_formant_tracker_free(pointer)
+    }
+
+    /// Get the raw pointer for FFI calls
+    func getPointer() -> UnsafeMutableRawPointer {
+        return pointer
+    }
+}
+
+/// Creates a formant tracker that keeps F1-F4 continuous across frames and buffers
+/// MEMORY SAFETY: Returns a handle that owns the Rust memory; freed on handle deinit
+public func createFormantTrackerWrapper(
+    sampleRate: Int,
+    options: FormantTrackerOptions = FormantTrackerOptions()
+) throws -> FormantTrackerHandle {
+    // A negative order would clamp to 0, which picks the order from the sample rate
+    guard options.lpcOrder >= 0 else {
+        throw RustFFIError.invalidInput("lpcOrder must be in 8...24, or 0 for automatic")
+    }
+
+    var config = loqa_formant_tracker_config_default()
+    config.sample_rate = UInt32(clamping: sampleRate)
+    config.frame_size = UInt32(clamping: options.frameSize ?? (sampleRate / 40))
+    config.hop_size = UInt32(clamping: options.hopSize ?? (sampleRate / 100))
+    config.lpc_order = UInt32(clamping: options.lpcOrder)
+    config.ceiling_hz = options.ceilingHz
+    config.continuity_weight = options.continuityWeight
+    config.min_confidence = options.minConfidence
+    config.silence_db = options.silenceDb
+
+    guard let pointer = loqa_formant_tracker_new(config) else {
+        throw rustFFIError(loqa_last_error_code(), operation: "Formant tracker creation")
+    }
+    return FormantTrackerHandle(pointer: pointer)
+}
+
+/// Tracks F1-F4 over the frames of a buffer, continuing from the previous buffer
+///
+/// Calls on one handle must not overlap.
+/// MEMORY SAFETY: Uses defer to guarantee Rust memory is freed
+///
+/// - Throws: RustFFIError if the buffer is empty or not longer than a frame
+public func trackFormantsWrapper(
+    tracker: FormantTrackerHandle,
+    samples: [Float]
+) throws -> FormantTrack {
+    var cResult = samples.withUnsafeBufferPointer { samplesPtr in
+        loqa_formant_tracker_process_buffer(
+            tracker.getPointer(),
+            samplesPtr.baseAddress,
+            samples.count
+        )
+    }
+    // Free Rust-allocated memory on every path
+    defer { loqa_free_formant_track(&cResult) }
+
+    guard cResult.success else {
+        throw rustFFIError(cResult.error_code, operation: "Formant tracking")
+    }
+    return FormantTrack(from: cResult)
+}
+
+/// Makes a formant tracker forget the previous buffer
+public func resetFormantTrackerWrapper(tracker: FormantTrackerHandle) {
+    loqa_formant_tracker_reset(tracker.getPointer())
+}
+
 /*
  MEMORY MANAGEMENT PATTERN FOR FFI CALLS
  ========================================
 
  This file implements a consistent pattern for safe memory management across the FFI boundary:
 
- 1. POINTER-RETURNING FUNCTIONS (e.g., loqa_compute_fft):
+ 1. POINTER-RETURNING FUNCTIONS (e.g., loqa_compute_fft_ex):
     - Rust allocates memory and returns a struct with pointers
     - Swift copies data to Swift-owned arrays immediately
-    - Swift calls loqa_free_fft_result() to deallocate Rust memory
+    - Swift calls loqa_free_fft_result_ex() to deallocate Rust memory
     - Pattern: allocate -> copy -> free -> return Swift array
 
- 2. VALUE-RETURNING FUNCTIONS (e.g., loqa_detect_pitch):
+ 2. VALUE-RETURNING FUNCTIONS (e.g., loqa_detect_pitch_ex):
     - Rust returns a struct by value (not a pointer)
-    - Each struct has a 'success' field to indicate success/failure
+    - Each struct has a 'success' field or an 'error_code' to indicate failure
     - No memory management needed - struct is copied to Swift stack
     - Pattern: call -> check success -> return Swift tuple
 
+ Only the wrapper's own exports are called: they turn Rust panics into
+ LOQA_ERR_PANIC, where upstream's loqa_detect_pitch, loqa_compute_fft,
+ loqa_calculate_hnr, loqa_voice_analyzer_process_stream, etc. would abort.
+
  CRITICAL SAFETY RULES:
- - Always check the 'success' field before using other fields
+ - Always check the 'success' field (or 'error_code') before using other fields
  - Always copy pointer data before any operation that could fail
  - Always free Rust memory in the same function that receives it
  - Never store Rust pointers in Swift properties
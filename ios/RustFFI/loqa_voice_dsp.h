@@ -1,7 +1,19 @@
 // loqa_voice_dsp.h
-// C header for Rust FFI functions exported by loqa-voice-dsp v0.4.0
+// C header for Rust FFI functions exported by the loqa-expo-dsp wrapper crate (rust/)
 // This header allows Swift to call Rust extern "C" functions with proper C ABI
 //
+// Every declaration below is checked against the Rust exports by `cargo test`
+// (rust/src/c_abi.rs): function names, signatures and struct layouts must match.
+// Update both sides together.
+//
+// loqa_detect_pitch, loqa_extract_formants, loqa_compute_fft/loqa_free_fft_result,
+// loqa_analyze_spectrum, loqa_calculate_hnr, loqa_calculate_h1h2 and
+// loqa_voice_analyzer_new/process_frame/process_stream/reset/free come straight
+// from upstream loqa-voice-dsp and abort the app if they panic. They are kept for
+// ABI compatibility only; new code calls the panic-safe replacements (the *_ex
+// functions, loqa_detect_pitch_range, loqa_voice_analyzer_create), which report
+// failures as error codes.
+//
 // BREAKING CHANGES from v0.2.x:
 // - All structs now have 'success' field first
 // - PitchResultFFI adds 'voiced_probability' field
@@ -16,6 +28,59 @@
 #include <stdbool.h>
 #include <stddef.h>
 
+// MARK: - Error reporting (thread-local last error)
+
+/// Stable error codes (values never change; new codes are only appended)
+typedef enum {
+    LOQA_OK = 0,
+    LOQA_ERR_NULL_POINTER = 1,
+    LOQA_ERR_INVALID_LENGTH = 2,
+    LOQA_ERR_INVALID_SAMPLE_RATE = 3,
+    LOQA_ERR_INVALID_FFT_SIZE = 4,
+    LOQA_ERR_INVALID_LPC_ORDER = 5,
+    LOQA_ERR_BUFFER_TOO_SHORT = 6,
+    LOQA_ERR_INVALID_FREQUENCY_RANGE = 7,
+    LOQA_ERR_INVALID_ARGUMENT = 8,
+    LOQA_ERR_ANALYSIS_FAILED = 9,
+    LOQA_ERR_PANIC = 10,
+} LoqaErrorCode;
+
+/// Error code recorded by the most recent wrapper call on the calling thread
+LoqaErrorCode loqa_last_error_code(void);
+
+/// Message for the most recent failing call on the calling thread, or NULL
+/// Owned by Rust; valid until the next call on the same thread (copy it immediately)
+const char* loqa_last_error_message(void);
+
+/// Clear the calling thread's last error
+void loqa_clear_last_error(void);
+
+// MARK: - Logging
+
+/// Message severity; also used as the filter threshold for loqa_set_log_level
+typedef enum {
+    LOQA_LOG_OFF = 0,
+    LOQA_LOG_ERROR = 1,
+    LOQA_LOG_WARN = 2,
+    LOQA_LOG_INFO = 3,
+    LOQA_LOG_DEBUG = 4,
+    LOQA_LOG_TRACE = 5,
+} LoqaLogLevel;
+
+/// Receives Rust diagnostics; may be called from any thread
+/// message is only valid for the duration of the call (copy it)
+typedef void (*LoqaLogCallback)(LoqaLogLevel level, const char* message);
+
+/// Route Rust diagnostics to callback (NULL stops logging, the default)
+void loqa_set_log_callback(LoqaLogCallback callback);
+
+/// Deliver messages up to and including level (default LOQA_LOG_WARN)
+/// Returns LOQA_ERR_INVALID_ARGUMENT for values outside LoqaLogLevel
+LoqaErrorCode loqa_set_log_level(uint32_t level);
+
+/// Current level set by loqa_set_log_level (a LoqaLogLevel value)
+uint32_t loqa_log_level(void);
+
 // MARK: - Result Structs (matching Rust #[repr(C)] layout)
 
 /// Pitch detection result (v0.4.0 - includes voiced_probability)
@@ -70,6 +135,14 @@ typedef struct {
     float f0;
 } H1H2ResultFFI;
 
+/// Pitch algorithm selector for AnalysisConfigFFI.algorithm and loqa_detect_pitch_ex
+typedef enum {
+    LOQA_PITCH_ALGORITHM_AUTO = 0,      // pYIN -> YIN -> Autocorr fallback (recommended)
+    LOQA_PITCH_ALGORITHM_PYIN = 1,
+    LOQA_PITCH_ALGORITHM_YIN = 2,
+    LOQA_PITCH_ALGORITHM_AUTOCORR = 3,
+} LoqaPitchAlgorithm;
+
 /// VoiceAnalyzer configuration (must match Rust struct layout exactly)
 typedef struct {
     uint32_t sample_rate;
@@ -77,7 +150,7 @@ typedef struct {
     uint32_t hop_size;
     float min_frequency;
     float max_frequency;
-    uint32_t algorithm;      // 0=Auto, 1=PYIN, 2=YIN, 3=Autocorr
+    uint32_t algorithm;      // LoqaPitchAlgorithm: 0=Auto, 1=PYIN, 2=YIN, 3=Autocorr
     float threshold;
     float min_confidence;
     bool interpolate;
@@ -86,6 +159,10 @@ typedef struct {
 /// Get default analysis configuration
 AnalysisConfigFFI loqa_analysis_config_default(void);
 
+/// Validate a configuration without creating an analyzer (loqa_voice_analyzer_create
+/// runs the same checks). Returns LOQA_OK or an error code; see loqa_last_error_message().
+LoqaErrorCode loqa_analysis_config_validate(AnalysisConfigFFI config);
+
 // MARK: - FFI Function Declarations
 
 /// Pitch detection using pYIN algorithm with min/max frequency bounds
@@ -98,13 +175,72 @@ PitchResultFFI loqa_detect_pitch(
     float max_frequency
 );
 
+/// Pitch detection result with the reason a detection failed
+typedef struct {
+    bool success;
+    float frequency;
+    float confidence;
+    bool is_voiced;
+    float voiced_probability;
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} PitchResultExFFI;
+
+/// Pitch detection with a configurable frequency range and algorithm
+/// algorithm is a LoqaPitchAlgorithm value. audio_len must be at least 512 and hold
+/// two periods of min_frequency (LOQA_ERR_BUFFER_TOO_SHORT otherwise)
+PitchResultExFFI loqa_detect_pitch_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    float min_frequency,
+    float max_frequency,
+    uint32_t algorithm
+);
+
+/// Pitch detection with the default detector of loqa_detect_pitch (YIN with an
+/// autocorrelation fallback) that reports panics and rejected input in error_code.
+/// No 512-sample minimum; audio_len must hold two periods of min_frequency
+PitchResultExFFI loqa_detect_pitch_range(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    float min_frequency,
+    float max_frequency
+);
+
 /// Formant extraction using LPC analysis
 /// Returns FormantResultFFI by value with success=true if extraction succeeded
 FormantResultFFI loqa_extract_formants(
     const float* audio_ptr,
     size_t audio_len,
     uint32_t sample_rate,
-    size_t lpc_order
+    uint32_t lpc_order
+);
+
+/// Formants F1-F5 with bandwidths and a confidence per formant
+/// Formants that were not found are 0.0 with zero confidence
+typedef struct {
+    float f1;
+    float f2;
+    float f3;
+    float bw1;                   // Bandwidth of F1 in Hz
+    float bw2;
+    float bw3;
+    float f4;
+    float f5;
+    float bw4;
+    float bw5;
+    float confidence[5];         // Confidence of F1..F5 [0.0, 1.0]
+    LoqaErrorCode error_code;    // LOQA_OK on success
+} FormantsResultFFI;
+
+/// Formant extraction with bandwidths, F4/F5 and per-formant confidence
+/// lpc_order is 8-24, or 0 for sample_rate / 1000 + 2
+FormantsResultFFI loqa_extract_formants_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    uint32_t lpc_order
 );
 
 /// FFT computation
@@ -113,18 +249,247 @@ FFTResultFFI loqa_compute_fft(
     const float* audio_ptr,
     size_t audio_len,
     uint32_t sample_rate,
-    size_t fft_size
+    uint32_t fft_size
 );
 
 /// Free FFT result memory allocated by loqa_compute_fft
 void loqa_free_fft_result(FFTResultFFI* result);
 
+/// Window function selector for FFTConfigFFI.window
+typedef enum {
+    LOQA_WINDOW_NONE = 0,           // Rectangular
+    LOQA_WINDOW_HANN = 1,           // Default
+    LOQA_WINDOW_HAMMING = 2,
+    LOQA_WINDOW_BLACKMAN = 3,
+    LOQA_WINDOW_KAISER = 4,         // window_param = beta (0 = 8.6)
+    LOQA_WINDOW_FLAT_TOP = 5,       // Accurate peak amplitudes between bins
+    LOQA_WINDOW_GAUSSIAN = 6,       // window_param = sigma relative to half the window (0 = 0.4)
+} LoqaWindowType;
+
+/// Magnitude scaling selector for FFTConfigFFI.normalization
+typedef enum {
+    LOQA_FFT_NORMALIZATION_AMPLITUDE = 0,   // Single-sided amplitude (sine of amplitude A reads A)
+    LOQA_FFT_NORMALIZATION_PSD = 1,         // Single-sided power spectral density, units^2/Hz
+} LoqaFftNormalization;
+
+/// Configuration for loqa_compute_fft_ex (start from loqa_fft_config_default)
+typedef struct {
+    uint32_t fft_size;       // Power of two in [64, 8192], or 0 = smallest holding the buffer
+    uint32_t window;         // LoqaWindowType
+    float window_param;      // Kaiser beta / Gaussian sigma (0 = default), ignored otherwise
+    uint32_t normalization;  // LoqaFftNormalization
+    bool include_phase;      // Fill phases_ptr
+} FFTConfigFFI;
+
+/// Default FFT configuration: automatic size, Hann window, amplitude scaling, no phase
+FFTConfigFFI loqa_fft_config_default(void);
+
+/// Windowed FFT result - caller must free with loqa_free_fft_result_ex
+typedef struct {
+    bool success;
+    float* magnitudes_ptr;       // Scaled per FFTConfigFFI.normalization
+    float* phases_ptr;           // Radians; NULL unless include_phase was set
+    float* frequencies_ptr;      // Bin centers in Hz
+    size_t length;               // fft_size / 2 + 1 (length of every array)
+    uint32_t fft_size;           // FFT size actually used
+    uint32_t sample_rate;
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} FFTResultExFFI;
+
+/// FFT with a window, phase, bin frequencies and window-compensated scaling
+/// Buffers longer than the FFT size are truncated, shorter ones zero-padded
+FFTResultExFFI loqa_compute_fft_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    FFTConfigFFI config
+);
+
+/// Free the arrays of an FFTResultExFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_fft_result_ex(FFTResultExFFI* result);
+
+/// Complex spectrum - caller must free with loqa_free_complex_spectrum
+typedef struct {
+    bool success;
+    float* bins_ptr;             // Interleaved [re0, im0, re1, im1, ...] (2 * bin_count floats)
+    size_t bin_count;            // fft_size / 2 + 1
+    uint32_t fft_size;           // FFT size actually used
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} ComplexSpectrumFFI;
+
+/// Unwindowed, unnormalized one-sided complex FFT (fft_size 0 = smallest holding the buffer)
+/// loqa_compute_ifft of the result reconstructs the zero-padded input
+ComplexSpectrumFFI loqa_compute_fft_complex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t fft_size
+);
+
+/// Inverse FFT result - caller must free with loqa_free_ifft_result
+typedef struct {
+    bool success;
+    float* samples_ptr;          // Time-domain samples
+    size_t length;               // fft_size
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} InverseFFTResultFFI;
+
+/// Inverse of loqa_compute_fft_complex, scaled by 1 / fft_size
+/// bin_count must be fft_size / 2 + 1 for a power of two fft_size in [64, 8192]
+InverseFFTResultFFI loqa_compute_ifft(
+    const float* bins_ptr,
+    size_t bin_count
+);
+
+/// Free the array of a ComplexSpectrumFFI; nulls its pointer, so a second call is a no-op
+void loqa_free_complex_spectrum(ComplexSpectrumFFI* result);
+
+/// Free the array of an InverseFFTResultFFI; nulls its pointer, so a second call is a no-op
+void loqa_free_ifft_result(InverseFFTResultFFI* result);
+
+/// Value stored in each cell for SpectrogramConfigFFI.scaling
+typedef enum {
+    LOQA_SPECTROGRAM_LINEAR = 0,     // Window-compensated single-sided amplitude
+    LOQA_SPECTROGRAM_POWER = 1,      // Squared amplitude
+    LOQA_SPECTROGRAM_DECIBELS = 2,   // 20 * log10(amplitude) dBFS, clamped below at db_floor
+} LoqaSpectrogramScaling;
+
+/// Configuration for loqa_compute_spectrogram (start from loqa_spectrogram_config_default)
+typedef struct {
+    uint32_t frame_size;     // Samples per frame (= FFT size): power of two in [64, 8192]
+    uint32_t hop_size;       // Samples between frame starts, in [1, frame_size]
+    uint32_t window;         // LoqaWindowType
+    float window_param;      // Kaiser beta / Gaussian sigma (0 = default), ignored otherwise
+    uint32_t scaling;        // LoqaSpectrogramScaling
+    float db_floor;          // Lowest dB value for LOQA_SPECTROGRAM_DECIBELS, ignored otherwise
+} SpectrogramConfigFFI;
+
+/// Default spectrogram configuration: 2048-sample Hann frames every 512 samples, dB with a -120 dB floor
+SpectrogramConfigFFI loqa_spectrogram_config_default(void);
+
+/// Spectrogram result - caller must free with loqa_free_spectrogram
+typedef struct {
+    bool success;
+    float* values_ptr;           // Row-major frame_count x bin_count; frame i starts at values_ptr[i * bin_count]
+    float* timestamps_ptr;       // Frame starts in seconds (same grid as PitchTrackFFI.timestamps_ptr)
+    size_t frame_count;
+    size_t bin_count;            // frame_size / 2 + 1; bin k is centered on k * sample_rate / frame_size Hz
+    uint32_t frame_size;
+    uint32_t hop_size;
+    uint32_t sample_rate;
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} SpectrogramFFI;
+
+/// Spectrogram of a whole buffer; audio_len must be greater than frame_size
+/// Frame i starts at sample i * hop_size, matching loqa_voice_analyzer_process_buffer
+SpectrogramFFI loqa_compute_spectrogram(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    SpectrogramConfigFFI config
+);
+
+/// Free the arrays of a SpectrogramFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_spectrogram(SpectrogramFFI* result);
+
+/// Mel scale and filter normalization for MelConfigFFI.mel_scale
+typedef enum {
+    LOQA_MEL_SCALE_HTK = 0,      // 2595 * log10(1 + f / 700), unit-height filters
+    LOQA_MEL_SCALE_SLANEY = 1,   // Linear below 1 kHz, log above, area-normalized filters
+} LoqaMelScale;
+
+/// Configuration for loqa_compute_log_mel_spectrogram / loqa_compute_mfcc (start from loqa_mel_config_default)
+typedef struct {
+    uint32_t frame_size;     // Samples per frame (= FFT size): power of two in [64, 8192]
+    uint32_t hop_size;       // Samples between frame starts, in [1, frame_size]
+    uint32_t window;         // LoqaWindowType
+    float window_param;      // Kaiser beta / Gaussian sigma (0 = default), ignored otherwise
+    uint32_t n_mels;         // Mel bands, in [1, 256]
+    float fmin;              // Lower edge of the first band in Hz
+    float fmax;              // Upper edge of the last band in Hz (0 = Nyquist)
+    uint32_t mel_scale;      // LoqaMelScale
+    uint32_t n_mfcc;         // Cepstral coefficients, in [1, n_mels] (MFCC only)
+    float lifter;            // Sinusoidal lifter length, 0 = none (MFCC only)
+    uint32_t delta_order;    // 0 = static, 1 = + deltas, 2 = + delta-deltas (MFCC only)
+    uint32_t delta_width;    // Frames on each side of the delta regression, >= 1 (MFCC only)
+} MelConfigFFI;
+
+/// Default mel configuration: 2048-sample Hann frames every 512 samples, 40 Slaney bands, 13 MFCCs
+MelConfigFFI loqa_mel_config_default(void);
+
+/// Framewise feature matrix - caller must free with loqa_free_feature_matrix
+typedef struct {
+    bool success;
+    float* values_ptr;           // Row-major frame_count x feature_count
+    float* timestamps_ptr;       // Frame starts in seconds (same grid as PitchTrackFFI.timestamps_ptr)
+    size_t frame_count;
+    size_t feature_count;        // Values per frame
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} FeatureMatrixFFI;
+
+/// Log-mel spectrogram in dB (floor -100 dB), n_mels values per frame
+FeatureMatrixFFI loqa_compute_log_mel_spectrogram(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    MelConfigFFI config
+);
+
+/// MFCCs (DCT-II of log-mel, optional lifter), followed by deltas / delta-deltas per delta_order
+/// n_mfcc * (1 + delta_order) values per frame
+FeatureMatrixFFI loqa_compute_mfcc(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    MelConfigFFI config
+);
+
+/// Free the arrays of a FeatureMatrixFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_feature_matrix(FeatureMatrixFFI* result);
+
 /// Spectral analysis (centroid, tilt, rolloff)
 /// Takes pointer to FFTResultFFI, returns SpectralFeaturesFFI by value
 SpectralFeaturesFFI loqa_analyze_spectrum(
     const FFTResultFFI* fft_result
 );
 
+/// Configuration for loqa_analyze_spectrum_ex (start from loqa_spectrum_config_default)
+typedef struct {
+    uint32_t fft_size;               // Power of two in [64, 8192], or 0 = smallest holding the buffer
+    bool average_frames;             // Average 50%-overlapping frames when the buffer is longer
+    float rolloff_low_threshold;     // Energy fraction for rolloff_low (default 0.85)
+    float rolloff_high_threshold;    // Energy fraction for rolloff_high (default 0.95)
+} SpectrumConfigFFI;
+
+/// Default spectrum configuration: automatic FFT size, averaging, 85%/95% rolloff
+SpectrumConfigFFI loqa_spectrum_config_default(void);
+
+/// Spectral descriptors of a buffer of any length
+typedef struct {
+    bool success;
+    float centroid;              // Hz
+    float spread;                // Hz
+    float skewness;
+    float kurtosis;
+    float flatness;              // 0 (tonal) - 1 (white noise)
+    float entropy;               // 0 (one bin) - 1 (flat), normalized
+    float crest_factor;          // Peak / mean magnitude
+    float rolloff_low;           // Hz, at rolloff_low_threshold
+    float rolloff_high;          // Hz, at rolloff_high_threshold
+    float tilt;                  // dB/octave
+    uint32_t fft_size;           // FFT size actually used
+    uint32_t frame_count;        // Frames averaged
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} SpectralFeaturesExFFI;
+
+/// Spectral analysis straight from samples; any audio_len is accepted
+/// (zero-padded to the FFT size, or averaged over frames if longer)
+SpectralFeaturesExFFI loqa_analyze_spectrum_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    SpectrumConfigFFI config
+);
+
 /// HNR calculation using Boersma's autocorrelation method
 /// Returns HNRResultFFI by value with success=true if calculation succeeded
 HNRResultFFI loqa_calculate_hnr(
@@ -145,10 +510,50 @@ H1H2ResultFFI loqa_calculate_h1h2(
     float f0
 );
 
+/// HNR result with the reason a calculation failed
+typedef struct {
+    float hnr;                   // dB
+    float f0;                    // Hz
+    bool is_voiced;
+    LoqaErrorCode error_code;    // LOQA_OK on success
+} HNRResultExFFI;
+
+/// Panic-safe HNR calculation (use instead of loqa_calculate_hnr)
+HNRResultExFFI loqa_calculate_hnr_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    float min_frequency,
+    float max_frequency
+);
+
+/// H1-H2 result with the reason a calculation failed
+typedef struct {
+    float h1h2;                  // dB
+    float h1_amplitude_db;
+    float h2_amplitude_db;
+    float f0;                    // Hz, detected or as passed
+    LoqaErrorCode error_code;    // LOQA_OK on success
+} H1H2ResultExFFI;
+
+/// Panic-safe H1-H2 calculation (use instead of loqa_calculate_h1h2)
+/// Pass f0=0.0 for auto-detection of fundamental frequency
+H1H2ResultExFFI loqa_calculate_h1h2_ex(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    float f0
+);
+
 // MARK: - Stateful VoiceAnalyzer API (v0.4.0)
 
-/// Create a new VoiceAnalyzer instance
-/// Returns opaque pointer to analyzer (caller must free with loqa_voice_analyzer_free)
+/// Create a new VoiceAnalyzer instance (entry point for new code)
+/// Returns opaque pointer to analyzer (caller must free with loqa_voice_analyzer_free_ex),
+/// or NULL for an invalid config with the reason in loqa_last_error_code/message
+void* loqa_voice_analyzer_create(AnalysisConfigFFI config);
+
+/// Upstream constructor, kept for ABI compatibility: returns NULL without a reason and
+/// maps an unknown algorithm to Auto. Use loqa_voice_analyzer_create instead
 void* loqa_voice_analyzer_new(AnalysisConfigFFI config);
 
 /// Process a single frame with the VoiceAnalyzer
@@ -175,6 +580,31 @@ void loqa_voice_analyzer_reset(void* analyzer);
 /// Free a VoiceAnalyzer instance
 void loqa_voice_analyzer_free(void* analyzer);
 
+/// Panic-safe loqa_voice_analyzer_process_frame; on failure success is false and
+/// error_code holds the reason
+PitchResultExFFI loqa_voice_analyzer_process_frame_ex(
+    void* analyzer,
+    const float* samples,
+    size_t len
+);
+
+/// Panic-safe loqa_voice_analyzer_process_stream
+/// Returns number of results written (up to max_results), or 0 with the reason in
+/// loqa_last_error_code/message (a buffer shorter than one frame leaves LOQA_OK)
+size_t loqa_voice_analyzer_process_stream_ex(
+    void* analyzer,
+    const float* samples,
+    size_t len,
+    PitchResultExFFI* results_out,
+    size_t max_results
+);
+
+/// Panic-safe loqa_voice_analyzer_reset; NULL is ignored
+void loqa_voice_analyzer_reset_ex(void* analyzer);
+
+/// Panic-safe loqa_voice_analyzer_free; NULL is ignored
+void loqa_voice_analyzer_free_ex(void* analyzer);
+
 // MARK: - PitchTrack result for HMM-smoothed Viterbi decoding (v0.5.0)
 
 /// PitchTrack result from process_buffer (Viterbi decoding)
@@ -185,6 +615,7 @@ typedef struct {
     float* voiced_probs_ptr;     // Voiced probability per frame [0.0, 1.0]
     float* timestamps_ptr;       // Frame timestamps in seconds
     size_t length;               // Number of frames
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
 } PitchTrackFFI;
 
 /// Process buffer with HMM-smoothed Viterbi decoding for globally optimal pitch track
@@ -200,4 +631,544 @@ PitchTrackFFI loqa_voice_analyzer_process_buffer(
 /// Free PitchTrackFFI memory allocated by loqa_voice_analyzer_process_buffer
 void loqa_free_pitch_track(PitchTrackFFI* result);
 
+// MARK: - Pitch track post-processing (octave correction, gap filling, smoothing)
+
+/// Smoothing filter selector for PitchPostprocessConfigFFI.smoothing
+typedef enum {
+    LOQA_PITCH_SMOOTHING_NONE = 0,
+    LOQA_PITCH_SMOOTHING_MEDIAN = 1,            // Removes isolated outliers, keeps steps sharp
+    LOQA_PITCH_SMOOTHING_MOVING_AVERAGE = 2,    // Removes jitter, rounds off steps
+} LoqaPitchSmoothing;
+
+/// Post-processing configuration (obtain defaults from loqa_pitch_postprocess_config_default)
+typedef struct {
+    bool correct_octave_jumps;   // Move frames an octave or two off their neighbours back
+    uint32_t octave_window;      // Frames whose median is the octave reference (odd, 3-1001)
+    uint32_t smoothing;          // LoqaPitchSmoothing
+    uint32_t smoothing_window;   // Frames in the smoothing window (odd, 1-1001; 1 = off)
+    uint32_t max_gap_frames;     // Longest unvoiced run filled by interpolation (0 = off)
+} PitchPostprocessConfigFFI;
+
+/// Returns octave correction over 11 frames, gaps up to 5 frames filled, 5-frame median
+PitchPostprocessConfigFFI loqa_pitch_postprocess_config_default(void);
+
+/// Corrects octave jumps, interpolates short unvoiced gaps (in log-frequency) and
+/// smooths within voiced runs; voiced probabilities and timestamps are copied
+/// unchanged so frames stay aligned
+/// Returns PitchTrackFFI of the same length - caller must free with loqa_free_pitch_track
+PitchTrackFFI loqa_postprocess_pitch_track(
+    const float* pitch_track,
+    const float* voiced_probs,
+    const float* timestamps,
+    size_t length,
+    PitchPostprocessConfigFFI config
+);
+
+// MARK: - Pitch statistics (Hz and semitone summaries weighted by voicing)
+
+/// Statistics configuration (obtain defaults from loqa_pitch_stats_config_default)
+typedef struct {
+    float reference_hz;          // Frequency of 0 semitones in Hz (> 0)
+    bool weight_by_voicing;      // Weight voiced frames by voiced probability (false = equal)
+} PitchStatsConfigFFI;
+
+/// Pitch track statistics; Hz and semitone measures are NaN when no voiced
+/// frame has a positive weight
+typedef struct {
+    bool success;
+    uint32_t frame_count;        // Frames in the track
+    uint32_t voiced_frame_count; // Frames with a pitch above 0
+    float voiced_ratio;          // voiced_frame_count / frame_count
+    float mean_hz;               // Weighted mean pitch in Hz
+    float median_hz;             // Weighted median pitch in Hz
+    float std_hz;                // Weighted population standard deviation in Hz
+    float p5_hz;                 // Weighted 5th percentile in Hz
+    float p95_hz;                // Weighted 95th percentile in Hz
+    float mean_semitones;        // Weighted mean in semitones re reference_hz
+    float median_semitones;      // Weighted median in semitones re reference_hz
+    float std_semitones;         // Weighted population standard deviation in semitones
+    float p5_semitones;          // Weighted 5th percentile in semitones re reference_hz
+    float p95_semitones;         // Weighted 95th percentile in semitones re reference_hz
+    float range_semitones;       // p95_semitones - p5_semitones
+    LoqaErrorCode error_code;
+} PitchStatsFFI;
+
+/// Returns semitones re 100 Hz with frames weighted by voiced probability
+PitchStatsConfigFFI loqa_pitch_stats_config_default(void);
+
+/// Summarizes the pitch and voiced probability arrays of a PitchTrackFFI in Hz
+/// and semitones; computed in double precision in frame order, so iOS and
+/// Android give identical results
+/// Returns PitchStatsFFI by value (no cleanup needed)
+PitchStatsFFI loqa_pitch_track_statistics(
+    const float* pitch_track,
+    const float* voiced_probs,
+    size_t length,
+    PitchStatsConfigFFI config
+);
+
+// MARK: - Vibrato (rate, extent, cycle regularity and onset from pitch tracks)
+
+/// Vibrato settings (obtain defaults from loqa_vibrato_config_default)
+typedef struct {
+    float frame_rate;            // Pitch frames per second (sample_rate / hop_size, > 0)
+    float min_rate_hz;           // Slowest vibrato rate (Hz, > 0)
+    float max_rate_hz;           // Fastest vibrato rate (Hz, > min_rate_hz, <= frame_rate / 4)
+    float min_extent_cents;      // Smallest extent (± cents, > 0)
+    float max_extent_cents;      // Largest extent (± cents, > min_extent_cents)
+    uint32_t min_cycles;         // Fewest full cycles in a segment (>= 2)
+    float window_seconds;        // Sliding window length (s, >= 0; 0 = no windows)
+    uint32_t window_hop_frames;  // Frames between window ends (>= 1)
+} VibratoConfigFFI;
+
+/// Returns 100 frames/s, 4-8 Hz, ±15 to ±200 cents, 2 cycles, 1 s windows every frame
+VibratoConfigFFI loqa_vibrato_config_default(void);
+
+/// One stretch of regular vibrato; times in seconds from the first frame
+typedef struct {
+    float start;                 // Time of the first peak or trough
+    float end;                   // Time of the last peak or trough
+    float rate_hz;               // Vibrato rate (Hz)
+    float extent_cents;          // Mean extent (± cents)
+    float rate_jitter;           // Relative cycle-to-cycle period variation (0.01 = 1 %)
+    float extent_jitter;         // Relative variation between consecutive extents
+    float onset_delay;           // Seconds from the start of the voiced note to start
+} VibratoSegmentFFI;
+
+/// Vibrato result - caller must free with loqa_free_vibrato; summary measures
+/// and window values are NaN where no vibrato was found
+typedef struct {
+    bool success;
+    float vibrato_ratio;         // Seconds of vibrato / seconds of voiced frames
+    float rate_hz;               // Rate over all segments (Hz)
+    float extent_cents;          // Mean extent over all segments (± cents)
+    float rate_jitter;           // Segment rate jitter, weighted by cycles
+    float extent_jitter;         // Segment extent jitter, weighted by cycles
+    float onset_delay;           // Mean onset delay of each note's first segment (s)
+    VibratoSegmentFFI* segments_ptr; // Vibrato segments (NULL if none)
+    size_t segment_count;
+    float* window_times_ptr;     // Time of each window's last frame (s from the first frame)
+    float* window_rate_ptr;      // Rate per window (Hz)
+    float* window_extent_ptr;    // Extent per window (± cents)
+    float* window_rate_jitter_ptr;   // Rate jitter per window
+    float* window_extent_jitter_ptr; // Extent jitter per window
+    size_t window_count;         // Length of the five window arrays (NULL when 0)
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} VibratoFFI;
+
+/// Vibrato segments, summary and sliding windows of a pitch track (0 = unvoiced);
+/// the last window always ends at the last frame, for live display
+VibratoFFI loqa_analyze_vibrato(
+    const float* pitch_track,
+    size_t length,
+    VibratoConfigFFI config
+);
+
+/// Free the arrays of a VibratoFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_vibrato(VibratoFFI* result);
+
+// MARK: - Jitter and shimmer (Praat-compatible perturbation measures)
+
+/// Praat jitter measures from the voiced periods of a buffer
+/// Relative measures are fractions; a measure is NaN when no run of valid
+/// periods is long enough for it (2 for local, 3 for RAP/DDP, 5 for PPQ5)
+typedef struct {
+    bool success;
+    float local;                 // Mean |T(i) - T(i-1)| / mean period
+    float local_absolute;        // Mean |T(i) - T(i-1)| in seconds
+    float rap;                   // Relative average perturbation (3 periods)
+    float ppq5;                  // Five-point period perturbation quotient
+    float ddp;                   // Difference of differences of periods (3 x RAP)
+    float mean_period;           // Mean period in seconds
+    uint32_t period_count;       // Valid periods the measures are based on
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} JitterResultFFI;
+
+/// Praat shimmer measures from the peak amplitudes of the voiced periods of a buffer
+/// A measure is NaN when no run of valid periods is long enough for it
+/// (2 for local/local_db, 3 for APQ3/DDA, 5 for APQ5, 11 for APQ11)
+typedef struct {
+    bool success;
+    float local;                 // Mean |A(i) - A(i-1)| / mean amplitude
+    float local_db;              // Mean |20 log10(A(i) / A(i-1))|
+    float apq3;                  // Three-point amplitude perturbation quotient
+    float apq5;                  // Five-point amplitude perturbation quotient
+    float apq11;                 // Eleven-point amplitude perturbation quotient
+    float dda;                   // Difference of differences of amplitudes (3 x APQ3)
+    float mean_amplitude;        // Mean peak amplitude
+    uint32_t period_count;       // Valid periods the measures are based on
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} ShimmerResultFFI;
+
+/// Jitter of a buffer, with periods located from the pitch track of a
+/// VoiceAnalyzer built from config (min/max_frequency bound the periods)
+/// Fails with LOQA_ERR_BUFFER_TOO_SHORT when fewer than 3 valid periods are found
+JitterResultFFI loqa_calculate_jitter(
+    const float* audio_ptr,
+    size_t audio_len,
+    AnalysisConfigFFI config
+);
+
+/// Shimmer of a buffer, measured on the same periods as loqa_calculate_jitter
+ShimmerResultFFI loqa_calculate_shimmer(
+    const float* audio_ptr,
+    size_t audio_len,
+    AnalysisConfigFFI config
+);
+
+// MARK: - Cepstral peak prominence (CPP / CPPS)
+
+/// CPP configuration (obtain defaults from loqa_cpp_config_default)
+typedef struct {
+    uint32_t frame_size;         // Power of two in [64, 8192]; above two periods of min_frequency
+    uint32_t hop_size;           // Samples between frame starts, in [1, frame_size]
+    float min_frequency;         // Lowest F0 of the cepstral peak search (Hz)
+    float max_frequency;         // Highest F0 of the cepstral peak search (Hz)
+    float time_smoothing;        // CPPS moving average across frames (seconds, 0 = none)
+    float quefrency_smoothing;   // CPPS moving average across quefrency (seconds, 0 = none)
+} CppConfigFFI;
+
+/// Returns 2048-sample Hann frames every 128 samples, 60-330 Hz and Praat's
+/// CPPS smoothing (0.02 s in time, 0.0005 s in quefrency)
+CppConfigFFI loqa_cpp_config_default(void);
+
+/// CPP result; both measures are means over every frame, in dB
+typedef struct {
+    bool success;
+    float cpp;                   // Cepstral peak prominence of the unsmoothed cepstra
+    float cpps;                  // Cepstral peak prominence of the smoothed cepstra
+    float f0;                    // Median F0 of the smoothed cepstral peaks (Hz)
+    uint32_t frame_count;        // Number of frames averaged
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} CppResultFFI;
+
+/// Cepstral peak prominence (Hillenbrand & Houde) and its smoothed variant
+/// Higher values indicate a more periodic, less breathy voice
+CppResultFFI loqa_calculate_cpp(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    CppConfigFFI config
+);
+
+// MARK: - Intensity (RMS / peak / calibrated dB contours)
+
+/// Intensity configuration (obtain defaults from loqa_intensity_config_default)
+typedef struct {
+    uint32_t frame_size;         // Samples per frame (> 0, any length)
+    uint32_t hop_size;           // Samples between frame starts, in [1, frame_size]
+    float calibration_offset;    // dB added to every dBFS level (mic's dB SPL at 0 dBFS)
+    float floor_db;              // Lowest level in dBFS, applied before the offset
+} IntensityConfigFFI;
+
+/// Returns 2048-sample frames every 512 samples, uncalibrated dBFS, -120 dB floor
+IntensityConfigFFI loqa_intensity_config_default(void);
+
+/// Intensity contour - caller must free with loqa_free_intensity_contour
+typedef struct {
+    bool success;
+    float* rms_ptr;              // Linear RMS per frame (full scale = 1.0), uncalibrated
+    float* intensity_db_ptr;     // RMS level per frame (dBFS + calibration_offset)
+    float* peak_db_ptr;          // Absolute peak level per frame (dBFS + calibration_offset)
+    float* timestamps_ptr;       // Frame start times (seconds), same grid as PitchTrackFFI
+    size_t frame_count;          // Length of all four arrays
+    float mean_db;               // Energy-averaged intensity (dB)
+    float min_db;                // Quietest frame (dB)
+    float max_db;                // Loudest frame (dB)
+    float dynamic_range_db;      // max_db - min_db
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} IntensityContourFFI;
+
+/// Framewise RMS, peak and intensity of a whole buffer; audio_len must be greater than frame_size
+/// Frame i starts at sample i * hop_size, matching loqa_voice_analyzer_process_buffer
+IntensityContourFFI loqa_compute_intensity(
+    const float* audio_ptr,
+    size_t audio_len,
+    uint32_t sample_rate,
+    IntensityConfigFFI config
+);
+
+/// Free the arrays of an IntensityContourFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_intensity_contour(IntensityContourFFI* result);
+
+// MARK: - Voice activity detection (energy, zero-crossing rate, pYIN voicing)
+
+/// VAD thresholds (obtain defaults from loqa_vad_config_default)
+typedef struct {
+    float energy_threshold_db;   // Lowest RMS level of a speech frame (dBFS)
+    float zcr_threshold;         // Crossings per second marking unvoiced speech
+    float voicing_threshold;     // pYIN voicing probability of a voiced frame, in [0, 1]
+    float hangover_time;         // Seconds a speech decision is held (>= 0)
+} VadConfigFFI;
+
+/// Returns a -50 dBFS gate, 3000 crossings/s, voicing 0.5 and a 0.2 s hangover
+VadConfigFFI loqa_vad_config_default(void);
+
+/// Speech segment as the half-open sample range [start, end)
+typedef struct {
+    size_t start;
+    size_t end;
+} SpeechSegmentFFI;
+
+/// VAD result - caller must free with loqa_free_voice_activity
+typedef struct {
+    bool success;
+    float* timestamps_ptr;       // Frame start times (seconds), same grid as PitchTrackFFI
+    float* energy_db_ptr;        // RMS level per frame (dBFS)
+    float* zcr_ptr;              // Zero crossings per second per frame
+    float* voiced_probs_ptr;     // pYIN voicing probability per frame
+    bool* is_speech_ptr;         // Speech decision per frame, after hangover
+    size_t frame_count;          // Length of the five frame arrays
+    SpeechSegmentFFI* segments_ptr; // Merged speech segments (NULL if none)
+    size_t segment_count;
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} VadResultFFI;
+
+/// Speech frames and segments; audio_len must be greater than analysis.frame_size
+/// Frames follow analysis, matching loqa_voice_analyzer_process_buffer
+VadResultFFI loqa_detect_voice_activity(
+    const float* audio_ptr,
+    size_t audio_len,
+    AnalysisConfigFFI analysis,
+    VadConfigFFI config
+);
+
+/// Free the arrays of a VadResultFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_voice_activity(VadResultFFI* result);
+
+// MARK: - Vocal tract length (formant spacing, uniform-tube regression)
+
+/// VTL settings (obtain defaults from loqa_vtl_config_default)
+typedef struct {
+    uint32_t lpc_order;           // LPC order in [8, 24]; 0 = sample_rate / 1000 + 2
+    float min_formant_confidence; // Minimum confidence of a formant to be used, in [0, 1]
+    float speed_of_sound;         // Speed of sound in the vocal tract (cm/s, > 0)
+} VtlConfigFFI;
+
+/// Returns the LPC order from the sample rate, confidence 0.3 and 35000 cm/s
+VtlConfigFFI loqa_vtl_config_default(void);
+
+/// Vocal tract length estimate over the voiced frames of a buffer
+typedef struct {
+    bool success;
+    float vtl_cm;                 // Uniform-tube regression estimate (cm)
+    float spacing_vtl_cm;         // Average formant spacing estimate (cm)
+    float formant_spacing_hz;     // Formant spacing of vtl_cm (Hz)
+    float f1;                     // Median formants over the frames used (Hz, 0 if never found)
+    float f2;
+    float f3;
+    float f4;
+    float confidence;             // 0.0-1.0
+    uint32_t frame_count;         // Voiced frames that contributed an estimate
+    LoqaErrorCode error_code;     // LOQA_OK on success, otherwise why success is false
+} VtlResultFFI;
+
+/// Vocal tract length from F1-F4 of the voiced frames;
+/// audio_len must be greater than analysis.frame_size
+VtlResultFFI loqa_estimate_vocal_tract_length(
+    const float* audio_ptr,
+    size_t audio_len,
+    AnalysisConfigFFI analysis,
+    VtlConfigFFI config
+);
+
+// MARK: - Formant tracker (framewise LPC with Viterbi continuity of F1-F4)
+
+/// Formant tracker framing, LPC order, ceiling and reliability limits
+typedef struct {
+    uint32_t sample_rate;         // Sample rate (Hz), in [8000, 48000]
+    uint32_t frame_size;          // LPC window (samples), 10-100 ms
+    uint32_t hop_size;            // Samples between frame starts, in [1, frame_size]
+    uint32_t lpc_order;           // LPC order in [8, 24]; 0 = sample_rate / 1000 + 2
+    float ceiling_hz;             // Highest formant (Hz), in [3000, 7000] and below sample_rate / 2
+    float continuity_weight;      // Continuity against fit to neutral-vowel formants (>= 0)
+    float min_confidence;         // Minimum mean F1-F3 confidence of a reliable frame, in [0, 1]
+    float silence_db;             // Frames below this RMS level (dBFS, <= 0) are unreliable
+} FormantTrackerConfigFFI;
+
+/// Returns 16 kHz, 400-sample frames every 160 samples, a 5500 Hz ceiling,
+/// continuity weight 1, confidence 0.3 and silence below -50 dBFS
+FormantTrackerConfigFFI loqa_formant_tracker_config_default(void);
+
+/// Formant contours of one buffer - caller must free with loqa_free_formant_track
+typedef struct {
+    bool success;
+    float* frequencies_ptr;      // Row-major F1-F4 per frame (Hz, 0.0 = missing); NULL if empty
+    float* bandwidths_ptr;       // Bandwidths of the same formants (Hz), same layout
+    float* confidences_ptr;      // Mean F1-F3 confidence per frame [0.0, 1.0]
+    bool* reliable_ptr;          // False for silent, incomplete or low-confidence frames
+    float* timestamps_ptr;       // Frame start times (seconds from buffer start)
+    size_t length;               // Number of frames
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} FormantTrackFFI;
+
+/// Create a formant tracker; returns NULL for an invalid config (see loqa_last_error_message)
+/// Free with loqa_formant_tracker_free
+void* loqa_formant_tracker_new(FormantTrackerConfigFFI config);
+
+/// Track F1-F4 over the frames of len samples (len > frame_size), continuing
+/// from the previous buffer
+FormantTrackFFI loqa_formant_tracker_process_buffer(
+    void* tracker,
+    const float* samples,
+    size_t len
+);
+
+/// Forget the previous buffer, so the next one starts a new recording
+void loqa_formant_tracker_reset(void* tracker);
+
+/// Free a formant tracker
+void loqa_formant_tracker_free(void* tracker);
+
+/// Free the arrays of a FormantTrackFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_formant_track(FormantTrackFFI* result);
+
+// MARK: - Streaming analyzer (pushes of any size, continuous timestamps)
+
+/// Frames completed by one push or flush - caller must free with loqa_free_stream_frames
+typedef struct {
+    bool success;
+    float* frequencies_ptr;      // Pitch per frame (Hz, 0.0 = unvoiced); NULL if no frames
+    float* confidences_ptr;      // Confidence per frame [0.0, 1.0]
+    bool* is_voiced_ptr;         // Voicing decision per frame
+    float* voiced_probs_ptr;     // Voiced probability per frame [0.0, 1.0]
+    float* timestamps_ptr;       // Frame start times (seconds since the stream began)
+    size_t frame_count;          // Length of the five arrays
+    LoqaErrorCode error_code;    // LOQA_OK on success, otherwise why success is false
+} StreamFramesFFI;
+
+/// Create a streaming analyzer; returns NULL for an invalid config (see loqa_last_error_message)
+/// Free with loqa_stream_analyzer_free
+void* loqa_stream_analyzer_new(AnalysisConfigFFI config);
+
+/// Append len samples (any size) and analyse every frame they complete
+StreamFramesFFI loqa_stream_analyzer_push(
+    void* analyzer,
+    const float* samples,
+    size_t len
+);
+
+/// Analyse the zero-padded tail, then restart the stream at timestamp 0
+StreamFramesFFI loqa_stream_analyzer_flush(void* analyzer);
+
+/// Discard buffered samples and restart timestamps at 0
+void loqa_stream_analyzer_reset(void* analyzer);
+
+/// Free a streaming analyzer
+void loqa_stream_analyzer_free(void* analyzer);
+
+/// Free the arrays of a StreamFramesFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_stream_frames(StreamFramesFFI* result);
+
+// MARK: - Tuner (nearest note, cents deviation and stability)
+
+/// Tuner reference pitch, temperament and stability window
+typedef struct {
+    float reference_hz;           // Frequency of A4 (Hz, > 0)
+    float temperament_cents[12];  // Offset of C, C#, ..., B from equal temperament (cents, within ±50)
+    uint32_t stability_frames;    // Frames over which stability is measured, in [1, 1000]
+} TunerConfigFFI;
+
+/// Returns A4 = 440 Hz, equal temperament and stability over 8 frames
+TunerConfigFFI loqa_tuner_config_default(void);
+
+/// Latest tuner reading; note fields are only meaningful when is_voiced is true
+typedef struct {
+    bool success;
+    uint32_t frame_count;         // Frames analysed by this push (0 = previous reading)
+    float timestamp;              // Start of the frame read (seconds since the stream began)
+    bool is_voiced;
+    float frequency;              // Refined pitch (Hz, 0.0 if unvoiced)
+    float confidence;             // Pitch confidence [0.0, 1.0]
+    int32_t midi_note;            // Nearest note (A4 = 69)
+    uint32_t pitch_class;         // 0 (C) to 11 (B)
+    int32_t octave;               // Scientific pitch notation (middle C is C4)
+    float cents;                  // Deviation from the tuned note (positive is sharp)
+    float target_frequency;       // Frequency the note is tuned to (Hz)
+    float stability;              // 0.0-1.0: how steadily the note has been held
+    LoqaErrorCode error_code;     // LOQA_OK on success, otherwise why success is false
+} TunerReadingFFI;
+
+/// Create a tuner; returns NULL for an invalid config (see loqa_last_error_message)
+/// Free with loqa_tuner_free
+void* loqa_tuner_new(AnalysisConfigFFI analysis, TunerConfigFFI config);
+
+/// Append len samples (any size) and return the latest reading
+TunerReadingFFI loqa_tuner_push(
+    void* tuner,
+    const float* samples,
+    size_t len
+);
+
+/// Discard buffered samples and the reading; timestamps restart at 0
+void loqa_tuner_reset(void* tuner);
+
+/// Free a tuner
+void loqa_tuner_free(void* tuner);
+
+// MARK: - Voice range profile (semitone x dB phonetogram)
+
+/// Profile grid and coverage threshold (obtain defaults from loqa_vrp_config_default)
+typedef struct {
+    float reference_hz;           // Frequency of A4 (Hz, > 0)
+    uint32_t min_midi_note;       // Note of the first row, in [0, 127]
+    uint32_t max_midi_note;       // Note of the last row, in [min_midi_note, 127]
+    float min_db;                 // Lower edge of the first level column (dB)
+    float max_db;                 // Upper end of the level range (dB, > min_db)
+    float db_step;                // Width of a level column (dB, > 0, at most 1000 columns)
+    uint32_t min_cell_frames;     // Frames before a cell counts as sustained (>= 1)
+} VrpConfigFFI;
+
+/// Returns A4 = 440 Hz, notes C1 (24) to C8 (108), 40-120 dB in 1 dB steps, 3 frames per cell
+VrpConfigFFI loqa_vrp_config_default(void);
+
+/// Profile snapshot - caller must free with loqa_free_vrp
+typedef struct {
+    bool success;
+    uint32_t* counts_ptr;         // Row-major row_count x column_count frame counts
+    float* soft_db_ptr;           // Per row: lower edge of the lowest sustained cell (NaN if none)
+    float* loud_db_ptr;           // Per row: upper edge of the highest sustained cell (NaN if none)
+    float* dynamic_range_db_ptr;  // Per row: loud - soft (NaN if the note is not sustained)
+    size_t row_count;             // Notes; row r is MIDI note min_midi_note + r
+    size_t column_count;          // Level columns; column c starts at min_db + c * db_step
+    int32_t min_midi_note;
+    float min_db;
+    float db_step;
+    int32_t lowest_midi_note;     // Lowest sustained note (-1 if none)
+    int32_t highest_midi_note;    // Highest sustained note (-1 if none)
+    float lowest_hz;              // Equal-tempered frequency of the lowest note (NaN if none)
+    float highest_hz;             // Equal-tempered frequency of the highest note (NaN if none)
+    uint32_t covered_cells;       // Cells holding at least min_cell_frames frames
+    float area;                   // covered_cells * db_step (semitone·dB)
+    uint32_t frame_count;         // Frames binned so far
+    LoqaErrorCode error_code;     // LOQA_OK on success, otherwise why success is false
+} VrpFFI;
+
+/// Create an empty profile; returns NULL for an invalid config (see loqa_last_error_message)
+/// Free with loqa_vrp_free
+void* loqa_vrp_new(VrpConfigFFI config);
+
+/// Add frame i = (pitch_track[i] Hz, intensity_db[i] dB); unvoiced and off-grid frames are skipped
+LoqaErrorCode loqa_vrp_add_frames(
+    void* profile,
+    const float* pitch_track,
+    const float* intensity_db,
+    size_t length
+);
+
+/// Add the counts of source to target; both must have the same config
+LoqaErrorCode loqa_vrp_merge(void* target, const void* source);
+
+/// Grid, per-note dynamics and sustainable range of a profile
+VrpFFI loqa_vrp_get(const void* profile);
+
+/// Free the arrays of a VrpFFI; nulls its pointers, so a second call is a no-op
+void loqa_free_vrp(VrpFFI* result);
+
+/// Empty a profile's grid
+void loqa_vrp_reset(void* profile);
+
+/// Free a profile
+void loqa_vrp_free(void* profile);
+
 #endif /* loqa_voice_dsp_h */
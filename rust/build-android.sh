@@ -18,17 +18,17 @@ mkdir -p "$JNI_LIBS/x86_64"
 
 # Build for arm64-v8a (64-bit ARM devices)
 echo "Building for Android arm64-v8a..."
-cargo build --release --target aarch64-linux-android
+cargo build --release --features android --target aarch64-linux-android
 cp target/aarch64-linux-android/release/libloqa_voice_dsp.so "$JNI_LIBS/arm64-v8a/"
 
 # Build for armeabi-v7a (32-bit ARM devices)
 echo "Building for Android armeabi-v7a..."
-cargo build --release --target armv7-linux-androideabi
+cargo build --release --features android --target armv7-linux-androideabi
 cp target/armv7-linux-androideabi/release/libloqa_voice_dsp.so "$JNI_LIBS/armeabi-v7a/"
 
 # Build for x86_64 (64-bit Intel emulator)
 echo "Building for Android x86_64..."
-cargo build --release --target x86_64-linux-android
+cargo build --release --features android --target x86_64-linux-android
 cp target/x86_64-linux-android/release/libloqa_voice_dsp.so "$JNI_LIBS/x86_64/"
 
 echo "✅ Android libraries built successfully"
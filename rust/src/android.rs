@@ -0,0 +1,2268 @@
+// Android JNI entry points for com.loqalabs.loqaexpodsp.RustJNI.RustBridge
+//
+// Compiled only with the `android` cargo feature (build-android.sh enables it).
+// Each `Java_*` function below backs one `external fun` in RustBridge.kt; the
+// symbol prefix comes from that file's `package` declaration, which is what the
+// JVM resolves against (not the directory it lives in). Inputs are read from a
+// `float[]` or a direct, native-order `java.nio.FloatBuffer`, and results are
+// returned as the Kotlin data classes, float arrays or a `HashMap`.
+//
+// Failures are recorded through the same last-error slot as the C exports and
+// then raised as Java exceptions, so Kotlin never sees a half-initialised result.
+
+use crate::error::{catch_panic, clear_last_error, set_last_error};
+use crate::formant_track::FormantTracker;
+use crate::stream::{StreamFrame, StreamingAnalyzer};
+use crate::tuner::{Tuner, PITCH_CLASSES};
+use crate::vrp::VoiceRangeProfile;
+use crate::{
+    analyze_formants_checked, analyze_spectrum_checked, analyze_vibrato_checked,
+    calculate_cpp_checked, calculate_h1h2_checked, calculate_hnr_checked, calculate_jitter_checked,
+    calculate_shimmer_checked, compute_fft_checked, compute_fft_complex_checked,
+    compute_ifft_checked, compute_intensity_checked, compute_log_mel_checked, compute_mfcc_checked,
+    compute_spectrogram_checked, detect_pitch_checked, detect_pitch_with_algorithm_checked,
+    detect_voice_activity_checked, estimate_vocal_tract_length_checked,
+    formant_tracker_new_checked, formants, loqa_analysis_config_default, loqa_last_error_code,
+    loqa_last_error_message, loqa_mel_config_default, loqa_set_log_callback, loqa_set_log_level,
+    measure_or_nan, pitch_algorithm_from_raw, pitch_track_statistics_checked,
+    postprocess_pitch_track_checked, stream_analyzer_new_checked, track_formants_checked,
+    tuner_new_checked, voice_analyzer_new_checked, vrp_add_frames_checked, vrp_new_checked,
+    AnalysisConfigFFI, CppConfigFFI, FFTConfigFFI, FeatureMatrix, FormantTrackerConfigFFI,
+    IntensityConfigFFI, LoqaErrorCode, LoqaLogLevel, MelConfigFFI, PitchPostprocessConfigFFI,
+    PitchStatsConfigFFI, PitchStatsFFI, SpectrogramConfigFFI, SpectrumConfigFFI, TunerConfigFFI,
+    TunerReadingFFI, VadConfigFFI, VibratoConfigFFI, VrpConfigFFI, VtlConfigFFI,
+};
+use jni::objects::{GlobalRef, JByteBuffer, JClass, JFloatArray, JObject, JObjectArray, JValue};
+use jni::sys::{jboolean, jfloat, jint, jlong, jsize};
+use jni::{JNIEnv, JavaVM};
+use loqa_voice_dsp::VoiceAnalyzer;
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+// Kotlin data classes constructed from Rust (declared in RustBridge.kt)
+pub(crate) const PITCH_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/PitchResult";
+pub(crate) const PITCH_RESULT_CTOR: &str = "(FFZF)V";
+pub(crate) const FORMANTS_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/FormantsResult";
+pub(crate) const FORMANTS_RESULT_CTOR: &str = "(FFFFFF[F[F)V";
+pub(crate) const HNR_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/HNRResult";
+pub(crate) const HNR_RESULT_CTOR: &str = "(FFZ)V";
+pub(crate) const H1H2_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/H1H2Result";
+pub(crate) const H1H2_RESULT_CTOR: &str = "(FFFF)V";
+pub(crate) const PITCH_TRACK_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/PitchTrackResult";
+pub(crate) const PITCH_TRACK_RESULT_CTOR: &str = "([F[F[F)V";
+pub(crate) const PITCH_STATISTICS_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/PitchStatisticsResult";
+pub(crate) const PITCH_STATISTICS_RESULT_CTOR: &str = "(IIFFFFFFFFFFFF)V";
+pub(crate) const VIBRATO_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/VibratoResult";
+pub(crate) const VIBRATO_RESULT_CTOR: &str = "([F[F[F[F[F[FFFFFFF)V";
+pub(crate) const FFT_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/FFTResult";
+pub(crate) const FFT_RESULT_CTOR: &str = "([F[F[F)V";
+pub(crate) const SPECTROGRAM_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/SpectrogramResult";
+pub(crate) const SPECTROGRAM_RESULT_CTOR: &str = "([F[FII)V";
+pub(crate) const FEATURE_MATRIX_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/FeatureMatrix";
+pub(crate) const FEATURE_MATRIX_CTOR: &str = "([F[FII)V";
+pub(crate) const JITTER_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/JitterResult";
+pub(crate) const JITTER_RESULT_CTOR: &str = "(FFFFFFI)V";
+pub(crate) const SHIMMER_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/ShimmerResult";
+pub(crate) const SHIMMER_RESULT_CTOR: &str = "(FFFFFFFI)V";
+pub(crate) const CPP_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/CppResult";
+pub(crate) const CPP_RESULT_CTOR: &str = "(FFFI)V";
+pub(crate) const INTENSITY_RESULT_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/IntensityResult";
+pub(crate) const INTENSITY_RESULT_CTOR: &str = "([F[F[F[FFFFF)V";
+pub(crate) const VOICE_ACTIVITY_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/VoiceActivityResult";
+pub(crate) const VOICE_ACTIVITY_RESULT_CTOR: &str = "([F[F[F[F[Z[I)V";
+pub(crate) const VOCAL_TRACT_LENGTH_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/VocalTractLengthResult";
+pub(crate) const VOCAL_TRACT_LENGTH_RESULT_CTOR: &str = "(FFFFFFFFI)V";
+pub(crate) const STREAM_FRAMES_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/StreamFramesResult";
+pub(crate) const STREAM_FRAMES_RESULT_CTOR: &str = "([F[F[Z[F[F)V";
+pub(crate) const TUNER_READING_CLASS: &str = "com/loqalabs/loqaexpodsp/RustJNI/TunerReading";
+pub(crate) const TUNER_READING_CTOR: &str = "(IFZFFIIIFFF)V";
+pub(crate) const VOICE_RANGE_PROFILE_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/VoiceRangeProfileResult";
+pub(crate) const VOICE_RANGE_PROFILE_RESULT_CTOR: &str = "([I[F[F[FIIIFFIIFFIFI)V";
+pub(crate) const FORMANT_TRACK_RESULT_CLASS: &str =
+    "com/loqalabs/loqaexpodsp/RustJNI/FormantTrackResult";
+pub(crate) const FORMANT_TRACK_RESULT_CTOR: &str = "([F[F[F[Z[F)V";
+
+/// Why a JNI call could not produce a result
+enum Failure {
+    /// Validation or analysis error that still has to be recorded as the last error
+    Error(LoqaErrorCode, String),
+    /// Error already recorded by one of the `*_rust` exports
+    Recorded(LoqaErrorCode, String),
+    /// The JNI call itself failed (possibly with a Java exception already pending)
+    Jni(jni::errors::Error),
+}
+
+impl From<(LoqaErrorCode, String)> for Failure {
+    fn from((code, message): (LoqaErrorCode, String)) -> Self {
+        Failure::Error(code, message)
+    }
+}
+
+impl From<jni::errors::Error> for Failure {
+    fn from(error: jni::errors::Error) -> Self {
+        Failure::Jni(error)
+    }
+}
+
+/// Turns the error code returned by a `*_rust` export into a `Failure`
+fn check_recorded(code: LoqaErrorCode) -> Result<(), Failure> {
+    if code == LoqaErrorCode::Ok {
+        return Ok(());
+    }
+    Err(recorded_failure())
+}
+
+/// Builds a `Failure` from the calling thread's last error
+fn recorded_failure() -> Failure {
+    let message = loqa_last_error_message();
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        // SAFETY: non-null pointers from loqa_last_error_message are valid C strings
+        // until the next FFI call on this thread
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    Failure::Recorded(loqa_last_error_code(), message)
+}
+
+/// Raises `failure` as a Java exception
+///
+/// Input validation errors become `IllegalArgumentException` (or
+/// `NullPointerException`), analysis errors `RuntimeException`.
+fn throw(env: &mut JNIEnv, failure: Failure) {
+    let (code, message) = match failure {
+        Failure::Error(code, message) => {
+            set_last_error(code, message.clone());
+            (code, message)
+        }
+        Failure::Recorded(code, message) => (code, message),
+        // The pending Java exception propagates to Kotlin as-is
+        Failure::Jni(jni::errors::Error::JavaException) => return,
+        Failure::Jni(error) => {
+            let _ = env.throw_new("java/lang/RuntimeException", format!("JNI error: {error}"));
+            return;
+        }
+    };
+
+    let class = match code {
+        LoqaErrorCode::NullPointer => "java/lang/NullPointerException",
+        LoqaErrorCode::AnalysisFailed | LoqaErrorCode::Panic => "java/lang/RuntimeException",
+        _ => "java/lang/IllegalArgumentException",
+    };
+    let _ = env.throw_new(class, format!("{message} ({code:?})"));
+}
+
+/// Runs a JNI entry point body, converting a failure or panic into a pending Java
+/// exception
+///
+/// The returned default (null object, 0, ...) is never observed by Kotlin because
+/// the exception is thrown as soon as the native method returns.
+fn jni_call<'local, T: Default>(
+    env: &mut JNIEnv<'local>,
+    method: &str,
+    body: impl FnOnce(&mut JNIEnv<'local>) -> Result<T, Failure>,
+) -> T {
+    clear_last_error();
+    let result = catch_panic(method, || body(env))
+        .unwrap_or_else(|message| Err(Failure::Recorded(LoqaErrorCode::Panic, message)));
+    result.unwrap_or_else(|failure| {
+        throw(env, failure);
+        T::default()
+    })
+}
+
+/// Converts a size, count or selector passed as a Kotlin `Int`
+///
+/// Negative values are rejected with `InvalidArgument`; `as u32` would wrap them
+/// to huge values that some configs accept (e.g. a `min_cycles` no vibrato reaches).
+fn non_negative(name: &str, value: jint) -> Result<u32, Failure> {
+    u32::try_from(value).map_err(|_| {
+        Failure::Error(
+            LoqaErrorCode::InvalidArgument,
+            format!("{name} must be >= 0, got {value}"),
+        )
+    })
+}
+
+/// Reads audio samples from a `float[]` or a direct `FloatBuffer`
+///
+/// Arrays are copied; direct buffers are borrowed in place (only the region between
+/// `position()` and `limit()` is used).
+fn read_samples<'a>(env: &mut JNIEnv, buffer: &'a JObject) -> Result<Cow<'a, [f32]>, Failure> {
+    if buffer.is_null() {
+        return Err(Failure::Error(
+            LoqaErrorCode::NullPointer,
+            "buffer is null".into(),
+        ));
+    }
+
+    if env.is_instance_of(buffer, "java/nio/FloatBuffer")? {
+        return read_direct_buffer(env, buffer).map(Cow::Borrowed);
+    }
+
+    let array = <&JFloatArray>::from(buffer);
+    let length = env.get_array_length(array)?;
+    let mut samples = vec![0.0; length as usize];
+    env.get_float_array_region(array, 0, &mut samples)?;
+    Ok(Cow::Owned(samples))
+}
+
+/// Borrows the remaining elements of a direct, native-order `FloatBuffer`
+fn read_direct_buffer<'a>(env: &mut JNIEnv, buffer: &'a JObject) -> Result<&'a [f32], Failure> {
+    if !env.call_method(buffer, "isDirect", "()Z", &[])?.z()? {
+        return Err(Failure::Error(
+            LoqaErrorCode::InvalidArgument,
+            "FloatBuffer must be direct (ByteBuffer.allocateDirect(..).asFloatBuffer())".into(),
+        ));
+    }
+
+    // Samples are read as native f32, so a big-endian view would be garbage
+    let order = env
+        .call_method(buffer, "order", "()Ljava/nio/ByteOrder;", &[])?
+        .l()?;
+    let native_order = env
+        .call_static_method(
+            "java/nio/ByteOrder",
+            "nativeOrder",
+            "()Ljava/nio/ByteOrder;",
+            &[],
+        )?
+        .l()?;
+    if !env.is_same_object(&order, &native_order)? {
+        return Err(Failure::Error(
+            LoqaErrorCode::InvalidArgument,
+            "FloatBuffer must use ByteOrder.nativeOrder()".into(),
+        ));
+    }
+
+    let position = env.call_method(buffer, "position", "()I", &[])?.i()? as usize;
+    let limit = env.call_method(buffer, "limit", "()I", &[])?.i()? as usize;
+
+    // GetDirectBufferAddress accepts any java.nio.Buffer, not only ByteBuffer
+    let address = env.get_direct_buffer_address(<&JByteBuffer>::from(buffer))? as *const f32;
+    if !address.is_aligned() {
+        return Err(Failure::Error(
+            LoqaErrorCode::InvalidArgument,
+            "FloatBuffer memory is not 4-byte aligned".into(),
+        ));
+    }
+
+    // SAFETY: the buffer holds at least `limit` floats at `address` and stays alive
+    // (pinned by the local reference) for the duration of the native call
+    Ok(unsafe { std::slice::from_raw_parts(address.add(position), limit - position) })
+}
+
+/// Copies `values` into a new Java `float[]`
+fn new_float_array<'local>(
+    env: &mut JNIEnv<'local>,
+    values: &[f32],
+) -> Result<JFloatArray<'local>, Failure> {
+    let array = env.new_float_array(values.len() as jsize)?;
+    env.set_float_array_region(&array, 0, values)?;
+    Ok(array)
+}
+
+/// Builds a Kotlin `PitchResult`, using the same conventions as `detect_pitch_rust`
+fn new_pitch_result<'local>(
+    env: &mut JNIEnv<'local>,
+    result: &loqa_voice_dsp::PitchResult,
+) -> Result<JObject<'local>, Failure> {
+    let frequency = if result.is_voiced {
+        result.frequency
+    } else {
+        0.0
+    };
+    Ok(env.new_object(
+        PITCH_RESULT_CLASS,
+        PITCH_RESULT_CTOR,
+        &[
+            JValue::Float(frequency),
+            JValue::Float(result.confidence.clamp(0.0, 1.0)),
+            JValue::Bool(result.is_voiced as jboolean),
+            JValue::Float(result.voiced_probability.clamp(0.0, 1.0)),
+        ],
+    )?)
+}
+
+/// Reborrows the `VoiceAnalyzer` behind a handle from `nativeVoiceAnalyzerNew`
+///
+/// # Safety
+/// * `handle` must be 0 or a live handle returned by `nativeVoiceAnalyzerNew`
+unsafe fn analyzer_from_handle<'a>(handle: jlong) -> Result<&'a mut VoiceAnalyzer, Failure> {
+    (handle as *mut VoiceAnalyzer)
+        .as_mut()
+        .ok_or_else(|| Failure::Error(LoqaErrorCode::NullPointer, "analyzer handle is 0".into()))
+}
+
+// ============================================================================
+// One-shot analysis
+// ============================================================================
+
+/// `external fun nativeComputeFFT(buffer: FloatArray, sampleRate: Int, fftSize: Int, windowType: Int, windowParam: Float, normalization: Int, includePhase: Boolean): FFTResult`
+///
+/// `phase` is an empty array unless `includePhase` is set.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeFFT<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    fft_size: jint,
+    window_type: jint,
+    window_param: jfloat,
+    normalization: jint,
+    include_phase: jboolean,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeComputeFFT", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = FFTConfigFFI {
+            fft_size: non_negative("fft_size", fft_size)?,
+            window: non_negative("window_type", window_type)?,
+            window_param,
+            normalization: non_negative("normalization", normalization)?,
+            include_phase: include_phase != 0,
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let (spectrum, _) = compute_fft_checked(&samples, sample_rate, &config)?;
+
+        let phases: &[f32] = if config.include_phase {
+            &spectrum.phases
+        } else {
+            &[]
+        };
+        let magnitude = new_float_array(env, &spectrum.magnitudes)?;
+        let phase = new_float_array(env, phases)?;
+        let frequencies = new_float_array(env, &spectrum.frequencies)?;
+        Ok(env.new_object(
+            FFT_RESULT_CLASS,
+            FFT_RESULT_CTOR,
+            &[
+                JValue::Object(&magnitude),
+                JValue::Object(&phase),
+                JValue::Object(&frequencies),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeComputeComplexFFT(buffer: FloatArray, fftSize: Int): FloatArray`
+///
+/// Returns interleaved `[re0, im0, re1, im1, ...]` bins.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeComplexFFT<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    fft_size: jint,
+) -> JFloatArray<'local> {
+    jni_call(&mut env, "nativeComputeComplexFFT", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let (bins, _) = compute_fft_complex_checked(&samples, non_negative("fft_size", fft_size)?)?;
+        new_float_array(env, &bins)
+    })
+}
+
+/// `external fun nativeInverseFFT(bins: FloatArray): FloatArray`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeInverseFFT<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    bins: JObject<'local>,
+) -> JFloatArray<'local> {
+    jni_call(&mut env, "nativeInverseFFT", |env| {
+        let bins = read_samples(env, &bins)?;
+        let samples = compute_ifft_checked(&bins)?;
+        new_float_array(env, &samples)
+    })
+}
+
+/// `external fun nativeComputeSpectrogram(buffer: FloatArray, sampleRate: Int, frameSize: Int, hopSize: Int, windowType: Int, windowParam: Float, scaling: Int, dbFloor: Float): SpectrogramResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeSpectrogram<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    window_type: jint,
+    window_param: jfloat,
+    scaling: jint,
+    db_floor: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeComputeSpectrogram", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = SpectrogramConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            window: non_negative("window_type", window_type)?,
+            window_param,
+            scaling: non_negative("scaling", scaling)?,
+            db_floor,
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let spectrogram = compute_spectrogram_checked(&samples, sample_rate, &config)?;
+
+        let values = new_float_array(env, &spectrogram.values)?;
+        let timestamps = new_float_array(env, &spectrogram.timestamps)?;
+        Ok(env.new_object(
+            SPECTROGRAM_RESULT_CLASS,
+            SPECTROGRAM_RESULT_CTOR,
+            &[
+                JValue::Object(&values),
+                JValue::Object(&timestamps),
+                JValue::Int(spectrogram.frame_count as jint),
+                JValue::Int(spectrogram.bin_count as jint),
+            ],
+        )?)
+    })
+}
+
+/// Builds a Kotlin `FeatureMatrix` from framewise features
+fn new_feature_matrix<'local>(
+    env: &mut JNIEnv<'local>,
+    features: &FeatureMatrix,
+) -> Result<JObject<'local>, Failure> {
+    let values = new_float_array(env, &features.values)?;
+    let timestamps = new_float_array(env, &features.timestamps)?;
+    Ok(env.new_object(
+        FEATURE_MATRIX_CLASS,
+        FEATURE_MATRIX_CTOR,
+        &[
+            JValue::Object(&values),
+            JValue::Object(&timestamps),
+            JValue::Int(features.timestamps.len() as jint),
+            JValue::Int(features.feature_count as jint),
+        ],
+    )?)
+}
+
+/// `external fun nativeComputeLogMelSpectrogram(buffer: FloatArray, sampleRate: Int, frameSize: Int, hopSize: Int, windowType: Int, windowParam: Float, nMels: Int, fmin: Float, fmax: Float, melScale: Int): FeatureMatrix`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeLogMelSpectrogram<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    window_type: jint,
+    window_param: jfloat,
+    n_mels: jint,
+    fmin: jfloat,
+    fmax: jfloat,
+    mel_scale: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeComputeLogMelSpectrogram", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = MelConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            window: non_negative("window_type", window_type)?,
+            window_param,
+            n_mels: non_negative("n_mels", n_mels)?,
+            fmin,
+            fmax,
+            mel_scale: non_negative("mel_scale", mel_scale)?,
+            ..loqa_mel_config_default()
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let features = compute_log_mel_checked(&samples, sample_rate, &config)?;
+        new_feature_matrix(env, &features)
+    })
+}
+
+/// `external fun nativeComputeMfcc(buffer: FloatArray, sampleRate: Int, frameSize: Int, hopSize: Int, windowType: Int, windowParam: Float, nMels: Int, fmin: Float, fmax: Float, melScale: Int, nMfcc: Int, lifter: Float, deltaOrder: Int, deltaWidth: Int): FeatureMatrix`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeMfcc<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    window_type: jint,
+    window_param: jfloat,
+    n_mels: jint,
+    fmin: jfloat,
+    fmax: jfloat,
+    mel_scale: jint,
+    n_mfcc: jint,
+    lifter: jfloat,
+    delta_order: jint,
+    delta_width: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeComputeMfcc", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = MelConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            window: non_negative("window_type", window_type)?,
+            window_param,
+            n_mels: non_negative("n_mels", n_mels)?,
+            fmin,
+            fmax,
+            mel_scale: non_negative("mel_scale", mel_scale)?,
+            n_mfcc: non_negative("n_mfcc", n_mfcc)?,
+            lifter,
+            delta_order: non_negative("delta_order", delta_order)?,
+            delta_width: non_negative("delta_width", delta_width)?,
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let features = compute_mfcc_checked(&samples, sample_rate, &config)?;
+        new_feature_matrix(env, &features)
+    })
+}
+
+/// `external fun nativeDetectPitch(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float): PitchResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeDetectPitch", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let result = detect_pitch_checked(&samples, sample_rate, min_frequency, max_frequency)?;
+        new_pitch_result(env, &result)
+    })
+}
+
+/// `external fun nativeDetectPitchDirect(buffer: FloatBuffer, sampleRate: Int, minFrequency: Float, maxFrequency: Float): PitchResult`
+///
+/// Zero-copy variant of `nativeDetectPitch` for direct buffers.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitchDirect<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+) -> JObject<'local> {
+    // read_samples dispatches on the runtime type, so both entry points share one body
+    Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch(
+        env,
+        class,
+        buffer,
+        sample_rate,
+        min_frequency,
+        max_frequency,
+    )
+}
+
+/// `external fun nativeDetectPitchWithAlgorithm(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float, algorithm: Int): PitchResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitchWithAlgorithm<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    algorithm: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeDetectPitchWithAlgorithm", |env| {
+        let algorithm = pitch_algorithm_from_raw(non_negative("algorithm", algorithm)?)?;
+        let samples = read_samples(env, &buffer)?;
+        let result = detect_pitch_with_algorithm_checked(
+            &samples,
+            sample_rate,
+            min_frequency,
+            max_frequency,
+            algorithm,
+        )?;
+        new_pitch_result(env, &result)
+    })
+}
+
+/// `external fun nativeExtractFormants(buffer: FloatArray, sampleRate: Int, lpcOrder: Int): FormantsResult`
+///
+/// Shares its validation and LPC pole analysis with `loqa_extract_formants_ex`;
+/// `confidence` is the mean of the F1-F3 confidences.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeExtractFormants<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    lpc_order: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeExtractFormants", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let formants = analyze_formants_checked(&samples, sample_rate, lpc_order)?;
+        let bandwidths = new_float_array(env, &formants.map(|f| f.bandwidth))?;
+        let confidences = new_float_array(env, &formants.map(|f| f.confidence))?;
+        Ok(env.new_object(
+            FORMANTS_RESULT_CLASS,
+            FORMANTS_RESULT_CTOR,
+            &[
+                JValue::Float(formants[0].frequency),
+                JValue::Float(formants[1].frequency),
+                JValue::Float(formants[2].frequency),
+                JValue::Float(formants::mean_confidence(&formants)),
+                JValue::Float(formants[3].frequency),
+                JValue::Float(formants[4].frequency),
+                JValue::Object(&bandwidths),
+                JValue::Object(&confidences),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeAnalyzeSpectrum(buffer: FloatArray, sampleRate: Int, fftSize: Int, averageFrames: Boolean, rolloffLowThreshold: Float, rolloffHighThreshold: Float): Map<String, Any>`
+///
+/// Returns a `java.util.HashMap` with boxed `Float` values for "centroid",
+/// "rolloff" (at the high threshold), "tilt", "spread", "skewness", "kurtosis",
+/// "flatness", "entropy", "crestFactor", "rolloffLow" and "rolloffHigh".
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeSpectrum<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    fft_size: jint,
+    average_frames: jboolean,
+    rolloff_low_threshold: jfloat,
+    rolloff_high_threshold: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeAnalyzeSpectrum", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = SpectrumConfigFFI {
+            fft_size: non_negative("fft_size", fft_size)?,
+            average_frames: average_frames != 0,
+            rolloff_low_threshold,
+            rolloff_high_threshold,
+        };
+        let d = analyze_spectrum_checked(&samples, sample_rate, &config)?.descriptors;
+
+        let map = env.new_object("java/util/HashMap", "()V", &[])?;
+        for (key, value) in [
+            ("centroid", d.centroid),
+            ("rolloff", d.rolloff_high),
+            ("tilt", d.tilt),
+            ("spread", d.spread),
+            ("skewness", d.skewness),
+            ("kurtosis", d.kurtosis),
+            ("flatness", d.flatness),
+            ("entropy", d.entropy),
+            ("crestFactor", d.crest_factor),
+            ("rolloffLow", d.rolloff_low),
+            ("rolloffHigh", d.rolloff_high),
+        ] {
+            let key = env.new_string(key)?;
+            let value = env
+                .call_static_method(
+                    "java/lang/Float",
+                    "valueOf",
+                    "(F)Ljava/lang/Float;",
+                    &[JValue::Float(value)],
+                )?
+                .l()?;
+            env.call_method(
+                &map,
+                "put",
+                "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                &[JValue::Object(&key), JValue::Object(&value)],
+            )?;
+        }
+        Ok(map)
+    })
+}
+
+/// `external fun nativeCalculateHNR(buffer: FloatArray, sampleRate: Int, minFreq: Float, maxFreq: Float): HNRResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateHNR<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_freq: jfloat,
+    max_freq: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeCalculateHNR", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let result = calculate_hnr_checked(&samples, sample_rate, min_freq, max_freq)?;
+
+        Ok(env.new_object(
+            HNR_RESULT_CLASS,
+            HNR_RESULT_CTOR,
+            &[
+                JValue::Float(result.hnr),
+                JValue::Float(result.f0),
+                JValue::Bool(result.is_voiced as jboolean),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeCalculateH1H2(buffer: FloatArray, sampleRate: Int, f0: Float): H1H2Result`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateH1H2<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    f0: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeCalculateH1H2", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let result = calculate_h1h2_checked(&samples, sample_rate, f0)?;
+
+        Ok(env.new_object(
+            H1H2_RESULT_CLASS,
+            H1H2_RESULT_CTOR,
+            &[
+                JValue::Float(result.h1h2),
+                JValue::Float(result.h1_amplitude_db),
+                JValue::Float(result.h2_amplitude_db),
+                JValue::Float(result.f0),
+            ],
+        )?)
+    })
+}
+
+/// Pitch tracking configuration for the whole-buffer analyses (perturbation,
+/// VAD): the analyzer defaults with the caller's sample rate and frequency range
+fn pitch_tracking_config(
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+) -> AnalysisConfigFFI {
+    AnalysisConfigFFI {
+        sample_rate: u32::try_from(sample_rate).unwrap_or(0),
+        min_frequency,
+        max_frequency,
+        ..loqa_analysis_config_default()
+    }
+}
+
+/// `external fun nativeCalculateJitter(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float): JitterResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateJitter<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeCalculateJitter", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let config = pitch_tracking_config(sample_rate, min_frequency, max_frequency);
+        let jitter = calculate_jitter_checked(&samples, &config)?;
+
+        Ok(env.new_object(
+            JITTER_RESULT_CLASS,
+            JITTER_RESULT_CTOR,
+            &[
+                JValue::Float(measure_or_nan(jitter.local)),
+                JValue::Float(measure_or_nan(jitter.local_absolute)),
+                JValue::Float(measure_or_nan(jitter.rap)),
+                JValue::Float(measure_or_nan(jitter.ppq5)),
+                JValue::Float(measure_or_nan(jitter.ddp)),
+                JValue::Float(measure_or_nan(jitter.mean_period)),
+                JValue::Int(jitter.period_count as jint),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeCalculateShimmer(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float): ShimmerResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateShimmer<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeCalculateShimmer", |env| {
+        let samples = read_samples(env, &buffer)?;
+        let config = pitch_tracking_config(sample_rate, min_frequency, max_frequency);
+        let shimmer = calculate_shimmer_checked(&samples, &config)?;
+
+        Ok(env.new_object(
+            SHIMMER_RESULT_CLASS,
+            SHIMMER_RESULT_CTOR,
+            &[
+                JValue::Float(measure_or_nan(shimmer.local)),
+                JValue::Float(measure_or_nan(shimmer.local_db)),
+                JValue::Float(measure_or_nan(shimmer.apq3)),
+                JValue::Float(measure_or_nan(shimmer.apq5)),
+                JValue::Float(measure_or_nan(shimmer.apq11)),
+                JValue::Float(measure_or_nan(shimmer.dda)),
+                JValue::Float(measure_or_nan(shimmer.mean_amplitude)),
+                JValue::Int(shimmer.period_count as jint),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeCalculateCpp(buffer: FloatArray, sampleRate: Int, frameSize: Int, hopSize: Int, minFrequency: Float, maxFrequency: Float, timeSmoothing: Float, quefrencySmoothing: Float): CppResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateCpp<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    time_smoothing: jfloat,
+    quefrency_smoothing: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeCalculateCpp", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = CppConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            min_frequency,
+            max_frequency,
+            time_smoothing,
+            quefrency_smoothing,
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let cpp = calculate_cpp_checked(&samples, sample_rate, &config)?;
+
+        Ok(env.new_object(
+            CPP_RESULT_CLASS,
+            CPP_RESULT_CTOR,
+            &[
+                JValue::Float(cpp.cpp as jfloat),
+                JValue::Float(cpp.cpps as jfloat),
+                JValue::Float(cpp.f0 as jfloat),
+                JValue::Int(cpp.frame_count as jint),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeComputeIntensity(buffer: FloatArray, sampleRate: Int, frameSize: Int, hopSize: Int, calibrationOffset: Float, floorDb: Float): IntensityResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeIntensity<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    calibration_offset: jfloat,
+    floor_db: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeComputeIntensity", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let config = IntensityConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            calibration_offset,
+            floor_db,
+        };
+        let sample_rate = u32::try_from(sample_rate).unwrap_or(0);
+        let contour = compute_intensity_checked(&samples, sample_rate, &config)?;
+
+        let rms = new_float_array(env, &contour.rms)?;
+        let intensity_db = new_float_array(env, &contour.intensity_db)?;
+        let peak_db = new_float_array(env, &contour.peak_db)?;
+        let timestamps = new_float_array(env, &contour.timestamps)?;
+        Ok(env.new_object(
+            INTENSITY_RESULT_CLASS,
+            INTENSITY_RESULT_CTOR,
+            &[
+                JValue::Object(&rms),
+                JValue::Object(&intensity_db),
+                JValue::Object(&peak_db),
+                JValue::Object(&timestamps),
+                JValue::Float(contour.mean_db),
+                JValue::Float(contour.min_db),
+                JValue::Float(contour.max_db),
+                JValue::Float(contour.dynamic_range_db()),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeDetectVoiceActivity(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float, frameSize: Int, hopSize: Int, energyThresholdDb: Float, zcrThreshold: Float, voicingThreshold: Float, hangoverTime: Float): VoiceActivityResult`
+///
+/// Segments are returned as a flat `IntArray` of `[start, end)` sample pairs.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectVoiceActivity<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    frame_size: jint,
+    hop_size: jint,
+    energy_threshold_db: jfloat,
+    zcr_threshold: jfloat,
+    voicing_threshold: jfloat,
+    hangover_time: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeDetectVoiceActivity", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let analysis = AnalysisConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            ..pitch_tracking_config(sample_rate, min_frequency, max_frequency)
+        };
+        let config = VadConfigFFI {
+            energy_threshold_db,
+            zcr_threshold,
+            voicing_threshold,
+            hangover_time,
+        };
+        let activity = detect_voice_activity_checked(&samples, &analysis, &config)?;
+
+        let timestamps = new_float_array(env, &activity.timestamps)?;
+        let energy_db = new_float_array(env, &activity.energy_db)?;
+        let zcr = new_float_array(env, &activity.zcr)?;
+        let voiced_probabilities = new_float_array(env, &activity.voiced_probabilities)?;
+
+        let flags: Vec<jboolean> = activity.is_speech.iter().map(|&s| s as jboolean).collect();
+        let is_speech = env.new_boolean_array(flags.len() as jsize)?;
+        env.set_boolean_array_region(&is_speech, 0, &flags)?;
+
+        let bounds: Vec<jint> = activity
+            .segments
+            .iter()
+            .flat_map(|segment| [segment.start as jint, segment.end as jint])
+            .collect();
+        let segments = env.new_int_array(bounds.len() as jsize)?;
+        env.set_int_array_region(&segments, 0, &bounds)?;
+
+        Ok(env.new_object(
+            VOICE_ACTIVITY_RESULT_CLASS,
+            VOICE_ACTIVITY_RESULT_CTOR,
+            &[
+                JValue::Object(&timestamps),
+                JValue::Object(&energy_db),
+                JValue::Object(&zcr),
+                JValue::Object(&voiced_probabilities),
+                JValue::Object(&is_speech),
+                JValue::Object(&segments),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeEstimateVocalTractLength(buffer: FloatArray, sampleRate: Int, minFrequency: Float, maxFrequency: Float, frameSize: Int, hopSize: Int, lpcOrder: Int, minFormantConfidence: Float, speedOfSound: Float): VocalTractLengthResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeEstimateVocalTractLength<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    buffer: JObject<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    frame_size: jint,
+    hop_size: jint,
+    lpc_order: jint,
+    min_formant_confidence: jfloat,
+    speed_of_sound: jfloat,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeEstimateVocalTractLength", |env| {
+        let samples = read_samples(env, &buffer)?;
+
+        let analysis = AnalysisConfigFFI {
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            ..pitch_tracking_config(sample_rate, min_frequency, max_frequency)
+        };
+        let config = VtlConfigFFI {
+            lpc_order: non_negative("lpc_order", lpc_order)?,
+            min_formant_confidence,
+            speed_of_sound,
+        };
+        let vtl = estimate_vocal_tract_length_checked(&samples, &analysis, &config)?;
+
+        let [f1, f2, f3, f4] = vtl.formants;
+        Ok(env.new_object(
+            VOCAL_TRACT_LENGTH_RESULT_CLASS,
+            VOCAL_TRACT_LENGTH_RESULT_CTOR,
+            &[
+                JValue::Float(vtl.vtl_cm),
+                JValue::Float(vtl.spacing_vtl_cm),
+                JValue::Float(vtl.formant_spacing_hz),
+                JValue::Float(f1),
+                JValue::Float(f2),
+                JValue::Float(f3),
+                JValue::Float(f4),
+                JValue::Float(vtl.confidence),
+                JValue::Int(vtl.frame_count as jint),
+            ],
+        )?)
+    })
+}
+
+// ============================================================================
+// VoiceAnalyzer (streaming API)
+// ============================================================================
+
+/// `external fun nativeVoiceAnalyzerNew(sampleRate: Int, minFrequency: Float, maxFrequency: Float, frameSize: Int, hopSize: Int): Long`
+///
+/// Returns a handle to a boxed `VoiceAnalyzer` (freed by `nativeVoiceAnalyzerFree`),
+/// or throws `IllegalArgumentException` for an invalid configuration.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerNew<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    frame_size: jint,
+    hop_size: jint,
+) -> jlong {
+    jni_call(&mut env, "nativeVoiceAnalyzerNew", |_env| {
+        let mut config = loqa_analysis_config_default();
+        config.sample_rate = non_negative("sample_rate", sample_rate)?;
+        config.min_frequency = min_frequency;
+        config.max_frequency = max_frequency;
+        config.frame_size = non_negative("frame_size", frame_size)?;
+        config.hop_size = non_negative("hop_size", hop_size)?;
+
+        let analyzer = voice_analyzer_new_checked(&config)?;
+        Ok(Box::into_raw(Box::new(analyzer)) as jlong)
+    })
+}
+
+/// `external fun nativeVoiceAnalyzerProcessStream(handle: Long, buffer: FloatArray): Array<PitchResult>`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerProcessStream<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObjectArray<'local> {
+    jni_call(&mut env, "nativeVoiceAnalyzerProcessStream", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVoiceAnalyzerNew
+        let analyzer = unsafe { analyzer_from_handle(handle)? };
+        let samples = read_samples(env, &buffer)?;
+        let frames = analyzer.process_stream(&samples);
+
+        let array =
+            env.new_object_array(frames.len() as jsize, PITCH_RESULT_CLASS, JObject::null())?;
+        for (index, frame) in frames.iter().enumerate() {
+            let element = new_pitch_result(env, frame)?;
+            env.set_object_array_element(&array, index as jsize, &element)?;
+            // Long streams can exceed the local reference table otherwise
+            env.delete_local_ref(element)?;
+        }
+        Ok(array)
+    })
+}
+
+/// `external fun nativeVoiceAnalyzerProcessStreamDirect(handle: Long, buffer: FloatBuffer): Array<PitchResult>`
+///
+/// Zero-copy variant of `nativeVoiceAnalyzerProcessStream` for direct buffers.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerProcessStreamDirect<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObjectArray<'local> {
+    Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerProcessStream(
+        env, class, handle, buffer,
+    )
+}
+
+/// `external fun nativeVoiceAnalyzerProcessBuffer(handle: Long, buffer: FloatArray): PitchTrackResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerProcessBuffer<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeVoiceAnalyzerProcessBuffer", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVoiceAnalyzerNew
+        let analyzer = unsafe { analyzer_from_handle(handle)? };
+        let samples = read_samples(env, &buffer)?;
+        if samples.is_empty() {
+            return Err(Failure::Error(
+                LoqaErrorCode::InvalidLength,
+                "length must be > 0, got 0".into(),
+            ));
+        }
+
+        let track = analyzer.process_buffer(&samples).map_err(|e| {
+            Failure::Error(
+                LoqaErrorCode::AnalysisFailed,
+                format!("process_buffer failed: {e}"),
+            )
+        })?;
+
+        let pitch_track = new_float_array(env, &track.pitch_track)?;
+        let voiced_probabilities = new_float_array(env, &track.voiced_probabilities)?;
+        let timestamps = new_float_array(env, &track.timestamps)?;
+        Ok(env.new_object(
+            PITCH_TRACK_RESULT_CLASS,
+            PITCH_TRACK_RESULT_CTOR,
+            &[
+                JValue::Object(&pitch_track),
+                JValue::Object(&voiced_probabilities),
+                JValue::Object(&timestamps),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativePostprocessPitchTrack(pitchTrack: FloatArray, voicedProbabilities: FloatArray, timestamps: FloatArray, correctOctaveJumps: Boolean, octaveWindow: Int, smoothing: Int, smoothingWindow: Int, maxGapFrames: Int): PitchTrackResult`
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativePostprocessPitchTrack<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pitch_track: JObject<'local>,
+    voiced_probabilities: JObject<'local>,
+    timestamps: JObject<'local>,
+    correct_octave_jumps: jboolean,
+    octave_window: jint,
+    smoothing: jint,
+    smoothing_window: jint,
+    max_gap_frames: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativePostprocessPitchTrack", |env| {
+        let pitch = read_samples(env, &pitch_track)?;
+        let probs = read_samples(env, &voiced_probabilities)?;
+        let times = read_samples(env, &timestamps)?;
+        if probs.len() != pitch.len() || times.len() != pitch.len() {
+            return Err(Failure::Error(
+                LoqaErrorCode::InvalidLength,
+                format!(
+                    "pitchTrack, voicedProbabilities and timestamps must have equal lengths, \
+                     got {}, {} and {}",
+                    pitch.len(),
+                    probs.len(),
+                    times.len()
+                ),
+            ));
+        }
+        let max_gap_frames = non_negative("max_gap_frames", max_gap_frames)?;
+
+        let config = PitchPostprocessConfigFFI {
+            correct_octave_jumps: correct_octave_jumps != 0,
+            octave_window: non_negative("octave_window", octave_window)?,
+            smoothing: non_negative("smoothing", smoothing)?,
+            smoothing_window: non_negative("smoothing_window", smoothing_window)?,
+            max_gap_frames,
+        };
+        let processed = postprocess_pitch_track_checked(&pitch, &config)?;
+
+        let pitch_track = new_float_array(env, &processed)?;
+        let voiced_probabilities = new_float_array(env, &probs)?;
+        let timestamps = new_float_array(env, &times)?;
+        Ok(env.new_object(
+            PITCH_TRACK_RESULT_CLASS,
+            PITCH_TRACK_RESULT_CTOR,
+            &[
+                JValue::Object(&pitch_track),
+                JValue::Object(&voiced_probabilities),
+                JValue::Object(&timestamps),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativePitchTrackStatistics(pitchTrack: FloatArray, voicedProbabilities: FloatArray, referenceHz: Float, weightByVoicing: Boolean): PitchStatisticsResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativePitchTrackStatistics<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pitch_track: JObject<'local>,
+    voiced_probabilities: JObject<'local>,
+    reference_hz: jfloat,
+    weight_by_voicing: jboolean,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativePitchTrackStatistics", |env| {
+        let pitch = read_samples(env, &pitch_track)?;
+        let probs = read_samples(env, &voiced_probabilities)?;
+        if probs.len() != pitch.len() {
+            return Err(Failure::Error(
+                LoqaErrorCode::InvalidLength,
+                format!(
+                    "pitchTrack and voicedProbabilities must have equal lengths, got {} and {}",
+                    pitch.len(),
+                    probs.len()
+                ),
+            ));
+        }
+
+        let config = PitchStatsConfigFFI {
+            reference_hz,
+            weight_by_voicing: weight_by_voicing != 0,
+        };
+        let stats = PitchStatsFFI::from(pitch_track_statistics_checked(&pitch, &probs, &config)?);
+
+        Ok(env.new_object(
+            PITCH_STATISTICS_RESULT_CLASS,
+            PITCH_STATISTICS_RESULT_CTOR,
+            &[
+                JValue::Int(stats.frame_count as jint),
+                JValue::Int(stats.voiced_frame_count as jint),
+                JValue::Float(stats.voiced_ratio),
+                JValue::Float(stats.mean_hz),
+                JValue::Float(stats.median_hz),
+                JValue::Float(stats.std_hz),
+                JValue::Float(stats.p5_hz),
+                JValue::Float(stats.p95_hz),
+                JValue::Float(stats.mean_semitones),
+                JValue::Float(stats.median_semitones),
+                JValue::Float(stats.std_semitones),
+                JValue::Float(stats.p5_semitones),
+                JValue::Float(stats.p95_semitones),
+                JValue::Float(stats.range_semitones),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeAnalyzeVibrato(pitchTrack: FloatArray, frameRate: Float, minRateHz: Float, maxRateHz: Float, minExtentCents: Float, maxExtentCents: Float, minCycles: Int, windowSeconds: Float, windowHopFrames: Int): VibratoResult`
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeVibrato<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    pitch_track: JObject<'local>,
+    frame_rate: jfloat,
+    min_rate_hz: jfloat,
+    max_rate_hz: jfloat,
+    min_extent_cents: jfloat,
+    max_extent_cents: jfloat,
+    min_cycles: jint,
+    window_seconds: jfloat,
+    window_hop_frames: jint,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeAnalyzeVibrato", |env| {
+        let pitch = read_samples(env, &pitch_track)?;
+        let config = VibratoConfigFFI {
+            frame_rate,
+            min_rate_hz,
+            max_rate_hz,
+            min_extent_cents,
+            max_extent_cents,
+            min_cycles: non_negative("min_cycles", min_cycles)?,
+            window_seconds,
+            window_hop_frames: non_negative("window_hop_frames", window_hop_frames)?,
+        };
+        let analysis = analyze_vibrato_checked(&pitch, &config)?;
+
+        // Segments go to Kotlin as 7 floats each
+        let segments: Vec<f32> = analysis
+            .segments
+            .iter()
+            .flat_map(|s| {
+                [
+                    s.start,
+                    s.end,
+                    s.rate_hz,
+                    s.extent_cents,
+                    s.rate_jitter,
+                    s.extent_jitter,
+                    s.onset_delay,
+                ]
+            })
+            .collect();
+        let segments = new_float_array(env, &segments)?;
+        let window_times = new_float_array(env, &analysis.window_times)?;
+        let window_rate = new_float_array(env, &analysis.window_rate)?;
+        let window_extent = new_float_array(env, &analysis.window_extent)?;
+        let window_rate_jitter = new_float_array(env, &analysis.window_rate_jitter)?;
+        let window_extent_jitter = new_float_array(env, &analysis.window_extent_jitter)?;
+        let summary = analysis.summary;
+
+        Ok(env.new_object(
+            VIBRATO_RESULT_CLASS,
+            VIBRATO_RESULT_CTOR,
+            &[
+                JValue::Object(&segments),
+                JValue::Object(&window_times),
+                JValue::Object(&window_rate),
+                JValue::Object(&window_extent),
+                JValue::Object(&window_rate_jitter),
+                JValue::Object(&window_extent_jitter),
+                JValue::Float(summary.vibrato_ratio as jfloat),
+                JValue::Float(measure_or_nan(summary.rate_hz)),
+                JValue::Float(measure_or_nan(summary.extent_cents)),
+                JValue::Float(measure_or_nan(summary.rate_jitter)),
+                JValue::Float(measure_or_nan(summary.extent_jitter)),
+                JValue::Float(measure_or_nan(summary.onset_delay)),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeVoiceAnalyzerReset(handle: Long)`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerReset<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    jni_call(&mut env, "nativeVoiceAnalyzerReset", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVoiceAnalyzerNew
+        unsafe { analyzer_from_handle(handle)? }.reset();
+        Ok(())
+    })
+}
+
+/// `external fun nativeVoiceAnalyzerFree(handle: Long)`
+///
+/// A handle of 0 is ignored; any other handle must not be used afterwards.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVoiceAnalyzerFree<
+    'local,
+>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    clear_last_error();
+    if handle != 0 {
+        // Cleanup must not throw; a panic while dropping is only recorded and logged
+        let _ = catch_panic("nativeVoiceAnalyzerFree", || {
+            // SAFETY: the handle was produced by Box::into_raw in nativeVoiceAnalyzerNew
+            drop(unsafe { Box::from_raw(handle as *mut VoiceAnalyzer) });
+        });
+    }
+}
+
+// ============================================================================
+// Streaming analyzer (pushes of any size)
+// ============================================================================
+
+/// Reborrows the `StreamingAnalyzer` behind a handle from `nativeStreamAnalyzerNew`
+///
+/// # Safety
+/// * `handle` must be 0 or a live handle returned by `nativeStreamAnalyzerNew`
+unsafe fn stream_from_handle<'a>(handle: jlong) -> Result<&'a mut StreamingAnalyzer, Failure> {
+    (handle as *mut StreamingAnalyzer).as_mut().ok_or_else(|| {
+        Failure::Error(
+            LoqaErrorCode::NullPointer,
+            "stream analyzer handle is 0".into(),
+        )
+    })
+}
+
+/// Builds a Kotlin `StreamFramesResult` from the frames of a push or flush
+fn new_stream_frames<'local>(
+    env: &mut JNIEnv<'local>,
+    frames: &[StreamFrame],
+) -> Result<JObject<'local>, Failure> {
+    let frequencies: Vec<f32> = frames.iter().map(StreamFrame::frequency).collect();
+    let confidences: Vec<f32> = frames.iter().map(|f| f.pitch.confidence).collect();
+    let probabilities: Vec<f32> = frames.iter().map(|f| f.pitch.voiced_probability).collect();
+    let timestamps: Vec<f32> = frames.iter().map(|f| f.timestamp).collect();
+    let flags: Vec<jboolean> = frames
+        .iter()
+        .map(|f| f.pitch.is_voiced as jboolean)
+        .collect();
+
+    let frequencies = new_float_array(env, &frequencies)?;
+    let confidences = new_float_array(env, &confidences)?;
+    let is_voiced = env.new_boolean_array(flags.len() as jsize)?;
+    env.set_boolean_array_region(&is_voiced, 0, &flags)?;
+    let voiced_probabilities = new_float_array(env, &probabilities)?;
+    let timestamps = new_float_array(env, &timestamps)?;
+    Ok(env.new_object(
+        STREAM_FRAMES_RESULT_CLASS,
+        STREAM_FRAMES_RESULT_CTOR,
+        &[
+            JValue::Object(&frequencies),
+            JValue::Object(&confidences),
+            JValue::Object(&is_voiced),
+            JValue::Object(&voiced_probabilities),
+            JValue::Object(&timestamps),
+        ],
+    )?)
+}
+
+/// `external fun nativeStreamAnalyzerNew(sampleRate: Int, minFrequency: Float, maxFrequency: Float, frameSize: Int, hopSize: Int): Long`
+///
+/// Returns a handle to a boxed `StreamingAnalyzer` (freed by `nativeStreamAnalyzerFree`),
+/// or throws `IllegalArgumentException` for an invalid configuration.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeStreamAnalyzerNew<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    frame_size: jint,
+    hop_size: jint,
+) -> jlong {
+    jni_call(&mut env, "nativeStreamAnalyzerNew", |_env| {
+        let config = AnalysisConfigFFI {
+            sample_rate: non_negative("sample_rate", sample_rate)?,
+            min_frequency,
+            max_frequency,
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            ..loqa_analysis_config_default()
+        };
+        let analyzer = stream_analyzer_new_checked(&config)?;
+        Ok(Box::into_raw(Box::new(analyzer)) as jlong)
+    })
+}
+
+/// `external fun nativeStreamAnalyzerPush(handle: Long, buffer: FloatArray): StreamFramesResult`
+///
+/// Accepts a `FloatArray` or a direct `FloatBuffer` of any length.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeStreamAnalyzerPush<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeStreamAnalyzerPush", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeStreamAnalyzerNew
+        let analyzer = unsafe { stream_from_handle(handle)? };
+        let samples = read_samples(env, &buffer)?;
+        let frames = analyzer.push(&samples);
+        new_stream_frames(env, &frames)
+    })
+}
+
+/// `external fun nativeStreamAnalyzerFlush(handle: Long): StreamFramesResult`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeStreamAnalyzerFlush<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeStreamAnalyzerFlush", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeStreamAnalyzerNew
+        let frames = unsafe { stream_from_handle(handle)? }.flush();
+        new_stream_frames(env, &frames)
+    })
+}
+
+/// `external fun nativeStreamAnalyzerReset(handle: Long)`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeStreamAnalyzerReset<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    jni_call(&mut env, "nativeStreamAnalyzerReset", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeStreamAnalyzerNew
+        unsafe { stream_from_handle(handle)? }.reset();
+        Ok(())
+    })
+}
+
+/// `external fun nativeStreamAnalyzerFree(handle: Long)`
+///
+/// A handle of 0 is ignored; any other handle must not be used afterwards.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeStreamAnalyzerFree<
+    'local,
+>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    clear_last_error();
+    if handle != 0 {
+        // Cleanup must not throw; a panic while dropping is only recorded and logged
+        let _ = catch_panic("nativeStreamAnalyzerFree", || {
+            // SAFETY: the handle was produced by Box::into_raw in nativeStreamAnalyzerNew
+            drop(unsafe { Box::from_raw(handle as *mut StreamingAnalyzer) });
+        });
+    }
+}
+
+// ============================================================================
+// Tuner (nearest note, cents deviation and stability)
+// ============================================================================
+
+/// Reborrows the `Tuner` behind a handle from `nativeTunerNew`
+///
+/// # Safety
+/// * `handle` must be 0 or a live handle returned by `nativeTunerNew`
+unsafe fn tuner_from_handle<'a>(handle: jlong) -> Result<&'a mut Tuner, Failure> {
+    (handle as *mut Tuner)
+        .as_mut()
+        .ok_or_else(|| Failure::Error(LoqaErrorCode::NullPointer, "tuner handle is 0".into()))
+}
+
+/// `external fun nativeTunerNew(sampleRate: Int, minFrequency: Float, maxFrequency: Float, frameSize: Int, hopSize: Int, referenceHz: Float, temperamentCents: FloatArray, stabilityFrames: Int): Long`
+///
+/// `temperamentCents` holds the offsets of C to B from equal temperament.
+/// Returns a handle to a boxed `Tuner` (freed by `nativeTunerFree`), or throws
+/// `IllegalArgumentException` for an invalid configuration.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeTunerNew<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    sample_rate: jint,
+    min_frequency: jfloat,
+    max_frequency: jfloat,
+    frame_size: jint,
+    hop_size: jint,
+    reference_hz: jfloat,
+    temperament_cents: JObject<'local>,
+    stability_frames: jint,
+) -> jlong {
+    jni_call(&mut env, "nativeTunerNew", |env| {
+        let cents = read_samples(env, &temperament_cents)?;
+        let temperament = <[f32; PITCH_CLASSES]>::try_from(&*cents).map_err(|_| {
+            Failure::Error(
+                LoqaErrorCode::InvalidArgument,
+                format!("temperamentCents must hold 12 offsets, got {}", cents.len()),
+            )
+        })?;
+
+        let analysis = AnalysisConfigFFI {
+            sample_rate: non_negative("sample_rate", sample_rate)?,
+            min_frequency,
+            max_frequency,
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            ..loqa_analysis_config_default()
+        };
+        let config = TunerConfigFFI {
+            reference_hz,
+            temperament_cents: temperament,
+            stability_frames: non_negative("stability_frames", stability_frames)?,
+        };
+        let tuner = tuner_new_checked(&analysis, &config)?;
+        Ok(Box::into_raw(Box::new(tuner)) as jlong)
+    })
+}
+
+/// `external fun nativeTunerPush(handle: Long, buffer: FloatArray): TunerReading`
+///
+/// Accepts a `FloatArray` or a direct `FloatBuffer` of any length.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeTunerPush<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeTunerPush", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeTunerNew
+        let tuner = unsafe { tuner_from_handle(handle)? };
+        let samples = read_samples(env, &buffer)?;
+        let reading = TunerReadingFFI::from(tuner.push(&samples));
+        Ok(env.new_object(
+            TUNER_READING_CLASS,
+            TUNER_READING_CTOR,
+            &[
+                JValue::Int(reading.frame_count as jint),
+                JValue::Float(reading.timestamp),
+                JValue::Bool(reading.is_voiced as jboolean),
+                JValue::Float(reading.frequency),
+                JValue::Float(reading.confidence),
+                JValue::Int(reading.midi_note),
+                JValue::Int(reading.pitch_class as jint),
+                JValue::Int(reading.octave),
+                JValue::Float(reading.cents),
+                JValue::Float(reading.target_frequency),
+                JValue::Float(reading.stability),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeTunerReset(handle: Long)`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeTunerReset<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    jni_call(&mut env, "nativeTunerReset", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeTunerNew
+        unsafe { tuner_from_handle(handle)? }.reset();
+        Ok(())
+    })
+}
+
+/// `external fun nativeTunerFree(handle: Long)`
+///
+/// A handle of 0 is ignored; any other handle must not be used afterwards.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeTunerFree<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    clear_last_error();
+    if handle != 0 {
+        // Cleanup must not throw; a panic while dropping is only recorded and logged
+        let _ = catch_panic("nativeTunerFree", || {
+            // SAFETY: the handle was produced by Box::into_raw in nativeTunerNew
+            drop(unsafe { Box::from_raw(handle as *mut Tuner) });
+        });
+    }
+}
+
+// ============================================================================
+// Voice range profile (semitone x dB phonetogram)
+// ============================================================================
+
+/// Reborrows the `VoiceRangeProfile` behind a handle from `nativeVrpNew`
+///
+/// # Safety
+/// * `handle` must be 0 or a live handle returned by `nativeVrpNew`
+unsafe fn vrp_from_handle<'a>(handle: jlong) -> Result<&'a mut VoiceRangeProfile, Failure> {
+    (handle as *mut VoiceRangeProfile)
+        .as_mut()
+        .ok_or_else(|| Failure::Error(LoqaErrorCode::NullPointer, "profile handle is 0".into()))
+}
+
+/// `external fun nativeVrpNew(referenceHz: Float, minMidiNote: Int, maxMidiNote: Int, minDb: Float, maxDb: Float, dbStep: Float, minCellFrames: Int): Long`
+///
+/// Returns a handle to a boxed `VoiceRangeProfile` (freed by `nativeVrpFree`),
+/// or throws `IllegalArgumentException` for an invalid configuration.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpNew<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    reference_hz: jfloat,
+    min_midi_note: jint,
+    max_midi_note: jint,
+    min_db: jfloat,
+    max_db: jfloat,
+    db_step: jfloat,
+    min_cell_frames: jint,
+) -> jlong {
+    jni_call(&mut env, "nativeVrpNew", |_env| {
+        let config = VrpConfigFFI {
+            reference_hz,
+            min_midi_note: non_negative("min_midi_note", min_midi_note)?,
+            max_midi_note: non_negative("max_midi_note", max_midi_note)?,
+            min_db,
+            max_db,
+            db_step,
+            min_cell_frames: non_negative("min_cell_frames", min_cell_frames)?,
+        };
+        let profile = vrp_new_checked(&config)?;
+        Ok(Box::into_raw(Box::new(profile)) as jlong)
+    })
+}
+
+/// `external fun nativeVrpAddFrames(handle: Long, pitchTrack: FloatArray, intensityDb: FloatArray)`
+///
+/// Frame i pairs `pitchTrack[i]` (Hz, 0 = unvoiced) with `intensityDb[i]`.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpAddFrames<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    pitch_track: JObject<'local>,
+    intensity_db: JObject<'local>,
+) {
+    jni_call(&mut env, "nativeVrpAddFrames", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVrpNew
+        let profile = unsafe { vrp_from_handle(handle)? };
+        let pitch_track = read_samples(env, &pitch_track)?;
+        let intensity_db = read_samples(env, &intensity_db)?;
+        vrp_add_frames_checked(profile, &pitch_track, &intensity_db)?;
+        Ok(())
+    })
+}
+
+/// `external fun nativeVrpMerge(handle: Long, sourceHandle: Long)`
+///
+/// Adds the counts of `sourceHandle` to `handle`; both must share a config.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpMerge<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    source_handle: jlong,
+) {
+    jni_call(&mut env, "nativeVrpMerge", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVrpNew; the
+        // source is copied before the target is borrowed, so they may be equal
+        let source = unsafe { vrp_from_handle(source_handle)? }.clone();
+        let target = unsafe { vrp_from_handle(handle)? };
+        target
+            .merge(&source)
+            .map_err(|message| Failure::Error(LoqaErrorCode::InvalidArgument, message))
+    })
+}
+
+/// `external fun nativeVrpGet(handle: Long): VoiceRangeProfileResult`
+///
+/// Per-note levels are NaN and the lowest and highest notes -1 where no cell
+/// is sustained, as in `VrpFFI`.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpGet<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeVrpGet", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVrpNew
+        let profile = unsafe { vrp_from_handle(handle)? };
+        let settings = profile.settings();
+        let summary = profile.summary();
+        let or_nan = |levels: &[Option<f32>]| -> Vec<f32> {
+            levels.iter().map(|db| db.unwrap_or(f32::NAN)).collect()
+        };
+        let note_hz = |note: Option<i32>| note.map_or(f32::NAN, |m| settings.note_hz(m));
+
+        let cells: Vec<jint> = profile
+            .counts()
+            .iter()
+            .map(|&n| n.min(jint::MAX as u32) as jint)
+            .collect();
+        let counts = env.new_int_array(cells.len() as jsize)?;
+        env.set_int_array_region(&counts, 0, &cells)?;
+        let soft_db = new_float_array(env, &or_nan(&summary.soft_db))?;
+        let loud_db = new_float_array(env, &or_nan(&summary.loud_db))?;
+        let dynamic_range_db = new_float_array(env, &or_nan(&summary.dynamic_range_db()))?;
+
+        Ok(env.new_object(
+            VOICE_RANGE_PROFILE_RESULT_CLASS,
+            VOICE_RANGE_PROFILE_RESULT_CTOR,
+            &[
+                JValue::Object(&counts),
+                JValue::Object(&soft_db),
+                JValue::Object(&loud_db),
+                JValue::Object(&dynamic_range_db),
+                JValue::Int(settings.rows() as jint),
+                JValue::Int(settings.db_columns as jint),
+                JValue::Int(settings.min_note),
+                JValue::Float(settings.min_db),
+                JValue::Float(settings.db_step),
+                JValue::Int(summary.lowest_note.unwrap_or(-1)),
+                JValue::Int(summary.highest_note.unwrap_or(-1)),
+                JValue::Float(note_hz(summary.lowest_note)),
+                JValue::Float(note_hz(summary.highest_note)),
+                JValue::Int(summary.covered_cells as jint),
+                JValue::Float(summary.area),
+                JValue::Int(profile.frame_count().min(jint::MAX as u64) as jint),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeVrpReset(handle: Long)`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpReset<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    jni_call(&mut env, "nativeVrpReset", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeVrpNew
+        unsafe { vrp_from_handle(handle)? }.reset();
+        Ok(())
+    })
+}
+
+/// `external fun nativeVrpFree(handle: Long)`
+///
+/// A handle of 0 is ignored; any other handle must not be used afterwards.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeVrpFree<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    clear_last_error();
+    if handle != 0 {
+        // Cleanup must not throw; a panic while dropping is only recorded and logged
+        let _ = catch_panic("nativeVrpFree", || {
+            // SAFETY: the handle was produced by Box::into_raw in nativeVrpNew
+            drop(unsafe { Box::from_raw(handle as *mut VoiceRangeProfile) });
+        });
+    }
+}
+
+// ============================================================================
+// Formant tracker (framewise LPC with Viterbi continuity of F1-F4)
+// ============================================================================
+
+/// Reborrows the `FormantTracker` behind a handle from `nativeFormantTrackerNew`
+///
+/// # Safety
+/// * `handle` must be 0 or a live handle returned by `nativeFormantTrackerNew`
+unsafe fn formant_tracker_from_handle<'a>(
+    handle: jlong,
+) -> Result<&'a mut FormantTracker, Failure> {
+    (handle as *mut FormantTracker)
+        .as_mut()
+        .ok_or_else(|| Failure::Error(LoqaErrorCode::NullPointer, "tracker handle is 0".into()))
+}
+
+/// `external fun nativeFormantTrackerNew(sampleRate: Int, frameSize: Int, hopSize: Int, lpcOrder: Int, ceilingHz: Float, continuityWeight: Float, minConfidence: Float, silenceDb: Float): Long`
+///
+/// Returns a handle to a boxed `FormantTracker` (freed by
+/// `nativeFormantTrackerFree`), or throws `IllegalArgumentException` for an
+/// invalid configuration.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeFormantTrackerNew<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    sample_rate: jint,
+    frame_size: jint,
+    hop_size: jint,
+    lpc_order: jint,
+    ceiling_hz: jfloat,
+    continuity_weight: jfloat,
+    min_confidence: jfloat,
+    silence_db: jfloat,
+) -> jlong {
+    jni_call(&mut env, "nativeFormantTrackerNew", |_env| {
+        let config = FormantTrackerConfigFFI {
+            sample_rate: non_negative("sample_rate", sample_rate)?,
+            frame_size: non_negative("frame_size", frame_size)?,
+            hop_size: non_negative("hop_size", hop_size)?,
+            lpc_order: non_negative("lpc_order", lpc_order)?,
+            ceiling_hz,
+            continuity_weight,
+            min_confidence,
+            silence_db,
+        };
+        let tracker = formant_tracker_new_checked(&config)?;
+        Ok(Box::into_raw(Box::new(tracker)) as jlong)
+    })
+}
+
+/// `external fun nativeFormantTrackerProcessBuffer(handle: Long, buffer: FloatArray): FormantTrackResult`
+///
+/// F1-F4 and their bandwidths are returned row-major, four values per frame.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeFormantTrackerProcessBuffer<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JObject<'local>,
+) -> JObject<'local> {
+    jni_call(&mut env, "nativeFormantTrackerProcessBuffer", |env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeFormantTrackerNew
+        let tracker = unsafe { formant_tracker_from_handle(handle)? };
+        let samples = read_samples(env, &buffer)?;
+        let frames = track_formants_checked(tracker, &samples)?;
+
+        let formants = || frames.iter().flat_map(|frame| frame.formants);
+        let frequencies: Vec<f32> = formants().map(|f| f.frequency).collect();
+        let bandwidths: Vec<f32> = formants().map(|f| f.bandwidth).collect();
+        let confidences: Vec<f32> = frames.iter().map(|frame| frame.confidence).collect();
+        let timestamps: Vec<f32> = frames.iter().map(|frame| frame.timestamp).collect();
+
+        let frequencies = new_float_array(env, &frequencies)?;
+        let bandwidths = new_float_array(env, &bandwidths)?;
+        let confidences = new_float_array(env, &confidences)?;
+        let timestamps = new_float_array(env, &timestamps)?;
+
+        let flags: Vec<jboolean> = frames
+            .iter()
+            .map(|frame| frame.reliable as jboolean)
+            .collect();
+        let reliable = env.new_boolean_array(flags.len() as jsize)?;
+        env.set_boolean_array_region(&reliable, 0, &flags)?;
+
+        Ok(env.new_object(
+            FORMANT_TRACK_RESULT_CLASS,
+            FORMANT_TRACK_RESULT_CTOR,
+            &[
+                JValue::Object(&frequencies),
+                JValue::Object(&bandwidths),
+                JValue::Object(&confidences),
+                JValue::Object(&reliable),
+                JValue::Object(&timestamps),
+            ],
+        )?)
+    })
+}
+
+/// `external fun nativeFormantTrackerReset(handle: Long)`
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeFormantTrackerReset<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    jni_call(&mut env, "nativeFormantTrackerReset", |_env| {
+        // SAFETY: Kotlin only passes handles obtained from nativeFormantTrackerNew
+        unsafe { formant_tracker_from_handle(handle)? }.reset();
+        Ok(())
+    })
+}
+
+/// `external fun nativeFormantTrackerFree(handle: Long)`
+///
+/// A handle of 0 is ignored; any other handle must not be used afterwards.
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeFormantTrackerFree<
+    'local,
+>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+) {
+    clear_last_error();
+    if handle != 0 {
+        // Cleanup must not throw; a panic while dropping is only recorded and logged
+        let _ = catch_panic("nativeFormantTrackerFree", || {
+            // SAFETY: the handle was produced by Box::into_raw in nativeFormantTrackerNew
+            drop(unsafe { Box::from_raw(handle as *mut FormantTracker) });
+        });
+    }
+}
+
+// ============================================================================
+// Logging
+// ============================================================================
+
+/// Kotlin `RustLogger` that receives forwarded messages, plus the VM needed to
+/// attach whichever thread happens to log
+struct JavaLogger {
+    vm: JavaVM,
+    logger: GlobalRef,
+}
+
+static JAVA_LOGGER: RwLock<Option<Arc<JavaLogger>>> = RwLock::new(None);
+
+/// `LoqaLogCallback` that forwards to the installed Kotlin `RustLogger`
+extern "C" fn forward_to_java_logger(level: LoqaLogLevel, message: *const c_char) {
+    // Clone out of the lock so a logger that calls setLogger cannot deadlock
+    let Some(java) = JAVA_LOGGER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+    else {
+        return;
+    };
+    let Ok(mut env) = java.vm.attach_current_thread_as_daemon() else {
+        return;
+    };
+
+    // Calling into Java with an exception pending is not allowed; drop the message
+    if env.exception_check().unwrap_or(true) {
+        return;
+    }
+
+    // SAFETY: the logging module passes a valid C string for the duration of the call
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    let Ok(message) = env.new_string(message) else {
+        return;
+    };
+    let logged = env.call_method(
+        &java.logger,
+        "log",
+        "(ILjava/lang/String;)V",
+        &[JValue::Int(level as jint), JValue::Object(&message)],
+    );
+    if logged.is_err() {
+        // A throwing logger must not leak its exception into unrelated Kotlin code
+        let _ = env.exception_clear();
+    }
+    let _ = env.delete_local_ref(message);
+}
+
+/// `external fun nativeSetLogCallback(logger: RustLogger?)`
+///
+/// Routes Rust diagnostics to `logger.log(level, message)`; null stops logging
+/// (the default).
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeSetLogCallback<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    logger: JObject<'local>,
+) {
+    jni_call(&mut env, "nativeSetLogCallback", |env| {
+        let java = if logger.is_null() {
+            None
+        } else {
+            Some(Arc::new(JavaLogger {
+                vm: env.get_java_vm()?,
+                logger: env.new_global_ref(&logger)?,
+            }))
+        };
+        let callback = java.is_some().then_some(forward_to_java_logger as _);
+
+        *JAVA_LOGGER
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = java;
+        loqa_set_log_callback(callback);
+        Ok(())
+    })
+}
+
+/// `external fun nativeSetLogLevel(level: Int)`
+///
+/// Throws `IllegalArgumentException` for values outside 0 (off) to 5 (trace).
+#[no_mangle]
+pub extern "system" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeSetLogLevel<
+    'local,
+>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    level: jint,
+) {
+    jni_call(&mut env, "nativeSetLogLevel", |_env| {
+        // Negative values wrap to out-of-range levels and are rejected
+        check_recorded(loqa_set_log_level(level as u32))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const RUST_BRIDGE_KT: &str =
+        include_str!("../../android/src/main/java/com/loqalabs/loqaaudiodsp/RustJNI/RustBridge.kt");
+
+    /// JNI constructor descriptor for a Kotlin `data class` declared in RustBridge.kt
+    fn kotlin_constructor_descriptor(class_name: &str) -> String {
+        let declaration = format!("data class {class_name}(");
+        let start = RUST_BRIDGE_KT
+            .find(&declaration)
+            .unwrap_or_else(|| panic!("{class_name} not declared in RustBridge.kt"))
+            + declaration.len();
+        let end = start + RUST_BRIDGE_KT[start..].find(')').unwrap();
+
+        let params: String = RUST_BRIDGE_KT[start..end]
+            .split(',')
+            .map(|param| match param.rsplit(':').next().unwrap().trim() {
+                "Float" => "F",
+                "Boolean" => "Z",
+                "Int" => "I",
+                "FloatArray" => "[F",
+                "BooleanArray" => "[Z",
+                "IntArray" => "[I",
+                other => panic!("unsupported Kotlin type {other} in {class_name}"),
+            })
+            .collect();
+        format!("({params})V")
+    }
+
+    /// Fully qualified JNI symbols for every `external fun` in RustBridge.kt,
+    /// derived from the file's `package` declaration
+    fn kotlin_jni_symbols() -> BTreeSet<String> {
+        let package = RUST_BRIDGE_KT
+            .lines()
+            .find_map(|line| line.strip_prefix("package "))
+            .expect("RustBridge.kt has no package declaration")
+            .trim();
+        let prefix = format!(
+            "Java_{}_RustBridge_",
+            package.replace('_', "_1").replace('.', "_")
+        );
+
+        RUST_BRIDGE_KT
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("external fun "))
+            .map(|rest| {
+                let name = &rest[..rest.find('(').unwrap()];
+                format!("{prefix}{}", name.replace('_', "_1"))
+            })
+            .collect()
+    }
+
+    /// Names of the `Java_*` functions implemented in this file
+    fn rust_jni_symbols() -> BTreeSet<String> {
+        include_str!("android.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("pub extern \"system\" fn "))
+            .map(|rest| {
+                rest.chars()
+                    .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_jni_symbols_match_kotlin_external_funs() {
+        let kotlin = kotlin_jni_symbols();
+        let rust = rust_jni_symbols();
+
+        assert!(kotlin
+            .iter()
+            .all(|symbol| symbol.contains("_RustBridge_native")));
+        // A missing implementation is an UnsatisfiedLinkError at the first call
+        assert_eq!(
+            kotlin.difference(&rust).collect::<Vec<_>>(),
+            Vec::<&String>::new(),
+            "external funs without a Java_* function in android.rs"
+        );
+        assert_eq!(
+            rust.difference(&kotlin).collect::<Vec<_>>(),
+            Vec::<&String>::new(),
+            "Java_* functions in android.rs without an external fun"
+        );
+    }
+
+    #[test]
+    fn test_constructor_descriptors_match_kotlin_data_classes() {
+        for (class, descriptor) in [
+            (PITCH_RESULT_CLASS, PITCH_RESULT_CTOR),
+            (FORMANTS_RESULT_CLASS, FORMANTS_RESULT_CTOR),
+            (HNR_RESULT_CLASS, HNR_RESULT_CTOR),
+            (H1H2_RESULT_CLASS, H1H2_RESULT_CTOR),
+            (PITCH_TRACK_RESULT_CLASS, PITCH_TRACK_RESULT_CTOR),
+            (PITCH_STATISTICS_RESULT_CLASS, PITCH_STATISTICS_RESULT_CTOR),
+            (VIBRATO_RESULT_CLASS, VIBRATO_RESULT_CTOR),
+            (FFT_RESULT_CLASS, FFT_RESULT_CTOR),
+            (SPECTROGRAM_RESULT_CLASS, SPECTROGRAM_RESULT_CTOR),
+            (FEATURE_MATRIX_CLASS, FEATURE_MATRIX_CTOR),
+            (JITTER_RESULT_CLASS, JITTER_RESULT_CTOR),
+            (SHIMMER_RESULT_CLASS, SHIMMER_RESULT_CTOR),
+            (CPP_RESULT_CLASS, CPP_RESULT_CTOR),
+            (INTENSITY_RESULT_CLASS, INTENSITY_RESULT_CTOR),
+            (VOICE_ACTIVITY_RESULT_CLASS, VOICE_ACTIVITY_RESULT_CTOR),
+            (
+                VOCAL_TRACT_LENGTH_RESULT_CLASS,
+                VOCAL_TRACT_LENGTH_RESULT_CTOR,
+            ),
+            (STREAM_FRAMES_RESULT_CLASS, STREAM_FRAMES_RESULT_CTOR),
+            (TUNER_READING_CLASS, TUNER_READING_CTOR),
+            (
+                VOICE_RANGE_PROFILE_RESULT_CLASS,
+                VOICE_RANGE_PROFILE_RESULT_CTOR,
+            ),
+            (FORMANT_TRACK_RESULT_CLASS, FORMANT_TRACK_RESULT_CTOR),
+        ] {
+            let (package, name) = class.rsplit_once('/').unwrap();
+            assert!(
+                RUST_BRIDGE_KT.contains(&format!("package {}\n", package.replace('/', "."))),
+                "{class} is not in RustBridge.kt's package"
+            );
+            assert_eq!(kotlin_constructor_descriptor(name), descriptor, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_negative_ints_are_rejected_instead_of_wrapping() {
+        assert!(matches!(non_negative("min_cycles", 3), Ok(3)));
+        assert!(matches!(non_negative("min_cycles", 0), Ok(0)));
+        match non_negative("min_cycles", -1) {
+            Err(Failure::Error(LoqaErrorCode::InvalidArgument, message)) => {
+                assert_eq!(message, "min_cycles must be >= 0, got -1")
+            }
+            _ => panic!("negative min_cycles was accepted"),
+        }
+    }
+
+    #[test]
+    fn test_analyzer_handle_zero_is_rejected() {
+        assert!(matches!(
+            unsafe { analyzer_from_handle(0) },
+            Err(Failure::Error(LoqaErrorCode::NullPointer, _))
+        ));
+        assert!(matches!(
+            unsafe { stream_from_handle(0) },
+            Err(Failure::Error(LoqaErrorCode::NullPointer, _))
+        ));
+        assert!(matches!(
+            unsafe { tuner_from_handle(0) },
+            Err(Failure::Error(LoqaErrorCode::NullPointer, _))
+        ));
+        assert!(matches!(
+            unsafe { vrp_from_handle(0) },
+            Err(Failure::Error(LoqaErrorCode::NullPointer, _))
+        ));
+        assert!(matches!(
+            unsafe { formant_tracker_from_handle(0) },
+            Err(Failure::Error(LoqaErrorCode::NullPointer, _))
+        ));
+    }
+}
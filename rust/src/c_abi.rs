@@ -0,0 +1,803 @@
+// v0.4 `loqa_*` C ABI declared in ios/RustFFI/loqa_voice_dsp.h
+//
+// The `loqa_*` exports and their result structs are defined with
+// `#[no_mangle]` by loqa-voice-dsp's `ffi::ios` module, which upstream compiles
+// unconditionally. Defining the same symbols in this crate is a duplicate-symbol
+// link error, so the wrapper takes ownership of the surface by re-exporting it
+// here and pinning every header declaration (name, signature and struct layout)
+// to the Rust side with tests. The header, the upstream crate and the binary we
+// ship can therefore no longer drift apart without `cargo test` failing.
+//
+// Because these functions are upstream's own symbols, they cannot be wrapped in
+// `ffi_guard`; a panic inside them still aborts. They are kept only for ABI
+// compatibility: each has a guarded replacement in lib.rs that reports panics as
+// `LoqaErrorCode::Panic` (`loqa_detect_pitch_range`, `loqa_detect_pitch_ex`,
+// `loqa_extract_formants_ex`, `loqa_compute_fft_ex`, `loqa_analyze_spectrum_ex`,
+// `loqa_calculate_hnr_ex`, `loqa_calculate_h1h2_ex`, `loqa_voice_analyzer_create`
+// and the `loqa_voice_analyzer_*_ex` functions), which is what the Swift bridge calls.
+
+pub use loqa_voice_dsp::ffi::ios::{
+    loqa_analysis_config_default, loqa_analyze_spectrum, loqa_calculate_h1h2, loqa_calculate_hnr,
+    loqa_compute_fft, loqa_detect_pitch, loqa_extract_formants, loqa_free_fft_result,
+    AnalysisConfigFFI, FFTResultFFI, FormantResultFFI, H1H2ResultFFI, HNRResultFFI, PitchResultFFI,
+    SpectralFeaturesFFI,
+};
+
+// VoiceAnalyzer lifecycle. `loqa_voice_analyzer_create` (lib.rs) and upstream's
+// `loqa_voice_analyzer_new` both return an opaque pointer to a boxed
+// `loqa_voice_dsp::VoiceAnalyzer`, so pointers from either work with the upstream
+// functions below and the guarded `_ex` ones in lib.rs. `_new` also reports no
+// error and accepts unknown algorithms.
+pub use loqa_voice_dsp::ffi::ios::{
+    loqa_voice_analyzer_free, loqa_voice_analyzer_new, loqa_voice_analyzer_process_frame,
+    loqa_voice_analyzer_process_stream, loqa_voice_analyzer_reset,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use std::ffi::c_void;
+    use std::mem::{align_of, offset_of, size_of};
+    use std::os::raw::c_char;
+
+    const HEADER: &str = include_str!("../../ios/RustFFI/loqa_voice_dsp.h");
+
+    /// Names of all functions declared at the top level of the header
+    fn header_functions() -> Vec<String> {
+        HEADER
+            .lines()
+            .filter(|line| {
+                !line.starts_with(char::is_whitespace)
+                    && !line.starts_with("//")
+                    && !line.starts_with('#')
+                    && !line.starts_with("typedef")
+            })
+            .filter_map(|line| {
+                let start = line.find("loqa_")?;
+                let name: String = line[start..]
+                    .chars()
+                    .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                    .collect();
+                line[start + name.len()..].starts_with('(').then_some(name)
+            })
+            .collect()
+    }
+
+    /// Field list `(c_type, name)` of a `typedef struct { ... } name;` in the header
+    fn header_struct_fields(struct_name: &str) -> Vec<(String, String)> {
+        let terminator = format!("}} {struct_name};");
+        let end = HEADER
+            .find(&terminator)
+            .unwrap_or_else(|| panic!("{struct_name} not declared in header"));
+        let start = HEADER[..end].rfind("typedef struct {").unwrap() + "typedef struct {".len();
+
+        HEADER[start..end]
+            .lines()
+            .map(|line| {
+                line.split("//")
+                    .next()
+                    .unwrap()
+                    .trim()
+                    .trim_end_matches(';')
+            })
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let split = line.rfind(|c: char| c.is_whitespace() || c == '*').unwrap() + 1;
+                (line[..split].trim().to_string(), line[split..].to_string())
+            })
+            .collect()
+    }
+
+    /// Size (== alignment) of a header field type on the current target
+    fn c_type_size(c_type: &str) -> usize {
+        if c_type.ends_with('*') {
+            return size_of::<*const c_void>();
+        }
+        match c_type {
+            "bool" => 1,
+            "float" | "uint32_t" | "int32_t" | "LoqaErrorCode" => 4,
+            "size_t" => size_of::<usize>(),
+            other => panic!("unknown C type in header: {other}"),
+        }
+    }
+
+    /// Computes `(size, [(field, offset)])` for a header struct using C layout rules
+    fn header_layout(struct_name: &str) -> (usize, Vec<(String, usize)>) {
+        let mut offset: usize = 0;
+        let mut max_align = 1;
+        let mut fields = Vec::new();
+        for (c_type, declarator) in header_struct_fields(struct_name) {
+            // A fixed-size array such as `float cents[12]` aligns like its element
+            let (name, count) = match declarator.split_once('[') {
+                Some((name, count)) => (
+                    name.to_string(),
+                    count.trim_end_matches(']').parse().unwrap(),
+                ),
+                None => (declarator, 1),
+            };
+            let size = c_type_size(&c_type);
+            offset = offset.next_multiple_of(size);
+            fields.push((name, offset));
+            offset += size * count;
+            max_align = max_align.max(size);
+        }
+        (offset.next_multiple_of(max_align), fields)
+    }
+
+    /// Asserts a Rust `#[repr(C)]` struct matches the header declaration of the same name
+    macro_rules! assert_matches_header {
+        ($ty:ident { $($field:ident),* $(,)? }) => {{
+            let (header_size, header_fields) = header_layout(stringify!($ty));
+            let rust_fields: Vec<(String, usize)> =
+                vec![$((stringify!($field).to_string(), offset_of!($ty, $field))),*];
+            assert_eq!(
+                rust_fields, header_fields,
+                "{} field names/offsets differ from loqa_voice_dsp.h",
+                stringify!($ty)
+            );
+            assert_eq!(size_of::<$ty>(), header_size, "{} size differs", stringify!($ty));
+        }};
+    }
+
+    #[test]
+    fn test_header_struct_layouts_match_rust() {
+        assert_matches_header!(PitchResultFFI {
+            success,
+            frequency,
+            confidence,
+            is_voiced,
+            voiced_probability,
+        });
+        assert_matches_header!(PitchResultExFFI {
+            success,
+            frequency,
+            confidence,
+            is_voiced,
+            voiced_probability,
+            error_code,
+        });
+        assert_matches_header!(FormantResultFFI {
+            success,
+            f1,
+            f2,
+            f3,
+            confidence
+        });
+        assert_matches_header!(FormantsResultFFI {
+            f1,
+            f2,
+            f3,
+            bw1,
+            bw2,
+            bw3,
+            f4,
+            f5,
+            bw4,
+            bw5,
+            confidence,
+            error_code,
+        });
+        assert_matches_header!(FFTResultFFI {
+            success,
+            magnitudes_ptr,
+            frequencies_ptr,
+            length,
+            sample_rate,
+        });
+        assert_matches_header!(SpectralFeaturesFFI {
+            success,
+            centroid,
+            tilt,
+            rolloff_95
+        });
+        assert_matches_header!(FFTConfigFFI {
+            fft_size,
+            window,
+            window_param,
+            normalization,
+            include_phase,
+        });
+        assert_matches_header!(FFTResultExFFI {
+            success,
+            magnitudes_ptr,
+            phases_ptr,
+            frequencies_ptr,
+            length,
+            fft_size,
+            sample_rate,
+            error_code,
+        });
+        assert_matches_header!(ComplexSpectrumFFI {
+            success,
+            bins_ptr,
+            bin_count,
+            fft_size,
+            error_code,
+        });
+        assert_matches_header!(InverseFFTResultFFI {
+            success,
+            samples_ptr,
+            length,
+            error_code,
+        });
+        assert_matches_header!(SpectrogramConfigFFI {
+            frame_size,
+            hop_size,
+            window,
+            window_param,
+            scaling,
+            db_floor,
+        });
+        assert_matches_header!(SpectrogramFFI {
+            success,
+            values_ptr,
+            timestamps_ptr,
+            frame_count,
+            bin_count,
+            frame_size,
+            hop_size,
+            sample_rate,
+            error_code,
+        });
+        assert_matches_header!(MelConfigFFI {
+            frame_size,
+            hop_size,
+            window,
+            window_param,
+            n_mels,
+            fmin,
+            fmax,
+            mel_scale,
+            n_mfcc,
+            lifter,
+            delta_order,
+            delta_width,
+        });
+        assert_matches_header!(FeatureMatrixFFI {
+            success,
+            values_ptr,
+            timestamps_ptr,
+            frame_count,
+            feature_count,
+            error_code,
+        });
+        assert_matches_header!(SpectrumConfigFFI {
+            fft_size,
+            average_frames,
+            rolloff_low_threshold,
+            rolloff_high_threshold,
+        });
+        assert_matches_header!(SpectralFeaturesExFFI {
+            success,
+            centroid,
+            spread,
+            skewness,
+            kurtosis,
+            flatness,
+            entropy,
+            crest_factor,
+            rolloff_low,
+            rolloff_high,
+            tilt,
+            fft_size,
+            frame_count,
+            error_code,
+        });
+        assert_matches_header!(HNRResultFFI {
+            success,
+            hnr,
+            f0,
+            is_voiced
+        });
+        assert_matches_header!(H1H2ResultFFI {
+            success,
+            h1h2,
+            h1_amplitude_db,
+            h2_amplitude_db,
+            f0,
+        });
+        assert_matches_header!(HNRResultExFFI {
+            hnr,
+            f0,
+            is_voiced,
+            error_code
+        });
+        assert_matches_header!(H1H2ResultExFFI {
+            h1h2,
+            h1_amplitude_db,
+            h2_amplitude_db,
+            f0,
+            error_code,
+        });
+        assert_matches_header!(AnalysisConfigFFI {
+            sample_rate,
+            frame_size,
+            hop_size,
+            min_frequency,
+            max_frequency,
+            algorithm,
+            threshold,
+            min_confidence,
+            interpolate,
+        });
+        assert_matches_header!(PitchTrackFFI {
+            success,
+            pitch_track_ptr,
+            voiced_probs_ptr,
+            timestamps_ptr,
+            length,
+            error_code,
+        });
+        assert_matches_header!(PitchPostprocessConfigFFI {
+            correct_octave_jumps,
+            octave_window,
+            smoothing,
+            smoothing_window,
+            max_gap_frames,
+        });
+        assert_matches_header!(PitchStatsConfigFFI {
+            reference_hz,
+            weight_by_voicing,
+        });
+        assert_matches_header!(PitchStatsFFI {
+            success,
+            frame_count,
+            voiced_frame_count,
+            voiced_ratio,
+            mean_hz,
+            median_hz,
+            std_hz,
+            p5_hz,
+            p95_hz,
+            mean_semitones,
+            median_semitones,
+            std_semitones,
+            p5_semitones,
+            p95_semitones,
+            range_semitones,
+            error_code,
+        });
+        assert_matches_header!(VibratoConfigFFI {
+            frame_rate,
+            min_rate_hz,
+            max_rate_hz,
+            min_extent_cents,
+            max_extent_cents,
+            min_cycles,
+            window_seconds,
+            window_hop_frames,
+        });
+        assert_matches_header!(VibratoSegmentFFI {
+            start,
+            end,
+            rate_hz,
+            extent_cents,
+            rate_jitter,
+            extent_jitter,
+            onset_delay,
+        });
+        assert_matches_header!(VibratoFFI {
+            success,
+            vibrato_ratio,
+            rate_hz,
+            extent_cents,
+            rate_jitter,
+            extent_jitter,
+            onset_delay,
+            segments_ptr,
+            segment_count,
+            window_times_ptr,
+            window_rate_ptr,
+            window_extent_ptr,
+            window_rate_jitter_ptr,
+            window_extent_jitter_ptr,
+            window_count,
+            error_code,
+        });
+        assert_matches_header!(JitterResultFFI {
+            success,
+            local,
+            local_absolute,
+            rap,
+            ppq5,
+            ddp,
+            mean_period,
+            period_count,
+            error_code,
+        });
+        assert_matches_header!(ShimmerResultFFI {
+            success,
+            local,
+            local_db,
+            apq3,
+            apq5,
+            apq11,
+            dda,
+            mean_amplitude,
+            period_count,
+            error_code,
+        });
+        assert_matches_header!(CppConfigFFI {
+            frame_size,
+            hop_size,
+            min_frequency,
+            max_frequency,
+            time_smoothing,
+            quefrency_smoothing,
+        });
+        assert_matches_header!(CppResultFFI {
+            success,
+            cpp,
+            cpps,
+            f0,
+            frame_count,
+            error_code,
+        });
+        assert_matches_header!(IntensityConfigFFI {
+            frame_size,
+            hop_size,
+            calibration_offset,
+            floor_db,
+        });
+        assert_matches_header!(IntensityContourFFI {
+            success,
+            rms_ptr,
+            intensity_db_ptr,
+            peak_db_ptr,
+            timestamps_ptr,
+            frame_count,
+            mean_db,
+            min_db,
+            max_db,
+            dynamic_range_db,
+            error_code,
+        });
+        assert_matches_header!(VadConfigFFI {
+            energy_threshold_db,
+            zcr_threshold,
+            voicing_threshold,
+            hangover_time,
+        });
+        assert_matches_header!(SpeechSegmentFFI { start, end });
+        assert_matches_header!(VadResultFFI {
+            success,
+            timestamps_ptr,
+            energy_db_ptr,
+            zcr_ptr,
+            voiced_probs_ptr,
+            is_speech_ptr,
+            frame_count,
+            segments_ptr,
+            segment_count,
+            error_code,
+        });
+        assert_matches_header!(VtlConfigFFI {
+            lpc_order,
+            min_formant_confidence,
+            speed_of_sound,
+        });
+        assert_matches_header!(VtlResultFFI {
+            success,
+            vtl_cm,
+            spacing_vtl_cm,
+            formant_spacing_hz,
+            f1,
+            f2,
+            f3,
+            f4,
+            confidence,
+            frame_count,
+            error_code,
+        });
+        assert_matches_header!(FormantTrackerConfigFFI {
+            sample_rate,
+            frame_size,
+            hop_size,
+            lpc_order,
+            ceiling_hz,
+            continuity_weight,
+            min_confidence,
+            silence_db,
+        });
+        assert_matches_header!(FormantTrackFFI {
+            success,
+            frequencies_ptr,
+            bandwidths_ptr,
+            confidences_ptr,
+            reliable_ptr,
+            timestamps_ptr,
+            length,
+            error_code,
+        });
+        assert_matches_header!(StreamFramesFFI {
+            success,
+            frequencies_ptr,
+            confidences_ptr,
+            is_voiced_ptr,
+            voiced_probs_ptr,
+            timestamps_ptr,
+            frame_count,
+            error_code,
+        });
+        assert_matches_header!(TunerConfigFFI {
+            reference_hz,
+            temperament_cents,
+            stability_frames,
+        });
+        assert_matches_header!(TunerReadingFFI {
+            success,
+            frame_count,
+            timestamp,
+            is_voiced,
+            frequency,
+            confidence,
+            midi_note,
+            pitch_class,
+            octave,
+            cents,
+            target_frequency,
+            stability,
+            error_code,
+        });
+        assert_matches_header!(VrpConfigFFI {
+            reference_hz,
+            min_midi_note,
+            max_midi_note,
+            min_db,
+            max_db,
+            db_step,
+            min_cell_frames,
+        });
+        assert_matches_header!(VrpFFI {
+            success,
+            counts_ptr,
+            soft_db_ptr,
+            loud_db_ptr,
+            dynamic_range_db_ptr,
+            row_count,
+            column_count,
+            min_midi_note,
+            min_db,
+            db_step,
+            lowest_midi_note,
+            highest_midi_note,
+            lowest_hz,
+            highest_hz,
+            covered_cells,
+            area,
+            frame_count,
+            error_code,
+        });
+        assert_eq!(align_of::<FFTResultFFI>(), align_of::<usize>());
+    }
+
+    /// Binds every export to the C signature declared in the header (checked at
+    /// compile time) and returns the exported names
+    macro_rules! exported_functions {
+        ($($name:ident: $signature:ty),* $(,)?) => {{
+            $(let _: $signature = $name;)*
+            vec![$(stringify!($name).to_string()),*]
+        }};
+    }
+
+    #[test]
+    fn test_header_functions_match_rust_exports() {
+        let mut exported = exported_functions! {
+            loqa_last_error_code: extern "C" fn() -> LoqaErrorCode,
+            loqa_last_error_message: extern "C" fn() -> *const c_char,
+            loqa_clear_last_error: extern "C" fn(),
+            loqa_set_log_callback: extern "C" fn(LoqaLogCallback),
+            loqa_set_log_level: extern "C" fn(u32) -> LoqaErrorCode,
+            loqa_log_level: extern "C" fn() -> u32,
+            loqa_analysis_config_default: extern "C" fn() -> AnalysisConfigFFI,
+            loqa_analysis_config_validate: extern "C" fn(AnalysisConfigFFI) -> LoqaErrorCode,
+            loqa_detect_pitch:
+                unsafe extern "C" fn(*const f32, usize, u32, f32, f32) -> PitchResultFFI,
+            loqa_detect_pitch_ex:
+                unsafe extern "C" fn(*const f32, usize, u32, f32, f32, u32) -> PitchResultExFFI,
+            loqa_detect_pitch_range:
+                unsafe extern "C" fn(*const f32, usize, u32, f32, f32) -> PitchResultExFFI,
+            loqa_extract_formants:
+                unsafe extern "C" fn(*const f32, usize, u32, u32) -> FormantResultFFI,
+            loqa_extract_formants_ex:
+                unsafe extern "C" fn(*const f32, usize, u32, u32) -> FormantsResultFFI,
+            loqa_compute_fft: unsafe extern "C" fn(*const f32, usize, u32, u32) -> FFTResultFFI,
+            loqa_free_fft_result: unsafe extern "C" fn(*mut FFTResultFFI),
+            loqa_fft_config_default: extern "C" fn() -> FFTConfigFFI,
+            loqa_compute_fft_ex:
+                unsafe extern "C" fn(*const f32, usize, u32, FFTConfigFFI) -> FFTResultExFFI,
+            loqa_free_fft_result_ex: unsafe extern "C" fn(*mut FFTResultExFFI),
+            loqa_compute_fft_complex:
+                unsafe extern "C" fn(*const f32, usize, u32) -> ComplexSpectrumFFI,
+            loqa_compute_ifft: unsafe extern "C" fn(*const f32, usize) -> InverseFFTResultFFI,
+            loqa_free_complex_spectrum: unsafe extern "C" fn(*mut ComplexSpectrumFFI),
+            loqa_free_ifft_result: unsafe extern "C" fn(*mut InverseFFTResultFFI),
+            loqa_spectrogram_config_default: extern "C" fn() -> SpectrogramConfigFFI,
+            loqa_compute_spectrogram:
+                unsafe extern "C" fn(*const f32, usize, u32, SpectrogramConfigFFI) -> SpectrogramFFI,
+            loqa_free_spectrogram: unsafe extern "C" fn(*mut SpectrogramFFI),
+            loqa_mel_config_default: extern "C" fn() -> MelConfigFFI,
+            loqa_compute_log_mel_spectrogram:
+                unsafe extern "C" fn(*const f32, usize, u32, MelConfigFFI) -> FeatureMatrixFFI,
+            loqa_compute_mfcc:
+                unsafe extern "C" fn(*const f32, usize, u32, MelConfigFFI) -> FeatureMatrixFFI,
+            loqa_free_feature_matrix: unsafe extern "C" fn(*mut FeatureMatrixFFI),
+            loqa_analyze_spectrum:
+                unsafe extern "C" fn(*const FFTResultFFI) -> SpectralFeaturesFFI,
+            loqa_spectrum_config_default: extern "C" fn() -> SpectrumConfigFFI,
+            loqa_analyze_spectrum_ex: unsafe extern "C" fn(
+                *const f32,
+                usize,
+                u32,
+                SpectrumConfigFFI,
+            ) -> SpectralFeaturesExFFI,
+            loqa_calculate_hnr:
+                unsafe extern "C" fn(*const f32, usize, u32, f32, f32) -> HNRResultFFI,
+            loqa_calculate_h1h2:
+                unsafe extern "C" fn(*const f32, usize, u32, f32) -> H1H2ResultFFI,
+            loqa_calculate_hnr_ex:
+                unsafe extern "C" fn(*const f32, usize, u32, f32, f32) -> HNRResultExFFI,
+            loqa_calculate_h1h2_ex:
+                unsafe extern "C" fn(*const f32, usize, u32, f32) -> H1H2ResultExFFI,
+            loqa_voice_analyzer_create: extern "C" fn(AnalysisConfigFFI) -> *mut c_void,
+            loqa_voice_analyzer_new: unsafe extern "C" fn(AnalysisConfigFFI) -> *mut c_void,
+            loqa_voice_analyzer_process_frame:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> PitchResultFFI,
+            loqa_voice_analyzer_process_stream: unsafe extern "C" fn(
+                *mut c_void,
+                *const f32,
+                usize,
+                *mut PitchResultFFI,
+                usize,
+            ) -> usize,
+            loqa_voice_analyzer_reset: unsafe extern "C" fn(*mut c_void),
+            loqa_voice_analyzer_free: unsafe extern "C" fn(*mut c_void),
+            loqa_voice_analyzer_process_frame_ex:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> PitchResultExFFI,
+            loqa_voice_analyzer_process_stream_ex: unsafe extern "C" fn(
+                *mut c_void,
+                *const f32,
+                usize,
+                *mut PitchResultExFFI,
+                usize,
+            ) -> usize,
+            loqa_voice_analyzer_reset_ex: unsafe extern "C" fn(*mut c_void),
+            loqa_voice_analyzer_free_ex: unsafe extern "C" fn(*mut c_void),
+            loqa_voice_analyzer_process_buffer:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> PitchTrackFFI,
+            loqa_free_pitch_track: unsafe extern "C" fn(*mut PitchTrackFFI),
+            loqa_pitch_postprocess_config_default: extern "C" fn() -> PitchPostprocessConfigFFI,
+            loqa_postprocess_pitch_track: unsafe extern "C" fn(
+                *const f32,
+                *const f32,
+                *const f32,
+                usize,
+                PitchPostprocessConfigFFI,
+            ) -> PitchTrackFFI,
+            loqa_pitch_stats_config_default: extern "C" fn() -> PitchStatsConfigFFI,
+            loqa_pitch_track_statistics: unsafe extern "C" fn(
+                *const f32,
+                *const f32,
+                usize,
+                PitchStatsConfigFFI,
+            ) -> PitchStatsFFI,
+            loqa_vibrato_config_default: extern "C" fn() -> VibratoConfigFFI,
+            loqa_analyze_vibrato:
+                unsafe extern "C" fn(*const f32, usize, VibratoConfigFFI) -> VibratoFFI,
+            loqa_free_vibrato: unsafe extern "C" fn(*mut VibratoFFI),
+            loqa_calculate_jitter:
+                unsafe extern "C" fn(*const f32, usize, AnalysisConfigFFI) -> JitterResultFFI,
+            loqa_calculate_shimmer:
+                unsafe extern "C" fn(*const f32, usize, AnalysisConfigFFI) -> ShimmerResultFFI,
+            loqa_cpp_config_default: extern "C" fn() -> CppConfigFFI,
+            loqa_calculate_cpp:
+                unsafe extern "C" fn(*const f32, usize, u32, CppConfigFFI) -> CppResultFFI,
+            loqa_intensity_config_default: extern "C" fn() -> IntensityConfigFFI,
+            loqa_compute_intensity: unsafe extern "C" fn(
+                *const f32,
+                usize,
+                u32,
+                IntensityConfigFFI,
+            ) -> IntensityContourFFI,
+            loqa_free_intensity_contour: unsafe extern "C" fn(*mut IntensityContourFFI),
+            loqa_vad_config_default: extern "C" fn() -> VadConfigFFI,
+            loqa_detect_voice_activity: unsafe extern "C" fn(
+                *const f32,
+                usize,
+                AnalysisConfigFFI,
+                VadConfigFFI,
+            ) -> VadResultFFI,
+            loqa_free_voice_activity: unsafe extern "C" fn(*mut VadResultFFI),
+            loqa_vtl_config_default: extern "C" fn() -> VtlConfigFFI,
+            loqa_estimate_vocal_tract_length: unsafe extern "C" fn(
+                *const f32,
+                usize,
+                AnalysisConfigFFI,
+                VtlConfigFFI,
+            ) -> VtlResultFFI,
+            loqa_formant_tracker_config_default: extern "C" fn() -> FormantTrackerConfigFFI,
+            loqa_formant_tracker_new: extern "C" fn(FormantTrackerConfigFFI) -> *mut c_void,
+            loqa_formant_tracker_process_buffer:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> FormantTrackFFI,
+            loqa_formant_tracker_reset: unsafe extern "C" fn(*mut c_void),
+            loqa_formant_tracker_free: unsafe extern "C" fn(*mut c_void),
+            loqa_free_formant_track: unsafe extern "C" fn(*mut FormantTrackFFI),
+            loqa_stream_analyzer_new: extern "C" fn(AnalysisConfigFFI) -> *mut c_void,
+            loqa_stream_analyzer_push:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> StreamFramesFFI,
+            loqa_stream_analyzer_flush: unsafe extern "C" fn(*mut c_void) -> StreamFramesFFI,
+            loqa_stream_analyzer_reset: unsafe extern "C" fn(*mut c_void),
+            loqa_stream_analyzer_free: unsafe extern "C" fn(*mut c_void),
+            loqa_free_stream_frames: unsafe extern "C" fn(*mut StreamFramesFFI),
+            loqa_tuner_config_default: extern "C" fn() -> TunerConfigFFI,
+            loqa_tuner_new: extern "C" fn(AnalysisConfigFFI, TunerConfigFFI) -> *mut c_void,
+            loqa_tuner_push:
+                unsafe extern "C" fn(*mut c_void, *const f32, usize) -> TunerReadingFFI,
+            loqa_tuner_reset: unsafe extern "C" fn(*mut c_void),
+            loqa_tuner_free: unsafe extern "C" fn(*mut c_void),
+            loqa_vrp_config_default: extern "C" fn() -> VrpConfigFFI,
+            loqa_vrp_new: extern "C" fn(VrpConfigFFI) -> *mut c_void,
+            loqa_vrp_add_frames: unsafe extern "C" fn(
+                *mut c_void,
+                *const f32,
+                *const f32,
+                usize,
+            ) -> LoqaErrorCode,
+            loqa_vrp_merge: unsafe extern "C" fn(*mut c_void, *const c_void) -> LoqaErrorCode,
+            loqa_vrp_get: unsafe extern "C" fn(*const c_void) -> VrpFFI,
+            loqa_free_vrp: unsafe extern "C" fn(*mut VrpFFI),
+            loqa_vrp_reset: unsafe extern "C" fn(*mut c_void),
+            loqa_vrp_free: unsafe extern "C" fn(*mut c_void),
+        };
+        let mut declared = header_functions();
+
+        exported.sort();
+        declared.sort();
+        assert_eq!(
+            declared, exported,
+            "functions declared in loqa_voice_dsp.h differ from the Rust exports"
+        );
+    }
+
+    #[test]
+    fn test_loqa_detect_pitch_sine_wave() {
+        let sample_rate = 16000;
+        let samples: Vec<f32> = (0..4096)
+            .map(|i| (2.0 * std::f32::consts::PI * 220.0 * i as f32 / sample_rate as f32).sin())
+            .collect();
+
+        unsafe {
+            let result =
+                loqa_detect_pitch(samples.as_ptr(), samples.len(), sample_rate, 80.0, 400.0);
+            assert!(result.success);
+            assert!(result.is_voiced);
+            assert!(
+                (result.frequency - 220.0).abs() < 5.0,
+                "got {} Hz",
+                result.frequency
+            );
+
+            let result = loqa_detect_pitch(std::ptr::null(), 0, sample_rate, 80.0, 400.0);
+            assert!(!result.success);
+        }
+    }
+
+    #[test]
+    fn test_loqa_compute_fft_round_trip_free() {
+        let samples = vec![0.25_f32; 2048];
+
+        unsafe {
+            let mut fft = loqa_compute_fft(samples.as_ptr(), samples.len(), 44100, 1024);
+            assert!(fft.success);
+            assert_eq!(fft.length, 513);
+            assert_eq!(fft.sample_rate, 44100);
+
+            let spectrum = loqa_analyze_spectrum(&fft);
+            assert!(spectrum.success);
+            assert!(spectrum.centroid.is_finite());
+
+            loqa_free_fft_result(&mut fft);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Cepstral peak prominence (CPP) and smoothed CPP (CPPS)
+//
+// Each frame's power spectrum comes from `spectrogram::compute` with power
+// scaling. Its log spectrum, 10 * log10(max(power, 1e-12)) dB, is transformed
+// back with an inverse real FFT to give the real cepstrum c[n], and the power
+// cepstrum is 20 * log10(max(|c[n]|, 1e-12)) dB at quefrency n / sample_rate
+// seconds for n = 0..=frame_size / 2.
+//
+// Following Hillenbrand & Houde (1996) and Praat's "Get CPPS":
+//
+// * The peak is searched between quefrencies 1 / max_f0 and 1 / min_f0 and
+//   refined by parabolic interpolation
+// * A straight line is fitted by least squares to the power cepstrum from
+//   1 ms up to the last quefrency
+// * CPP is the height of the peak above that line, in dB
+//
+// For CPPS the dB cepstra are first averaged over `time_frames` neighbouring
+// frames and then over `quefrency_bins` neighbouring quefrencies (centred
+// moving averages, truncated at the edges). Both measures are the mean over
+// all frames, voiced or not, which is what makes CPPS usable on connected
+// speech without a voicing decision.
+
+use crate::spectrogram::Spectrogram;
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+
+/// Floor applied before taking logarithms of spectra and cepstra (-120 dB)
+const LOG_FLOOR: f64 = 1e-12;
+/// Lowest quefrency of the regression line, in seconds
+const REGRESSION_START: f64 = 0.001;
+
+/// Mean cepstral peak prominence of a buffer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Cpp {
+    /// Mean CPP of the unsmoothed cepstra in dB
+    pub cpp: f64,
+    /// Mean CPP of the time- and quefrency-smoothed cepstra in dB
+    pub cpps: f64,
+    /// Median of 1 / peak quefrency over the smoothed frames, in Hz
+    pub f0: f64,
+    pub frame_count: usize,
+}
+
+/// Peak of one power cepstrum relative to its regression line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CepstralPeak {
+    /// Height above the regression line in dB
+    pub prominence: f64,
+    /// Quefrency of the peak in seconds
+    pub quefrency: f64,
+}
+
+/// Power cepstrum in dB of every frame of `power`, quefrencies 0..=frame_size / 2
+pub(crate) fn power_cepstra(power: &Spectrogram) -> Vec<Vec<f64>> {
+    let frame_size = (power.bin_count - 1) * 2;
+    let ifft = RealFftPlanner::<f64>::new().plan_fft_inverse(frame_size);
+    let mut log_spectrum = ifft.make_input_vec();
+    let mut cepstrum = ifft.make_output_vec();
+    let mut scratch = ifft.make_scratch_vec();
+
+    power
+        .values
+        .chunks_exact(power.bin_count)
+        .map(|frame| {
+            for (slot, &p) in log_spectrum.iter_mut().zip(frame) {
+                *slot = Complex::new(10.0 * (p as f64).max(LOG_FLOOR).log10(), 0.0);
+            }
+            // Only fails on buffer length mismatches or non-zero imaginary parts
+            // at DC and Nyquist, neither of which can happen here
+            ifft.process_with_scratch(&mut log_spectrum, &mut cepstrum, &mut scratch)
+                .expect("realfft buffer sizes");
+
+            cepstrum[..=frame_size / 2]
+                .iter()
+                .map(|&c| 20.0 * (c.abs() / frame_size as f64).max(LOG_FLOOR).log10())
+                .collect()
+        })
+        .collect()
+}
+
+/// Centred moving average of `values` over `width` points (rounded up to odd)
+fn moving_average(values: &[f64], width: usize) -> Vec<f64> {
+    let half = width / 2;
+    (0..values.len())
+        .map(|i| {
+            let window = &values[i.saturating_sub(half)..(i + half + 1).min(values.len())];
+            window.iter().sum::<f64>() / window.len() as f64
+        })
+        .collect()
+}
+
+/// Averages `cepstra` over `time_frames` neighbouring frames, then each frame
+/// over `quefrency_bins` neighbouring quefrencies
+pub(crate) fn smooth(
+    cepstra: &[Vec<f64>],
+    time_frames: usize,
+    quefrency_bins: usize,
+) -> Vec<Vec<f64>> {
+    let half = time_frames / 2;
+    (0..cepstra.len())
+        .map(|t| {
+            let neighbours = &cepstra[t.saturating_sub(half)..(t + half + 1).min(cepstra.len())];
+            let averaged: Vec<f64> = (0..cepstra[t].len())
+                .map(|n| {
+                    neighbours.iter().map(|frame| frame[n]).sum::<f64>() / neighbours.len() as f64
+                })
+                .collect();
+            moving_average(&averaged, quefrency_bins)
+        })
+        .collect()
+}
+
+/// Cepstral peak between quefrencies `1 / max_f0` and `1 / min_f0`
+///
+/// Callers validate that `sample_rate / min_f0` lies below the last quefrency
+/// bin and that `min_f0 < max_f0`.
+pub(crate) fn peak_prominence(
+    cepstrum_db: &[f64],
+    sample_rate: u32,
+    min_f0: f64,
+    max_f0: f64,
+) -> CepstralPeak {
+    let fs = sample_rate as f64;
+    let last = cepstrum_db.len() - 1;
+
+    // Least-squares line over [1 ms, last quefrency]
+    let start = ((REGRESSION_START * fs).ceil() as usize).min(last - 1);
+    let points = &cepstrum_db[start..];
+    let count = points.len() as f64;
+    let mean_q = points
+        .iter()
+        .enumerate()
+        .map(|(i, _)| (start + i) as f64)
+        .sum::<f64>()
+        / count;
+    let mean_db = points.iter().sum::<f64>() / count;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (i, &db) in points.iter().enumerate() {
+        let dq = (start + i) as f64 - mean_q;
+        covariance += dq * (db - mean_db);
+        variance += dq * dq;
+    }
+    let slope = covariance / variance;
+
+    // Search range, kept at least one bin away from both ends for interpolation
+    let low = ((fs / max_f0).floor() as usize).max(1);
+    let high = ((fs / min_f0).ceil() as usize).min(last - 1).max(low);
+    let peak = (low..=high)
+        .max_by(|&a, &b| cepstrum_db[a].total_cmp(&cepstrum_db[b]))
+        .unwrap_or(low);
+
+    // Parabolic interpolation through the peak and its neighbours
+    let (left, centre, right) = (
+        cepstrum_db[peak - 1],
+        cepstrum_db[peak],
+        cepstrum_db[peak + 1],
+    );
+    let curvature = left - 2.0 * centre + right;
+    let (offset, height) = if curvature < 0.0 {
+        let offset = 0.5 * (left - right) / curvature;
+        (offset, centre - 0.25 * (left - right) * offset)
+    } else {
+        (0.0, centre)
+    };
+
+    let position = peak as f64 + offset;
+    let line = mean_db + slope * (position - mean_q);
+    CepstralPeak {
+        prominence: height - line,
+        quefrency: position / fs,
+    }
+}
+
+/// Mean CPP and CPPS over every frame of `power`
+///
+/// Callers validate the frequency range (see `peak_prominence`) and that
+/// `power` has at least one frame; smoothing widths of 0 or 1 disable smoothing.
+pub(crate) fn analyze(
+    power: &Spectrogram,
+    sample_rate: u32,
+    min_f0: f64,
+    max_f0: f64,
+    time_frames: usize,
+    quefrency_bins: usize,
+) -> Cpp {
+    let cepstra = power_cepstra(power);
+    let smoothed = smooth(&cepstra, time_frames, quefrency_bins);
+    let mean = |peaks: &[CepstralPeak]| {
+        peaks.iter().map(|p| p.prominence).sum::<f64>() / peaks.len() as f64
+    };
+
+    let raw: Vec<CepstralPeak> = cepstra
+        .iter()
+        .map(|c| peak_prominence(c, sample_rate, min_f0, max_f0))
+        .collect();
+    let smoothed: Vec<CepstralPeak> = smoothed
+        .iter()
+        .map(|c| peak_prominence(c, sample_rate, min_f0, max_f0))
+        .collect();
+
+    let mut frequencies: Vec<f64> = smoothed.iter().map(|p| 1.0 / p.quefrency).collect();
+    frequencies.sort_by(f64::total_cmp);
+
+    Cpp {
+        cpp: mean(&raw),
+        cpps: mean(&smoothed),
+        f0: frequencies[frequencies.len() / 2],
+        frame_count: cepstra.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::Window;
+    use crate::spectrogram::{compute, Scaling};
+    use std::f64::consts::PI;
+
+    /// Harmonic complex at `f0` with 1 / k amplitudes plus uniform noise of `noise` peak amplitude
+    fn voice(f0: f64, sample_rate: u32, harmonic_gain: f64, noise: f64, length: usize) -> Vec<f32> {
+        let mut state = 0x2545_f491_u32;
+        (0..length)
+            .map(|i| {
+                let t = i as f64 / sample_rate as f64;
+                let harmonics: f64 = (1..=20)
+                    .map(|k| (2.0 * PI * f0 * k as f64 * t).sin() / k as f64)
+                    .sum();
+                // xorshift32
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                let uniform = state as f64 / u32::MAX as f64 * 2.0 - 1.0;
+                (harmonic_gain * harmonics + noise * uniform) as f32
+            })
+            .collect()
+    }
+
+    fn cpp_of(samples: &[f32]) -> Cpp {
+        let power = compute(samples, 16000, 1024, 256, Window::Hann, Scaling::Power);
+        analyze(&power, 16000, 60.0, 330.0, 5, 9)
+    }
+
+    #[test]
+    fn test_moving_average_truncates_at_edges() {
+        let averaged = moving_average(&[0.0, 3.0, 6.0, 9.0], 3);
+        assert_eq!(averaged, vec![1.5, 3.0, 6.0, 7.5]);
+        assert_eq!(moving_average(&[1.0, 2.0], 0), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_modal_voice_has_higher_cpps_than_breathy_voice() {
+        let modal = cpp_of(&voice(150.0, 16000, 0.3, 0.01, 16000));
+        let breathy = cpp_of(&voice(150.0, 16000, 0.05, 0.3, 16000));
+
+        assert!((modal.f0 - 150.0).abs() < 5.0, "modal f0 {}", modal.f0);
+        assert!(
+            modal.cpps > breathy.cpps + 6.0,
+            "modal {modal:?}, breathy {breathy:?}"
+        );
+        assert!(
+            modal.cpp > breathy.cpp,
+            "modal {modal:?}, breathy {breathy:?}"
+        );
+        // Both voices still show a peak above the regression line
+        assert!(modal.cpps > 0.0 && breathy.cpps > 0.0);
+        assert_eq!(modal.frame_count, (16000 - 1024usize).div_ceil(256));
+    }
+
+    #[test]
+    fn test_noise_has_little_prominence() {
+        let noise = cpp_of(&voice(150.0, 16000, 0.0, 0.5, 16000));
+        let modal = cpp_of(&voice(150.0, 16000, 0.3, 0.01, 16000));
+        assert!(
+            noise.cpps < modal.cpps / 3.0,
+            "noise {noise:?}, modal {modal:?}"
+        );
+    }
+}
@@ -0,0 +1,348 @@
+// Structured error reporting for the FFI boundary
+//
+// Every export clears the calling thread's last error on entry and records an
+// error code plus a human-readable message when it fails. Result structs also
+// carry the code inline, so Swift and Kotlin can distinguish invalid input from
+// a legitimately silent or unvoiced result without guessing from zeroed fields.
+//
+// Export bodies run inside `ffi_guard`, which catches any panic raised in this
+// crate or in loqa-voice-dsp before it can unwind across `extern "C"` (which
+// aborts the host app) and reports it as `LoqaErrorCode::Panic` instead.
+
+use crate::logging::{self, LoqaLogLevel};
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_float, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Stable error codes reported by every FFI export
+///
+/// Values are part of the C ABI and must never be renumbered. New codes are
+/// only ever appended.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaErrorCode {
+    /// No error
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// Buffer length was zero or negative
+    InvalidLength = 2,
+    /// Sample rate outside the supported range
+    InvalidSampleRate = 3,
+    /// FFT size not a power of two or outside the supported range
+    InvalidFftSize = 4,
+    /// LPC order outside the supported range
+    InvalidLpcOrder = 5,
+    /// Buffer too short for the requested analysis
+    BufferTooShort = 6,
+    /// Minimum/maximum frequency bounds are invalid
+    InvalidFrequencyRange = 7,
+    /// Any other argument failed validation
+    InvalidArgument = 8,
+    /// The underlying DSP routine in loqa-voice-dsp returned an error
+    AnalysisFailed = 9,
+    /// A Rust panic was caught at the FFI boundary (a bug in this crate or loqa-voice-dsp)
+    Panic = 10,
+}
+
+struct LastError {
+    code: LoqaErrorCode,
+    message: CString,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+/// Records an error for the calling thread and logs it at `LoqaLogLevel::Error`
+pub(crate) fn set_last_error(code: LoqaErrorCode, message: impl Into<String>) {
+    let message = message.into();
+    logging::log(LoqaLogLevel::Error, &message);
+
+    // Interior NUL bytes cannot be represented in a C string; strip them
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|cell| *cell.borrow_mut() = Some(LastError { code, message }));
+}
+
+/// Clears the calling thread's last error (called on entry to every export)
+pub(crate) fn clear_last_error() {
+    LAST_ERROR.with(|cell| *cell.borrow_mut() = None);
+}
+
+/// Value an export returns when it fails with `code` before producing a result
+///
+/// Implemented by every export return type so `ffi_guard` can build the failure
+/// value after a panic; result structs are zeroed and carry `code` inline.
+pub(crate) trait ErrorResult {
+    fn error_result(code: LoqaErrorCode) -> Self;
+}
+
+impl ErrorResult for () {
+    fn error_result(_code: LoqaErrorCode) -> Self {}
+}
+
+impl ErrorResult for i32 {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        0
+    }
+}
+
+impl ErrorResult for u32 {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        0
+    }
+}
+
+impl ErrorResult for usize {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        0
+    }
+}
+
+impl ErrorResult for LoqaErrorCode {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        code
+    }
+}
+
+impl ErrorResult for *mut c_float {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl ErrorResult for *mut c_void {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl ErrorResult for *const c_char {
+    fn error_result(_code: LoqaErrorCode) -> Self {
+        std::ptr::null()
+    }
+}
+
+/// Runs `body`, turning a panic into a recorded `LoqaErrorCode::Panic` error
+///
+/// # Returns
+/// * `Err(message)` if `body` panicked; the message has already been recorded
+///
+/// # Note
+/// `body` is treated as unwind safe: after a panic the caller only ever sees an
+/// error result, and any object it was mutating (e.g. a `VoiceAnalyzer`) should
+/// be reset or freed by the host.
+pub(crate) fn catch_panic<T>(export: &str, body: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        #[cfg(test)]
+        test_hooks::maybe_panic(export);
+        body()
+    }))
+    .map_err(|payload| {
+        let message = format!("{export} panicked: {}", panic_message(payload.as_ref()));
+        set_last_error(LoqaErrorCode::Panic, message.clone());
+        message
+    })
+}
+
+/// Runs an export body, returning its `error_result(Panic)` if it panics
+pub(crate) fn ffi_guard<T: ErrorResult>(export: &str, body: impl FnOnce() -> T) -> T {
+    catch_panic(export, body).unwrap_or_else(|_| T::error_result(LoqaErrorCode::Panic))
+}
+
+/// Extracts the `panic!` message from a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic payload"
+    }
+}
+
+/// Test-only hook that makes the next guarded export on this thread panic, so the
+/// panic path of every export can be exercised without a real DSP bug
+#[cfg(test)]
+pub(crate) mod test_hooks {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static PANIC_IN: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    /// Arms a panic for the next call of `export` on the calling thread
+    pub(crate) fn panic_in(export: &str) {
+        PANIC_IN.with(|cell| *cell.borrow_mut() = Some(export.to_string()));
+    }
+
+    pub(super) fn maybe_panic(export: &str) {
+        let armed = PANIC_IN.with(|cell| {
+            let mut armed = cell.borrow_mut();
+            if armed.as_deref() == Some(export) {
+                armed.take()
+            } else {
+                None
+            }
+        });
+        if armed.is_some() {
+            panic!("injected test panic");
+        }
+    }
+}
+
+/// Returns the error code recorded by the most recent FFI call on this thread
+///
+/// # Returns
+/// * `LoqaErrorCode::Ok` if the last call succeeded or no call has been made
+#[no_mangle]
+pub extern "C" fn loqa_last_error_code() -> LoqaErrorCode {
+    ffi_guard("loqa_last_error_code", || {
+        LAST_ERROR.with(|cell| {
+            cell.borrow()
+                .as_ref()
+                .map_or(LoqaErrorCode::Ok, |error| error.code)
+        })
+    })
+}
+
+/// Returns the message recorded by the most recent failing FFI call on this thread
+///
+/// # Returns
+/// * Pointer to a NUL-terminated UTF-8 string, or null if there is no error
+///
+/// # Memory Management
+/// * The string is owned by Rust and must NOT be freed by the caller
+/// * It remains valid until the next FFI call made on the same thread;
+///   copy it (e.g. `String(cString:)` / `NewStringUTF`) before calling again
+#[no_mangle]
+pub extern "C" fn loqa_last_error_message() -> *const c_char {
+    ffi_guard("loqa_last_error_message", || {
+        LAST_ERROR.with(|cell| {
+            cell.borrow()
+                .as_ref()
+                .map_or(std::ptr::null(), |error| error.message.as_ptr())
+        })
+    })
+}
+
+/// Clears the last error recorded on this thread
+#[no_mangle]
+pub extern "C" fn loqa_clear_last_error() {
+    ffi_guard("loqa_clear_last_error", clear_last_error);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_last_error_defaults_to_ok() {
+        clear_last_error();
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+        assert!(loqa_last_error_message().is_null());
+    }
+
+    #[test]
+    fn test_set_and_clear_last_error() {
+        set_last_error(
+            LoqaErrorCode::InvalidSampleRate,
+            "sample_rate must be > 0, got 0",
+        );
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
+
+        let message = unsafe { CStr::from_ptr(loqa_last_error_message()) };
+        assert_eq!(message.to_str().unwrap(), "sample_rate must be > 0, got 0");
+
+        loqa_clear_last_error();
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+        assert!(loqa_last_error_message().is_null());
+    }
+
+    #[test]
+    fn test_last_error_strips_interior_nul() {
+        set_last_error(LoqaErrorCode::InvalidArgument, "bad\0value");
+        let message = unsafe { CStr::from_ptr(loqa_last_error_message()) };
+        assert_eq!(message.to_str().unwrap(), "badvalue");
+    }
+
+    #[test]
+    fn test_last_error_is_thread_local() {
+        set_last_error(LoqaErrorCode::NullPointer, "buffer pointer is null");
+
+        let other_thread_code = std::thread::spawn(|| loqa_last_error_code())
+            .join()
+            .unwrap();
+        assert_eq!(other_thread_code, LoqaErrorCode::Ok);
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
+    }
+
+    #[test]
+    fn test_error_code_values_are_stable() {
+        // These discriminants are part of the C ABI
+        assert_eq!(LoqaErrorCode::Ok as i32, 0);
+        assert_eq!(LoqaErrorCode::NullPointer as i32, 1);
+        assert_eq!(LoqaErrorCode::InvalidLength as i32, 2);
+        assert_eq!(LoqaErrorCode::InvalidSampleRate as i32, 3);
+        assert_eq!(LoqaErrorCode::InvalidFftSize as i32, 4);
+        assert_eq!(LoqaErrorCode::InvalidLpcOrder as i32, 5);
+        assert_eq!(LoqaErrorCode::BufferTooShort as i32, 6);
+        assert_eq!(LoqaErrorCode::InvalidFrequencyRange as i32, 7);
+        assert_eq!(LoqaErrorCode::InvalidArgument as i32, 8);
+        assert_eq!(LoqaErrorCode::AnalysisFailed as i32, 9);
+        assert_eq!(LoqaErrorCode::Panic as i32, 10);
+        assert_eq!(std::mem::size_of::<LoqaErrorCode>(), 4);
+    }
+
+    #[test]
+    fn test_catch_panic_records_message() {
+        let result: Result<(), String> =
+            catch_panic("some_export", || panic!("index out of bounds"));
+        assert_eq!(
+            result.unwrap_err(),
+            "some_export panicked: index out of bounds"
+        );
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Panic);
+
+        let value = 42;
+        let result = catch_panic("some_export", || panic!("bad value {value}"));
+        assert_eq!(
+            result,
+            Err::<(), _>("some_export panicked: bad value 42".into())
+        );
+    }
+
+    #[test]
+    fn test_ffi_guard_returns_error_result_on_panic() {
+        let code: LoqaErrorCode = ffi_guard("some_export", || panic!("boom"));
+        assert_eq!(code, LoqaErrorCode::Panic);
+
+        let ptr: *mut c_float = ffi_guard("some_export", || panic!("boom"));
+        assert!(ptr.is_null());
+
+        assert_eq!(
+            ffi_guard("some_export", || LoqaErrorCode::Ok),
+            LoqaErrorCode::Ok
+        );
+    }
+
+    #[test]
+    fn test_panic_hook_only_fires_for_armed_export() {
+        test_hooks::panic_in("loqa_clear_last_error");
+        assert_eq!(ffi_guard("other_export", || 7), 7);
+
+        loqa_clear_last_error();
+        let message = unsafe { CStr::from_ptr(loqa_last_error_message()) };
+        assert_eq!(
+            message.to_str().unwrap(),
+            "loqa_clear_last_error panicked: injected test panic"
+        );
+
+        // The hook is one-shot
+        loqa_clear_last_error();
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+    }
+}
@@ -0,0 +1,495 @@
+// Windowed real FFT with calibrated amplitude and PSD scaling
+//
+// loqa-voice-dsp's `compute_fft` applies no window and divides magnitudes by the
+// FFT size, so the height of a spectral peak depends on how much of the FFT the
+// signal fills. This module windows the samples itself, transforms them with
+// realfft and scales the one-sided spectrum with the window's own gain:
+//
+// * Amplitude: |X[k]| * 2 / sum(w), so an on-bin sine of amplitude A reads A
+//   whatever the window (coherent gain compensation)
+// * PSD: |X[k]|^2 * 2 / (fs * sum(w^2)) in units^2/Hz, so summing the bins and
+//   multiplying by the bin width gives the signal's mean square (noise power
+//   gain compensation)
+//
+// DC and Nyquist have no mirror image and are not doubled. Windows are periodic
+// (DFT-even), the usual choice for spectral analysis, and only span the samples
+// actually present; shorter buffers are zero-padded after windowing.
+//
+// The complex transforms are unwindowed and unnormalized in the forward
+// direction; `inverse` divides by the FFT size, so `inverse(&forward(x, n))`
+// returns x zero-padded (or truncated) to n samples.
+
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+
+/// Kaiser shape parameter used when the caller passes 0 (~ -90 dB sidelobes)
+pub(crate) const DEFAULT_KAISER_BETA: f32 = 8.6;
+/// Gaussian standard deviation (relative to half the window) used when the caller passes 0
+pub(crate) const DEFAULT_GAUSSIAN_SIGMA: f32 = 0.4;
+
+/// Window function applied before the transform
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Window {
+    /// No window (all ones)
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+    /// Kaiser-Bessel window; larger `beta` trades main-lobe width for lower sidelobes
+    Kaiser {
+        beta: f32,
+    },
+    /// Five-term flat-top window: wide main lobe, but peak amplitudes are accurate
+    /// to about 0.01 dB even between bins
+    FlatTop,
+    /// Gaussian window; `sigma` is the standard deviation relative to half the length
+    Gaussian {
+        sigma: f32,
+    },
+}
+
+impl Window {
+    /// Periodic window coefficients for `len` samples
+    pub(crate) fn coefficients(self, len: usize) -> Vec<f32> {
+        if len <= 1 {
+            return vec![1.0; len];
+        }
+
+        let n = len as f64;
+        let position = |i: usize| i as f64 / n;
+        let values: Vec<f64> = match self {
+            Window::Rectangular => vec![1.0; len],
+            Window::Hann => cosine_sum(&[0.5, 0.5], len),
+            Window::Hamming => cosine_sum(&[0.54, 0.46], len),
+            Window::Blackman => cosine_sum(&[0.42, 0.5, 0.08], len),
+            Window::FlatTop => cosine_sum(
+                &[
+                    0.215_578_95,
+                    0.416_631_58,
+                    0.277_263_158,
+                    0.083_578_947,
+                    0.006_947_368,
+                ],
+                len,
+            ),
+            Window::Kaiser { beta } => {
+                let beta = beta as f64;
+                let norm = bessel_i0(beta);
+                (0..len)
+                    .map(|i| {
+                        let x = 2.0 * position(i) - 1.0;
+                        bessel_i0(beta * (1.0 - x * x).max(0.0).sqrt()) / norm
+                    })
+                    .collect()
+            }
+            Window::Gaussian { sigma } => {
+                let width = sigma as f64 * 0.5;
+                (0..len)
+                    .map(|i| (-0.5 * ((position(i) - 0.5) / width).powi(2)).exp())
+                    .collect()
+            }
+        };
+        values.into_iter().map(|w| w as f32).collect()
+    }
+}
+
+/// Periodic generalized cosine window `sum_k (-1)^k a[k] cos(2 pi k i / len)`
+fn cosine_sum(coefficients: &[f64], len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|i| {
+            let phase = 2.0 * std::f64::consts::PI * i as f64 / len as f64;
+            coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, &a)| if k % 2 == 0 { a } else { -a } * (k as f64 * phase).cos())
+                .sum()
+        })
+        .collect()
+}
+
+/// Zeroth-order modified Bessel function of the first kind (power series)
+fn bessel_i0(x: f64) -> f64 {
+    let quarter_x_squared = x * x / 4.0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..500 {
+        term *= quarter_x_squared / (k * k) as f64;
+        sum += term;
+        if term < sum * 1e-16 {
+            break;
+        }
+    }
+    sum
+}
+
+/// How magnitudes are scaled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Normalization {
+    /// Single-sided amplitude spectrum in signal units
+    Amplitude,
+    /// Single-sided power spectral density in units^2/Hz
+    Psd,
+}
+
+/// One-sided spectrum of a windowed, zero-padded buffer
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Spectrum {
+    /// Scaled magnitudes (fft_size / 2 + 1 bins)
+    pub magnitudes: Vec<f32>,
+    /// Phase of each bin in radians, in (-pi, pi]
+    pub phases: Vec<f32>,
+    /// Bin-center frequencies in Hz (k * sample_rate / fft_size)
+    pub frequencies: Vec<f32>,
+}
+
+/// Windows the first `fft_size` samples, zero-pads to `fft_size` and transforms them
+///
+/// # Returns
+/// * The complex bins (`fft_size / 2 + 1`) plus `sum(w)` and `sum(w^2)` of the
+///   window over the samples used
+///
+/// Callers validate that `samples` is non-empty and `fft_size` is a power of two.
+pub(crate) fn windowed_fft(
+    samples: &[f32],
+    fft_size: usize,
+    window: Window,
+) -> (Vec<Complex<f32>>, f64, f64) {
+    let used = samples.len().min(fft_size);
+    let coefficients = window.coefficients(used);
+
+    let mut input = vec![0.0f32; fft_size];
+    for ((slot, sample), w) in input.iter_mut().zip(samples).zip(&coefficients) {
+        *slot = sample * w;
+    }
+    let gain: f64 = coefficients.iter().map(|&w| w as f64).sum();
+    let power_gain: f64 = coefficients.iter().map(|&w| (w as f64).powi(2)).sum();
+
+    let fft = RealFftPlanner::<f32>::new().plan_fft_forward(fft_size);
+    let mut bins = fft.make_output_vec();
+    // Only fails on buffer length mismatches, which the planner rules out
+    fft.process(&mut input, &mut bins)
+        .expect("realfft buffer sizes");
+
+    (bins, gain, power_gain)
+}
+
+/// Computes the scaled one-sided spectrum of `samples`
+///
+/// Callers validate that `samples` is non-empty, `sample_rate` is positive and
+/// `fft_size` is a power of two.
+pub(crate) fn spectrum(
+    samples: &[f32],
+    sample_rate: u32,
+    fft_size: usize,
+    window: Window,
+    normalization: Normalization,
+) -> Spectrum {
+    let (bins, gain, power_gain) = windowed_fft(samples, fft_size, window);
+    let nyquist = fft_size / 2;
+
+    let magnitudes = bins
+        .iter()
+        .enumerate()
+        .map(|(k, bin)| {
+            let one_sided = if k == 0 || k == nyquist { 1.0 } else { 2.0 };
+            let magnitude = bin.norm() as f64;
+            let value = match normalization {
+                // An all-zero window (e.g. a very narrow Gaussian on a tiny buffer)
+                // has no gain to compensate
+                _ if gain <= 0.0 => 0.0,
+                Normalization::Amplitude => one_sided * magnitude / gain,
+                Normalization::Psd => {
+                    one_sided * magnitude * magnitude / (sample_rate as f64 * power_gain)
+                }
+            };
+            value as f32
+        })
+        .collect();
+
+    let bin_width = sample_rate as f32 / fft_size as f32;
+    Spectrum {
+        magnitudes,
+        phases: bins.iter().map(|bin| bin.arg()).collect(),
+        frequencies: (0..bins.len()).map(|k| k as f32 * bin_width).collect(),
+    }
+}
+
+/// Unwindowed, unnormalized one-sided spectrum (`fft_size / 2 + 1` bins) of the
+/// first `fft_size` samples, zero-padded to `fft_size`
+///
+/// Callers validate that `fft_size` is a power of two.
+pub(crate) fn forward(samples: &[f32], fft_size: usize) -> Vec<Complex<f32>> {
+    windowed_fft(samples, fft_size, Window::Rectangular).0
+}
+
+/// Real signal of `2 * (bins.len() - 1)` samples whose one-sided spectrum is `bins`
+///
+/// The imaginary parts of the DC and Nyquist bins cannot be represented by a
+/// real signal and are ignored. Callers validate that `bins.len() - 1` is a
+/// power of two.
+pub(crate) fn inverse(bins: &[Complex<f32>]) -> Vec<f32> {
+    let fft_size = 2 * (bins.len() - 1);
+    let mut input = bins.to_vec();
+    input[0].im = 0.0;
+    input[fft_size / 2].im = 0.0;
+
+    let fft = RealFftPlanner::<f32>::new().plan_fft_inverse(fft_size);
+    let mut output = fft.make_output_vec();
+    // Only fails on buffer length mismatches or non-real DC/Nyquist, both ruled out above
+    fft.process(&mut input, &mut output)
+        .expect("realfft inverse input");
+
+    let scale = 1.0 / fft_size as f32;
+    output.iter_mut().for_each(|sample| *sample *= scale);
+    output
+}
+
+/// Flattens complex bins to `[re0, im0, re1, im1, ...]`
+pub(crate) fn interleave(bins: &[Complex<f32>]) -> Vec<f32> {
+    bins.iter().flat_map(|bin| [bin.re, bin.im]).collect()
+}
+
+/// Inverse of `interleave`; callers validate that `values` has an even length
+pub(crate) fn deinterleave(values: &[f32]) -> Vec<Complex<f32>> {
+    values
+        .chunks_exact(2)
+        .map(|pair| Complex::new(pair[0], pair[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    const ALL_WINDOWS: [Window; 7] = [
+        Window::Rectangular,
+        Window::Hann,
+        Window::Hamming,
+        Window::Blackman,
+        Window::Kaiser {
+            beta: DEFAULT_KAISER_BETA,
+        },
+        Window::FlatTop,
+        Window::Gaussian {
+            sigma: DEFAULT_GAUSSIAN_SIGMA,
+        },
+    ];
+
+    fn tone(frequency: f32, amplitude: f32, sample_rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| amplitude * (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    /// Largest magnitude and its bin
+    fn peak(spectrum: &Spectrum) -> (usize, f32) {
+        spectrum
+            .magnitudes
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_window_shapes() {
+        let hann = Window::Hann.coefficients(8);
+        assert!(hann[0].abs() < 1e-7);
+        assert!((hann[4] - 1.0).abs() < 1e-7);
+        assert!((Window::Hamming.coefficients(8)[0] - 0.08).abs() < 1e-6);
+        assert!(Window::Blackman.coefficients(8)[0].abs() < 1e-6);
+        assert!(Window::Kaiser { beta: 0.0 }
+            .coefficients(8)
+            .iter()
+            .all(|&w| (w - 1.0).abs() < 1e-6));
+        assert!((Window::Gaussian { sigma: 0.4 }.coefficients(8)[4] - 1.0).abs() < 1e-7);
+        assert!((Window::FlatTop.coefficients(8)[4] - 1.0).abs() < 1e-6);
+
+        // Periodic windows are symmetric around len / 2
+        for window in ALL_WINDOWS {
+            let w = window.coefficients(16);
+            for i in 1..16 {
+                assert!((w[i] - w[16 - i]).abs() < 1e-6, "{window:?} at {i}");
+            }
+        }
+        assert_eq!(Window::Hann.coefficients(1), vec![1.0]);
+    }
+
+    #[test]
+    fn test_bessel_i0() {
+        assert_eq!(bessel_i0(0.0), 1.0);
+        assert!((bessel_i0(1.0) - 1.266_065_877_752_008).abs() < 1e-12);
+        assert!((bessel_i0(5.0) - 27.239_871_823_604_44).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_amplitude_normalization_is_window_independent() {
+        // Bin 64 of 1024 at 16 kHz is exactly 1000 Hz
+        let samples = tone(1000.0, 0.5, 16000, 1024);
+        for window in ALL_WINDOWS {
+            let spectrum = spectrum(&samples, 16000, 1024, window, Normalization::Amplitude);
+            let (bin, amplitude) = peak(&spectrum);
+            assert_eq!(bin, 64, "{window:?}");
+            assert_eq!(spectrum.frequencies[bin], 1000.0);
+            assert!((amplitude - 0.5).abs() < 1e-3, "{window:?}: {amplitude}");
+        }
+    }
+
+    #[test]
+    fn test_flat_top_is_accurate_between_bins() {
+        // Halfway between bins 64 and 65: worst-case scalloping
+        let samples = tone(1007.8125, 1.0, 16000, 1024);
+        let flat_top = spectrum(
+            &samples,
+            16000,
+            1024,
+            Window::FlatTop,
+            Normalization::Amplitude,
+        );
+        let hann = spectrum(
+            &samples,
+            16000,
+            1024,
+            Window::Hann,
+            Normalization::Amplitude,
+        );
+
+        assert!(
+            (peak(&flat_top).1 - 1.0).abs() < 0.005,
+            "{}",
+            peak(&flat_top).1
+        );
+        // Hann loses ~1.4 dB here
+        assert!(peak(&hann).1 < 0.9, "{}", peak(&hann).1);
+    }
+
+    #[test]
+    fn test_psd_integrates_to_mean_square() {
+        let sample_rate = 16000;
+        let bin_width = sample_rate as f32 / 1024.0;
+
+        // Rectangular window: exactly Parseval's theorem
+        let mut state: u32 = 7;
+        let noise: Vec<f32> = (0..1024)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect();
+        let mean_square = noise.iter().map(|x| x * x).sum::<f32>() / noise.len() as f32;
+        let psd = spectrum(
+            &noise,
+            sample_rate,
+            1024,
+            Window::Rectangular,
+            Normalization::Psd,
+        );
+        let total = psd.magnitudes.iter().sum::<f32>() * bin_width;
+        assert!(
+            (total - mean_square).abs() / mean_square < 1e-4,
+            "{total} vs {mean_square}"
+        );
+
+        // Any window: a sine of amplitude A carries A^2 / 2
+        let samples = tone(1000.0, 2.0, sample_rate, 1024);
+        for window in ALL_WINDOWS {
+            let psd = spectrum(&samples, sample_rate, 1024, window, Normalization::Psd);
+            let total = psd.magnitudes.iter().sum::<f32>() * bin_width;
+            assert!((total - 2.0).abs() < 0.02, "{window:?}: {total}");
+        }
+    }
+
+    #[test]
+    fn test_phase_of_on_bin_tones() {
+        let cosine: Vec<f32> = (0..256)
+            .map(|i| (2.0 * PI * 10.0 * i as f32 / 256.0).cos())
+            .collect();
+        let sine: Vec<f32> = (0..256)
+            .map(|i| (2.0 * PI * 10.0 * i as f32 / 256.0).sin())
+            .collect();
+
+        let cosine = spectrum(
+            &cosine,
+            256,
+            256,
+            Window::Rectangular,
+            Normalization::Amplitude,
+        );
+        let sine = spectrum(
+            &sine,
+            256,
+            256,
+            Window::Rectangular,
+            Normalization::Amplitude,
+        );
+        assert!(cosine.phases[10].abs() < 1e-4, "{}", cosine.phases[10]);
+        assert!(
+            (sine.phases[10] + PI / 2.0).abs() < 1e-4,
+            "{}",
+            sine.phases[10]
+        );
+    }
+
+    #[test]
+    fn test_zero_padding_uses_gain_of_samples_present() {
+        let samples = vec![0.3; 100];
+        let spectrum = spectrum(
+            &samples,
+            8000,
+            256,
+            Window::Rectangular,
+            Normalization::Amplitude,
+        );
+        assert_eq!(spectrum.magnitudes.len(), 129);
+        assert_eq!(spectrum.frequencies.len(), 129);
+        assert_eq!(spectrum.frequencies[128], 4000.0);
+        assert!((spectrum.magnitudes[0] - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_inverse_reconstructs_forward_input() {
+        let mut state: u32 = 3;
+        let signal: Vec<f32> = (0..1024)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect();
+
+        let bins = forward(&signal, 1024);
+        assert_eq!(bins.len(), 513);
+        let reconstructed = inverse(&deinterleave(&interleave(&bins)));
+        assert_eq!(reconstructed.len(), 1024);
+        for (a, b) in signal.iter().zip(&reconstructed) {
+            assert!((a - b).abs() < 1e-5, "{a} vs {b}");
+        }
+
+        // Shorter input comes back zero-padded
+        let reconstructed = inverse(&forward(&signal[..300], 512));
+        assert!(reconstructed[..300]
+            .iter()
+            .zip(&signal)
+            .all(|(a, b)| (a - b).abs() < 1e-5));
+        assert!(reconstructed[300..].iter().all(|x| x.abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_inverse_of_single_bin_is_cosine() {
+        let mut bins = vec![Complex::new(0.0, 0.0); 129];
+        // A cosine of amplitude 1 at bin 5 has |X[5]| = N / 2
+        bins[5] = Complex::new(128.0, 0.0);
+        // Non-real DC is ignored
+        bins[0] = Complex::new(0.0, 3.0);
+
+        let signal = inverse(&bins);
+        for (i, sample) in signal.iter().enumerate() {
+            let expected = (2.0 * PI * 5.0 * i as f32 / 256.0).cos();
+            assert!(
+                (sample - expected).abs() < 1e-5,
+                "sample {i}: {sample} vs {expected}"
+            );
+        }
+    }
+}
@@ -0,0 +1,421 @@
+// Formant tracking over time with continuity constraints
+//
+// `formants::analyze` numbers the LPC resonances of one frame in frequency
+// order, so a resonance that appears or vanishes (a nasal pole, F1 and F2
+// merging in a back vowel, a weak F2 next to a strong F1) relabels every
+// formant above it and the contours of a word jump by a whole formant from
+// one frame to the next. `FormantTracker` keeps every resonance below the
+// formant ceiling as a candidate and labels all frames of a buffer together
+// by Viterbi decoding, as Praat's "Formant: Track..." does:
+//
+//   states      F1-F4 drawn in increasing order from the frame's candidates,
+//               each of them possibly missing
+//   local cost  Σ |F - Rn| / Rn + B / F over the formants present, with the
+//               references Rn = (2n - 1)·ceiling / 10 (550, 1650, 2750 and
+//               3850 Hz for a 5500 Hz ceiling), plus MISSING_COST for each
+//               missing formant
+//   transition  continuity_weight · Σ |F - F'| / (F + F') over the formants
+//               present in both frames, plus SWITCH_COST for each formant that
+//               appears or disappears
+//
+// A missing formant costs less than shifting the ones above it, so a dropout
+// leaves a gap instead of a jump. The last frame of each buffer seeds the
+// first frame of the next, so consecutive buffers of one recording join up;
+// `reset` forgets it.
+//
+// A frame is unreliable when it is quieter than `silence_db`, when one of
+// F1-F3 is missing or when their mean confidence (`formants::confidence`) is
+// below `min_confidence`. Unreliable frames keep their decoded formants, since
+// those still constrained the neighbouring frames.
+
+use crate::formants::{self, Formant};
+use crate::spectrogram::frame_starts;
+
+/// Formants tracked (F1-F4)
+pub(crate) const TRACKED_FORMANTS: usize = 4;
+
+/// Formants a frame needs, confidently, to be reliable (F1-F3)
+const RELIABLE_FORMANTS: usize = 3;
+
+/// Candidates kept per frame (the narrowest), bounding the state count at 330
+const MAX_CANDIDATES: usize = 7;
+
+/// Local cost of a missing formant; about that of a candidate 80% off its reference
+const MISSING_COST: f64 = 1.0;
+
+/// Transition cost of a formant appearing or disappearing
+const SWITCH_COST: f64 = 0.5;
+
+/// F1-F4 of one state as `(frequency, bandwidth)`, `None` where missing
+type Labels = [Option<(f64, f64)>; TRACKED_FORMANTS];
+
+/// Tracker parameters; callers validate them
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TrackerSettings {
+    pub sample_rate: u32,
+    pub frame_size: usize,
+    pub hop_size: usize,
+    pub lpc_order: usize,
+    /// Highest formant frequency considered in Hz
+    pub ceiling_hz: f64,
+    /// Weight of the transition cost against the local cost
+    pub continuity_weight: f64,
+    /// Mean F1-F3 confidence a frame needs to be reliable
+    pub min_confidence: f32,
+    /// Frames below this RMS level in dBFS are unreliable and not analysed
+    pub silence_db: f32,
+}
+
+/// Decoded formants of one frame
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct TrackedFrame {
+    /// Frame start in seconds from buffer start
+    pub timestamp: f32,
+    /// F1-F4, zeroed where missing
+    pub formants: [Formant; TRACKED_FORMANTS],
+    /// Mean confidence of F1-F3 (0.0 for a missing formant)
+    pub confidence: f32,
+    pub reliable: bool,
+}
+
+/// Every labelling of `candidates` (sorted by frequency) as F1-F4
+fn labellings(candidates: &[(f64, f64)]) -> Vec<Labels> {
+    fn extend(
+        labels: Labels,
+        slot: usize,
+        next: usize,
+        candidates: &[(f64, f64)],
+        out: &mut Vec<Labels>,
+    ) {
+        if slot == TRACKED_FORMANTS {
+            out.push(labels);
+            return;
+        }
+        // Leave the slot missing, or give it any candidate above the ones used
+        extend(labels, slot + 1, next, candidates, out);
+        for (index, &candidate) in candidates.iter().enumerate().skip(next) {
+            let mut labels = labels;
+            labels[slot] = Some(candidate);
+            extend(labels, slot + 1, index + 1, candidates, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    extend([None; TRACKED_FORMANTS], 0, 0, candidates, &mut out);
+    out
+}
+
+fn local_cost(labels: &Labels, references: &[f64; TRACKED_FORMANTS]) -> f64 {
+    labels
+        .iter()
+        .zip(references)
+        .map(|(label, &reference)| match label {
+            Some((frequency, bandwidth)) => {
+                (frequency - reference).abs() / reference + bandwidth / frequency
+            }
+            None => MISSING_COST,
+        })
+        .sum()
+}
+
+fn transition_cost(from: &Labels, to: &Labels, continuity_weight: f64) -> f64 {
+    from.iter()
+        .zip(to)
+        .map(|(from, to)| match (from, to) {
+            (Some((f, _)), Some((g, _))) => continuity_weight * (f - g).abs() / (f + g),
+            (None, None) => 0.0,
+            _ => SWITCH_COST,
+        })
+        .sum()
+}
+
+/// Finds the cheapest labelling of every frame's candidates
+///
+/// `previous`, the labels of the frame before the first, adds its transition
+/// cost to the first frame.
+fn decode(
+    frames: &[Vec<(f64, f64)>],
+    ceiling_hz: f64,
+    continuity_weight: f64,
+    previous: Option<&Labels>,
+) -> Vec<Labels> {
+    if frames.is_empty() {
+        return Vec::new();
+    }
+
+    let references = std::array::from_fn(|n| (2 * n + 1) as f64 * ceiling_hz / 10.0);
+    // Every frame has at least the all-missing state, so no minimum is empty
+    let states: Vec<Vec<Labels>> = frames.iter().map(|c| labellings(c)).collect();
+
+    let mut costs: Vec<f64> = states[0]
+        .iter()
+        .map(|state| {
+            let entry = previous.map_or(0.0, |p| transition_cost(p, state, continuity_weight));
+            local_cost(state, &references) + entry
+        })
+        .collect();
+    // back[t - 1][i]: best predecessor in frame t - 1 of state i of frame t
+    let mut back: Vec<Vec<usize>> = Vec::with_capacity(frames.len() - 1);
+
+    for pair in states.windows(2) {
+        let (next_costs, pointers): (Vec<f64>, Vec<usize>) = pair[1]
+            .iter()
+            .map(|state| {
+                let (best, cost) = pair[0]
+                    .iter()
+                    .zip(&costs)
+                    .map(|(from, &cost)| cost + transition_cost(from, state, continuity_weight))
+                    .enumerate()
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .expect("every frame has a state");
+                (cost + local_cost(state, &references), best)
+            })
+            .unzip();
+        costs = next_costs;
+        back.push(pointers);
+    }
+
+    let mut index = costs
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .map_or(0, |(index, _)| index);
+    let mut path = vec![states[states.len() - 1][index]];
+    for (t, pointers) in back.iter().enumerate().rev() {
+        index = pointers[index];
+        path.push(states[t][index]);
+    }
+    path.reverse();
+    path
+}
+
+/// RMS level of `frame` in dBFS (-inf for digital silence)
+fn level_db(frame: &[f32]) -> f32 {
+    let power = frame.iter().map(|&s| s * s).sum::<f32>() / frame.len() as f32;
+    10.0 * power.log10()
+}
+
+/// Framewise LPC formant analysis with Viterbi continuity over each buffer
+pub(crate) struct FormantTracker {
+    settings: TrackerSettings,
+    /// Labels of the last frame processed, which seed the next buffer
+    previous: Option<Labels>,
+}
+
+impl FormantTracker {
+    pub fn new(settings: TrackerSettings) -> Self {
+        FormantTracker {
+            settings,
+            previous: None,
+        }
+    }
+
+    pub fn frame_size(&self) -> usize {
+        self.settings.frame_size
+    }
+
+    /// Formant candidates of one frame: the narrowest resonances below the ceiling
+    fn candidates(&self, frame: &[f32]) -> Vec<(f64, f64)> {
+        let settings = &self.settings;
+        // Silent or degenerate frames have no candidates rather than failing the buffer
+        let mut candidates: Vec<(f64, f64)> =
+            formants::resonances(frame, settings.sample_rate, settings.lpc_order)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|&(frequency, _)| frequency < settings.ceiling_hz)
+                .collect();
+        if candidates.len() > MAX_CANDIDATES {
+            candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+            candidates.truncate(MAX_CANDIDATES);
+            candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        candidates
+    }
+
+    /// Tracks F1-F4 over the frames of `samples`, on the same frame grid as
+    /// `process_buffer`, continuing from the previous buffer
+    pub fn process_buffer(&mut self, samples: &[f32]) -> Vec<TrackedFrame> {
+        let TrackerSettings {
+            sample_rate,
+            frame_size,
+            hop_size,
+            ..
+        } = self.settings;
+        let starts: Vec<usize> = frame_starts(samples.len(), frame_size, hop_size).collect();
+        let loud: Vec<bool> = starts
+            .iter()
+            .map(|&start| level_db(&samples[start..start + frame_size]) >= self.settings.silence_db)
+            .collect();
+        let candidates: Vec<Vec<(f64, f64)>> = starts
+            .iter()
+            .zip(&loud)
+            .map(|(&start, &loud)| {
+                if loud {
+                    self.candidates(&samples[start..start + frame_size])
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+
+        let path = decode(
+            &candidates,
+            self.settings.ceiling_hz,
+            self.settings.continuity_weight,
+            self.previous.as_ref(),
+        );
+        if let Some(&last) = path.last() {
+            self.previous = Some(last);
+        }
+
+        starts
+            .iter()
+            .zip(loud)
+            .zip(path)
+            .map(|((&start, loud), labels)| {
+                let formants: [Formant; TRACKED_FORMANTS] =
+                    std::array::from_fn(|n| match labels[n] {
+                        Some((frequency, bandwidth)) => Formant {
+                            frequency: frequency as f32,
+                            bandwidth: bandwidth as f32,
+                            confidence: formants::confidence(n, frequency, bandwidth) as f32,
+                        },
+                        None => Formant::default(),
+                    });
+                let confidence = formants::mean_confidence(&formants);
+                let complete = labels[..RELIABLE_FORMANTS].iter().all(Option::is_some);
+                TrackedFrame {
+                    timestamp: start as f32 / sample_rate as f32,
+                    formants,
+                    confidence,
+                    reliable: loud && complete && confidence >= self.settings.min_confidence,
+                }
+            })
+            .collect()
+    }
+
+    /// Forgets the previous buffer, so the next one starts a new recording
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formants::tests::synthetic_vowel;
+
+    const STEADY: [(f64, f64); TRACKED_FORMANTS] = [
+        (500.0, 60.0),
+        (1500.0, 80.0),
+        (2500.0, 100.0),
+        (3500.0, 150.0),
+    ];
+
+    fn settings() -> TrackerSettings {
+        TrackerSettings {
+            sample_rate: 16000,
+            frame_size: 400,
+            hop_size: 160,
+            lpc_order: 18,
+            ceiling_hz: 5500.0,
+            continuity_weight: 1.0,
+            min_confidence: 0.3,
+            silence_db: -50.0,
+        }
+    }
+
+    fn frequencies(labels: &Labels) -> [f64; TRACKED_FORMANTS] {
+        labels.map(|label| label.map_or(0.0, |(frequency, _)| frequency))
+    }
+
+    #[test]
+    fn test_labellings_keep_formants_in_order_with_gaps() {
+        assert_eq!(labellings(&[]), vec![[None; TRACKED_FORMANTS]]);
+
+        // Σ C(2, k)·C(4, k): 1 empty, 2·4 with one formant, 1·6 with both
+        let states = labellings(&[(500.0, 60.0), (1500.0, 80.0)]);
+        assert_eq!(states.len(), 15);
+        for state in &states {
+            let present: Vec<f64> = state.iter().flatten().map(|c| c.0).collect();
+            assert!(present.windows(2).all(|w| w[0] < w[1]), "{state:?}");
+        }
+
+        let full = labellings(&STEADY);
+        assert!(full.contains(&STEADY.map(Some)));
+        assert_eq!(labellings(&[(1.0, 1.0); MAX_CANDIDATES]).len(), 330);
+    }
+
+    #[test]
+    fn test_decode_bridges_dropouts_and_ignores_spurious_resonances() {
+        let mut frames = vec![STEADY.to_vec(); 10];
+        // F2 drops out: numbering by order would make F3 the new F2
+        frames[4].remove(1);
+        // A broad extra resonance between F1 and F2
+        frames[7].insert(1, (1000.0, 250.0));
+
+        let path = decode(&frames, 5500.0, 1.0, None);
+        assert_eq!(path.len(), 10);
+        for (t, labels) in path.iter().enumerate() {
+            let expected = if t == 4 {
+                [500.0, 0.0, 2500.0, 3500.0]
+            } else {
+                [500.0, 1500.0, 2500.0, 3500.0]
+            };
+            assert_eq!(frequencies(labels), expected, "frame {t}");
+        }
+
+        // The previous buffer's last frame decides an ambiguous first frame:
+        // alone, 1900 Hz fits F2 better than F3
+        let ambiguous = vec![vec![(500.0, 60.0), (1900.0, 90.0), (3500.0, 150.0)]];
+        let alone = decode(&ambiguous, 5500.0, 1.0, None);
+        assert_eq!(frequencies(&alone[0]), [500.0, 1900.0, 0.0, 3500.0]);
+        let previous = [
+            (500.0, 60.0),
+            (1100.0, 80.0),
+            (2000.0, 100.0),
+            (3500.0, 150.0),
+        ];
+        let seeded = decode(&ambiguous, 5500.0, 4.0, Some(&previous.map(Some)));
+        assert_eq!(frequencies(&seeded[0]), [500.0, 0.0, 1900.0, 3500.0]);
+    }
+
+    #[test]
+    fn test_tracks_synthetic_vowel_and_marks_silence_unreliable() {
+        let mut samples = vec![0.0; 1600];
+        samples.extend(synthetic_vowel(&STEADY, 120.0, 16000, 8000));
+
+        let mut tracker = FormantTracker::new(settings());
+        let frames = tracker.process_buffer(&samples);
+        assert_eq!(frames.len(), (samples.len() - 400).div_ceil(160));
+        assert!((frames[1].timestamp - 0.01).abs() < 1e-6);
+
+        // Frames entirely in the leading 100 ms of silence
+        for frame in &frames[..8] {
+            assert!(!frame.reliable, "{frame:?}");
+            assert_eq!(frame.formants, [Formant::default(); TRACKED_FORMANTS]);
+        }
+        // The weak F4 drops out of some 25 ms frames; it leaves a gap, not a shift
+        for frame in &frames[11..] {
+            assert!(frame.reliable, "{frame:?}");
+            for (formant, &(expected, _)) in frame.formants.iter().zip(&STEADY) {
+                let error = (formant.frequency as f64 - expected).abs() / expected;
+                assert!(
+                    error < 0.08 || formant.frequency == 0.0 && expected > 3000.0,
+                    "{frame:?}"
+                );
+            }
+        }
+        assert!(
+            frames[11..]
+                .iter()
+                .filter(|f| f.formants[3].frequency > 0.0)
+                .count()
+                >= 15
+        );
+
+        assert!(tracker.previous.is_some());
+        tracker.reset();
+        assert!(tracker.previous.is_none());
+    }
+}
@@ -0,0 +1,444 @@
+// LPC formant analysis with bandwidths
+//
+// loqa-voice-dsp's `extract_formants` only reports F1-F3 frequencies: the LPC
+// poles it finds internally (and therefore their radii) are private. This module
+// repeats the same pipeline (downsampling above 20 kHz, pre-emphasis, Hamming
+// window, autocorrelation, Levinson-Durbin) in f64 and keeps every pole, so each
+// formant comes with its bandwidth and a confidence derived from it.
+//
+// For a pole z = r·e^(iθ) at sample rate fs:
+//   frequency = θ·fs / 2π
+//   bandwidth = -ln(r)·fs / π
+// (a resonance whose poles sit closer to the unit circle is sharper).
+
+use crate::logging::loqa_log;
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Number of formants reported (F1-F5)
+pub(crate) const MAX_FORMANTS: usize = 5;
+
+/// Rates above this are downsampled to `TARGET_SAMPLE_RATE`, as upstream does
+const DOWNSAMPLE_ABOVE: u32 = 20000;
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Pre-emphasis coefficient (matches loqa-voice-dsp)
+const PRE_EMPHASIS: f64 = 0.95;
+
+/// Poles below this frequency model glottal tilt, not the vocal tract
+const MIN_FORMANT_FREQUENCY: f64 = 90.0;
+
+/// Poles wider than this are treated as spectral shaping rather than formants
+pub(crate) const MAX_FORMANT_BANDWIDTH: f64 = 700.0;
+
+/// Typical frequency range of each formant for adult and child voices (Hz)
+const FORMANT_RANGES: [(f64, f64); MAX_FORMANTS] = [
+    (200.0, 1200.0),
+    (600.0, 3500.0),
+    (1200.0, 4500.0),
+    (2500.0, 5500.0),
+    (3000.0, 7000.0),
+];
+
+/// One formant estimated from a complex-conjugate LPC pole pair
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Formant {
+    /// Centre frequency in Hz (0.0 if the formant was not found)
+    pub frequency: f32,
+    /// -3 dB bandwidth in Hz
+    pub bandwidth: f32,
+    /// 1.0 for a sharp resonance in the expected range, falling to 0.0 as the
+    /// bandwidth approaches `MAX_FORMANT_BANDWIDTH`
+    pub confidence: f32,
+}
+
+/// Estimates F1-F5 with bandwidths from `samples`
+///
+/// Callers validate the sample rate and LPC order. Missing formants (fewer than
+/// five resonances found) are left zeroed.
+///
+/// # Returns
+/// * Formants sorted by frequency
+/// * `Err` if the signal is silent or no resonance was found
+pub(crate) fn analyze(
+    samples: &[f32],
+    sample_rate: u32,
+    lpc_order: usize,
+) -> Result<[Formant; MAX_FORMANTS], String> {
+    let candidates = resonances(samples, sample_rate, lpc_order)?;
+    if candidates.is_empty() {
+        return Err("no formants detected in signal".into());
+    }
+
+    let mut formants = [Formant::default(); MAX_FORMANTS];
+    for (index, (formant, (frequency, bandwidth))) in
+        formants.iter_mut().zip(candidates).enumerate()
+    {
+        *formant = Formant {
+            frequency: frequency as f32,
+            bandwidth: bandwidth as f32,
+            confidence: confidence(index, frequency, bandwidth) as f32,
+        };
+    }
+    Ok(formants)
+}
+
+/// Every resonance of the LPC model of `samples` as `(frequency, bandwidth)`
+/// in Hz, sorted by frequency
+///
+/// These are the formant candidates before labelling: `analyze` numbers them
+/// in order, `formant_track` picks among them for continuity.
+///
+/// # Returns
+/// * `Err` if the signal is silent or too short for `lpc_order`
+pub(crate) fn resonances(
+    samples: &[f32],
+    sample_rate: u32,
+    lpc_order: usize,
+) -> Result<Vec<(f64, f64)>, String> {
+    let (samples, sample_rate, lpc_order) = if sample_rate > DOWNSAMPLE_ABOVE {
+        let (samples, rate) = downsample(samples, sample_rate, TARGET_SAMPLE_RATE);
+        // An order chosen for the original rate would spend its extra poles on
+        // harmonics; cap it at the usual rate/1000 + 2 for the analysis rate
+        let max_order = (rate / 1000 + 2) as usize;
+        if lpc_order > max_order {
+            loqa_log!(
+                Info,
+                "LPC order {lpc_order} capped at {max_order} after downsampling {sample_rate} Hz to {rate} Hz"
+            );
+        }
+        (samples, rate, lpc_order.min(max_order))
+    } else {
+        (
+            samples.iter().map(|&s| s as f64).collect(),
+            sample_rate,
+            lpc_order,
+        )
+    };
+    if samples.len() <= lpc_order * 2 {
+        return Err(format!(
+            "{} samples after downsampling is too short for LPC order {lpc_order}",
+            samples.len()
+        ));
+    }
+
+    let windowed = hamming(&pre_emphasize(&samples));
+    let autocorr = autocorrelation(&windowed, lpc_order);
+    if autocorr[0] <= f64::EPSILON {
+        return Err("signal is silent".into());
+    }
+    let lpc = levinson_durbin(&autocorr, lpc_order);
+
+    let fs = sample_rate as f64;
+    let mut candidates: Vec<(f64, f64)> = polynomial_roots(&lpc)
+        .into_iter()
+        .filter(|root| root.im > 0.0 && root.norm() < 1.0 && root.is_finite())
+        .map(|root| {
+            let frequency = root.arg() * fs / (2.0 * PI);
+            let bandwidth = -root.norm().ln() * fs / PI;
+            (frequency, bandwidth)
+        })
+        .filter(|&(frequency, bandwidth)| {
+            frequency > MIN_FORMANT_FREQUENCY
+                && frequency < fs / 2.0 - MIN_FORMANT_FREQUENCY
+                && bandwidth < MAX_FORMANT_BANDWIDTH
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+    Ok(candidates)
+}
+
+/// Confidence of the `index`-th formant: sharpness, halved outside its usual range
+pub(crate) fn confidence(index: usize, frequency: f64, bandwidth: f64) -> f64 {
+    let sharpness = (1.0 - bandwidth / MAX_FORMANT_BANDWIDTH).clamp(0.0, 1.0);
+    let (low, high) = FORMANT_RANGES[index];
+    if (low..=high).contains(&frequency) {
+        sharpness
+    } else {
+        sharpness * 0.5
+    }
+}
+
+/// Mean confidence of F1-F3 (0.0 for a missing formant), the overall confidence
+/// reported next to individual formants
+pub(crate) fn mean_confidence(formants: &[Formant]) -> f32 {
+    formants[..3].iter().map(|f| f.confidence).sum::<f32>() / 3.0
+}
+
+/// Low-pass filters and decimates by the integer factor closest to
+/// `from_rate / to_rate`, returning the samples and their actual rate
+///
+/// Unlike loqa-voice-dsp's moving average, the windowed-sinc filter keeps images
+/// of the upper harmonics out of the LPC band, and the returned rate is exact
+/// (44.1 kHz decimates by 3 to 14.7 kHz, not 16 kHz).
+fn downsample(samples: &[f32], from_rate: u32, to_rate: u32) -> (Vec<f64>, u32) {
+    let factor = (from_rate as f64 / to_rate as f64).round().max(1.0) as usize;
+    if factor == 1 {
+        return (samples.iter().map(|&s| s as f64).collect(), from_rate);
+    }
+
+    // Blackman-windowed sinc with its cutoff at 90% of the new Nyquist frequency
+    const TAPS: usize = 63;
+    let cutoff = 0.45 / factor as f64;
+    let centre = (TAPS / 2) as f64;
+    let kernel: Vec<f64> = (0..TAPS)
+        .map(|i| {
+            let x = i as f64 - centre;
+            let sinc = if x == 0.0 {
+                2.0 * cutoff
+            } else {
+                (2.0 * PI * cutoff * x).sin() / (PI * x)
+            };
+            let phase = 2.0 * PI * i as f64 / (TAPS - 1) as f64;
+            sinc * (0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos())
+        })
+        .collect();
+    let gain: f64 = kernel.iter().sum();
+
+    let decimated = (0..samples.len())
+        .step_by(factor)
+        .map(|n| {
+            kernel
+                .iter()
+                .enumerate()
+                .filter_map(|(k, &h)| {
+                    let index = (n + k).checked_sub(TAPS / 2)?;
+                    samples.get(index).map(|&s| s as f64 * h)
+                })
+                .sum::<f64>()
+                / gain
+        })
+        .collect();
+    (decimated, from_rate / factor as u32)
+}
+
+fn pre_emphasize(samples: &[f64]) -> Vec<f64> {
+    std::iter::once(samples[0])
+        .chain(samples.windows(2).map(|w| w[1] - PRE_EMPHASIS * w[0]))
+        .collect()
+}
+
+fn hamming(samples: &[f64]) -> Vec<f64> {
+    let last = (samples.len() - 1) as f64;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &s)| s * (0.54 - 0.46 * (2.0 * PI * i as f64 / last).cos()))
+        .collect()
+}
+
+/// Autocorrelation at lags 0..=order
+fn autocorrelation(samples: &[f64], order: usize) -> Vec<f64> {
+    (0..=order)
+        .map(|lag| {
+            samples
+                .iter()
+                .zip(&samples[lag..])
+                .map(|(a, b)| a * b)
+                .sum()
+        })
+        .collect()
+}
+
+/// Solves for the prediction polynomial `A(z) = 1 + a1·z^-1 + ... + ap·z^-p`
+fn levinson_durbin(autocorr: &[f64], order: usize) -> Vec<f64> {
+    let mut a = vec![0.0; order + 1];
+    a[0] = 1.0;
+    let mut error = autocorr[0];
+
+    for i in 1..=order {
+        let acc: f64 = (0..i).map(|j| a[j] * autocorr[i - j]).sum();
+        let k = -acc / error;
+        let previous = a.clone();
+        for j in 1..i {
+            a[j] = previous[j] + k * previous[i - j];
+        }
+        a[i] = k;
+        error *= 1.0 - k * k;
+        if error <= 0.0 {
+            break;
+        }
+    }
+    a
+}
+
+/// Roots of `coeffs[0]·z^n + coeffs[1]·z^(n-1) + ... + coeffs[n]` (Durand-Kerner)
+fn polynomial_roots(coeffs: &[f64]) -> Vec<Complex64> {
+    let degree = coeffs.len() - 1;
+    let lead = coeffs[0];
+    let eval = |z: Complex64| {
+        coeffs[1..]
+            .iter()
+            .fold(Complex64::new(1.0, 0.0), |acc, &c| acc * z + c / lead)
+    };
+
+    // Standard non-symmetric starting points (powers of 0.4 + 0.9i)
+    let seed = Complex64::new(0.4, 0.9);
+    let mut roots: Vec<Complex64> = (0..degree).map(|i| seed.powu(i as u32)).collect();
+
+    for _ in 0..500 {
+        let mut max_step: f64 = 0.0;
+        for i in 0..degree {
+            let denominator = roots
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Complex64::new(1.0, 0.0), |acc, (_, &other)| {
+                    acc * (roots[i] - other)
+                });
+            if denominator.norm() < 1e-300 {
+                continue;
+            }
+            let step = eval(roots[i]) / denominator;
+            roots[i] -= step;
+            max_step = max_step.max(step.norm());
+        }
+        if max_step < 1e-12 {
+            break;
+        }
+    }
+    roots
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Synthesizes a vowel: a glottal pulse train (impulses shaped by a -12 dB/octave
+    /// source) through a cascade of two-pole resonators with the given `(F, B)` pairs
+    pub(crate) fn synthetic_vowel(
+        resonances: &[(f64, f64)],
+        f0: f64,
+        sample_rate: u32,
+        num_samples: usize,
+    ) -> Vec<f32> {
+        let fs = sample_rate as f64;
+        let period = fs / f0;
+        let mut signal: Vec<f64> = (0..num_samples)
+            .map(|n| if (n as f64 % period) < 1.0 { 1.0 } else { 0.0 })
+            .collect();
+
+        // Glottal source spectrum: two real poles near DC
+        for _ in 0..2 {
+            let mut previous = 0.0;
+            for sample in signal.iter_mut() {
+                *sample += 0.9 * previous;
+                previous = *sample;
+            }
+        }
+
+        for &(frequency, bandwidth) in resonances {
+            let r = (-PI * bandwidth / fs).exp();
+            let a1 = 2.0 * r * (2.0 * PI * frequency / fs).cos();
+            let a2 = -r * r;
+            let (mut y1, mut y2) = (0.0, 0.0);
+            for sample in signal.iter_mut() {
+                let y = *sample + a1 * y1 + a2 * y2;
+                y2 = y1;
+                y1 = y;
+                *sample = y;
+            }
+        }
+
+        let peak = signal.iter().fold(0.0_f64, |m, s| m.max(s.abs()));
+        signal.iter().map(|s| (s / peak) as f32).collect()
+    }
+
+    /// /a/ and /i/ with textbook formant frequencies and typical bandwidths
+    const VOWEL_A: [(f64, f64); MAX_FORMANTS] = [
+        (730.0, 60.0),
+        (1090.0, 90.0),
+        (2440.0, 120.0),
+        (3400.0, 180.0),
+        (4200.0, 250.0),
+    ];
+    const VOWEL_I: [(f64, f64); MAX_FORMANTS] = [
+        (270.0, 50.0),
+        (2290.0, 100.0),
+        (3010.0, 150.0),
+        (3700.0, 200.0),
+        (4500.0, 250.0),
+    ];
+
+    /// Asserts each estimated frequency is within 8% of `expected` and, unless
+    /// `bandwidth_tolerance` is 0, each bandwidth within that relative tolerance
+    fn assert_formants_close(
+        formants: &[Formant],
+        expected: &[(f64, f64)],
+        bandwidth_tolerance: f64,
+    ) {
+        for (i, (formant, &(frequency, bandwidth))) in formants.iter().zip(expected).enumerate() {
+            let f = formant.frequency as f64;
+            let b = formant.bandwidth as f64;
+            assert!(
+                (f - frequency).abs() < frequency * 0.08,
+                "F{}: expected {frequency} Hz, got {f} Hz",
+                i + 1
+            );
+            if bandwidth_tolerance > 0.0 {
+                assert!(
+                    (b - bandwidth).abs() < bandwidth * bandwidth_tolerance,
+                    "B{}: expected {bandwidth} Hz, got {b} Hz",
+                    i + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_recovers_frequencies_and_bandwidths_of_synthetic_vowels() {
+        for vowel in [VOWEL_A, VOWEL_I] {
+            let samples = synthetic_vowel(&vowel, 100.0, 16000, 4000);
+            let formants = analyze(&samples, 16000, 18).unwrap();
+            assert_formants_close(&formants, &vowel, 0.4);
+
+            for formant in &formants {
+                assert!(formant.confidence > 0.5, "{formant:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_wider_resonances_give_wider_bandwidths_and_lower_confidence() {
+        let wide: Vec<(f64, f64)> = VOWEL_A.iter().map(|&(f, b)| (f, b + 200.0)).collect();
+        let narrow = analyze(&synthetic_vowel(&VOWEL_A, 100.0, 16000, 4000), 16000, 18).unwrap();
+        let wide = analyze(&synthetic_vowel(&wide, 100.0, 16000, 4000), 16000, 18).unwrap();
+
+        for i in 0..3 {
+            assert!(
+                wide[i].bandwidth > narrow[i].bandwidth + 100.0,
+                "{:?} vs {:?}",
+                wide[i],
+                narrow[i]
+            );
+            assert!(wide[i].confidence < narrow[i].confidence);
+        }
+    }
+
+    #[test]
+    fn test_downsamples_high_sample_rates_at_the_exact_rate() {
+        let samples = synthetic_vowel(&VOWEL_A, 100.0, 44100, 11025);
+        let formants = analyze(&samples, 44100, 24).unwrap();
+        // Labelling the decimated signal as 16 kHz would put every formant ~9% high
+        assert_formants_close(&formants[..4], &VOWEL_A[..4], 0.0);
+
+        let (decimated, rate) = downsample(&samples, 44100, TARGET_SAMPLE_RATE);
+        assert_eq!(rate, 14700);
+        assert_eq!(decimated.len(), 3675);
+    }
+
+    #[test]
+    fn test_silence_is_an_error() {
+        assert!(analyze(&[0.0; 1024], 16000, 18).is_err());
+        assert!(analyze(&[0.1; 20], 16000, 18).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_roots() {
+        // z^2 - 1.2z + 0.61 = (z - (0.6 + 0.5i))(z - (0.6 - 0.5i))
+        let mut roots = polynomial_roots(&[1.0, -1.2, 0.61]);
+        roots.sort_by(|a, b| a.im.total_cmp(&b.im));
+        assert!((roots[0] - Complex64::new(0.6, -0.5)).norm() < 1e-9);
+        assert!((roots[1] - Complex64::new(0.6, 0.5)).norm() < 1e-9);
+    }
+}
@@ -0,0 +1,166 @@
+// Framewise RMS, peak and intensity contours in dB
+//
+// Frames use the same grid as `spectrogram::frame_starts` (and upstream
+// `process_buffer`), so with the same frame and hop sizes entry i lines up
+// with entry i of a pitch track. Each frame is unwindowed: its RMS is
+// sqrt(mean(x^2)) and its peak max(|x|), both relative to a full-scale
+// amplitude of 1.0.
+//
+// Levels are 20 * log10(value) dBFS, clamped below at `floor_db` and then
+// shifted by `calibration_offset` dB. With the offset measured against a
+// sound level meter (the meter's dB SPL minus the contour's dBFS for the same
+// steady sound) the intensity contour reads approximate dB SPL; with an
+// offset of 0 it stays in dBFS, where a full-scale sine is -3.01 dB.
+//
+// The summary mean is energy-averaged like Praat's "Get mean... energy": the
+// mean of the frames' squared RMS converted to dB, which weights loud frames
+// the way the ear does rather than averaging dB values. Minimum, maximum and
+// dynamic range (maximum - minimum) are taken over the floored contour.
+
+use crate::spectrogram::frame_starts;
+
+/// Framewise levels of a buffer plus summary statistics
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IntensityContour {
+    /// Linear RMS of each frame (full scale = 1.0), without calibration
+    pub rms: Vec<f32>,
+    /// RMS level of each frame in dB (dBFS + calibration offset)
+    pub intensity_db: Vec<f32>,
+    /// Absolute peak level of each frame in dB (dBFS + calibration offset)
+    pub peak_db: Vec<f32>,
+    /// Start of each frame in seconds from buffer start
+    pub timestamps: Vec<f32>,
+    /// Energy-averaged intensity in dB
+    pub mean_db: f32,
+    pub min_db: f32,
+    pub max_db: f32,
+}
+
+impl IntensityContour {
+    /// Difference between the loudest and quietest frame in dB
+    pub fn dynamic_range_db(&self) -> f32 {
+        self.max_db - self.min_db
+    }
+}
+
+/// Converts a linear amplitude to floored dBFS plus `offset`
+fn level_db(amplitude: f64, floor_db: f32, offset: f32) -> f32 {
+    let db = if amplitude > 0.0 {
+        20.0 * amplitude.log10()
+    } else {
+        f64::NEG_INFINITY
+    };
+    db.max(floor_db as f64) as f32 + offset
+}
+
+/// Computes the intensity contour of `samples`
+///
+/// Callers validate that `sample_rate` and `hop_size` are positive, that
+/// `floor_db` and `calibration_offset` are finite and that `samples` is longer
+/// than `frame_size`, so there is at least one frame.
+pub(crate) fn contour(
+    samples: &[f32],
+    sample_rate: u32,
+    frame_size: usize,
+    hop_size: usize,
+    calibration_offset: f32,
+    floor_db: f32,
+) -> IntensityContour {
+    let starts = frame_starts(samples.len(), frame_size, hop_size);
+    let frame_count = starts.len();
+    let mut rms = Vec::with_capacity(frame_count);
+    let mut intensity_db = Vec::with_capacity(frame_count);
+    let mut peak_db = Vec::with_capacity(frame_count);
+    let mut timestamps = Vec::with_capacity(frame_count);
+    let mut energy_sum = 0.0;
+
+    for start in starts {
+        let frame = &samples[start..start + frame_size];
+        let energy = frame.iter().map(|&x| x as f64 * x as f64).sum::<f64>() / frame_size as f64;
+        let peak = frame.iter().fold(0.0f32, |peak, &x| peak.max(x.abs()));
+
+        energy_sum += energy;
+        rms.push(energy.sqrt() as f32);
+        intensity_db.push(level_db(energy.sqrt(), floor_db, calibration_offset));
+        peak_db.push(level_db(peak as f64, floor_db, calibration_offset));
+        timestamps.push(start as f32 / sample_rate as f32);
+    }
+
+    let mean_energy = energy_sum / frame_count as f64;
+    let min_db = intensity_db.iter().copied().fold(f32::INFINITY, f32::min);
+    let max_db = intensity_db
+        .iter()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    IntensityContour {
+        rms,
+        intensity_db,
+        peak_db,
+        timestamps,
+        mean_db: level_db(mean_energy.sqrt(), floor_db, calibration_offset),
+        min_db,
+        max_db,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn sine(amplitude: f32, length: usize) -> Vec<f32> {
+        (0..length)
+            .map(|i| amplitude * (2.0 * PI * 250.0 * i as f32 / 16000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_full_scale_sine_levels() {
+        let result = contour(&sine(1.0, 16000), 16000, 1024, 256, 0.0, -120.0);
+
+        assert_eq!(result.rms.len(), (16000 - 1024usize).div_ceil(256));
+        assert_eq!(result.timestamps[1], 256.0 / 16000.0);
+        for ((&rms, &intensity), &peak) in result
+            .rms
+            .iter()
+            .zip(&result.intensity_db)
+            .zip(&result.peak_db)
+        {
+            assert!(
+                (rms - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3,
+                "rms {rms}"
+            );
+            assert!((intensity + 3.01).abs() < 0.01, "intensity {intensity}");
+            assert!(peak.abs() < 0.01, "peak {peak}");
+        }
+        assert!((result.mean_db + 3.01).abs() < 0.01);
+        assert!(result.dynamic_range_db() < 0.01);
+    }
+
+    #[test]
+    fn test_calibration_offset_and_floor() {
+        // Half a second of silence, then a sine 20 dB below full scale
+        let mut samples = vec![0.0; 8000];
+        samples.extend(sine(0.1, 8000));
+        let result = contour(&samples, 16000, 1024, 512, 94.0, -100.0);
+
+        assert_eq!(result.min_db, -6.0);
+        assert!(
+            (result.max_db - (94.0 - 23.01)).abs() < 0.01,
+            "max {}",
+            result.max_db
+        );
+        assert!((result.dynamic_range_db() - 76.99).abs() < 0.01);
+        assert_eq!(result.rms[0], 0.0);
+        assert_eq!(result.peak_db[0], -6.0);
+
+        // Energy averaging: about half the frames carry the sine, so the mean
+        // sits roughly 3 dB below it rather than halfway to the floor
+        let loud = result.max_db;
+        assert!(
+            result.mean_db < loud - 2.0 && result.mean_db > loud - 4.5,
+            "{result:?}"
+        );
+    }
+}
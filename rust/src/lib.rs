@@ -1,6 +1,35 @@
 // FFI wrapper for loqa-voice-dsp crate
 // Provides C-compatible exports for iOS (Swift FFI) and Android (Kotlin JNI)
 
+#[cfg(feature = "android")]
+mod android;
+mod c_abi;
+mod cepstrum;
+mod error;
+mod fft;
+mod formant_track;
+mod formants;
+mod intensity;
+mod logging;
+mod mel;
+mod perturbation;
+mod pitch_stats;
+mod pitch_track;
+mod spectral;
+mod spectrogram;
+mod stream;
+mod tuner;
+mod vad;
+mod vibrato;
+mod vrp;
+mod vtl;
+
+pub use c_abi::*;
+pub use error::{loqa_clear_last_error, loqa_last_error_code, loqa_last_error_message, LoqaErrorCode};
+pub use logging::{loqa_log_level, loqa_set_log_callback, loqa_set_log_level, LoqaLogCallback, LoqaLogLevel};
+
+use error::{clear_last_error, ffi_guard, set_last_error, ErrorResult};
+use logging::loqa_log;
 use std::os::raw::{c_float, c_int};
 use std::slice;
 
@@ -14,6 +43,7 @@ use std::slice;
 ///
 /// # Returns
 /// * Pointer to magnitude spectrum (length = fft_size / 2 + 1) or null on error
+/// * On error, `loqa_last_error_code` / `loqa_last_error_message` describe the failure
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -26,9 +56,9 @@ use std::slice;
 /// * Rust allocates → Returns raw pointer → Swift/Kotlin copies → Swift/Kotlin frees Rust memory
 ///
 /// # Note
-/// The loqa-voice-dsp crate applies its own windowing internally, so we don't expose
-/// window type as a parameter in this FFI interface. The TypeScript layer may accept
-/// window type as an option, but it will be handled at that layer for v0.1.0.
+/// Magnitudes come straight from loqa-voice-dsp: no window is applied and they are
+/// divided by `fft_size`. Use `loqa_compute_fft_ex` for a choice of window, phase,
+/// bin frequencies and amplitude/PSD scaling.
 #[no_mangle]
 pub unsafe extern "C" fn compute_fft_rust(
     buffer: *const c_float,
@@ -36,56 +66,75 @@ pub unsafe extern "C" fn compute_fft_rust(
     sample_rate: c_int,
     fft_size: c_int,
 ) -> *mut c_float {
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return std::ptr::null_mut();
-    }
-
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return std::ptr::null_mut();
-    }
-
-    if sample_rate <= 0 {
-        eprintln!("[Rust FFI] Error: sample_rate must be > 0, got {sample_rate}");
-        return std::ptr::null_mut();
-    }
+    ffi_guard("compute_fft_rust", || {
+        clear_last_error();
 
-    let fft_size_usize = fft_size as usize;
+        // Input validation
+        if buffer.is_null() {
+            set_last_error(LoqaErrorCode::NullPointer, "buffer pointer is null");
+            return std::ptr::null_mut();
+        }
 
-    // Validate FFT size is power of 2
-    if fft_size <= 0 || (fft_size_usize & (fft_size_usize - 1)) != 0 {
-        eprintln!("[Rust FFI] Error: fft_size must be power of 2, got {fft_size}");
-        return std::ptr::null_mut();
-    }
+        if length <= 0 {
+            set_last_error(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+            return std::ptr::null_mut();
+        }
 
-    // Validate FFT size range (256 to 8192)
-    if !(256..=8192).contains(&fft_size) {
-        eprintln!("[Rust FFI] Error: fft_size must be in range [256, 8192], got {fft_size}");
-        return std::ptr::null_mut();
-    }
+        if sample_rate <= 0 {
+            set_last_error(
+                LoqaErrorCode::InvalidSampleRate,
+                format!("sample_rate must be > 0, got {sample_rate}"),
+            );
+            return std::ptr::null_mut();
+        }
 
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
+        let fft_size_usize = fft_size as usize;
 
-    // Call loqa-voice-dsp FFT function
-    let fft_result =
-        loqa_voice_dsp::compute_fft(input_slice, sample_rate as u32, fft_size_usize);
+        // Validate FFT size is power of 2
+        if fft_size <= 0 || (fft_size_usize & (fft_size_usize - 1)) != 0 {
+            set_last_error(
+                LoqaErrorCode::InvalidFftSize,
+                format!("fft_size must be power of 2, got {fft_size}"),
+            );
+            return std::ptr::null_mut();
+        }
 
-    // Handle FFT computation result
-    let magnitudes = match fft_result {
-        Ok(result) => result.magnitudes,
-        Err(e) => {
-            eprintln!("[Rust FFI] FFT computation failed: {e:?}");
+        // Validate FFT size range (256 to 8192)
+        if !(256..=8192).contains(&fft_size) {
+            set_last_error(
+                LoqaErrorCode::InvalidFftSize,
+                format!("fft_size must be in range [256, 8192], got {fft_size}"),
+            );
             return std::ptr::null_mut();
         }
-    };
 
-    // Convert Vec<f32> to raw pointer for FFI
-    // This transfers ownership to the caller
-    // CRITICAL: Caller MUST call free_fft_result_rust to prevent memory leak
-    Box::into_raw(magnitudes.into_boxed_slice()) as *mut c_float
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
+
+        // Call loqa-voice-dsp FFT function
+        let fft_result =
+            loqa_voice_dsp::compute_fft(input_slice, sample_rate as u32, fft_size_usize);
+
+        // Handle FFT computation result
+        let magnitudes = match fft_result {
+            Ok(result) => result.magnitudes,
+            Err(e) => {
+                set_last_error(
+                    LoqaErrorCode::AnalysisFailed,
+                    format!("FFT computation failed: {e}"),
+                );
+                return std::ptr::null_mut();
+            }
+        };
+
+        // Convert Vec<f32> to raw pointer for FFI
+        // This transfers ownership to the caller
+        // CRITICAL: Caller MUST call free_fft_result_rust to prevent memory leak
+        Box::into_raw(magnitudes.into_boxed_slice()) as *mut c_float
+    })
 }
 
 /// Frees FFT result memory allocated by compute_fft_rust
@@ -107,70 +156,25 @@ pub unsafe extern "C" fn compute_fft_rust(
 /// * Null pointers are handled gracefully and do nothing
 #[no_mangle]
 pub unsafe extern "C" fn free_fft_result_rust(ptr: *mut c_float, length: c_int) {
-    if ptr.is_null() {
-        return;
-    }
-
-    if length <= 0 {
-        eprintln!(
-            "[Rust FFI] Error: free_fft_result_rust called with invalid length {length}"
-        );
-        return;
-    }
+    ffi_guard("free_fft_result_rust", || {
+        clear_last_error();
 
-    // Reconstruct the Box from the raw pointer with correct length
-    // This will automatically deallocate when Box goes out of scope
-    let _ = Box::from_raw(slice::from_raw_parts_mut(ptr, length as usize));
-}
+        if ptr.is_null() {
+            return;
+        }
 
-/// Android JNI native method for computeFFT
-///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeComputeFFT(buffer: FloatArray, fftSize: Int, windowType: Int): FloatArray`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeComputeFFT
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeFFT
-///
-/// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `fft_size` - FFT size (must be power of 2, range: 256-8192)
-/// * `window_type` - Window function type (0=none, 1=hanning, 2=hamming, 3=blackman) - IGNORED in v0.1.0
-///
-/// # Returns
-/// * JNI jfloatArray containing magnitude spectrum (length = fft_size / 2 + 1) or null on error
-///
-/// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
-///
-/// # Note
-/// For v0.1.0, window_type is accepted but ignored - loqa-voice-dsp applies windowing internally.
-/// Sample rate is hardcoded to 44100 Hz (matches default in LoqaExpoDspModule.kt).
-/// This function delegates to compute_fft_rust with appropriate parameters.
-///
-/// # JNI Implementation Note
-/// This requires proper JNI environment handling and FloatArray conversion,
-/// which should be implemented using jni-rs crate or manual JNI calls.
-/// For now, we provide the C ABI signature that matches Kotlin expectations.
-#[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeComputeFFT(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
-    buffer: *const c_float,
-    buffer_length: c_int,
-    fft_size: c_int,
-    _window_type: c_int,  // Accepted but ignored - windowing handled by loqa-voice-dsp
-) -> *mut c_float {
-    // Use default sample rate (44100 Hz) for Android in v0.1.0
-    // Matches the default in LoqaExpoDspModule.kt
-    const DEFAULT_SAMPLE_RATE: c_int = 44100;
+        if length <= 0 {
+            set_last_error(
+                LoqaErrorCode::InvalidLength,
+                format!("free_fft_result_rust called with invalid length {length}"),
+            );
+            return;
+        }
 
-    // Delegate to the main FFT implementation
-    // The JNI framework handles conversion of FloatArray to *const f32 and back
-    compute_fft_rust(buffer, buffer_length, DEFAULT_SAMPLE_RATE, fft_size)
+        // Reconstruct the Box from the raw pointer with correct length
+        // This will automatically deallocate when Box goes out of scope
+        let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, length as usize));
+    })
 }
 
 /// Result structure for pitch detection
@@ -182,12 +186,25 @@ pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_native
 /// * `frequency` - Detected pitch in Hz (0.0 if unvoiced or no pitch detected)
 /// * `confidence` - Confidence score from 0.0 (low) to 1.0 (high)
 /// * `is_voiced` - Whether the audio segment is voiced (true) or unvoiced (false)
+/// * `error_code` - `LoqaErrorCode::Ok` on success; distinguishes errors from silence
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct PitchResult {
     pub frequency: c_float,
     pub confidence: c_float,
     pub is_voiced: bool,
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for PitchResult {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        PitchResult {
+            frequency: 0.0,
+            confidence: 0.0,
+            is_voiced: false,
+            error_code: code,
+        }
+    }
 }
 
 /// Detects pitch using YIN algorithm from loqa-voice-dsp crate
@@ -199,7 +216,7 @@ pub struct PitchResult {
 ///
 /// # Returns
 /// * PitchResult struct with frequency, confidence, and is_voiced
-/// * Returns frequency=0.0, confidence=0.0, is_voiced=false on error
+/// * Returns frequency=0.0, confidence=0.0, is_voiced=false and a non-Ok `error_code` on error
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -222,113 +239,349 @@ pub unsafe extern "C" fn detect_pitch_rust(
     length: c_int,
     sample_rate: c_int,
 ) -> PitchResult {
-    // Default error result
-    let error_result = PitchResult {
-        frequency: 0.0,
-        confidence: 0.0,
-        is_voiced: false,
-    };
+    ffi_guard("detect_pitch_rust", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchResult::error_result(code)
+        };
+
+        // Input validation
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length <= 0 {
+            return fail(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+        }
+
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
+
+        // Define frequency range for YIN algorithm
+        // Default range suitable for human voice: 80 Hz (low male) to 400 Hz (high female)
+        // Callers needing other ranges or algorithms use loqa_detect_pitch_ex
+        const MIN_FREQUENCY: f32 = 80.0;
+        const MAX_FREQUENCY: f32 = 400.0;
+
+        // Handle pitch detection result
+        match detect_pitch_checked(input_slice, sample_rate, MIN_FREQUENCY, MAX_FREQUENCY) {
+            Ok(result) => {
+                // Extract frequency, confidence, and voiced classification
+                let frequency = if result.is_voiced { result.frequency } else { 0.0 }; // AC4: Return 0.0 if unvoiced
+                let confidence = result.confidence.clamp(0.0, 1.0); // Ensure 0.0-1.0 range (AC5)
+                let is_voiced = result.is_voiced;
+
+                PitchResult {
+                    frequency,
+                    confidence,
+                    is_voiced,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Validates pitch detection inputs and runs loqa-voice-dsp's detector
+///
+/// Shared by `detect_pitch_rust` and the Android JNI bindings, which both work on
+/// a safe slice once the pointer (or Java array) has been checked.
+///
+/// # Returns
+/// * The upstream `PitchResult` (including `voiced_probability`) on success
+/// * The error code and message to record on failure
+pub(crate) fn detect_pitch_checked(
+    samples: &[f32],
+    sample_rate: c_int,
+    min_frequency: c_float,
+    max_frequency: c_float,
+) -> Result<loqa_voice_dsp::PitchResult, (LoqaErrorCode, String)> {
+    validate_pitch_input(samples, sample_rate, min_frequency, max_frequency)?;
+
+    // Call loqa-voice-dsp pitch detection function (AC2)
+    loqa_voice_dsp::detect_pitch(samples, sample_rate as u32, min_frequency, max_frequency)
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("Pitch detection failed: {e}")))
+}
 
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return error_result;
+/// Validates pitch detection inputs and runs the selected algorithm
+///
+/// Upstream only exposes the individual algorithms through `VoiceAnalyzer`, so the
+/// whole buffer is analyzed as a single frame. That frame is subject to the
+/// analyzer's minimum frame size.
+///
+/// # Returns
+/// * The upstream `PitchResult` on success; `is_voiced` uses the analyzer's
+///   default `min_confidence` of 0.5
+/// * The error code and message to record on failure
+pub(crate) fn detect_pitch_with_algorithm_checked(
+    samples: &[f32],
+    sample_rate: c_int,
+    min_frequency: c_float,
+    max_frequency: c_float,
+    algorithm: LoqaPitchAlgorithm,
+) -> Result<loqa_voice_dsp::PitchResult, (LoqaErrorCode, String)> {
+    validate_pitch_input(samples, sample_rate, min_frequency, max_frequency)?;
+
+    if samples.len() < MIN_ANALYZER_FRAME_SIZE {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "{algorithm:?} pitch detection needs at least {MIN_ANALYZER_FRAME_SIZE} samples, got {}",
+                samples.len()
+            ),
+        ));
     }
 
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return error_result;
+    let config = loqa_voice_dsp::AnalysisConfig {
+        sample_rate: sample_rate as u32,
+        frame_size: samples.len(),
+        hop_size: samples.len(),
+        min_frequency,
+        max_frequency,
+        algorithm: algorithm.into(),
+        ..Default::default()
+    };
+
+    loqa_voice_dsp::VoiceAnalyzer::new(config)
+        .and_then(|mut analyzer| analyzer.process_frame(samples))
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("Pitch detection failed: {e}")))
+}
+
+/// Smallest frame `loqa_voice_dsp::VoiceAnalyzer` accepts
+const MIN_ANALYZER_FRAME_SIZE: usize = 512;
+
+/// Checks the buffer, sample rate and frequency range shared by all pitch detectors
+///
+/// Every upstream detector needs the longest period (`sample_rate / min_frequency`)
+/// to fit in less than half the buffer; otherwise it silently reports "unvoiced",
+/// which is indistinguishable from silence. Reject those buffers up front instead.
+fn validate_pitch_input(
+    samples: &[f32],
+    sample_rate: c_int,
+    min_frequency: c_float,
+    max_frequency: c_float,
+) -> Result<(), (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
     }
 
     // Validate sample rate range: 8000-48000 Hz (AC3)
     if !(8000..=48000).contains(&sample_rate) {
-        eprintln!(
-            "[Rust FFI] Error: sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"
-        );
-        return error_result;
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
     }
 
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
+    let nyquist = sample_rate as f32 / 2.0;
+    if !(min_frequency > 0.0 && min_frequency < max_frequency && max_frequency <= nyquist) {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "frequency range must satisfy 0 < min < max <= {nyquist} Hz, got [{min_frequency}, {max_frequency}]"
+            ),
+        ));
+    }
 
-    // Define frequency range for YIN algorithm
-    // Default range suitable for human voice: 80 Hz (low male) to 400 Hz (high female)
-    // Can be extended to 800 Hz for wider coverage
-    const MIN_FREQUENCY: f32 = 80.0;
-    const MAX_FREQUENCY: f32 = 400.0;
+    let max_period = (sample_rate as f32 / min_frequency) as usize;
+    let required = 2 * max_period + 2;
+    if samples.len() < required {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "min_frequency {min_frequency} Hz needs at least {required} samples at {sample_rate} Hz, got {}",
+                samples.len()
+            ),
+        ));
+    }
 
-    // Call loqa-voice-dsp YIN pitch detection function (AC2)
-    let pitch_result = loqa_voice_dsp::detect_pitch(
-        input_slice,
-        sample_rate as u32,
-        MIN_FREQUENCY,
-        MAX_FREQUENCY
-    );
+    Ok(())
+}
 
-    // Handle pitch detection result
-    match pitch_result {
-        Ok(result) => {
-            // Extract frequency, confidence, and voiced classification
-            let frequency = if result.is_voiced { result.frequency } else { 0.0 }; // AC4: Return 0.0 if unvoiced
-            let confidence = result.confidence.clamp(0.0, 1.0); // Ensure 0.0-1.0 range (AC5)
-            let is_voiced = result.is_voiced;
+/// Result structure for `loqa_detect_pitch_ex` and `loqa_detect_pitch_range`
+///
+/// `PitchResultFFI` plus the reason a detection failed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PitchResultExFFI {
+    /// True if detection ran (the frame may still be unvoiced)
+    pub success: bool,
+    /// Detected pitch in Hz (0.0 if no pitch was found)
+    pub frequency: c_float,
+    /// Confidence score [0.0, 1.0]
+    pub confidence: c_float,
+    /// Whether the frame is voiced
+    pub is_voiced: bool,
+    /// Probability that the frame is voiced [0.0, 1.0]
+    pub voiced_probability: c_float,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
 
-            PitchResult {
-                frequency,
-                confidence,
-                is_voiced,
-            }
+impl From<loqa_voice_dsp::PitchResult> for PitchResultExFFI {
+    /// Successful result; unvoiced frames report a frequency of 0.0
+    fn from(result: loqa_voice_dsp::PitchResult) -> Self {
+        PitchResultExFFI {
+            success: true,
+            frequency: if result.is_voiced { result.frequency } else { 0.0 },
+            confidence: result.confidence.clamp(0.0, 1.0),
+            is_voiced: result.is_voiced,
+            voiced_probability: result.voiced_probability.clamp(0.0, 1.0),
+            error_code: LoqaErrorCode::Ok,
         }
-        Err(e) => {
-            eprintln!("[Rust FFI] Pitch detection failed: {e:?}");
-            error_result
+    }
+}
+
+impl ErrorResult for PitchResultExFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        PitchResultExFFI {
+            success: false,
+            frequency: 0.0,
+            confidence: 0.0,
+            is_voiced: false,
+            voiced_probability: 0.0,
+            error_code: code,
         }
     }
 }
 
-/// Android JNI native method for detectPitch
+/// Detects pitch with a caller-chosen frequency range and algorithm
 ///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeDetectPitch(buffer: FloatArray, sampleRate: Int): PitchResult`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeDetectPitch
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch
+/// `loqa_detect_pitch` always runs YIN with an autocorrelation fallback; this
+/// export also lets callers pick pYIN, YIN or autocorrelation explicitly, e.g.
+/// to trade accuracy on breathy voices for speed.
 ///
 /// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `buffer_length` - Number of samples in buffer
-/// * `sample_rate` - Sample rate in Hz (8000-48000)
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer (at least 512)
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
+/// * `min_frequency` - Lowest pitch to search for in Hz (e.g. 50 for vocal fry)
+/// * `max_frequency` - Highest pitch to search for in Hz, up to Nyquist
+///   (e.g. 1200 for sopranos and children)
+/// * `algorithm` - A `LoqaPitchAlgorithm` value
 ///
 /// # Returns
-/// * PitchResult struct with frequency, confidence, and is_voiced
+/// * PitchResultExFFI with success=true if detection ran
+/// * On error, `error_code` is set; `BufferTooShort` means the buffer cannot hold
+///   two periods of `min_frequency`
 ///
 /// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
+#[no_mangle]
+pub unsafe extern "C" fn loqa_detect_pitch_ex(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    min_frequency: c_float,
+    max_frequency: c_float,
+    algorithm: u32,
+) -> PitchResultExFFI {
+    ffi_guard("loqa_detect_pitch_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchResultExFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        let algorithm = match pitch_algorithm_from_raw(algorithm) {
+            Ok(algorithm) => algorithm,
+            Err((code, message)) => return fail(code, message),
+        };
+
+        // Out-of-range rates are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match detect_pitch_with_algorithm_checked(
+            samples,
+            sample_rate,
+            min_frequency,
+            max_frequency,
+            algorithm,
+        ) {
+            Ok(result) => PitchResultExFFI::from(result),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Detects pitch with the default detector (YIN with an autocorrelation
+/// fallback) and a caller-chosen frequency range
 ///
-/// # Note
-/// Unlike FFT, PitchResult is returned by value (small struct), not by pointer.
-/// JNI will automatically marshal this back to Kotlin data class.
+/// Gives the same estimates as `loqa_detect_pitch`, but reports panics and
+/// rejected input through `error_code` instead of aborting or returning a bare
+/// `success = false`. Unlike `loqa_detect_pitch_ex` it has no 512-sample minimum.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
+/// * `min_frequency` - Lowest pitch to search for in Hz
+/// * `max_frequency` - Highest pitch to search for in Hz, up to Nyquist
+///
+/// # Returns
+/// * PitchResultExFFI with success=true if detection ran
+/// * On error, `error_code` is set; `BufferTooShort` means the buffer cannot hold
+///   two periods of `min_frequency`
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
 #[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeDetectPitch(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
+pub unsafe extern "C" fn loqa_detect_pitch_range(
     buffer: *const c_float,
-    buffer_length: c_int,
-    sample_rate: c_int,
-) -> PitchResult {
-    // Delegate to the main pitch detection implementation
-    // The JNI framework handles conversion of FloatArray to *const f32
-    detect_pitch_rust(buffer, buffer_length, sample_rate)
+    length: usize,
+    sample_rate: u32,
+    min_frequency: c_float,
+    max_frequency: c_float,
+) -> PitchResultExFFI {
+    ffi_guard("loqa_detect_pitch_range", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchResultExFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        // Out-of-range rates are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match detect_pitch_checked(samples, sample_rate, min_frequency, max_frequency) {
+            Ok(result) => PitchResultExFFI::from(result),
+            Err((code, message)) => fail(code, message),
+        }
+    })
 }
 
 /// Result structure for formant extraction
 ///
-/// Returns the first three formant frequencies (F1, F2, F3) and their bandwidths.
-/// This struct is C-compatible for FFI/JNI interop.
+/// Returns the first five formant frequencies (F1-F5), their bandwidths and a
+/// confidence per formant. This struct is C-compatible for FFI/JNI interop.
+/// Formants that were not found are 0.0 with zero confidence.
 ///
 /// # Fields
 /// * `f1` - First formant frequency in Hz (typically 200-1000 Hz for human voice)
@@ -337,6 +590,12 @@ pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_native
 /// * `bw1` - Bandwidth of first formant in Hz
 /// * `bw2` - Bandwidth of second formant in Hz
 /// * `bw3` - Bandwidth of third formant in Hz
+/// * `f4` - Fourth formant frequency in Hz (typically 3000-4500 Hz)
+/// * `f5` - Fifth formant frequency in Hz (typically 4000-5500 Hz)
+/// * `bw4` - Bandwidth of fourth formant in Hz
+/// * `bw5` - Bandwidth of fifth formant in Hz
+/// * `confidence` - Confidence of F1..F5 from 0.0 (broad or implausible) to 1.0
+/// * `error_code` - `LoqaErrorCode::Ok` on success
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct FormantsResult {
@@ -346,9 +605,44 @@ pub struct FormantsResult {
     pub bw1: c_float,
     pub bw2: c_float,
     pub bw3: c_float,
+    pub f4: c_float,
+    pub f5: c_float,
+    pub bw4: c_float,
+    pub bw5: c_float,
+    pub confidence: [c_float; formants::MAX_FORMANTS],
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for FormantsResult {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        FormantsResult::from_formants(&[formants::Formant::default(); formants::MAX_FORMANTS], code)
+    }
+}
+
+impl FormantsResult {
+    fn from_formants(
+        formants: &[formants::Formant; formants::MAX_FORMANTS],
+        error_code: LoqaErrorCode,
+    ) -> Self {
+        let [f1, f2, f3, f4, f5] = *formants;
+        FormantsResult {
+            f1: f1.frequency,
+            f2: f2.frequency,
+            f3: f3.frequency,
+            bw1: f1.bandwidth,
+            bw2: f2.bandwidth,
+            bw3: f3.bandwidth,
+            f4: f4.frequency,
+            f5: f5.frequency,
+            bw4: f4.bandwidth,
+            bw5: f5.bandwidth,
+            confidence: formants.map(|formant| formant.confidence),
+            error_code,
+        }
+    }
 }
 
-/// Extracts formants (F1, F2, F3) using LPC analysis from loqa-voice-dsp crate
+/// Extracts formants (F1-F5) and their bandwidths using LPC analysis
 ///
 /// # Arguments
 /// * `buffer` - Pointer to input audio samples (Float32 array)
@@ -357,8 +651,8 @@ pub struct FormantsResult {
 /// * `lpc_order` - LPC order (if 0, uses default: sample_rate / 1000 + 2)
 ///
 /// # Returns
-/// * FormantsResult struct with f1, f2, f3 frequencies and bandwidths
-/// * Returns zeros on error
+/// * FormantsResult struct with F1-F5 frequencies, bandwidths and confidences
+/// * Returns zeros and a non-Ok `error_code` on error
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -375,7 +669,10 @@ pub struct FormantsResult {
 /// * LPC (Linear Predictive Coding) finds resonant frequencies of vocal tract
 /// * Best results with voiced speech segments (vowels)
 /// * F1, F2, F3 are essential for vowel identification
-/// * Formant bandwidths indicate the width of resonance peaks
+/// * Bandwidths come from the LPC pole radii (see `formants.rs`); F4/F5 need an
+///   LPC order of at least 12 and a sample rate of at least 11 kHz
+/// * Audio above 20 kHz is downsampled first, and the order is capped for the
+///   lower analysis rate
 #[no_mangle]
 pub unsafe extern "C" fn extract_formants_rust(
     buffer: *const c_float,
@@ -383,160 +680,264 @@ pub unsafe extern "C" fn extract_formants_rust(
     sample_rate: c_int,
     lpc_order: c_int,
 ) -> FormantsResult {
-    // Default error result (all zeros)
-    let error_result = FormantsResult {
-        f1: 0.0,
-        f2: 0.0,
-        f3: 0.0,
-        bw1: 0.0,
-        bw2: 0.0,
-        bw3: 0.0,
-    };
+    ffi_guard("extract_formants_rust", || {
+        clear_last_error();
 
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return error_result;
-    }
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FormantsResult::error_result(code)
+        };
+
+        // Input validation
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length <= 0 {
+            return fail(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+        }
+
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
+
+        // Handle formant extraction result (AC1, AC5)
+        match analyze_formants_checked(input_slice, sample_rate, lpc_order) {
+            Ok(formants) => FormantsResult::from_formants(&formants, LoqaErrorCode::Ok),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Name of `FormantsResult` in loqa_voice_dsp.h, where Swift's own `FormantsResult`
+/// would otherwise clash with it
+pub type FormantsResultFFI = FormantsResult;
+
+/// Extracts F1-F5 with bandwidths and per-formant confidence using LPC analysis
+///
+/// Unlike upstream's `loqa_extract_formants`, which only reports F1-F3 and one
+/// overall confidence, this runs the wrapper's own LPC pole analysis
+/// (`formants.rs`) and reports why an extraction failed.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
+/// * `lpc_order` - LPC order in [8, 24], or 0 for the default of sample_rate / 1000 + 2
+///
+/// # Returns
+/// * FormantsResultFFI with `error_code` `LoqaErrorCode::Ok` on success;
+///   formants that were not found are 0.0 with zero confidence
+/// * Zeros and a non-Ok `error_code` on error
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
+#[no_mangle]
+pub unsafe extern "C" fn loqa_extract_formants_ex(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    lpc_order: u32,
+) -> FormantsResultFFI {
+    ffi_guard("loqa_extract_formants_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FormantsResult::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        // Out-of-range values are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let Ok(lpc_order) = c_int::try_from(lpc_order) else {
+            return fail(
+                LoqaErrorCode::InvalidLpcOrder,
+                format!("LPC order must be in range [8, 24], got {lpc_order}"),
+            );
+        };
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match analyze_formants_checked(samples, sample_rate, lpc_order) {
+            Ok(formants) => FormantsResult::from_formants(&formants, LoqaErrorCode::Ok),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Validates formant extraction inputs and estimates F1-F5 with bandwidths
+///
+/// Runs the wrapper's own LPC pole analysis (`formants.rs`), since upstream does
+/// not expose pole radii. An `lpc_order` of 0 (or less) selects the default of
+/// `sample_rate / 1000 + 2`.
+pub(crate) fn analyze_formants_checked(
+    samples: &[f32],
+    sample_rate: c_int,
+    lpc_order: c_int,
+) -> Result<[formants::Formant; formants::MAX_FORMANTS], (LoqaErrorCode, String)> {
+    let lpc_order = validate_formant_input(samples, sample_rate, lpc_order)?;
+
+    formants::analyze(samples, sample_rate as u32, lpc_order)
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("Formant extraction failed: {e}")))
+}
 
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return error_result;
+/// Checks the buffer and sample rate and resolves the LPC order (0 = default)
+pub(crate) fn validate_formant_input(
+    samples: &[f32],
+    sample_rate: c_int,
+    lpc_order: c_int,
+) -> Result<usize, (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
     }
 
     // Validate sample rate range: 8000-48000 Hz (AC3)
     if !(8000..=48000).contains(&sample_rate) {
-        eprintln!(
-            "[Rust FFI] Error: sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"
-        );
-        return error_result;
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
+    }
+
+    let computed_lpc_order = resolve_lpc_order(sample_rate, lpc_order)?;
+
+    // Validate buffer is long enough for LPC analysis
+    // Need at least lpc_order * 2 samples for meaningful analysis
+    let min_length = computed_lpc_order * 2;
+    if samples.len() < min_length {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "buffer length {} too short for LPC order {computed_lpc_order} (need at least {min_length})",
+                samples.len()
+            ),
+        ));
     }
 
+    Ok(computed_lpc_order)
+}
+
+/// Resolves the LPC order for `sample_rate` (0 = default) and checks its range
+pub(crate) fn resolve_lpc_order(
+    sample_rate: c_int,
+    lpc_order: c_int,
+) -> Result<usize, (LoqaErrorCode, String)> {
     // Calculate default LPC order if not specified (AC4)
     // Default: (sample_rate / 1000) + 2, clamped to valid range [8, 24]
     // loqa-voice-dsp requires LPC order to be in range 8-24
     let computed_lpc_order = if lpc_order <= 0 {
         let calculated = (sample_rate / 1000) + 2;
-        calculated.clamp(8, 24)
+        let clamped = calculated.clamp(8, 24);
+        if clamped != calculated {
+            loqa_log!(
+                Warn,
+                "default LPC order {calculated} for {sample_rate} Hz is outside [8, 24]; using {clamped}"
+            );
+        } else {
+            loqa_log!(Debug, "using default LPC order {clamped} for {sample_rate} Hz");
+        }
+        clamped
     } else {
         lpc_order
     };
 
     // Validate LPC order is in the range supported by loqa-voice-dsp (8-24)
     if !(8..=24).contains(&computed_lpc_order) {
-        eprintln!(
-            "[Rust FFI] Error: LPC order must be in range [8, 24], got {computed_lpc_order}"
-        );
-        return error_result;
-    }
-
-    // Validate buffer is long enough for LPC analysis
-    // Need at least lpc_order * 2 samples for meaningful analysis
-    if length < computed_lpc_order * 2 {
-        eprintln!(
-            "[Rust FFI] Error: buffer length {length} too short for LPC order {computed_lpc_order} (need at least {})",
-            computed_lpc_order * 2
-        );
-        return error_result;
-    }
-
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
-
-    // Call loqa-voice-dsp LPC formant extraction function (AC2)
-    let formants_result = loqa_voice_dsp::extract_formants(
-        input_slice,
-        sample_rate as u32,
-        computed_lpc_order as usize,
-    );
-
-    // Handle formant extraction result
-    match formants_result {
-        Ok(result) => {
-            // Extract F1, F2, F3 (AC1, AC5)
-            // Note: loqa-voice-dsp v0.1 returns f1, f2, f3, and confidence
-            // Bandwidth estimation is not yet available in v0.1, so we set them to 0
-            // Future versions may include bandwidth information
-            FormantsResult {
-                f1: result.f1,
-                f2: result.f2,
-                f3: result.f3,
-                bw1: 0.0,  // TODO: Add bandwidth estimation in future version
-                bw2: 0.0,
-                bw3: 0.0,
-            }
-        }
-        Err(e) => {
-            eprintln!("[Rust FFI] Formant extraction failed: {e:?}");
-            error_result
-        }
+        return Err((
+            LoqaErrorCode::InvalidLpcOrder,
+            format!("LPC order must be in range [8, 24], got {computed_lpc_order}"),
+        ));
     }
-}
 
-/// Android JNI native method for extractFormants
-///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeExtractFormants(buffer: FloatArray, sampleRate: Int, lpcOrder: Int): FormantsResult`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeExtractFormants
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeExtractFormants
-///
-/// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `buffer_length` - Number of samples in buffer
-/// * `sample_rate` - Sample rate in Hz (8000-48000)
-/// * `lpc_order` - LPC order (0 for default: sample_rate / 1000 + 2)
-///
-/// # Returns
-/// * FormantsResult struct with f1, f2, f3 and bandwidths
-///
-/// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
-///
-/// # Note
-/// FormantsResult is returned by value (small struct), not by pointer.
-/// JNI will automatically marshal this back to Kotlin data class.
-#[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeExtractFormants(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
-    buffer: *const c_float,
-    buffer_length: c_int,
-    sample_rate: c_int,
-    lpc_order: c_int,
-) -> FormantsResult {
-    // Delegate to the main formant extraction implementation
-    // The JNI framework handles conversion of FloatArray to *const f32
-    extract_formants_rust(buffer, buffer_length, sample_rate, lpc_order)
+    Ok(computed_lpc_order as usize)
 }
 
 /// Result structure for spectral analysis
 ///
-/// Returns spectral features: centroid (brightness), rolloff (energy distribution), and tilt (spectral slope).
+/// Returns spectral features: centroid (brightness), rolloff (energy distribution),
+/// tilt (spectral slope) and the shape descriptors computed by `spectral.rs`.
 /// This struct is C-compatible for FFI/JNI interop.
 ///
 /// # Fields
 /// * `centroid` - Spectral centroid in Hz (weighted mean of frequencies, indicates brightness)
 /// * `rolloff` - Spectral rolloff frequency in Hz (frequency below which 95% of energy is concentrated)
 /// * `tilt` - Spectral tilt (slope of spectrum, negative = more low frequency energy)
+/// * `spread` - Standard deviation of frequency around the centroid in Hz
+/// * `skewness` - Asymmetry of the spectrum around the centroid
+/// * `kurtosis` - Peakedness of the spectrum around the centroid
+/// * `flatness` - 0.0 (tonal) to 1.0 (noise-like)
+/// * `entropy` - Normalized spectral entropy, 0.0 (one bin) to 1.0 (flat)
+/// * `crest_factor` - Peak magnitude over mean magnitude
+/// * `rolloff_85` - Frequency below which 85% of energy is concentrated in Hz
+/// * `error_code` - `LoqaErrorCode::Ok` on success
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct SpectrumResult {
     pub centroid: c_float,
     pub rolloff: c_float,
     pub tilt: c_float,
+    pub spread: c_float,
+    pub skewness: c_float,
+    pub kurtosis: c_float,
+    pub flatness: c_float,
+    pub entropy: c_float,
+    pub crest_factor: c_float,
+    pub rolloff_85: c_float,
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for SpectrumResult {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        SpectrumResult::from_descriptors(&spectral::SpectralDescriptors::default(), code)
+    }
+}
+
+impl SpectrumResult {
+    /// Builds the result from descriptors computed with 85%/95% rolloff thresholds
+    fn from_descriptors(
+        descriptors: &spectral::SpectralDescriptors,
+        error_code: LoqaErrorCode,
+    ) -> Self {
+        SpectrumResult {
+            centroid: descriptors.centroid,
+            rolloff: descriptors.rolloff_high,
+            tilt: descriptors.tilt,
+            spread: descriptors.spread,
+            skewness: descriptors.skewness,
+            kurtosis: descriptors.kurtosis,
+            flatness: descriptors.flatness,
+            entropy: descriptors.entropy,
+            crest_factor: descriptors.crest_factor,
+            rolloff_85: descriptors.rolloff_low,
+            error_code,
+        }
+    }
 }
 
-/// Analyzes spectral features using loqa-voice-dsp crate
+/// Analyzes spectral features of a buffer of any length
 ///
-/// Computes three key spectral features in a single efficient function call:
+/// Computes the spectral descriptors in a single call:
 /// - Spectral centroid: weighted mean of frequencies (brightness measure)
-/// - Spectral rolloff: frequency below which 95% of energy is concentrated
+/// - Spectral rolloff: frequency below which 95% (and 85%) of energy is concentrated
 /// - Spectral tilt: overall slope of the spectral envelope
+/// - Spread, skewness, kurtosis, flatness, entropy and crest factor
+///
+/// The FFT size is the smallest power of two holding the buffer (64-8192);
+/// shorter buffers are zero-padded, and longer ones are analyzed as the average
+/// of 50%-overlapping 8192-sample frames. Use `loqa_analyze_spectrum_ex` to
+/// choose the FFT size, disable averaging or change the rolloff thresholds.
 ///
 /// # Arguments
 /// * `buffer` - Pointer to input audio samples (Float32 array)
@@ -544,8 +945,8 @@ pub struct SpectrumResult {
 /// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
 ///
 /// # Returns
-/// * SpectrumResult struct with centroid, rolloff, and tilt
-/// * Returns zeros on error
+/// * SpectrumResult struct with every descriptor
+/// * Returns zeros and a non-Ok `error_code` on error
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -554,7 +955,7 @@ pub struct SpectrumResult {
 ///
 /// # Validation
 /// * Sample rate must be between 8000 and 48000 Hz (AC1)
-/// * All three spectral features computed in single pass for efficiency (AC5)
+/// * All spectral features computed in single pass for efficiency (AC5)
 ///
 /// # Spectral Feature Definitions
 /// * **Spectral Centroid (AC2):** Weighted mean of frequencies, indicates "brightness"
@@ -573,112 +974,105 @@ pub unsafe extern "C" fn analyze_spectrum_rust(
     length: c_int,
     sample_rate: c_int,
 ) -> SpectrumResult {
-    // Default error result (all zeros)
-    let error_result = SpectrumResult {
-        centroid: 0.0,
-        rolloff: 0.0,
-        tilt: 0.0,
-    };
+    ffi_guard("analyze_spectrum_rust", || {
+        clear_last_error();
 
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return error_result;
-    }
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            SpectrumResult::error_result(code)
+        };
 
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return error_result;
-    }
+        // Input validation
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
 
-    // Validate sample rate range: 8000-48000 Hz (AC1)
-    if !(8000..=48000).contains(&sample_rate) {
-        eprintln!(
-            "[Rust FFI] Error: sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"
-        );
-        return error_result;
-    }
+        if length <= 0 {
+            return fail(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+        }
 
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
 
-    // First, compute FFT to get frequency domain representation
-    // We use the same FFT size as buffer length for spectral analysis
-    let fft_size = length as usize;
+        match analyze_spectrum_checked(input_slice, sample_rate, &loqa_spectrum_config_default()) {
+            Ok(analysis) => SpectrumResult::from_descriptors(&analysis.descriptors, LoqaErrorCode::Ok),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
 
-    // Call loqa-voice-dsp FFT function
-    let fft_result = loqa_voice_dsp::compute_fft(
-        input_slice,
-        sample_rate as u32,
-        fft_size
-    );
+/// Validates spectrum analysis inputs and runs `spectral::analyze`
+///
+/// Shared by `analyze_spectrum_rust`, `loqa_analyze_spectrum_ex` and the Android
+/// JNI bindings.
+pub(crate) fn analyze_spectrum_checked(
+    samples: &[f32],
+    sample_rate: c_int,
+    config: &SpectrumConfigFFI,
+) -> Result<spectral::SpectralAnalysis, (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
 
-    let fft_data = match fft_result {
-        Ok(result) => result,
-        Err(e) => {
-            eprintln!("[Rust FFI] FFT computation for spectral analysis failed: {e:?}");
-            return error_result;
-        }
-    };
+    // Validate sample rate range: 8000-48000 Hz (AC1)
+    if !(8000..=48000).contains(&sample_rate) {
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
+    }
 
-    // Call loqa-voice-dsp spectral analysis function
-    // AC5: All three features computed in single function call for efficiency
-    let spectrum_result = loqa_voice_dsp::analyze_spectrum(&fft_data);
+    validate_spectrum_config(config)?;
 
-    // Handle spectral analysis result
-    match spectrum_result {
-        Ok(result) => {
-            // Extract spectral features (AC2, AC3, AC4)
-            SpectrumResult {
-                centroid: result.centroid,      // AC2: Spectral centroid in Hz
-                rolloff: result.rolloff_95,     // AC3: Spectral rolloff (95% energy threshold)
-                tilt: result.tilt,              // AC4: Spectral tilt (slope)
-            }
-        }
-        Err(e) => {
-            eprintln!("[Rust FFI] Spectral analysis failed: {e:?}");
-            error_result
-        }
+    spectral::analyze(
+        samples,
+        sample_rate as u32,
+        config.fft_size as usize,
+        config.average_frames,
+        config.rolloff_low_threshold,
+        config.rolloff_high_threshold,
+    )
+    .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("Spectral analysis failed: {e}")))
+}
+
+/// Checks the FFT size (0 or a power of two in range) and rolloff thresholds
+fn validate_spectrum_config(config: &SpectrumConfigFFI) -> Result<(), (LoqaErrorCode, String)> {
+    validate_fft_size(config.fft_size)?;
+
+    let (low, high) = (config.rolloff_low_threshold, config.rolloff_high_threshold);
+    if !(low > 0.0 && low <= high && high <= 1.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "rolloff thresholds must satisfy 0 < low <= high <= 1, got low={low}, high={high}"
+            ),
+        ));
     }
+
+    Ok(())
 }
 
-/// Android JNI native method for analyzeSpectrum
-///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeAnalyzeSpectrum(buffer: FloatArray, sampleRate: Int): SpectrumResult`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeAnalyzeSpectrum
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeSpectrum
-///
-/// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `buffer_length` - Number of samples in buffer
-/// * `sample_rate` - Sample rate in Hz (8000-48000)
-///
-/// # Returns
-/// * SpectrumResult struct with centroid, rolloff, and tilt
-///
-/// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
-///
-/// # Note
-/// SpectrumResult is returned by value (small struct), not by pointer.
-/// JNI will automatically marshal this back to Kotlin data class.
-#[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeAnalyzeSpectrum(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
-    buffer: *const c_float,
-    buffer_length: c_int,
-    sample_rate: c_int,
-) -> SpectrumResult {
-    // Delegate to the main spectral analysis implementation
-    // The JNI framework handles conversion of FloatArray to *const f32
-    analyze_spectrum_rust(buffer, buffer_length, sample_rate)
+/// Checks an FFT size is 0 (auto) or a power of two in [64, 8192]
+fn validate_fft_size(fft_size: u32) -> Result<(), (LoqaErrorCode, String)> {
+    let fft_size = fft_size as usize;
+    if fft_size != 0
+        && !(fft_size.is_power_of_two()
+            && (spectral::MIN_FFT_SIZE..=spectral::MAX_FFT_SIZE).contains(&fft_size))
+    {
+        return Err((
+            LoqaErrorCode::InvalidFftSize,
+            format!(
+                "fft_size must be 0 (auto) or a power of 2 in range [{}, {}], got {fft_size}",
+                spectral::MIN_FFT_SIZE,
+                spectral::MAX_FFT_SIZE
+            ),
+        ));
+    }
+    Ok(())
 }
 
 /// Result structure for HNR (Harmonics-to-Noise Ratio) calculation
@@ -690,12 +1084,25 @@ pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_native
 /// * `hnr` - Harmonics-to-Noise Ratio in dB (higher = clearer voice, lower = breathier)
 /// * `f0` - Detected fundamental frequency in Hz
 /// * `is_voiced` - Whether the signal is voiced (periodic)
+/// * `error_code` - `LoqaErrorCode::Ok` on success
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct HNRResult {
     pub hnr: c_float,
     pub f0: c_float,
     pub is_voiced: bool,
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for HNRResult {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        HNRResult {
+            hnr: 0.0,
+            f0: 0.0,
+            is_voiced: false,
+            error_code: code,
+        }
+    }
 }
 
 /// Calculates Harmonics-to-Noise Ratio using Boersma's autocorrelation method
@@ -714,7 +1121,7 @@ pub struct HNRResult {
 ///
 /// # Returns
 /// * HNRResult struct with hnr (dB), f0 (Hz), and is_voiced flag
-/// * Returns hnr=0.0, f0=0.0, is_voiced=false on error
+/// * Returns hnr=0.0, f0=0.0, is_voiced=false and a non-Ok `error_code` on error
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -728,63 +1135,128 @@ pub unsafe extern "C" fn calculate_hnr_rust(
     min_freq: c_float,
     max_freq: c_float,
 ) -> HNRResult {
-    // Default error result
-    let error_result = HNRResult {
-        hnr: 0.0,
-        f0: 0.0,
-        is_voiced: false,
-    };
+    ffi_guard("calculate_hnr_rust", || {
+        clear_last_error();
 
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return error_result;
-    }
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            HNRResult::error_result(code)
+        };
 
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return error_result;
-    }
+        // Input validation
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length <= 0 {
+            return fail(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+        }
+
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
+
+        match calculate_hnr_checked(input_slice, sample_rate, min_freq, max_freq) {
+            Ok(result) => result,
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Name of `HNRResult` in loqa_voice_dsp.h, where upstream's `HNRResultFFI` and
+/// Swift's own `HNRResult` are already taken
+pub type HNRResultExFFI = HNRResult;
+
+/// Calculates Harmonics-to-Noise Ratio and reports why a calculation failed
+///
+/// Panic-safe replacement for upstream's `loqa_calculate_hnr`, sharing its
+/// validation with `calculate_hnr_rust`.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
+/// * `min_freq` - Minimum F0 frequency to search (typically 75 Hz)
+/// * `max_freq` - Maximum F0 frequency to search (typically 500 Hz)
+///
+/// # Returns
+/// * HNRResultExFFI with `error_code` `LoqaErrorCode::Ok` on success
+/// * Zeros and a non-Ok `error_code` on error
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
+#[no_mangle]
+pub unsafe extern "C" fn loqa_calculate_hnr_ex(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    min_freq: c_float,
+    max_freq: c_float,
+) -> HNRResultExFFI {
+    ffi_guard("loqa_calculate_hnr_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            HNRResult::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        // Out-of-range values are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match calculate_hnr_checked(samples, sample_rate, min_freq, max_freq) {
+            Ok(result) => result,
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
 
+/// Validates HNR inputs and runs loqa-voice-dsp's autocorrelation HNR
+pub(crate) fn calculate_hnr_checked(
+    samples: &[f32],
+    sample_rate: c_int,
+    min_freq: c_float,
+    max_freq: c_float,
+) -> Result<HNRResult, (LoqaErrorCode, String)> {
     // Validate sample rate range: 8000-48000 Hz
     if !(8000..=48000).contains(&sample_rate) {
-        eprintln!(
-            "[Rust FFI] Error: sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"
-        );
-        return error_result;
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
     }
 
     // Validate frequency range
     if min_freq <= 0.0 || max_freq <= min_freq {
-        eprintln!(
-            "[Rust FFI] Error: invalid frequency range: min={min_freq}, max={max_freq}"
-        );
-        return error_result;
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!("invalid frequency range: min={min_freq}, max={max_freq}"),
+        ));
     }
 
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
-
-    // Call loqa-voice-dsp HNR calculation function
-    let hnr_result = loqa_voice_dsp::calculate_hnr(
-        input_slice,
-        sample_rate as u32,
-        min_freq,
-        max_freq,
-    );
+    let result = loqa_voice_dsp::calculate_hnr(samples, sample_rate as u32, min_freq, max_freq)
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("HNR calculation failed: {e}")))?;
 
-    // Handle HNR calculation result
-    match hnr_result {
-        Ok(result) => HNRResult {
-            hnr: result.hnr,
-            f0: result.f0,
-            is_voiced: result.is_voiced,
-        },
-        Err(e) => {
-            eprintln!("[Rust FFI] HNR calculation failed: {e:?}");
-            error_result
-        }
-    }
+    Ok(HNRResult {
+        hnr: result.hnr,
+        f0: result.f0,
+        is_voiced: result.is_voiced,
+        error_code: LoqaErrorCode::Ok,
+    })
 }
 
 /// Result structure for H1-H2 amplitude difference calculation
@@ -797,6 +1269,7 @@ pub unsafe extern "C" fn calculate_hnr_rust(
 /// * `h1_amplitude_db` - First harmonic (fundamental) amplitude in dB
 /// * `h2_amplitude_db` - Second harmonic amplitude in dB
 /// * `f0` - Fundamental frequency used for calculation in Hz
+/// * `error_code` - `LoqaErrorCode::Ok` on success
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct H1H2Result {
@@ -804,6 +1277,19 @@ pub struct H1H2Result {
     pub h1_amplitude_db: c_float,
     pub h2_amplitude_db: c_float,
     pub f0: c_float,
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for H1H2Result {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        H1H2Result {
+            h1h2: 0.0,
+            h1_amplitude_db: 0.0,
+            h2_amplitude_db: 0.0,
+            f0: 0.0,
+            error_code: code,
+        }
+    }
 }
 
 /// Calculates H1-H2 amplitude difference for vocal weight analysis
@@ -821,7 +1307,7 @@ pub struct H1H2Result {
 ///
 /// # Returns
 /// * H1H2Result struct with h1h2, h1_amplitude_db, h2_amplitude_db, and f0
-/// * Returns all zeros on error
+/// * Returns all zeros and a non-Ok `error_code` on error
 ///
 /// # Safety
 /// * Caller must ensure `buffer` points to valid memory of at least `length` samples
@@ -834,1426 +1320,8959 @@ pub unsafe extern "C" fn calculate_h1h2_rust(
     sample_rate: c_int,
     f0: c_float,
 ) -> H1H2Result {
-    // Default error result
-    let error_result = H1H2Result {
-        h1h2: 0.0,
-        h1_amplitude_db: 0.0,
-        h2_amplitude_db: 0.0,
-        f0: 0.0,
-    };
+    ffi_guard("calculate_h1h2_rust", || {
+        clear_last_error();
 
-    // Input validation
-    if buffer.is_null() {
-        eprintln!("[Rust FFI] Error: buffer pointer is null");
-        return error_result;
-    }
-
-    if length <= 0 {
-        eprintln!("[Rust FFI] Error: length must be > 0, got {length}");
-        return error_result;
-    }
-
-    // Validate sample rate range: 8000-48000 Hz
-    if !(8000..=48000).contains(&sample_rate) {
-        eprintln!(
-            "[Rust FFI] Error: sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"
-        );
-        return error_result;
-    }
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            H1H2Result::error_result(code)
+        };
 
-    // Convert raw pointer to Rust slice
-    let input_slice = slice::from_raw_parts(buffer, length as usize);
+        // Input validation
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
 
-    // Convert f0: 0.0 means auto-detect (None), otherwise Some(f0)
-    let f0_option = if f0 > 0.0 { Some(f0) } else { None };
+        if length <= 0 {
+            return fail(
+                LoqaErrorCode::InvalidLength,
+                format!("length must be > 0, got {length}"),
+            );
+        }
 
-    // Call loqa-voice-dsp H1-H2 calculation function
-    let h1h2_result = loqa_voice_dsp::calculate_h1h2(
-        input_slice,
-        sample_rate as u32,
-        f0_option,
-    );
+        // Convert raw pointer to Rust slice
+        let input_slice = slice::from_raw_parts(buffer, length as usize);
 
-    // Handle H1-H2 calculation result
-    match h1h2_result {
-        Ok(result) => H1H2Result {
-            h1h2: result.h1h2,
-            h1_amplitude_db: result.h1_amplitude_db,
-            h2_amplitude_db: result.h2_amplitude_db,
-            f0: result.f0,
-        },
-        Err(e) => {
-            eprintln!("[Rust FFI] H1-H2 calculation failed: {e:?}");
-            error_result
+        match calculate_h1h2_checked(input_slice, sample_rate, f0) {
+            Ok(result) => result,
+            Err((code, message)) => fail(code, message),
         }
-    }
+    })
 }
 
-/// Android JNI native method for calculateHNR
-///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeCalculateHNR(buffer: FloatArray, sampleRate: Int, minFreq: Float, maxFreq: Float): HNRResult`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeCalculateHNR
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateHNR
-///
-/// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `buffer_length` - Number of samples in buffer
-/// * `sample_rate` - Sample rate in Hz (8000-48000)
-/// * `min_freq` - Minimum F0 frequency to search (typically 75 Hz)
-/// * `max_freq` - Maximum F0 frequency to search (typically 500 Hz)
-///
-/// # Returns
-/// * HNRResult struct with hnr (dB), f0 (Hz), and is_voiced flag
-///
-/// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
-///
-/// # Note
-/// HNRResult is returned by value (small struct), not by pointer.
-/// JNI will automatically marshal this back to Kotlin data class.
-#[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateHNR(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
-    buffer: *const c_float,
-    buffer_length: c_int,
-    sample_rate: c_int,
-    min_freq: c_float,
-    max_freq: c_float,
-) -> HNRResult {
-    // Delegate to the main HNR implementation
-    // The JNI framework handles conversion of FloatArray to *const f32
-    calculate_hnr_rust(buffer, buffer_length, sample_rate, min_freq, max_freq)
-}
+/// Name of `H1H2Result` in loqa_voice_dsp.h, where upstream's `H1H2ResultFFI` and
+/// Swift's own `H1H2Result` are already taken
+pub type H1H2ResultExFFI = H1H2Result;
 
-/// Android JNI native method for calculateH1H2
+/// Calculates the H1-H2 amplitude difference and reports why a calculation failed
 ///
-/// JNI Method Signature Resolution:
-/// - Kotlin declaration: `external fun nativeCalculateH1H2(buffer: FloatArray, sampleRate: Int, f0: Float): H1H2Result`
-/// - Package: com.loqalabs.loqaexpodsp.RustJNI
-/// - Class: RustBridge (object)
-/// - Method: nativeCalculateH1H2
-/// - JNI Function Name: Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateH1H2
+/// Panic-safe replacement for upstream's `loqa_calculate_h1h2`, sharing its
+/// validation with `calculate_h1h2_rust`.
 ///
 /// # Arguments
-/// * `env` - JNI environment pointer (unused but required by JNI)
-/// * `class` - JNI class reference (unused but required by JNI)
-/// * `buffer` - JNI jfloatArray reference to input audio samples
-/// * `buffer_length` - Number of samples in buffer
-/// * `sample_rate` - Sample rate in Hz (8000-48000)
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
 /// * `f0` - Fundamental frequency in Hz, or 0.0 to auto-detect
 ///
 /// # Returns
-/// * H1H2Result struct with h1h2, h1_amplitude_db, h2_amplitude_db, and f0
+/// * H1H2ResultExFFI with `error_code` `LoqaErrorCode::Ok` on success
+/// * Zeros and a non-Ok `error_code` on error
 ///
 /// # Safety
-/// * JNI framework ensures proper type conversions and memory management
-/// * This function is called from Kotlin via JNI, not directly
-///
-/// # Note
-/// H1H2Result is returned by value (small struct), not by pointer.
-/// JNI will automatically marshal this back to Kotlin data class.
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
 #[no_mangle]
-pub unsafe extern "C" fn Java_com_loqalabs_loqaexpodsp_RustJNI_RustBridge_nativeCalculateH1H2(
-    _env: *mut std::os::raw::c_void,
-    _class: *mut std::os::raw::c_void,
+pub unsafe extern "C" fn loqa_calculate_h1h2_ex(
     buffer: *const c_float,
-    buffer_length: c_int,
+    length: usize,
+    sample_rate: u32,
+    f0: c_float,
+) -> H1H2ResultExFFI {
+    ffi_guard("loqa_calculate_h1h2_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            H1H2Result::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        if length == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        // Out-of-range values are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match calculate_h1h2_checked(samples, sample_rate, f0) {
+            Ok(result) => result,
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Validates H1-H2 inputs and runs loqa-voice-dsp's harmonic amplitude analysis
+pub(crate) fn calculate_h1h2_checked(
+    samples: &[f32],
     sample_rate: c_int,
     f0: c_float,
-) -> H1H2Result {
-    // Delegate to the main H1-H2 implementation
-    // The JNI framework handles conversion of FloatArray to *const f32
-    calculate_h1h2_rust(buffer, buffer_length, sample_rate, f0)
+) -> Result<H1H2Result, (LoqaErrorCode, String)> {
+    // Validate sample rate range: 8000-48000 Hz
+    if !(8000..=48000).contains(&sample_rate) {
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
+    }
+
+    // Convert f0: 0.0 means auto-detect (None), otherwise Some(f0)
+    let f0_option = if f0 > 0.0 { Some(f0) } else { None };
+
+    let result = loqa_voice_dsp::calculate_h1h2(samples, sample_rate as u32, f0_option)
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("H1-H2 calculation failed: {e}")))?;
+
+    Ok(H1H2Result {
+        h1h2: result.h1h2,
+        h1_amplitude_db: result.h1_amplitude_db,
+        h2_amplitude_db: result.h2_amplitude_db,
+        f0: result.f0,
+        error_code: LoqaErrorCode::Ok,
+    })
 }
 
 // ============================================================================
-// VoiceAnalyzer process_buffer FFI - HMM-smoothed pitch tracking (v0.5.0)
+// Windowed FFT FFI - window functions, phase and calibrated scaling
 // ============================================================================
 
-/// C-compatible PitchTrack result from Viterbi decoding
+/// Window function selector for `FFTConfigFFI::window`
+///
+/// Carried as a plain `u32` to match the C header; these are the only accepted
+/// values. The numbering of the first four matches the Android `windowType`.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaWindowType {
+    /// Rectangular (no window)
+    None = 0,
+    /// Hann (the default; good general-purpose window)
+    Hann = 1,
+    /// Hamming (lower first sidelobe than Hann, slower falloff)
+    Hamming = 2,
+    /// Blackman (lower sidelobes, wider main lobe)
+    Blackman = 3,
+    /// Kaiser; `window_param` is beta (0 = 8.6)
+    Kaiser = 4,
+    /// Five-term flat-top (accurate peak amplitudes between bins)
+    FlatTop = 5,
+    /// Gaussian; `window_param` is sigma relative to half the window (0 = 0.4)
+    Gaussian = 6,
+}
+
+impl LoqaWindowType {
+    /// Parses a raw selector value
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::None),
+            1 => Some(Self::Hann),
+            2 => Some(Self::Hamming),
+            3 => Some(Self::Blackman),
+            4 => Some(Self::Kaiser),
+            5 => Some(Self::FlatTop),
+            6 => Some(Self::Gaussian),
+            _ => None,
+        }
+    }
+}
+
+/// Magnitude scaling selector for `FFTConfigFFI::normalization`
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaFftNormalization {
+    /// Single-sided amplitude: an on-bin sine of amplitude A reads A
+    Amplitude = 0,
+    /// Single-sided power spectral density in units^2/Hz
+    Psd = 1,
+}
+
+impl LoqaFftNormalization {
+    /// Parses a raw selector value
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Amplitude),
+            1 => Some(Self::Psd),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration for `loqa_compute_fft_ex` (see `loqa_fft_config_default`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FFTConfigFFI {
+    /// FFT size: a power of two in [64, 8192], or 0 for the smallest one holding
+    /// the buffer (capped at 8192). Longer buffers are truncated, shorter ones
+    /// zero-padded after windowing
+    pub fft_size: u32,
+    /// `LoqaWindowType` value
+    pub window: u32,
+    /// Kaiser beta or Gaussian sigma (0 selects the default); ignored by other windows
+    pub window_param: c_float,
+    /// `LoqaFftNormalization` value
+    pub normalization: u32,
+    /// Return the phase of each bin in `phases_ptr`
+    pub include_phase: bool,
+}
+
+/// Returns the default FFT configuration: automatic size, Hann window, amplitude
+/// scaling and no phase
+#[no_mangle]
+pub extern "C" fn loqa_fft_config_default() -> FFTConfigFFI {
+    FFTConfigFFI {
+        fft_size: 0,
+        window: LoqaWindowType::Hann as u32,
+        window_param: 0.0,
+        normalization: LoqaFftNormalization::Amplitude as u32,
+        include_phase: false,
+    }
+}
+
+/// Result structure for `loqa_compute_fft_ex`
 ///
-/// Contains smoothed pitch track, voiced probabilities, and timestamps.
 /// Arrays are heap-allocated and owned by caller after return.
-/// Caller MUST call `loqa_free_pitch_track` to prevent memory leaks.
+/// Caller MUST call `loqa_free_fft_result_ex` to prevent memory leaks.
 #[repr(C)]
 #[derive(Debug)]
-pub struct PitchTrackFFI {
-    /// True if processing succeeded
+pub struct FFTResultExFFI {
+    /// True if the FFT was computed
     pub success: bool,
-    /// Pointer to pitch estimates array (Hz, 0.0 = unvoiced)
-    pub pitch_track_ptr: *mut c_float,
-    /// Pointer to voiced probabilities array [0.0, 1.0]
-    pub voiced_probs_ptr: *mut c_float,
-    /// Pointer to timestamps array (seconds from buffer start)
-    pub timestamps_ptr: *mut c_float,
-    /// Number of frames (length of all three arrays)
+    /// Pointer to scaled magnitudes (see `LoqaFftNormalization`)
+    pub magnitudes_ptr: *mut c_float,
+    /// Pointer to bin phases in radians, or null unless `include_phase` was set
+    pub phases_ptr: *mut c_float,
+    /// Pointer to bin-center frequencies in Hz
+    pub frequencies_ptr: *mut c_float,
+    /// Number of bins (fft_size / 2 + 1, the length of every array)
     pub length: usize,
+    /// FFT size actually used
+    pub fft_size: u32,
+    /// Sample rate used for the bin frequencies
+    pub sample_rate: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
 }
 
-/// Process audio buffer with HMM-smoothed Viterbi decoding
-///
-/// Unlike `process_stream` which treats frames independently, this method uses
-/// Viterbi decoding to find the globally optimal pitch track across all frames,
-/// reducing octave errors (from ~8-12% to ≤3%) and producing smoother contours.
-///
-/// This is best suited for offline analysis of complete utterances
-/// (typically < 60 seconds). For longer recordings, segment into utterances first.
-///
-/// **Note:** Always uses pYIN algorithm regardless of config settings, since
-/// HMM smoothing requires the probabilistic candidates that only pYIN provides.
+impl ErrorResult for FFTResultExFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        FFTResultExFFI {
+            success: false,
+            magnitudes_ptr: std::ptr::null_mut(),
+            phases_ptr: std::ptr::null_mut(),
+            frequencies_ptr: std::ptr::null_mut(),
+            length: 0,
+            fft_size: 0,
+            sample_rate: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Validates windowed FFT inputs and runs `fft::spectrum`
 ///
-/// # Arguments
-/// * `analyzer` - Pointer to VoiceAnalyzer from `loqa_voice_analyzer_new`
-/// * `samples` - Pointer to audio samples (Float32 array)
-/// * `len` - Number of samples in buffer
+/// Shared by `loqa_compute_fft_ex` and the Android JNI bindings. The returned
+/// spectrum always carries phases; callers drop them unless `include_phase` is set.
 ///
 /// # Returns
-/// * PitchTrackFFI with success=true and allocated arrays if processing succeeded
-/// * Caller MUST call `loqa_free_pitch_track` to deallocate arrays
-///
-/// # Safety
-/// * `analyzer` must be a valid pointer from `loqa_voice_analyzer_new`
-/// * `samples` must point to valid f32 array of length `len`
-/// * Caller MUST call `loqa_free_pitch_track` to avoid memory leaks
-#[no_mangle]
-pub unsafe extern "C" fn loqa_voice_analyzer_process_buffer(
-    analyzer: *mut std::ffi::c_void,
-    samples: *const c_float,
-    len: usize,
-) -> PitchTrackFFI {
-    // Error result helper
-    let error_result = PitchTrackFFI {
-        success: false,
-        pitch_track_ptr: std::ptr::null_mut(),
-        voiced_probs_ptr: std::ptr::null_mut(),
-        timestamps_ptr: std::ptr::null_mut(),
-        length: 0,
-    };
-
-    // Null pointer checks
-    if analyzer.is_null() {
-        eprintln!("[Rust FFI] Error: analyzer pointer is null");
-        return error_result;
+/// * The spectrum and the FFT size used
+pub(crate) fn compute_fft_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &FFTConfigFFI,
+) -> Result<(fft::Spectrum, usize), (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
     }
 
-    if samples.is_null() || len == 0 {
-        eprintln!("[Rust FFI] Error: samples pointer is null or length is 0");
-        return error_result;
+    if sample_rate == 0 {
+        return Err((LoqaErrorCode::InvalidSampleRate, "sample_rate must be > 0, got 0".into()));
     }
 
-    // Cast back to VoiceAnalyzer
-    let analyzer_ref = &mut *(analyzer as *mut loqa_voice_dsp::VoiceAnalyzer);
-    let samples_slice = slice::from_raw_parts(samples, len);
-
-    // Call process_buffer for Viterbi-smoothed pitch track
-    match analyzer_ref.process_buffer(samples_slice) {
-        Ok(track) => {
-            let frame_count = track.pitch_track.len();
+    validate_fft_size(config.fft_size)?;
+    let window = fft_window_from_raw(config.window, config.window_param)?;
+    let normalization = match LoqaFftNormalization::from_raw(config.normalization) {
+        Some(LoqaFftNormalization::Amplitude) => fft::Normalization::Amplitude,
+        Some(LoqaFftNormalization::Psd) => fft::Normalization::Psd,
+        None => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!(
+                    "normalization must be 0-1 (Amplitude/PSD), got {}",
+                    config.normalization
+                ),
+            ))
+        }
+    };
 
-            // Allocate C-compatible arrays
-            let mut pitch_vec = track.pitch_track.into_boxed_slice();
-            let mut probs_vec = track.voiced_probabilities.into_boxed_slice();
-            let mut times_vec = track.timestamps.into_boxed_slice();
+    let fft_size = resolve_fft_size(samples.len(), config.fft_size);
+    let spectrum = fft::spectrum(samples, sample_rate, fft_size, window, normalization);
+    Ok((spectrum, fft_size))
+}
 
-            let pitch_ptr = pitch_vec.as_mut_ptr();
-            let probs_ptr = probs_vec.as_mut_ptr();
-            let times_ptr = times_vec.as_mut_ptr();
+/// FFT size for a buffer of `length` samples given a validated `fft_size` (0 = auto)
+///
+/// Logs the size used, and a warning when the buffer is longer and gets truncated.
+fn resolve_fft_size(length: usize, fft_size: u32) -> usize {
+    let fft_size = match fft_size {
+        0 => spectral::auto_fft_size(length),
+        size => size as usize,
+    };
+    if length > fft_size {
+        loqa_log!(
+            Warn,
+            "buffer of {length} samples truncated to fft_size {fft_size}; {} samples ignored",
+            length - fft_size
+        );
+    }
+    loqa_log!(Debug, "FFT of {length} samples with fft_size {fft_size}");
+    fft_size
+}
 
-            // Prevent Rust from freeing the memory (caller will free via loqa_free_pitch_track)
-            std::mem::forget(pitch_vec);
-            std::mem::forget(probs_vec);
-            std::mem::forget(times_vec);
+/// Converts a `LoqaWindowType` selector and its Kaiser beta / Gaussian sigma parameter
+fn fft_window_from_raw(
+    window: u32,
+    param: c_float,
+) -> Result<fft::Window, (LoqaErrorCode, String)> {
+    let invalid_param = |name: &str| {
+        (
+            LoqaErrorCode::InvalidArgument,
+            format!("window_param ({name}) must be finite and >= 0, got {param}"),
+        )
+    };
 
-            PitchTrackFFI {
-                success: true,
-                pitch_track_ptr: pitch_ptr,
-                voiced_probs_ptr: probs_ptr,
-                timestamps_ptr: times_ptr,
-                length: frame_count,
+    Ok(match LoqaWindowType::from_raw(window) {
+        Some(LoqaWindowType::None) => fft::Window::Rectangular,
+        Some(LoqaWindowType::Hann) => fft::Window::Hann,
+        Some(LoqaWindowType::Hamming) => fft::Window::Hamming,
+        Some(LoqaWindowType::Blackman) => fft::Window::Blackman,
+        Some(LoqaWindowType::FlatTop) => fft::Window::FlatTop,
+        Some(LoqaWindowType::Kaiser) => {
+            if !(param.is_finite() && param >= 0.0) {
+                return Err(invalid_param("Kaiser beta"));
+            }
+            let beta = if param == 0.0 { fft::DEFAULT_KAISER_BETA } else { param };
+            fft::Window::Kaiser { beta }
+        }
+        Some(LoqaWindowType::Gaussian) => {
+            if !(param.is_finite() && param >= 0.0) {
+                return Err(invalid_param("Gaussian sigma"));
             }
+            let sigma = if param == 0.0 { fft::DEFAULT_GAUSSIAN_SIGMA } else { param };
+            fft::Window::Gaussian { sigma }
         }
-        Err(e) => {
-            eprintln!("[Rust FFI] process_buffer failed: {e}");
-            error_result
+        None => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!(
+                    "window must be 0-6 (None/Hann/Hamming/Blackman/Kaiser/FlatTop/Gaussian), got {window}"
+                ),
+            ))
         }
-    }
+    })
 }
 
-/// Free PitchTrackFFI arrays allocated by `loqa_voice_analyzer_process_buffer`
+/// Computes a windowed FFT with phase, bin frequencies and calibrated scaling
+///
+/// Unlike `loqa_compute_fft`, the samples are windowed before the transform and
+/// magnitudes are compensated for the window's gain, so they can be read as
+/// signal amplitudes (or a PSD) whichever window is used.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz, used for the bin frequencies and PSD
+/// * `config` - FFT size, window, scaling and whether to return phases
+///
+/// # Returns
+/// * FFTResultExFFI with success=true and allocated arrays if the FFT was computed
+/// * On error, `error_code` is set (`InvalidFftSize`, `InvalidArgument` for an
+///   unknown window or normalization, ...)
 ///
 /// # Safety
-/// * `result` must point to valid PitchTrackFFI from `loqa_voice_analyzer_process_buffer`
-/// * Must be called exactly once per successful `loqa_voice_analyzer_process_buffer` call
-/// * After calling this, the pointers in `result` are invalid
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_fft_result_ex` to deallocate the arrays
 #[no_mangle]
-pub unsafe extern "C" fn loqa_free_pitch_track(result: *mut PitchTrackFFI) {
-    if result.is_null() {
-        return;
-    }
+pub unsafe extern "C" fn loqa_compute_fft_ex(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: FFTConfigFFI,
+) -> FFTResultExFFI {
+    ffi_guard("loqa_compute_fft_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FFTResultExFFI::error_result(code)
+        };
 
-    let res = &*result;
-
-    // Free each array if non-null
-    if !res.pitch_track_ptr.is_null() && res.length > 0 {
-        let _ = Box::from_raw(slice::from_raw_parts_mut(res.pitch_track_ptr, res.length));
-    }
-
-    if !res.voiced_probs_ptr.is_null() && res.length > 0 {
-        let _ = Box::from_raw(slice::from_raw_parts_mut(res.voiced_probs_ptr, res.length));
-    }
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
 
-    if !res.timestamps_ptr.is_null() && res.length > 0 {
-        let _ = Box::from_raw(slice::from_raw_parts_mut(res.timestamps_ptr, res.length));
-    }
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_fft_checked(samples, sample_rate, &config) {
+            Ok((spectrum, fft_size)) => {
+                // Ownership of each array passes to the caller until loqa_free_fft_result_ex
+                let into_raw =
+                    |values: Vec<f32>| Box::into_raw(values.into_boxed_slice()) as *mut c_float;
+                FFTResultExFFI {
+                    success: true,
+                    length: spectrum.magnitudes.len(),
+                    magnitudes_ptr: into_raw(spectrum.magnitudes),
+                    phases_ptr: if config.include_phase {
+                        into_raw(spectrum.phases)
+                    } else {
+                        std::ptr::null_mut()
+                    },
+                    frequencies_ptr: into_raw(spectrum.frequencies),
+                    fft_size: fft_size as u32,
+                    sample_rate,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
 }
 
-/// Placeholder FFI function for testing build infrastructure (retained for backward compatibility)
+/// Free FFTResultExFFI arrays allocated by `loqa_compute_fft_ex`
+///
+/// The pointers in `result` are set to null and `length` to 0, so freeing the
+/// same result twice is harmless.
+///
+/// # Safety
+/// * `result` must be null or point to an FFTResultExFFI from `loqa_compute_fft_ex`
 #[no_mangle]
-pub extern "C" fn test_ffi_bridge() -> i32 {
-    42
+pub unsafe extern "C" fn loqa_free_fft_result_ex(result: *mut FFTResultExFFI) {
+    ffi_guard("loqa_free_fft_result_ex", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        for ptr in [&mut res.magnitudes_ptr, &mut res.phases_ptr, &mut res.frequencies_ptr] {
+            if !ptr.is_null() && res.length > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, res.length));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        res.length = 0;
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::f32::consts::PI;
+// ============================================================================
+// Complex FFT FFI - complex spectrum and inverse transform
+// ============================================================================
 
-    #[test]
-    fn test_ffi_placeholder() {
-        assert_eq!(test_ffi_bridge(), 42);
-    }
+/// Result structure for `loqa_compute_fft_complex`
+///
+/// `bins_ptr` is heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_complex_spectrum` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ComplexSpectrumFFI {
+    /// True if the FFT was computed
+    pub success: bool,
+    /// Pointer to interleaved bins `[re0, im0, re1, im1, ...]` (2 * bin_count floats)
+    pub bins_ptr: *mut c_float,
+    /// Number of complex bins (fft_size / 2 + 1)
+    pub bin_count: usize,
+    /// FFT size actually used
+    pub fft_size: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
 
-    #[test]
-    fn test_compute_fft_null_buffer() {
-        unsafe {
-            let result = compute_fft_rust(std::ptr::null(), 1024, 44100, 512);
-            assert!(result.is_null(), "Should return null for null buffer");
+impl ErrorResult for ComplexSpectrumFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        ComplexSpectrumFFI {
+            success: false,
+            bins_ptr: std::ptr::null_mut(),
+            bin_count: 0,
+            fft_size: 0,
+            error_code: code,
         }
     }
+}
 
-    #[test]
-    fn test_compute_fft_invalid_length() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), 0, 44100, 512);
-            assert!(result.is_null(), "Should return null for length <= 0");
+/// Result structure for `loqa_compute_ifft`
+///
+/// `samples_ptr` is heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_ifft_result` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct InverseFFTResultFFI {
+    /// True if the inverse FFT was computed
+    pub success: bool,
+    /// Pointer to the time-domain samples
+    pub samples_ptr: *mut c_float,
+    /// Number of samples (the FFT size)
+    pub length: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
 
-            let result = compute_fft_rust(buffer.as_ptr(), -10, 44100, 512);
-            assert!(result.is_null(), "Should return null for negative length");
+impl ErrorResult for InverseFFTResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        InverseFFTResultFFI {
+            success: false,
+            samples_ptr: std::ptr::null_mut(),
+            length: 0,
+            error_code: code,
         }
     }
+}
 
-    #[test]
-    fn test_compute_fft_invalid_sample_rate() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, 0, 512);
-            assert!(result.is_null(), "Should return null for sample_rate <= 0");
-
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, -100, 512);
-            assert!(
-                result.is_null(),
-                "Should return null for negative sample_rate"
-            );
-        }
+/// Validates forward complex FFT inputs and runs `fft::forward`
+///
+/// Shared by `loqa_compute_fft_complex` and the Android JNI bindings.
+///
+/// # Returns
+/// * The interleaved bins and the FFT size used
+pub(crate) fn compute_fft_complex_checked(
+    samples: &[f32],
+    fft_size: u32,
+) -> Result<(Vec<f32>, usize), (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
     }
 
-    #[test]
-    fn test_compute_fft_invalid_fft_size_not_power_of_2() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    validate_fft_size(fft_size)?;
+    let fft_size = resolve_fft_size(samples.len(), fft_size);
+    Ok((fft::interleave(&fft::forward(samples, fft_size)), fft_size))
+}
 
-        unsafe {
-            // Test non-power-of-2 sizes
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 500);
-            assert!(
-                result.is_null(),
-                "Should return null for non-power-of-2 FFT size"
-            );
+/// Validates interleaved one-sided bins and runs `fft::inverse`
+///
+/// Shared by `loqa_compute_ifft` and the Android JNI bindings.
+pub(crate) fn compute_ifft_checked(bins: &[f32]) -> Result<Vec<f32>, (LoqaErrorCode, String)> {
+    if bins.is_empty() || !bins.len().is_multiple_of(2) {
+        return Err((
+            LoqaErrorCode::InvalidLength,
+            format!("interleaved bins must hold re/im pairs, got {} floats", bins.len()),
+        ));
+    }
 
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 1000);
-            assert!(
-                result.is_null(),
-                "Should return null for non-power-of-2 FFT size"
-            );
-        }
+    let bin_count = bins.len() / 2;
+    let fft_size = 2 * (bin_count - 1);
+    if !(fft_size.is_power_of_two()
+        && (spectral::MIN_FFT_SIZE..=spectral::MAX_FFT_SIZE).contains(&fft_size))
+    {
+        return Err((
+            LoqaErrorCode::InvalidFftSize,
+            format!(
+                "bin_count must be fft_size / 2 + 1 for a power of 2 fft_size in range [{}, {}], got {bin_count}",
+                spectral::MIN_FFT_SIZE,
+                spectral::MAX_FFT_SIZE
+            ),
+        ));
     }
 
-    #[test]
-    fn test_compute_fft_invalid_fft_size_out_of_range() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    Ok(fft::inverse(&fft::deinterleave(bins)))
+}
 
-        unsafe {
-            // Test below minimum (256)
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 128);
-            assert!(result.is_null(), "Should return null for FFT size < 256");
+/// Computes the complex one-sided spectrum of a real buffer
+///
+/// No window is applied and the bins are not normalized, so
+/// `loqa_compute_ifft` of the result reconstructs the (zero-padded) input.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `fft_size` - Power of two in [64, 8192], or 0 for the smallest one holding
+///   the buffer (capped at 8192); longer buffers are truncated
+///
+/// # Returns
+/// * ComplexSpectrumFFI with success=true and an allocated array if the FFT was computed
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_complex_spectrum` to deallocate the array
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_fft_complex(
+    buffer: *const c_float,
+    length: usize,
+    fft_size: u32,
+) -> ComplexSpectrumFFI {
+    ffi_guard("loqa_compute_fft_complex", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            ComplexSpectrumFFI::error_result(code)
+        };
 
-            // Test above maximum (8192)
-            let result = compute_fft_rust(buffer.as_ptr(), 16384, 44100, 16384);
-            assert!(result.is_null(), "Should return null for FFT size > 8192");
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
         }
-    }
-
-    #[test]
-    fn test_compute_fft_valid_input_returns_non_null() {
-        // Generate a simple sine wave at 440 Hz
-        let sample_rate = 44100;
-        let frequency = 440.0;
-        let duration = 0.1; // 100ms
-        let num_samples = (sample_rate as f32 * duration) as usize;
 
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            buffer.push((2.0 * PI * frequency * t).sin());
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_fft_complex_checked(samples, fft_size) {
+            Ok((bins, fft_size)) => ComplexSpectrumFFI {
+                success: true,
+                bin_count: bins.len() / 2,
+                // Ownership passes to the caller until loqa_free_complex_spectrum
+                bins_ptr: Box::into_raw(bins.into_boxed_slice()) as *mut c_float,
+                fft_size: fft_size as u32,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
         }
+    })
+}
 
-        let fft_size = 2048;
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), num_samples as c_int, sample_rate, fft_size);
-            assert!(!result.is_null(), "Should return valid pointer");
+/// Computes the real signal whose one-sided spectrum is `bins`
+///
+/// Scaled by 1 / fft_size, so it inverts `loqa_compute_fft_complex`. The
+/// imaginary parts of the DC and Nyquist bins are ignored.
+///
+/// # Arguments
+/// * `bins` - Pointer to interleaved bins `[re0, im0, re1, im1, ...]`
+/// * `bin_count` - Number of complex bins: fft_size / 2 + 1 for a power of two
+///   fft_size in [64, 8192]
+///
+/// # Returns
+/// * InverseFFTResultFFI with success=true and fft_size allocated samples
+///
+/// # Safety
+/// * Caller must ensure `bins` points to valid memory of at least `2 * bin_count` floats
+/// * Caller MUST call `loqa_free_ifft_result` to deallocate the array
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_ifft(
+    bins: *const c_float,
+    bin_count: usize,
+) -> InverseFFTResultFFI {
+    ffi_guard("loqa_compute_ifft", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            InverseFFTResultFFI::error_result(code)
+        };
 
-            // Clean up memory (fft_size / 2 + 1)
-            free_fft_result_rust(result, (fft_size / 2) + 1);
+        if bins.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "bins pointer is null".into());
         }
-    }
-
-    #[test]
-    fn test_compute_fft_result_length() {
-        let buffer: Vec<f32> = vec![0.5; 2048];
-        let sample_rate = 44100;
-        let fft_size = 1024;
-        let expected_result_length = (fft_size / 2) + 1; // loqa-voice-dsp returns N/2 + 1
 
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), 2048, sample_rate, fft_size);
-            assert!(!result.is_null());
+        let Some(float_count) = bin_count.checked_mul(2) else {
+            return fail(LoqaErrorCode::InvalidLength, format!("bin_count {bin_count} is too large"));
+        };
+        match compute_ifft_checked(slice::from_raw_parts(bins, float_count)) {
+            Ok(samples) => InverseFFTResultFFI {
+                success: true,
+                length: samples.len(),
+                // Ownership passes to the caller until loqa_free_ifft_result
+                samples_ptr: Box::into_raw(samples.into_boxed_slice()) as *mut c_float,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
 
-            // Verify we can read the result (this tests memory safety)
-            let result_slice = slice::from_raw_parts(result, expected_result_length as usize);
-            assert_eq!(result_slice.len(), expected_result_length as usize);
+/// Free the array of a ComplexSpectrumFFI; nulls its pointer, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a ComplexSpectrumFFI from `loqa_compute_fft_complex`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_complex_spectrum(result: *mut ComplexSpectrumFFI) {
+    ffi_guard("loqa_free_complex_spectrum", || {
+        clear_last_error();
 
-            // All values should be finite (not NaN or Infinity)
-            for val in result_slice {
-                assert!(val.is_finite(), "FFT result should be finite");
-            }
+        let Some(res) = result.as_mut() else {
+            return;
+        };
 
-            // Clean up
-            free_fft_result_rust(result, expected_result_length);
+        if !res.bins_ptr.is_null() && res.bin_count > 0 {
+            let len = 2 * res.bin_count;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.bins_ptr, len));
         }
-    }
+        res.bins_ptr = std::ptr::null_mut();
+        res.bin_count = 0;
+    })
+}
 
-    #[test]
-    fn test_compute_fft_sine_wave_peak_detection() {
-        // Generate a pure sine wave at known frequency
-        let sample_rate = 44100;
-        let target_frequency = 1000.0; // 1 kHz
-        let fft_size = 4096;
-        let num_samples = fft_size;
+/// Free the array of an InverseFFTResultFFI; nulls its pointer, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to an InverseFFTResultFFI from `loqa_compute_ifft`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_ifft_result(result: *mut InverseFFTResultFFI) {
+    ffi_guard("loqa_free_ifft_result", || {
+        clear_last_error();
 
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            buffer.push((2.0 * PI * target_frequency * t).sin());
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        if !res.samples_ptr.is_null() && res.length > 0 {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.samples_ptr, res.length));
         }
+        res.samples_ptr = std::ptr::null_mut();
+        res.length = 0;
+    })
+}
 
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), num_samples as c_int, sample_rate, fft_size as c_int);
-            assert!(!result.is_null());
+// ============================================================================
+// Spectrogram FFI - short-time Fourier transform over a whole buffer
+// ============================================================================
 
-            let magnitude_len = (fft_size / 2) + 1;
-            let magnitude_slice = slice::from_raw_parts(result, magnitude_len);
+/// Value stored in each spectrogram cell (`SpectrogramConfigFFI::scaling`)
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaSpectrogramScaling {
+    /// Window-compensated single-sided amplitude
+    Linear = 0,
+    /// Squared amplitude
+    Power = 1,
+    /// 20 * log10(amplitude) dBFS, clamped below at `db_floor`
+    Decibels = 2,
+}
 
-            // Find the peak in the magnitude spectrum
-            let mut max_magnitude = 0.0_f32;
-            let mut max_index = 0;
-            for (i, &mag) in magnitude_slice.iter().enumerate() {
-                if mag > max_magnitude {
-                    max_magnitude = mag;
-                    max_index = i;
-                }
-            }
+impl LoqaSpectrogramScaling {
+    /// Converts a raw FFI value, returning None for unknown values
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(LoqaSpectrogramScaling::Linear),
+            1 => Some(LoqaSpectrogramScaling::Power),
+            2 => Some(LoqaSpectrogramScaling::Decibels),
+            _ => None,
+        }
+    }
+}
 
-            // Calculate the frequency of the peak
-            let peak_frequency = (max_index as f32) * (sample_rate as f32 / fft_size as f32);
+/// Configuration for `loqa_compute_spectrogram`
+///
+/// Obtain defaults from `loqa_spectrogram_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SpectrogramConfigFFI {
+    /// Samples per frame (= FFT size): power of two in [64, 8192]
+    pub frame_size: u32,
+    /// Samples between frame starts, in [1, frame_size]
+    pub hop_size: u32,
+    /// `LoqaWindowType` value
+    pub window: u32,
+    /// Kaiser beta or Gaussian sigma (0 selects the default); ignored by other windows
+    pub window_param: c_float,
+    /// `LoqaSpectrogramScaling` value
+    pub scaling: u32,
+    /// Lowest value in dB for `Decibels` scaling; ignored otherwise
+    pub db_floor: c_float,
+}
 
-            // The peak should be close to our target frequency (within 1 bin)
-            let frequency_resolution = sample_rate as f32 / fft_size as f32;
-            let frequency_error = (peak_frequency - target_frequency).abs();
+/// Returns the default spectrogram configuration: 2048-sample Hann frames every
+/// 512 samples, in dB with a -120 dB floor
+#[no_mangle]
+pub extern "C" fn loqa_spectrogram_config_default() -> SpectrogramConfigFFI {
+    SpectrogramConfigFFI {
+        frame_size: 2048,
+        hop_size: 512,
+        window: LoqaWindowType::Hann as u32,
+        window_param: 0.0,
+        scaling: LoqaSpectrogramScaling::Decibels as u32,
+        db_floor: -120.0,
+    }
+}
 
-            assert!(
-                frequency_error < frequency_resolution * 1.5,
-                "Peak frequency {peak_frequency} Hz should be close to target {target_frequency} Hz (error: {frequency_error} Hz)"
-            );
+/// Result structure for `loqa_compute_spectrogram`
+///
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_spectrogram` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SpectrogramFFI {
+    /// True if the spectrogram was computed
+    pub success: bool,
+    /// Row-major `frame_count x bin_count` matrix: frame i starts at `values_ptr[i * bin_count]`
+    pub values_ptr: *mut c_float,
+    /// Start of each frame in seconds from buffer start (same grid as `PitchTrackFFI`)
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames (rows)
+    pub frame_count: usize,
+    /// Bins per frame (frame_size / 2 + 1); bin k is centered on k * sample_rate / frame_size Hz
+    pub bin_count: usize,
+    pub frame_size: u32,
+    pub hop_size: u32,
+    pub sample_rate: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
 
-            free_fft_result_rust(result, ((fft_size / 2) + 1) as c_int);
+impl ErrorResult for SpectrogramFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        SpectrogramFFI {
+            success: false,
+            values_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            frame_count: 0,
+            bin_count: 0,
+            frame_size: 0,
+            hop_size: 0,
+            sample_rate: 0,
+            error_code: code,
         }
     }
+}
 
-    #[test]
-    fn test_free_fft_result_handles_null() {
-        // Should not crash
-        unsafe {
-            free_fft_result_rust(std::ptr::null_mut(), 256);
-        }
+/// Validates spectrogram inputs and runs `spectrogram::compute`
+///
+/// Shared by `loqa_compute_spectrogram` and the Android JNI bindings.
+pub(crate) fn compute_spectrogram_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &SpectrogramConfigFFI,
+) -> Result<spectrogram::Spectrogram, (LoqaErrorCode, String)> {
+    if sample_rate == 0 {
+        return Err((LoqaErrorCode::InvalidSampleRate, "sample_rate must be > 0, got 0".into()));
     }
 
-    #[test]
-    fn test_free_fft_result_handles_invalid_length() {
-        let buffer: Vec<f32> = vec![0.5; 1024];
-        unsafe {
-            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 512);
-            assert!(!result.is_null());
+    if config.frame_size == 0 {
+        return Err((LoqaErrorCode::InvalidFftSize, "frame_size must be > 0, got 0".into()));
+    }
+    validate_fft_size(config.frame_size)?;
+    let frame_size = config.frame_size as usize;
+
+    if !(1..=config.frame_size).contains(&config.hop_size) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "hop_size must be in range [1, frame_size = {}], got {}",
+                config.frame_size, config.hop_size
+            ),
+        ));
+    }
 
-            // These should handle gracefully (not crash)
-            free_fft_result_rust(result, 0);
+    let window = fft_window_from_raw(config.window, config.window_param)?;
+    let scaling = match LoqaSpectrogramScaling::from_raw(config.scaling) {
+        Some(LoqaSpectrogramScaling::Linear) => spectrogram::Scaling::Linear,
+        Some(LoqaSpectrogramScaling::Power) => spectrogram::Scaling::Power,
+        Some(LoqaSpectrogramScaling::Decibels) if config.db_floor.is_finite() => {
+            spectrogram::Scaling::Decibels { floor_db: config.db_floor }
         }
-        // Note: We've now leaked the memory, but that's ok for this test
-        // In production, free should be called with correct length
+        Some(LoqaSpectrogramScaling::Decibels) => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("db_floor must be finite, got {}", config.db_floor),
+            ))
+        }
+        None => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("scaling must be 0-2 (Linear/Power/Decibels), got {}", config.scaling),
+            ))
+        }
+    };
+
+    // Same frame grid as process_buffer: the buffer must extend past the first frame
+    if samples.len() <= frame_size {
+        return Err((
+            LoqaErrorCode::InvalidLength,
+            format!(
+                "length must be > frame_size ({frame_size}), got {}",
+                samples.len()
+            ),
+        ));
+    }
+
+    let hop_size = config.hop_size as usize;
+    let spectrogram = spectrogram::compute(samples, sample_rate, frame_size, hop_size, window, scaling);
+    loqa_log!(
+        Debug,
+        "spectrogram of {} samples: {} frames of {} bins (frame_size {frame_size}, hop_size {hop_size})",
+        samples.len(),
+        spectrogram.frame_count,
+        spectrogram.bin_count
+    );
+    Ok(spectrogram)
+}
+
+/// Computes a spectrogram of a whole buffer in one call
+///
+/// Every frame is windowed and scaled like `loqa_compute_fft_ex` with amplitude
+/// normalization. Frame i starts at sample i * hop_size, so with the same frame
+/// and hop sizes its timestamp matches entry i of `loqa_voice_analyzer_process_buffer`.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `frame_size`
+/// * `sample_rate` - Sample rate in Hz, used for the timestamps
+/// * `config` - Frame, hop, window and scaling (see `loqa_spectrogram_config_default`)
+///
+/// # Returns
+/// * SpectrogramFFI with success=true and allocated arrays if the spectrogram was computed
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_spectrogram` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_spectrogram(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: SpectrogramConfigFFI,
+) -> SpectrogramFFI {
+    ffi_guard("loqa_compute_spectrogram", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            SpectrogramFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_spectrogram_checked(samples, sample_rate, &config) {
+            // Ownership passes to the caller until loqa_free_spectrogram
+            Ok(spectrogram) => SpectrogramFFI {
+                success: true,
+                values_ptr: Box::into_raw(spectrogram.values.into_boxed_slice()) as *mut c_float,
+                timestamps_ptr: Box::into_raw(spectrogram.timestamps.into_boxed_slice())
+                    as *mut c_float,
+                frame_count: spectrogram.frame_count,
+                bin_count: spectrogram.bin_count,
+                frame_size: config.frame_size,
+                hop_size: config.hop_size,
+                sample_rate,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Free the arrays of a SpectrogramFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a SpectrogramFFI from `loqa_compute_spectrogram`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_spectrogram(result: *mut SpectrogramFFI) {
+    ffi_guard("loqa_free_spectrogram", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        if res.frame_count > 0 {
+            if !res.values_ptr.is_null() {
+                let len = res.frame_count * res.bin_count;
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.values_ptr, len));
+            }
+            if !res.timestamps_ptr.is_null() {
+                let len = res.frame_count;
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.timestamps_ptr, len));
+            }
+        }
+        res.values_ptr = std::ptr::null_mut();
+        res.timestamps_ptr = std::ptr::null_mut();
+        res.frame_count = 0;
+    })
+}
+
+// ============================================================================
+// Mel features FFI - log-mel spectrogram and MFCC
+// ============================================================================
+
+/// Largest number of mel bands accepted by `MelConfigFFI::n_mels`
+const MAX_MEL_BANDS: u32 = 256;
+
+/// Mel scale and filter normalization (`MelConfigFFI::mel_scale`)
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaMelScale {
+    /// 2595 * log10(1 + f / 700) with unit-height filters
+    Htk = 0,
+    /// Slaney's linear/log scale with area-normalized filters (librosa's default)
+    Slaney = 1,
+}
+
+impl LoqaMelScale {
+    /// Converts a raw FFI value, returning None for unknown values
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(LoqaMelScale::Htk),
+            1 => Some(LoqaMelScale::Slaney),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration for `loqa_compute_log_mel_spectrogram` and `loqa_compute_mfcc`
+///
+/// Obtain defaults from `loqa_mel_config_default` and override fields. The MFCC
+/// fields are ignored by `loqa_compute_log_mel_spectrogram`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct MelConfigFFI {
+    /// Samples per frame (= FFT size): power of two in [64, 8192]
+    pub frame_size: u32,
+    /// Samples between frame starts, in [1, frame_size]
+    pub hop_size: u32,
+    /// `LoqaWindowType` value
+    pub window: u32,
+    /// Kaiser beta or Gaussian sigma (0 selects the default); ignored by other windows
+    pub window_param: c_float,
+    /// Number of mel bands, in [1, 256]
+    pub n_mels: u32,
+    /// Lower edge of the first band in Hz
+    pub fmin: c_float,
+    /// Upper edge of the last band in Hz (0 = Nyquist)
+    pub fmax: c_float,
+    /// `LoqaMelScale` value
+    pub mel_scale: u32,
+    /// Number of cepstral coefficients, in [1, n_mels]
+    pub n_mfcc: u32,
+    /// Sinusoidal lifter length (0 = no liftering)
+    pub lifter: c_float,
+    /// 0 = static coefficients, 1 = plus deltas, 2 = plus deltas and delta-deltas
+    pub delta_order: u32,
+    /// Frames on each side used by the delta regression (>= 1)
+    pub delta_width: u32,
+}
+
+/// Returns the default mel configuration: 2048-sample Hann frames every 512
+/// samples, 40 Slaney bands up to Nyquist and 13 unliftered MFCCs without deltas
+#[no_mangle]
+pub extern "C" fn loqa_mel_config_default() -> MelConfigFFI {
+    MelConfigFFI {
+        frame_size: 2048,
+        hop_size: 512,
+        window: LoqaWindowType::Hann as u32,
+        window_param: 0.0,
+        n_mels: 40,
+        fmin: 0.0,
+        fmax: 0.0,
+        mel_scale: LoqaMelScale::Slaney as u32,
+        n_mfcc: 13,
+        lifter: 0.0,
+        delta_order: 0,
+        delta_width: 2,
+    }
+}
+
+/// Framewise feature matrix returned by `loqa_compute_log_mel_spectrogram` and
+/// `loqa_compute_mfcc`
+///
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_feature_matrix` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FeatureMatrixFFI {
+    /// True if the features were computed
+    pub success: bool,
+    /// Row-major `frame_count x feature_count` matrix: frame i starts at `values_ptr[i * feature_count]`
+    pub values_ptr: *mut c_float,
+    /// Start of each frame in seconds from buffer start (same grid as `PitchTrackFFI`)
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames (rows)
+    pub frame_count: usize,
+    /// Values per frame
+    pub feature_count: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for FeatureMatrixFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        FeatureMatrixFFI {
+            success: false,
+            values_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            frame_count: 0,
+            feature_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Row-major framewise features plus frame timestamps
+#[derive(Debug, Clone)]
+pub(crate) struct FeatureMatrix {
+    pub values: Vec<f32>,
+    pub timestamps: Vec<f32>,
+    pub feature_count: usize,
+}
+
+impl FeatureMatrix {
+    /// Moves the matrix into a successful FFI result owned by the caller
+    fn into_ffi(self) -> FeatureMatrixFFI {
+        FeatureMatrixFFI {
+            success: true,
+            frame_count: self.timestamps.len(),
+            feature_count: self.feature_count,
+            // Ownership passes to the caller until loqa_free_feature_matrix
+            values_ptr: Box::into_raw(self.values.into_boxed_slice()) as *mut c_float,
+            timestamps_ptr: Box::into_raw(self.timestamps.into_boxed_slice()) as *mut c_float,
+            error_code: LoqaErrorCode::Ok,
+        }
+    }
+}
+
+/// Validates the mel fields of `config` and computes the log-mel frames
+///
+/// Shared by `loqa_compute_log_mel_spectrogram`, `compute_mfcc_checked` and the
+/// Android JNI bindings.
+pub(crate) fn compute_log_mel_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &MelConfigFFI,
+) -> Result<FeatureMatrix, (LoqaErrorCode, String)> {
+    if sample_rate == 0 {
+        return Err((LoqaErrorCode::InvalidSampleRate, "sample_rate must be > 0, got 0".into()));
+    }
+
+    if !(1..=MAX_MEL_BANDS).contains(&config.n_mels) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("n_mels must be in range [1, {MAX_MEL_BANDS}], got {}", config.n_mels),
+        ));
+    }
+
+    let nyquist = sample_rate as f32 / 2.0;
+    let fmax = if config.fmax == 0.0 { nyquist } else { config.fmax };
+    if !(config.fmin >= 0.0 && fmax > config.fmin && fmax <= nyquist) {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "invalid mel frequency range: fmin={}, fmax={} (must satisfy 0 <= fmin < fmax <= {nyquist})",
+                config.fmin, config.fmax
+            ),
+        ));
+    }
+
+    let scale = match LoqaMelScale::from_raw(config.mel_scale) {
+        Some(LoqaMelScale::Htk) => mel::MelScale::Htk,
+        Some(LoqaMelScale::Slaney) => mel::MelScale::Slaney,
+        None => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("mel_scale must be 0-1 (HTK/Slaney), got {}", config.mel_scale),
+            ))
+        }
+    };
+
+    // Frame, hop, window and buffer length rules are the spectrogram's
+    let spectrogram_config = SpectrogramConfigFFI {
+        frame_size: config.frame_size,
+        hop_size: config.hop_size,
+        window: config.window,
+        window_param: config.window_param,
+        scaling: LoqaSpectrogramScaling::Power as u32,
+        db_floor: 0.0,
+    };
+    let power = compute_spectrogram_checked(samples, sample_rate, &spectrogram_config)?;
+
+    let n_mels = config.n_mels as usize;
+    let filters = mel::filterbank(
+        n_mels,
+        config.frame_size as usize,
+        sample_rate,
+        config.fmin,
+        fmax,
+        scale,
+    );
+    Ok(FeatureMatrix {
+        values: mel::log_mel(&power.values, power.bin_count, &filters),
+        timestamps: power.timestamps,
+        feature_count: n_mels,
+    })
+}
+
+/// Validates `config` and computes MFCCs (plus deltas as configured)
+///
+/// Shared by `loqa_compute_mfcc` and the Android JNI bindings.
+pub(crate) fn compute_mfcc_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &MelConfigFFI,
+) -> Result<FeatureMatrix, (LoqaErrorCode, String)> {
+    if !(1..=config.n_mels).contains(&config.n_mfcc) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "n_mfcc must be in range [1, n_mels = {}], got {}",
+                config.n_mels, config.n_mfcc
+            ),
+        ));
+    }
+
+    if !(config.lifter.is_finite() && config.lifter >= 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("lifter must be finite and >= 0, got {}", config.lifter),
+        ));
+    }
+
+    if config.delta_order > 2 {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("delta_order must be 0-2, got {}", config.delta_order),
+        ));
+    }
+
+    if config.delta_width == 0 {
+        return Err((LoqaErrorCode::InvalidArgument, "delta_width must be >= 1, got 0".into()));
+    }
+
+    let log_mel = compute_log_mel_checked(samples, sample_rate, config)?;
+    let options = mel::MfccOptions {
+        n_mfcc: config.n_mfcc as usize,
+        lifter: config.lifter,
+        delta_order: config.delta_order as usize,
+        delta_width: config.delta_width as usize,
+    };
+    Ok(FeatureMatrix {
+        values: mel::mfcc(&log_mel.values, log_mel.feature_count, options),
+        timestamps: log_mel.timestamps,
+        feature_count: options.n_mfcc * (1 + options.delta_order),
+    })
+}
+
+/// Computes the log-mel spectrogram of a whole buffer
+///
+/// Band energies are the mel-weighted power spectrum of each frame (a sine of
+/// amplitude A contributes A^2), in dB with a -100 dB floor. Frames follow
+/// `loqa_compute_spectrogram`.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `frame_size`
+/// * `sample_rate` - Sample rate in Hz
+/// * `config` - Framing and filterbank (see `loqa_mel_config_default`)
+///
+/// # Returns
+/// * FeatureMatrixFFI with `n_mels` values per frame
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_feature_matrix` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_log_mel_spectrogram(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: MelConfigFFI,
+) -> FeatureMatrixFFI {
+    ffi_guard("loqa_compute_log_mel_spectrogram", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FeatureMatrixFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_log_mel_checked(samples, sample_rate, &config) {
+            Ok(features) => features.into_ffi(),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Computes MFCCs of a whole buffer, optionally with deltas and delta-deltas
+///
+/// Each row holds `n_mfcc` coefficients (DCT-II of the log-mel frame, liftered
+/// if `lifter` > 0), followed by their deltas and delta-deltas when
+/// `delta_order` asks for them.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `frame_size`
+/// * `sample_rate` - Sample rate in Hz
+/// * `config` - Framing, filterbank and cepstral options (see `loqa_mel_config_default`)
+///
+/// # Returns
+/// * FeatureMatrixFFI with `n_mfcc * (1 + delta_order)` values per frame
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_feature_matrix` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_mfcc(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: MelConfigFFI,
+) -> FeatureMatrixFFI {
+    ffi_guard("loqa_compute_mfcc", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FeatureMatrixFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_mfcc_checked(samples, sample_rate, &config) {
+            Ok(features) => features.into_ffi(),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Free the arrays of a FeatureMatrixFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a FeatureMatrixFFI from one of the feature exports
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_feature_matrix(result: *mut FeatureMatrixFFI) {
+    ffi_guard("loqa_free_feature_matrix", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        if res.frame_count > 0 {
+            if !res.values_ptr.is_null() {
+                let len = res.frame_count * res.feature_count;
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.values_ptr, len));
+            }
+            if !res.timestamps_ptr.is_null() {
+                let len = res.frame_count;
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.timestamps_ptr, len));
+            }
+        }
+        res.values_ptr = std::ptr::null_mut();
+        res.timestamps_ptr = std::ptr::null_mut();
+        res.frame_count = 0;
+    })
+}
+
+// ============================================================================
+// Extended spectral analysis FFI - FFT size, frame averaging, rolloff thresholds
+// ============================================================================
+
+/// Configuration for `loqa_analyze_spectrum_ex` (see `loqa_spectrum_config_default`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SpectrumConfigFFI {
+    /// FFT size: a power of two in [64, 8192], or 0 for the smallest one holding
+    /// the buffer (capped at 8192)
+    pub fft_size: u32,
+    /// Average the power spectra of 50%-overlapping frames when the buffer is
+    /// longer than `fft_size`; otherwise only the first `fft_size` samples are used
+    pub average_frames: bool,
+    /// Energy fraction for `rolloff_low`, in (0, 1]
+    pub rolloff_low_threshold: c_float,
+    /// Energy fraction for `rolloff_high`, in [`rolloff_low_threshold`, 1]
+    pub rolloff_high_threshold: c_float,
+}
+
+/// Returns the configuration `analyze_spectrum_rust` uses: automatic FFT size,
+/// frame averaging, and 85%/95% rolloff
+#[no_mangle]
+pub extern "C" fn loqa_spectrum_config_default() -> SpectrumConfigFFI {
+    SpectrumConfigFFI {
+        fft_size: 0,
+        average_frames: true,
+        rolloff_low_threshold: 0.85,
+        rolloff_high_threshold: 0.95,
+    }
+}
+
+/// Result structure for `loqa_analyze_spectrum_ex`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SpectralFeaturesExFFI {
+    /// True if the analysis ran
+    pub success: bool,
+    /// Magnitude-weighted mean frequency in Hz
+    pub centroid: c_float,
+    /// Standard deviation of frequency around the centroid in Hz
+    pub spread: c_float,
+    /// Asymmetry around the centroid (positive = tail towards high frequencies)
+    pub skewness: c_float,
+    /// Peakedness around the centroid (3.0 for a Gaussian-shaped spectrum)
+    pub kurtosis: c_float,
+    /// 0.0 (tonal) to 1.0 (white noise)
+    pub flatness: c_float,
+    /// Normalized spectral entropy, 0.0 (one bin) to 1.0 (flat)
+    pub entropy: c_float,
+    /// Peak magnitude over mean magnitude
+    pub crest_factor: c_float,
+    /// Rolloff frequency at `rolloff_low_threshold` in Hz
+    pub rolloff_low: c_float,
+    /// Rolloff frequency at `rolloff_high_threshold` in Hz
+    pub rolloff_high: c_float,
+    /// Spectral tilt in dB/octave
+    pub tilt: c_float,
+    /// FFT size actually used
+    pub fft_size: u32,
+    /// Number of frames averaged
+    pub frame_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for SpectralFeaturesExFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        SpectralFeaturesExFFI {
+            success: false,
+            centroid: 0.0,
+            spread: 0.0,
+            skewness: 0.0,
+            kurtosis: 0.0,
+            flatness: 0.0,
+            entropy: 0.0,
+            crest_factor: 0.0,
+            rolloff_low: 0.0,
+            rolloff_high: 0.0,
+            tilt: 0.0,
+            fft_size: 0,
+            frame_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Analyzes the spectrum of a buffer of any length with a caller-chosen configuration
+///
+/// Unlike `loqa_analyze_spectrum`, which needs an `FFTResultFFI` of a
+/// power-of-two size, this takes the samples directly.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples in input buffer
+/// * `sample_rate` - Sample rate in Hz (must be 8000-48000 Hz)
+/// * `config` - FFT size, averaging and rolloff thresholds
+///
+/// # Returns
+/// * SpectralFeaturesExFFI with success=true if the analysis ran
+/// * On error, `error_code` is set (`InvalidFftSize`, `InvalidArgument` for
+///   bad rolloff thresholds, ...)
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Buffer must remain valid for the duration of this function call
+#[no_mangle]
+pub unsafe extern "C" fn loqa_analyze_spectrum_ex(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: SpectrumConfigFFI,
+) -> SpectralFeaturesExFFI {
+    ffi_guard("loqa_analyze_spectrum_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            SpectralFeaturesExFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        // Out-of-range rates are rejected by the shared validation
+        let sample_rate = c_int::try_from(sample_rate).unwrap_or(c_int::MAX);
+        let samples = slice::from_raw_parts(buffer, length);
+
+        match analyze_spectrum_checked(samples, sample_rate, &config) {
+            Ok(analysis) => {
+                let d = analysis.descriptors;
+                SpectralFeaturesExFFI {
+                    success: true,
+                    centroid: d.centroid,
+                    spread: d.spread,
+                    skewness: d.skewness,
+                    kurtosis: d.kurtosis,
+                    flatness: d.flatness,
+                    entropy: d.entropy,
+                    crest_factor: d.crest_factor,
+                    rolloff_low: d.rolloff_low,
+                    rolloff_high: d.rolloff_high,
+                    tilt: d.tilt,
+                    fft_size: analysis.fft_size as u32,
+                    frame_count: analysis.frame_count as u32,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// VoiceAnalyzer lifecycle FFI - create, configure, reset and free
+// ============================================================================
+
+/// Pitch algorithm selector for `AnalysisConfigFFI::algorithm` and `loqa_detect_pitch_ex`
+///
+/// Both carry the selector as a plain `u32` to match the C header;
+/// these are the only accepted values.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaPitchAlgorithm {
+    /// Tries pYIN, then YIN, then autocorrelation (recommended)
+    Auto = 0,
+    /// Probabilistic YIN only (best for noisy/breathy voice)
+    Pyin = 1,
+    /// Classic YIN only (fast, accurate for clean signals)
+    Yin = 2,
+    /// Autocorrelation only (fallback for very noisy signals)
+    Autocorr = 3,
+}
+
+impl LoqaPitchAlgorithm {
+    /// Parses a raw selector value
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Auto),
+            1 => Some(Self::Pyin),
+            2 => Some(Self::Yin),
+            3 => Some(Self::Autocorr),
+            _ => None,
+        }
+    }
+}
+
+impl From<LoqaPitchAlgorithm> for loqa_voice_dsp::PitchAlgorithm {
+    fn from(algorithm: LoqaPitchAlgorithm) -> Self {
+        match algorithm {
+            LoqaPitchAlgorithm::Auto => Self::Auto,
+            LoqaPitchAlgorithm::Pyin => Self::PYIN,
+            LoqaPitchAlgorithm::Yin => Self::YIN,
+            LoqaPitchAlgorithm::Autocorr => Self::Autocorr,
+        }
+    }
+}
+
+/// Parses a raw algorithm selector, reporting unknown values as `InvalidArgument`
+pub(crate) fn pitch_algorithm_from_raw(
+    value: u32,
+) -> Result<LoqaPitchAlgorithm, (LoqaErrorCode, String)> {
+    LoqaPitchAlgorithm::from_raw(value).ok_or_else(|| {
+        (
+            LoqaErrorCode::InvalidArgument,
+            format!("algorithm must be 0-3 (Auto/PYIN/YIN/Autocorr), got {value}"),
+        )
+    })
+}
+
+/// Converts and validates an FFI analyzer config
+///
+/// Checks everything upstream `loqa_voice_analyzer_new` would reject (plus an
+/// unknown algorithm selector, which upstream silently maps to Auto) and reports the
+/// first problem with a precise error code.
+pub(crate) fn analysis_config_from_ffi(
+    config: &AnalysisConfigFFI,
+) -> Result<loqa_voice_dsp::AnalysisConfig, (LoqaErrorCode, String)> {
+    if !(8000..=48000).contains(&config.sample_rate) {
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!(
+                "sample_rate must be in range [8000, 48000] Hz, got {}",
+                config.sample_rate
+            ),
+        ));
+    }
+
+    let nyquist = config.sample_rate as f32 / 2.0;
+    if !(config.min_frequency > 0.0
+        && config.max_frequency > config.min_frequency
+        && config.max_frequency <= nyquist)
+    {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "invalid frequency range: min={}, max={} (must satisfy 0 < min < max <= {nyquist})",
+                config.min_frequency, config.max_frequency
+            ),
+        ));
+    }
+
+    let algorithm = pitch_algorithm_from_raw(config.algorithm)?;
+
+    let rust_config = loqa_voice_dsp::AnalysisConfig {
+        sample_rate: config.sample_rate,
+        frame_size: config.frame_size as usize,
+        hop_size: config.hop_size as usize,
+        min_frequency: config.min_frequency,
+        max_frequency: config.max_frequency,
+        algorithm: algorithm.into(),
+        threshold: config.threshold,
+        min_confidence: config.min_confidence,
+        interpolate: config.interpolate,
+    };
+
+    // Frame/hop size, threshold and confidence rules live upstream
+    rust_config
+        .validate()
+        .map_err(|e| (LoqaErrorCode::InvalidArgument, e))?;
+
+    Ok(rust_config)
+}
+
+/// Validates a VoiceAnalyzer configuration without creating an analyzer
+///
+/// `loqa_voice_analyzer_create` runs the same checks; use this to check a config
+/// up front, e.g. while the user is editing settings.
+///
+/// # Arguments
+/// * `config` - Analyzer configuration (see `loqa_analysis_config_default`)
+///
+/// # Returns
+/// * `LoqaErrorCode::Ok` if `loqa_voice_analyzer_create` will accept the config
+/// * Otherwise the error code; `loqa_last_error_message` holds the reason
+#[no_mangle]
+pub extern "C" fn loqa_analysis_config_validate(config: AnalysisConfigFFI) -> LoqaErrorCode {
+    ffi_guard("loqa_analysis_config_validate", || {
+        clear_last_error();
+
+        match analysis_config_from_ffi(&config) {
+            Ok(_) => LoqaErrorCode::Ok,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                code
+            }
+        }
+    })
+}
+
+/// Validates an FFI analyzer config and creates a `VoiceAnalyzer` for it
+///
+/// Shared by `loqa_voice_analyzer_create` and the Android JNI bindings.
+pub(crate) fn voice_analyzer_new_checked(
+    config: &AnalysisConfigFFI,
+) -> Result<loqa_voice_dsp::VoiceAnalyzer, (LoqaErrorCode, String)> {
+    let config = analysis_config_from_ffi(config)?;
+    loqa_voice_dsp::VoiceAnalyzer::new(config).map_err(|e| {
+        (
+            LoqaErrorCode::AnalysisFailed,
+            format!("VoiceAnalyzer creation failed: {e}"),
+        )
+    })
+}
+
+/// Creates a VoiceAnalyzer, recording why a configuration is rejected
+///
+/// This is the entry point for new code. Upstream's `loqa_voice_analyzer_new`
+/// returns null without a reason and silently maps an unknown algorithm to
+/// Auto; it is only kept for ABI compatibility. This export applies the same
+/// checks as `loqa_analysis_config_validate`, so no separate call is needed.
+///
+/// # Arguments
+/// * `config` - Analyzer configuration (see `loqa_analysis_config_default`)
+///
+/// # Returns
+/// * Opaque analyzer pointer, usable with every `loqa_voice_analyzer_*` function;
+///   free it with `loqa_voice_analyzer_free_ex`
+/// * Null if the config is invalid; `loqa_last_error_code` / `loqa_last_error_message`
+///   hold the reason
+#[no_mangle]
+pub extern "C" fn loqa_voice_analyzer_create(config: AnalysisConfigFFI) -> *mut std::ffi::c_void {
+    ffi_guard("loqa_voice_analyzer_create", || {
+        clear_last_error();
+
+        match voice_analyzer_new_checked(&config) {
+            Ok(analyzer) => Box::into_raw(Box::new(analyzer)) as *mut std::ffi::c_void,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Reborrows the VoiceAnalyzer behind a pointer from `loqa_voice_analyzer_create`
+///
+/// # Safety
+/// * `analyzer` must be null or a live pointer from `loqa_voice_analyzer_create`
+///   (or upstream's `loqa_voice_analyzer_new`)
+unsafe fn voice_analyzer_from_ptr<'a>(
+    analyzer: *mut std::ffi::c_void,
+) -> Result<&'a mut loqa_voice_dsp::VoiceAnalyzer, (LoqaErrorCode, String)> {
+    (analyzer as *mut loqa_voice_dsp::VoiceAnalyzer)
+        .as_mut()
+        .ok_or_else(|| (LoqaErrorCode::NullPointer, "analyzer pointer is null".into()))
+}
+
+/// Copies one analyzer frame into the C result, unmodified
+fn pitch_result_ex(result: &loqa_voice_dsp::PitchResult) -> PitchResultExFFI {
+    PitchResultExFFI {
+        success: true,
+        frequency: result.frequency,
+        confidence: result.confidence,
+        is_voiced: result.is_voiced,
+        voiced_probability: result.voiced_probability,
+        error_code: LoqaErrorCode::Ok,
+    }
+}
+
+/// Process a single frame with the VoiceAnalyzer, reporting why it failed
+///
+/// Panic-safe replacement for upstream's `loqa_voice_analyzer_process_frame`.
+///
+/// # Returns
+/// * PitchResultExFFI with success=true for the frame
+/// * success=false and the reason in `error_code` on error
+///
+/// # Safety
+/// * `analyzer` must be null or a live pointer from `loqa_voice_analyzer_create`
+/// * `samples` must point to valid f32 array of length `len`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_voice_analyzer_process_frame_ex(
+    analyzer: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+) -> PitchResultExFFI {
+    ffi_guard("loqa_voice_analyzer_process_frame_ex", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchResultExFFI::error_result(code)
+        };
+
+        let analyzer = match voice_analyzer_from_ptr(analyzer) {
+            Ok(analyzer) => analyzer,
+            Err((code, message)) => return fail(code, message),
+        };
+
+        if samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        if len == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        match analyzer.process_frame(slice::from_raw_parts(samples, len)) {
+            Ok(result) => pitch_result_ex(&result),
+            Err(e) => fail(LoqaErrorCode::AnalysisFailed, format!("process_frame failed: {e}")),
+        }
+    })
+}
+
+/// Process streaming audio, writing one result per frame to `results_out`
+///
+/// Panic-safe replacement for upstream's `loqa_voice_analyzer_process_stream`.
+/// Frames are independent; see `loqa_voice_analyzer_process_buffer` for a
+/// Viterbi-smoothed track.
+///
+/// # Returns
+/// * Number of results written (at most `max_results`; frames beyond it are dropped)
+/// * 0 on error, with the reason in `loqa_last_error_code` / `loqa_last_error_message`
+///   (a buffer shorter than one frame also returns 0 but leaves `LOQA_OK`)
+///
+/// # Safety
+/// * `analyzer` must be null or a live pointer from `loqa_voice_analyzer_create`
+/// * `samples` must point to valid f32 array of length `len`
+/// * `results_out` must point to writable memory for `max_results` results
+#[no_mangle]
+pub unsafe extern "C" fn loqa_voice_analyzer_process_stream_ex(
+    analyzer: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+    results_out: *mut PitchResultExFFI,
+    max_results: usize,
+) -> usize {
+    ffi_guard("loqa_voice_analyzer_process_stream_ex", || {
+        clear_last_error();
+
+        // Records the error; nothing was written
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            0
+        };
+
+        let analyzer = match voice_analyzer_from_ptr(analyzer) {
+            Ok(analyzer) => analyzer,
+            Err((code, message)) => return fail(code, message),
+        };
+
+        if samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        if results_out.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "results_out pointer is null".into());
+        }
+
+        if len == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        let results = analyzer.process_stream(slice::from_raw_parts(samples, len));
+        let output = slice::from_raw_parts_mut(results_out, results.len().min(max_results));
+        for (slot, result) in output.iter_mut().zip(&results) {
+            *slot = pitch_result_ex(result);
+        }
+        output.len()
+    })
+}
+
+/// Reset the VoiceAnalyzer state; null is ignored
+///
+/// # Safety
+/// * `analyzer` must be null or a live pointer from `loqa_voice_analyzer_create`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_voice_analyzer_reset_ex(analyzer: *mut std::ffi::c_void) {
+    ffi_guard("loqa_voice_analyzer_reset_ex", || {
+        clear_last_error();
+
+        if let Ok(analyzer) = voice_analyzer_from_ptr(analyzer) {
+            analyzer.reset();
+        }
+    })
+}
+
+/// Free a VoiceAnalyzer; null is ignored
+///
+/// # Safety
+/// * `analyzer` must be null or a pointer from `loqa_voice_analyzer_create` that
+///   has not been freed
+#[no_mangle]
+pub unsafe extern "C" fn loqa_voice_analyzer_free_ex(analyzer: *mut std::ffi::c_void) {
+    ffi_guard("loqa_voice_analyzer_free_ex", || {
+        clear_last_error();
+
+        if !analyzer.is_null() {
+            drop(Box::from_raw(analyzer as *mut loqa_voice_dsp::VoiceAnalyzer));
+        }
+    })
+}
+
+// ============================================================================
+// VoiceAnalyzer process_buffer FFI - HMM-smoothed pitch tracking (v0.5.0)
+// ============================================================================
+
+/// C-compatible PitchTrack result from Viterbi decoding
+///
+/// Contains smoothed pitch track, voiced probabilities, and timestamps.
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_pitch_track` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PitchTrackFFI {
+    /// True if processing succeeded
+    pub success: bool,
+    /// Pointer to pitch estimates array (Hz, 0.0 = unvoiced)
+    pub pitch_track_ptr: *mut c_float,
+    /// Pointer to voiced probabilities array [0.0, 1.0]
+    pub voiced_probs_ptr: *mut c_float,
+    /// Pointer to timestamps array (seconds from buffer start)
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames (length of all three arrays)
+    pub length: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for PitchTrackFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        PitchTrackFFI {
+            success: false,
+            pitch_track_ptr: std::ptr::null_mut(),
+            voiced_probs_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            length: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Process audio buffer with HMM-smoothed Viterbi decoding
+///
+/// Unlike `process_stream` which treats frames independently, this method uses
+/// Viterbi decoding to find the globally optimal pitch track across all frames,
+/// reducing octave errors (from ~8-12% to ≤3%) and producing smoother contours.
+///
+/// This is best suited for offline analysis of complete utterances
+/// (typically < 60 seconds). For longer recordings, segment into utterances first.
+///
+/// **Note:** Always uses pYIN algorithm regardless of config settings, since
+/// HMM smoothing requires the probabilistic candidates that only pYIN provides.
+///
+/// # Arguments
+/// * `analyzer` - Pointer to VoiceAnalyzer from `loqa_voice_analyzer_create`
+/// * `samples` - Pointer to audio samples (Float32 array)
+/// * `len` - Number of samples in buffer
+///
+/// # Returns
+/// * PitchTrackFFI with success=true and allocated arrays if processing succeeded
+/// * Caller MUST call `loqa_free_pitch_track` to deallocate arrays
+///
+/// # Safety
+/// * `analyzer` must be a valid pointer from `loqa_voice_analyzer_create`
+/// * `samples` must point to valid f32 array of length `len`
+/// * Caller MUST call `loqa_free_pitch_track` to avoid memory leaks
+#[no_mangle]
+pub unsafe extern "C" fn loqa_voice_analyzer_process_buffer(
+    analyzer: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+) -> PitchTrackFFI {
+    ffi_guard("loqa_voice_analyzer_process_buffer", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchTrackFFI::error_result(code)
+        };
+
+        // Null pointer checks
+        if analyzer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "analyzer pointer is null".into());
+        }
+
+        if samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        if len == 0 {
+            return fail(LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into());
+        }
+
+        // Cast back to VoiceAnalyzer
+        let analyzer_ref = &mut *(analyzer as *mut loqa_voice_dsp::VoiceAnalyzer);
+        let samples_slice = slice::from_raw_parts(samples, len);
+
+        // Call process_buffer for Viterbi-smoothed pitch track
+        match analyzer_ref.process_buffer(samples_slice) {
+            Ok(track) => {
+                let frame_count = track.pitch_track.len();
+
+                // Allocate C-compatible arrays
+                let mut pitch_vec = track.pitch_track.into_boxed_slice();
+                let mut probs_vec = track.voiced_probabilities.into_boxed_slice();
+                let mut times_vec = track.timestamps.into_boxed_slice();
+
+                let pitch_ptr = pitch_vec.as_mut_ptr();
+                let probs_ptr = probs_vec.as_mut_ptr();
+                let times_ptr = times_vec.as_mut_ptr();
+
+                // Prevent Rust from freeing the memory (caller will free via loqa_free_pitch_track)
+                std::mem::forget(pitch_vec);
+                std::mem::forget(probs_vec);
+                std::mem::forget(times_vec);
+
+                PitchTrackFFI {
+                    success: true,
+                    pitch_track_ptr: pitch_ptr,
+                    voiced_probs_ptr: probs_ptr,
+                    timestamps_ptr: times_ptr,
+                    length: frame_count,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err(e) => fail(
+                LoqaErrorCode::AnalysisFailed,
+                format!("process_buffer failed: {e}"),
+            ),
+        }
+    })
+}
+
+/// Free PitchTrackFFI arrays allocated by `loqa_voice_analyzer_process_buffer`
+///
+/// # Safety
+/// * `result` must point to valid PitchTrackFFI from `loqa_voice_analyzer_process_buffer`
+/// * Must be called exactly once per successful `loqa_voice_analyzer_process_buffer` call
+/// * After calling this, the pointers in `result` are invalid
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_pitch_track(result: *mut PitchTrackFFI) {
+    ffi_guard("loqa_free_pitch_track", || {
+        clear_last_error();
+
+        if result.is_null() {
+            return;
+        }
+
+        let res = &*result;
+
+        // Free each array if non-null
+        if !res.pitch_track_ptr.is_null() && res.length > 0 {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.pitch_track_ptr, res.length));
+        }
+
+        if !res.voiced_probs_ptr.is_null() && res.length > 0 {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.voiced_probs_ptr, res.length));
+        }
+
+        if !res.timestamps_ptr.is_null() && res.length > 0 {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.timestamps_ptr, res.length));
+        }
+    })
+}
+
+// ============================================================================
+// Pitch track post-processing FFI - octave correction, gap filling, smoothing
+// ============================================================================
+
+/// Smoothing filter selector for `PitchPostprocessConfigFFI::smoothing`
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoqaPitchSmoothing {
+    /// Leave the track unsmoothed
+    None = 0,
+    /// Running median (removes isolated outliers, keeps steps sharp)
+    Median = 1,
+    /// Running mean (removes jitter, rounds off steps)
+    MovingAverage = 2,
+}
+
+impl LoqaPitchSmoothing {
+    /// Parses a raw selector value
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::None),
+            1 => Some(Self::Median),
+            2 => Some(Self::MovingAverage),
+            _ => None,
+        }
+    }
+}
+
+/// Largest octave or smoothing window in frames
+const MAX_PITCH_WINDOW_FRAMES: u32 = 1001;
+
+/// Configuration for `loqa_postprocess_pitch_track`
+///
+/// Obtain defaults from `loqa_pitch_postprocess_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PitchPostprocessConfigFFI {
+    /// Move frames an octave or two away from their neighbours back into line
+    pub correct_octave_jumps: bool,
+    /// Frames whose median voiced pitch is the octave reference (odd, 3-1001)
+    pub octave_window: u32,
+    /// `LoqaPitchSmoothing` value
+    pub smoothing: u32,
+    /// Frames in the smoothing window (odd, 1-1001; 1 disables smoothing)
+    pub smoothing_window: u32,
+    /// Longest run of unvoiced frames filled by interpolation (0 disables it)
+    pub max_gap_frames: u32,
+}
+
+/// Returns the default post-processing configuration: octave correction over
+/// 11 frames, interpolation across gaps of up to 5 frames (about 60 ms at the
+/// default hop) and a 5-frame median
+#[no_mangle]
+pub extern "C" fn loqa_pitch_postprocess_config_default() -> PitchPostprocessConfigFFI {
+    PitchPostprocessConfigFFI {
+        correct_octave_jumps: true,
+        octave_window: 11,
+        smoothing: LoqaPitchSmoothing::Median as u32,
+        smoothing_window: 5,
+        max_gap_frames: 5,
+    }
+}
+
+/// Validates `config` and post-processes `pitch_track`
+///
+/// Shared by `loqa_postprocess_pitch_track` and the Android JNI bindings.
+pub(crate) fn postprocess_pitch_track_checked(
+    pitch_track: &[f32],
+    config: &PitchPostprocessConfigFFI,
+) -> Result<Vec<f32>, (LoqaErrorCode, String)> {
+    if pitch_track.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
+
+    let smoothing = match LoqaPitchSmoothing::from_raw(config.smoothing) {
+        Some(LoqaPitchSmoothing::None) => pitch_track::Smoothing::None,
+        Some(LoqaPitchSmoothing::Median) => pitch_track::Smoothing::Median,
+        Some(LoqaPitchSmoothing::MovingAverage) => pitch_track::Smoothing::MovingAverage,
+        None => {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!(
+                    "smoothing must be 0-2 (None/Median/MovingAverage), got {}",
+                    config.smoothing
+                ),
+            ))
+        }
+    };
+
+    for (name, value, min) in [
+        ("octave_window", config.octave_window, 3),
+        ("smoothing_window", config.smoothing_window, 1),
+    ] {
+        if value % 2 == 0 || !(min..=MAX_PITCH_WINDOW_FRAMES).contains(&value) {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!(
+                    "{name} must be odd and in range [{min}, {MAX_PITCH_WINDOW_FRAMES}], \
+                     got {value}"
+                ),
+            ));
+        }
+    }
+
+    if let Some(i) = pitch_track.iter().position(|f| !(f.is_finite() && *f >= 0.0)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "pitch_track values must be finite and >= 0 (0 = unvoiced), got {} at frame {i}",
+                pitch_track[i]
+            ),
+        ));
+    }
+
+    let settings = pitch_track::PostprocessSettings {
+        correct_octave_jumps: config.correct_octave_jumps,
+        octave_window: config.octave_window as usize,
+        smoothing,
+        smoothing_window: config.smoothing_window as usize,
+        max_gap_frames: config.max_gap_frames as usize,
+    };
+    Ok(pitch_track::postprocess(pitch_track, &settings))
+}
+
+/// Corrects octave jumps, fills short unvoiced gaps and smooths a pitch track
+///
+/// Takes the three arrays of a `PitchTrackFFI` (for example from
+/// `loqa_voice_analyzer_process_buffer`) and returns a new track of the same
+/// length. Octave correction runs first, then gap interpolation in
+/// log-frequency, then median or moving-average smoothing within each voiced
+/// run. Voiced probabilities and timestamps are copied unchanged, so entry i
+/// still describes frame i; frames filled by interpolation keep their low
+/// voiced probability.
+///
+/// # Arguments
+/// * `pitch_track` - Pitch per frame in Hz (0.0 = unvoiced)
+/// * `voiced_probs` - Voiced probability per frame
+/// * `timestamps` - Frame start per frame in seconds
+/// * `length` - Number of frames in each array (> 0)
+/// * `config` - Steps and windows (see `loqa_pitch_postprocess_config_default`)
+///
+/// # Returns
+/// * PitchTrackFFI with success=true and allocated arrays if processing succeeded
+///
+/// # Safety
+/// * All three pointers must point to valid f32 arrays of `length` frames
+/// * Caller MUST call `loqa_free_pitch_track` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_postprocess_pitch_track(
+    pitch_track: *const c_float,
+    voiced_probs: *const c_float,
+    timestamps: *const c_float,
+    length: usize,
+    config: PitchPostprocessConfigFFI,
+) -> PitchTrackFFI {
+    ffi_guard("loqa_postprocess_pitch_track", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchTrackFFI::error_result(code)
+        };
+
+        for (name, ptr) in [
+            ("pitch_track", pitch_track),
+            ("voiced_probs", voiced_probs),
+            ("timestamps", timestamps),
+        ] {
+            if ptr.is_null() {
+                return fail(LoqaErrorCode::NullPointer, format!("{name} pointer is null"));
+            }
+        }
+
+        let pitch = slice::from_raw_parts(pitch_track, length);
+        match postprocess_pitch_track_checked(pitch, &config) {
+            // Ownership passes to the caller until loqa_free_pitch_track
+            Ok(processed) => {
+                let probs = slice::from_raw_parts(voiced_probs, length).to_vec();
+                let times = slice::from_raw_parts(timestamps, length).to_vec();
+                PitchTrackFFI {
+                    success: true,
+                    pitch_track_ptr: Box::into_raw(processed.into_boxed_slice()) as *mut c_float,
+                    voiced_probs_ptr: Box::into_raw(probs.into_boxed_slice()) as *mut c_float,
+                    timestamps_ptr: Box::into_raw(times.into_boxed_slice()) as *mut c_float,
+                    length,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// Pitch statistics FFI - Hz and semitone summaries weighted by voicing
+// ============================================================================
+
+/// Configuration for `loqa_pitch_track_statistics`
+///
+/// Obtain defaults from `loqa_pitch_stats_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PitchStatsConfigFFI {
+    /// Frequency of 0 semitones in Hz (> 0)
+    pub reference_hz: c_float,
+    /// Weight each voiced frame by its voiced probability (false: equal weights)
+    pub weight_by_voicing: bool,
+}
+
+/// Returns the default statistics configuration: semitones re 100 Hz (as in
+/// Praat) and frames weighted by voiced probability
+#[no_mangle]
+pub extern "C" fn loqa_pitch_stats_config_default() -> PitchStatsConfigFFI {
+    PitchStatsConfigFFI {
+        reference_hz: 100.0,
+        weight_by_voicing: true,
+    }
+}
+
+/// Result structure for `loqa_pitch_track_statistics`
+///
+/// The Hz and semitone measures are NaN when no voiced frame has a positive
+/// weight (for example a silent track).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PitchStatsFFI {
+    /// True if the track was valid
+    pub success: bool,
+    /// Number of frames in the track
+    pub frame_count: u32,
+    /// Frames with a pitch above 0
+    pub voiced_frame_count: u32,
+    /// voiced_frame_count / frame_count
+    pub voiced_ratio: c_float,
+    /// Weighted mean pitch in Hz
+    pub mean_hz: c_float,
+    /// Weighted median pitch in Hz
+    pub median_hz: c_float,
+    /// Weighted population standard deviation in Hz
+    pub std_hz: c_float,
+    /// Weighted 5th percentile in Hz
+    pub p5_hz: c_float,
+    /// Weighted 95th percentile in Hz
+    pub p95_hz: c_float,
+    /// Weighted mean in semitones re `reference_hz`
+    pub mean_semitones: c_float,
+    /// Weighted median in semitones re `reference_hz`
+    pub median_semitones: c_float,
+    /// Weighted population standard deviation in semitones
+    pub std_semitones: c_float,
+    /// Weighted 5th percentile in semitones re `reference_hz`
+    pub p5_semitones: c_float,
+    /// Weighted 95th percentile in semitones re `reference_hz`
+    pub p95_semitones: c_float,
+    /// p95_semitones - p5_semitones
+    pub range_semitones: c_float,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for PitchStatsFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        PitchStatsFFI {
+            success: false,
+            frame_count: 0,
+            voiced_frame_count: 0,
+            voiced_ratio: 0.0,
+            mean_hz: 0.0,
+            median_hz: 0.0,
+            std_hz: 0.0,
+            p5_hz: 0.0,
+            p95_hz: 0.0,
+            mean_semitones: 0.0,
+            median_semitones: 0.0,
+            std_semitones: 0.0,
+            p5_semitones: 0.0,
+            p95_semitones: 0.0,
+            range_semitones: 0.0,
+            error_code: code,
+        }
+    }
+}
+
+impl From<pitch_stats::PitchStatistics> for PitchStatsFFI {
+    fn from(stats: pitch_stats::PitchStatistics) -> Self {
+        let hz = |pick: fn(&pitch_stats::Summary) -> f64| {
+            measure_or_nan(stats.hz.as_ref().map(pick))
+        };
+        let st = |pick: fn(&pitch_stats::Summary) -> f64| {
+            measure_or_nan(stats.semitones.as_ref().map(pick))
+        };
+        PitchStatsFFI {
+            success: true,
+            frame_count: stats.frame_count as u32,
+            voiced_frame_count: stats.voiced_frame_count as u32,
+            voiced_ratio: stats.voiced_ratio() as c_float,
+            mean_hz: hz(|s| s.mean),
+            median_hz: hz(|s| s.median),
+            std_hz: hz(|s| s.std),
+            p5_hz: hz(|s| s.p5),
+            p95_hz: hz(|s| s.p95),
+            mean_semitones: st(|s| s.mean),
+            median_semitones: st(|s| s.median),
+            std_semitones: st(|s| s.std),
+            p5_semitones: st(|s| s.p5),
+            p95_semitones: st(|s| s.p95),
+            range_semitones: measure_or_nan(stats.range_semitones()),
+            error_code: LoqaErrorCode::Ok,
+        }
+    }
+}
+
+/// Validates `config` and the track and computes its statistics
+///
+/// Shared by `loqa_pitch_track_statistics` and the Android JNI bindings.
+pub(crate) fn pitch_track_statistics_checked(
+    pitch_track: &[f32],
+    voiced_probs: &[f32],
+    config: &PitchStatsConfigFFI,
+) -> Result<pitch_stats::PitchStatistics, (LoqaErrorCode, String)> {
+    if pitch_track.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
+
+    if !(config.reference_hz.is_finite() && config.reference_hz > 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("reference_hz must be finite and > 0, got {}", config.reference_hz),
+        ));
+    }
+
+    if let Some(i) = pitch_track.iter().position(|f| !(f.is_finite() && *f >= 0.0)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "pitch_track values must be finite and >= 0 (0 = unvoiced), got {} at frame {i}",
+                pitch_track[i]
+            ),
+        ));
+    }
+
+    if let Some(i) = voiced_probs.iter().position(|p| !(0.0..=1.0).contains(p)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("voiced_probs must be in range [0, 1], got {} at frame {i}", voiced_probs[i]),
+        ));
+    }
+
+    Ok(pitch_stats::statistics(
+        pitch_track,
+        voiced_probs,
+        config.reference_hz,
+        config.weight_by_voicing,
+    ))
+}
+
+/// Summarizes a pitch track in Hz and in semitones
+///
+/// Takes the pitch and voiced probability arrays of a `PitchTrackFFI` (from
+/// `loqa_voice_analyzer_process_buffer` or `loqa_postprocess_pitch_track`).
+/// Frames with a pitch above 0 are voiced; each counts with its voiced
+/// probability as weight, so uncertain frames pull the statistics less.
+/// Percentiles interpolate between weighted midpoints, which gives the usual
+/// median for equal weights. Sums run in f64 in frame order, so iOS and
+/// Android get identical results for the same track.
+///
+/// # Arguments
+/// * `pitch_track` - Pitch per frame in Hz (0.0 = unvoiced)
+/// * `voiced_probs` - Voiced probability per frame in [0, 1]
+/// * `length` - Number of frames in each array (> 0)
+/// * `config` - Semitone reference and weighting (see `loqa_pitch_stats_config_default`)
+///
+/// # Returns
+/// * PitchStatsFFI with success=true if the track was valid
+///
+/// # Safety
+/// * Both pointers must point to valid f32 arrays of `length` frames
+#[no_mangle]
+pub unsafe extern "C" fn loqa_pitch_track_statistics(
+    pitch_track: *const c_float,
+    voiced_probs: *const c_float,
+    length: usize,
+    config: PitchStatsConfigFFI,
+) -> PitchStatsFFI {
+    ffi_guard("loqa_pitch_track_statistics", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            PitchStatsFFI::error_result(code)
+        };
+
+        for (name, ptr) in [("pitch_track", pitch_track), ("voiced_probs", voiced_probs)] {
+            if ptr.is_null() {
+                return fail(LoqaErrorCode::NullPointer, format!("{name} pointer is null"));
+            }
+        }
+
+        let pitch = slice::from_raw_parts(pitch_track, length);
+        let probs = slice::from_raw_parts(voiced_probs, length);
+        match pitch_track_statistics_checked(pitch, probs, &config) {
+            Ok(stats) => stats.into(),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// Vibrato FFI - rate, extent, cycle regularity and onset from pitch tracks
+// ============================================================================
+
+/// Configuration for `loqa_analyze_vibrato`
+///
+/// Obtain defaults from `loqa_vibrato_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VibratoConfigFFI {
+    /// Pitch frames per second (sample_rate / hop_size of the track; > 0)
+    pub frame_rate: c_float,
+    /// Slowest vibrato rate in Hz (> 0)
+    pub min_rate_hz: c_float,
+    /// Fastest vibrato rate in Hz (> min_rate_hz, at most frame_rate / 4)
+    pub max_rate_hz: c_float,
+    /// Smallest vibrato extent in ± cents (> 0)
+    pub min_extent_cents: c_float,
+    /// Largest vibrato extent in ± cents (> min_extent_cents)
+    pub max_extent_cents: c_float,
+    /// Fewest full cycles in a vibrato segment (>= 2)
+    pub min_cycles: u32,
+    /// Length of the sliding windows in seconds (>= 0; 0 skips the windows)
+    pub window_seconds: c_float,
+    /// Frames between the ends of consecutive windows (>= 1)
+    pub window_hop_frames: u32,
+}
+
+/// Returns the default vibrato configuration: 100 frames per second, 4-8 Hz,
+/// ±15 to ±200 cents, at least 2 cycles, and 1 s windows ending at every frame
+#[no_mangle]
+pub extern "C" fn loqa_vibrato_config_default() -> VibratoConfigFFI {
+    VibratoConfigFFI {
+        frame_rate: 100.0,
+        min_rate_hz: 4.0,
+        max_rate_hz: 8.0,
+        min_extent_cents: 15.0,
+        max_extent_cents: 200.0,
+        min_cycles: 2,
+        window_seconds: 1.0,
+        window_hop_frames: 1,
+    }
+}
+
+/// One stretch of regular vibrato; times in seconds from the first frame
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VibratoSegmentFFI {
+    /// Time of the segment's first peak or trough
+    pub start: c_float,
+    /// Time of the segment's last peak or trough
+    pub end: c_float,
+    /// Vibrato rate in Hz
+    pub rate_hz: c_float,
+    /// Mean extent in ± cents
+    pub extent_cents: c_float,
+    /// Relative cycle-to-cycle period variation (0.01 = 1 %)
+    pub rate_jitter: c_float,
+    /// Relative variation between consecutive extents (0.01 = 1 %)
+    pub extent_jitter: c_float,
+    /// Seconds from the start of the voiced note to `start`
+    pub onset_delay: c_float,
+}
+
+/// Result structure for `loqa_analyze_vibrato`
+///
+/// Summary measures and window values are NaN where no vibrato was found.
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_vibrato` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct VibratoFFI {
+    /// True if the track was valid
+    pub success: bool,
+    /// Seconds of vibrato over seconds of voiced frames
+    pub vibrato_ratio: c_float,
+    /// Rate over all segments in Hz
+    pub rate_hz: c_float,
+    /// Mean extent over all segments in ± cents
+    pub extent_cents: c_float,
+    /// Rate jitter of the segments, weighted by their cycles
+    pub rate_jitter: c_float,
+    /// Extent jitter of the segments, weighted by their cycles
+    pub extent_jitter: c_float,
+    /// Mean onset delay of the first segment of each voiced note
+    pub onset_delay: c_float,
+    /// Vibrato segments in track order
+    pub segments_ptr: *mut VibratoSegmentFFI,
+    /// Number of segments (0 if the track has no vibrato)
+    pub segment_count: usize,
+    /// Time of each window's last frame in seconds from the first frame
+    pub window_times_ptr: *mut c_float,
+    /// Rate of each window in Hz
+    pub window_rate_ptr: *mut c_float,
+    /// Extent of each window in ± cents
+    pub window_extent_ptr: *mut c_float,
+    /// Rate jitter of each window
+    pub window_rate_jitter_ptr: *mut c_float,
+    /// Extent jitter of each window
+    pub window_extent_jitter_ptr: *mut c_float,
+    /// Number of windows (length of the five window arrays)
+    pub window_count: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for VibratoFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        VibratoFFI {
+            success: false,
+            vibrato_ratio: 0.0,
+            rate_hz: 0.0,
+            extent_cents: 0.0,
+            rate_jitter: 0.0,
+            extent_jitter: 0.0,
+            onset_delay: 0.0,
+            segments_ptr: std::ptr::null_mut(),
+            segment_count: 0,
+            window_times_ptr: std::ptr::null_mut(),
+            window_rate_ptr: std::ptr::null_mut(),
+            window_extent_ptr: std::ptr::null_mut(),
+            window_rate_jitter_ptr: std::ptr::null_mut(),
+            window_extent_jitter_ptr: std::ptr::null_mut(),
+            window_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Vibrato of a whole track plus its sliding windows
+#[derive(Debug, Clone)]
+pub(crate) struct VibratoAnalysis {
+    pub segments: Vec<VibratoSegmentFFI>,
+    pub summary: vibrato::VibratoSummary,
+    pub window_times: Vec<f32>,
+    pub window_rate: Vec<f32>,
+    pub window_extent: Vec<f32>,
+    pub window_rate_jitter: Vec<f32>,
+    pub window_extent_jitter: Vec<f32>,
+}
+
+/// Validates `config` and the track and analyses its vibrato
+///
+/// Shared by `loqa_analyze_vibrato` and the Android JNI bindings.
+pub(crate) fn analyze_vibrato_checked(
+    pitch_track: &[f32],
+    config: &VibratoConfigFFI,
+) -> Result<VibratoAnalysis, (LoqaErrorCode, String)> {
+    if pitch_track.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
+
+    for (name, value) in [
+        ("frame_rate", config.frame_rate),
+        ("min_rate_hz", config.min_rate_hz),
+        ("min_extent_cents", config.min_extent_cents),
+    ] {
+        if !(value.is_finite() && value > 0.0) {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("{name} must be finite and > 0, got {value}"),
+            ));
+        }
+    }
+
+    if !(config.max_rate_hz > config.min_rate_hz && config.max_rate_hz * 4.0 <= config.frame_rate)
+    {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "max_rate_hz must be > min_rate_hz ({}) and at most frame_rate / 4 ({}), got {}",
+                config.min_rate_hz,
+                config.frame_rate / 4.0,
+                config.max_rate_hz
+            ),
+        ));
+    }
+
+    if !(config.max_extent_cents.is_finite() && config.max_extent_cents > config.min_extent_cents)
+    {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "max_extent_cents must be finite and > min_extent_cents ({}), got {}",
+                config.min_extent_cents, config.max_extent_cents
+            ),
+        ));
+    }
+
+    if config.min_cycles < 2 {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("min_cycles must be >= 2, got {}", config.min_cycles),
+        ));
+    }
+
+    if !(config.window_seconds.is_finite() && config.window_seconds >= 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("window_seconds must be finite and >= 0, got {}", config.window_seconds),
+        ));
+    }
+
+    if config.window_hop_frames == 0 {
+        return Err((LoqaErrorCode::InvalidArgument, "window_hop_frames must be >= 1".into()));
+    }
+
+    if let Some(i) = pitch_track.iter().position(|f| !(f.is_finite() && *f >= 0.0)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "pitch_track values must be finite and >= 0 (0 = unvoiced), got {} at frame {i}",
+                pitch_track[i]
+            ),
+        ));
+    }
+
+    let settings = vibrato::VibratoSettings {
+        frame_rate: config.frame_rate as f64,
+        min_rate_hz: config.min_rate_hz as f64,
+        max_rate_hz: config.max_rate_hz as f64,
+        min_extent_cents: config.min_extent_cents as f64,
+        max_extent_cents: config.max_extent_cents as f64,
+        min_cycles: config.min_cycles as usize,
+    };
+    let analysis = vibrato::analyze(pitch_track, &settings);
+    let windows = if config.window_seconds > 0.0 {
+        let window_frames = ((config.window_seconds * config.frame_rate).round() as usize).max(1);
+        vibrato::sliding(pitch_track, &settings, window_frames, config.window_hop_frames as usize)
+    } else {
+        Vec::new()
+    };
+
+    let window_values = |pick: fn(&vibrato::VibratoSummary) -> Option<f64>| -> Vec<f32> {
+        windows.iter().map(|w| measure_or_nan(pick(&w.summary))).collect()
+    };
+    Ok(VibratoAnalysis {
+        segments: analysis
+            .segments
+            .iter()
+            .map(|s| VibratoSegmentFFI {
+                start: s.start as c_float,
+                end: s.end as c_float,
+                rate_hz: s.rate_hz as c_float,
+                extent_cents: s.extent_cents as c_float,
+                rate_jitter: s.rate_jitter as c_float,
+                extent_jitter: s.extent_jitter as c_float,
+                onset_delay: s.onset_delay as c_float,
+            })
+            .collect(),
+        summary: analysis.summary,
+        window_times: windows.iter().map(|w| w.time as c_float).collect(),
+        window_rate: window_values(|s| s.rate_hz),
+        window_extent: window_values(|s| s.extent_cents),
+        window_rate_jitter: window_values(|s| s.rate_jitter),
+        window_extent_jitter: window_values(|s| s.extent_jitter),
+    })
+}
+
+/// Finds vibrato in a pitch track and measures its rate, extent and regularity
+///
+/// Takes the pitch array of a `PitchTrackFFI` (from
+/// `loqa_voice_analyzer_process_buffer`, `loqa_postprocess_pitch_track` or the
+/// frames of a stream). Each voiced note is converted to cents, detrended with
+/// a moving average one slowest vibrato period long, and its peaks and troughs
+/// are picked with `min_extent_cents` of hysteresis. Chains of at least
+/// `min_cycles` cycles whose rate and extent stay within the configured ranges
+/// form the segments. Rate jitter and extent jitter are mean absolute
+/// differences between consecutive periods and extents over their means, so
+/// lower values mean a more regular vibrato.
+///
+/// Besides the whole-track result, windows of `window_seconds` ending every
+/// `window_hop_frames` frames are analysed on their own; the last window
+/// always ends at the last frame, so a live display can analyse the recent
+/// frames of a stream and read the final window.
+///
+/// # Arguments
+/// * `pitch_track` - Pitch per frame in Hz (0.0 = unvoiced)
+/// * `length` - Number of frames (> 0)
+/// * `config` - Frame rate, detection ranges and windows (see `loqa_vibrato_config_default`)
+///
+/// # Returns
+/// * VibratoFFI with success=true, the summary, the segments and the windows
+///
+/// # Safety
+/// * `pitch_track` must point to a valid f32 array of `length` frames
+/// * Caller MUST call `loqa_free_vibrato` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_analyze_vibrato(
+    pitch_track: *const c_float,
+    length: usize,
+    config: VibratoConfigFFI,
+) -> VibratoFFI {
+    ffi_guard("loqa_analyze_vibrato", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            VibratoFFI::error_result(code)
+        };
+
+        if pitch_track.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "pitch_track pointer is null".into());
+        }
+
+        let pitch = slice::from_raw_parts(pitch_track, length);
+        match analyze_vibrato_checked(pitch, &config) {
+            Ok(analysis) => {
+                let segment_count = analysis.segments.len();
+                // No vibrato gives a null segment array rather than a dangling pointer
+                let segments_ptr = if analysis.segments.is_empty() {
+                    std::ptr::null_mut()
+                } else {
+                    Box::into_raw(analysis.segments.into_boxed_slice()) as *mut VibratoSegmentFFI
+                };
+                let window_count = analysis.window_times.len();
+                // Likewise for the window arrays when windows are off
+                let window_array = |values: Vec<f32>| {
+                    if values.is_empty() {
+                        std::ptr::null_mut()
+                    } else {
+                        Box::into_raw(values.into_boxed_slice()) as *mut c_float
+                    }
+                };
+                let summary = analysis.summary;
+
+                // Ownership passes to the caller until loqa_free_vibrato
+                VibratoFFI {
+                    success: true,
+                    vibrato_ratio: summary.vibrato_ratio as c_float,
+                    rate_hz: measure_or_nan(summary.rate_hz),
+                    extent_cents: measure_or_nan(summary.extent_cents),
+                    rate_jitter: measure_or_nan(summary.rate_jitter),
+                    extent_jitter: measure_or_nan(summary.extent_jitter),
+                    onset_delay: measure_or_nan(summary.onset_delay),
+                    segments_ptr,
+                    segment_count,
+                    window_times_ptr: window_array(analysis.window_times),
+                    window_rate_ptr: window_array(analysis.window_rate),
+                    window_extent_ptr: window_array(analysis.window_extent),
+                    window_rate_jitter_ptr: window_array(analysis.window_rate_jitter),
+                    window_extent_jitter_ptr: window_array(analysis.window_extent_jitter),
+                    window_count,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Free the arrays of a VibratoFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a VibratoFFI from `loqa_analyze_vibrato`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_vibrato(result: *mut VibratoFFI) {
+    ffi_guard("loqa_free_vibrato", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        for ptr in [
+            &mut res.window_times_ptr,
+            &mut res.window_rate_ptr,
+            &mut res.window_extent_ptr,
+            &mut res.window_rate_jitter_ptr,
+            &mut res.window_extent_jitter_ptr,
+        ] {
+            if !ptr.is_null() && res.window_count > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, res.window_count));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        if !res.segments_ptr.is_null() && res.segment_count > 0 {
+            let len = res.segment_count;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.segments_ptr, len));
+        }
+        res.segments_ptr = std::ptr::null_mut();
+        res.window_count = 0;
+        res.segment_count = 0;
+    })
+}
+
+// ============================================================================
+// Jitter and shimmer FFI - Praat-compatible period and amplitude perturbation
+// ============================================================================
+
+/// Fewest valid periods for which jitter and shimmer are reported
+const MIN_PERTURBATION_PERIODS: usize = 3;
+
+/// Praat jitter measures from the periods of the voiced parts of a buffer
+///
+/// Relative measures are fractions (Praat shows 0.01 as 1 %). A measure is NaN
+/// when the buffer has no run of consecutive valid periods long enough for it
+/// (2 for local, 3 for RAP and DDP, 5 for PPQ5).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct JitterResultFFI {
+    /// True if at least `MIN_PERTURBATION_PERIODS` valid periods were found
+    pub success: bool,
+    /// Mean absolute difference of consecutive periods / mean period
+    pub local: c_float,
+    /// Mean absolute difference of consecutive periods in seconds
+    pub local_absolute: c_float,
+    /// Relative average perturbation: deviation from the 3-period average / mean period
+    pub rap: c_float,
+    /// Five-point period perturbation quotient
+    pub ppq5: c_float,
+    /// Mean absolute difference of differences of consecutive periods / mean period (3 x RAP)
+    pub ddp: c_float,
+    /// Mean period in seconds
+    pub mean_period: c_float,
+    /// Number of valid periods
+    pub period_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for JitterResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        JitterResultFFI {
+            success: false,
+            local: 0.0,
+            local_absolute: 0.0,
+            rap: 0.0,
+            ppq5: 0.0,
+            ddp: 0.0,
+            mean_period: 0.0,
+            period_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Praat shimmer measures from the peak amplitudes of the voiced periods of a buffer
+///
+/// Relative measures are fractions. A measure is NaN when the buffer has no
+/// run of consecutive valid periods long enough for it (2 for local and local
+/// dB, 3 for APQ3 and DDA, 5 for APQ5, 11 for APQ11).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ShimmerResultFFI {
+    /// True if at least `MIN_PERTURBATION_PERIODS` valid periods were found
+    pub success: bool,
+    /// Mean absolute difference of consecutive amplitudes / mean amplitude
+    pub local: c_float,
+    /// Mean absolute ratio of consecutive amplitudes in dB
+    pub local_db: c_float,
+    /// Three-point amplitude perturbation quotient
+    pub apq3: c_float,
+    /// Five-point amplitude perturbation quotient
+    pub apq5: c_float,
+    /// Eleven-point amplitude perturbation quotient
+    pub apq11: c_float,
+    /// Mean absolute difference of differences of consecutive amplitudes / mean amplitude (3 x APQ3)
+    pub dda: c_float,
+    /// Mean peak amplitude (same units as the samples)
+    pub mean_amplitude: c_float,
+    /// Number of valid periods
+    pub period_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for ShimmerResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        ShimmerResultFFI {
+            success: false,
+            local: 0.0,
+            local_db: 0.0,
+            apq3: 0.0,
+            apq5: 0.0,
+            apq11: 0.0,
+            dda: 0.0,
+            mean_amplitude: 0.0,
+            period_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Undefined measures cross the FFI boundary as NaN
+pub(crate) fn measure_or_nan(value: Option<f64>) -> c_float {
+    value.map_or(f32::NAN, |v| v as f32)
+}
+
+/// Validates `config` and tracks pitch over `samples` with `process_buffer`
+///
+/// Returns the converted config with the track. Shared by the whole-buffer
+/// analyses that need pYIN pitch or voicing (perturbation, VAD).
+pub(crate) fn track_pitch_checked(
+    samples: &[f32],
+    config: &AnalysisConfigFFI,
+) -> Result<(loqa_voice_dsp::AnalysisConfig, loqa_voice_dsp::PitchTrack), (LoqaErrorCode, String)>
+{
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
+
+    let config = analysis_config_from_ffi(config)?;
+    if samples.len() <= config.frame_size {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "buffer must be longer than frame_size ({}), got {} samples",
+                config.frame_size,
+                samples.len()
+            ),
+        ));
+    }
+
+    let mut analyzer = loqa_voice_dsp::VoiceAnalyzer::new(config.clone())
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("VoiceAnalyzer creation failed: {e}")))?;
+    let track = analyzer
+        .process_buffer(samples)
+        .map_err(|e| (LoqaErrorCode::AnalysisFailed, format!("process_buffer failed: {e}")))?;
+    loqa_log!(
+        Debug,
+        "pitch track of {} samples: {} frames (frame_size {}, hop_size {})",
+        samples.len(),
+        track.pitch_track.len(),
+        config.frame_size,
+        config.hop_size
+    );
+    Ok((config, track))
+}
+
+/// Validates `config`, tracks pitch with `process_buffer` and extracts the glottal cycles
+///
+/// Shared by `loqa_calculate_jitter`, `loqa_calculate_shimmer` and the Android JNI bindings.
+pub(crate) fn extract_cycles_checked(
+    samples: &[f32],
+    config: &AnalysisConfigFFI,
+) -> Result<Vec<Vec<perturbation::Cycle>>, (LoqaErrorCode, String)> {
+    let (config, track) = track_pitch_checked(samples, config)?;
+    let (sample_rate, frame_size, hop_size) =
+        (config.sample_rate, config.frame_size, config.hop_size);
+
+    Ok(perturbation::extract_cycles(
+        samples,
+        sample_rate,
+        &track.pitch_track,
+        frame_size,
+        hop_size,
+    ))
+}
+
+/// Rejects results with fewer than `MIN_PERTURBATION_PERIODS` valid periods
+fn check_period_count(period_count: usize) -> Result<(), (LoqaErrorCode, String)> {
+    if period_count < MIN_PERTURBATION_PERIODS {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "found {period_count} valid voiced periods, need at least {MIN_PERTURBATION_PERIODS}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates `config` and computes jitter of the voiced parts of `samples`
+///
+/// Shared by `loqa_calculate_jitter` and the Android JNI bindings.
+pub(crate) fn calculate_jitter_checked(
+    samples: &[f32],
+    config: &AnalysisConfigFFI,
+) -> Result<perturbation::Jitter, (LoqaErrorCode, String)> {
+    let jitter = perturbation::jitter(&extract_cycles_checked(samples, config)?);
+    check_period_count(jitter.period_count)?;
+    Ok(jitter)
+}
+
+/// Validates `config` and computes shimmer of the voiced parts of `samples`
+///
+/// Shared by `loqa_calculate_shimmer` and the Android JNI bindings.
+pub(crate) fn calculate_shimmer_checked(
+    samples: &[f32],
+    config: &AnalysisConfigFFI,
+) -> Result<perturbation::Shimmer, (LoqaErrorCode, String)> {
+    let shimmer = perturbation::shimmer(&extract_cycles_checked(samples, config)?);
+    check_period_count(shimmer.period_count)?;
+    Ok(shimmer)
+}
+
+/// Calculates Praat-compatible jitter (local, local absolute, RAP, PPQ5, DDP)
+///
+/// Glottal pulses are picked from the waveform within the voiced frames of the
+/// `loqa_voice_analyzer_process_buffer` pitch track for `config`, using Praat's
+/// default period range (0.1-20 ms) and maximum period factor (1.3).
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `config.frame_size`
+/// * `config` - Pitch tracking configuration (see `loqa_analysis_config_default`)
+///
+/// # Returns
+/// * JitterResultFFI; `error_code` is `BufferTooShort` if fewer than 3 valid periods were found
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+#[no_mangle]
+pub unsafe extern "C" fn loqa_calculate_jitter(
+    buffer: *const c_float,
+    length: usize,
+    config: AnalysisConfigFFI,
+) -> JitterResultFFI {
+    ffi_guard("loqa_calculate_jitter", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            JitterResultFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match calculate_jitter_checked(samples, &config) {
+            Ok(jitter) => JitterResultFFI {
+                success: true,
+                local: measure_or_nan(jitter.local),
+                local_absolute: measure_or_nan(jitter.local_absolute),
+                rap: measure_or_nan(jitter.rap),
+                ppq5: measure_or_nan(jitter.ppq5),
+                ddp: measure_or_nan(jitter.ddp),
+                mean_period: measure_or_nan(jitter.mean_period),
+                period_count: jitter.period_count as u32,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Calculates Praat-compatible shimmer (local, local dB, APQ3, APQ5, APQ11, DDA)
+///
+/// Uses the peak amplitude of the same glottal pulses as `loqa_calculate_jitter`,
+/// skipping neighbours whose amplitudes differ by more than Praat's default
+/// maximum amplitude factor (1.6).
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `config.frame_size`
+/// * `config` - Pitch tracking configuration (see `loqa_analysis_config_default`)
+///
+/// # Returns
+/// * ShimmerResultFFI; `error_code` is `BufferTooShort` if fewer than 3 valid periods were found
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+#[no_mangle]
+pub unsafe extern "C" fn loqa_calculate_shimmer(
+    buffer: *const c_float,
+    length: usize,
+    config: AnalysisConfigFFI,
+) -> ShimmerResultFFI {
+    ffi_guard("loqa_calculate_shimmer", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            ShimmerResultFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match calculate_shimmer_checked(samples, &config) {
+            Ok(shimmer) => ShimmerResultFFI {
+                success: true,
+                local: measure_or_nan(shimmer.local),
+                local_db: measure_or_nan(shimmer.local_db),
+                apq3: measure_or_nan(shimmer.apq3),
+                apq5: measure_or_nan(shimmer.apq5),
+                apq11: measure_or_nan(shimmer.apq11),
+                dda: measure_or_nan(shimmer.dda),
+                mean_amplitude: measure_or_nan(shimmer.mean_amplitude),
+                period_count: shimmer.period_count as u32,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// Cepstral peak prominence FFI - CPP and smoothed CPPS
+// ============================================================================
+
+/// Configuration for `loqa_calculate_cpp`
+///
+/// Obtain defaults from `loqa_cpp_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CppConfigFFI {
+    /// Samples per frame (= FFT size): power of two in [64, 8192]; must exceed
+    /// two periods of `min_frequency`
+    pub frame_size: u32,
+    /// Samples between frame starts, in [1, frame_size]
+    pub hop_size: u32,
+    /// Lowest F0 of the cepstral peak search in Hz
+    pub min_frequency: c_float,
+    /// Highest F0 of the cepstral peak search in Hz
+    pub max_frequency: c_float,
+    /// Width in seconds of the moving average across frames for CPPS (0 = none)
+    pub time_smoothing: c_float,
+    /// Width in seconds of the moving average across quefrency for CPPS (0 = none)
+    pub quefrency_smoothing: c_float,
+}
+
+/// Returns the default CPP configuration: 2048-sample Hann frames every 128
+/// samples, peak search for 60-330 Hz and Praat's CPPS smoothing (0.02 s in
+/// time, 0.0005 s in quefrency)
+#[no_mangle]
+pub extern "C" fn loqa_cpp_config_default() -> CppConfigFFI {
+    CppConfigFFI {
+        frame_size: 2048,
+        hop_size: 128,
+        min_frequency: 60.0,
+        max_frequency: 330.0,
+        time_smoothing: 0.02,
+        quefrency_smoothing: 0.0005,
+    }
+}
+
+/// Result structure for `loqa_calculate_cpp`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CppResultFFI {
+    /// True if the measures were computed
+    pub success: bool,
+    /// Mean cepstral peak prominence of the unsmoothed frames in dB
+    pub cpp: c_float,
+    /// Mean cepstral peak prominence of the smoothed frames in dB
+    pub cpps: c_float,
+    /// Median F0 of the smoothed cepstral peaks in Hz
+    pub f0: c_float,
+    /// Number of frames averaged
+    pub frame_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for CppResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        CppResultFFI {
+            success: false,
+            cpp: 0.0,
+            cpps: 0.0,
+            f0: 0.0,
+            frame_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Validates `config` and computes CPP and CPPS of `samples`
+///
+/// Shared by `loqa_calculate_cpp` and the Android JNI bindings.
+pub(crate) fn calculate_cpp_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &CppConfigFFI,
+) -> Result<cepstrum::Cpp, (LoqaErrorCode, String)> {
+    if sample_rate == 0 {
+        return Err((LoqaErrorCode::InvalidSampleRate, "sample_rate must be > 0, got 0".into()));
+    }
+
+    if config.frame_size == 0 {
+        return Err((LoqaErrorCode::InvalidFftSize, "frame_size must be > 0, got 0".into()));
+    }
+    validate_fft_size(config.frame_size)?;
+
+    let nyquist = sample_rate as f32 / 2.0;
+    let (min_f0, max_f0) = (config.min_frequency, config.max_frequency);
+    if !(min_f0 > 0.0 && max_f0 > min_f0 && max_f0 <= nyquist) {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "invalid frequency range: min={min_f0}, max={max_f0} (must satisfy 0 < min < max <= {nyquist})"
+            ),
+        ));
+    }
+
+    // The longest period searched must fit in the first half of the cepstrum
+    let longest_period = (sample_rate as f32 / min_f0).ceil();
+    if longest_period >= (config.frame_size / 2) as f32 {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "min_frequency {min_f0} Hz needs a frame_size above {} samples, got {}",
+                2.0 * longest_period,
+                config.frame_size
+            ),
+        ));
+    }
+
+    for (name, width) in [
+        ("time_smoothing", config.time_smoothing),
+        ("quefrency_smoothing", config.quefrency_smoothing),
+    ] {
+        if !(width.is_finite() && width >= 0.0) {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("{name} must be finite and >= 0, got {width}"),
+            ));
+        }
+    }
+
+    // Hop and buffer length rules are the spectrogram's
+    let spectrogram_config = SpectrogramConfigFFI {
+        frame_size: config.frame_size,
+        hop_size: config.hop_size,
+        window: LoqaWindowType::Hann as u32,
+        window_param: 0.0,
+        scaling: LoqaSpectrogramScaling::Power as u32,
+        db_floor: 0.0,
+    };
+    let power = compute_spectrogram_checked(samples, sample_rate, &spectrogram_config)?;
+
+    let hop_seconds = config.hop_size as f32 / sample_rate as f32;
+    let time_frames = (config.time_smoothing / hop_seconds).round() as usize;
+    let quefrency_bins = (config.quefrency_smoothing * sample_rate as f32).round() as usize;
+    Ok(cepstrum::analyze(
+        &power,
+        sample_rate,
+        min_f0 as f64,
+        max_f0 as f64,
+        time_frames,
+        quefrency_bins,
+    ))
+}
+
+/// Calculates cepstral peak prominence (CPP) and smoothed CPP (CPPS)
+///
+/// CPP is the height in dB of the power cepstrum's peak between quefrencies
+/// 1 / max_frequency and 1 / min_frequency above a straight line fitted from
+/// 1 ms upwards (Hillenbrand & Houde). CPPS does the same on cepstra averaged
+/// over time and quefrency. Both are means over every frame, so connected
+/// speech needs no voicing decision; breathier voices give lower values.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `frame_size`
+/// * `sample_rate` - Sample rate in Hz
+/// * `config` - Framing, F0 range and smoothing (see `loqa_cpp_config_default`)
+///
+/// # Returns
+/// * CppResultFFI with cpp and cpps in dB and the median cepstral F0
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+#[no_mangle]
+pub unsafe extern "C" fn loqa_calculate_cpp(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: CppConfigFFI,
+) -> CppResultFFI {
+    ffi_guard("loqa_calculate_cpp", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            CppResultFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match calculate_cpp_checked(samples, sample_rate, &config) {
+            Ok(cpp) => CppResultFFI {
+                success: true,
+                cpp: cpp.cpp as f32,
+                cpps: cpp.cpps as f32,
+                f0: cpp.f0 as f32,
+                frame_count: cpp.frame_count as u32,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// Intensity FFI - framewise RMS, peak and calibrated intensity contours
+// ============================================================================
+
+/// Configuration for `loqa_compute_intensity`
+///
+/// Obtain defaults from `loqa_intensity_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IntensityConfigFFI {
+    /// Samples per frame (> 0; need not be a power of two)
+    pub frame_size: u32,
+    /// Samples between frame starts, in [1, frame_size]
+    pub hop_size: u32,
+    /// dB added to every dBFS level; the mic's dB SPL at 0 dBFS gives approximate dB SPL
+    pub calibration_offset: c_float,
+    /// Lowest level in dBFS, applied before `calibration_offset` (silent frames read this)
+    pub floor_db: c_float,
+}
+
+/// Returns the default intensity configuration: 2048-sample frames every 512
+/// samples (the spectrogram grid), uncalibrated dBFS with a -120 dB floor
+#[no_mangle]
+pub extern "C" fn loqa_intensity_config_default() -> IntensityConfigFFI {
+    IntensityConfigFFI {
+        frame_size: 2048,
+        hop_size: 512,
+        calibration_offset: 0.0,
+        floor_db: -120.0,
+    }
+}
+
+/// Result structure for `loqa_compute_intensity`
+///
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_intensity_contour` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IntensityContourFFI {
+    /// True if the contour was computed
+    pub success: bool,
+    /// Linear RMS of each frame (full scale = 1.0), without calibration
+    pub rms_ptr: *mut c_float,
+    /// RMS level of each frame in dB (dBFS + calibration offset)
+    pub intensity_db_ptr: *mut c_float,
+    /// Absolute peak level of each frame in dB (dBFS + calibration offset)
+    pub peak_db_ptr: *mut c_float,
+    /// Start of each frame in seconds from buffer start (same grid as `PitchTrackFFI`)
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames (length of all four arrays)
+    pub frame_count: usize,
+    /// Energy-averaged intensity in dB
+    pub mean_db: c_float,
+    /// Quietest frame's intensity in dB
+    pub min_db: c_float,
+    /// Loudest frame's intensity in dB
+    pub max_db: c_float,
+    /// `max_db - min_db`
+    pub dynamic_range_db: c_float,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for IntensityContourFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        IntensityContourFFI {
+            success: false,
+            rms_ptr: std::ptr::null_mut(),
+            intensity_db_ptr: std::ptr::null_mut(),
+            peak_db_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            frame_count: 0,
+            mean_db: 0.0,
+            min_db: 0.0,
+            max_db: 0.0,
+            dynamic_range_db: 0.0,
+            error_code: code,
+        }
+    }
+}
+
+/// Validates `config` and computes the intensity contour of `samples`
+///
+/// Shared by `loqa_compute_intensity` and the Android JNI bindings.
+pub(crate) fn compute_intensity_checked(
+    samples: &[f32],
+    sample_rate: u32,
+    config: &IntensityConfigFFI,
+) -> Result<intensity::IntensityContour, (LoqaErrorCode, String)> {
+    if sample_rate == 0 {
+        return Err((LoqaErrorCode::InvalidSampleRate, "sample_rate must be > 0, got 0".into()));
+    }
+
+    if config.frame_size == 0 {
+        return Err((LoqaErrorCode::InvalidArgument, "frame_size must be > 0, got 0".into()));
+    }
+
+    if !(1..=config.frame_size).contains(&config.hop_size) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "hop_size must be in range [1, frame_size = {}], got {}",
+                config.frame_size, config.hop_size
+            ),
+        ));
+    }
+
+    for (name, value) in [
+        ("calibration_offset", config.calibration_offset),
+        ("floor_db", config.floor_db),
+    ] {
+        if !value.is_finite() {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("{name} must be finite, got {value}"),
+            ));
+        }
+    }
+
+    // Same frame grid as process_buffer: the buffer must extend past the first frame
+    let frame_size = config.frame_size as usize;
+    if samples.len() <= frame_size {
+        return Err((
+            LoqaErrorCode::InvalidLength,
+            format!(
+                "length must be > frame_size ({frame_size}), got {}",
+                samples.len()
+            ),
+        ));
+    }
+
+    let contour = intensity::contour(
+        samples,
+        sample_rate,
+        frame_size,
+        config.hop_size as usize,
+        config.calibration_offset,
+        config.floor_db,
+    );
+    loqa_log!(
+        Debug,
+        "intensity contour of {} samples: {} frames",
+        samples.len(),
+        contour.timestamps.len()
+    );
+    Ok(contour)
+}
+
+/// Computes framewise RMS, peak and intensity contours of a whole buffer
+///
+/// Levels are 20 * log10 of the unwindowed frame RMS or absolute peak in dBFS,
+/// clamped at `floor_db` and shifted by `calibration_offset`. Summary
+/// statistics cover the intensity contour; the mean is energy-averaged (the
+/// mean squared RMS in dB), as in Praat. Frame i starts at sample i * hop_size,
+/// so with the same frame and hop sizes it lines up with entry i of
+/// `loqa_voice_analyzer_process_buffer`.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `frame_size`
+/// * `sample_rate` - Sample rate in Hz, used for the timestamps
+/// * `config` - Framing, calibration and floor (see `loqa_intensity_config_default`)
+///
+/// # Returns
+/// * IntensityContourFFI with success=true and allocated arrays if the contour was computed
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_intensity_contour` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_compute_intensity(
+    buffer: *const c_float,
+    length: usize,
+    sample_rate: u32,
+    config: IntensityConfigFFI,
+) -> IntensityContourFFI {
+    ffi_guard("loqa_compute_intensity", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            IntensityContourFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match compute_intensity_checked(samples, sample_rate, &config) {
+            // Ownership passes to the caller until loqa_free_intensity_contour
+            Ok(contour) => IntensityContourFFI {
+                success: true,
+                frame_count: contour.timestamps.len(),
+                mean_db: contour.mean_db,
+                min_db: contour.min_db,
+                max_db: contour.max_db,
+                dynamic_range_db: contour.dynamic_range_db(),
+                rms_ptr: Box::into_raw(contour.rms.into_boxed_slice()) as *mut c_float,
+                intensity_db_ptr: Box::into_raw(contour.intensity_db.into_boxed_slice())
+                    as *mut c_float,
+                peak_db_ptr: Box::into_raw(contour.peak_db.into_boxed_slice()) as *mut c_float,
+                timestamps_ptr: Box::into_raw(contour.timestamps.into_boxed_slice())
+                    as *mut c_float,
+                error_code: LoqaErrorCode::Ok,
+            },
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Free the arrays of an IntensityContourFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to an IntensityContourFFI from `loqa_compute_intensity`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_intensity_contour(result: *mut IntensityContourFFI) {
+    ffi_guard("loqa_free_intensity_contour", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        for ptr in [
+            &mut res.rms_ptr,
+            &mut res.intensity_db_ptr,
+            &mut res.peak_db_ptr,
+            &mut res.timestamps_ptr,
+        ] {
+            if !ptr.is_null() && res.frame_count > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, res.frame_count));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        res.frame_count = 0;
+    })
+}
+
+// ============================================================================
+// Voice activity FFI - zero-crossing rate, energy and pYIN voicing
+// ============================================================================
+
+/// Configuration for `loqa_detect_voice_activity`
+///
+/// Obtain defaults from `loqa_vad_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VadConfigFFI {
+    /// Lowest RMS level of a speech frame in dBFS
+    pub energy_threshold_db: c_float,
+    /// Zero crossings per second at or above which an unvoiced frame counts as speech
+    pub zcr_threshold: c_float,
+    /// pYIN voicing probability in [0, 1] at or above which a frame is voiced
+    pub voicing_threshold: c_float,
+    /// Seconds a speech decision is held after the last speech frame (>= 0)
+    pub hangover_time: c_float,
+}
+
+/// Returns the default VAD configuration: -50 dBFS energy gate, 3000 crossings
+/// per second for unvoiced speech, voicing probability 0.5 and 0.2 s hangover
+#[no_mangle]
+pub extern "C" fn loqa_vad_config_default() -> VadConfigFFI {
+    VadConfigFFI {
+        energy_threshold_db: -50.0,
+        zcr_threshold: 3000.0,
+        voicing_threshold: 0.5,
+        hangover_time: 0.2,
+    }
+}
+
+/// Speech segment as a half-open sample range `[start, end)`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeechSegmentFFI {
+    /// First sample of the segment
+    pub start: usize,
+    /// One past the last sample of the segment
+    pub end: usize,
+}
+
+/// Result structure for `loqa_detect_voice_activity`
+///
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_voice_activity` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct VadResultFFI {
+    /// True if the analysis succeeded
+    pub success: bool,
+    /// Start of each frame in seconds from buffer start (same grid as `PitchTrackFFI`)
+    pub timestamps_ptr: *mut c_float,
+    /// RMS level of each frame in dBFS (short-time energy)
+    pub energy_db_ptr: *mut c_float,
+    /// Zero crossings per second of each frame
+    pub zcr_ptr: *mut c_float,
+    /// pYIN voicing probability of each frame
+    pub voiced_probs_ptr: *mut c_float,
+    /// Speech decision of each frame, after hangover
+    pub is_speech_ptr: *mut bool,
+    /// Number of frames (length of the five frame arrays)
+    pub frame_count: usize,
+    /// Merged speech segments in buffer order
+    pub segments_ptr: *mut SpeechSegmentFFI,
+    /// Number of speech segments (0 if the buffer holds no speech)
+    pub segment_count: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for VadResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        VadResultFFI {
+            success: false,
+            timestamps_ptr: std::ptr::null_mut(),
+            energy_db_ptr: std::ptr::null_mut(),
+            zcr_ptr: std::ptr::null_mut(),
+            voiced_probs_ptr: std::ptr::null_mut(),
+            is_speech_ptr: std::ptr::null_mut(),
+            frame_count: 0,
+            segments_ptr: std::ptr::null_mut(),
+            segment_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Validates both configs, tracks voicing with `process_buffer` and runs the VAD
+///
+/// Shared by `loqa_detect_voice_activity` and the Android JNI bindings.
+pub(crate) fn detect_voice_activity_checked(
+    samples: &[f32],
+    analysis: &AnalysisConfigFFI,
+    config: &VadConfigFFI,
+) -> Result<vad::VoiceActivity, (LoqaErrorCode, String)> {
+    if !config.energy_threshold_db.is_finite() {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("energy_threshold_db must be finite, got {}", config.energy_threshold_db),
+        ));
+    }
+
+    for (name, value) in [
+        ("zcr_threshold", config.zcr_threshold),
+        ("hangover_time", config.hangover_time),
+    ] {
+        if !(value.is_finite() && value >= 0.0) {
+            return Err((
+                LoqaErrorCode::InvalidArgument,
+                format!("{name} must be finite and >= 0, got {value}"),
+            ));
+        }
+    }
+
+    if !(0.0..=1.0).contains(&config.voicing_threshold) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "voicing_threshold must be in range [0, 1], got {}",
+                config.voicing_threshold
+            ),
+        ));
+    }
+
+    let (analysis, track) = track_pitch_checked(samples, analysis)?;
+    let hop_seconds = analysis.hop_size as f32 / analysis.sample_rate as f32;
+    let thresholds = vad::VadThresholds {
+        energy_db: config.energy_threshold_db,
+        zcr: config.zcr_threshold,
+        voicing: config.voicing_threshold,
+        hangover_frames: (config.hangover_time / hop_seconds).round() as usize,
+    };
+    let activity = vad::detect(
+        samples,
+        analysis.sample_rate,
+        analysis.frame_size,
+        analysis.hop_size,
+        &track.voiced_probabilities,
+        &thresholds,
+    );
+    loqa_log!(
+        Debug,
+        "voice activity over {} frames: {} speech segments",
+        activity.timestamps.len(),
+        activity.segments.len()
+    );
+    Ok(activity)
+}
+
+/// Detects speech with energy, zero-crossing rate and pYIN voicing
+///
+/// A frame is speech when its RMS level reaches `energy_threshold_db` and it is
+/// voiced or has a zero-crossing rate of at least `zcr_threshold` (unvoiced
+/// consonants). Decisions are held for `hangover_time` after the last speech
+/// frame, and runs of speech frames are merged into sample ranges, so callers
+/// can skip silence before `detect_pitch_rust` or `extract_formants_rust`.
+/// Frames follow `analysis`, so they line up with
+/// `loqa_voice_analyzer_process_buffer` for the same config.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `analysis.frame_size`
+/// * `analysis` - Sample rate, framing and pitch range of the pYIN pass
+///   (see `loqa_analysis_config_default`; the algorithm field is ignored)
+/// * `config` - Decision thresholds and hangover (see `loqa_vad_config_default`)
+///
+/// # Returns
+/// * VadResultFFI with success=true, the framewise features and the speech segments
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+/// * Caller MUST call `loqa_free_voice_activity` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_detect_voice_activity(
+    buffer: *const c_float,
+    length: usize,
+    analysis: AnalysisConfigFFI,
+    config: VadConfigFFI,
+) -> VadResultFFI {
+    ffi_guard("loqa_detect_voice_activity", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            VadResultFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match detect_voice_activity_checked(samples, &analysis, &config) {
+            Ok(activity) => {
+                let segments: Vec<SpeechSegmentFFI> = activity
+                    .segments
+                    .iter()
+                    .map(|s| SpeechSegmentFFI { start: s.start, end: s.end })
+                    .collect();
+                let segment_count = segments.len();
+                // No speech gives a null segment array rather than a dangling pointer
+                let segments_ptr = if segments.is_empty() {
+                    std::ptr::null_mut()
+                } else {
+                    Box::into_raw(segments.into_boxed_slice()) as *mut SpeechSegmentFFI
+                };
+
+                // Ownership passes to the caller until loqa_free_voice_activity
+                VadResultFFI {
+                    success: true,
+                    frame_count: activity.timestamps.len(),
+                    segment_count,
+                    timestamps_ptr: Box::into_raw(activity.timestamps.into_boxed_slice())
+                        as *mut c_float,
+                    energy_db_ptr: Box::into_raw(activity.energy_db.into_boxed_slice())
+                        as *mut c_float,
+                    zcr_ptr: Box::into_raw(activity.zcr.into_boxed_slice()) as *mut c_float,
+                    voiced_probs_ptr: Box::into_raw(
+                        activity.voiced_probabilities.into_boxed_slice(),
+                    ) as *mut c_float,
+                    is_speech_ptr: Box::into_raw(activity.is_speech.into_boxed_slice())
+                        as *mut bool,
+                    segments_ptr,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Free the arrays of a VadResultFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a VadResultFFI from `loqa_detect_voice_activity`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_voice_activity(result: *mut VadResultFFI) {
+    ffi_guard("loqa_free_voice_activity", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        for ptr in [
+            &mut res.timestamps_ptr,
+            &mut res.energy_db_ptr,
+            &mut res.zcr_ptr,
+            &mut res.voiced_probs_ptr,
+        ] {
+            if !ptr.is_null() && res.frame_count > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, res.frame_count));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        if !res.is_speech_ptr.is_null() && res.frame_count > 0 {
+            let len = res.frame_count;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.is_speech_ptr, len));
+        }
+        if !res.segments_ptr.is_null() && res.segment_count > 0 {
+            let len = res.segment_count;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.segments_ptr, len));
+        }
+        res.is_speech_ptr = std::ptr::null_mut();
+        res.segments_ptr = std::ptr::null_mut();
+        res.frame_count = 0;
+        res.segment_count = 0;
+    })
+}
+
+// ============================================================================
+// Vocal tract length FFI - formant spacing and uniform-tube regression
+// ============================================================================
+
+/// Configuration for `loqa_estimate_vocal_tract_length`
+///
+/// Obtain defaults from `loqa_vtl_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VtlConfigFFI {
+    /// LPC order of the per-frame formant analysis in [8, 24]; 0 picks
+    /// sample_rate / 1000 + 2, as `extract_formants_rust` does
+    pub lpc_order: u32,
+    /// Minimum confidence in [0, 1] for a formant to be used
+    pub min_formant_confidence: c_float,
+    /// Speed of sound in the vocal tract in cm/s (> 0)
+    pub speed_of_sound: c_float,
+}
+
+/// Returns the default VTL configuration: LPC order from the sample rate,
+/// formant confidence 0.3 and a speed of sound of 35000 cm/s (warm, humid air)
+#[no_mangle]
+pub extern "C" fn loqa_vtl_config_default() -> VtlConfigFFI {
+    VtlConfigFFI {
+        lpc_order: 0,
+        min_formant_confidence: 0.3,
+        speed_of_sound: 35000.0,
+    }
+}
+
+/// Result structure for `loqa_estimate_vocal_tract_length`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VtlResultFFI {
+    /// True if at least one voiced frame gave an estimate
+    pub success: bool,
+    /// Vocal tract length from the uniform-tube regression in cm (primary estimate)
+    pub vtl_cm: c_float,
+    /// Vocal tract length from the average formant spacing in cm
+    pub spacing_vtl_cm: c_float,
+    /// Formant spacing of `vtl_cm` (speed_of_sound / 2·vtl_cm) in Hz
+    pub formant_spacing_hz: c_float,
+    /// Median F1 over the frames used in Hz (0.0 if never confident)
+    pub f1: c_float,
+    /// Median F2 over the frames used in Hz
+    pub f2: c_float,
+    /// Median F3 over the frames used in Hz
+    pub f3: c_float,
+    /// Median F4 over the frames used in Hz
+    pub f4: c_float,
+    /// 0.0-1.0: formant confidence × agreement between frames × coverage
+    pub confidence: c_float,
+    /// Voiced frames that contributed an estimate
+    pub frame_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for VtlResultFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        VtlResultFFI {
+            success: false,
+            vtl_cm: 0.0,
+            spacing_vtl_cm: 0.0,
+            formant_spacing_hz: 0.0,
+            f1: 0.0,
+            f2: 0.0,
+            f3: 0.0,
+            f4: 0.0,
+            confidence: 0.0,
+            frame_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+/// Validates both configs, tracks pitch with `process_buffer` and estimates
+/// the vocal tract length from the formants of the voiced frames
+///
+/// Shared by `loqa_estimate_vocal_tract_length` and the Android JNI bindings.
+pub(crate) fn estimate_vocal_tract_length_checked(
+    samples: &[f32],
+    analysis: &AnalysisConfigFFI,
+    config: &VtlConfigFFI,
+) -> Result<vtl::VocalTractLength, (LoqaErrorCode, String)> {
+    if !(0.0..=1.0).contains(&config.min_formant_confidence) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "min_formant_confidence must be in range [0, 1], got {}",
+                config.min_formant_confidence
+            ),
+        ));
+    }
+
+    if !(config.speed_of_sound.is_finite() && config.speed_of_sound > 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("speed_of_sound must be finite and > 0, got {}", config.speed_of_sound),
+        ));
+    }
+
+    let (analysis, track) = track_pitch_checked(samples, analysis)?;
+    let lpc_order = validate_formant_input(
+        &samples[..analysis.frame_size],
+        analysis.sample_rate as c_int,
+        config.lpc_order.min(c_int::MAX as u32) as c_int,
+    )?;
+
+    vtl::estimate(
+        samples,
+        analysis.sample_rate,
+        analysis.frame_size,
+        analysis.hop_size,
+        &track.pitch_track,
+        lpc_order,
+        config.min_formant_confidence,
+        config.speed_of_sound,
+    )
+    .ok_or_else(|| {
+        (
+            LoqaErrorCode::AnalysisFailed,
+            "no voiced frame had three confident formants in a plausible range".into(),
+        )
+    })
+}
+
+/// Estimates vocal tract length from the formant spacing of voiced frames
+///
+/// Each frame that `process_buffer` marks voiced is analysed for F1-F4 (see
+/// `extract_formants_rust`), and a uniform tube closed at the glottis
+/// (Fn = (2n - 1)·c / 4L) is fitted two ways: by regression of the formants
+/// on (2n - 1)/2 (Reby & McComb) and by their average spacing (Fitch). The
+/// medians over frames give the clip estimate, so a sustained vowel or a
+/// phrase of connected speech both work; use it for speaker normalization.
+///
+/// # Arguments
+/// * `buffer` - Pointer to input audio samples (Float32 array)
+/// * `length` - Number of samples; must be greater than `analysis.frame_size`
+/// * `analysis` - Sample rate, framing and pitch range of the pYIN pass
+///   (see `loqa_analysis_config_default`; the algorithm field is ignored)
+/// * `config` - LPC order, formant confidence and speed of sound
+///   (see `loqa_vtl_config_default`)
+///
+/// # Returns
+/// * VtlResultFFI with both estimates in cm, the median formants and a confidence
+///
+/// # Safety
+/// * Caller must ensure `buffer` points to valid memory of at least `length` samples
+#[no_mangle]
+pub unsafe extern "C" fn loqa_estimate_vocal_tract_length(
+    buffer: *const c_float,
+    length: usize,
+    analysis: AnalysisConfigFFI,
+    config: VtlConfigFFI,
+) -> VtlResultFFI {
+    ffi_guard("loqa_estimate_vocal_tract_length", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            VtlResultFFI::error_result(code)
+        };
+
+        if buffer.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "buffer pointer is null".into());
+        }
+
+        let samples = slice::from_raw_parts(buffer, length);
+        match estimate_vocal_tract_length_checked(samples, &analysis, &config) {
+            Ok(vtl) => {
+                let [f1, f2, f3, f4] = vtl.formants;
+                VtlResultFFI {
+                    success: true,
+                    vtl_cm: vtl.vtl_cm,
+                    spacing_vtl_cm: vtl.spacing_vtl_cm,
+                    formant_spacing_hz: vtl.formant_spacing_hz,
+                    f1,
+                    f2,
+                    f3,
+                    f4,
+                    confidence: vtl.confidence,
+                    frame_count: vtl.frame_count as u32,
+                    error_code: LoqaErrorCode::Ok,
+                }
+            }
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+// ============================================================================
+// Formant tracking FFI - framewise LPC with Viterbi continuity of F1-F4
+// ============================================================================
+
+/// Configuration for `loqa_formant_tracker_new`
+///
+/// Obtain defaults from `loqa_formant_tracker_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FormantTrackerConfigFFI {
+    /// Sample rate in Hz, in [8000, 48000]
+    pub sample_rate: u32,
+    /// LPC analysis window in samples, 10-100 ms at `sample_rate`
+    pub frame_size: u32,
+    /// Samples between frame starts, in [1, frame_size]
+    pub hop_size: u32,
+    /// LPC order in [8, 24]; 0 picks sample_rate / 1000 + 2, as
+    /// `extract_formants_rust` does
+    pub lpc_order: u32,
+    /// Highest formant frequency in Hz, in [3000, 7000] and below
+    /// sample_rate / 2: about 5000 for adult male, 5500 for adult female voices
+    pub ceiling_hz: c_float,
+    /// Weight of frame-to-frame continuity against the fit to neutral-vowel
+    /// formants (finite, >= 0); 0 labels every frame on its own
+    pub continuity_weight: c_float,
+    /// Minimum mean confidence of F1-F3 in [0, 1] for a frame to be reliable
+    pub min_confidence: c_float,
+    /// Frames below this RMS level in dBFS (finite, <= 0) are unreliable
+    pub silence_db: c_float,
+}
+
+/// Returns the default formant tracker configuration: 16 kHz, 25 ms frames
+/// every 10 ms, LPC order from the sample rate, a 5500 Hz ceiling, continuity
+/// weight 1, confidence 0.3 and silence below -50 dBFS
+#[no_mangle]
+pub extern "C" fn loqa_formant_tracker_config_default() -> FormantTrackerConfigFFI {
+    FormantTrackerConfigFFI {
+        sample_rate: 16000,
+        frame_size: 400,
+        hop_size: 160,
+        lpc_order: 0,
+        ceiling_hz: 5500.0,
+        continuity_weight: 1.0,
+        min_confidence: 0.3,
+        silence_db: -50.0,
+    }
+}
+
+/// Formant contours returned by `loqa_formant_tracker_process_buffer`
+///
+/// Arrays are heap-allocated and owned by caller after return; they are null
+/// when the buffer held no frame. Caller MUST call `loqa_free_formant_track`
+/// to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FormantTrackFFI {
+    /// True if processing succeeded
+    pub success: bool,
+    /// Row-major `length x 4` matrix of F1-F4 in Hz: frame i starts at
+    /// `frequencies_ptr[i * 4]`; 0.0 where a formant is missing
+    pub frequencies_ptr: *mut c_float,
+    /// Bandwidths of the same formants in Hz, laid out like `frequencies_ptr`
+    pub bandwidths_ptr: *mut c_float,
+    /// Mean confidence of F1-F3 of each frame [0.0, 1.0]
+    pub confidences_ptr: *mut c_float,
+    /// False for frames whose formants should not be used: silent, missing one
+    /// of F1-F3 or below `min_confidence`
+    pub reliable_ptr: *mut bool,
+    /// Start of each frame in seconds from buffer start (same grid as `PitchTrackFFI`)
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames
+    pub length: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for FormantTrackFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        FormantTrackFFI {
+            success: false,
+            frequencies_ptr: std::ptr::null_mut(),
+            bandwidths_ptr: std::ptr::null_mut(),
+            confidences_ptr: std::ptr::null_mut(),
+            reliable_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            length: 0,
+            error_code: code,
+        }
+    }
+}
+
+impl From<Vec<formant_track::TrackedFrame>> for FormantTrackFFI {
+    fn from(frames: Vec<formant_track::TrackedFrame>) -> Self {
+        // No frames gives null arrays rather than dangling pointers
+        if frames.is_empty() {
+            return FormantTrackFFI {
+                success: true,
+                ..FormantTrackFFI::error_result(LoqaErrorCode::Ok)
+            };
+        }
+
+        fn leak<T>(values: Vec<T>) -> *mut T {
+            Box::into_raw(values.into_boxed_slice()) as *mut T
+        }
+
+        let formants = || frames.iter().flat_map(|f| f.formants);
+
+        // Ownership passes to the caller until loqa_free_formant_track
+        FormantTrackFFI {
+            success: true,
+            frequencies_ptr: leak(formants().map(|f| f.frequency).collect()),
+            bandwidths_ptr: leak(formants().map(|f| f.bandwidth).collect()),
+            confidences_ptr: leak(frames.iter().map(|f| f.confidence).collect()),
+            reliable_ptr: leak(frames.iter().map(|f| f.reliable).collect()),
+            timestamps_ptr: leak(frames.iter().map(|f| f.timestamp).collect()),
+            length: frames.len(),
+            error_code: LoqaErrorCode::Ok,
+        }
+    }
+}
+
+/// Validates a formant tracker configuration and creates the tracker
+///
+/// Shared by `loqa_formant_tracker_new` and the Android JNI bindings.
+pub(crate) fn formant_tracker_new_checked(
+    config: &FormantTrackerConfigFFI,
+) -> Result<formant_track::FormantTracker, (LoqaErrorCode, String)> {
+    let sample_rate = config.sample_rate;
+    if !(8000..=48000).contains(&sample_rate) {
+        return Err((
+            LoqaErrorCode::InvalidSampleRate,
+            format!("sample_rate must be in range [8000, 48000] Hz, got {sample_rate}"),
+        ));
+    }
+
+    let (min_frame, max_frame) = (sample_rate / 100, sample_rate / 10);
+    if !(min_frame..=max_frame).contains(&config.frame_size) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "frame_size must be in range [{min_frame}, {max_frame}] (10-100 ms), got {}",
+                config.frame_size
+            ),
+        ));
+    }
+
+    if !(1..=config.frame_size).contains(&config.hop_size) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "hop_size must be in range [1, frame_size = {}], got {}",
+                config.frame_size, config.hop_size
+            ),
+        ));
+    }
+
+    let lpc_order = resolve_lpc_order(
+        sample_rate as c_int,
+        config.lpc_order.min(c_int::MAX as u32) as c_int,
+    )?;
+
+    let nyquist = sample_rate as f32 / 2.0;
+    if !((3000.0..=7000.0).contains(&config.ceiling_hz) && config.ceiling_hz < nyquist) {
+        return Err((
+            LoqaErrorCode::InvalidFrequencyRange,
+            format!(
+                "ceiling_hz must be in range [3000, 7000] Hz and below {nyquist}, got {}",
+                config.ceiling_hz
+            ),
+        ));
+    }
+
+    if !(config.continuity_weight.is_finite() && config.continuity_weight >= 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("continuity_weight must be finite and >= 0, got {}", config.continuity_weight),
+        ));
+    }
+
+    if !(0.0..=1.0).contains(&config.min_confidence) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("min_confidence must be in range [0, 1], got {}", config.min_confidence),
+        ));
+    }
+
+    if !(config.silence_db.is_finite() && config.silence_db <= 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("silence_db must be finite and <= 0, got {}", config.silence_db),
+        ));
+    }
+
+    Ok(formant_track::FormantTracker::new(formant_track::TrackerSettings {
+        sample_rate,
+        frame_size: config.frame_size as usize,
+        hop_size: config.hop_size as usize,
+        lpc_order,
+        ceiling_hz: config.ceiling_hz as f64,
+        continuity_weight: config.continuity_weight as f64,
+        min_confidence: config.min_confidence,
+        silence_db: config.silence_db,
+    }))
+}
+
+/// Checks the buffer length and tracks the formants of its frames
+///
+/// Shared by `loqa_formant_tracker_process_buffer` and the Android JNI bindings.
+pub(crate) fn track_formants_checked(
+    tracker: &mut formant_track::FormantTracker,
+    samples: &[f32],
+) -> Result<Vec<formant_track::TrackedFrame>, (LoqaErrorCode, String)> {
+    if samples.is_empty() {
+        return Err((LoqaErrorCode::InvalidLength, "length must be > 0, got 0".into()));
+    }
+
+    let frame_size = tracker.frame_size();
+    if samples.len() <= frame_size {
+        return Err((
+            LoqaErrorCode::BufferTooShort,
+            format!(
+                "buffer length {} too short for one frame (need more than {frame_size})",
+                samples.len()
+            ),
+        ));
+    }
+
+    let frames = tracker.process_buffer(samples);
+    loqa_log!(Debug, "formant track of {} samples: {} frames", samples.len(), frames.len());
+    Ok(frames)
+}
+
+/// Creates a formant tracker that follows F1-F4 smoothly across frames
+///
+/// `extract_formants_rust` analyses one buffer and numbers its LPC resonances
+/// in order, so over a word a resonance that comes or goes shifts the labels
+/// and the contours jump. The tracker runs LPC on every frame (as
+/// `extract_formants_rust` does), keeps all resonances below `ceiling_hz` as
+/// candidates and picks F1-F4 for the whole buffer by Viterbi decoding,
+/// trading fit to neutral-vowel formants against continuity between frames.
+/// A formant that drops out leaves a gap instead of shifting the others, and
+/// the last frame of a buffer seeds the next, so consecutive buffers of one
+/// recording join up.
+///
+/// # Arguments
+/// * `config` - Framing, LPC order, ceiling, continuity and reliability limits
+///   (see `loqa_formant_tracker_config_default`)
+///
+/// # Returns
+/// * Opaque tracker pointer; free it with `loqa_formant_tracker_free`
+/// * Null if the config is invalid; `loqa_last_error_message` holds the reason
+#[no_mangle]
+pub extern "C" fn loqa_formant_tracker_new(
+    config: FormantTrackerConfigFFI,
+) -> *mut std::ffi::c_void {
+    ffi_guard("loqa_formant_tracker_new", || {
+        clear_last_error();
+
+        match formant_tracker_new_checked(&config) {
+            Ok(tracker) => Box::into_raw(Box::new(tracker)) as *mut std::ffi::c_void,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Tracks F1-F4 over the frames of a buffer
+///
+/// Frames start every `hop_size` samples from the buffer start, as in
+/// `loqa_voice_analyzer_process_buffer`, so the arrays line up with its pitch
+/// track when both use the same sample rate, frame and hop sizes.
+///
+/// # Arguments
+/// * `tracker` - Pointer from `loqa_formant_tracker_new`
+/// * `samples` - Pointer to audio samples (Float32 array)
+/// * `len` - Number of samples; must be greater than `frame_size`
+///
+/// # Returns
+/// * FormantTrackFFI with success=true and allocated arrays if processing succeeded
+///
+/// # Safety
+/// * `tracker` must be a live pointer from `loqa_formant_tracker_new`, not used
+///   concurrently from another thread
+/// * `samples` must point to valid memory of at least `len` samples
+/// * Caller MUST call `loqa_free_formant_track` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_formant_tracker_process_buffer(
+    tracker: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+) -> FormantTrackFFI {
+    ffi_guard("loqa_formant_tracker_process_buffer", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            FormantTrackFFI::error_result(code)
+        };
+
+        let Some(tracker) = (tracker as *mut formant_track::FormantTracker).as_mut() else {
+            return fail(LoqaErrorCode::NullPointer, "tracker pointer is null".into());
+        };
+
+        if samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        match track_formants_checked(tracker, slice::from_raw_parts(samples, len)) {
+            Ok(frames) => frames.into(),
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Forgets the previous buffer, so the next one starts a new recording
+///
+/// # Safety
+/// * `tracker` must be null or a live pointer from `loqa_formant_tracker_new`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_formant_tracker_reset(tracker: *mut std::ffi::c_void) {
+    ffi_guard("loqa_formant_tracker_reset", || {
+        clear_last_error();
+
+        if let Some(tracker) = (tracker as *mut formant_track::FormantTracker).as_mut() {
+            tracker.reset();
+        }
+    })
+}
+
+/// Free a formant tracker; null is ignored
+///
+/// # Safety
+/// * `tracker` must be null or a pointer from `loqa_formant_tracker_new` that
+///   has not been freed
+#[no_mangle]
+pub unsafe extern "C" fn loqa_formant_tracker_free(tracker: *mut std::ffi::c_void) {
+    ffi_guard("loqa_formant_tracker_free", || {
+        clear_last_error();
+
+        if !tracker.is_null() {
+            drop(Box::from_raw(tracker as *mut formant_track::FormantTracker));
+        }
+    })
+}
+
+/// Free the arrays of a FormantTrackFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a FormantTrackFFI from
+///   `loqa_formant_tracker_process_buffer`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_formant_track(result: *mut FormantTrackFFI) {
+    ffi_guard("loqa_free_formant_track", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        let matrix_len = res.length * formant_track::TRACKED_FORMANTS;
+        for (ptr, len) in [
+            (&mut res.frequencies_ptr, matrix_len),
+            (&mut res.bandwidths_ptr, matrix_len),
+            (&mut res.confidences_ptr, res.length),
+            (&mut res.timestamps_ptr, res.length),
+        ] {
+            if !ptr.is_null() && len > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, len));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        if !res.reliable_ptr.is_null() && res.length > 0 {
+            let len = res.length;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.reliable_ptr, len));
+        }
+        res.reliable_ptr = std::ptr::null_mut();
+        res.length = 0;
+    })
+}
+
+// ============================================================================
+// Streaming analyzer FFI - pushes of any size with continuous timestamps
+// ============================================================================
+
+/// Frames produced by one `loqa_stream_analyzer_push` or `loqa_stream_analyzer_flush`
+///
+/// Arrays are heap-allocated and owned by caller after return; they are null
+/// when the call completed no frame. Caller MUST call `loqa_free_stream_frames`
+/// to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct StreamFramesFFI {
+    /// True if the push or flush succeeded (even if it completed no frame)
+    pub success: bool,
+    /// Pitch of each frame in Hz (0.0 if unvoiced)
+    pub frequencies_ptr: *mut c_float,
+    /// Confidence of each frame [0.0, 1.0]
+    pub confidences_ptr: *mut c_float,
+    /// Voicing decision of each frame
+    pub is_voiced_ptr: *mut bool,
+    /// Voiced probability of each frame [0.0, 1.0]
+    pub voiced_probs_ptr: *mut c_float,
+    /// Start of each frame in seconds since the stream began
+    pub timestamps_ptr: *mut c_float,
+    /// Number of frames (length of all five arrays)
+    pub frame_count: usize,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for StreamFramesFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        StreamFramesFFI {
+            success: false,
+            frequencies_ptr: std::ptr::null_mut(),
+            confidences_ptr: std::ptr::null_mut(),
+            is_voiced_ptr: std::ptr::null_mut(),
+            voiced_probs_ptr: std::ptr::null_mut(),
+            timestamps_ptr: std::ptr::null_mut(),
+            frame_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+impl From<Vec<stream::StreamFrame>> for StreamFramesFFI {
+    fn from(frames: Vec<stream::StreamFrame>) -> Self {
+        // No frames gives null arrays rather than dangling pointers
+        if frames.is_empty() {
+            return StreamFramesFFI {
+                success: true,
+                ..StreamFramesFFI::error_result(LoqaErrorCode::Ok)
+            };
+        }
+
+        fn leak<T>(values: Vec<T>) -> *mut T {
+            Box::into_raw(values.into_boxed_slice()) as *mut T
+        }
+
+        // Ownership passes to the caller until loqa_free_stream_frames
+        StreamFramesFFI {
+            success: true,
+            frequencies_ptr: leak(frames.iter().map(stream::StreamFrame::frequency).collect()),
+            confidences_ptr: leak(frames.iter().map(|f| f.pitch.confidence).collect()),
+            is_voiced_ptr: leak(frames.iter().map(|f| f.pitch.is_voiced).collect()),
+            voiced_probs_ptr: leak(frames.iter().map(|f| f.pitch.voiced_probability).collect()),
+            timestamps_ptr: leak(frames.iter().map(|f| f.timestamp).collect()),
+            frame_count: frames.len(),
+            error_code: LoqaErrorCode::Ok,
+        }
+    }
+}
+
+/// Validates an FFI analyzer config and creates a streaming analyzer for it
+///
+/// Shared by `loqa_stream_analyzer_new` and the Android JNI bindings.
+pub(crate) fn stream_analyzer_new_checked(
+    config: &AnalysisConfigFFI,
+) -> Result<stream::StreamingAnalyzer, (LoqaErrorCode, String)> {
+    let config = analysis_config_from_ffi(config)?;
+    stream::StreamingAnalyzer::new(config).map_err(|e| (LoqaErrorCode::InvalidArgument, e))
+}
+
+/// Creates a streaming analyzer that accepts audio in chunks of any size
+///
+/// Unlike `loqa_voice_analyzer_process_stream`, which only sees the frames
+/// inside each buffer it is given, the streaming analyzer keeps the samples
+/// between calls in a ring buffer. A frame is analysed (with `process_frame`
+/// and `config.algorithm`) as soon as its last sample is pushed, frames start
+/// every `hop_size` samples however the audio is chunked, and timestamps run
+/// on from push to push. Use it for live pitch display from microphone
+/// callbacks.
+///
+/// # Arguments
+/// * `config` - Analyzer configuration (see `loqa_analysis_config_default`)
+///
+/// # Returns
+/// * Opaque analyzer pointer; free it with `loqa_stream_analyzer_free`
+/// * Null if the config is invalid; `loqa_last_error_message` holds the reason
+#[no_mangle]
+pub extern "C" fn loqa_stream_analyzer_new(config: AnalysisConfigFFI) -> *mut std::ffi::c_void {
+    ffi_guard("loqa_stream_analyzer_new", || {
+        clear_last_error();
+
+        match stream_analyzer_new_checked(&config) {
+            Ok(analyzer) => Box::into_raw(Box::new(analyzer)) as *mut std::ffi::c_void,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Appends samples to a streaming analyzer and analyses every frame they complete
+///
+/// Frames upstream cannot analyse (such as silence) are reported unvoiced, so
+/// frame i of the stream always starts at i * hop_size / sample_rate seconds.
+/// A push that completes no frame succeeds with `frame_count` 0.
+///
+/// # Arguments
+/// * `analyzer` - Pointer from `loqa_stream_analyzer_new`
+/// * `samples` - Pointer to audio samples (Float32 array); may be null if `len` is 0
+/// * `len` - Number of samples; any size
+///
+/// # Returns
+/// * StreamFramesFFI with the completed frames in stream order
+///
+/// # Safety
+/// * `analyzer` must be a live pointer from `loqa_stream_analyzer_new`, not used
+///   concurrently from another thread
+/// * `samples` must point to valid memory of at least `len` samples
+/// * Caller MUST call `loqa_free_stream_frames` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_stream_analyzer_push(
+    analyzer: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+) -> StreamFramesFFI {
+    ffi_guard("loqa_stream_analyzer_push", || {
+        clear_last_error();
+
+        // Records the error and builds the empty result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            StreamFramesFFI::error_result(code)
+        };
+
+        let Some(analyzer) = (analyzer as *mut stream::StreamingAnalyzer).as_mut() else {
+            return fail(LoqaErrorCode::NullPointer, "analyzer pointer is null".into());
+        };
+
+        if len == 0 {
+            return StreamFramesFFI::from(Vec::new());
+        }
+
+        if samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        analyzer.push(slice::from_raw_parts(samples, len)).into()
+    })
+}
+
+/// Analyses the buffered tail of a stream and starts a new stream
+///
+/// The tail is zero-padded to full frames, and the frames whose centre lies in
+/// pushed audio are returned. Afterwards the buffer is empty and the next push
+/// starts at timestamp 0. Call it when recording stops.
+///
+/// # Safety
+/// * `analyzer` must be a live pointer from `loqa_stream_analyzer_new`
+/// * Caller MUST call `loqa_free_stream_frames` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_stream_analyzer_flush(
+    analyzer: *mut std::ffi::c_void,
+) -> StreamFramesFFI {
+    ffi_guard("loqa_stream_analyzer_flush", || {
+        clear_last_error();
+
+        let Some(analyzer) = (analyzer as *mut stream::StreamingAnalyzer).as_mut() else {
+            set_last_error(LoqaErrorCode::NullPointer, "analyzer pointer is null");
+            return StreamFramesFFI::error_result(LoqaErrorCode::NullPointer);
+        };
+
+        analyzer.flush().into()
+    })
+}
+
+/// Discards the buffered samples of a stream and restarts timestamps at 0
+///
+/// # Safety
+/// * `analyzer` must be null or a live pointer from `loqa_stream_analyzer_new`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_stream_analyzer_reset(analyzer: *mut std::ffi::c_void) {
+    ffi_guard("loqa_stream_analyzer_reset", || {
+        clear_last_error();
+
+        if let Some(analyzer) = (analyzer as *mut stream::StreamingAnalyzer).as_mut() {
+            analyzer.reset();
+        }
+    })
+}
+
+/// Free a streaming analyzer; null is ignored
+///
+/// # Safety
+/// * `analyzer` must be null or a pointer from `loqa_stream_analyzer_new` that
+///   has not been freed
+#[no_mangle]
+pub unsafe extern "C" fn loqa_stream_analyzer_free(analyzer: *mut std::ffi::c_void) {
+    ffi_guard("loqa_stream_analyzer_free", || {
+        clear_last_error();
+
+        if !analyzer.is_null() {
+            drop(Box::from_raw(analyzer as *mut stream::StreamingAnalyzer));
+        }
+    })
+}
+
+/// Free the arrays of a StreamFramesFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a StreamFramesFFI from
+///   `loqa_stream_analyzer_push` or `loqa_stream_analyzer_flush`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_stream_frames(result: *mut StreamFramesFFI) {
+    ffi_guard("loqa_free_stream_frames", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        for ptr in [
+            &mut res.frequencies_ptr,
+            &mut res.confidences_ptr,
+            &mut res.voiced_probs_ptr,
+            &mut res.timestamps_ptr,
+        ] {
+            if !ptr.is_null() && res.frame_count > 0 {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(*ptr, res.frame_count));
+            }
+            *ptr = std::ptr::null_mut();
+        }
+        if !res.is_voiced_ptr.is_null() && res.frame_count > 0 {
+            let len = res.frame_count;
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.is_voiced_ptr, len));
+        }
+        res.is_voiced_ptr = std::ptr::null_mut();
+        res.frame_count = 0;
+    })
+}
+
+// ============================================================================
+// Tuner FFI - nearest note, cents deviation and stability of live pitch
+// ============================================================================
+
+/// Configuration for `loqa_tuner_new`
+///
+/// Obtain defaults from `loqa_tuner_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TunerConfigFFI {
+    /// Frequency of A4 in Hz (finite, > 0)
+    pub reference_hz: c_float,
+    /// Offset of each pitch class (C, C#, ..., B) from equal temperament in
+    /// cents, within ±50; applied relative to A, all zeros is equal temperament
+    pub temperament_cents: [c_float; tuner::PITCH_CLASSES],
+    /// Frames of one note over which stability is measured, in [1, 1000]
+    pub stability_frames: u32,
+}
+
+/// Returns the default tuner configuration: A4 = 440 Hz, equal temperament
+/// and stability over 8 frames
+#[no_mangle]
+pub extern "C" fn loqa_tuner_config_default() -> TunerConfigFFI {
+    TunerConfigFFI {
+        reference_hz: 440.0,
+        temperament_cents: [0.0; tuner::PITCH_CLASSES],
+        stability_frames: 8,
+    }
+}
+
+/// Latest reading of a tuner, returned by `loqa_tuner_push`
+///
+/// The note fields are only meaningful when `is_voiced` is true.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TunerReadingFFI {
+    /// True if the push succeeded (even if it completed no frame)
+    pub success: bool,
+    /// Frames analysed by this push; 0 means the reading is the previous one
+    pub frame_count: u32,
+    /// Start of the frame read in seconds since the stream began
+    pub timestamp: c_float,
+    /// True if the frame is voiced and has a note
+    pub is_voiced: bool,
+    /// Refined pitch in Hz (0.0 if unvoiced)
+    pub frequency: c_float,
+    /// Pitch confidence [0.0, 1.0]
+    pub confidence: c_float,
+    /// MIDI note number of the nearest note (A4 = 69)
+    pub midi_note: i32,
+    /// Pitch class of the nearest note, 0 (C) to 11 (B)
+    pub pitch_class: u32,
+    /// Octave of the nearest note in scientific pitch notation (middle C is C4)
+    pub octave: i32,
+    /// Deviation from the tuned note in cents; positive is sharp
+    pub cents: c_float,
+    /// Frequency the nearest note is tuned to in Hz
+    pub target_frequency: c_float,
+    /// 0.0-1.0: how steadily the note has been held
+    pub stability: c_float,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for TunerReadingFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        TunerReadingFFI {
+            success: false,
+            frame_count: 0,
+            timestamp: 0.0,
+            is_voiced: false,
+            frequency: 0.0,
+            confidence: 0.0,
+            midi_note: 0,
+            pitch_class: 0,
+            octave: 0,
+            cents: 0.0,
+            target_frequency: 0.0,
+            stability: 0.0,
+            error_code: code,
+        }
+    }
+}
+
+impl From<(usize, tuner::TunerReading)> for TunerReadingFFI {
+    fn from((frame_count, reading): (usize, tuner::TunerReading)) -> Self {
+        let mut result = TunerReadingFFI {
+            success: true,
+            frame_count: frame_count as u32,
+            timestamp: reading.frame.timestamp,
+            confidence: reading.frame.pitch.confidence,
+            stability: reading.stability,
+            ..TunerReadingFFI::error_result(LoqaErrorCode::Ok)
+        };
+        if let Some(note) = reading.note {
+            result.is_voiced = true;
+            result.frequency = reading.frame.frequency();
+            result.midi_note = note.midi;
+            result.pitch_class = note.pitch_class();
+            result.octave = note.octave();
+            result.cents = note.cents;
+            result.target_frequency = note.target_hz;
+        }
+        result
+    }
+}
+
+/// Validates both configs and creates a tuner for them
+///
+/// Shared by `loqa_tuner_new` and the Android JNI bindings.
+pub(crate) fn tuner_new_checked(
+    analysis: &AnalysisConfigFFI,
+    config: &TunerConfigFFI,
+) -> Result<tuner::Tuner, (LoqaErrorCode, String)> {
+    if !(config.reference_hz.is_finite() && config.reference_hz > 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("reference_hz must be finite and > 0, got {}", config.reference_hz),
+        ));
+    }
+
+    let limit = tuner::MAX_TEMPERAMENT_OFFSET_CENTS;
+    if let Some(offset) = config.temperament_cents.iter().find(|c| !(-limit..=limit).contains(*c)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("temperament_cents must be in range [-{limit}, {limit}], got {offset}"),
+        ));
+    }
+
+    if !(1..=1000).contains(&config.stability_frames) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("stability_frames must be in range [1, 1000], got {}", config.stability_frames),
+        ));
+    }
+
+    let settings = tuner::TunerSettings {
+        reference_hz: config.reference_hz,
+        temperament: config.temperament_cents,
+        stability_frames: config.stability_frames as usize,
+    };
+    let analysis = analysis_config_from_ffi(analysis)?;
+    tuner::Tuner::new(analysis, settings).map_err(|e| (LoqaErrorCode::InvalidArgument, e))
+}
+
+/// Creates a tuner that reads the nearest note of live audio
+///
+/// Audio is pushed in chunks of any size and analysed as by
+/// `loqa_stream_analyzer_push`, with each voiced frame's period refined to a
+/// fraction of a sample. Every frame is matched to the nearest note under the
+/// temperament, tuned so that A4 is `reference_hz`, and the spread of its
+/// cents deviation over recent frames gives a stability indicator. Use a
+/// short frame and hop in `analysis` for a responsive display.
+///
+/// # Arguments
+/// * `analysis` - Sample rate, framing, pitch range and algorithm
+///   (see `loqa_analysis_config_default`)
+/// * `config` - Reference pitch, temperament and stability window
+///   (see `loqa_tuner_config_default`)
+///
+/// # Returns
+/// * Opaque tuner pointer; free it with `loqa_tuner_free`
+/// * Null if a config is invalid; `loqa_last_error_message` holds the reason
+#[no_mangle]
+pub extern "C" fn loqa_tuner_new(
+    analysis: AnalysisConfigFFI,
+    config: TunerConfigFFI,
+) -> *mut std::ffi::c_void {
+    ffi_guard("loqa_tuner_new", || {
+        clear_last_error();
+
+        match tuner_new_checked(&analysis, &config) {
+            Ok(tuner) => Box::into_raw(Box::new(tuner)) as *mut std::ffi::c_void,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Appends samples to a tuner and returns its latest reading
+///
+/// Frames the samples complete update the reading in order; if they complete
+/// none, the previous reading is returned with `frame_count` 0.
+///
+/// # Arguments
+/// * `tuner` - Pointer from `loqa_tuner_new`
+/// * `samples` - Pointer to audio samples (Float32 array); may be null if `len` is 0
+/// * `len` - Number of samples; any size
+///
+/// # Safety
+/// * `tuner` must be a live pointer from `loqa_tuner_new`, not used
+///   concurrently from another thread
+/// * `samples` must point to valid memory of at least `len` samples
+#[no_mangle]
+pub unsafe extern "C" fn loqa_tuner_push(
+    tuner: *mut std::ffi::c_void,
+    samples: *const c_float,
+    len: usize,
+) -> TunerReadingFFI {
+    ffi_guard("loqa_tuner_push", || {
+        clear_last_error();
+
+        // Records the error and builds the zeroed result carrying its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            TunerReadingFFI::error_result(code)
+        };
+
+        let Some(tuner) = (tuner as *mut tuner::Tuner).as_mut() else {
+            return fail(LoqaErrorCode::NullPointer, "tuner pointer is null".into());
+        };
+
+        if len > 0 && samples.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "samples pointer is null".into());
+        }
+
+        let samples = if len == 0 { &[] } else { slice::from_raw_parts(samples, len) };
+        tuner.push(samples).into()
+    })
+}
+
+/// Discards a tuner's buffered audio and reading; timestamps restart at 0
+///
+/// # Safety
+/// * `tuner` must be null or a live pointer from `loqa_tuner_new`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_tuner_reset(tuner: *mut std::ffi::c_void) {
+    ffi_guard("loqa_tuner_reset", || {
+        clear_last_error();
+
+        if let Some(tuner) = (tuner as *mut tuner::Tuner).as_mut() {
+            tuner.reset();
+        }
+    })
+}
+
+/// Free a tuner; null is ignored
+///
+/// # Safety
+/// * `tuner` must be null or a pointer from `loqa_tuner_new` that has not been freed
+#[no_mangle]
+pub unsafe extern "C" fn loqa_tuner_free(tuner: *mut std::ffi::c_void) {
+    ffi_guard("loqa_tuner_free", || {
+        clear_last_error();
+
+        if !tuner.is_null() {
+            drop(Box::from_raw(tuner as *mut tuner::Tuner));
+        }
+    })
+}
+
+// ============================================================================
+// Voice range profile FFI - semitone x dB phonetogram accumulated over a session
+// ============================================================================
+
+/// Configuration for `loqa_vrp_new`
+///
+/// Obtain defaults from `loqa_vrp_config_default` and override fields.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VrpConfigFFI {
+    /// Frequency of A4 in Hz (finite, > 0)
+    pub reference_hz: c_float,
+    /// MIDI note of the first row, in [0, 127]
+    pub min_midi_note: u32,
+    /// MIDI note of the last row, in [min_midi_note, 127]
+    pub max_midi_note: u32,
+    /// Lower edge of the first level column in dB (finite)
+    pub min_db: c_float,
+    /// Upper end of the level range in dB (> min_db); the last column may
+    /// extend past it when the span is not a whole number of steps
+    pub max_db: c_float,
+    /// Width of a level column in dB (> 0, at most 1000 columns)
+    pub db_step: c_float,
+    /// Frames a cell needs before it counts as sustained (>= 1)
+    pub min_cell_frames: u32,
+}
+
+/// Returns the default profile configuration: A4 = 440 Hz, notes C1 (24) to
+/// C8 (108), 40-120 dB in 1 dB steps, and 3 frames per sustained cell
+#[no_mangle]
+pub extern "C" fn loqa_vrp_config_default() -> VrpConfigFFI {
+    VrpConfigFFI {
+        reference_hz: 440.0,
+        min_midi_note: 24,
+        max_midi_note: 108,
+        min_db: 40.0,
+        max_db: 120.0,
+        db_step: 1.0,
+        min_cell_frames: 3,
+    }
+}
+
+/// Snapshot of a voice range profile, returned by `loqa_vrp_get`
+///
+/// Arrays are heap-allocated and owned by caller after return.
+/// Caller MUST call `loqa_free_vrp` to prevent memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct VrpFFI {
+    /// True if the snapshot was taken
+    pub success: bool,
+    /// Row-major `row_count x column_count` frame counts: row r is MIDI note
+    /// `min_midi_note + r` and starts at `counts_ptr[r * column_count]`
+    pub counts_ptr: *mut u32,
+    /// Per row: lower edge of the lowest sustained cell in dB (NaN if none)
+    pub soft_db_ptr: *mut c_float,
+    /// Per row: upper edge of the highest sustained cell in dB (NaN if none)
+    pub loud_db_ptr: *mut c_float,
+    /// Per row: loud minus soft level in dB (NaN if the note is not sustained)
+    pub dynamic_range_db_ptr: *mut c_float,
+    /// Number of rows (notes)
+    pub row_count: usize,
+    /// Number of level columns; column c starts at `min_db + c * db_step`
+    pub column_count: usize,
+    pub min_midi_note: i32,
+    pub min_db: c_float,
+    pub db_step: c_float,
+    /// Lowest MIDI note with a sustained cell (-1 if none)
+    pub lowest_midi_note: i32,
+    /// Highest MIDI note with a sustained cell (-1 if none)
+    pub highest_midi_note: i32,
+    /// Equal-tempered frequency of the lowest note in Hz (NaN if none)
+    pub lowest_hz: c_float,
+    /// Equal-tempered frequency of the highest note in Hz (NaN if none)
+    pub highest_hz: c_float,
+    /// Cells holding at least `min_cell_frames` frames
+    pub covered_cells: u32,
+    /// Covered cells times db_step, in semitone·dB
+    pub area: c_float,
+    /// Frames binned into the grid so far (saturates at u32::MAX)
+    pub frame_count: u32,
+    /// `LoqaErrorCode::Ok` on success, otherwise the reason `success` is false
+    pub error_code: LoqaErrorCode,
+}
+
+impl ErrorResult for VrpFFI {
+    fn error_result(code: LoqaErrorCode) -> Self {
+        VrpFFI {
+            success: false,
+            counts_ptr: std::ptr::null_mut(),
+            soft_db_ptr: std::ptr::null_mut(),
+            loud_db_ptr: std::ptr::null_mut(),
+            dynamic_range_db_ptr: std::ptr::null_mut(),
+            row_count: 0,
+            column_count: 0,
+            min_midi_note: 0,
+            min_db: 0.0,
+            db_step: 0.0,
+            lowest_midi_note: -1,
+            highest_midi_note: -1,
+            lowest_hz: f32::NAN,
+            highest_hz: f32::NAN,
+            covered_cells: 0,
+            area: 0.0,
+            frame_count: 0,
+            error_code: code,
+        }
+    }
+}
+
+impl From<&vrp::VoiceRangeProfile> for VrpFFI {
+    fn from(profile: &vrp::VoiceRangeProfile) -> Self {
+        let settings = profile.settings();
+        let summary = profile.summary();
+        let note_hz = |note: Option<i32>| note.map_or(f32::NAN, |m| settings.note_hz(m));
+        let db_or_nan = |levels: Vec<Option<f32>>| -> *mut c_float {
+            let levels: Vec<f32> = levels.into_iter().map(|db| db.unwrap_or(f32::NAN)).collect();
+            Box::into_raw(levels.into_boxed_slice()) as *mut c_float
+        };
+
+        // Ownership passes to the caller until loqa_free_vrp
+        VrpFFI {
+            success: true,
+            counts_ptr: Box::into_raw(profile.counts().to_vec().into_boxed_slice()) as *mut u32,
+            dynamic_range_db_ptr: db_or_nan(summary.dynamic_range_db()),
+            soft_db_ptr: db_or_nan(summary.soft_db),
+            loud_db_ptr: db_or_nan(summary.loud_db),
+            row_count: settings.rows(),
+            column_count: settings.db_columns,
+            min_midi_note: settings.min_note,
+            min_db: settings.min_db,
+            db_step: settings.db_step,
+            lowest_midi_note: summary.lowest_note.unwrap_or(-1),
+            highest_midi_note: summary.highest_note.unwrap_or(-1),
+            lowest_hz: note_hz(summary.lowest_note),
+            highest_hz: note_hz(summary.highest_note),
+            covered_cells: summary.covered_cells as u32,
+            area: summary.area,
+            frame_count: profile.frame_count().min(u32::MAX as u64) as u32,
+            error_code: LoqaErrorCode::Ok,
+        }
+    }
+}
+
+/// Validates `config` and creates an empty profile for it
+///
+/// Shared by `loqa_vrp_new` and the Android JNI bindings.
+pub(crate) fn vrp_new_checked(
+    config: &VrpConfigFFI,
+) -> Result<vrp::VoiceRangeProfile, (LoqaErrorCode, String)> {
+    if !(config.reference_hz.is_finite() && config.reference_hz > 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("reference_hz must be finite and > 0, got {}", config.reference_hz),
+        ));
+    }
+
+    let (min_note, max_note) = (config.min_midi_note as i32, config.max_midi_note as i32);
+    if !(vrp::MIDI_NOTES.contains(&min_note) && (min_note..=127).contains(&max_note)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "MIDI notes must satisfy 0 <= min_midi_note <= max_midi_note <= 127, got {} and {}",
+                config.min_midi_note, config.max_midi_note
+            ),
+        ));
+    }
+
+    let span = config.max_db - config.min_db;
+    if !(config.min_db.is_finite() && config.max_db.is_finite() && span > 0.0) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "min_db and max_db must be finite with min_db < max_db, got {} and {}",
+                config.min_db, config.max_db
+            ),
+        ));
+    }
+
+    let columns = (span / config.db_step).ceil();
+    if !(config.db_step > 0.0 && columns <= vrp::MAX_DB_COLUMNS as f32) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "db_step must be > 0 and give at most {} columns over {span} dB, got {}",
+                vrp::MAX_DB_COLUMNS,
+                config.db_step
+            ),
+        ));
+    }
+
+    if config.min_cell_frames == 0 {
+        return Err((LoqaErrorCode::InvalidArgument, "min_cell_frames must be >= 1, got 0".into()));
+    }
+
+    Ok(vrp::VoiceRangeProfile::new(vrp::VrpSettings {
+        reference_hz: config.reference_hz,
+        min_note,
+        max_note,
+        min_db: config.min_db,
+        db_step: config.db_step,
+        db_columns: columns as usize,
+        min_cell_frames: config.min_cell_frames,
+    }))
+}
+
+/// Validates a framewise pitch and intensity pair and adds it to `profile`
+///
+/// Shared by `loqa_vrp_add_frames` and the Android JNI bindings.
+pub(crate) fn vrp_add_frames_checked(
+    profile: &mut vrp::VoiceRangeProfile,
+    pitch_track: &[f32],
+    intensity_db: &[f32],
+) -> Result<usize, (LoqaErrorCode, String)> {
+    if pitch_track.len() != intensity_db.len() {
+        return Err((
+            LoqaErrorCode::InvalidLength,
+            format!(
+                "pitch_track and intensity_db must have equal lengths, got {} and {}",
+                pitch_track.len(),
+                intensity_db.len()
+            ),
+        ));
+    }
+
+    if let Some(i) = pitch_track.iter().position(|f| !(f.is_finite() && *f >= 0.0)) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!(
+                "pitch_track values must be finite and >= 0 (0 = unvoiced), got {} at frame {i}",
+                pitch_track[i]
+            ),
+        ));
+    }
+
+    if let Some(i) = intensity_db.iter().position(|db| !db.is_finite()) {
+        return Err((
+            LoqaErrorCode::InvalidArgument,
+            format!("intensity_db values must be finite, got {} at frame {i}", intensity_db[i]),
+        ));
+    }
+
+    Ok(profile.add_frames(pitch_track, intensity_db))
+}
+
+/// Creates an empty voice range profile (phonetogram)
+///
+/// The profile counts frames in a grid of one row per semitone and one
+/// column per `db_step` of intensity. Feed it the framewise pitch and
+/// intensity of each recording with `loqa_vrp_add_frames`, combine profiles
+/// of separate recordings with `loqa_vrp_merge`, and read the grid and the
+/// sustainable range with `loqa_vrp_get`.
+///
+/// # Arguments
+/// * `config` - Note and level ranges, step and coverage threshold
+///   (see `loqa_vrp_config_default`)
+///
+/// # Returns
+/// * Opaque profile pointer; free it with `loqa_vrp_free`
+/// * Null if the config is invalid; `loqa_last_error_message` holds the reason
+#[no_mangle]
+pub extern "C" fn loqa_vrp_new(config: VrpConfigFFI) -> *mut std::ffi::c_void {
+    ffi_guard("loqa_vrp_new", || {
+        clear_last_error();
+
+        match vrp_new_checked(&config) {
+            Ok(profile) => Box::into_raw(Box::new(profile)) as *mut std::ffi::c_void,
+            Err((code, message)) => {
+                set_last_error(code, message);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Adds framewise pitch and intensity to a profile
+///
+/// Frame i pairs `pitch_track[i]` with `intensity_db[i]`, so both must come
+/// from the same frame grid, e.g. `loqa_voice_analyzer_process_buffer` and
+/// `loqa_compute_intensity` with equal frame and hop sizes. Unvoiced frames
+/// (pitch 0) and frames outside the grid are skipped. Calibrate the intensity
+/// to dB SPL for profiles that compare across devices.
+///
+/// # Arguments
+/// * `profile` - Pointer from `loqa_vrp_new`
+/// * `pitch_track` - Pitch per frame in Hz (0.0 = unvoiced); may be null if `length` is 0
+/// * `intensity_db` - Level per frame in dB; may be null if `length` is 0
+/// * `length` - Number of frames in each array
+///
+/// # Returns
+/// * `LoqaErrorCode::Ok` if the frames were added
+/// * Otherwise the error code; `loqa_last_error_message` holds the reason and
+///   the profile is unchanged
+///
+/// # Safety
+/// * `profile` must be a live pointer from `loqa_vrp_new`, not used
+///   concurrently from another thread
+/// * `pitch_track` and `intensity_db` must point to valid memory of at least
+///   `length` values
+#[no_mangle]
+pub unsafe extern "C" fn loqa_vrp_add_frames(
+    profile: *mut std::ffi::c_void,
+    pitch_track: *const c_float,
+    intensity_db: *const c_float,
+    length: usize,
+) -> LoqaErrorCode {
+    ffi_guard("loqa_vrp_add_frames", || {
+        clear_last_error();
+
+        // Records the error and returns its code
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            code
+        };
+
+        let Some(profile) = (profile as *mut vrp::VoiceRangeProfile).as_mut() else {
+            return fail(LoqaErrorCode::NullPointer, "profile pointer is null".into());
+        };
+
+        if length > 0 && (pitch_track.is_null() || intensity_db.is_null()) {
+            return fail(
+                LoqaErrorCode::NullPointer,
+                "pitch_track or intensity_db pointer is null".into(),
+            );
+        }
+
+        let (pitch_track, intensity_db) = if length == 0 {
+            (&[][..], &[][..])
+        } else {
+            (
+                slice::from_raw_parts(pitch_track, length),
+                slice::from_raw_parts(intensity_db, length),
+            )
+        };
+        match vrp_add_frames_checked(profile, pitch_track, intensity_db) {
+            Ok(_) => LoqaErrorCode::Ok,
+            Err((code, message)) => fail(code, message),
+        }
+    })
+}
+
+/// Adds the counts of `source` to `target`, e.g. to combine recordings
+///
+/// Both profiles must have been created with the same config. `source` is
+/// left unchanged.
+///
+/// # Returns
+/// * `LoqaErrorCode::Ok` if the profiles were merged
+/// * Otherwise the error code; `loqa_last_error_message` holds the reason
+///
+/// # Safety
+/// * `target` and `source` must be live pointers from `loqa_vrp_new`, not
+///   used concurrently from another thread; they may be the same profile
+#[no_mangle]
+pub unsafe extern "C" fn loqa_vrp_merge(
+    target: *mut std::ffi::c_void,
+    source: *const std::ffi::c_void,
+) -> LoqaErrorCode {
+    ffi_guard("loqa_vrp_merge", || {
+        clear_last_error();
+
+        let fail = |code: LoqaErrorCode, message: String| {
+            set_last_error(code, message);
+            code
+        };
+
+        let Some(source) = (source as *const vrp::VoiceRangeProfile).as_ref() else {
+            return fail(LoqaErrorCode::NullPointer, "source pointer is null".into());
+        };
+        if target.is_null() {
+            return fail(LoqaErrorCode::NullPointer, "target pointer is null".into());
+        }
+
+        // Copy first so merging a profile into itself never aliases
+        let source = source.clone();
+        let target = &mut *(target as *mut vrp::VoiceRangeProfile);
+        match target.merge(&source) {
+            Ok(()) => LoqaErrorCode::Ok,
+            Err(message) => fail(LoqaErrorCode::InvalidArgument, message),
+        }
+    })
+}
+
+/// Returns the grid and the sustainable range of a profile
+///
+/// # Returns
+/// * VrpFFI with success=true and allocated arrays if `profile` is non-null
+///
+/// # Safety
+/// * `profile` must be a live pointer from `loqa_vrp_new`
+/// * Caller MUST call `loqa_free_vrp` to deallocate the arrays
+#[no_mangle]
+pub unsafe extern "C" fn loqa_vrp_get(profile: *const std::ffi::c_void) -> VrpFFI {
+    ffi_guard("loqa_vrp_get", || {
+        clear_last_error();
+
+        match (profile as *const vrp::VoiceRangeProfile).as_ref() {
+            Some(profile) => profile.into(),
+            None => {
+                set_last_error(LoqaErrorCode::NullPointer, "profile pointer is null");
+                VrpFFI::error_result(LoqaErrorCode::NullPointer)
+            }
+        }
+    })
+}
+
+/// Free the arrays of a VrpFFI; nulls its pointers, so a second call is a no-op
+///
+/// # Safety
+/// * `result` must be null or point to a VrpFFI from `loqa_vrp_get`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_free_vrp(result: *mut VrpFFI) {
+    ffi_guard("loqa_free_vrp", || {
+        clear_last_error();
+
+        let Some(res) = result.as_mut() else {
+            return;
+        };
+
+        if res.row_count > 0 {
+            let cells = res.row_count * res.column_count;
+            if !res.counts_ptr.is_null() {
+                let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(res.counts_ptr, cells));
+            }
+            for ptr in [res.soft_db_ptr, res.loud_db_ptr, res.dynamic_range_db_ptr] {
+                if !ptr.is_null() {
+                    let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, res.row_count));
+                }
+            }
+        }
+        res.counts_ptr = std::ptr::null_mut();
+        res.soft_db_ptr = std::ptr::null_mut();
+        res.loud_db_ptr = std::ptr::null_mut();
+        res.dynamic_range_db_ptr = std::ptr::null_mut();
+        res.row_count = 0;
+    })
+}
+
+/// Empties a profile's grid
+///
+/// # Safety
+/// * `profile` must be null or a live pointer from `loqa_vrp_new`
+#[no_mangle]
+pub unsafe extern "C" fn loqa_vrp_reset(profile: *mut std::ffi::c_void) {
+    ffi_guard("loqa_vrp_reset", || {
+        clear_last_error();
+
+        if let Some(profile) = (profile as *mut vrp::VoiceRangeProfile).as_mut() {
+            profile.reset();
+        }
+    })
+}
+
+/// Free a profile; null is ignored
+///
+/// # Safety
+/// * `profile` must be null or a pointer from `loqa_vrp_new` that has not been freed
+#[no_mangle]
+pub unsafe extern "C" fn loqa_vrp_free(profile: *mut std::ffi::c_void) {
+    ffi_guard("loqa_vrp_free", || {
+        clear_last_error();
+
+        if !profile.is_null() {
+            drop(Box::from_raw(profile as *mut vrp::VoiceRangeProfile));
+        }
+    })
+}
+
+/// Placeholder FFI function for testing build infrastructure (retained for backward compatibility)
+#[no_mangle]
+pub extern "C" fn test_ffi_bridge() -> i32 {
+    ffi_guard("test_ffi_bridge", || {
+        42
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_ffi_placeholder() {
+        assert_eq!(test_ffi_bridge(), 42);
+    }
+
+    #[test]
+    fn test_compute_fft_null_buffer() {
+        unsafe {
+            let result = compute_fft_rust(std::ptr::null(), 1024, 44100, 512);
+            assert!(result.is_null(), "Should return null for null buffer");
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_invalid_length() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), 0, 44100, 512);
+            assert!(result.is_null(), "Should return null for length <= 0");
+
+            let result = compute_fft_rust(buffer.as_ptr(), -10, 44100, 512);
+            assert!(result.is_null(), "Should return null for negative length");
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_invalid_sample_rate() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 0, 512);
+            assert!(result.is_null(), "Should return null for sample_rate <= 0");
+
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, -100, 512);
+            assert!(
+                result.is_null(),
+                "Should return null for negative sample_rate"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_invalid_fft_size_not_power_of_2() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+
+        unsafe {
+            // Test non-power-of-2 sizes
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 500);
+            assert!(
+                result.is_null(),
+                "Should return null for non-power-of-2 FFT size"
+            );
+
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 1000);
+            assert!(
+                result.is_null(),
+                "Should return null for non-power-of-2 FFT size"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_invalid_fft_size_out_of_range() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+
+        unsafe {
+            // Test below minimum (256)
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 128);
+            assert!(result.is_null(), "Should return null for FFT size < 256");
+
+            // Test above maximum (8192)
+            let result = compute_fft_rust(buffer.as_ptr(), 16384, 44100, 16384);
+            assert!(result.is_null(), "Should return null for FFT size > 8192");
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_valid_input_returns_non_null() {
+        // Generate a simple sine wave at 440 Hz
+        let sample_rate = 44100;
+        let frequency = 440.0;
+        let duration = 0.1; // 100ms
+        let num_samples = (sample_rate as f32 * duration) as usize;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            buffer.push((2.0 * PI * frequency * t).sin());
+        }
+
+        let fft_size = 2048;
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), num_samples as c_int, sample_rate, fft_size);
+            assert!(!result.is_null(), "Should return valid pointer");
+
+            // Clean up memory (fft_size / 2 + 1)
+            free_fft_result_rust(result, (fft_size / 2) + 1);
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_result_length() {
+        let buffer: Vec<f32> = vec![0.5; 2048];
+        let sample_rate = 44100;
+        let fft_size = 1024;
+        let expected_result_length = (fft_size / 2) + 1; // loqa-voice-dsp returns N/2 + 1
+
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), 2048, sample_rate, fft_size);
+            assert!(!result.is_null());
+
+            // Verify we can read the result (this tests memory safety)
+            let result_slice = slice::from_raw_parts(result, expected_result_length as usize);
+            assert_eq!(result_slice.len(), expected_result_length as usize);
+
+            // All values should be finite (not NaN or Infinity)
+            for val in result_slice {
+                assert!(val.is_finite(), "FFT result should be finite");
+            }
+
+            // Clean up
+            free_fft_result_rust(result, expected_result_length);
+        }
+    }
+
+    #[test]
+    fn test_compute_fft_sine_wave_peak_detection() {
+        // Generate a pure sine wave at known frequency
+        let sample_rate = 44100;
+        let target_frequency = 1000.0; // 1 kHz
+        let fft_size = 4096;
+        let num_samples = fft_size;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            buffer.push((2.0 * PI * target_frequency * t).sin());
+        }
+
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), num_samples as c_int, sample_rate, fft_size as c_int);
+            assert!(!result.is_null());
+
+            let magnitude_len = (fft_size / 2) + 1;
+            let magnitude_slice = slice::from_raw_parts(result, magnitude_len);
+
+            // Find the peak in the magnitude spectrum
+            let mut max_magnitude = 0.0_f32;
+            let mut max_index = 0;
+            for (i, &mag) in magnitude_slice.iter().enumerate() {
+                if mag > max_magnitude {
+                    max_magnitude = mag;
+                    max_index = i;
+                }
+            }
+
+            // Calculate the frequency of the peak
+            let peak_frequency = (max_index as f32) * (sample_rate as f32 / fft_size as f32);
+
+            // The peak should be close to our target frequency (within 1 bin)
+            let frequency_resolution = sample_rate as f32 / fft_size as f32;
+            let frequency_error = (peak_frequency - target_frequency).abs();
+
+            assert!(
+                frequency_error < frequency_resolution * 1.5,
+                "Peak frequency {peak_frequency} Hz should be close to target {target_frequency} Hz (error: {frequency_error} Hz)"
+            );
+
+            free_fft_result_rust(result, ((fft_size / 2) + 1) as c_int);
+        }
+    }
+
+    #[test]
+    fn test_free_fft_result_handles_null() {
+        // Should not crash
+        unsafe {
+            free_fft_result_rust(std::ptr::null_mut(), 256);
+        }
+    }
+
+    #[test]
+    fn test_free_fft_result_handles_invalid_length() {
+        let buffer: Vec<f32> = vec![0.5; 1024];
+        unsafe {
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 512);
+            assert!(!result.is_null());
+
+            // These should handle gracefully (not crash)
+            free_fft_result_rust(result, 0);
+        }
+        // Note: We've now leaked the memory, but that's ok for this test
+        // In production, free should be called with correct length
+    }
+
+    #[test]
+    fn test_memory_safety_multiple_allocations() {
+        // Test that we can allocate and free multiple FFT results without issues
+        let buffer: Vec<f32> = vec![0.5; 2048];
+        let sample_rate = 44100;
+        let fft_size = 1024;
+        let result_len = (fft_size / 2) + 1;
+
+        unsafe {
+            for _ in 0..10 {
+                let result = compute_fft_rust(buffer.as_ptr(), 2048, sample_rate, fft_size);
+                assert!(!result.is_null());
+                free_fft_result_rust(result, result_len);
+            }
+        }
+    }
+
+    // ======== Pitch Detection Tests ========
+
+    #[test]
+    fn test_detect_pitch_null_buffer() {
+        unsafe {
+            let result = detect_pitch_rust(std::ptr::null(), 1024, 44100);
+            assert_eq!(result.frequency, 0.0, "Should return frequency=0.0 for null buffer");
+            assert_eq!(result.confidence, 0.0, "Should return confidence=0.0 for null buffer");
+            assert!(!result.is_voiced, "Should return is_voiced=false for null buffer");
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_invalid_length() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test zero length
+            let result = detect_pitch_rust(buffer.as_ptr(), 0, 44100);
+            assert_eq!(result.frequency, 0.0);
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+
+            // Test negative length
+            let result = detect_pitch_rust(buffer.as_ptr(), -10, 44100);
+            assert_eq!(result.frequency, 0.0);
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_invalid_sample_rate_below_minimum() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test below 8000 Hz (AC3)
+            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 7999);
+            assert_eq!(result.frequency, 0.0, "Should return error for sample rate < 8000 Hz");
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+
+            // Test zero sample rate
+            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 0);
+            assert_eq!(result.frequency, 0.0);
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+
+            // Test negative sample rate
+            let result = detect_pitch_rust(buffer.as_ptr(), 1024, -100);
+            assert_eq!(result.frequency, 0.0);
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_invalid_sample_rate_above_maximum() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test above 48000 Hz (AC3)
+            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 48001);
+            assert_eq!(result.frequency, 0.0, "Should return error for sample rate > 48000 Hz");
+            assert_eq!(result.confidence, 0.0);
+            assert!(!result.is_voiced);
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_valid_sample_rates() {
+        let buffer: Vec<f32> = vec![0.5; 2048];
+
+        unsafe {
+            // Test minimum valid sample rate (8000 Hz)
+            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 8000);
+            // Should not error (frequency may be 0 due to buffer content, but call should succeed)
+            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+
+            // Test common sample rate (44100 Hz)
+            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 44100);
+            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+
+            // Test maximum valid sample rate (48000 Hz)
+            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 48000);
+            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_confidence_range() {
+        // Generate synthetic tone at 440 Hz
+        let sample_rate = 44100;
+        let frequency = 440.0;
+        let duration = 0.1; // 100ms
+        let num_samples = (sample_rate as f32 * duration) as usize;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            buffer.push((2.0 * PI * frequency * t).sin());
+        }
+
+        unsafe {
+            let result = detect_pitch_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+
+            // AC5: Confidence must be in range [0.0, 1.0]
+            assert!(
+                result.confidence >= 0.0 && result.confidence <= 1.0,
+                "Confidence {:.3} must be in range [0.0, 1.0]",
+                result.confidence
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_sine_wave_220hz() {
+        // Generate a pure 220 Hz sine wave (A3) - within human voice range
+        let sample_rate = 44100;
+        let target_frequency = 220.0; // Within MIN_FREQUENCY..MAX_FREQUENCY range
+        let duration = 0.1; // 100ms should be enough for YIN
+        let num_samples = (sample_rate as f32 * duration) as usize;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            buffer.push((2.0 * PI * target_frequency * t).sin());
+        }
+
+        unsafe {
+            let result = detect_pitch_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+
+            // For a clear sine wave within the detection range, we should detect a pitch
+            // YIN is very accurate for pure tones in the target frequency range
+            if result.is_voiced {
+                // If voiced, frequency should be close to 220 Hz
+                let error = (result.frequency - target_frequency).abs();
+                let error_percent = (error / target_frequency) * 100.0;
+
+                assert!(
+                    error_percent < 10.0,
+                    "Detected frequency {:.1} Hz should be within 10% of target {:.1} Hz (error: {:.2}%)",
+                    result.frequency,
+                    target_frequency,
+                    error_percent
+                );
+
+                // Confidence should be reasonably high for clean tone
+                assert!(
+                    result.confidence > 0.5,
+                    "Confidence {:.3} should be > 0.5 for clear sine wave",
+                    result.confidence
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_silence_returns_unvoiced() {
+        // Test with silence (all zeros)
+        let buffer: Vec<f32> = vec![0.0; 2048];
+        let sample_rate = 44100;
+
+        unsafe {
+            let result = detect_pitch_rust(buffer.as_ptr(), 2048, sample_rate);
+
+            // AC4: Silence should return frequency=0.0 and is_voiced=false
+            assert_eq!(
+                result.frequency, 0.0,
+                "Silence should return frequency=0.0"
+            );
+            assert!(
+                !result.is_voiced,
+                "Silence should be classified as unvoiced"
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_noise_behavior() {
+        // Generate white noise (random values)
+        let mut buffer: Vec<f32> = vec![0.0; 2048];
+        let sample_rate = 44100;
+
+        // Simple pseudo-random noise generator
+        for (i, sample) in buffer.iter_mut().enumerate() {
+            // Use a simple hash-like function for reproducibility
+            let hash = (i as u32).wrapping_mul(2654435761);
+            *sample = ((hash % 1000) as f32 / 1000.0) * 2.0 - 1.0; // Range: [-1.0, 1.0]
+        }
+
+        unsafe {
+            let result = detect_pitch_rust(buffer.as_ptr(), 2048, sample_rate);
+
+            // Noise behavior: The YIN algorithm may detect spurious periodicities in noise
+            // The important thing is that confidence values are always in valid range
+            assert!(
+                result.confidence >= 0.0 && result.confidence <= 1.0,
+                "Confidence must be in valid range [0.0, 1.0], got {:.3}",
+                result.confidence
+            );
+
+            // AC4: If unvoiced, frequency should be 0.0
+            if !result.is_voiced {
+                assert_eq!(
+                    result.frequency, 0.0,
+                    "Unvoiced noise should have frequency=0.0"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_multiple_sample_rates() {
+        // Generate 220 Hz tone (A3)
+        let target_frequency = 220.0;
+
+        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
+            let duration = 0.1;
+            let num_samples = (sample_rate as f32 * duration) as usize;
+
+            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+            for i in 0..num_samples {
+                let t = i as f32 / sample_rate as f32;
+                buffer.push((2.0 * PI * target_frequency * t).sin());
+            }
+
+            unsafe {
+                let result = detect_pitch_rust(
+                    buffer.as_ptr(),
+                    num_samples as c_int,
+                    sample_rate as c_int
+                );
+
+                // AC3: All sample rates in 8000-48000 Hz should work
+                assert!(
+                    result.confidence >= 0.0 && result.confidence <= 1.0,
+                    "Sample rate {} Hz should work (got confidence {:.3})",
+                    sample_rate,
+                    result.confidence
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_result_struct_layout() {
+        // Verify PitchResult struct is properly laid out for FFI
+        // This is a compile-time check, but runtime verification doesn't hurt
+        let test_result = PitchResult {
+            frequency: 440.0,
+            confidence: 0.95,
+            is_voiced: true,
+            error_code: LoqaErrorCode::Ok,
+        };
+
+        assert_eq!(test_result.frequency, 440.0);
+        assert_eq!(test_result.confidence, 0.95);
+        assert!(test_result.is_voiced);
+
+        // Verify struct is Copy (required for FFI)
+        let copied = test_result;
+        assert_eq!(copied.frequency, 440.0);
+        assert_eq!(test_result.frequency, 440.0); // Original still valid
+    }
+
+    #[test]
+    fn test_detect_pitch_ex_extended_ranges_with_every_algorithm() {
+        // Sopranos/children above the legacy 400 Hz cap and vocal fry below 80 Hz
+        let cases = [(1046.5, 44100, 500.0, 1500.0), (60.0, 16000, 50.0, 200.0)];
+        let algorithms = [
+            LoqaPitchAlgorithm::Auto,
+            LoqaPitchAlgorithm::Pyin,
+            LoqaPitchAlgorithm::Yin,
+            LoqaPitchAlgorithm::Autocorr,
+        ];
+
+        for (frequency, sample_rate, min, max) in cases {
+            let samples = sine_wave(frequency, sample_rate, 4096);
+            for algorithm in algorithms {
+                let result = unsafe {
+                    loqa_detect_pitch_ex(
+                        samples.as_ptr(),
+                        samples.len(),
+                        sample_rate,
+                        min,
+                        max,
+                        algorithm as u32,
+                    )
+                };
+                assert!(result.success, "{algorithm:?} failed at {frequency} Hz");
+                assert_eq!(result.error_code, LoqaErrorCode::Ok);
+                assert!(result.is_voiced, "{algorithm:?} unvoiced at {frequency} Hz");
+                assert!(
+                    (result.frequency - frequency).abs() < frequency * 0.03,
+                    "{algorithm:?}: expected ~{frequency} Hz, got {} Hz",
+                    result.frequency
+                );
+                assert!((0.0..=1.0).contains(&result.voiced_probability));
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_pitch_ex_rejects_buffer_too_short_for_range() {
+        // 50 Hz at 44.1 kHz needs two 882-sample periods
+        let samples = sine_wave(100.0, 44100, 1024);
+        let result = unsafe {
+            loqa_detect_pitch_ex(samples.as_ptr(), samples.len(), 44100, 50.0, 400.0, 0)
+        };
+        assert!(!result.success);
+        assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+
+        // The range fits, but the buffer is below the analyzer's minimum frame
+        let samples = sine_wave(800.0, 16000, 256);
+        let result = unsafe {
+            loqa_detect_pitch_ex(samples.as_ptr(), samples.len(), 16000, 500.0, 1000.0, 2)
+        };
+        assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+
+        // The legacy export applies the same range check
+        let result = unsafe { detect_pitch_rust(samples.as_ptr(), 150, 16000) };
+        assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+    }
+
+    #[test]
+    fn test_detect_pitch_range_matches_legacy_detector() {
+        // 256 samples: below the analyzer minimum that loqa_detect_pitch_ex enforces
+        let samples = sine_wave(440.0, 16000, 256);
+        let legacy =
+            unsafe { loqa_detect_pitch(samples.as_ptr(), samples.len(), 16000, 300.0, 600.0) };
+        let result = unsafe {
+            loqa_detect_pitch_range(samples.as_ptr(), samples.len(), 16000, 300.0, 600.0)
+        };
+        assert!(legacy.success && result.success);
+        assert_eq!(result.error_code, LoqaErrorCode::Ok);
+        assert_eq!(result.frequency, legacy.frequency);
+        assert_eq!(result.confidence, legacy.confidence);
+        assert_eq!(result.is_voiced, legacy.is_voiced);
+
+        let result = unsafe {
+            loqa_detect_pitch_range(samples.as_ptr(), samples.len(), 16000, 400.0, 300.0)
+        };
+        assert_eq!(result.error_code, LoqaErrorCode::InvalidFrequencyRange);
+        let result = unsafe { loqa_detect_pitch_range(std::ptr::null(), 256, 16000, 80.0, 400.0) };
+        assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+    }
+
+    #[test]
+    fn test_detect_pitch_ex_invalid_arguments() {
+        let samples = sine_wave(220.0, 16000, 2048);
+        let detect = |sample_rate: u32, min: f32, max: f32, algorithm: u32| unsafe {
+            loqa_detect_pitch_ex(samples.as_ptr(), samples.len(), sample_rate, min, max, algorithm)
+        };
+
+        // Above Nyquist
+        assert_eq!(detect(16000, 80.0, 9000.0, 0).error_code, LoqaErrorCode::InvalidFrequencyRange);
+        assert_eq!(detect(16000, 400.0, 80.0, 0).error_code, LoqaErrorCode::InvalidFrequencyRange);
+        assert_eq!(detect(96000, 80.0, 400.0, 0).error_code, LoqaErrorCode::InvalidSampleRate);
+        assert_eq!(detect(16000, 80.0, 400.0, 4).error_code, LoqaErrorCode::InvalidArgument);
+
+        let result = unsafe { loqa_detect_pitch_ex(std::ptr::null(), 2048, 16000, 80.0, 400.0, 0) };
+        assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
+    }
+
+    // ======== Formant Extraction Tests ========
+
+    #[test]
+    fn test_extract_formants_null_buffer() {
+        unsafe {
+            let result = extract_formants_rust(std::ptr::null(), 1024, 44100, 0);
+            assert_eq!(result.f1, 0.0, "Should return f1=0.0 for null buffer");
+            assert_eq!(result.f2, 0.0, "Should return f2=0.0 for null buffer");
+            assert_eq!(result.f3, 0.0, "Should return f3=0.0 for null buffer");
+            assert_eq!(result.bw1, 0.0, "Should return bw1=0.0 for null buffer");
+            assert_eq!(result.bw2, 0.0, "Should return bw2=0.0 for null buffer");
+            assert_eq!(result.bw3, 0.0, "Should return bw3=0.0 for null buffer");
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_invalid_length() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test zero length
+            let result = extract_formants_rust(buffer.as_ptr(), 0, 44100, 0);
+            assert_eq!(result.f1, 0.0);
+            assert_eq!(result.f2, 0.0);
+            assert_eq!(result.f3, 0.0);
+
+            // Test negative length
+            let result = extract_formants_rust(buffer.as_ptr(), -10, 44100, 0);
+            assert_eq!(result.f1, 0.0);
+            assert_eq!(result.f2, 0.0);
+            assert_eq!(result.f3, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_invalid_sample_rate() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test below 8000 Hz (AC3)
+            let result = extract_formants_rust(buffer.as_ptr(), 1024, 7999, 0);
+            assert_eq!(result.f1, 0.0, "Should return error for sample rate < 8000 Hz");
+
+            // Test above 48000 Hz
+            let result = extract_formants_rust(buffer.as_ptr(), 1024, 48001, 0);
+            assert_eq!(result.f1, 0.0, "Should return error for sample rate > 48000 Hz");
+
+            // Test zero/negative sample rate
+            let result = extract_formants_rust(buffer.as_ptr(), 1024, 0, 0);
+            assert_eq!(result.f1, 0.0);
+
+            let result = extract_formants_rust(buffer.as_ptr(), 1024, -100, 0);
+            assert_eq!(result.f1, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_default_lpc_order() {
+        // Test that default LPC order is computed correctly (AC4)
+        // Default should be: (sample_rate / 1000) + 2
+
+        let test_cases = [
+            (8000, (8000 / 1000) + 2),    // 10
+            (16000, (16000 / 1000) + 2),  // 18
+            (44100, (44100 / 1000) + 2),  // 46
+            (48000, (48000 / 1000) + 2),  // 50
+        ];
+
+        for (sample_rate, expected_order) in test_cases {
+            let buffer_len = expected_order * 4; // Ensure buffer is long enough
+            let buffer: Vec<f32> = vec![0.5; buffer_len as usize];
+
+            unsafe {
+                // Call with lpc_order = 0 to use default
+                let result = extract_formants_rust(buffer.as_ptr(), buffer_len, sample_rate, 0);
+
+                // If the function succeeds (doesn't return error), it used the default order
+                // We can't directly verify the order, but we can verify the function accepts valid inputs
+                // The function should not crash or return null - formants may be 0 due to buffer content
+                assert!(
+                    result.f1 >= 0.0 && result.f2 >= 0.0 && result.f3 >= 0.0,
+                    "Formant values should be non-negative for sample rate {sample_rate} Hz",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_custom_lpc_order() {
+        let buffer: Vec<f32> = vec![0.5; 2048];
+        let sample_rate = 44100;
+        let custom_lpc_order = 20;
+
+        unsafe {
+            let result = extract_formants_rust(buffer.as_ptr(), 2048, sample_rate, custom_lpc_order);
+
+            // Should accept custom LPC order
+            // Formant values should be non-negative
+            assert!(result.f1 >= 0.0);
+            assert!(result.f2 >= 0.0);
+            assert!(result.f3 >= 0.0);
+            assert!(result.bw1 >= 0.0);
+            assert!(result.bw2 >= 0.0);
+            assert!(result.bw3 >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_buffer_too_short() {
+        // Buffer must be at least lpc_order * 2 samples long
+        let sample_rate = 44100;
+        let lpc_order = 46; // Default for 44100 Hz
+        let buffer_len = lpc_order - 1; // Too short
+        let buffer: Vec<f32> = vec![0.5; buffer_len as usize];
+
+        unsafe {
+            let result = extract_formants_rust(buffer.as_ptr(), buffer_len, sample_rate, lpc_order);
+
+            // Should return error (zeros) for buffer that's too short
+            assert_eq!(result.f1, 0.0, "Should fail for buffer that's too short");
+            assert_eq!(result.f2, 0.0);
+            assert_eq!(result.f3, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_vowel_a_synthetic() {
+        // Test formant extraction with a synthetic vowel-like signal
+        // Note: LPC analysis is designed for real voiced speech signals
+        // Synthetic signals may not produce accurate formant estimates, but we test basic functionality
+        let sample_rate = 44100;
+        let duration = 0.1; // 100ms for better LPC analysis
+        let num_samples = (sample_rate as f32 * duration) as usize;
+
+        // Create a more realistic synthetic vowel using pitch + formant resonances
+        // Fundamental frequency (pitch): 120 Hz (typical male voice)
+        let f0 = 120.0;
+        // Formant frequencies for /a/ vowel: F1 ~700 Hz, F2 ~1200 Hz, F3 ~2500 Hz
+        let f1_target = 700.0;
+        let f2_target = 1200.0;
+        let f3_target = 2500.0;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            // Generate pitched source signal (sum of harmonics)
+            let mut source = 0.0;
+            for harmonic in 1..=20 {
+                let freq = f0 * harmonic as f32;
+                source += (1.0 / harmonic as f32) * (2.0 * PI * freq * t).sin();
+            }
+            // Apply simple formant emphasis (not perfect, but better than raw sine waves)
+            let formant_emphasis =
+                0.5 * (2.0 * PI * f1_target * t).sin() +
+                0.3 * (2.0 * PI * f2_target * t).sin() +
+                0.2 * (2.0 * PI * f3_target * t).sin();
+            buffer.push(source * 0.3 + formant_emphasis * 0.7);
+        }
+
+        unsafe {
+            let result = extract_formants_rust(
+                buffer.as_ptr(),
+                num_samples as c_int,
+                sample_rate,
+                0  // Use default LPC order
+            );
+
+            // AC1, AC2, AC5: Should extract formants and return them in Hz
+            // For synthetic signals, LPC may produce varying results
+            // Key tests:
+            // 1. Function executes without crashing
+            // 2. Returns valid (finite, non-NaN) values
+            // 3. At least one formant is detected (F1 should be non-zero for voiced signal)
+
+            // All formants should be finite (not NaN or Infinity)
+            assert!(result.f1.is_finite(), "F1 should be finite");
+            assert!(result.f2.is_finite(), "F2 should be finite");
+            assert!(result.f3.is_finite(), "F3 should be finite");
+
+            // All formants should be non-negative
+            assert!(result.f1 >= 0.0, "F1 should be non-negative");
+            assert!(result.f2 >= 0.0, "F2 should be non-negative");
+            assert!(result.f3 >= 0.0, "F3 should be non-negative");
+
+            // For a voiced signal (even synthetic), we expect at least F1 to be detected
+            // F2 and F3 may be 0 depending on the signal quality and LPC algorithm behavior
+            if result.f1 > 0.0 {
+                // If formants are detected, they should be in physically plausible ranges
+                // Very wide ranges to accommodate synthetic signal limitations
+                assert!(
+                    result.f1 <= 5000.0,
+                    "F1 {:.1} Hz should be below Nyquist/2 for 44.1kHz",
+                    result.f1
+                );
+                if result.f2 > 0.0 {
+                    assert!(
+                        result.f2 <= 5000.0,
+                        "F2 {:.1} Hz should be below Nyquist/2",
+                        result.f2
+                    );
+                }
+                if result.f3 > 0.0 {
+                    assert!(
+                        result.f3 <= 5000.0,
+                        "F3 {:.1} Hz should be below Nyquist/2",
+                        result.f3
+                    );
+                }
+            }
+
+            // Bandwidths come from the LPC pole radii
+            assert!(result.bw1 >= 0.0, "Bandwidth 1 should be non-negative");
+            assert!(result.bw2 >= 0.0, "Bandwidth 2 should be non-negative");
+            assert!(result.bw3 >= 0.0, "Bandwidth 3 should be non-negative");
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_reports_bandwidths_f4_f5_and_confidence() {
+        // 100 Hz glottal pulse train through resonators at known (frequency, bandwidth)
+        let sample_rate = 16000;
+        let resonances = [(730.0, 60.0), (1090.0, 90.0), (2440.0, 120.0), (3400.0, 180.0), (4200.0, 250.0)];
+        let buffer = formants::tests::synthetic_vowel(&resonances, 100.0, sample_rate, 4000);
+
+        let result = unsafe { extract_formants_rust(buffer.as_ptr(), 4000, sample_rate as i32, 0) };
+        assert_eq!(result.error_code, LoqaErrorCode::Ok);
+
+        let estimated: [(f32, f32); 5] = [
+            (result.f1, result.bw1),
+            (result.f2, result.bw2),
+            (result.f3, result.bw3),
+            (result.f4, result.bw4),
+            (result.f5, result.bw5),
+        ];
+        for (i, ((frequency, bandwidth), (expected_f, expected_bw))) in
+            estimated.into_iter().zip(resonances).enumerate()
+        {
+            let (frequency, bandwidth) = (frequency as f64, bandwidth as f64);
+            assert!(
+                (frequency - expected_f).abs() < expected_f * 0.08,
+                "F{}: expected {expected_f} Hz, got {frequency} Hz",
+                i + 1
+            );
+            assert!(
+                (bandwidth - expected_bw).abs() < expected_bw * 0.4,
+                "B{}: expected {expected_bw} Hz, got {bandwidth} Hz",
+                i + 1
+            );
+            assert!(result.confidence[i] > 0.0 && result.confidence[i] <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_ex_matches_rust_export_and_reports_errors() {
+        let resonances = [(730.0, 60.0), (1090.0, 90.0), (2440.0, 120.0), (3400.0, 180.0), (4200.0, 250.0)];
+        let buffer = formants::tests::synthetic_vowel(&resonances, 100.0, 16000, 4000);
+
+        unsafe {
+            let ex = loqa_extract_formants_ex(buffer.as_ptr(), buffer.len(), 16000, 0);
+            let rust = extract_formants_rust(buffer.as_ptr(), buffer.len() as c_int, 16000, 0);
+            assert_eq!(ex.error_code, LoqaErrorCode::Ok);
+            assert_eq!((ex.f1, ex.f4, ex.bw2, ex.confidence), (rust.f1, rust.f4, rust.bw2, rust.confidence));
+
+            let result = loqa_extract_formants_ex(std::ptr::null(), 4000, 16000, 0);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+
+            let result = loqa_extract_formants_ex(buffer.as_ptr(), buffer.len(), 96000, 0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
+
+            let result = loqa_extract_formants_ex(buffer.as_ptr(), buffer.len(), 16000, u32::MAX);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLpcOrder);
+            assert_eq!(result.f1, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_multiple_sample_rates() {
+        // Test formant extraction works across different sample rates
+        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
+            let duration = 0.05; // 50ms
+            let num_samples = (sample_rate as f32 * duration) as usize;
+
+            // Generate a simple periodic signal
+            let frequency = 200.0;
+            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+            for i in 0..num_samples {
+                let t = i as f32 / sample_rate as f32;
+                buffer.push((2.0 * PI * frequency * t).sin());
+            }
+
+            unsafe {
+                let result = extract_formants_rust(
+                    buffer.as_ptr(),
+                    num_samples as c_int,
+                    sample_rate as c_int,
+                    0  // Use default LPC order
+                );
+
+                // AC3: All sample rates in 8000-48000 Hz should work
+                // Formants should be non-negative (may be 0 depending on signal)
+                assert!(
+                    result.f1 >= 0.0 && result.f2 >= 0.0 && result.f3 >= 0.0,
+                    "Sample rate {} Hz should work (got F1={:.1}, F2={:.1}, F3={:.1})",
+                    sample_rate,
+                    result.f1,
+                    result.f2,
+                    result.f3
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_result_struct_layout() {
+        // Verify FormantsResult struct is properly laid out for FFI
+        let test_result = FormantsResult {
+            f1: 700.0,
+            f2: 1200.0,
+            f3: 2500.0,
+            bw1: 50.0,
+            bw2: 100.0,
+            bw3: 150.0,
+            f4: 3500.0,
+            f5: 4500.0,
+            bw4: 200.0,
+            bw5: 250.0,
+            confidence: [0.9, 0.8, 0.7, 0.6, 0.5],
+            error_code: LoqaErrorCode::Ok,
+        };
+
+        assert_eq!(test_result.f1, 700.0);
+        assert_eq!(test_result.f2, 1200.0);
+        assert_eq!(test_result.f3, 2500.0);
+        assert_eq!(test_result.bw1, 50.0);
+        assert_eq!(test_result.bw2, 100.0);
+        assert_eq!(test_result.bw3, 150.0);
+
+        // Verify struct is Copy (required for FFI)
+        let copied = test_result;
+        assert_eq!(copied.f1, 700.0);
+        assert_eq!(test_result.f1, 700.0); // Original still valid
     }
 
     #[test]
-    fn test_memory_safety_multiple_allocations() {
-        // Test that we can allocate and free multiple FFT results without issues
+    fn test_extract_formants_silence() {
+        // Test with silence (all zeros)
+        let buffer: Vec<f32> = vec![0.0; 2048];
+        let sample_rate = 44100;
+
+        unsafe {
+            let result = extract_formants_rust(buffer.as_ptr(), 2048, sample_rate, 0);
+
+            // Silence may produce formant estimates or zeros depending on algorithm
+            // The important thing is it doesn't crash and returns valid (non-NaN) values
+            assert!(result.f1.is_finite(), "F1 should be finite for silence");
+            assert!(result.f2.is_finite(), "F2 should be finite for silence");
+            assert!(result.f3.is_finite(), "F3 should be finite for silence");
+            assert!(result.bw1.is_finite(), "BW1 should be finite for silence");
+            assert!(result.bw2.is_finite(), "BW2 should be finite for silence");
+            assert!(result.bw3.is_finite(), "BW3 should be finite for silence");
+        }
+    }
+
+    // ======== Spectral Analysis Tests ========
+
+    #[test]
+    fn test_analyze_spectrum_null_buffer() {
+        unsafe {
+            let result = analyze_spectrum_rust(std::ptr::null(), 1024, 44100);
+            assert_eq!(result.centroid, 0.0, "Should return centroid=0.0 for null buffer");
+            assert_eq!(result.rolloff, 0.0, "Should return rolloff=0.0 for null buffer");
+            assert_eq!(result.tilt, 0.0, "Should return tilt=0.0 for null buffer");
+        }
+    }
+
+    #[test]
+    fn test_analyze_spectrum_invalid_length() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test zero length
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 0, 44100);
+            assert_eq!(result.centroid, 0.0);
+            assert_eq!(result.rolloff, 0.0);
+            assert_eq!(result.tilt, 0.0);
+
+            // Test negative length
+            let result = analyze_spectrum_rust(buffer.as_ptr(), -10, 44100);
+            assert_eq!(result.centroid, 0.0);
+            assert_eq!(result.rolloff, 0.0);
+            assert_eq!(result.tilt, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_analyze_spectrum_invalid_sample_rate() {
+        let buffer: Vec<f32> = vec![0.0; 1024];
+        unsafe {
+            // Test below 8000 Hz (AC1)
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 7999);
+            assert_eq!(result.centroid, 0.0, "Should return error for sample rate < 8000 Hz");
+
+            // Test above 48000 Hz (AC1)
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 48001);
+            assert_eq!(result.centroid, 0.0, "Should return error for sample rate > 48000 Hz");
+
+            // Test zero/negative sample rate
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 0);
+            assert_eq!(result.centroid, 0.0);
+
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, -100);
+            assert_eq!(result.centroid, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_analyze_spectrum_valid_sample_rates() {
         let buffer: Vec<f32> = vec![0.5; 2048];
+
+        unsafe {
+            // Test minimum valid sample rate (8000 Hz)
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 8000);
+            // Should not error (values may vary based on buffer content, but call should succeed)
+            assert!(result.centroid.is_finite());
+            assert!(result.rolloff.is_finite());
+            assert!(result.tilt.is_finite());
+
+            // Test common sample rate (44100 Hz)
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 44100);
+            assert!(result.centroid.is_finite());
+            assert!(result.rolloff.is_finite());
+            assert!(result.tilt.is_finite());
+
+            // Test maximum valid sample rate (48000 Hz)
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 48000);
+            assert!(result.centroid.is_finite());
+            assert!(result.rolloff.is_finite());
+            assert!(result.tilt.is_finite());
+        }
+    }
+
+    #[test]
+    fn test_analyze_spectrum_sine_wave_440hz() {
+        // Generate a pure 440 Hz sine wave
+        // Expected characteristics:
+        // - Centroid should be close to 440 Hz (narrow spectral peak)
+        // - Rolloff should be close to 440 Hz (most energy concentrated there)
+        // - Tilt should be near 0 (flat spectrum around the peak)
         let sample_rate = 44100;
-        let fft_size = 1024;
-        let result_len = (fft_size / 2) + 1;
+        let frequency = 440.0;
+        let duration = 0.1; // 100ms
+        let num_samples = (sample_rate as f32 * duration) as usize;
+
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            buffer.push((2.0 * PI * frequency * t).sin());
+        }
 
         unsafe {
-            for _ in 0..10 {
-                let result = compute_fft_rust(buffer.as_ptr(), 2048, sample_rate, fft_size);
-                assert!(!result.is_null());
-                free_fft_result_rust(result, result_len);
+            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+
+            // AC2, AC3, AC4: All spectral features should be computed
+            // All values should be finite (not NaN or Infinity)
+            assert!(result.centroid.is_finite(), "Centroid should be finite");
+            assert!(result.rolloff.is_finite(), "Rolloff should be finite");
+            assert!(result.tilt.is_finite(), "Tilt should be finite");
+
+            // All values should be non-negative for frequencies
+            assert!(result.centroid >= 0.0, "Centroid should be non-negative");
+            assert!(result.rolloff >= 0.0, "Rolloff should be non-negative");
+            // Tilt can be negative (indicating low-frequency emphasis)
+
+            // For a narrow sine wave, centroid should be close to the frequency
+            // Allow reasonable tolerance for FFT resolution and windowing effects
+            if result.centroid > 0.0 {
+                let centroid_error = (result.centroid - frequency).abs();
+                let error_percent = (centroid_error / frequency) * 100.0;
+
+                // Centroid should be within reasonable range of target frequency
+                // (allowing for FFT bin resolution and windowing artifacts)
+                assert!(
+                    error_percent < 50.0,
+                    "Centroid {:.1} Hz should be reasonably close to {:.1} Hz (error: {:.1}%)",
+                    result.centroid,
+                    frequency,
+                    error_percent
+                );
             }
         }
     }
 
-    // ======== Pitch Detection Tests ========
-
     #[test]
-    fn test_detect_pitch_null_buffer() {
+    fn test_analyze_spectrum_white_noise() {
+        // Generate white noise - broad spectrum
+        // Expected characteristics:
+        // - Centroid should be mid-range (around sample_rate / 4)
+        // - Rolloff should be high (energy distributed across spectrum)
+        // - Tilt should be near 0 (flat spectrum)
+        let sample_rate = 44100;
+        let num_samples = 2048;
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+
+        // Simple pseudo-random noise generator
+        for i in 0..num_samples {
+            let hash = (i as u32).wrapping_mul(2654435761);
+            buffer.push(((hash % 1000) as f32 / 1000.0) * 2.0 - 1.0);
+        }
+
         unsafe {
-            let result = detect_pitch_rust(std::ptr::null(), 1024, 44100);
-            assert_eq!(result.frequency, 0.0, "Should return frequency=0.0 for null buffer");
-            assert_eq!(result.confidence, 0.0, "Should return confidence=0.0 for null buffer");
-            assert!(!result.is_voiced, "Should return is_voiced=false for null buffer");
+            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+
+            // AC2, AC3, AC4: All features should be computed
+            assert!(result.centroid.is_finite(), "Centroid should be finite for white noise");
+            assert!(result.rolloff.is_finite(), "Rolloff should be finite for white noise");
+            assert!(result.tilt.is_finite(), "Tilt should be finite for white noise");
+
+            // For white noise, centroid should be somewhere in mid-range
+            // (not at extremes like 0 or Nyquist frequency)
+            if result.centroid > 0.0 {
+                let nyquist = sample_rate as f32 / 2.0;
+                assert!(
+                    result.centroid < nyquist,
+                    "Centroid {:.1} Hz should be below Nyquist {:.1} Hz",
+                    result.centroid,
+                    nyquist
+                );
+            }
+
+            // Rolloff should also be reasonable (below Nyquist)
+            if result.rolloff > 0.0 {
+                let nyquist = sample_rate as f32 / 2.0;
+                assert!(
+                    result.rolloff < nyquist,
+                    "Rolloff {:.1} Hz should be below Nyquist {:.1} Hz",
+                    result.rolloff,
+                    nyquist
+                );
+            }
         }
     }
 
-    #[test]
-    fn test_detect_pitch_invalid_length() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
-        unsafe {
-            // Test zero length
-            let result = detect_pitch_rust(buffer.as_ptr(), 0, 44100);
-            assert_eq!(result.frequency, 0.0);
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
+    #[test]
+    fn test_analyze_spectrum_pink_noise() {
+        // Generate pink noise (1/f spectrum) - more low frequency energy
+        // Expected characteristics:
+        // - Centroid should be lower than white noise
+        // - Rolloff should be lower than white noise
+        // - Tilt should be negative (more low-frequency energy)
+        let sample_rate = 44100;
+        let num_samples = 2048;
+        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+
+        // Approximate pink noise by summing sine waves with 1/f amplitude
+        for i in 0..num_samples {
+            let t = i as f32 / sample_rate as f32;
+            let mut sample = 0.0;
+            // Sum harmonics with decreasing amplitude (1/f)
+            for harmonic in 1..=20 {
+                let freq = 100.0 * harmonic as f32;
+                let amplitude = 1.0 / harmonic as f32;
+                sample += amplitude * (2.0 * PI * freq * t).sin();
+            }
+            buffer.push(sample * 0.1); // Scale down to reasonable amplitude
+        }
+
+        unsafe {
+            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+
+            // AC2, AC3, AC4: All features should be computed
+            assert!(result.centroid.is_finite(), "Centroid should be finite for pink noise");
+            assert!(result.rolloff.is_finite(), "Rolloff should be finite for pink noise");
+            assert!(result.tilt.is_finite(), "Tilt should be finite for pink noise");
+
+            // All frequencies should be in valid range
+            if result.centroid > 0.0 {
+                let nyquist = sample_rate as f32 / 2.0;
+                assert!(
+                    result.centroid < nyquist,
+                    "Centroid should be below Nyquist frequency"
+                );
+            }
+
+            if result.rolloff > 0.0 {
+                let nyquist = sample_rate as f32 / 2.0;
+                assert!(
+                    result.rolloff < nyquist,
+                    "Rolloff should be below Nyquist frequency"
+                );
+            }
 
-            // Test negative length
-            let result = detect_pitch_rust(buffer.as_ptr(), -10, 44100);
-            assert_eq!(result.frequency, 0.0);
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
+            // AC4: Pink noise should typically have negative tilt (more low freq energy)
+            // But this depends on the algorithm's tilt calculation, so we just verify it's finite
         }
     }
 
     #[test]
-    fn test_detect_pitch_invalid_sample_rate_below_minimum() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    fn test_analyze_spectrum_silence() {
+        // Test with silence (all zeros)
+        let buffer: Vec<f32> = vec![0.0; 2048];
+        let sample_rate = 44100;
+
         unsafe {
-            // Test below 8000 Hz (AC3)
-            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 7999);
-            assert_eq!(result.frequency, 0.0, "Should return error for sample rate < 8000 Hz");
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, sample_rate);
 
-            // Test zero sample rate
-            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 0);
-            assert_eq!(result.frequency, 0.0);
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
+            // Silence may produce specific values or zeros depending on algorithm
+            // The important thing is it doesn't crash and returns valid (non-NaN) values
+            assert!(result.centroid.is_finite(), "Centroid should be finite for silence");
+            assert!(result.rolloff.is_finite(), "Rolloff should be finite for silence");
+            assert!(result.tilt.is_finite(), "Tilt should be finite for silence");
 
-            // Test negative sample rate
-            let result = detect_pitch_rust(buffer.as_ptr(), 1024, -100);
-            assert_eq!(result.frequency, 0.0);
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
+            // All values should be non-negative for silence (no negative frequencies)
+            assert!(result.centroid >= 0.0, "Centroid should be non-negative for silence");
+            assert!(result.rolloff >= 0.0, "Rolloff should be non-negative for silence");
         }
     }
 
     #[test]
-    fn test_detect_pitch_invalid_sample_rate_above_maximum() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
-        unsafe {
-            // Test above 48000 Hz (AC3)
-            let result = detect_pitch_rust(buffer.as_ptr(), 1024, 48001);
-            assert_eq!(result.frequency, 0.0, "Should return error for sample rate > 48000 Hz");
-            assert_eq!(result.confidence, 0.0);
-            assert!(!result.is_voiced);
-        }
-    }
+    fn test_analyze_spectrum_multiple_sample_rates() {
+        // Test spectral analysis works across different sample rates
+        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
+            let duration = 0.05; // 50ms
+            let num_samples = (sample_rate as f32 * duration) as usize;
 
-    #[test]
-    fn test_detect_pitch_valid_sample_rates() {
-        let buffer: Vec<f32> = vec![0.5; 2048];
+            // Generate a simple periodic signal
+            let frequency = 200.0;
+            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+            for i in 0..num_samples {
+                let t = i as f32 / sample_rate as f32;
+                buffer.push((2.0 * PI * frequency * t).sin());
+            }
 
-        unsafe {
-            // Test minimum valid sample rate (8000 Hz)
-            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 8000);
-            // Should not error (frequency may be 0 due to buffer content, but call should succeed)
-            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+            unsafe {
+                let result = analyze_spectrum_rust(
+                    buffer.as_ptr(),
+                    num_samples as c_int,
+                    sample_rate as c_int,
+                );
 
-            // Test common sample rate (44100 Hz)
-            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 44100);
-            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+                // AC1: All sample rates in 8000-48000 Hz should work
+                assert!(
+                    result.centroid.is_finite() && result.rolloff.is_finite() && result.tilt.is_finite(),
+                    "Sample rate {} Hz should work (centroid={:.1}, rolloff={:.1}, tilt={:.3})",
+                    sample_rate,
+                    result.centroid,
+                    result.rolloff,
+                    result.tilt
+                );
 
-            // Test maximum valid sample rate (48000 Hz)
-            let result = detect_pitch_rust(buffer.as_ptr(), 2048, 48000);
-            assert!(result.confidence >= 0.0 && result.confidence <= 1.0);
+                // Verify values are in physically reasonable range
+                if result.centroid > 0.0 {
+                    let nyquist = sample_rate as f32 / 2.0;
+                    assert!(
+                        result.centroid <= nyquist,
+                        "Centroid {:.1} Hz should not exceed Nyquist {:.1} Hz at sample rate {}",
+                        result.centroid,
+                        nyquist,
+                        sample_rate
+                    );
+                }
+
+                if result.rolloff > 0.0 {
+                    let nyquist = sample_rate as f32 / 2.0;
+                    assert!(
+                        result.rolloff <= nyquist,
+                        "Rolloff {:.1} Hz should not exceed Nyquist {:.1} Hz at sample rate {}",
+                        result.rolloff,
+                        nyquist,
+                        sample_rate
+                    );
+                }
+            }
         }
     }
 
     #[test]
-    fn test_detect_pitch_confidence_range() {
-        // Generate synthetic tone at 440 Hz
-        let sample_rate = 44100;
-        let frequency = 440.0;
-        let duration = 0.1; // 100ms
-        let num_samples = (sample_rate as f32 * duration) as usize;
-
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            buffer.push((2.0 * PI * frequency * t).sin());
-        }
+    fn test_analyze_spectrum_result_struct_layout() {
+        // Verify SpectrumResult struct is properly laid out for FFI
+        let test_result = SpectrumResult {
+            centroid: 2000.0,
+            rolloff: 4000.0,
+            tilt: -0.5,
+            spread: 800.0,
+            skewness: 1.5,
+            kurtosis: 4.0,
+            flatness: 0.2,
+            entropy: 0.6,
+            crest_factor: 12.0,
+            rolloff_85: 3000.0,
+            error_code: LoqaErrorCode::Ok,
+        };
 
-        unsafe {
-            let result = detect_pitch_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+        assert_eq!(test_result.centroid, 2000.0);
+        assert_eq!(test_result.rolloff, 4000.0);
+        assert_eq!(test_result.tilt, -0.5);
+        assert_eq!(test_result.rolloff_85, 3000.0);
 
-            // AC5: Confidence must be in range [0.0, 1.0]
-            assert!(
-                result.confidence >= 0.0 && result.confidence <= 1.0,
-                "Confidence {:.3} must be in range [0.0, 1.0]",
-                result.confidence
-            );
-        }
+        // Verify struct is Copy (required for FFI)
+        let copied = test_result;
+        assert_eq!(copied.centroid, 2000.0);
+        assert_eq!(test_result.centroid, 2000.0); // Original still valid
     }
 
     #[test]
-    fn test_detect_pitch_sine_wave_220hz() {
-        // Generate a pure 220 Hz sine wave (A3) - within human voice range
+    fn test_analyze_spectrum_all_features_single_call() {
+        // AC5: Verify all three spectral features are computed in a single function call
         let sample_rate = 44100;
-        let target_frequency = 220.0; // Within MIN_FREQUENCY..MAX_FREQUENCY range
-        let duration = 0.1; // 100ms should be enough for YIN
-        let num_samples = (sample_rate as f32 * duration) as usize;
+        let num_samples = 2048;
 
+        // Generate a complex signal with multiple frequency components
         let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
         for i in 0..num_samples {
             let t = i as f32 / sample_rate as f32;
-            buffer.push((2.0 * PI * target_frequency * t).sin());
+            // Mix of low, mid, and high frequencies
+            buffer.push(
+                0.5 * (2.0 * PI * 200.0 * t).sin() +  // Low
+                0.3 * (2.0 * PI * 1000.0 * t).sin() +  // Mid
+                0.2 * (2.0 * PI * 4000.0 * t).sin()    // High
+            );
         }
 
         unsafe {
-            let result = detect_pitch_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
-
-            // For a clear sine wave within the detection range, we should detect a pitch
-            // YIN is very accurate for pure tones in the target frequency range
-            if result.is_voiced {
-                // If voiced, frequency should be close to 220 Hz
-                let error = (result.frequency - target_frequency).abs();
-                let error_percent = (error / target_frequency) * 100.0;
+            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
 
-                assert!(
-                    error_percent < 10.0,
-                    "Detected frequency {:.1} Hz should be within 10% of target {:.1} Hz (error: {:.2}%)",
-                    result.frequency,
-                    target_frequency,
-                    error_percent
-                );
+            // AC5: All three features should be computed and returned
+            // Verify all are valid (finite, non-NaN)
+            assert!(result.centroid.is_finite(), "Centroid should be computed");
+            assert!(result.rolloff.is_finite(), "Rolloff should be computed");
+            assert!(result.tilt.is_finite(), "Tilt should be computed");
 
-                // Confidence should be reasonably high for clean tone
-                assert!(
-                    result.confidence > 0.5,
-                    "Confidence {:.3} should be > 0.5 for clear sine wave",
-                    result.confidence
-                );
-            }
+            // For this mixed signal, all three values should be meaningful (non-zero if algorithm works)
+            // But we don't enforce non-zero as that depends on the algorithm implementation
         }
     }
 
     #[test]
-    fn test_detect_pitch_silence_returns_unvoiced() {
-        // Test with silence (all zeros)
-        let buffer: Vec<f32> = vec![0.0; 2048];
+    fn test_analyze_spectrum_any_buffer_length() {
+        // 50 ms at 44.1 kHz is 2205 samples, which used to fail as an FFT size
         let sample_rate = 44100;
-
-        unsafe {
-            let result = detect_pitch_rust(buffer.as_ptr(), 2048, sample_rate);
-
-            // AC4: Silence should return frequency=0.0 and is_voiced=false
-            assert_eq!(
-                result.frequency, 0.0,
-                "Silence should return frequency=0.0"
-            );
-            assert!(
-                !result.is_voiced,
-                "Silence should be classified as unvoiced"
-            );
+        for num_samples in [100, 2205, 5000, 44100] {
+            let buffer: Vec<f32> = (0..num_samples)
+                .map(|i| (2.0 * PI * 1000.0 * i as f32 / sample_rate as f32).sin())
+                .collect();
+
+            let result =
+                unsafe { analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate) };
+            assert_eq!(result.error_code, LoqaErrorCode::Ok, "{num_samples} samples");
+            assert!(result.centroid > 0.0, "{num_samples} samples");
+            assert!(result.rolloff_85 <= result.rolloff, "{num_samples} samples");
+            assert!(result.flatness < 0.5, "a tone should not look flat: {}", result.flatness);
         }
     }
 
     #[test]
-    fn test_detect_pitch_noise_behavior() {
-        // Generate white noise (random values)
-        let mut buffer: Vec<f32> = vec![0.0; 2048];
-        let sample_rate = 44100;
-
-        // Simple pseudo-random noise generator
-        for (i, sample) in buffer.iter_mut().enumerate() {
-            // Use a simple hash-like function for reproducibility
-            let hash = (i as u32).wrapping_mul(2654435761);
-            *sample = ((hash % 1000) as f32 / 1000.0) * 2.0 - 1.0; // Range: [-1.0, 1.0]
-        }
-
-        unsafe {
-            let result = detect_pitch_rust(buffer.as_ptr(), 2048, sample_rate);
+    fn test_analyze_spectrum_tone_vs_noise_descriptors() {
+        let sample_rate = 16000;
+        let tone: Vec<f32> = (0..16000)
+            .map(|i| (2.0 * PI * 1000.0 * i as f32 / sample_rate as f32).sin())
+            .collect();
+        let mut state: u32 = 1;
+        let noise: Vec<f32> = (0..16000)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+            })
+            .collect();
+
+        let (tone, noise) = unsafe {
+            (
+                analyze_spectrum_rust(tone.as_ptr(), 16000, sample_rate),
+                analyze_spectrum_rust(noise.as_ptr(), 16000, sample_rate),
+            )
+        };
+        assert!(tone.flatness < noise.flatness);
+        assert!(tone.entropy < noise.entropy);
+        assert!(tone.crest_factor > noise.crest_factor);
+        assert!(tone.spread < noise.spread);
+        // White noise: centroid near fs/4 and a near-uniform (kurtosis ~1.8) distribution
+        assert!((noise.centroid - 4000.0).abs() < 200.0, "{}", noise.centroid);
+        assert!((noise.kurtosis - 1.8).abs() < 0.2, "{}", noise.kurtosis);
+    }
 
-            // Noise behavior: The YIN algorithm may detect spurious periodicities in noise
-            // The important thing is that confidence values are always in valid range
-            assert!(
-                result.confidence >= 0.0 && result.confidence <= 1.0,
-                "Confidence must be in valid range [0.0, 1.0], got {:.3}",
-                result.confidence
-            );
+    #[test]
+    fn test_analyze_spectrum_ex_config() {
+        let sample_rate = 16000;
+        let buffer = sine_wave(440.0, sample_rate, 20000);
+        let mut config = loqa_spectrum_config_default();
 
-            // AC4: If unvoiced, frequency should be 0.0
-            if !result.is_voiced {
-                assert_eq!(
-                    result.frequency, 0.0,
-                    "Unvoiced noise should have frequency=0.0"
-                );
-            }
+        unsafe {
+            let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            assert!(result.success);
+            assert_eq!(result.fft_size, 8192);
+            assert_eq!(result.frame_count, (20000 - 8192) / 4096 + 1);
+
+            config.fft_size = 1024;
+            config.average_frames = false;
+            let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            assert!(result.success);
+            assert_eq!((result.fft_size, result.frame_count), (1024, 1));
+
+            // Rolloff thresholds are configurable
+            config.rolloff_low_threshold = 0.5;
+            config.rolloff_high_threshold = 0.999;
+            let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            assert!(result.rolloff_low < result.rolloff_high);
+            assert!((result.rolloff_low - 440.0).abs() < 20.0, "{}", result.rolloff_low);
         }
     }
 
     #[test]
-    fn test_detect_pitch_multiple_sample_rates() {
-        // Generate 220 Hz tone (A3)
-        let target_frequency = 220.0;
-
-        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
-            let duration = 0.1;
-            let num_samples = (sample_rate as f32 * duration) as usize;
+    fn test_analyze_spectrum_ex_invalid_arguments() {
+        let buffer = vec![0.1; 1024];
+        let default = loqa_spectrum_config_default();
+        let cases = [
+            (SpectrumConfigFFI { fft_size: 1000, ..default }, LoqaErrorCode::InvalidFftSize),
+            (SpectrumConfigFFI { fft_size: 32, ..default }, LoqaErrorCode::InvalidFftSize),
+            (SpectrumConfigFFI { fft_size: 16384, ..default }, LoqaErrorCode::InvalidFftSize),
+            (
+                SpectrumConfigFFI { rolloff_low_threshold: 0.96, ..default },
+                LoqaErrorCode::InvalidArgument,
+            ),
+            (
+                SpectrumConfigFFI { rolloff_high_threshold: 1.5, ..default },
+                LoqaErrorCode::InvalidArgument,
+            ),
+            (
+                SpectrumConfigFFI { rolloff_low_threshold: 0.0, ..default },
+                LoqaErrorCode::InvalidArgument,
+            ),
+        ];
 
-            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-            for i in 0..num_samples {
-                let t = i as f32 / sample_rate as f32;
-                buffer.push((2.0 * PI * target_frequency * t).sin());
+        unsafe {
+            for (config, expected) in cases {
+                let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), 1024, 16000, config);
+                assert!(!result.success);
+                assert_eq!(result.error_code, expected, "{config:?}");
+                assert_eq!(loqa_last_error_code(), expected);
             }
 
-            unsafe {
-                let result = detect_pitch_rust(
-                    buffer.as_ptr(),
-                    num_samples as c_int,
-                    sample_rate as c_int
-                );
+            let result = loqa_analyze_spectrum_ex(std::ptr::null(), 1024, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
 
-                // AC3: All sample rates in 8000-48000 Hz should work
-                assert!(
-                    result.confidence >= 0.0 && result.confidence <= 1.0,
-                    "Sample rate {} Hz should work (got confidence {:.3})",
-                    sample_rate,
-                    result.confidence
-                );
-            }
+            let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), 0, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+
+            let result = loqa_analyze_spectrum_ex(buffer.as_ptr(), 1024, 96000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
         }
     }
 
+    // ======== Windowed FFT Tests ========
+
     #[test]
-    fn test_detect_pitch_result_struct_layout() {
-        // Verify PitchResult struct is properly laid out for FFI
-        // This is a compile-time check, but runtime verification doesn't hurt
-        let test_result = PitchResult {
-            frequency: 440.0,
-            confidence: 0.95,
-            is_voiced: true,
-        };
+    fn test_compute_fft_ex_window_scaling_and_phase() {
+        let sample_rate = 16000;
+        // Bin 64 of 1024 is exactly 1000 Hz
+        let buffer: Vec<f32> = sine_wave(1000.0, sample_rate, 1024).iter().map(|x| 0.5 * x).collect();
+        let mut config = FFTConfigFFI { fft_size: 1024, ..loqa_fft_config_default() };
 
-        assert_eq!(test_result.frequency, 440.0);
-        assert_eq!(test_result.confidence, 0.95);
-        assert!(test_result.is_voiced);
+        unsafe {
+            for window in 0..=6 {
+                config.window = window;
+                let mut result = loqa_compute_fft_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+                assert!(result.success, "window {window}");
+                assert_eq!((result.length, result.fft_size, result.sample_rate), (513, 1024, 16000));
+                assert!(result.phases_ptr.is_null());
+
+                let magnitudes = slice::from_raw_parts(result.magnitudes_ptr, result.length);
+                let frequencies = slice::from_raw_parts(result.frequencies_ptr, result.length);
+                assert_eq!(frequencies[64], 1000.0);
+                assert!((magnitudes[64] - 0.5).abs() < 1e-3, "window {window}: {}", magnitudes[64]);
+
+                loqa_free_fft_result_ex(&mut result);
+                assert!(result.magnitudes_ptr.is_null() && result.frequencies_ptr.is_null());
+                assert_eq!(result.length, 0);
+                // Freeing twice is a no-op
+                loqa_free_fft_result_ex(&mut result);
+            }
 
-        // Verify struct is Copy (required for FFI)
-        let copied = test_result;
-        assert_eq!(copied.frequency, 440.0);
-        assert_eq!(test_result.frequency, 440.0); // Original still valid
+            config.window = LoqaWindowType::None as u32;
+            config.include_phase = true;
+            config.normalization = LoqaFftNormalization::Psd as u32;
+            let mut result = loqa_compute_fft_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            let phases = slice::from_raw_parts(result.phases_ptr, result.length);
+            assert!((phases[64] + std::f32::consts::FRAC_PI_2).abs() < 1e-3, "{}", phases[64]);
+
+            // PSD summed over the bins carries the sine's power, A^2 / 2
+            let psd = slice::from_raw_parts(result.magnitudes_ptr, result.length);
+            let power = psd.iter().sum::<f32>() * sample_rate as f32 / 1024.0;
+            assert!((power - 0.125).abs() < 1e-3, "{power}");
+            loqa_free_fft_result_ex(&mut result);
+
+            // Automatic size fits the buffer
+            let config = loqa_fft_config_default();
+            let mut result = loqa_compute_fft_ex(buffer.as_ptr(), 300, sample_rate, config);
+            assert_eq!((result.fft_size, result.length), (512, 257));
+            loqa_free_fft_result_ex(&mut result);
+            loqa_free_fft_result_ex(std::ptr::null_mut());
+        }
     }
 
-    // ======== Formant Extraction Tests ========
-
     #[test]
-    fn test_extract_formants_null_buffer() {
+    fn test_compute_fft_ex_invalid_arguments() {
+        let buffer = vec![0.1; 1024];
+        let default = loqa_fft_config_default();
+        let cases = [
+            (FFTConfigFFI { fft_size: 1000, ..default }, LoqaErrorCode::InvalidFftSize),
+            (FFTConfigFFI { fft_size: 16384, ..default }, LoqaErrorCode::InvalidFftSize),
+            (FFTConfigFFI { window: 7, ..default }, LoqaErrorCode::InvalidArgument),
+            (FFTConfigFFI { normalization: 2, ..default }, LoqaErrorCode::InvalidArgument),
+            (
+                FFTConfigFFI { window: LoqaWindowType::Kaiser as u32, window_param: -1.0, ..default },
+                LoqaErrorCode::InvalidArgument,
+            ),
+            (
+                FFTConfigFFI {
+                    window: LoqaWindowType::Gaussian as u32,
+                    window_param: f32::NAN,
+                    ..default
+                },
+                LoqaErrorCode::InvalidArgument,
+            ),
+        ];
+
         unsafe {
-            let result = extract_formants_rust(std::ptr::null(), 1024, 44100, 0);
-            assert_eq!(result.f1, 0.0, "Should return f1=0.0 for null buffer");
-            assert_eq!(result.f2, 0.0, "Should return f2=0.0 for null buffer");
-            assert_eq!(result.f3, 0.0, "Should return f3=0.0 for null buffer");
-            assert_eq!(result.bw1, 0.0, "Should return bw1=0.0 for null buffer");
-            assert_eq!(result.bw2, 0.0, "Should return bw2=0.0 for null buffer");
-            assert_eq!(result.bw3, 0.0, "Should return bw3=0.0 for null buffer");
+            for (config, expected) in cases {
+                let result = loqa_compute_fft_ex(buffer.as_ptr(), 1024, 16000, config);
+                assert!(!result.success);
+                assert!(result.magnitudes_ptr.is_null());
+                assert_eq!(result.error_code, expected, "{config:?}");
+                assert_eq!(loqa_last_error_code(), expected);
+            }
+
+            let result = loqa_compute_fft_ex(std::ptr::null(), 1024, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+
+            let result = loqa_compute_fft_ex(buffer.as_ptr(), 0, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+
+            let result = loqa_compute_fft_ex(buffer.as_ptr(), 1024, 0, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
         }
     }
 
+    // ======== Complex FFT Tests ========
+
     #[test]
-    fn test_extract_formants_invalid_length() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
-        unsafe {
-            // Test zero length
-            let result = extract_formants_rust(buffer.as_ptr(), 0, 44100, 0);
-            assert_eq!(result.f1, 0.0);
-            assert_eq!(result.f2, 0.0);
-            assert_eq!(result.f3, 0.0);
+    fn test_complex_fft_round_trip() {
+        let sample_rate = 16000;
+        let buffer: Vec<f32> = sine_wave(440.0, sample_rate, 1000)
+            .iter()
+            .zip(sine_wave(1234.0, sample_rate, 1000))
+            .map(|(a, b)| 0.6 * a + 0.3 * b)
+            .collect();
 
-            // Test negative length
-            let result = extract_formants_rust(buffer.as_ptr(), -10, 44100, 0);
-            assert_eq!(result.f1, 0.0);
-            assert_eq!(result.f2, 0.0);
-            assert_eq!(result.f3, 0.0);
+        unsafe {
+            let mut spectrum = loqa_compute_fft_complex(buffer.as_ptr(), buffer.len(), 0);
+            assert!(spectrum.success);
+            assert_eq!((spectrum.fft_size, spectrum.bin_count), (1024, 513));
+
+            // The bins agree with the windowed FFT's phases when no window is applied
+            let bins = slice::from_raw_parts(spectrum.bins_ptr, 2 * spectrum.bin_count);
+            let config = FFTConfigFFI {
+                window: LoqaWindowType::None as u32,
+                include_phase: true,
+                ..loqa_fft_config_default()
+            };
+            let mut windowed = loqa_compute_fft_ex(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            let phases = slice::from_raw_parts(windowed.phases_ptr, windowed.length);
+            for k in [10, 28, 79] {
+                assert!((bins[2 * k + 1].atan2(bins[2 * k]) - phases[k]).abs() < 1e-4, "bin {k}");
+            }
+            loqa_free_fft_result_ex(&mut windowed);
+
+            let mut signal = loqa_compute_ifft(spectrum.bins_ptr, spectrum.bin_count);
+            assert!(signal.success);
+            assert_eq!(signal.length, 1024);
+            let samples = slice::from_raw_parts(signal.samples_ptr, signal.length);
+            for (i, (a, b)) in buffer.iter().zip(samples).enumerate() {
+                assert!((a - b).abs() < 1e-5, "sample {i}: {a} vs {b}");
+            }
+            // Zero-padding comes back as silence
+            assert!(samples[1000..].iter().all(|x| x.abs() < 1e-5));
+
+            loqa_free_ifft_result(&mut signal);
+            assert!(signal.samples_ptr.is_null());
+            loqa_free_ifft_result(&mut signal);
+            loqa_free_complex_spectrum(&mut spectrum);
+            assert!(spectrum.bins_ptr.is_null());
+            loqa_free_complex_spectrum(&mut spectrum);
+            loqa_free_complex_spectrum(std::ptr::null_mut());
+            loqa_free_ifft_result(std::ptr::null_mut());
         }
     }
 
     #[test]
-    fn test_extract_formants_invalid_sample_rate() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    fn test_complex_fft_invalid_arguments() {
+        let buffer = vec![0.1; 1024];
+
         unsafe {
-            // Test below 8000 Hz (AC3)
-            let result = extract_formants_rust(buffer.as_ptr(), 1024, 7999, 0);
-            assert_eq!(result.f1, 0.0, "Should return error for sample rate < 8000 Hz");
+            let result = loqa_compute_fft_complex(std::ptr::null(), 1024, 0);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_compute_fft_complex(buffer.as_ptr(), 0, 0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            let result = loqa_compute_fft_complex(buffer.as_ptr(), 1024, 1000);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFftSize);
+            assert!(result.bins_ptr.is_null());
+
+            let result = loqa_compute_ifft(std::ptr::null(), 513);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_compute_ifft(buffer.as_ptr(), 0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            // 300 bins would be an FFT size of 598
+            let result = loqa_compute_ifft(buffer.as_ptr(), 300);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFftSize);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidFftSize);
+            let result = loqa_compute_ifft(buffer.as_ptr(), 17);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFftSize);
+            assert!(result.samples_ptr.is_null());
+        }
+    }
 
-            // Test above 48000 Hz
-            let result = extract_formants_rust(buffer.as_ptr(), 1024, 48001, 0);
-            assert_eq!(result.f1, 0.0, "Should return error for sample rate > 48000 Hz");
+    // ======== Spectrogram Tests ========
 
-            // Test zero/negative sample rate
-            let result = extract_formants_rust(buffer.as_ptr(), 1024, 0, 0);
-            assert_eq!(result.f1, 0.0);
+    #[test]
+    fn test_spectrogram_matches_pitch_track_timestamps() {
+        let sample_rate = 16000;
+        let buffer = sine_wave(250.0, sample_rate, 8000);
 
-            let result = extract_formants_rust(buffer.as_ptr(), 1024, -100, 0);
-            assert_eq!(result.f1, 0.0);
+        unsafe {
+            let config = SpectrogramConfigFFI {
+                frame_size: 2048,
+                hop_size: 1024,
+                scaling: LoqaSpectrogramScaling::Linear as u32,
+                ..loqa_spectrogram_config_default()
+            };
+            let mut spectrogram =
+                loqa_compute_spectrogram(buffer.as_ptr(), buffer.len(), sample_rate, config);
+            assert!(spectrogram.success);
+            assert_eq!(spectrogram.bin_count, 1025);
+            assert_eq!((spectrogram.frame_size, spectrogram.hop_size), (2048, 1024));
+
+            let analyzer_config = AnalysisConfigFFI {
+                hop_size: 1024,
+                ..analyzer_config(sample_rate, LoqaPitchAlgorithm::Pyin)
+            };
+            let analyzer = loqa_voice_analyzer_create(analyzer_config);
+            let mut track =
+                loqa_voice_analyzer_process_buffer(analyzer, buffer.as_ptr(), buffer.len());
+            assert!(track.success);
+            assert_eq!(spectrogram.frame_count, track.length);
+            let times = slice::from_raw_parts(spectrogram.timestamps_ptr, spectrogram.frame_count);
+            let track_times = slice::from_raw_parts(track.timestamps_ptr, track.length);
+            assert_eq!(times, track_times);
+
+            // 250 Hz is bin 32 at 7.8125 Hz per bin
+            let values = slice::from_raw_parts(
+                spectrogram.values_ptr,
+                spectrogram.frame_count * spectrogram.bin_count,
+            );
+            for frame in values.chunks_exact(spectrogram.bin_count) {
+                assert!((frame[32] - 1.0).abs() < 1e-3, "peak {}", frame[32]);
+            }
+
+            loqa_free_pitch_track(&mut track);
+            loqa_voice_analyzer_free(analyzer);
+            loqa_free_spectrogram(&mut spectrogram);
+            assert!(spectrogram.values_ptr.is_null() && spectrogram.timestamps_ptr.is_null());
+            loqa_free_spectrogram(&mut spectrogram);
+            loqa_free_spectrogram(std::ptr::null_mut());
         }
     }
 
     #[test]
-    fn test_extract_formants_default_lpc_order() {
-        // Test that default LPC order is computed correctly (AC4)
-        // Default should be: (sample_rate / 1000) + 2
-
-        let test_cases = [
-            (8000, (8000 / 1000) + 2),    // 10
-            (16000, (16000 / 1000) + 2),  // 18
-            (44100, (44100 / 1000) + 2),  // 46
-            (48000, (48000 / 1000) + 2),  // 50
-        ];
+    fn test_spectrogram_invalid_arguments() {
+        let buffer = vec![0.1; 4096];
+        let default = loqa_spectrogram_config_default();
 
-        for (sample_rate, expected_order) in test_cases {
-            let buffer_len = expected_order * 4; // Ensure buffer is long enough
-            let buffer: Vec<f32> = vec![0.5; buffer_len as usize];
+        unsafe {
+            let result = loqa_compute_spectrogram(std::ptr::null(), 4096, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_compute_spectrogram(buffer.as_ptr(), 4096, 0, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            // The buffer must extend past the first frame
+            let result = loqa_compute_spectrogram(buffer.as_ptr(), 2048, 16000, default);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+
+            let fft_size_error = LoqaErrorCode::InvalidFftSize;
+            let argument_error = LoqaErrorCode::InvalidArgument;
+            let cases = [
+                (SpectrogramConfigFFI { frame_size: 0, ..default }, fft_size_error),
+                (SpectrogramConfigFFI { frame_size: 1000, ..default }, fft_size_error),
+                (SpectrogramConfigFFI { hop_size: 0, ..default }, argument_error),
+                (SpectrogramConfigFFI { hop_size: 4096, ..default }, argument_error),
+                (SpectrogramConfigFFI { window: 7, ..default }, argument_error),
+                (SpectrogramConfigFFI { scaling: 3, ..default }, argument_error),
+                (SpectrogramConfigFFI { db_floor: f32::NEG_INFINITY, ..default }, argument_error),
+            ];
+            for (config, expected) in cases {
+                let result = loqa_compute_spectrogram(buffer.as_ptr(), 4096, 16000, config);
+                assert!(!result.success);
+                assert_eq!(result.error_code, expected, "{config:?}");
+                assert_eq!(loqa_last_error_code(), expected);
+                assert!(result.values_ptr.is_null() && result.timestamps_ptr.is_null());
+            }
 
-            unsafe {
-                // Call with lpc_order = 0 to use default
-                let result = extract_formants_rust(buffer.as_ptr(), buffer_len, sample_rate, 0);
+            // The floor only matters in dB
+            let config = SpectrogramConfigFFI {
+                scaling: LoqaSpectrogramScaling::Power as u32,
+                db_floor: f32::NAN,
+                ..default
+            };
+            let mut result = loqa_compute_spectrogram(buffer.as_ptr(), 4096, 16000, config);
+            assert!(result.success);
+            loqa_free_spectrogram(&mut result);
+        }
+    }
 
-                // If the function succeeds (doesn't return error), it used the default order
-                // We can't directly verify the order, but we can verify the function accepts valid inputs
-                // The function should not crash or return null - formants may be 0 due to buffer content
-                assert!(
-                    result.f1 >= 0.0 && result.f2 >= 0.0 && result.f3 >= 0.0,
-                    "Formant values should be non-negative for sample rate {sample_rate} Hz",
-                );
-            }
+    // ======== Mel Feature Tests ========
+
+    /// 8 HTK bands over 0-4000 Hz of 256-sample Hann frames every 128 samples
+    fn reference_mel_config() -> MelConfigFFI {
+        MelConfigFFI {
+            frame_size: 256,
+            hop_size: 128,
+            n_mels: 8,
+            mel_scale: LoqaMelScale::Htk as u32,
+            n_mfcc: 4,
+            lifter: 22.0,
+            ..loqa_mel_config_default()
         }
     }
 
+    /// 0.5 * sin(440 Hz) + 0.25 * sin(1250 Hz) at 8 kHz
+    fn reference_mel_signal() -> Vec<f32> {
+        sine_wave(440.0, 8000, 600)
+            .iter()
+            .zip(sine_wave(1250.0, 8000, 600))
+            .map(|(a, b)| 0.5 * a + 0.25 * b)
+            .collect()
+    }
+
     #[test]
-    fn test_extract_formants_custom_lpc_order() {
-        let buffer: Vec<f32> = vec![0.5; 2048];
-        let sample_rate = 44100;
-        let custom_lpc_order = 20;
+    fn test_log_mel_and_mfcc_match_reference() {
+        // Reference values from a direct float64 DFT of the same frames
+        let expected_log_mel: [[f32; 8]; 3] = [
+            [-63.347, -5.7041, -9.7429, -17.9179, -11.1015, -100.0, -100.0, -100.0],
+            [-63.3266, -5.7041, -9.7429, -17.9179, -11.1015, -100.0, -100.0, -100.0],
+            [-63.2983, -5.7041, -9.7429, -17.9179, -11.1015, -100.0, -100.0, -100.0],
+        ];
+        let expected_mfcc: [[f32; 4]; 3] = [
+            [-144.1838, 209.2998, -251.1841, -202.3318],
+            [-144.1766, 209.3255, -251.1454, -202.2845],
+            [-144.1666, 209.361, -251.0919, -202.2191],
+        ];
+        let expected_deltas: [[f32; 4]; 3] = [
+            [0.0036, 0.0129, 0.0193, 0.0237],
+            [0.0086, 0.0306, 0.0461, 0.0563],
+            [0.005, 0.0178, 0.0267, 0.0327],
+        ];
+        let buffer = reference_mel_signal();
 
         unsafe {
-            let result = extract_formants_rust(buffer.as_ptr(), 2048, sample_rate, custom_lpc_order);
-
-            // Should accept custom LPC order
-            // Formant values should be non-negative
-            assert!(result.f1 >= 0.0);
-            assert!(result.f2 >= 0.0);
-            assert!(result.f3 >= 0.0);
-            assert!(result.bw1 >= 0.0);
-            assert!(result.bw2 >= 0.0);
-            assert!(result.bw3 >= 0.0);
+            let config = reference_mel_config();
+            let mut log_mel = loqa_compute_log_mel_spectrogram(buffer.as_ptr(), 600, 8000, config);
+            assert!(log_mel.success);
+            assert_eq!((log_mel.frame_count, log_mel.feature_count), (3, 8));
+            let times = slice::from_raw_parts(log_mel.timestamps_ptr, 3);
+            assert_eq!(times, [0.0, 0.016, 0.032]);
+            let values = slice::from_raw_parts(log_mel.values_ptr, 3 * 8);
+            for (row, expected) in values.chunks_exact(8).zip(&expected_log_mel) {
+                for (actual, expected) in row.iter().zip(expected) {
+                    assert!((actual - expected).abs() < 2e-3, "log-mel {actual} vs {expected}");
+                }
+            }
+            loqa_free_feature_matrix(&mut log_mel);
+            assert!(log_mel.values_ptr.is_null() && log_mel.timestamps_ptr.is_null());
+            loqa_free_feature_matrix(&mut log_mel);
+
+            let config = MelConfigFFI { delta_order: 2, delta_width: 1, ..config };
+            let mut mfcc = loqa_compute_mfcc(buffer.as_ptr(), 600, 8000, config);
+            assert!(mfcc.success);
+            assert_eq!((mfcc.frame_count, mfcc.feature_count), (3, 12));
+            let values = slice::from_raw_parts(mfcc.values_ptr, 3 * 12);
+            for (t, row) in values.chunks_exact(12).enumerate() {
+                for (actual, expected) in row[..4].iter().zip(&expected_mfcc[t]) {
+                    assert!((actual - expected).abs() < 1e-2, "mfcc {actual} vs {expected}");
+                }
+                for (actual, expected) in row[4..8].iter().zip(&expected_deltas[t]) {
+                    assert!((actual - expected).abs() < 2e-3, "delta {actual} vs {expected}");
+                }
+            }
+            loqa_free_feature_matrix(&mut mfcc);
+            loqa_free_feature_matrix(std::ptr::null_mut());
         }
     }
 
     #[test]
-    fn test_extract_formants_buffer_too_short() {
-        // Buffer must be at least lpc_order * 2 samples long
-        let sample_rate = 44100;
-        let lpc_order = 46; // Default for 44100 Hz
-        let buffer_len = lpc_order - 1; // Too short
-        let buffer: Vec<f32> = vec![0.5; buffer_len as usize];
+    fn test_mel_features_invalid_arguments() {
+        let buffer = reference_mel_signal();
+        let base = reference_mel_config();
+        let range_error = LoqaErrorCode::InvalidFrequencyRange;
+        let argument_error = LoqaErrorCode::InvalidArgument;
 
         unsafe {
-            let result = extract_formants_rust(buffer.as_ptr(), buffer_len, sample_rate, lpc_order);
+            let result = loqa_compute_mfcc(std::ptr::null(), 600, 8000, base);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_compute_log_mel_spectrogram(buffer.as_ptr(), 600, 0, base);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            let result = loqa_compute_mfcc(buffer.as_ptr(), 256, 8000, base);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+
+            let cases = [
+                (MelConfigFFI { n_mels: 0, ..base }, argument_error),
+                (MelConfigFFI { n_mels: 257, n_mfcc: 4, ..base }, argument_error),
+                (MelConfigFFI { fmin: 500.0, fmax: 400.0, ..base }, range_error),
+                (MelConfigFFI { fmax: 5000.0, ..base }, range_error),
+                (MelConfigFFI { fmin: -1.0, ..base }, range_error),
+                (MelConfigFFI { mel_scale: 2, ..base }, argument_error),
+                (MelConfigFFI { hop_size: 0, ..base }, argument_error),
+                (MelConfigFFI { frame_size: 100, ..base }, LoqaErrorCode::InvalidFftSize),
+                (MelConfigFFI { n_mfcc: 9, ..base }, argument_error),
+                (MelConfigFFI { n_mfcc: 0, ..base }, argument_error),
+                (MelConfigFFI { lifter: -1.0, ..base }, argument_error),
+                (MelConfigFFI { delta_order: 3, ..base }, argument_error),
+                (MelConfigFFI { delta_width: 0, ..base }, argument_error),
+            ];
+            for (config, expected) in cases {
+                let result = loqa_compute_mfcc(buffer.as_ptr(), 600, 8000, config);
+                assert!(!result.success);
+                assert_eq!(result.error_code, expected, "{config:?}");
+                assert_eq!(loqa_last_error_code(), expected);
+                assert!(result.values_ptr.is_null());
+            }
 
-            // Should return error (zeros) for buffer that's too short
-            assert_eq!(result.f1, 0.0, "Should fail for buffer that's too short");
-            assert_eq!(result.f2, 0.0);
-            assert_eq!(result.f3, 0.0);
+            // MFCC-only fields do not affect the log-mel export
+            let config = MelConfigFFI { n_mfcc: 0, delta_order: 7, ..base };
+            let mut result = loqa_compute_log_mel_spectrogram(buffer.as_ptr(), 600, 8000, config);
+            assert!(result.success);
+            loqa_free_feature_matrix(&mut result);
         }
     }
 
-    #[test]
-    fn test_extract_formants_vowel_a_synthetic() {
-        // Test formant extraction with a synthetic vowel-like signal
-        // Note: LPC analysis is designed for real voiced speech signals
-        // Synthetic signals may not produce accurate formant estimates, but we test basic functionality
-        let sample_rate = 44100;
-        let duration = 0.1; // 100ms for better LPC analysis
-        let num_samples = (sample_rate as f32 * duration) as usize;
+    // ======== Jitter / Shimmer Tests ========
 
-        // Create a more realistic synthetic vowel using pitch + formant resonances
-        // Fundamental frequency (pitch): 120 Hz (typical male voice)
-        let f0 = 120.0;
-        // Formant frequencies for /a/ vowel: F1 ~700 Hz, F2 ~1200 Hz, F3 ~2500 Hz
-        let f1_target = 700.0;
-        let f2_target = 1200.0;
-        let f3_target = 2500.0;
+    /// Glottal-like Gaussian pulses at 16 kHz with periods 80 * (1 +/- d) samples
+    /// and heights 0.5 * (1 +/- e), alternating cycle by cycle
+    fn alternating_pulse_train(d: f64, e: f64, num_samples: usize) -> Vec<f32> {
+        let mut pulses = vec![(40.0, 0.5 * (1.0 + e))];
+        while pulses.last().unwrap().0 < num_samples as f64 {
+            let (position, _) = *pulses.last().unwrap();
+            let sign = if pulses.len() % 2 == 1 { 1.0 } else { -1.0 };
+            pulses.push((position + 80.0 * (1.0 + sign * d), 0.5 * (1.0 - sign * e)));
+        }
+        (0..num_samples)
+            .map(|n| {
+                pulses
+                    .iter()
+                    .map(|&(center, height)| height * (-((n as f64 - center) / 4.0).powi(2)).exp())
+                    .sum::<f64>() as f32
+            })
+            .collect()
+    }
 
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            // Generate pitched source signal (sum of harmonics)
-            let mut source = 0.0;
-            for harmonic in 1..=20 {
-                let freq = f0 * harmonic as f32;
-                source += (1.0 / harmonic as f32) * (2.0 * PI * freq * t).sin();
-            }
-            // Apply simple formant emphasis (not perfect, but better than raw sine waves)
-            let formant_emphasis =
-                0.5 * (2.0 * PI * f1_target * t).sin() +
-                0.3 * (2.0 * PI * f2_target * t).sin() +
-                0.2 * (2.0 * PI * f3_target * t).sin();
-            buffer.push(source * 0.3 + formant_emphasis * 0.7);
+    #[test]
+    fn test_jitter_and_shimmer_of_pulse_train() {
+        let buffer = alternating_pulse_train(0.01, 0.05, 16000);
+        let config = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+
+        unsafe {
+            let jitter = loqa_calculate_jitter(buffer.as_ptr(), buffer.len(), config());
+            assert!(jitter.success, "{:?}", jitter.error_code);
+            assert!(jitter.period_count > 150, "{}", jitter.period_count);
+            assert!((jitter.mean_period - 0.005).abs() < 1e-5);
+            // Alternating periods: local = 2d, RAP = 4d/3, PPQ5 = 4d/5, DDP = 4d
+            assert!((jitter.local - 0.02).abs() < 1e-3, "local {}", jitter.local);
+            assert!((jitter.local_absolute - 1e-4).abs() < 5e-6);
+            assert!((jitter.rap - 0.04 / 3.0).abs() < 1e-3, "rap {}", jitter.rap);
+            assert!((jitter.ppq5 - 0.008).abs() < 1e-3, "ppq5 {}", jitter.ppq5);
+            assert!((jitter.ddp - 3.0 * jitter.rap).abs() < 1e-4);
+
+            let shimmer = loqa_calculate_shimmer(buffer.as_ptr(), buffer.len(), config());
+            assert!(shimmer.success, "{:?}", shimmer.error_code);
+            assert!((shimmer.mean_amplitude - 0.5).abs() < 5e-3);
+            // Alternating amplitudes: local = 2e, APQ3 = 4e/3, APQ5 = 4e/5, APQ11 = 12e/11
+            assert!((shimmer.local - 0.1).abs() < 2e-3, "local {}", shimmer.local);
+            let local_db = 20.0 * (1.05f32 / 0.95).log10();
+            assert!((shimmer.local_db - local_db).abs() < 2e-2, "dB {}", shimmer.local_db);
+            assert!((shimmer.apq3 - 0.2 / 3.0).abs() < 2e-3, "apq3 {}", shimmer.apq3);
+            assert!((shimmer.apq5 - 0.04).abs() < 2e-3, "apq5 {}", shimmer.apq5);
+            assert!((shimmer.apq11 - 0.6 / 11.0).abs() < 2e-3, "apq11 {}", shimmer.apq11);
+            assert!((shimmer.dda - 3.0 * shimmer.apq3).abs() < 1e-4);
         }
+    }
+
+    #[test]
+    fn test_jitter_and_shimmer_invalid_input() {
+        let buffer = alternating_pulse_train(0.0, 0.0, 4096);
+        let config = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
 
         unsafe {
-            let result = extract_formants_rust(
-                buffer.as_ptr(),
-                num_samples as c_int,
-                sample_rate,
-                0  // Use default LPC order
-            );
+            let result = loqa_calculate_jitter(std::ptr::null(), 4096, config());
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_calculate_shimmer(buffer.as_ptr(), 0, config());
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            let result = loqa_calculate_jitter(buffer.as_ptr(), 2048, config());
+            assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+
+            let bad_rate = AnalysisConfigFFI { sample_rate: 4000, ..config() };
+            let result = loqa_calculate_jitter(buffer.as_ptr(), 4096, bad_rate);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            let bad_range = AnalysisConfigFFI { min_frequency: 500.0, ..config() };
+            let result = loqa_calculate_shimmer(buffer.as_ptr(), 4096, bad_range);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFrequencyRange);
+
+            // Silence has no voiced periods
+            let silence = vec![0.0f32; 8000];
+            let result = loqa_calculate_shimmer(silence.as_ptr(), silence.len(), config());
+            assert!(!result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::BufferTooShort);
+        }
+    }
 
-            // AC1, AC2, AC5: Should extract formants and return them in Hz
-            // For synthetic signals, LPC may produce varying results
-            // Key tests:
-            // 1. Function executes without crashing
-            // 2. Returns valid (finite, non-NaN) values
-            // 3. At least one formant is detected (F1 should be non-zero for voiced signal)
+    #[test]
+    fn test_cpp_of_harmonic_signal() {
+        // Sawtooth-like harmonic complex at 200 Hz
+        let buffer: Vec<f32> = (0..16000)
+            .map(|i| {
+                let t = i as f32 / 16000.0;
+                (1..=15).map(|k| (2.0 * PI * 200.0 * k as f32 * t).sin() / k as f32).sum()
+            })
+            .collect();
+        let config = loqa_cpp_config_default();
 
-            // All formants should be finite (not NaN or Infinity)
-            assert!(result.f1.is_finite(), "F1 should be finite");
-            assert!(result.f2.is_finite(), "F2 should be finite");
-            assert!(result.f3.is_finite(), "F3 should be finite");
+        unsafe {
+            let result = loqa_calculate_cpp(buffer.as_ptr(), buffer.len(), 16000, config);
+            assert!(result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::Ok);
+            assert!((result.f0 - 200.0).abs() < 5.0, "f0 {}", result.f0);
+            assert!(result.cpps > 10.0 && result.cpp > result.cpps, "{result:?}");
+            assert_eq!(result.frame_count, (16000 - 2048u32).div_ceil(128));
+
+            // Without smoothing CPPS is CPP
+            let unsmoothed = CppConfigFFI {
+                time_smoothing: 0.0,
+                quefrency_smoothing: 0.0,
+                ..config
+            };
+            let result = loqa_calculate_cpp(buffer.as_ptr(), buffer.len(), 16000, unsmoothed);
+            assert_eq!(result.cpp, result.cpps);
+        }
+    }
 
-            // All formants should be non-negative
-            assert!(result.f1 >= 0.0, "F1 should be non-negative");
-            assert!(result.f2 >= 0.0, "F2 should be non-negative");
-            assert!(result.f3 >= 0.0, "F3 should be non-negative");
+    #[test]
+    fn test_cpp_invalid_input() {
+        let buffer = vec![0.1f32; 4096];
+        let config = loqa_cpp_config_default();
 
-            // For a voiced signal (even synthetic), we expect at least F1 to be detected
-            // F2 and F3 may be 0 depending on the signal quality and LPC algorithm behavior
-            if result.f1 > 0.0 {
-                // If formants are detected, they should be in physically plausible ranges
-                // Very wide ranges to accommodate synthetic signal limitations
-                assert!(
-                    result.f1 <= 5000.0,
-                    "F1 {:.1} Hz should be below Nyquist/2 for 44.1kHz",
-                    result.f1
-                );
-                if result.f2 > 0.0 {
-                    assert!(
-                        result.f2 <= 5000.0,
-                        "F2 {:.1} Hz should be below Nyquist/2",
-                        result.f2
-                    );
-                }
-                if result.f3 > 0.0 {
-                    assert!(
-                        result.f3 <= 5000.0,
-                        "F3 {:.1} Hz should be below Nyquist/2",
-                        result.f3
-                    );
-                }
+        unsafe {
+            let result = loqa_calculate_cpp(std::ptr::null(), 4096, 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_calculate_cpp(buffer.as_ptr(), 2048, 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            let result = loqa_calculate_cpp(buffer.as_ptr(), 4096, 0, config);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+
+            let cases = [
+                (CppConfigFFI { frame_size: 1000, ..config }, LoqaErrorCode::InvalidFftSize),
+                (CppConfigFFI { hop_size: 0, ..config }, LoqaErrorCode::InvalidArgument),
+                (
+                    CppConfigFFI { min_frequency: 0.0, ..config },
+                    LoqaErrorCode::InvalidFrequencyRange,
+                ),
+                (
+                    CppConfigFFI { max_frequency: 9000.0, ..config },
+                    LoqaErrorCode::InvalidFrequencyRange,
+                ),
+                // A 20 Hz period (800 samples) does not fit in half a 1024-sample frame
+                (
+                    CppConfigFFI { frame_size: 1024, min_frequency: 20.0, ..config },
+                    LoqaErrorCode::InvalidFrequencyRange,
+                ),
+                (CppConfigFFI { time_smoothing: -1.0, ..config }, LoqaErrorCode::InvalidArgument),
+                (
+                    CppConfigFFI { quefrency_smoothing: f32::NAN, ..config },
+                    LoqaErrorCode::InvalidArgument,
+                ),
+            ];
+            for (config, expected) in cases {
+                let result = loqa_calculate_cpp(buffer.as_ptr(), 4096, 16000, config);
+                assert!(!result.success);
+                assert_eq!(result.error_code, expected, "{config:?}");
             }
+        }
+    }
 
-            // Bandwidths are not yet implemented in loqa-voice-dsp v0.1, so they will be 0
-            // This is acceptable for v0.1.0 - bandwidth estimation can be added in future versions
-            assert!(result.bw1 >= 0.0, "Bandwidth 1 should be non-negative");
-            assert!(result.bw2 >= 0.0, "Bandwidth 2 should be non-negative");
-            assert!(result.bw3 >= 0.0, "Bandwidth 3 should be non-negative");
+    #[test]
+    fn test_intensity_contour_calibration() {
+        // Full-scale sine with whole periods per frame: RMS 1 / sqrt(2), -3.01 dBFS
+        let buffer = sine_wave(250.0, 16000, 8000);
+        let config = IntensityConfigFFI {
+            calibration_offset: 100.0,
+            ..loqa_intensity_config_default()
+        };
+
+        unsafe {
+            let mut result = loqa_compute_intensity(buffer.as_ptr(), buffer.len(), 16000, config);
+            assert!(result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::Ok);
+            assert_eq!(result.frame_count, (8000 - 2048usize).div_ceil(512));
+
+            let rms = slice::from_raw_parts(result.rms_ptr, result.frame_count);
+            let intensity = slice::from_raw_parts(result.intensity_db_ptr, result.frame_count);
+            let peak = slice::from_raw_parts(result.peak_db_ptr, result.frame_count);
+            let times = slice::from_raw_parts(result.timestamps_ptr, result.frame_count);
+            assert!(rms.iter().all(|&r| (r - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3), "{rms:?}");
+            assert!(intensity.iter().all(|&db| (db - 96.99).abs() < 0.01), "{intensity:?}");
+            assert!(peak.iter().all(|&db| (db - 100.0).abs() < 0.01), "{peak:?}");
+            assert_eq!(times[2], 1024.0 / 16000.0);
+            assert!((result.mean_db - 96.99).abs() < 0.01);
+            assert!(result.dynamic_range_db < 0.01);
+            assert_eq!(result.dynamic_range_db, result.max_db - result.min_db);
+
+            loqa_free_intensity_contour(&mut result);
+            assert!(result.rms_ptr.is_null() && result.timestamps_ptr.is_null());
+            loqa_free_intensity_contour(&mut result);
+            loqa_free_intensity_contour(std::ptr::null_mut());
         }
     }
 
     #[test]
-    fn test_extract_formants_multiple_sample_rates() {
-        // Test formant extraction works across different sample rates
-        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
-            let duration = 0.05; // 50ms
-            let num_samples = (sample_rate as f32 * duration) as usize;
+    fn test_intensity_invalid_input() {
+        let buffer = vec![0.1f32; 4096];
+        let config = loqa_intensity_config_default();
 
-            // Generate a simple periodic signal
-            let frequency = 200.0;
-            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-            for i in 0..num_samples {
-                let t = i as f32 / sample_rate as f32;
-                buffer.push((2.0 * PI * frequency * t).sin());
+        unsafe {
+            let result = loqa_compute_intensity(std::ptr::null(), 4096, 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_compute_intensity(buffer.as_ptr(), 2048, 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            let result = loqa_compute_intensity(buffer.as_ptr(), 4096, 0, config);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+
+            let cases = [
+                IntensityConfigFFI { frame_size: 0, ..config },
+                IntensityConfigFFI { hop_size: 0, ..config },
+                IntensityConfigFFI { hop_size: 4096, ..config },
+                IntensityConfigFFI { calibration_offset: f32::NAN, ..config },
+                IntensityConfigFFI { floor_db: f32::NEG_INFINITY, ..config },
+            ];
+            for config in cases {
+                let result = loqa_compute_intensity(buffer.as_ptr(), 4096, 16000, config);
+                assert!(!result.success && result.rms_ptr.is_null());
+                assert_eq!(result.error_code, LoqaErrorCode::InvalidArgument, "{config:?}");
             }
 
-            unsafe {
-                let result = extract_formants_rust(
-                    buffer.as_ptr(),
-                    num_samples as c_int,
-                    sample_rate as c_int,
-                    0  // Use default LPC order
-                );
+            // Frame sizes need not be powers of two
+            let config = IntensityConfigFFI { frame_size: 441, hop_size: 441, ..config };
+            let mut result = loqa_compute_intensity(buffer.as_ptr(), 4096, 44100, config);
+            assert!(result.success);
+            assert_eq!(result.frame_count, 9);
+            loqa_free_intensity_contour(&mut result);
+        }
+    }
 
-                // AC3: All sample rates in 8000-48000 Hz should work
-                // Formants should be non-negative (may be 0 depending on signal)
-                assert!(
-                    result.f1 >= 0.0 && result.f2 >= 0.0 && result.f3 >= 0.0,
-                    "Sample rate {} Hz should work (got F1={:.1}, F2={:.1}, F3={:.1})",
-                    sample_rate,
-                    result.f1,
-                    result.f2,
-                    result.f3
-                );
+    #[test]
+    fn test_voice_activity_finds_tone_between_silences() {
+        // 1 s silence, 1 s of a 200 Hz tone at -9 dBFS, 1 s silence
+        let mut buffer = vec![0.0f32; 16000];
+        buffer.extend(sine_wave(200.0, 16000, 16000).iter().map(|x| 0.5 * x));
+        buffer.extend(vec![0.0f32; 16000]);
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+
+        unsafe {
+            let config = loqa_vad_config_default();
+            let mut result =
+                loqa_detect_voice_activity(buffer.as_ptr(), buffer.len(), analysis(), config);
+            assert!(result.success, "{:?}", result.error_code);
+            assert_eq!(result.frame_count, (48000 - 2048usize).div_ceil(512));
+            assert_eq!(result.segment_count, 1);
+
+            let segment = *result.segments_ptr;
+            assert!((14000..=16000).contains(&segment.start), "{segment:?}");
+            // The tone ends at 32000; the last frame and 0.2 s hangover extend the segment
+            assert!((32000..=32000 + 2048 + 3200).contains(&segment.end), "{segment:?}");
+
+            let frames = result.frame_count;
+            let energy = slice::from_raw_parts(result.energy_db_ptr, frames);
+            let zcr = slice::from_raw_parts(result.zcr_ptr, frames);
+            let voicing = slice::from_raw_parts(result.voiced_probs_ptr, frames);
+            let is_speech = slice::from_raw_parts(result.is_speech_ptr, frames);
+            let times = slice::from_raw_parts(result.timestamps_ptr, frames);
+            // Frame 40 starts at 1.28 s, inside the tone
+            assert_eq!(times[40], 1.28);
+            assert!((energy[40] + 9.03).abs() < 0.05, "{}", energy[40]);
+            assert!((zcr[40] - 400.0).abs() < 10.0, "{}", zcr[40]);
+            assert!(voicing[40] > 0.5 && is_speech[40]);
+            assert!(!is_speech[0] && !is_speech[frames - 1]);
+            assert_eq!(energy[0], -120.0);
+
+            loqa_free_voice_activity(&mut result);
+            assert!(result.segments_ptr.is_null() && result.is_speech_ptr.is_null());
+            loqa_free_voice_activity(&mut result);
+            loqa_free_voice_activity(std::ptr::null_mut());
+
+            // Silence alone has no segments
+            let silence = vec![0.0f32; 8000];
+            let mut result =
+                loqa_detect_voice_activity(silence.as_ptr(), silence.len(), analysis(), config);
+            assert!(result.success);
+            assert_eq!(result.segment_count, 0);
+            assert!(result.segments_ptr.is_null());
+            loqa_free_voice_activity(&mut result);
+        }
+    }
+
+    #[test]
+    fn test_voice_activity_invalid_input() {
+        let buffer = vec![0.1f32; 4096];
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+        let config = loqa_vad_config_default();
+
+        unsafe {
+            let result = loqa_detect_voice_activity(std::ptr::null(), 4096, analysis(), config);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            let result = loqa_detect_voice_activity(buffer.as_ptr(), 2048, analysis(), config);
+            assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+            let bad_rate = AnalysisConfigFFI { sample_rate: 0, ..analysis() };
+            let result = loqa_detect_voice_activity(buffer.as_ptr(), 4096, bad_rate, config);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+
+            let cases = [
+                VadConfigFFI { energy_threshold_db: f32::NAN, ..config },
+                VadConfigFFI { zcr_threshold: -1.0, ..config },
+                VadConfigFFI { voicing_threshold: 1.5, ..config },
+                VadConfigFFI { hangover_time: f32::INFINITY, ..config },
+            ];
+            for config in cases {
+                let result = loqa_detect_voice_activity(buffer.as_ptr(), 4096, analysis(), config);
+                assert!(!result.success && result.segments_ptr.is_null());
+                assert_eq!(result.error_code, LoqaErrorCode::InvalidArgument, "{config:?}");
             }
         }
     }
 
     #[test]
-    fn test_extract_formants_result_struct_layout() {
-        // Verify FormantsResult struct is properly laid out for FFI
-        let test_result = FormantsResult {
-            f1: 700.0,
-            f2: 1200.0,
-            f3: 2500.0,
-            bw1: 50.0,
-            bw2: 100.0,
-            bw3: 150.0,
+    fn test_vocal_tract_length_of_synthetic_vowels() {
+        // Uniform tubes: Fn = (2n - 1) * 35000 / 4L, so 17.5 cm gives 500, 1500, ...
+        // and 14 cm gives 625, 1875, ... (bandwidths widen with frequency)
+        let tube = |length: f64| -> Vec<(f64, f64)> {
+            (1..=5)
+                .map(|n| ((2 * n - 1) as f64 * 35000.0 / (4.0 * length), 40.0 + 30.0 * n as f64))
+                .collect()
         };
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+        let config = loqa_vtl_config_default();
+
+        for (length, f0) in [(17.5, 110.0), (14.0, 210.0)] {
+            let buffer = formants::tests::synthetic_vowel(&tube(length), f0, 16000, 24000);
+            let result = unsafe {
+                loqa_estimate_vocal_tract_length(buffer.as_ptr(), buffer.len(), analysis(), config)
+            };
+            assert!(result.success, "{:?}", result.error_code);
+            let expected = length as f32;
+            assert!((result.vtl_cm - expected).abs() < expected * 0.05, "{result:?}");
+            assert!((result.spacing_vtl_cm - expected).abs() < expected * 0.08, "{result:?}");
+            assert!(result.frame_count >= 30, "{result:?}");
+            assert!(result.confidence > 0.5, "{result:?}");
+            assert!((result.f1 - 17500.0 / (2.0 * expected)).abs() < 60.0, "{result:?}");
+            let spacing = 35000.0 / (2.0 * result.vtl_cm);
+            assert!((result.formant_spacing_hz - spacing).abs() < 1e-2);
+        }
+    }
 
-        assert_eq!(test_result.f1, 700.0);
-        assert_eq!(test_result.f2, 1200.0);
-        assert_eq!(test_result.f3, 2500.0);
-        assert_eq!(test_result.bw1, 50.0);
-        assert_eq!(test_result.bw2, 100.0);
-        assert_eq!(test_result.bw3, 150.0);
+    #[test]
+    fn test_vocal_tract_length_invalid_input() {
+        let buffer = sine_wave(200.0, 16000, 8000);
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+        let config = loqa_vtl_config_default();
+        let estimate = |samples: &[f32], config: VtlConfigFFI| unsafe {
+            loqa_estimate_vocal_tract_length(samples.as_ptr(), samples.len(), analysis(), config)
+        };
 
-        // Verify struct is Copy (required for FFI)
-        let copied = test_result;
-        assert_eq!(copied.f1, 700.0);
-        assert_eq!(test_result.f1, 700.0); // Original still valid
+        let result =
+            unsafe { loqa_estimate_vocal_tract_length(std::ptr::null(), 8000, analysis(), config) };
+        assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        assert_eq!(estimate(&buffer[..2048], config).error_code, LoqaErrorCode::BufferTooShort);
+
+        let invalid = LoqaErrorCode::InvalidArgument;
+        let cases = [
+            (VtlConfigFFI { min_formant_confidence: 1.5, ..config }, invalid),
+            (VtlConfigFFI { speed_of_sound: 0.0, ..config }, invalid),
+            (VtlConfigFFI { speed_of_sound: f32::NAN, ..config }, invalid),
+            (VtlConfigFFI { lpc_order: 30, ..config }, LoqaErrorCode::InvalidLpcOrder),
+        ];
+        for (config, code) in cases {
+            let result = estimate(&buffer, config);
+            assert!(!result.success);
+            assert_eq!(result.error_code, code, "{config:?}");
+        }
+
+        // Silence has no voiced frames to measure
+        let result = estimate(&[0.0; 8000], config);
+        assert_eq!(result.error_code, LoqaErrorCode::AnalysisFailed);
+        assert_eq!(result.vtl_cm, 0.0);
     }
 
     #[test]
-    fn test_extract_formants_silence() {
-        // Test with silence (all zeros)
-        let buffer: Vec<f32> = vec![0.0; 2048];
-        let sample_rate = 44100;
+    fn test_formant_tracker_tracks_vowels_and_flags_silence() {
+        let vowel = [(500.0, 60.0), (1500.0, 80.0), (2500.0, 100.0), (3500.0, 150.0)];
+        let mut buffer = vec![0.0; 1600];
+        buffer.extend(formants::tests::synthetic_vowel(&vowel, 120.0, 16000, 8000));
 
         unsafe {
-            let result = extract_formants_rust(buffer.as_ptr(), 2048, sample_rate, 0);
+            let tracker = loqa_formant_tracker_new(loqa_formant_tracker_config_default());
+            assert!(!tracker.is_null());
+
+            let mut track = loqa_formant_tracker_process_buffer(tracker, buffer.as_ptr(), 9600);
+            assert!(track.success, "{:?}", track.error_code);
+            assert_eq!(track.length, 58);
+
+            let frequencies = slice::from_raw_parts(track.frequencies_ptr, track.length * 4);
+            let bandwidths = slice::from_raw_parts(track.bandwidths_ptr, track.length * 4);
+            let confidences = slice::from_raw_parts(track.confidences_ptr, track.length);
+            let reliable = slice::from_raw_parts(track.reliable_ptr, track.length);
+            let timestamps = slice::from_raw_parts(track.timestamps_ptr, track.length);
+            assert!((timestamps[10] - 0.1).abs() < 1e-6);
+
+            // The leading 100 ms of silence is unreliable and has no formants
+            assert!(reliable[..8].iter().all(|&r| !r));
+            assert!(frequencies[..32].iter().all(|&f| f == 0.0));
+            for i in 11..track.length {
+                assert!(reliable[i], "frame {i}");
+                assert!(confidences[i] > 0.5, "frame {i}: {}", confidences[i]);
+                for (n, &(expected, _)) in vowel[..3].iter().enumerate() {
+                    let f = frequencies[i * 4 + n] as f64;
+                    assert!((f - expected).abs() < expected * 0.08, "frame {i} F{}: {f}", n + 1);
+                    assert!(bandwidths[i * 4 + n] > 0.0);
+                }
+            }
 
-            // Silence may produce formant estimates or zeros depending on algorithm
-            // The important thing is it doesn't crash and returns valid (non-NaN) values
-            assert!(result.f1.is_finite(), "F1 should be finite for silence");
-            assert!(result.f2.is_finite(), "F2 should be finite for silence");
-            assert!(result.f3.is_finite(), "F3 should be finite for silence");
-            assert!(result.bw1.is_finite(), "BW1 should be finite for silence");
-            assert!(result.bw2.is_finite(), "BW2 should be finite for silence");
-            assert!(result.bw3.is_finite(), "BW3 should be finite for silence");
+            loqa_free_formant_track(&mut track);
+            assert!(track.frequencies_ptr.is_null() && track.reliable_ptr.is_null());
+            assert_eq!(track.length, 0);
+            loqa_free_formant_track(&mut track);
+
+            // The next buffer continues the recording, and after a reset starts a new one
+            let tail = &buffer[1600..2400];
+            let mut next = loqa_formant_tracker_process_buffer(tracker, tail.as_ptr(), tail.len());
+            assert!(next.success);
+            assert_eq!(next.length, 3);
+            loqa_free_formant_track(&mut next);
+            loqa_formant_tracker_reset(tracker);
+            loqa_formant_tracker_free(tracker);
         }
     }
 
-    // ======== Spectral Analysis Tests ========
+    #[test]
+    fn test_formant_tracker_invalid_input() {
+        let default = loqa_formant_tracker_config_default();
+        let invalid = LoqaErrorCode::InvalidArgument;
+        let range = LoqaErrorCode::InvalidFrequencyRange;
+        let low_rate = FormantTrackerConfigFFI { sample_rate: 4000, ..default };
+        let narrowband = FormantTrackerConfigFFI { sample_rate: 8000, frame_size: 200, ..default };
+        let cases = [
+            (low_rate, LoqaErrorCode::InvalidSampleRate),
+            (FormantTrackerConfigFFI { frame_size: 100, ..default }, invalid),
+            (FormantTrackerConfigFFI { frame_size: 2000, ..default }, invalid),
+            (FormantTrackerConfigFFI { hop_size: 0, ..default }, invalid),
+            (FormantTrackerConfigFFI { hop_size: 401, ..default }, invalid),
+            (FormantTrackerConfigFFI { lpc_order: 30, ..default }, LoqaErrorCode::InvalidLpcOrder),
+            (FormantTrackerConfigFFI { ceiling_hz: 2000.0, ..default }, range),
+            (FormantTrackerConfigFFI { ceiling_hz: f32::NAN, ..default }, range),
+            (narrowband, range),
+            (FormantTrackerConfigFFI { continuity_weight: -1.0, ..default }, invalid),
+            (FormantTrackerConfigFFI { min_confidence: 1.5, ..default }, invalid),
+            (FormantTrackerConfigFFI { silence_db: 10.0, ..default }, invalid),
+        ];
+        for (config, code) in cases {
+            assert!(loqa_formant_tracker_new(config).is_null(), "{config:?}");
+            assert_eq!(loqa_last_error_code(), code, "{config:?}");
+        }
+
+        unsafe {
+            let samples = [0.1f32; 400];
+            let null = std::ptr::null_mut();
+            let track = loqa_formant_tracker_process_buffer(null, samples.as_ptr(), 400);
+            assert_eq!(track.error_code, LoqaErrorCode::NullPointer);
+
+            let tracker = loqa_formant_tracker_new(default);
+            let track = loqa_formant_tracker_process_buffer(tracker, std::ptr::null(), 400);
+            assert_eq!(track.error_code, LoqaErrorCode::NullPointer);
+            let track = loqa_formant_tracker_process_buffer(tracker, samples.as_ptr(), 0);
+            assert_eq!(track.error_code, LoqaErrorCode::InvalidLength);
+            let track = loqa_formant_tracker_process_buffer(tracker, samples.as_ptr(), 400);
+            assert_eq!(track.error_code, LoqaErrorCode::BufferTooShort);
+            assert!(!track.success && track.frequencies_ptr.is_null());
+            loqa_formant_tracker_free(tracker);
+
+            loqa_formant_tracker_reset(std::ptr::null_mut());
+            loqa_formant_tracker_free(std::ptr::null_mut());
+            loqa_free_formant_track(std::ptr::null_mut());
+        }
+    }
+
+    // ======== Error Code Tests ========
 
     #[test]
-    fn test_analyze_spectrum_null_buffer() {
+    fn test_compute_fft_sets_last_error_code() {
+        let buffer: Vec<f32> = vec![0.5; 1024];
         unsafe {
-            let result = analyze_spectrum_rust(std::ptr::null(), 1024, 44100);
-            assert_eq!(result.centroid, 0.0, "Should return centroid=0.0 for null buffer");
-            assert_eq!(result.rolloff, 0.0, "Should return rolloff=0.0 for null buffer");
-            assert_eq!(result.tilt, 0.0, "Should return tilt=0.0 for null buffer");
+            let result = compute_fft_rust(std::ptr::null(), 1024, 44100, 512);
+            assert!(result.is_null());
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
+
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 500);
+            assert!(result.is_null());
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidFftSize);
+
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 0, 512);
+            assert!(result.is_null());
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
+            assert!(!loqa_last_error_message().is_null());
+
+            // A successful call clears the previous error
+            let result = compute_fft_rust(buffer.as_ptr(), 1024, 44100, 512);
+            assert!(!result.is_null());
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+            assert!(loqa_last_error_message().is_null());
+            free_fft_result_rust(result, 257);
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_invalid_length() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    fn test_detect_pitch_distinguishes_silence_from_errors() {
+        let silence: Vec<f32> = vec![0.0; 2048];
         unsafe {
-            // Test zero length
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 0, 44100);
-            assert_eq!(result.centroid, 0.0);
-            assert_eq!(result.rolloff, 0.0);
-            assert_eq!(result.tilt, 0.0);
+            // Silence is a valid, unvoiced result
+            let result = detect_pitch_rust(silence.as_ptr(), 2048, 44100);
+            assert!(!result.is_voiced);
+            assert_eq!(result.error_code, LoqaErrorCode::Ok);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
 
-            // Test negative length
-            let result = analyze_spectrum_rust(buffer.as_ptr(), -10, 44100);
-            assert_eq!(result.centroid, 0.0);
-            assert_eq!(result.rolloff, 0.0);
-            assert_eq!(result.tilt, 0.0);
+            // Invalid sample rate is an error, even though the other fields look the same
+            let result = detect_pitch_rust(silence.as_ptr(), 2048, 7999);
+            assert!(!result.is_voiced);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
+
+            let result = detect_pitch_rust(silence.as_ptr(), 0, 44100);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_invalid_sample_rate() {
-        let buffer: Vec<f32> = vec![0.0; 1024];
+    fn test_extract_formants_error_codes() {
+        let buffer: Vec<f32> = vec![0.1; 1024];
         unsafe {
-            // Test below 8000 Hz (AC1)
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 7999);
-            assert_eq!(result.centroid, 0.0, "Should return error for sample rate < 8000 Hz");
+            let result = extract_formants_rust(buffer.as_ptr(), 1024, 16000, 30);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLpcOrder);
 
-            // Test above 48000 Hz (AC1)
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 48001);
-            assert_eq!(result.centroid, 0.0, "Should return error for sample rate > 48000 Hz");
+            let result = extract_formants_rust(buffer.as_ptr(), 20, 16000, 12);
+            assert_eq!(result.error_code, LoqaErrorCode::BufferTooShort);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::BufferTooShort);
 
-            // Test zero/negative sample rate
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 0);
-            assert_eq!(result.centroid, 0.0);
+            let message = std::ffi::CStr::from_ptr(loqa_last_error_message());
+            assert!(message.to_str().unwrap().contains("too short for LPC order 12"));
+        }
+    }
 
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, -100);
-            assert_eq!(result.centroid, 0.0);
+    #[test]
+    fn test_analyze_spectrum_error_codes() {
+        let buffer: Vec<f32> = vec![0.1; 1024];
+        unsafe {
+            let result = analyze_spectrum_rust(std::ptr::null(), 1024, 44100);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+
+            let result = analyze_spectrum_rust(buffer.as_ptr(), 1024, 96000);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_valid_sample_rates() {
-        let buffer: Vec<f32> = vec![0.5; 2048];
+    fn test_calculate_hnr_invalid_frequency_range() {
+        let buffer: Vec<f32> = vec![0.1; 4096];
+        unsafe {
+            let result = calculate_hnr_rust(buffer.as_ptr(), 4096, 44100, 500.0, 75.0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFrequencyRange);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidFrequencyRange);
+        }
+    }
 
+    #[test]
+    fn test_calculate_hnr_and_h1h2_ex_match_rust_exports() {
+        let buffer = sine_wave(200.0, 16000, 4096);
         unsafe {
-            // Test minimum valid sample rate (8000 Hz)
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 8000);
-            // Should not error (values may vary based on buffer content, but call should succeed)
-            assert!(result.centroid.is_finite());
-            assert!(result.rolloff.is_finite());
-            assert!(result.tilt.is_finite());
+            let ex = loqa_calculate_hnr_ex(buffer.as_ptr(), buffer.len(), 16000, 75.0, 500.0);
+            let rust = calculate_hnr_rust(buffer.as_ptr(), 4096, 16000, 75.0, 500.0);
+            assert_eq!(ex.error_code, LoqaErrorCode::Ok);
+            assert_eq!((ex.hnr, ex.f0, ex.is_voiced), (rust.hnr, rust.f0, rust.is_voiced));
+
+            let ex = loqa_calculate_h1h2_ex(buffer.as_ptr(), buffer.len(), 16000, 0.0);
+            let rust = calculate_h1h2_rust(buffer.as_ptr(), 4096, 16000, 0.0);
+            assert_eq!(ex.error_code, LoqaErrorCode::Ok);
+            assert_eq!((ex.h1h2, ex.f0), (rust.h1h2, rust.f0));
+
+            let result = loqa_calculate_hnr_ex(buffer.as_ptr(), buffer.len(), 16000, 500.0, 75.0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidFrequencyRange);
+            let result = loqa_calculate_hnr_ex(std::ptr::null(), 4096, 16000, 75.0, 500.0);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+
+            let result = loqa_calculate_h1h2_ex(buffer.as_ptr(), buffer.len(), u32::MAX, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidSampleRate);
+            let result = loqa_calculate_h1h2_ex(buffer.as_ptr(), 0, 16000, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidLength);
+        }
+    }
 
-            // Test common sample rate (44100 Hz)
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 44100);
-            assert!(result.centroid.is_finite());
-            assert!(result.rolloff.is_finite());
-            assert!(result.tilt.is_finite());
+    #[test]
+    fn test_calculate_h1h2_error_codes() {
+        let buffer: Vec<f32> = vec![0.1; 4096];
+        unsafe {
+            let result = calculate_h1h2_rust(std::ptr::null(), 4096, 44100, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
 
-            // Test maximum valid sample rate (48000 Hz)
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, 48000);
-            assert!(result.centroid.is_finite());
-            assert!(result.rolloff.is_finite());
-            assert!(result.tilt.is_finite());
+            let result = calculate_h1h2_rust(buffer.as_ptr(), -1, 44100, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_sine_wave_440hz() {
-        // Generate a pure 440 Hz sine wave
-        // Expected characteristics:
-        // - Centroid should be close to 440 Hz (narrow spectral peak)
-        // - Rolloff should be close to 440 Hz (most energy concentrated there)
-        // - Tilt should be near 0 (flat spectrum around the peak)
-        let sample_rate = 44100;
-        let frequency = 440.0;
-        let duration = 0.1; // 100ms
-        let num_samples = (sample_rate as f32 * duration) as usize;
+    fn test_process_buffer_null_analyzer_error_code() {
+        let buffer: Vec<f32> = vec![0.1; 4096];
+        unsafe {
+            let result = loqa_voice_analyzer_process_buffer(
+                std::ptr::null_mut(),
+                buffer.as_ptr(),
+                buffer.len(),
+            );
+            assert!(!result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
+        }
+    }
 
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            buffer.push((2.0 * PI * frequency * t).sin());
+    // ======== VoiceAnalyzer Lifecycle Tests ========
+
+    fn analyzer_config(sample_rate: u32, algorithm: LoqaPitchAlgorithm) -> AnalysisConfigFFI {
+        AnalysisConfigFFI {
+            sample_rate,
+            frame_size: 2048,
+            hop_size: 512,
+            min_frequency: 80.0,
+            max_frequency: 400.0,
+            algorithm: algorithm as u32,
+            threshold: 0.15,
+            min_confidence: 0.5,
+            interpolate: true,
         }
+    }
+
+    fn sine_wave(frequency: f32, sample_rate: u32, num_samples: usize) -> Vec<f32> {
+        (0..num_samples)
+            .map(|i| (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_analysis_config_default_is_valid() {
+        assert_eq!(
+            loqa_analysis_config_validate(loqa_analysis_config_default()),
+            LoqaErrorCode::Ok
+        );
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+    }
+
+    #[test]
+    fn test_analysis_config_validate_reports_reason() {
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.algorithm = 7;
+        assert_eq!(loqa_analysis_config_validate(config), LoqaErrorCode::InvalidArgument);
+
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.max_frequency = 9000.0; // Above Nyquist
+        assert_eq!(
+            loqa_analysis_config_validate(config),
+            LoqaErrorCode::InvalidFrequencyRange
+        );
+
+        let config = analyzer_config(4000, LoqaPitchAlgorithm::Auto);
+        assert_eq!(
+            loqa_analysis_config_validate(config),
+            LoqaErrorCode::InvalidSampleRate
+        );
+
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.hop_size = 4096;
+        assert_eq!(loqa_analysis_config_validate(config), LoqaErrorCode::InvalidArgument);
+        let message = unsafe { std::ffi::CStr::from_ptr(loqa_last_error_message()) };
+        assert!(message.to_str().unwrap().contains("hop_size"));
+    }
+
+    #[test]
+    fn test_voice_analyzer_create_reports_reason() {
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.algorithm = 7; // Upstream loqa_voice_analyzer_new silently uses Auto
+        assert!(loqa_voice_analyzer_create(config).is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidArgument);
+
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.frame_size = 128;
+        assert!(loqa_voice_analyzer_create(config).is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidArgument);
+        let message = unsafe { std::ffi::CStr::from_ptr(loqa_last_error_message()) };
+        assert!(message.to_str().unwrap().contains("frame_size"));
+
+        let analyzer = loqa_voice_analyzer_create(analyzer_config(16000, LoqaPitchAlgorithm::Yin));
+        assert!(!analyzer.is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+        unsafe { loqa_voice_analyzer_free(analyzer) };
+    }
+
+    #[test]
+    fn test_pitch_algorithm_selector_values() {
+        assert_eq!(LoqaPitchAlgorithm::from_raw(0), Some(LoqaPitchAlgorithm::Auto));
+        assert_eq!(LoqaPitchAlgorithm::from_raw(1), Some(LoqaPitchAlgorithm::Pyin));
+        assert_eq!(LoqaPitchAlgorithm::from_raw(2), Some(LoqaPitchAlgorithm::Yin));
+        assert_eq!(LoqaPitchAlgorithm::from_raw(3), Some(LoqaPitchAlgorithm::Autocorr));
+        assert_eq!(LoqaPitchAlgorithm::from_raw(4), None);
+    }
+
+    #[test]
+    fn test_voice_analyzer_lifecycle() {
+        let sample_rate = 16000;
+        let samples = sine_wave(220.0, sample_rate, 16000);
 
         unsafe {
-            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+            let analyzer = loqa_voice_analyzer_create(analyzer_config(sample_rate, LoqaPitchAlgorithm::Pyin));
+            assert!(!analyzer.is_null());
+
+            let mut track = loqa_voice_analyzer_process_buffer(analyzer, samples.as_ptr(), samples.len());
+            assert!(track.success);
+            assert!(track.length > 0);
+            let pitches = slice::from_raw_parts(track.pitch_track_ptr, track.length);
+            let voiced: Vec<f32> = pitches.iter().copied().filter(|&p| p > 0.0).collect();
+            assert!(!voiced.is_empty());
+            let mean = voiced.iter().sum::<f32>() / voiced.len() as f32;
+            assert!((mean - 220.0).abs() < 10.0, "mean pitch {mean} Hz");
+            loqa_free_pitch_track(&mut track);
+
+            // Reset and reuse the same analyzer
+            loqa_voice_analyzer_reset_ex(analyzer);
+            let mut track = loqa_voice_analyzer_process_buffer(analyzer, samples.as_ptr(), samples.len());
+            assert!(track.success);
+            loqa_free_pitch_track(&mut track);
+
+            loqa_voice_analyzer_free_ex(analyzer);
+        }
+    }
 
-            // AC2, AC3, AC4: All spectral features should be computed
-            // All values should be finite (not NaN or Infinity)
-            assert!(result.centroid.is_finite(), "Centroid should be finite");
-            assert!(result.rolloff.is_finite(), "Rolloff should be finite");
-            assert!(result.tilt.is_finite(), "Tilt should be finite");
+    #[test]
+    fn test_postprocess_pitch_track_keeps_frames_aligned() {
+        // An octave error, a two-frame dropout and a spike in a 200 Hz track
+        let pitch = [200.0f32, 201.0, 402.0, 199.0, 0.0, 0.0, 200.0, 230.0, 200.0, 201.0];
+        let probs: Vec<f32> = pitch.iter().map(|&f| if f > 0.0 { 0.9 } else { 0.2 }).collect();
+        let times: Vec<f32> = (0..pitch.len()).map(|i| i as f32 * 0.032).collect();
 
-            // All values should be non-negative for frequencies
-            assert!(result.centroid >= 0.0, "Centroid should be non-negative");
-            assert!(result.rolloff >= 0.0, "Rolloff should be non-negative");
-            // Tilt can be negative (indicating low-frequency emphasis)
+        unsafe {
+            let config = loqa_pitch_postprocess_config_default();
+            let mut track = loqa_postprocess_pitch_track(
+                pitch.as_ptr(),
+                probs.as_ptr(),
+                times.as_ptr(),
+                pitch.len(),
+                PitchPostprocessConfigFFI { octave_window: 5, smoothing_window: 3, ..config },
+            );
+            assert!(track.success);
+            assert_eq!(track.length, pitch.len());
+
+            let processed = slice::from_raw_parts(track.pitch_track_ptr, track.length);
+            assert!(processed.iter().all(|&f| (f - 200.0).abs() <= 1.5), "{processed:?}");
+            assert_eq!(slice::from_raw_parts(track.voiced_probs_ptr, track.length), probs);
+            assert_eq!(slice::from_raw_parts(track.timestamps_ptr, track.length), times);
+            loqa_free_pitch_track(&mut track);
+
+            // With every step off the track comes back unchanged
+            let off = PitchPostprocessConfigFFI {
+                correct_octave_jumps: false,
+                smoothing: LoqaPitchSmoothing::None as u32,
+                max_gap_frames: 0,
+                ..config
+            };
+            let mut track = loqa_postprocess_pitch_track(
+                pitch.as_ptr(),
+                probs.as_ptr(),
+                times.as_ptr(),
+                pitch.len(),
+                off,
+            );
+            assert_eq!(slice::from_raw_parts(track.pitch_track_ptr, track.length), pitch);
+            loqa_free_pitch_track(&mut track);
+        }
+    }
 
-            // For a narrow sine wave, centroid should be close to the frequency
-            // Allow reasonable tolerance for FFT resolution and windowing effects
-            if result.centroid > 0.0 {
-                let centroid_error = (result.centroid - frequency).abs();
-                let error_percent = (centroid_error / frequency) * 100.0;
+    #[test]
+    fn test_postprocess_pitch_track_invalid_input() {
+        let pitch = [200.0f32; 8];
+        let config = loqa_pitch_postprocess_config_default();
+        let run = |pitch: &[f32], config: PitchPostprocessConfigFFI| unsafe {
+            let result = loqa_postprocess_pitch_track(
+                pitch.as_ptr(),
+                pitch.as_ptr(),
+                pitch.as_ptr(),
+                pitch.len(),
+                config,
+            );
+            assert!(!result.success && result.pitch_track_ptr.is_null());
+            result.error_code
+        };
+
+        unsafe {
+            let result = loqa_postprocess_pitch_track(
+                pitch.as_ptr(),
+                std::ptr::null(),
+                pitch.as_ptr(),
+                8,
+                config,
+            );
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        }
+        assert_eq!(run(&[], config), LoqaErrorCode::InvalidLength);
+        assert_eq!(run(&[200.0, f32::NAN], config), LoqaErrorCode::InvalidArgument);
+        assert_eq!(run(&[200.0, -1.0], config), LoqaErrorCode::InvalidArgument);
+
+        for config in [
+            PitchPostprocessConfigFFI { smoothing: 3, ..config },
+            PitchPostprocessConfigFFI { octave_window: 1, ..config },
+            PitchPostprocessConfigFFI { octave_window: 10, ..config },
+            PitchPostprocessConfigFFI { smoothing_window: 0, ..config },
+            PitchPostprocessConfigFFI { smoothing_window: 1003, ..config },
+        ] {
+            assert_eq!(run(&pitch, config), LoqaErrorCode::InvalidArgument, "{config:?}");
+        }
+    }
+
+    #[test]
+    fn test_pitch_track_statistics_weighted_summaries() {
+        // 200 Hz with an unsure octave error and two unvoiced frames
+        let pitch = [200.0f32, 200.0, 0.0, 200.0, 400.0, 0.0, 200.0, 200.0];
+        let probs = [0.9f32, 0.9, 0.1, 0.9, 0.1, 0.1, 0.9, 0.9];
+        let config = loqa_pitch_stats_config_default();
+
+        unsafe {
+            let stats =
+                loqa_pitch_track_statistics(pitch.as_ptr(), probs.as_ptr(), pitch.len(), config);
+            assert!(stats.success);
+            assert_eq!((stats.frame_count, stats.voiced_frame_count), (8, 6));
+            assert_eq!(stats.voiced_ratio, 0.75);
+            assert_eq!(stats.median_hz, 200.0);
+            assert!(stats.mean_hz < 205.0 && stats.p95_hz > 200.0, "{stats:?}");
+            // 200 Hz is one octave above the 100 Hz reference
+            assert!((stats.median_semitones - 12.0).abs() < 1e-4);
+            let spread = stats.p95_semitones - stats.p5_semitones;
+            assert!((stats.range_semitones - spread).abs() < 1e-6);
+
+            let unweighted = PitchStatsConfigFFI { weight_by_voicing: false, ..config };
+            let stats = loqa_pitch_track_statistics(
+                pitch.as_ptr(),
+                probs.as_ptr(),
+                pitch.len(),
+                unweighted,
+            );
+            assert!((stats.mean_hz - 1400.0 / 6.0).abs() < 1e-3, "{stats:?}");
+
+            // A silent track is valid but has no pitch statistics
+            let silent = [0.0f32; 4];
+            let stats = loqa_pitch_track_statistics(silent.as_ptr(), probs.as_ptr(), 4, config);
+            assert!(stats.success && stats.voiced_frame_count == 0);
+            assert!(stats.median_hz.is_nan() && stats.range_semitones.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_pitch_track_statistics_invalid_input() {
+        let pitch = [200.0f32; 4];
+        let probs = [0.5f32; 4];
+        let config = loqa_pitch_stats_config_default();
+        let run = |pitch: &[f32], probs: &[f32], config: PitchStatsConfigFFI| unsafe {
+            let result =
+                loqa_pitch_track_statistics(pitch.as_ptr(), probs.as_ptr(), pitch.len(), config);
+            assert!(!result.success);
+            result.error_code
+        };
 
-                // Centroid should be within reasonable range of target frequency
-                // (allowing for FFT bin resolution and windowing artifacts)
-                assert!(
-                    error_percent < 50.0,
-                    "Centroid {:.1} Hz should be reasonably close to {:.1} Hz (error: {:.1}%)",
-                    result.centroid,
-                    frequency,
-                    error_percent
-                );
-            }
+        unsafe {
+            let result = loqa_pitch_track_statistics(pitch.as_ptr(), std::ptr::null(), 4, config);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        }
+        assert_eq!(run(&[], &[], config), LoqaErrorCode::InvalidLength);
+        assert_eq!(run(&[200.0, -1.0], &probs, config), LoqaErrorCode::InvalidArgument);
+        assert_eq!(run(&pitch, &[0.5, 1.5, 0.5, 0.5], config), LoqaErrorCode::InvalidArgument);
+        assert_eq!(run(&pitch, &[0.5, f32::NAN, 0.5, 0.5], config), LoqaErrorCode::InvalidArgument);
+        for reference_hz in [0.0, -100.0, f32::INFINITY] {
+            let config = PitchStatsConfigFFI { reference_hz, ..config };
+            assert_eq!(run(&pitch, &probs, config), LoqaErrorCode::InvalidArgument);
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_white_noise() {
-        // Generate white noise - broad spectrum
-        // Expected characteristics:
-        // - Centroid should be mid-range (around sample_rate / 4)
-        // - Rolloff should be high (energy distributed across spectrum)
-        // - Tilt should be near 0 (flat spectrum)
-        let sample_rate = 44100;
-        let num_samples = 2048;
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
+    fn test_analyze_vibrato_segments_summary_and_windows() {
+        // 220 Hz held for 1 s, then 1.5 s of 5 Hz vibrato of ±40 cents, at 100 frames/s
+        let mut pitch: Vec<f32> = (0..250)
+            .map(|k| {
+                let t = (k as f32 - 100.0).max(0.0) / 100.0;
+                let cents = 40.0 * (2.0 * std::f32::consts::PI * 5.0 * t).sin();
+                220.0 * (cents / 1200.0).exp2()
+            })
+            .collect();
+        pitch.extend([0.0; 10]);
+        let config = VibratoConfigFFI { window_hop_frames: 10, ..loqa_vibrato_config_default() };
 
-        // Simple pseudo-random noise generator
-        for i in 0..num_samples {
-            let hash = (i as u32).wrapping_mul(2654435761);
-            buffer.push(((hash % 1000) as f32 / 1000.0) * 2.0 - 1.0);
+        unsafe {
+            let mut result = loqa_analyze_vibrato(pitch.as_ptr(), pitch.len(), config);
+            assert!(result.success);
+            assert!((result.rate_hz - 5.0).abs() < 0.1, "{result:?}");
+            assert!((result.extent_cents - 40.0).abs() < 3.0, "{result:?}");
+            assert!(result.rate_jitter < 0.05 && result.extent_jitter < 0.05);
+            // The first peak comes a quarter cycle after the vibrato starts
+            assert!((result.onset_delay - 1.05).abs() < 0.03, "{result:?}");
+
+            assert_eq!(result.segment_count, 1);
+            let segment = *result.segments_ptr;
+            assert!((segment.start - 1.05).abs() < 0.03 && segment.end > 2.2, "{segment:?}");
+            assert_eq!(segment.rate_hz, result.rate_hz);
+
+            // Windows end every 10 frames, the last one at the last frame; the
+            // first holds only the straight tone
+            assert_eq!(result.window_count, 17);
+            let times = slice::from_raw_parts(result.window_times_ptr, result.window_count);
+            let rates = slice::from_raw_parts(result.window_rate_ptr, result.window_count);
+            assert!((times[16] - 2.59).abs() < 1e-5);
+            assert!(rates[0].is_nan());
+            assert!((rates[12] - 5.0).abs() < 0.2, "{rates:?}");
+
+            loqa_free_vibrato(&mut result);
+            assert!(result.segments_ptr.is_null() && result.window_times_ptr.is_null());
+            assert_eq!((result.segment_count, result.window_count), (0, 0));
+            loqa_free_vibrato(&mut result);
+
+            // A straight tone has no vibrato, and window_seconds = 0 skips the windows
+            let straight = [220.0f32; 100];
+            let config = VibratoConfigFFI { window_seconds: 0.0, ..config };
+            let mut result = loqa_analyze_vibrato(straight.as_ptr(), straight.len(), config);
+            assert!(result.success && result.vibrato_ratio == 0.0);
+            assert!(result.rate_hz.is_nan() && result.onset_delay.is_nan());
+            assert!(result.segments_ptr.is_null() && result.window_times_ptr.is_null());
+            loqa_free_vibrato(&mut result);
         }
+    }
+
+    #[test]
+    fn test_analyze_vibrato_invalid_input() {
+        let pitch = [220.0f32; 50];
+        let default = loqa_vibrato_config_default();
+        let run = |pitch: &[f32], config: VibratoConfigFFI| unsafe {
+            let result = loqa_analyze_vibrato(pitch.as_ptr(), pitch.len(), config);
+            assert!(!result.success);
+            result.error_code
+        };
 
         unsafe {
-            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+            let result = loqa_analyze_vibrato(std::ptr::null(), 4, default);
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        }
+        assert_eq!(run(&[], default), LoqaErrorCode::InvalidLength);
+        assert_eq!(run(&[220.0, -1.0], default), LoqaErrorCode::InvalidArgument);
+        assert_eq!(run(&[220.0, f32::NAN], default), LoqaErrorCode::InvalidArgument);
+
+        let invalid = [
+            VibratoConfigFFI { frame_rate: 0.0, ..default },
+            VibratoConfigFFI { frame_rate: 20.0, ..default },
+            VibratoConfigFFI { min_rate_hz: 0.0, ..default },
+            VibratoConfigFFI { max_rate_hz: 4.0, ..default },
+            VibratoConfigFFI { min_extent_cents: f32::NAN, ..default },
+            VibratoConfigFFI { max_extent_cents: 10.0, ..default },
+            VibratoConfigFFI { min_cycles: 1, ..default },
+            VibratoConfigFFI { window_seconds: -1.0, ..default },
+            VibratoConfigFFI { window_hop_frames: 0, ..default },
+        ];
+        for config in invalid {
+            assert_eq!(run(&pitch, config), LoqaErrorCode::InvalidArgument, "{config:?}");
+        }
+    }
 
-            // AC2, AC3, AC4: All features should be computed
-            assert!(result.centroid.is_finite(), "Centroid should be finite for white noise");
-            assert!(result.rolloff.is_finite(), "Rolloff should be finite for white noise");
-            assert!(result.tilt.is_finite(), "Tilt should be finite for white noise");
+    #[test]
+    fn test_voice_analyzer_every_algorithm_detects_pitch() {
+        let sample_rate = 16000;
+        let frame = sine_wave(200.0, sample_rate, 2048);
+
+        for algorithm in [
+            LoqaPitchAlgorithm::Auto,
+            LoqaPitchAlgorithm::Pyin,
+            LoqaPitchAlgorithm::Yin,
+            LoqaPitchAlgorithm::Autocorr,
+        ] {
+            unsafe {
+                let analyzer = loqa_voice_analyzer_create(analyzer_config(sample_rate, algorithm));
+                assert!(!analyzer.is_null());
 
-            // For white noise, centroid should be somewhere in mid-range
-            // (not at extremes like 0 or Nyquist frequency)
-            if result.centroid > 0.0 {
-                let nyquist = sample_rate as f32 / 2.0;
+                let result =
+                    loqa_voice_analyzer_process_frame_ex(analyzer, frame.as_ptr(), frame.len());
+                assert!(result.success, "{algorithm:?} should succeed");
+                assert_eq!(result.error_code, LoqaErrorCode::Ok);
                 assert!(
-                    result.centroid < nyquist,
-                    "Centroid {:.1} Hz should be below Nyquist {:.1} Hz",
-                    result.centroid,
-                    nyquist
+                    (result.frequency - 200.0).abs() < 10.0,
+                    "{algorithm:?} detected {} Hz",
+                    result.frequency
                 );
-            }
 
-            // Rolloff should also be reasonable (below Nyquist)
-            if result.rolloff > 0.0 {
-                let nyquist = sample_rate as f32 / 2.0;
-                assert!(
-                    result.rolloff < nyquist,
-                    "Rolloff {:.1} Hz should be below Nyquist {:.1} Hz",
-                    result.rolloff,
-                    nyquist
-                );
+                loqa_voice_analyzer_free_ex(analyzer);
             }
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_pink_noise() {
-        // Generate pink noise (1/f spectrum) - more low frequency energy
-        // Expected characteristics:
-        // - Centroid should be lower than white noise
-        // - Rolloff should be lower than white noise
-        // - Tilt should be negative (more low-frequency energy)
-        let sample_rate = 44100;
-        let num_samples = 2048;
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-
-        // Approximate pink noise by summing sine waves with 1/f amplitude
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            let mut sample = 0.0;
-            // Sum harmonics with decreasing amplitude (1/f)
-            for harmonic in 1..=20 {
-                let freq = 100.0 * harmonic as f32;
-                let amplitude = 1.0 / harmonic as f32;
-                sample += amplitude * (2.0 * PI * freq * t).sin();
-            }
-            buffer.push(sample * 0.1); // Scale down to reasonable amplitude
+    fn test_voice_analyzer_new_rejects_invalid_config() {
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.frame_size = 128;
+        unsafe {
+            assert!(loqa_voice_analyzer_new(config).is_null());
         }
+    }
 
+    #[test]
+    fn test_voice_analyzer_reset_and_free_handle_null() {
         unsafe {
-            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+            loqa_voice_analyzer_reset(std::ptr::null_mut());
+            loqa_voice_analyzer_free(std::ptr::null_mut());
+            loqa_voice_analyzer_reset_ex(std::ptr::null_mut());
+            loqa_voice_analyzer_free_ex(std::ptr::null_mut());
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::Ok);
+        }
+    }
 
-            // AC2, AC3, AC4: All features should be computed
-            assert!(result.centroid.is_finite(), "Centroid should be finite for pink noise");
-            assert!(result.rolloff.is_finite(), "Rolloff should be finite for pink noise");
-            assert!(result.tilt.is_finite(), "Tilt should be finite for pink noise");
+    #[test]
+    fn test_voice_analyzer_process_stream_ex_matches_upstream() {
+        let sample_rate = 16000;
+        let samples = sine_wave(220.0, sample_rate, 8000);
+        let config = || analyzer_config(sample_rate, LoqaPitchAlgorithm::Yin);
 
-            // All frequencies should be in valid range
-            if result.centroid > 0.0 {
-                let nyquist = sample_rate as f32 / 2.0;
-                assert!(
-                    result.centroid < nyquist,
-                    "Centroid should be below Nyquist frequency"
-                );
+        unsafe {
+            let upstream = loqa_voice_analyzer_create(config());
+            let guarded = loqa_voice_analyzer_create(config());
+
+            let mut expected: Vec<PitchResultFFI> = (0..64)
+                .map(|_| PitchResultFFI {
+                    success: false,
+                    frequency: 0.0,
+                    confidence: 0.0,
+                    is_voiced: false,
+                    voiced_probability: 0.0,
+                })
+                .collect();
+            let count = loqa_voice_analyzer_process_stream(
+                upstream,
+                samples.as_ptr(),
+                samples.len(),
+                expected.as_mut_ptr(),
+                expected.len(),
+            );
+            let mut results = vec![PitchResultExFFI::error_result(LoqaErrorCode::Ok); 64];
+            let written = loqa_voice_analyzer_process_stream_ex(
+                guarded,
+                samples.as_ptr(),
+                samples.len(),
+                results.as_mut_ptr(),
+                results.len(),
+            );
+            assert!(written > 0);
+            assert_eq!(written, count);
+            for (result, expected) in results.iter().zip(&expected).take(written) {
+                assert!(result.success);
+                assert_eq!(result.error_code, LoqaErrorCode::Ok);
+                assert_eq!(result.frequency, expected.frequency);
+                assert_eq!(result.voiced_probability, expected.voiced_probability);
             }
 
-            if result.rolloff > 0.0 {
-                let nyquist = sample_rate as f32 / 2.0;
-                assert!(
-                    result.rolloff < nyquist,
-                    "Rolloff should be below Nyquist frequency"
-                );
-            }
+            // Frames beyond max_results are dropped
+            let written = loqa_voice_analyzer_process_stream_ex(
+                guarded,
+                samples.as_ptr(),
+                samples.len(),
+                results.as_mut_ptr(),
+                2,
+            );
+            assert_eq!(written, 2);
+
+            let written = loqa_voice_analyzer_process_stream_ex(
+                std::ptr::null_mut(),
+                samples.as_ptr(),
+                samples.len(),
+                results.as_mut_ptr(),
+                results.len(),
+            );
+            assert_eq!(written, 0);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
+
+            let written = loqa_voice_analyzer_process_stream_ex(
+                guarded,
+                samples.as_ptr(),
+                samples.len(),
+                std::ptr::null_mut(),
+                results.len(),
+            );
+            assert_eq!(written, 0);
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::NullPointer);
 
-            // AC4: Pink noise should typically have negative tilt (more low freq energy)
-            // But this depends on the algorithm's tilt calculation, so we just verify it's finite
+            let result = loqa_voice_analyzer_process_frame_ex(guarded, samples.as_ptr(), 0);
+            assert!(!result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::InvalidLength);
+
+            loqa_voice_analyzer_free(upstream);
+            loqa_voice_analyzer_free_ex(guarded);
         }
     }
 
+    // ======== Streaming Analyzer Tests ========
+
     #[test]
-    fn test_analyze_spectrum_silence() {
-        // Test with silence (all zeros)
-        let buffer: Vec<f32> = vec![0.0; 2048];
-        let sample_rate = 44100;
+    fn test_stream_analyzer_chunks_keep_frame_grid() {
+        let samples = sine_wave(220.0, 16000, 16000);
+        let config = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
 
         unsafe {
-            let result = analyze_spectrum_rust(buffer.as_ptr(), 2048, sample_rate);
+            let analyzer = loqa_stream_analyzer_new(config());
+            assert!(!analyzer.is_null());
+
+            let mut timestamps = Vec::new();
+            let mut frequencies = Vec::new();
+            for chunk in samples.chunks(300) {
+                let mut frames = loqa_stream_analyzer_push(analyzer, chunk.as_ptr(), chunk.len());
+                assert!(frames.success);
+                if frames.frame_count == 0 {
+                    // A push that completes no frame allocates nothing
+                    assert!(frames.timestamps_ptr.is_null());
+                } else {
+                    let count = frames.frame_count;
+                    let pushed = slice::from_raw_parts(frames.timestamps_ptr, count);
+                    timestamps.extend_from_slice(pushed);
+                    let pushed = slice::from_raw_parts(frames.frequencies_ptr, count);
+                    frequencies.extend_from_slice(pushed);
+                }
+                loqa_free_stream_frames(&mut frames);
+                assert!(frames.frequencies_ptr.is_null());
+            }
 
-            // Silence may produce specific values or zeros depending on algorithm
-            // The important thing is it doesn't crash and returns valid (non-NaN) values
-            assert!(result.centroid.is_finite(), "Centroid should be finite for silence");
-            assert!(result.rolloff.is_finite(), "Rolloff should be finite for silence");
-            assert!(result.tilt.is_finite(), "Tilt should be finite for silence");
+            // One frame per hop for every frame whose last sample was pushed
+            assert_eq!(timestamps.len(), (16000 - 2048) / 512 + 1);
+            for (i, &timestamp) in timestamps.iter().enumerate() {
+                assert_eq!(timestamp, (i * 512) as f32 / 16000.0);
+            }
+            assert!(frequencies.iter().all(|f| (f - 220.0).abs() < 5.0), "{frequencies:?}");
+
+            // 1664 samples remain; the frames at the next two hops have their centre in them
+            let mut tail = loqa_stream_analyzer_flush(analyzer);
+            assert!(tail.success);
+            assert_eq!(tail.frame_count, 2);
+            assert_eq!(*tail.timestamps_ptr, (timestamps.len() * 512) as f32 / 16000.0);
+            loqa_free_stream_frames(&mut tail);
+
+            // After a flush the stream restarts at 0
+            let mut frames = loqa_stream_analyzer_push(analyzer, samples.as_ptr(), 2048);
+            assert_eq!(frames.frame_count, 1);
+            assert_eq!(*frames.timestamps_ptr, 0.0);
+            loqa_free_stream_frames(&mut frames);
+
+            // Reset drops the buffered samples, leaving no tail to flush
+            loqa_stream_analyzer_reset(analyzer);
+            let mut frames = loqa_stream_analyzer_push(analyzer, samples.as_ptr(), 1500);
+            assert_eq!(frames.frame_count, 0);
+            loqa_stream_analyzer_reset(analyzer);
+            let mut tail = loqa_stream_analyzer_flush(analyzer);
+            assert!(tail.success);
+            assert_eq!(tail.frame_count, 0);
+            loqa_free_stream_frames(&mut frames);
+            loqa_free_stream_frames(&mut tail);
+
+            loqa_stream_analyzer_free(analyzer);
+        }
+    }
 
-            // All values should be non-negative for silence (no negative frequencies)
-            assert!(result.centroid >= 0.0, "Centroid should be non-negative for silence");
-            assert!(result.rolloff >= 0.0, "Rolloff should be non-negative for silence");
+    #[test]
+    fn test_stream_analyzer_invalid_input() {
+        let mut config = analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        config.hop_size = 4096;
+        assert!(loqa_stream_analyzer_new(config).is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidArgument);
+
+        config = analyzer_config(4000, LoqaPitchAlgorithm::Auto);
+        assert!(loqa_stream_analyzer_new(config).is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
+
+        unsafe {
+            let samples = [0.0f32; 16];
+            let frames = loqa_stream_analyzer_push(std::ptr::null_mut(), samples.as_ptr(), 16);
+            assert_eq!(frames.error_code, LoqaErrorCode::NullPointer);
+            let frames = loqa_stream_analyzer_flush(std::ptr::null_mut());
+            assert_eq!(frames.error_code, LoqaErrorCode::NullPointer);
+
+            let analyzer = loqa_stream_analyzer_new(analyzer_config(16000, LoqaPitchAlgorithm::Auto));
+            let frames = loqa_stream_analyzer_push(analyzer, std::ptr::null(), 16);
+            assert_eq!(frames.error_code, LoqaErrorCode::NullPointer);
+            let frames = loqa_stream_analyzer_push(analyzer, std::ptr::null(), 0);
+            assert!(frames.success);
+            assert_eq!(frames.frame_count, 0);
+            loqa_stream_analyzer_free(analyzer);
+
+            loqa_stream_analyzer_reset(std::ptr::null_mut());
+            loqa_stream_analyzer_free(std::ptr::null_mut());
+            loqa_free_stream_frames(std::ptr::null_mut());
         }
     }
 
+    // ======== Tuner Tests ========
+
     #[test]
-    fn test_analyze_spectrum_multiple_sample_rates() {
-        // Test spectral analysis works across different sample rates
-        for sample_rate in [8000, 16000, 22050, 44100, 48000] {
-            let duration = 0.05; // 50ms
-            let num_samples = (sample_rate as f32 * duration) as usize;
+    fn test_tuner_reads_note_cents_and_stability() {
+        // 296 Hz is 13.7 cents sharp of D4 with A4 = 440 Hz
+        let samples = sine_wave(296.0, 16000, 16000);
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Yin);
 
-            // Generate a simple periodic signal
-            let frequency = 200.0;
-            let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-            for i in 0..num_samples {
-                let t = i as f32 / sample_rate as f32;
-                buffer.push((2.0 * PI * frequency * t).sin());
+        unsafe {
+            let tuner = loqa_tuner_new(analysis(), loqa_tuner_config_default());
+            assert!(!tuner.is_null());
+
+            let mut reading = loqa_tuner_push(tuner, samples.as_ptr(), 1000);
+            assert!(reading.success);
+            assert_eq!(reading.frame_count, 0);
+            assert!(!reading.is_voiced);
+
+            for chunk in samples[1000..].chunks(512) {
+                reading = loqa_tuner_push(tuner, chunk.as_ptr(), chunk.len());
+                assert!(reading.success);
             }
+            assert!(reading.is_voiced);
+            assert_eq!((reading.midi_note, reading.pitch_class, reading.octave), (62, 2, 4));
+            assert!((reading.cents - 13.7).abs() < 1.0, "{reading:?}");
+            assert!((reading.target_frequency - 293.66).abs() < 0.01, "{reading:?}");
+            assert!(reading.stability > 0.8, "{reading:?}");
+
+            // A4 = 452 Hz makes the same tone 32.9 cents flat
+            let config = TunerConfigFFI { reference_hz: 452.0, ..loqa_tuner_config_default() };
+            let sharp_reference = loqa_tuner_new(analysis(), config);
+            let reading = loqa_tuner_push(sharp_reference, samples.as_ptr(), 4096);
+            assert_eq!(reading.midi_note, 62);
+            assert!((reading.cents + 32.9).abs() < 1.0, "{reading:?}");
+            loqa_tuner_free(sharp_reference);
+
+            loqa_tuner_reset(tuner);
+            let reading = loqa_tuner_push(tuner, std::ptr::null(), 0);
+            assert!(reading.success);
+            assert!(!reading.is_voiced);
+            assert_eq!(reading.stability, 0.0);
+            loqa_tuner_free(tuner);
+        }
+    }
 
-            unsafe {
-                let result = analyze_spectrum_rust(
-                    buffer.as_ptr(),
-                    num_samples as c_int,
-                    sample_rate as c_int,
-                );
+    #[test]
+    fn test_tuner_invalid_input() {
+        let analysis = || analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+        let default = loqa_tuner_config_default();
+
+        let mut temperament = default.temperament_cents;
+        temperament[4] = -60.0;
+        let invalid = [
+            TunerConfigFFI { reference_hz: 0.0, ..default },
+            TunerConfigFFI { reference_hz: f32::NAN, ..default },
+            TunerConfigFFI { temperament_cents: temperament, ..default },
+            TunerConfigFFI { stability_frames: 0, ..default },
+        ];
+        for config in invalid {
+            assert!(loqa_tuner_new(analysis(), config).is_null(), "{config:?}");
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidArgument);
+        }
 
-                // AC1: All sample rates in 8000-48000 Hz should work
-                assert!(
-                    result.centroid.is_finite() && result.rolloff.is_finite() && result.tilt.is_finite(),
-                    "Sample rate {} Hz should work (centroid={:.1}, rolloff={:.1}, tilt={:.3})",
-                    sample_rate,
-                    result.centroid,
-                    result.rolloff,
-                    result.tilt
-                );
+        let low_rate = analyzer_config(4000, LoqaPitchAlgorithm::Auto);
+        assert!(loqa_tuner_new(low_rate, default).is_null());
+        assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidSampleRate);
 
-                // Verify values are in physically reasonable range
-                if result.centroid > 0.0 {
-                    let nyquist = sample_rate as f32 / 2.0;
-                    assert!(
-                        result.centroid <= nyquist,
-                        "Centroid {:.1} Hz should not exceed Nyquist {:.1} Hz at sample rate {}",
-                        result.centroid,
-                        nyquist,
-                        sample_rate
-                    );
-                }
+        unsafe {
+            let samples = [0.0f32; 16];
+            let reading = loqa_tuner_push(std::ptr::null_mut(), samples.as_ptr(), 16);
+            assert_eq!(reading.error_code, LoqaErrorCode::NullPointer);
 
-                if result.rolloff > 0.0 {
-                    let nyquist = sample_rate as f32 / 2.0;
-                    assert!(
-                        result.rolloff <= nyquist,
-                        "Rolloff {:.1} Hz should not exceed Nyquist {:.1} Hz at sample rate {}",
-                        result.rolloff,
-                        nyquist,
-                        sample_rate
-                    );
-                }
-            }
+            let tuner = loqa_tuner_new(analysis(), default);
+            let reading = loqa_tuner_push(tuner, std::ptr::null(), 16);
+            assert_eq!(reading.error_code, LoqaErrorCode::NullPointer);
+            loqa_tuner_free(tuner);
+
+            loqa_tuner_reset(std::ptr::null_mut());
+            loqa_tuner_free(std::ptr::null_mut());
         }
     }
 
     #[test]
-    fn test_analyze_spectrum_result_struct_layout() {
-        // Verify SpectrumResult struct is properly laid out for FFI
-        let test_result = SpectrumResult {
-            centroid: 2000.0,
-            rolloff: 4000.0,
-            tilt: -0.5,
+    fn test_vrp_accumulates_merges_and_exports_the_grid() {
+        let config = VrpConfigFFI {
+            min_midi_note: 60,
+            max_midi_note: 71,
+            min_db: 50.0,
+            max_db: 80.0,
+            db_step: 5.0,
+            min_cell_frames: 2,
+            ..loqa_vrp_config_default()
         };
+        // A3 (off the grid), C4 at 55-60 dB, A4 at 60-65 and 70-75 dB
+        let pitch = [220.0f32, 261.63, 261.63, 440.0, 440.0, 440.0, 440.0, 0.0];
+        let level = [60.0f32, 56.0, 58.0, 61.0, 62.0, 72.0, 73.0, 90.0];
 
-        assert_eq!(test_result.centroid, 2000.0);
-        assert_eq!(test_result.rolloff, 4000.0);
-        assert_eq!(test_result.tilt, -0.5);
+        unsafe {
+            let profile = loqa_vrp_new(config);
+            let other = loqa_vrp_new(config);
+            assert!(!profile.is_null());
+            let code = loqa_vrp_add_frames(profile, pitch.as_ptr(), level.as_ptr(), 3);
+            assert_eq!(code, LoqaErrorCode::Ok);
+            let code = loqa_vrp_add_frames(other, pitch[3..].as_ptr(), level[3..].as_ptr(), 5);
+            assert_eq!(code, LoqaErrorCode::Ok);
+            assert_eq!(loqa_vrp_merge(profile, other), LoqaErrorCode::Ok);
+
+            let mut result = loqa_vrp_get(profile);
+            assert!(result.success);
+            assert_eq!((result.row_count, result.column_count), (12, 6));
+            assert_eq!((result.lowest_midi_note, result.highest_midi_note), (60, 69));
+            assert!((result.lowest_hz - 261.63).abs() < 0.01);
+            assert_eq!(result.highest_hz, 440.0);
+            assert_eq!((result.covered_cells, result.area, result.frame_count), (3, 15.0, 6));
+
+            let counts = slice::from_raw_parts(result.counts_ptr, 72);
+            assert_eq!(counts[1], 2);
+            assert_eq!((counts[9 * 6 + 2], counts[9 * 6 + 4]), (2, 2));
+            let soft = slice::from_raw_parts(result.soft_db_ptr, 12);
+            let loud = slice::from_raw_parts(result.loud_db_ptr, 12);
+            let range = slice::from_raw_parts(result.dynamic_range_db_ptr, 12);
+            assert_eq!((soft[9], loud[9], range[9]), (60.0, 75.0, 15.0));
+            assert_eq!(range[0], 5.0);
+            assert!(soft[1].is_nan() && range[1].is_nan());
+
+            loqa_free_vrp(&mut result);
+            assert!(result.counts_ptr.is_null());
+            loqa_free_vrp(&mut result);
+
+            loqa_vrp_reset(profile);
+            let mut result = loqa_vrp_get(profile);
+            assert_eq!((result.lowest_midi_note, result.frame_count), (-1, 0));
+            assert!(result.lowest_hz.is_nan());
+            loqa_free_vrp(&mut result);
+
+            loqa_vrp_free(profile);
+            loqa_vrp_free(other);
+        }
+    }
 
-        // Verify struct is Copy (required for FFI)
-        let copied = test_result;
-        assert_eq!(copied.centroid, 2000.0);
-        assert_eq!(test_result.centroid, 2000.0); // Original still valid
+    #[test]
+    fn test_vrp_invalid_input() {
+        let default = loqa_vrp_config_default();
+        let invalid = [
+            VrpConfigFFI { reference_hz: 0.0, ..default },
+            VrpConfigFFI { min_midi_note: 70, max_midi_note: 60, ..default },
+            VrpConfigFFI { max_midi_note: 128, ..default },
+            VrpConfigFFI { min_db: 120.0, max_db: 40.0, ..default },
+            VrpConfigFFI { min_db: f32::NEG_INFINITY, ..default },
+            VrpConfigFFI { db_step: 0.0, ..default },
+            VrpConfigFFI { db_step: 0.05, ..default },
+            VrpConfigFFI { min_cell_frames: 0, ..default },
+        ];
+        for config in invalid {
+            assert!(loqa_vrp_new(config).is_null(), "{config:?}");
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::InvalidArgument);
+        }
+
+        unsafe {
+            let profile = loqa_vrp_new(default);
+            let pitch = [440.0f32, -1.0];
+            let level = [70.0f32, f32::NAN];
+            let code = loqa_vrp_add_frames(profile, pitch.as_ptr(), [70.0f32; 2].as_ptr(), 2);
+            assert_eq!(code, LoqaErrorCode::InvalidArgument);
+            let code = loqa_vrp_add_frames(profile, [440.0f32; 2].as_ptr(), level.as_ptr(), 2);
+            assert_eq!(code, LoqaErrorCode::InvalidArgument);
+            let code = loqa_vrp_add_frames(profile, std::ptr::null(), level.as_ptr(), 2);
+            assert_eq!(code, LoqaErrorCode::NullPointer);
+            let code = loqa_vrp_add_frames(std::ptr::null_mut(), pitch.as_ptr(), level.as_ptr(), 0);
+            assert_eq!(code, LoqaErrorCode::NullPointer);
+            assert_eq!(
+                loqa_vrp_add_frames(profile, std::ptr::null(), std::ptr::null(), 0),
+                LoqaErrorCode::Ok
+            );
+
+            // Grids must match to merge
+            let finer = loqa_vrp_new(VrpConfigFFI { db_step: 0.5, ..default });
+            assert_eq!(loqa_vrp_merge(profile, finer), LoqaErrorCode::InvalidArgument);
+            assert_eq!(loqa_vrp_merge(profile, std::ptr::null()), LoqaErrorCode::NullPointer);
+            assert_eq!(loqa_vrp_merge(profile, profile), LoqaErrorCode::Ok);
+
+            let result = loqa_vrp_get(std::ptr::null());
+            assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+
+            loqa_vrp_free(finer);
+            loqa_vrp_free(profile);
+            loqa_vrp_reset(std::ptr::null_mut());
+            loqa_vrp_free(std::ptr::null_mut());
+            loqa_free_vrp(std::ptr::null_mut());
+        }
     }
 
+    // ======== Panic Safety Tests ========
+
     #[test]
-    fn test_analyze_spectrum_all_features_single_call() {
-        // AC5: Verify all three spectral features are computed in a single function call
-        let sample_rate = 44100;
-        let num_samples = 2048;
+    fn test_every_export_converts_panics_to_errors() {
+        use crate::error::test_hooks::panic_in;
 
-        // Generate a complex signal with multiple frequency components
-        let mut buffer: Vec<f32> = Vec::with_capacity(num_samples);
-        for i in 0..num_samples {
-            let t = i as f32 / sample_rate as f32;
-            // Mix of low, mid, and high frequencies
-            buffer.push(
-                0.5 * (2.0 * PI * 200.0 * t).sin() +  // Low
-                0.3 * (2.0 * PI * 1000.0 * t).sin() +  // Mid
-                0.2 * (2.0 * PI * 4000.0 * t).sin()    // High
+        let samples = sine_wave(220.0, 16000, 4096);
+        let (ptr, len) = (samples.as_ptr(), samples.len() as c_int);
+
+        // Asserts the export reported the injected panic through the last error
+        fn assert_panicked(export: &str) {
+            assert_eq!(loqa_last_error_code(), LoqaErrorCode::Panic, "{export}");
+            let message = unsafe { std::ffi::CStr::from_ptr(loqa_last_error_message()) };
+            assert_eq!(
+                message.to_str().unwrap(),
+                format!("{export} panicked: injected test panic")
             );
         }
 
         unsafe {
-            let result = analyze_spectrum_rust(buffer.as_ptr(), num_samples as c_int, sample_rate);
+            panic_in("compute_fft_rust");
+            assert!(compute_fft_rust(ptr, len, 16000, 1024).is_null());
+            assert_panicked("compute_fft_rust");
 
-            // AC5: All three features should be computed and returned
-            // Verify all are valid (finite, non-NaN)
-            assert!(result.centroid.is_finite(), "Centroid should be computed");
-            assert!(result.rolloff.is_finite(), "Rolloff should be computed");
-            assert!(result.tilt.is_finite(), "Tilt should be computed");
+            panic_in("free_fft_result_rust");
+            free_fft_result_rust(std::ptr::null_mut(), 0);
+            assert_panicked("free_fft_result_rust");
 
-            // For this mixed signal, all three values should be meaningful (non-zero if algorithm works)
-            // But we don't enforce non-zero as that depends on the algorithm implementation
+            panic_in("detect_pitch_rust");
+            let result = detect_pitch_rust(ptr, len, 16000);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_eq!(result.frequency, 0.0);
+            assert_panicked("detect_pitch_rust");
+
+            panic_in("loqa_detect_pitch_ex");
+            let result = loqa_detect_pitch_ex(ptr, samples.len(), 16000, 80.0, 400.0, 0);
+            assert!(!result.success);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_detect_pitch_ex");
+
+            panic_in("loqa_detect_pitch_range");
+            let result = loqa_detect_pitch_range(ptr, samples.len(), 16000, 80.0, 400.0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_detect_pitch_range");
+
+            panic_in("extract_formants_rust");
+            assert_eq!(extract_formants_rust(ptr, len, 16000, 0).error_code, LoqaErrorCode::Panic);
+            assert_panicked("extract_formants_rust");
+
+            panic_in("loqa_extract_formants_ex");
+            let result = loqa_extract_formants_ex(ptr, samples.len(), 16000, 0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_extract_formants_ex");
+
+            panic_in("loqa_calculate_hnr_ex");
+            let result = loqa_calculate_hnr_ex(ptr, samples.len(), 16000, 75.0, 500.0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_calculate_hnr_ex");
+
+            panic_in("loqa_calculate_h1h2_ex");
+            let result = loqa_calculate_h1h2_ex(ptr, samples.len(), 16000, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_calculate_h1h2_ex");
+
+            panic_in("analyze_spectrum_rust");
+            assert_eq!(analyze_spectrum_rust(ptr, len, 16000).error_code, LoqaErrorCode::Panic);
+            assert_panicked("analyze_spectrum_rust");
+
+            panic_in("loqa_analyze_spectrum_ex");
+            let config = loqa_spectrum_config_default();
+            let result = loqa_analyze_spectrum_ex(ptr, samples.len(), 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_analyze_spectrum_ex");
+
+            panic_in("loqa_compute_fft_ex");
+            let mut result = loqa_compute_fft_ex(ptr, samples.len(), 16000, loqa_fft_config_default());
+            assert!(result.magnitudes_ptr.is_null());
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_fft_ex");
+
+            panic_in("loqa_free_fft_result_ex");
+            loqa_free_fft_result_ex(&mut result);
+            assert_panicked("loqa_free_fft_result_ex");
+
+            panic_in("loqa_compute_fft_complex");
+            let mut spectrum = loqa_compute_fft_complex(ptr, samples.len(), 0);
+            assert_eq!(spectrum.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_fft_complex");
+
+            panic_in("loqa_free_complex_spectrum");
+            loqa_free_complex_spectrum(&mut spectrum);
+            assert_panicked("loqa_free_complex_spectrum");
+
+            panic_in("loqa_compute_ifft");
+            let mut signal = loqa_compute_ifft(ptr, 513);
+            assert_eq!(signal.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_ifft");
+
+            panic_in("loqa_free_ifft_result");
+            loqa_free_ifft_result(&mut signal);
+            assert_panicked("loqa_free_ifft_result");
+
+            panic_in("loqa_compute_spectrogram");
+            let config = loqa_spectrogram_config_default();
+            let mut spectrogram = loqa_compute_spectrogram(ptr, samples.len(), 16000, config);
+            assert_eq!(spectrogram.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_spectrogram");
+
+            panic_in("loqa_free_spectrogram");
+            loqa_free_spectrogram(&mut spectrogram);
+            assert_panicked("loqa_free_spectrogram");
+
+            panic_in("loqa_compute_log_mel_spectrogram");
+            let config = loqa_mel_config_default();
+            let result = loqa_compute_log_mel_spectrogram(ptr, samples.len(), 16000, config);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_log_mel_spectrogram");
+
+            panic_in("loqa_compute_mfcc");
+            let mut features = loqa_compute_mfcc(ptr, samples.len(), 16000, config);
+            assert_eq!(features.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_mfcc");
+
+            panic_in("loqa_free_feature_matrix");
+            loqa_free_feature_matrix(&mut features);
+            assert_panicked("loqa_free_feature_matrix");
+
+            let analysis_config = || analyzer_config(16000, LoqaPitchAlgorithm::Pyin);
+            panic_in("loqa_calculate_jitter");
+            let result = loqa_calculate_jitter(ptr, samples.len(), analysis_config());
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_calculate_jitter");
+
+            panic_in("loqa_calculate_shimmer");
+            let result = loqa_calculate_shimmer(ptr, samples.len(), analysis_config());
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_calculate_shimmer");
+
+            panic_in("loqa_calculate_cpp");
+            let result = loqa_calculate_cpp(ptr, samples.len(), 16000, loqa_cpp_config_default());
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_calculate_cpp");
+
+            panic_in("loqa_compute_intensity");
+            let config = loqa_intensity_config_default();
+            let mut contour = loqa_compute_intensity(ptr, samples.len(), 16000, config);
+            assert_eq!(contour.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_compute_intensity");
+
+            panic_in("loqa_free_intensity_contour");
+            loqa_free_intensity_contour(&mut contour);
+            assert_panicked("loqa_free_intensity_contour");
+
+            panic_in("loqa_detect_voice_activity");
+            let config = loqa_vad_config_default();
+            let mut activity =
+                loqa_detect_voice_activity(ptr, samples.len(), analysis_config(), config);
+            assert_eq!(activity.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_detect_voice_activity");
+
+            panic_in("loqa_free_voice_activity");
+            loqa_free_voice_activity(&mut activity);
+            assert_panicked("loqa_free_voice_activity");
+
+            panic_in("loqa_estimate_vocal_tract_length");
+            let config = loqa_vtl_config_default();
+            let result =
+                loqa_estimate_vocal_tract_length(ptr, samples.len(), analysis_config(), config);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_estimate_vocal_tract_length");
+
+            panic_in("calculate_hnr_rust");
+            let result = calculate_hnr_rust(ptr, len, 16000, 75.0, 500.0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("calculate_hnr_rust");
+
+            panic_in("calculate_h1h2_rust");
+            let result = calculate_h1h2_rust(ptr, len, 16000, 0.0);
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("calculate_h1h2_rust");
+
+            let config = || analyzer_config(16000, LoqaPitchAlgorithm::Auto);
+            panic_in("loqa_analysis_config_validate");
+            assert_eq!(loqa_analysis_config_validate(config()), LoqaErrorCode::Panic);
+            assert_panicked("loqa_analysis_config_validate");
+
+            panic_in("loqa_voice_analyzer_create");
+            assert!(loqa_voice_analyzer_create(config()).is_null());
+            assert_panicked("loqa_voice_analyzer_create");
+
+            let analyzer = loqa_voice_analyzer_create(config());
+            panic_in("loqa_voice_analyzer_process_buffer");
+            let mut track = loqa_voice_analyzer_process_buffer(analyzer, ptr, samples.len());
+            assert!(!track.success);
+            assert!(track.pitch_track_ptr.is_null());
+            assert_eq!(track.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_voice_analyzer_process_buffer");
+
+            panic_in("loqa_free_pitch_track");
+            loqa_free_pitch_track(&mut track);
+            assert_panicked("loqa_free_pitch_track");
+
+            panic_in("loqa_voice_analyzer_process_frame_ex");
+            let result = loqa_voice_analyzer_process_frame_ex(analyzer, ptr, samples.len());
+            assert_eq!(result.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_voice_analyzer_process_frame_ex");
+
+            panic_in("loqa_voice_analyzer_process_stream_ex");
+            let mut results = [PitchResultExFFI::error_result(LoqaErrorCode::Ok); 4];
+            let written = loqa_voice_analyzer_process_stream_ex(
+                analyzer,
+                ptr,
+                samples.len(),
+                results.as_mut_ptr(),
+                results.len(),
+            );
+            assert_eq!(written, 0);
+            assert_panicked("loqa_voice_analyzer_process_stream_ex");
+
+            panic_in("loqa_voice_analyzer_reset_ex");
+            loqa_voice_analyzer_reset_ex(analyzer);
+            assert_panicked("loqa_voice_analyzer_reset_ex");
+
+            panic_in("loqa_voice_analyzer_free_ex");
+            loqa_voice_analyzer_free_ex(std::ptr::null_mut());
+            assert_panicked("loqa_voice_analyzer_free_ex");
+            loqa_voice_analyzer_free_ex(analyzer);
+
+            panic_in("loqa_postprocess_pitch_track");
+            let postprocess = loqa_pitch_postprocess_config_default();
+            let track = loqa_postprocess_pitch_track(ptr, ptr, ptr, samples.len(), postprocess);
+            assert_eq!(track.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_postprocess_pitch_track");
+
+            panic_in("loqa_pitch_track_statistics");
+            let stats_config = loqa_pitch_stats_config_default();
+            let stats = loqa_pitch_track_statistics(ptr, ptr, samples.len(), stats_config);
+            assert_eq!(stats.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_pitch_track_statistics");
+
+            panic_in("loqa_analyze_vibrato");
+            let mut vibrato =
+                loqa_analyze_vibrato(ptr, samples.len(), loqa_vibrato_config_default());
+            assert_eq!(vibrato.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_analyze_vibrato");
+
+            panic_in("loqa_free_vibrato");
+            loqa_free_vibrato(&mut vibrato);
+            assert_panicked("loqa_free_vibrato");
+
+            let tracker_config = loqa_formant_tracker_config_default();
+            panic_in("loqa_formant_tracker_new");
+            assert!(loqa_formant_tracker_new(tracker_config).is_null());
+            assert_panicked("loqa_formant_tracker_new");
+
+            let tracker = loqa_formant_tracker_new(tracker_config);
+            panic_in("loqa_formant_tracker_process_buffer");
+            let mut formant_track =
+                loqa_formant_tracker_process_buffer(tracker, ptr, samples.len());
+            assert!(formant_track.frequencies_ptr.is_null());
+            assert_eq!(formant_track.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_formant_tracker_process_buffer");
+
+            panic_in("loqa_free_formant_track");
+            loqa_free_formant_track(&mut formant_track);
+            assert_panicked("loqa_free_formant_track");
+
+            panic_in("loqa_formant_tracker_reset");
+            loqa_formant_tracker_reset(tracker);
+            assert_panicked("loqa_formant_tracker_reset");
+
+            panic_in("loqa_formant_tracker_free");
+            loqa_formant_tracker_free(tracker);
+            assert_panicked("loqa_formant_tracker_free");
+            loqa_formant_tracker_free(tracker);
+
+            panic_in("loqa_stream_analyzer_new");
+            assert!(loqa_stream_analyzer_new(config()).is_null());
+            assert_panicked("loqa_stream_analyzer_new");
+
+            let analyzer = loqa_stream_analyzer_new(config());
+            panic_in("loqa_stream_analyzer_push");
+            let mut frames = loqa_stream_analyzer_push(analyzer, ptr, samples.len());
+            assert!(frames.frequencies_ptr.is_null());
+            assert_eq!(frames.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_stream_analyzer_push");
+
+            panic_in("loqa_stream_analyzer_flush");
+            assert_eq!(loqa_stream_analyzer_flush(analyzer).error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_stream_analyzer_flush");
+
+            panic_in("loqa_free_stream_frames");
+            loqa_free_stream_frames(&mut frames);
+            assert_panicked("loqa_free_stream_frames");
+
+            panic_in("loqa_stream_analyzer_reset");
+            loqa_stream_analyzer_reset(analyzer);
+            assert_panicked("loqa_stream_analyzer_reset");
+
+            panic_in("loqa_stream_analyzer_free");
+            loqa_stream_analyzer_free(analyzer);
+            assert_panicked("loqa_stream_analyzer_free");
+            loqa_stream_analyzer_free(analyzer);
+
+            let tuner_config = loqa_tuner_config_default();
+            panic_in("loqa_tuner_new");
+            assert!(loqa_tuner_new(config(), tuner_config).is_null());
+            assert_panicked("loqa_tuner_new");
+
+            let tuner = loqa_tuner_new(config(), tuner_config);
+            panic_in("loqa_tuner_push");
+            let reading = loqa_tuner_push(tuner, ptr, samples.len());
+            assert!(!reading.success);
+            assert_eq!(reading.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_tuner_push");
+
+            panic_in("loqa_tuner_reset");
+            loqa_tuner_reset(tuner);
+            assert_panicked("loqa_tuner_reset");
+
+            panic_in("loqa_tuner_free");
+            loqa_tuner_free(tuner);
+            assert_panicked("loqa_tuner_free");
+            loqa_tuner_free(tuner);
+
+            let vrp_config = loqa_vrp_config_default();
+            panic_in("loqa_vrp_new");
+            assert!(loqa_vrp_new(vrp_config).is_null());
+            assert_panicked("loqa_vrp_new");
+
+            let profile = loqa_vrp_new(vrp_config);
+            panic_in("loqa_vrp_add_frames");
+            let code = loqa_vrp_add_frames(profile, ptr, ptr, samples.len());
+            assert_eq!(code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_vrp_add_frames");
+
+            panic_in("loqa_vrp_merge");
+            assert_eq!(loqa_vrp_merge(profile, profile), LoqaErrorCode::Panic);
+            assert_panicked("loqa_vrp_merge");
+
+            panic_in("loqa_vrp_get");
+            let mut vrp = loqa_vrp_get(profile);
+            assert!(!vrp.success);
+            assert_eq!(vrp.error_code, LoqaErrorCode::Panic);
+            assert_panicked("loqa_vrp_get");
+
+            let mut vrp_result = loqa_vrp_get(profile);
+            panic_in("loqa_free_vrp");
+            loqa_free_vrp(&mut vrp_result);
+            assert_panicked("loqa_free_vrp");
+            loqa_free_vrp(&mut vrp_result);
+            loqa_free_vrp(&mut vrp);
+
+            panic_in("loqa_vrp_reset");
+            loqa_vrp_reset(profile);
+            assert_panicked("loqa_vrp_reset");
+
+            panic_in("loqa_vrp_free");
+            loqa_vrp_free(profile);
+            assert_panicked("loqa_vrp_free");
+            loqa_vrp_free(profile);
+
+            panic_in("test_ffi_bridge");
+            assert_eq!(test_ffi_bridge(), 0);
+            assert_panicked("test_ffi_bridge");
+        }
+
+        // The hook is one-shot: the next call runs normally
+        let result = unsafe { detect_pitch_rust(ptr, len, 16000) };
+        assert_eq!(result.error_code, LoqaErrorCode::Ok);
+    }
+
+    #[test]
+    fn test_detect_pitch_checked_validates_frequency_range() {
+        let samples = vec![0.1_f32; 2048];
+
+        for (min, max) in [(0.0, 400.0), (400.0, 80.0), (80.0, 9000.0)] {
+            let (code, _) = detect_pitch_checked(&samples, 16000, min, max).unwrap_err();
+            assert_eq!(code, LoqaErrorCode::InvalidFrequencyRange, "[{min}, {max}]");
         }
+        let (code, _) = detect_pitch_checked(&[], 16000, 80.0, 400.0).unwrap_err();
+        assert_eq!(code, LoqaErrorCode::InvalidLength);
     }
 }
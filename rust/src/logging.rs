@@ -0,0 +1,341 @@
+// Diagnostics routing for the FFI boundary
+//
+// Rust-side messages go through `log()` or the `loqa_log!` macro:
+//   Error - every error recorded via `set_last_error`
+//   Warn  - parameters the library clamped or ignored (e.g. an auto-selected
+//           LPC order outside [8, 24], a buffer truncated to fft_size)
+//   Info  - parameters it derived or adjusted as documented (e.g. the LPC
+//           order capped after downsampling)
+//   Debug - FFT sizes and frame counts of whole-buffer analyses
+//   Trace - per-push frame counts of the streaming analyzers
+// Nothing is emitted until the host installs a callback with
+// `loqa_set_log_callback`; Swift forwards messages to os_log and Kotlin to
+// Logcat. Without a callback, and for messages above `loqa_set_log_level`,
+// `loqa_log!` does not even format its arguments.
+
+use crate::error::{ffi_guard, set_last_error, LoqaErrorCode};
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::RwLock;
+
+/// Severity of a diagnostic message
+///
+/// Values are part of the C ABI. A level also acts as a filter threshold: a
+/// message is delivered when its level is <= the configured level, and
+/// `Off` suppresses everything.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LoqaLogLevel {
+    /// Filter value only: deliver nothing
+    Off = 0,
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl LoqaLogLevel {
+    /// Converts a raw C value, returning `None` for out-of-range values
+    pub fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(LoqaLogLevel::Off),
+            1 => Some(LoqaLogLevel::Error),
+            2 => Some(LoqaLogLevel::Warn),
+            3 => Some(LoqaLogLevel::Info),
+            4 => Some(LoqaLogLevel::Debug),
+            5 => Some(LoqaLogLevel::Trace),
+            _ => None,
+        }
+    }
+}
+
+/// Host callback receiving `(level, message)`
+///
+/// `message` is a NUL-terminated UTF-8 string valid only for the duration of
+/// the call. The callback may be invoked from any thread that calls into the
+/// library and must not unwind.
+pub type LoqaLogCallback = Option<extern "C" fn(level: LoqaLogLevel, message: *const c_char)>;
+
+/// Messages more verbose than this are dropped (stored as `LoqaLogLevel as u32`)
+static MAX_LEVEL: AtomicU32 = AtomicU32::new(LoqaLogLevel::Warn as u32);
+
+static CALLBACK: RwLock<LoqaLogCallback> = RwLock::new(None);
+
+/// Mirrors `CALLBACK.is_some()` so `enabled` does not take the lock
+static HAS_CALLBACK: AtomicBool = AtomicBool::new(false);
+
+/// Returns true if a message at `level` would currently be delivered
+pub(crate) fn enabled(level: LoqaLogLevel) -> bool {
+    level != LoqaLogLevel::Off
+        && level as u32 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && HAS_CALLBACK.load(Ordering::Relaxed)
+}
+
+/// Delivers `message` to the host callback; dropped if none is installed
+pub(crate) fn log(level: LoqaLogLevel, message: impl AsRef<str>) {
+    if !enabled(level) {
+        return;
+    }
+
+    // Copy the callback out so it never runs while the lock is held; a callback
+    // that calls loqa_set_log_callback would otherwise deadlock
+    let callback = *CALLBACK
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(callback) = callback {
+        // Interior NUL bytes cannot be represented in a C string; strip them
+        let message = CString::new(message.as_ref().replace('\0', "")).unwrap_or_default();
+        callback(level, message.as_ptr());
+    }
+}
+
+/// Logs a `format!` message at a `LoqaLogLevel` variant, e.g.
+/// `loqa_log!(Debug, "{frames} frames")`, formatting only if it is delivered
+macro_rules! loqa_log {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::logging::enabled($crate::logging::LoqaLogLevel::$level) {
+            $crate::logging::log($crate::logging::LoqaLogLevel::$level, format!($($arg)+));
+        }
+    };
+}
+pub(crate) use loqa_log;
+
+/// Installs (or with null, removes) the callback that receives log messages
+///
+/// # Arguments
+/// * `callback` - Function receiving `(level, message)`, or null to stop
+///   logging (the default)
+///
+/// # Note
+/// The callback is process-wide and may be called concurrently from several
+/// threads. The message pointer is only valid during the callback; copy it.
+#[no_mangle]
+pub extern "C" fn loqa_set_log_callback(callback: LoqaLogCallback) {
+    ffi_guard("loqa_set_log_callback", || {
+        let mut installed = CALLBACK
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *installed = callback;
+        HAS_CALLBACK.store(callback.is_some(), Ordering::Relaxed);
+    })
+}
+
+/// Sets the most verbose level that is delivered (default: `LOQA_LOG_WARN`)
+///
+/// # Arguments
+/// * `level` - A `LoqaLogLevel` value; `LOQA_LOG_OFF` disables logging
+///
+/// # Returns
+/// * `LoqaErrorCode::Ok`, or `InvalidArgument` if `level` is out of range
+///   (the current level is then left unchanged)
+#[no_mangle]
+pub extern "C" fn loqa_set_log_level(level: u32) -> LoqaErrorCode {
+    ffi_guard("loqa_set_log_level", || {
+        match LoqaLogLevel::from_raw(level) {
+            Some(level) => {
+                MAX_LEVEL.store(level as u32, Ordering::Relaxed);
+                LoqaErrorCode::Ok
+            }
+            None => {
+                set_last_error(
+                    LoqaErrorCode::InvalidArgument,
+                    format!("log level must be in range [0, 5], got {level}"),
+                );
+                LoqaErrorCode::InvalidArgument
+            }
+        }
+    })
+}
+
+/// Returns the level set by `loqa_set_log_level` as a `LoqaLogLevel` value
+#[no_mangle]
+pub extern "C" fn loqa_log_level() -> u32 {
+    ffi_guard("loqa_log_level", || MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::sync::{Mutex, MutexGuard};
+    use std::thread::{self, ThreadId};
+
+    /// Serializes tests that touch the process-wide callback and level
+    static LOGGING_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Messages received by `capture`, tagged with the logging thread so that
+    /// errors logged by unrelated tests running in parallel can be ignored
+    static CAPTURED: Mutex<Vec<(ThreadId, LoqaLogLevel, String)>> = Mutex::new(Vec::new());
+
+    extern "C" fn capture(level: LoqaLogLevel, message: *const c_char) {
+        let message = unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned();
+        CAPTURED
+            .lock()
+            .unwrap()
+            .push((thread::current().id(), level, message));
+    }
+
+    /// Installs the capturing callback at `level` and returns the lock guard
+    fn start_capture(level: LoqaLogLevel) -> MutexGuard<'static, ()> {
+        let guard = LOGGING_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        CAPTURED.lock().unwrap().clear();
+        loqa_set_log_callback(Some(capture));
+        assert_eq!(loqa_set_log_level(level as u32), LoqaErrorCode::Ok);
+        guard
+    }
+
+    /// Restores the defaults and returns what this thread logged since `start_capture`
+    fn finish_capture() -> Vec<(LoqaLogLevel, String)> {
+        loqa_set_log_callback(None);
+        loqa_set_log_level(LoqaLogLevel::Warn as u32);
+        let current = thread::current().id();
+        std::mem::take(&mut *CAPTURED.lock().unwrap())
+            .into_iter()
+            .filter(|(thread, _, _)| *thread == current)
+            .map(|(_, level, message)| (level, message))
+            .collect()
+    }
+
+    #[test]
+    fn test_callback_receives_messages_at_or_below_level() {
+        let _guard = start_capture(LoqaLogLevel::Info);
+        log(LoqaLogLevel::Error, "error message");
+        log(LoqaLogLevel::Info, "info message");
+        log(LoqaLogLevel::Debug, "debug message");
+        let captured = finish_capture();
+
+        assert_eq!(
+            captured,
+            vec![
+                (LoqaLogLevel::Error, "error message".to_string()),
+                (LoqaLogLevel::Info, "info message".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_level_off_suppresses_everything() {
+        let _guard = start_capture(LoqaLogLevel::Off);
+        log(LoqaLogLevel::Error, "dropped");
+        assert!(!enabled(LoqaLogLevel::Error));
+        assert!(finish_capture().is_empty());
+    }
+
+    #[test]
+    fn test_set_log_level_rejects_out_of_range() {
+        let _guard = LOGGING_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        assert_eq!(
+            loqa_set_log_level(LoqaLogLevel::Debug as u32),
+            LoqaErrorCode::Ok
+        );
+        assert_eq!(loqa_log_level(), LoqaLogLevel::Debug as u32);
+
+        assert_eq!(loqa_set_log_level(6), LoqaErrorCode::InvalidArgument);
+        assert_eq!(loqa_log_level(), LoqaLogLevel::Debug as u32);
+
+        loqa_set_log_level(LoqaLogLevel::Warn as u32);
+    }
+
+    #[test]
+    fn test_ffi_errors_are_routed_to_callback() {
+        let _guard = start_capture(LoqaLogLevel::Error);
+        let result = unsafe { crate::detect_pitch_rust(std::ptr::null(), 0, 16000) };
+        let captured = finish_capture();
+
+        assert_eq!(result.error_code, LoqaErrorCode::NullPointer);
+        assert_eq!(
+            captured,
+            vec![(LoqaLogLevel::Error, "buffer pointer is null".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_nothing_is_formatted_without_a_callback() {
+        let _guard = LOGGING_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        loqa_set_log_level(LoqaLogLevel::Trace as u32);
+        let mut formatted = false;
+        let mut argument = || {
+            formatted = true;
+            "unused"
+        };
+        loqa_log!(Error, "{}", argument());
+        loqa_set_log_level(LoqaLogLevel::Warn as u32);
+
+        assert!(!enabled(LoqaLogLevel::Error));
+        assert!(!formatted);
+    }
+
+    #[test]
+    fn test_clamped_default_lpc_order_warns() {
+        let _guard = start_capture(LoqaLogLevel::Debug);
+        let clamped = crate::resolve_lpc_order(44100, 0);
+        let defaulted = crate::resolve_lpc_order(16000, 0);
+        let explicit = crate::resolve_lpc_order(44100, 12);
+        let captured = finish_capture();
+
+        assert_eq!((clamped, defaulted, explicit), (Ok(24), Ok(18), Ok(12)));
+        assert_eq!(
+            captured,
+            vec![
+                (
+                    LoqaLogLevel::Warn,
+                    "default LPC order 46 for 44100 Hz is outside [8, 24]; using 24".to_string()
+                ),
+                (
+                    LoqaLogLevel::Debug,
+                    "using default LPC order 18 for 16000 Hz".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fft_reports_size_and_truncation() {
+        let _guard = start_capture(LoqaLogLevel::Debug);
+        let samples = vec![0.5f32; 100];
+        crate::compute_fft_complex_checked(&samples, 64).unwrap();
+        crate::compute_fft_complex_checked(&samples, 0).unwrap();
+        let captured = finish_capture();
+
+        assert_eq!(
+            captured,
+            vec![
+                (
+                    LoqaLogLevel::Warn,
+                    "buffer of 100 samples truncated to fft_size 64; 36 samples ignored"
+                        .to_string()
+                ),
+                (
+                    LoqaLogLevel::Debug,
+                    "FFT of 100 samples with fft_size 64".to_string()
+                ),
+                (
+                    LoqaLogLevel::Debug,
+                    "FFT of 100 samples with fft_size 128".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_level_values_are_stable() {
+        // These discriminants are part of the C ABI
+        assert_eq!(LoqaLogLevel::Off as u32, 0);
+        assert_eq!(LoqaLogLevel::Error as u32, 1);
+        assert_eq!(LoqaLogLevel::Warn as u32, 2);
+        assert_eq!(LoqaLogLevel::Info as u32, 3);
+        assert_eq!(LoqaLogLevel::Debug as u32, 4);
+        assert_eq!(LoqaLogLevel::Trace as u32, 5);
+        assert_eq!(LoqaLogLevel::from_raw(6), None);
+    }
+}
@@ -0,0 +1,337 @@
+// Mel filterbank, log-mel spectrogram and MFCCs
+//
+// Frames come from `spectrogram::compute` with power scaling, so a sine of
+// amplitude A contributes A^2 to the bands around it whatever the window or
+// frame size. Each frame's power spectrum is weighted by triangular filters
+// spaced evenly on the mel scale between fmin and fmax:
+//
+// * HTK: mel = 2595 * log10(1 + f / 700), filters peak at 1.0
+// * Slaney (Auditory Toolbox, librosa's default): linear below 1 kHz and
+//   logarithmic above, filters normalized to equal area (2 / bandwidth in Hz)
+//
+// Log-mel values are 10 * log10(max(energy, 1e-10)) dB. MFCCs are the
+// orthonormal DCT-II of the log-mel frame, optionally liftered with the HTK
+// sinusoidal lifter 1 + (L / 2) * sin(pi * n / L) (c0 is left unchanged), and
+// can be followed by HTK regression deltas and delta-deltas computed over
+// +/- `delta_width` frames with the first and last frames repeated at the edges.
+
+use std::f64::consts::PI;
+
+/// Floor applied to band energies before taking logarithms (-100 dB)
+const ENERGY_FLOOR: f64 = 1e-10;
+
+/// Mel scale and filter normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MelScale {
+    /// O'Shaughnessy / HTK formula with unit-height filters
+    Htk,
+    /// Slaney's piecewise linear/log scale with area-normalized filters
+    Slaney,
+}
+
+/// Slaney scale: 200/3 Hz per mel below 1 kHz
+const SLANEY_HZ_PER_MEL: f64 = 200.0 / 3.0;
+const SLANEY_MIN_LOG_HZ: f64 = 1000.0;
+const SLANEY_MIN_LOG_MEL: f64 = SLANEY_MIN_LOG_HZ / SLANEY_HZ_PER_MEL;
+
+/// Slaney scale: mels per natural-log step above 1 kHz
+fn slaney_log_step() -> f64 {
+    6.4f64.ln() / 27.0
+}
+
+impl MelScale {
+    pub(crate) fn hz_to_mel(self, hz: f64) -> f64 {
+        match self {
+            MelScale::Htk => 2595.0 * (1.0 + hz / 700.0).log10(),
+            MelScale::Slaney if hz >= SLANEY_MIN_LOG_HZ => {
+                SLANEY_MIN_LOG_MEL + (hz / SLANEY_MIN_LOG_HZ).ln() / slaney_log_step()
+            }
+            MelScale::Slaney => hz / SLANEY_HZ_PER_MEL,
+        }
+    }
+
+    pub(crate) fn mel_to_hz(self, mel: f64) -> f64 {
+        match self {
+            MelScale::Htk => 700.0 * (10f64.powf(mel / 2595.0) - 1.0),
+            MelScale::Slaney if mel >= SLANEY_MIN_LOG_MEL => {
+                SLANEY_MIN_LOG_HZ * (slaney_log_step() * (mel - SLANEY_MIN_LOG_MEL)).exp()
+            }
+            MelScale::Slaney => mel * SLANEY_HZ_PER_MEL,
+        }
+    }
+}
+
+/// Triangular filters as a row-major `n_mels x (fft_size / 2 + 1)` matrix
+///
+/// Callers validate that `n_mels` is positive and `0 <= fmin < fmax <= sample_rate / 2`.
+pub(crate) fn filterbank(
+    n_mels: usize,
+    fft_size: usize,
+    sample_rate: u32,
+    fmin: f32,
+    fmax: f32,
+    scale: MelScale,
+) -> Vec<f32> {
+    let bin_count = fft_size / 2 + 1;
+    let bin_width = sample_rate as f64 / fft_size as f64;
+
+    // n_mels + 2 band edges evenly spaced in mel
+    let mel_min = scale.hz_to_mel(fmin as f64);
+    let mel_max = scale.hz_to_mel(fmax as f64);
+    let edges: Vec<f64> = (0..n_mels + 2)
+        .map(|i| scale.mel_to_hz(mel_min + (mel_max - mel_min) * i as f64 / (n_mels + 1) as f64))
+        .collect();
+
+    let mut weights = vec![0.0f32; n_mels * bin_count];
+    for (band, row) in weights.chunks_exact_mut(bin_count).enumerate() {
+        let (lower, center, upper) = (edges[band], edges[band + 1], edges[band + 2]);
+        let gain = match scale {
+            MelScale::Htk => 1.0,
+            MelScale::Slaney => 2.0 / (upper - lower),
+        };
+        for (k, weight) in row.iter_mut().enumerate() {
+            let hz = k as f64 * bin_width;
+            let rising = (hz - lower) / (center - lower);
+            let falling = (upper - hz) / (upper - center);
+            *weight = (gain * rising.min(falling).max(0.0)) as f32;
+        }
+    }
+    weights
+}
+
+/// Applies `filterbank` to every power frame and converts the band energies to dB
+pub(crate) fn log_mel(power_frames: &[f32], bin_count: usize, filterbank: &[f32]) -> Vec<f32> {
+    let n_mels = filterbank.len() / bin_count;
+    let mut values = Vec::with_capacity(power_frames.len() / bin_count * n_mels);
+    for frame in power_frames.chunks_exact(bin_count) {
+        values.extend(filterbank.chunks_exact(bin_count).map(|filter| {
+            let energy: f64 = filter
+                .iter()
+                .zip(frame)
+                .map(|(&w, &p)| w as f64 * p as f64)
+                .sum();
+            (10.0 * energy.max(ENERGY_FLOOR).log10()) as f32
+        }));
+    }
+    values
+}
+
+/// First `n_out` coefficients of the orthonormal DCT-II of `input`
+pub(crate) fn dct_ii(input: &[f32], n_out: usize) -> Vec<f32> {
+    let n = input.len() as f64;
+    (0..n_out)
+        .map(|k| {
+            let sum: f64 = input
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| x as f64 * (PI * k as f64 * (2 * i + 1) as f64 / (2.0 * n)).cos())
+                .sum();
+            let scale = if k == 0 {
+                (1.0 / n).sqrt()
+            } else {
+                (2.0 / n).sqrt()
+            };
+            (scale * sum) as f32
+        })
+        .collect()
+}
+
+/// HTK regression deltas of row-major `frames` with `width` frames on each side
+///
+/// Callers validate that `width` is positive.
+pub(crate) fn deltas(frames: &[f32], coefficient_count: usize, width: usize) -> Vec<f32> {
+    let rows: Vec<&[f32]> = frames.chunks_exact(coefficient_count).collect();
+    let last = rows.len().saturating_sub(1);
+    let denominator = 2.0 * (1..=width).map(|n| (n * n) as f64).sum::<f64>();
+
+    let mut out = Vec::with_capacity(frames.len());
+    for t in 0..rows.len() {
+        out.extend((0..coefficient_count).map(|c| {
+            let sum: f64 = (1..=width)
+                .map(|n| {
+                    let ahead = rows[(t + n).min(last)][c] as f64;
+                    let behind = rows[t.saturating_sub(n)][c] as f64;
+                    n as f64 * (ahead - behind)
+                })
+                .sum();
+            (sum / denominator) as f32
+        }));
+    }
+    out
+}
+
+/// MFCC options beyond the log-mel frames themselves
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MfccOptions {
+    pub n_mfcc: usize,
+    /// Sinusoidal lifter length; 0 disables liftering
+    pub lifter: f32,
+    /// 0 = static coefficients only, 1 = plus deltas, 2 = plus deltas and delta-deltas
+    pub delta_order: usize,
+    pub delta_width: usize,
+}
+
+/// MFCCs of row-major log-mel frames
+///
+/// Each output row holds the `n_mfcc` static coefficients followed by their
+/// deltas and delta-deltas as requested, `n_mfcc * (1 + delta_order)` values in
+/// all. Callers validate that `n_mfcc <= n_mels`.
+pub(crate) fn mfcc(log_mel: &[f32], n_mels: usize, options: MfccOptions) -> Vec<f32> {
+    let n_mfcc = options.n_mfcc;
+    let lifter: Vec<f32> = (0..n_mfcc)
+        .map(|n| {
+            let l = options.lifter as f64;
+            if l > 0.0 {
+                (1.0 + l / 2.0 * (PI * n as f64 / l).sin()) as f32
+            } else {
+                1.0
+            }
+        })
+        .collect();
+
+    let mut statics = Vec::with_capacity(log_mel.len() / n_mels * n_mfcc);
+    for frame in log_mel.chunks_exact(n_mels) {
+        let coefficients = dct_ii(frame, n_mfcc);
+        statics.extend(coefficients.iter().zip(&lifter).map(|(c, l)| c * l));
+    }
+
+    let mut blocks = vec![statics];
+    for _ in 0..options.delta_order {
+        let previous = blocks.last().expect("statics block");
+        blocks.push(deltas(previous, n_mfcc, options.delta_width));
+    }
+
+    let coefficient_count = n_mfcc * blocks.len();
+    let frame_count = blocks[0].len() / n_mfcc;
+    let mut values = Vec::with_capacity(frame_count * coefficient_count);
+    for t in 0..frame_count {
+        for block in &blocks {
+            values.extend_from_slice(&block[t * n_mfcc..(t + 1) * n_mfcc]);
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f32], expected: &[f32], tolerance: f32) {
+        assert_eq!(actual.len(), expected.len());
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            assert!((a - e).abs() <= tolerance, "[{i}] {a} vs {e}");
+        }
+    }
+
+    #[test]
+    fn test_mel_scales() {
+        // HTK 1000 Hz = 999.99 mel; Slaney 500 Hz = 7.5, 1000 Hz = 15 and 6400 Hz = 42 mel
+        assert!((MelScale::Htk.hz_to_mel(1000.0) - 999.985_6).abs() < 1e-3);
+        assert!((MelScale::Slaney.hz_to_mel(1000.0) - 15.0).abs() < 1e-12);
+        assert!((MelScale::Slaney.hz_to_mel(6400.0) - 42.0).abs() < 1e-9);
+        assert!((MelScale::Slaney.hz_to_mel(500.0) - 7.5).abs() < 1e-12);
+
+        for scale in [MelScale::Htk, MelScale::Slaney] {
+            for hz in [0.0, 300.0, 1000.0, 4321.0, 8000.0] {
+                assert!(
+                    (scale.mel_to_hz(scale.hz_to_mel(hz)) - hz).abs() < 1e-6,
+                    "{scale:?} {hz}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_filterbank_reference_weights() {
+        // 4 bands over 0-4000 Hz of a 64-point FFT at 8 kHz (125 Hz bins)
+        let htk = filterbank(4, 64, 8000, 0.0, 4000.0, MelScale::Htk);
+        assert_eq!(htk.len(), 4 * 33);
+        // Band edges 0, 324.47, 799.33, 1494.31, 2511.43, 4000.0 Hz
+        assert_close(
+            &htk[0..6],
+            &[0.0, 0.385_247, 0.770_494, 0.893_585, 0.630_352, 0.367_120],
+            1e-5,
+        );
+        assert_close(
+            &htk[33 + 8..33 + 12],
+            &[0.711_260, 0.531_398, 0.351_536, 0.171_674],
+            1e-5,
+        );
+
+        let slaney = filterbank(4, 64, 8000, 0.0, 4000.0, MelScale::Slaney);
+        // Band edges 0, 468.85, 937.70, 1520.84, 2466.45, 4000.0 Hz
+        assert_close(
+            &slaney[0..6],
+            &[
+                0.0,
+                0.000_568_65,
+                0.001_137_29,
+                0.001_705_94,
+                0.001_991_17,
+                0.001_422_53,
+            ],
+            1e-8,
+        );
+
+        // Every row is a triangle: zero outside its edges, positive inside
+        for row in htk.chunks_exact(33) {
+            assert!(row.iter().any(|&w| w > 0.0));
+            assert!(row.iter().all(|&w| (0.0..=1.0).contains(&w)));
+        }
+    }
+
+    #[test]
+    fn test_dct_and_lifter_reference_values() {
+        let input = [1.0, -2.0, 3.0, 0.5, -1.5];
+        assert_close(
+            &dct_ii(&input, 5),
+            &[0.447_214, 0.574_382, -1.860_041, 2.433_122, 2.567_148],
+            1e-5,
+        );
+
+        // A constant frame only has energy in c0
+        let coefficients = dct_ii(&[2.0; 8], 4);
+        assert_close(&coefficients, &[2.0 * 8f32.sqrt(), 0.0, 0.0, 0.0], 1e-5);
+
+        let options = MfccOptions {
+            n_mfcc: 5,
+            lifter: 22.0,
+            delta_order: 0,
+            delta_width: 2,
+        };
+        let liftered = mfcc(&input, 5, options);
+        // 1 + 11 * sin(pi * n / 22) = 1, 2.565, 4.099, 5.570, 6.947
+        assert_close(
+            &liftered,
+            &[0.447_214, 1.473_557, -7.624_416, 13.551_434, 17.834_1],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_deltas_of_linear_ramp() {
+        // c(t) = 3t: interior deltas are 3, edges are damped by the repeated frames
+        let frames: Vec<f32> = (0..6).map(|t| 3.0 * t as f32).collect();
+        assert_close(
+            &deltas(&frames, 1, 2),
+            &[1.5, 2.4, 3.0, 3.0, 2.4, 1.5],
+            1e-6,
+        );
+        assert_close(
+            &deltas(&frames, 1, 1),
+            &[1.5, 3.0, 3.0, 3.0, 3.0, 1.5],
+            1e-6,
+        );
+
+        // With deltas and delta-deltas each row is [static, delta, delta-delta]
+        let options = MfccOptions {
+            n_mfcc: 1,
+            lifter: 0.0,
+            delta_order: 2,
+            delta_width: 1,
+        };
+        let values = mfcc(&frames, 1, options);
+        assert_eq!(values.len(), 18);
+        assert_close(&values[3..6], &[3.0, 3.0, 0.75], 1e-6);
+    }
+}
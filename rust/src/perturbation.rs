@@ -0,0 +1,485 @@
+// Glottal period extraction and Praat-compatible jitter / shimmer
+//
+// Pulses are picked directly from the waveform, guided by the HMM-smoothed
+// pitch track of `VoiceAnalyzer::process_buffer`. Within each run of voiced
+// frames the first pulse is the largest peak in the first period; each next
+// pulse is the largest peak between T0 / 1.25 and 1.25 * T0 after the previous
+// one, where T0 comes from the nearest voiced frame. Peaks are taken on the
+// side (positive or negative) with the larger excursion, and their time and
+// amplitude are refined by parabolic interpolation. Each pair of consecutive
+// pulses is one cycle: its period, and the absolute peak amplitude of the
+// pulse that starts it.
+//
+// Measures follow Praat's "Get jitter" / "Get shimmer" with its default
+// arguments: periods outside [0.1 ms, 20 ms] are ignored, as are neighbours
+// whose periods differ by more than a factor 1.3 or (for shimmer) whose
+// amplitudes differ by more than a factor 1.6. Every measure averages over
+// the windows of consecutive cycles that pass these checks, never across an
+// unvoiced gap, and is `None` when no such window exists.
+
+/// Shortest period counted as a glottal cycle (seconds)
+const PERIOD_FLOOR: f64 = 0.0001;
+/// Longest period counted as a glottal cycle (seconds)
+const PERIOD_CEILING: f64 = 0.02;
+/// Largest ratio between consecutive periods
+const MAX_PERIOD_FACTOR: f64 = 1.3;
+/// Largest ratio between consecutive peak amplitudes (shimmer only)
+const MAX_AMPLITUDE_FACTOR: f64 = 1.6;
+/// Search window for the next pulse, as a factor of the expected period
+const PULSE_SEARCH_FACTOR: f64 = 1.25;
+
+/// One glottal cycle between two consecutive pulses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Cycle {
+    /// Seconds from this pulse to the next
+    pub period: f64,
+    /// Absolute peak amplitude of the pulse starting the cycle
+    pub amplitude: f64,
+}
+
+/// Praat jitter measures; relative measures are fractions (0.01 = 1 %)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Jitter {
+    pub local: Option<f64>,
+    /// Mean absolute difference between consecutive periods in seconds
+    pub local_absolute: Option<f64>,
+    pub rap: Option<f64>,
+    pub ppq5: Option<f64>,
+    pub ddp: Option<f64>,
+    /// Mean of the valid periods in seconds
+    pub mean_period: Option<f64>,
+    /// Number of valid periods
+    pub period_count: usize,
+}
+
+/// Praat shimmer measures; relative measures are fractions, `local_db` is in dB
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Shimmer {
+    pub local: Option<f64>,
+    pub local_db: Option<f64>,
+    pub apq3: Option<f64>,
+    pub apq5: Option<f64>,
+    pub apq11: Option<f64>,
+    pub dda: Option<f64>,
+    /// Mean peak amplitude of the valid periods
+    pub mean_amplitude: Option<f64>,
+    /// Number of valid periods
+    pub period_count: usize,
+}
+
+/// Extracts the glottal cycles of every voiced run in `pitch_track`
+///
+/// `pitch_track` holds one F0 estimate (0 = unvoiced) per frame of
+/// `frame_size` samples, frames starting every `hop_size` samples. Each voiced
+/// run covers its frame centres plus half a hop on either side.
+pub(crate) fn extract_cycles(
+    samples: &[f32],
+    sample_rate: u32,
+    pitch_track: &[f32],
+    frame_size: usize,
+    hop_size: usize,
+) -> Vec<Vec<Cycle>> {
+    let rate = sample_rate as f64;
+    let mut runs = Vec::new();
+    let mut frame = 0;
+    while frame < pitch_track.len() {
+        if pitch_track[frame] <= 0.0 {
+            frame += 1;
+            continue;
+        }
+        let first = frame;
+        while frame < pitch_track.len() && pitch_track[frame] > 0.0 {
+            frame += 1;
+        }
+        let run = &pitch_track[first..frame];
+
+        let first_center = first * hop_size + frame_size / 2;
+        let start = first_center.saturating_sub(hop_size / 2);
+        let end = (first_center + (run.len() - 1) * hop_size + hop_size / 2).min(samples.len());
+
+        // Expected period in samples from the voiced frame nearest to `position`
+        let period_at = |position: f64| {
+            let index = ((position - first_center as f64) / hop_size as f64)
+                .round()
+                .clamp(0.0, (run.len() - 1) as f64) as usize;
+            rate / run[index] as f64
+        };
+
+        let pulses = pick_pulses(samples, start, end, period_at);
+        let cycles: Vec<Cycle> = pulses
+            .windows(2)
+            .map(|pair| Cycle {
+                period: (pair[1].0 - pair[0].0) / rate,
+                amplitude: pair[0].1,
+            })
+            .collect();
+        if !cycles.is_empty() {
+            runs.push(cycles);
+        }
+    }
+    runs
+}
+
+/// Picks `(position, absolute amplitude)` pulses in `samples[start..end]`
+fn pick_pulses(
+    samples: &[f32],
+    start: usize,
+    end: usize,
+    period_at: impl Fn(f64) -> f64,
+) -> Vec<(f64, f64)> {
+    // Parabolic refinement needs a sample on either side of every peak
+    let start = start.max(1);
+    let end = end.min(samples.len().saturating_sub(1));
+    if start >= end {
+        return Vec::new();
+    }
+
+    let region = &samples[start..end];
+    let max = region.iter().fold(f32::MIN, |a, &b| a.max(b));
+    let min = region.iter().fold(f32::MAX, |a, &b| a.min(b));
+    let polarity = if max >= -min { 1.0 } else { -1.0 };
+    let value = |k: usize| polarity * samples[k] as f64;
+    let largest =
+        |from: usize, to: usize| (from..to).max_by(|&a, &b| value(a).total_cmp(&value(b)));
+
+    let first_end = (start + period_at(start as f64).ceil() as usize).min(end);
+    let Some(first) = largest(start, first_end) else {
+        return Vec::new();
+    };
+
+    let mut pulses = vec![refine_peak(first, value)];
+    loop {
+        let last = pulses.last().expect("first pulse").0;
+        let period = period_at(last);
+        let from = (last + period / PULSE_SEARCH_FACTOR).ceil() as usize;
+        let to = (last + period * PULSE_SEARCH_FACTOR).floor() as usize + 1;
+        if to > end {
+            break;
+        }
+        match largest(from, to) {
+            Some(peak) => pulses.push(refine_peak(peak, value)),
+            None => break,
+        }
+    }
+    pulses
+}
+
+/// Parabolic interpolation of the peak at sample `k`: `(position, |amplitude|)`
+fn refine_peak(k: usize, value: impl Fn(usize) -> f64) -> (f64, f64) {
+    let (left, center, right) = (value(k - 1), value(k), value(k + 1));
+    let curvature = left - 2.0 * center + right;
+    if curvature >= 0.0 {
+        return (k as f64, center.abs());
+    }
+    let offset = 0.5 * (left - right) / curvature;
+    let peak = center - 0.25 * (left - right) * offset;
+    (k as f64 + offset, peak.abs())
+}
+
+fn period_in_range(period: f64) -> bool {
+    (PERIOD_FLOOR..=PERIOD_CEILING).contains(&period)
+}
+
+fn within_factor(a: f64, b: f64, factor: f64) -> bool {
+    a > 0.0 && b > 0.0 && a.max(b) / a.min(b) <= factor
+}
+
+/// Praat's `PointProcess_isPeriod`: in range, and not too different from an in-range neighbour
+fn is_period(run: &[Cycle], index: usize) -> bool {
+    let period = run[index].period;
+    if !period_in_range(period) {
+        return false;
+    }
+    let neighbours = [index.checked_sub(1), Some(index + 1)];
+    neighbours
+        .into_iter()
+        .flatten()
+        .filter_map(|i| run.get(i))
+        .filter(|neighbour| period_in_range(neighbour.period))
+        .all(|neighbour| within_factor(period, neighbour.period, MAX_PERIOD_FACTOR))
+}
+
+/// True if every period of `window` is in range and consecutive periods are similar
+fn periods_valid(window: &[Cycle]) -> bool {
+    window.iter().all(|cycle| period_in_range(cycle.period))
+        && window
+            .windows(2)
+            .all(|pair| within_factor(pair[0].period, pair[1].period, MAX_PERIOD_FACTOR))
+}
+
+/// `periods_valid`, plus consecutive amplitudes within the shimmer factor
+fn amplitudes_valid(window: &[Cycle]) -> bool {
+    periods_valid(window)
+        && window
+            .windows(2)
+            .all(|pair| within_factor(pair[0].amplitude, pair[1].amplitude, MAX_AMPLITUDE_FACTOR))
+}
+
+/// Mean of `term` over every window of `width` consecutive cycles accepted by `valid`
+fn window_mean(
+    runs: &[Vec<Cycle>],
+    width: usize,
+    valid: fn(&[Cycle]) -> bool,
+    term: impl Fn(&[Cycle]) -> f64,
+) -> Option<f64> {
+    let (sum, count) = runs
+        .iter()
+        .flat_map(|run| run.windows(width))
+        .filter(|window| valid(window))
+        .fold((0.0, 0usize), |(sum, count), window| {
+            (sum + term(window), count + 1)
+        });
+    (count > 0).then(|| sum / count as f64)
+}
+
+/// Mean of `value` over the cycles that are valid periods, and their count
+fn valid_period_mean(runs: &[Vec<Cycle>], value: impl Fn(&Cycle) -> f64) -> (Option<f64>, usize) {
+    let (sum, count) = runs
+        .iter()
+        .flat_map(|run| {
+            (0..run.len())
+                .filter(|&i| is_period(run, i))
+                .map(move |i| &run[i])
+        })
+        .fold((0.0, 0usize), |(sum, count), cycle| {
+            (sum + value(cycle), count + 1)
+        });
+    ((count > 0).then(|| sum / count as f64), count)
+}
+
+/// Absolute deviation of the middle value of `values` from their mean
+fn middle_deviation(values: &[f64]) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    (values[values.len() / 2] - mean).abs()
+}
+
+/// Second difference of three consecutive values
+fn second_difference(a: f64, b: f64, c: f64) -> f64 {
+    ((c - b) - (b - a)).abs()
+}
+
+/// Jitter (period perturbation) of the cycles from `extract_cycles`
+pub(crate) fn jitter(runs: &[Vec<Cycle>]) -> Jitter {
+    let (mean_period, period_count) = valid_period_mean(runs, |cycle| cycle.period);
+    let relative = |value: Option<f64>| Some(value? / mean_period?);
+    let periods = |window: &[Cycle]| window.iter().map(|cycle| cycle.period).collect::<Vec<_>>();
+
+    let local_absolute = window_mean(runs, 2, periods_valid, |w| {
+        (w[1].period - w[0].period).abs()
+    });
+    let rap = window_mean(runs, 3, periods_valid, |w| middle_deviation(&periods(w)));
+    let ppq5 = window_mean(runs, 5, periods_valid, |w| middle_deviation(&periods(w)));
+    let ddp = window_mean(runs, 3, periods_valid, |w| {
+        second_difference(w[0].period, w[1].period, w[2].period)
+    });
+
+    Jitter {
+        local: relative(local_absolute),
+        local_absolute,
+        rap: relative(rap),
+        ppq5: relative(ppq5),
+        ddp: relative(ddp),
+        mean_period,
+        period_count,
+    }
+}
+
+/// Shimmer (peak amplitude perturbation) of the cycles from `extract_cycles`
+pub(crate) fn shimmer(runs: &[Vec<Cycle>]) -> Shimmer {
+    let (mean_amplitude, period_count) = valid_period_mean(runs, |cycle| cycle.amplitude);
+    let relative = |value: Option<f64>| Some(value? / mean_amplitude?);
+    let amplitudes = |window: &[Cycle]| {
+        window
+            .iter()
+            .map(|cycle| cycle.amplitude)
+            .collect::<Vec<_>>()
+    };
+    let apq = |width: usize| {
+        window_mean(runs, width, amplitudes_valid, |w| {
+            middle_deviation(&amplitudes(w))
+        })
+    };
+
+    let local = window_mean(runs, 2, amplitudes_valid, |w| {
+        (w[1].amplitude - w[0].amplitude).abs()
+    });
+    let local_db = window_mean(runs, 2, amplitudes_valid, |w| {
+        (20.0 * (w[1].amplitude / w[0].amplitude).log10()).abs()
+    });
+    let dda = window_mean(runs, 3, amplitudes_valid, |w| {
+        second_difference(w[0].amplitude, w[1].amplitude, w[2].amplitude)
+    });
+
+    Shimmer {
+        local: relative(local),
+        local_db,
+        apq3: relative(apq(3)),
+        apq5: relative(apq(5)),
+        apq11: relative(apq(11)),
+        dda: relative(dda),
+        mean_amplitude,
+        period_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Periods alternating T(1 + d), T(1 - d) with amplitudes alternating A(1 + e), A(1 - e)
+    fn alternating_cycles(count: usize, period: f64, d: f64, amplitude: f64, e: f64) -> Vec<Cycle> {
+        (0..count)
+            .map(|i| {
+                let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                Cycle {
+                    period: period * (1.0 + sign * d),
+                    amplitude: amplitude * (1.0 + sign * e),
+                }
+            })
+            .collect()
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64, tolerance: f64) {
+        let actual = actual.expect("measure is defined");
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn test_jitter_reference_values() {
+        // Alternating periods: |p_i - p_i-1| = 2dT, so local = 2d, RAP = 4d/3,
+        // PPQ5 = 4d/5 and DDP = 3 * RAP = 4d
+        let d = 0.02;
+        let runs = vec![alternating_cycles(40, 0.005, d, 0.5, 0.0)];
+        let jitter = jitter(&runs);
+
+        assert_eq!(jitter.period_count, 40);
+        assert_close(jitter.mean_period, 0.005, 1e-12);
+        assert_close(jitter.local, 2.0 * d, 1e-12);
+        assert_close(jitter.local_absolute, 2.0 * d * 0.005, 1e-12);
+        assert_close(jitter.rap, 4.0 * d / 3.0, 1e-12);
+        assert_close(jitter.ppq5, 4.0 * d / 5.0, 1e-12);
+        assert_close(jitter.ddp, 4.0 * d, 1e-12);
+    }
+
+    #[test]
+    fn test_shimmer_reference_values() {
+        // Alternating amplitudes: local = 2e, APQ3 = 4e/3, APQ5 = 4e/5,
+        // APQ11 = 12e/11, DDA = 4e and local dB = 20 * log10((1 + e) / (1 - e))
+        let e = 0.1;
+        let runs = vec![alternating_cycles(40, 0.005, 0.0, 0.5, e)];
+        let shimmer = shimmer(&runs);
+
+        assert_eq!(shimmer.period_count, 40);
+        assert_close(shimmer.mean_amplitude, 0.5, 1e-12);
+        assert_close(shimmer.local, 2.0 * e, 1e-12);
+        assert_close(
+            shimmer.local_db,
+            20.0 * ((1.0 + e) / (1.0 - e)).log10(),
+            1e-12,
+        );
+        assert_close(shimmer.apq3, 4.0 * e / 3.0, 1e-12);
+        assert_close(shimmer.apq5, 4.0 * e / 5.0, 1e-12);
+        assert_close(shimmer.apq11, 12.0 * e / 11.0, 1e-12);
+        assert_close(shimmer.dda, 4.0 * e, 1e-12);
+    }
+
+    #[test]
+    fn test_windows_do_not_cross_gaps_or_outliers() {
+        let cycles = |period: f64| {
+            vec![
+                Cycle {
+                    period,
+                    amplitude: 1.0
+                };
+                3
+            ]
+        };
+
+        // Two runs of three equal periods: the jump between runs (5 ms vs 8 ms)
+        // is never compared, and neither run is long enough for PPQ5
+        let result = jitter(&[cycles(0.005), cycles(0.008)]);
+        assert_eq!(result.local, Some(0.0));
+        assert_eq!(result.ppq5, None);
+
+        // A 30 ms period is outside Praat's range and splits its neighbours
+        let mut run = cycles(0.005);
+        run.insert(
+            1,
+            Cycle {
+                period: 0.03,
+                amplitude: 1.0,
+            },
+        );
+        let result = jitter(&[run]);
+        assert_eq!(result.period_count, 3);
+        assert_eq!(result.local, Some(0.0));
+        assert_eq!(result.rap, None);
+
+        // Periods 1.5x apart are not valid periods either
+        let run = vec![
+            Cycle {
+                period: 0.004,
+                amplitude: 1.0,
+            },
+            Cycle {
+                period: 0.006,
+                amplitude: 1.0,
+            },
+        ];
+        assert_eq!(jitter(&[run]).period_count, 0);
+        assert_eq!(jitter(&[]).local, None);
+        assert_eq!(shimmer(&[]).local, None);
+    }
+
+    #[test]
+    fn test_extract_cycles_from_pulse_train() {
+        // Gaussian pulses at 16 kHz alternating 80 * (1 +/- 2%) samples apart,
+        // with alternating heights 0.8 and 0.6
+        let sample_rate = 16000;
+        let mut centers = vec![100.0];
+        while *centers.last().unwrap() < 7900.0 {
+            let step = if centers.len() % 2 == 1 { 81.6 } else { 78.4 };
+            centers.push(centers.last().unwrap() + step);
+        }
+        let samples: Vec<f32> = (0..8000)
+            .map(|n| {
+                centers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &c)| {
+                        let height = if i % 2 == 0 { 0.8 } else { 0.6 };
+                        height * (-((n as f64 - c) / 4.0).powi(2)).exp()
+                    })
+                    .sum::<f64>() as f32
+            })
+            .collect();
+
+        // 200 Hz everywhere except an unvoiced frame in the middle
+        let mut track = vec![200.0_f32; 14];
+        track[7] = 0.0;
+        let runs = extract_cycles(&samples, sample_rate, &track, 1024, 512);
+        assert_eq!(runs.len(), 2);
+
+        for cycle in runs.iter().flatten() {
+            let expected = [81.6 / 16000.0, 78.4 / 16000.0];
+            assert!(
+                expected
+                    .iter()
+                    .any(|p| (cycle.period - p).abs() < 0.02 / 16000.0),
+                "period {}",
+                cycle.period
+            );
+            assert!((cycle.amplitude - 0.8).abs() < 1e-3 || (cycle.amplitude - 0.6).abs() < 1e-3);
+        }
+
+        let jitter = jitter(&runs);
+        assert_close(jitter.local, 0.04, 1e-3);
+        assert_close(jitter.rap, 0.04 * 2.0 / 3.0, 1e-3);
+        let shimmer = shimmer(&runs);
+        assert_close(shimmer.local, 0.2 / 0.7, 1e-3);
+        assert_close(shimmer.local_db, 20.0 * (0.8f64 / 0.6).log10(), 1e-2);
+    }
+}
@@ -0,0 +1,176 @@
+// Pitch track statistics in Hz and semitones, weighted by voiced probability
+//
+// Every frame with a pitch above 0 is voiced. Each voiced frame counts with
+// its voiced probability as weight (1 when weighting is off), so frames the
+// tracker is unsure of pull the statistics less; voiced frames of weight 0 are
+// left out. Semitones are 12·log2(f / reference).
+//
+// Percentiles interpolate linearly between the weighted midpoints of the
+// sorted values: the k-th value sits at (W_k - w_k / 2) / W, where W_k is the
+// cumulative weight up to and including it and W the total. With equal
+// weights the median is the usual one (the mean of the middle two values for
+// an even count). Sums run in f64 in frame order, so the same track gives the
+// same bits on every platform.
+
+/// Weighted mean, median, standard deviation and 5th/95th percentiles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Summary {
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation (0 for a single frame)
+    pub std: f64,
+    pub p5: f64,
+    pub p95: f64,
+}
+
+/// Statistics of one pitch track
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PitchStatistics {
+    pub frame_count: usize,
+    /// Frames with a pitch above 0
+    pub voiced_frame_count: usize,
+    /// Summary in Hz; None when no voiced frame has a positive weight
+    pub hz: Option<Summary>,
+    /// Summary in semitones re the reference; None exactly when `hz` is
+    pub semitones: Option<Summary>,
+}
+
+impl PitchStatistics {
+    /// Voiced frames / frames (0 for an empty track)
+    pub fn voiced_ratio(&self) -> f64 {
+        if self.frame_count == 0 {
+            0.0
+        } else {
+            self.voiced_frame_count as f64 / self.frame_count as f64
+        }
+    }
+
+    /// Span between the 5th and 95th percentiles in semitones
+    pub fn range_semitones(&self) -> Option<f64> {
+        self.semitones.map(|s| s.p95 - s.p5)
+    }
+}
+
+/// Summary of `(value, weight)` pairs whose weights are all positive
+fn summarize(values: &[(f64, f64)]) -> Option<Summary> {
+    let total: f64 = values.iter().map(|&(_, w)| w).sum();
+    if values.is_empty() || total <= 0.0 {
+        return None;
+    }
+    let mean = values.iter().map(|&(v, w)| v * w).sum::<f64>() / total;
+    let variance = values
+        .iter()
+        .map(|&(v, w)| w * (v - mean).powi(2))
+        .sum::<f64>()
+        / total;
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut cumulative = 0.0;
+    let positions: Vec<f64> = sorted
+        .iter()
+        .map(|&(_, w)| {
+            cumulative += w;
+            (cumulative - w / 2.0) / total
+        })
+        .collect();
+    let percentile = |p: f64| {
+        let k = positions.partition_point(|&c| c < p);
+        if k == 0 {
+            return sorted[0].0;
+        }
+        if k == sorted.len() {
+            return sorted[k - 1].0;
+        }
+        let t = (p - positions[k - 1]) / (positions[k] - positions[k - 1]);
+        sorted[k - 1].0 + t * (sorted[k].0 - sorted[k - 1].0)
+    };
+
+    Some(Summary {
+        mean,
+        median: percentile(0.5),
+        std: variance.sqrt(),
+        p5: percentile(0.05),
+        p95: percentile(0.95),
+    })
+}
+
+/// Computes the statistics of `pitch_track`
+///
+/// Callers validate that both slices have the same length, pitches are finite
+/// and non-negative, probabilities are in [0, 1] and `reference_hz` is positive.
+pub(crate) fn statistics(
+    pitch_track: &[f32],
+    voiced_probs: &[f32],
+    reference_hz: f32,
+    weighted: bool,
+) -> PitchStatistics {
+    let voiced: Vec<(f64, f64)> = pitch_track
+        .iter()
+        .zip(voiced_probs)
+        .filter(|&(&f, _)| f > 0.0)
+        .map(|(&f, &p)| (f as f64, if weighted { p as f64 } else { 1.0 }))
+        .collect();
+    let weighted_frames: Vec<(f64, f64)> =
+        voiced.iter().copied().filter(|&(_, w)| w > 0.0).collect();
+    let semitones: Vec<(f64, f64)> = weighted_frames
+        .iter()
+        .map(|&(f, w)| (12.0 * (f / reference_hz as f64).log2(), w))
+        .collect();
+
+    PitchStatistics {
+        frame_count: pitch_track.len(),
+        voiced_frame_count: voiced.len(),
+        hz: summarize(&weighted_frames),
+        semitones: summarize(&semitones),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_weights_give_the_usual_median_and_population_std() {
+        let track = [0.0, 100.0, 200.0, 0.0, 300.0, 400.0];
+        let stats = statistics(&track, &[0.5; 6], 100.0, true);
+        let hz = stats.hz.unwrap();
+        assert_eq!(stats.voiced_frame_count, 4);
+        assert!((stats.voiced_ratio() - 4.0 / 6.0).abs() < 1e-12);
+        assert_eq!(hz.mean, 250.0);
+        assert_eq!(hz.median, 250.0);
+        assert!((hz.std - 125.0f64.sqrt() * 10.0).abs() < 1e-9);
+        // The lowest and highest midpoints are at 12.5 % and 87.5 %
+        assert_eq!((hz.p5, hz.p95), (100.0, 400.0));
+
+        let semitones = stats.semitones.unwrap();
+        assert!((semitones.p95 - 24.0).abs() < 1e-9);
+        assert!((stats.range_semitones().unwrap() - 24.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_voiced_probability_weights_pull_the_statistics() {
+        // A confident 200 Hz and an unsure 400 Hz octave error
+        let track = [200.0, 200.0, 200.0, 400.0];
+        let probs = [0.9, 0.9, 0.9, 0.1];
+        let weighted = statistics(&track, &probs, 100.0, true).hz.unwrap();
+        let unweighted = statistics(&track, &probs, 100.0, false).hz.unwrap();
+        assert!((weighted.mean - 1450.0 / 7.0).abs() < 1e-4, "{weighted:?}");
+        assert_eq!(unweighted.mean, 250.0);
+        assert_eq!(weighted.median, 200.0);
+
+        // Voiced frames of weight 0 count as voiced but not in the summaries
+        let stats = statistics(&[200.0, 400.0], &[1.0, 0.0], 100.0, true);
+        assert_eq!(stats.voiced_frame_count, 2);
+        assert_eq!(stats.hz.unwrap().std, 0.0);
+    }
+
+    #[test]
+    fn test_tracks_without_weighted_voiced_frames_have_no_summary() {
+        let stats = statistics(&[0.0, 0.0], &[0.9, 0.9], 100.0, true);
+        assert_eq!((stats.hz, stats.semitones), (None, None));
+        assert_eq!(stats.range_semitones(), None);
+        assert!(statistics(&[200.0], &[0.0], 100.0, true).hz.is_none());
+        assert_eq!(statistics(&[], &[], 100.0, true).voiced_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,213 @@
+// Pitch track post-processing: octave-jump correction, gap interpolation and
+// smoothing
+//
+// Tracks use the `process_buffer` convention of one pitch per frame with 0.0
+// for unvoiced frames. The steps run in this order, each on the previous
+// step's output, and never change the number of frames:
+//
+//   octave correction: a voiced frame whose ratio to the median voiced pitch
+//     of the `octave_window` frames around it is within
+//     `OCTAVE_TOLERANCE_CENTS` of a whole number of octaves (other than 0) is
+//     moved by that many octaves. The median comes from the uncorrected
+//     track, so it is reliable while errors are fewer than half the window;
+//     real intervals more than a tolerance away from an octave are kept.
+//   gap interpolation: a run of at most `max_gap_frames` unvoiced frames with
+//     voiced frames on both sides is filled by linear interpolation in
+//     log-frequency (evenly spaced in cents). Gaps at the start or end of the
+//     track are left unvoiced.
+//   smoothing: each voiced frame becomes the median or mean of the
+//     `smoothing_window` frames centred on it. The window shrinks
+//     symmetrically near the ends of a voiced run, so it never mixes in
+//     unvoiced frames or frames from another run, and run endpoints keep
+//     their value.
+
+/// Largest distance in cents from a whole number of octaves that is still
+/// treated as an octave error
+const OCTAVE_TOLERANCE_CENTS: f32 = 200.0;
+
+/// Filter applied by the smoothing step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Smoothing {
+    None,
+    Median,
+    MovingAverage,
+}
+
+/// Steps and windows of `postprocess`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PostprocessSettings {
+    /// Whether to run octave correction
+    pub correct_octave_jumps: bool,
+    /// Frames around each frame whose median is the octave reference (odd)
+    pub octave_window: usize,
+    pub smoothing: Smoothing,
+    /// Frames in the smoothing window (odd; 1 leaves the track unchanged)
+    pub smoothing_window: usize,
+    /// Longest unvoiced gap in frames that is interpolated (0 = none)
+    pub max_gap_frames: usize,
+}
+
+/// Upper median of `values`, which must be non-empty
+fn median(values: &mut [f32]) -> f32 {
+    values.sort_by(f32::total_cmp);
+    values[values.len() / 2]
+}
+
+/// Moves voiced frames an octave or more away from their neighbourhood back
+fn correct_octave_jumps(track: &[f32], window: usize) -> Vec<f32> {
+    let half = window / 2;
+    track
+        .iter()
+        .enumerate()
+        .map(|(i, &frequency)| {
+            if frequency <= 0.0 {
+                return frequency;
+            }
+            let neighbourhood = &track[i.saturating_sub(half)..(i + half + 1).min(track.len())];
+            let mut voiced: Vec<f32> = neighbourhood.iter().copied().filter(|&f| f > 0.0).collect();
+            let octaves = (frequency / median(&mut voiced)).log2();
+            let jump = octaves.round();
+            if jump != 0.0 && (octaves - jump).abs() * 1200.0 <= OCTAVE_TOLERANCE_CENTS {
+                frequency / jump.exp2()
+            } else {
+                frequency
+            }
+        })
+        .collect()
+}
+
+/// Fills interior unvoiced runs of at most `max_gap` frames
+fn interpolate_gaps(track: &mut [f32], max_gap: usize) {
+    let mut last_voiced: Option<usize> = None;
+    for i in 0..track.len() {
+        if track[i] <= 0.0 {
+            continue;
+        }
+        if let Some(start) = last_voiced {
+            let gap = i - start - 1;
+            if (1..=max_gap).contains(&gap) {
+                let (from, to) = (track[start], track[i]);
+                for j in 1..=gap {
+                    let t = j as f32 / (gap + 1) as f32;
+                    track[start + j] = from * (to / from).powf(t);
+                }
+            }
+        }
+        last_voiced = Some(i);
+    }
+}
+
+/// Median or moving-average filter over each voiced run
+fn smooth(track: &[f32], smoothing: Smoothing, window: usize) -> Vec<f32> {
+    let mut smoothed = track.to_vec();
+    if smoothing == Smoothing::None || window <= 1 {
+        return smoothed;
+    }
+
+    let mut start = 0;
+    while start < track.len() {
+        if track[start] <= 0.0 {
+            start += 1;
+            continue;
+        }
+        let end = start + track[start..].iter().take_while(|&&f| f > 0.0).count();
+        let run = &track[start..end];
+        for (j, value) in smoothed[start..end].iter_mut().enumerate() {
+            let half = (window / 2).min(j).min(run.len() - 1 - j);
+            let neighbourhood = &run[j - half..=j + half];
+            *value = match smoothing {
+                Smoothing::Median => median(&mut neighbourhood.to_vec()),
+                _ => neighbourhood.iter().sum::<f32>() / neighbourhood.len() as f32,
+            };
+        }
+        start = end;
+    }
+    smoothed
+}
+
+/// Runs octave correction, gap interpolation and smoothing on `track`
+///
+/// Callers validate that the windows are odd and the track holds finite,
+/// non-negative values.
+pub(crate) fn postprocess(track: &[f32], settings: &PostprocessSettings) -> Vec<f32> {
+    let mut processed = if settings.correct_octave_jumps {
+        correct_octave_jumps(track, settings.octave_window)
+    } else {
+        track.to_vec()
+    };
+    interpolate_gaps(&mut processed, settings.max_gap_frames);
+    smooth(&processed, settings.smoothing, settings.smoothing_window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_octave_errors_are_folded_and_real_intervals_kept() {
+        let track = [200.0, 202.0, 404.0, 204.0, 0.0, 103.0, 206.0, 208.0, 210.0];
+        let corrected = correct_octave_jumps(&track, 5);
+        assert_eq!(
+            corrected,
+            vec![200.0, 202.0, 202.0, 204.0, 0.0, 206.0, 206.0, 208.0, 210.0]
+        );
+
+        // A sustained fifth (702 cents) is far from an octave and stays
+        let fifth = [200.0, 200.0, 200.0, 300.0, 300.0, 300.0, 300.0];
+        assert_eq!(correct_octave_jumps(&fifth, 5), fifth.to_vec());
+
+        // Two-octave errors fold too
+        let corrected = correct_octave_jumps(&[220.0, 220.0, 880.0, 220.0, 220.0], 5);
+        assert_eq!(corrected[2], 220.0);
+    }
+
+    #[test]
+    fn test_interior_gaps_up_to_the_limit_are_interpolated_in_cents() {
+        let mut track = vec![
+            0.0, 100.0, 0.0, 0.0, 0.0, 1600.0, 0.0, 0.0, 0.0, 0.0, 1600.0, 0.0,
+        ];
+        interpolate_gaps(&mut track, 3);
+
+        // One octave per frame from 100 to 1600 Hz
+        for (filled, expected) in track[2..5].iter().zip([200.0, 400.0, 800.0]) {
+            assert!((filled - expected).abs() < 1e-2, "{track:?}");
+        }
+        // The 4-frame gap and the gaps at the ends stay unvoiced
+        assert_eq!(track[6..], [0.0, 0.0, 0.0, 0.0, 1600.0, 0.0]);
+        assert_eq!(track[0], 0.0);
+    }
+
+    #[test]
+    fn test_smoothing_stays_within_voiced_runs() {
+        let track = [0.0, 200.0, 220.0, 300.0, 220.0, 230.0, 0.0, 500.0, 100.0];
+        let median = smooth(&track, Smoothing::Median, 3);
+        assert_eq!(
+            median,
+            vec![0.0, 200.0, 220.0, 220.0, 230.0, 230.0, 0.0, 500.0, 100.0]
+        );
+
+        // Windows shrink to 3 frames next to run ends and to 1 at them
+        let mean = smooth(&track, Smoothing::MovingAverage, 5);
+        assert_eq!(mean[..6], [0.0, 200.0, 240.0, 234.0, 250.0, 230.0]);
+        assert_eq!(smooth(&track, Smoothing::None, 5), track.to_vec());
+    }
+
+    #[test]
+    fn test_postprocess_corrects_before_filling_and_smoothing() {
+        let settings = PostprocessSettings {
+            correct_octave_jumps: true,
+            octave_window: 5,
+            smoothing: Smoothing::Median,
+            smoothing_window: 3,
+            max_gap_frames: 2,
+        };
+        // The octave error next to the gap would otherwise bend the interpolation
+        let track = [200.0, 200.0, 400.0, 0.0, 0.0, 200.0, 200.0, 200.0];
+        let processed = postprocess(&track, &settings);
+        assert!(
+            processed.iter().all(|&f| (f - 200.0).abs() < 1e-3),
+            "{processed:?}"
+        );
+        assert_eq!(postprocess(&[], &settings), Vec::<f32>::new());
+    }
+}
@@ -0,0 +1,404 @@
+// Spectral shape descriptors over a frame-averaged magnitude spectrum
+//
+// loqa-voice-dsp's `compute_fft` only accepts power-of-two sizes (and silently
+// truncates longer input), and `analyze_spectrum` reports centroid, tilt and a
+// fixed 95% rolloff. This module picks a valid FFT size for any buffer length,
+// averages the power spectra of 50%-overlapping frames when the buffer is
+// longer than one FFT, and computes the remaining descriptors from the result.
+//
+// Frames are Hann-windowed before the transform (upstream applies no window).
+// Without it a tone between two bins leaks into the whole spectrum, which
+// inflates flatness, entropy and spread and drags the rolloffs upwards; Hann
+// also makes 50% overlap the usual Welch setup. Each frame's power is divided
+// by the window energy, so magnitudes keep upstream's |X| / N scale.
+//
+// Distribution descriptors (centroid, spread, skewness, kurtosis) treat the
+// normalized magnitude spectrum as a probability distribution over frequency,
+// matching upstream's magnitude-weighted centroid. Rolloff, flatness and
+// entropy are computed on the power spectrum.
+
+use crate::fft::{self, Window};
+use crate::logging::loqa_log;
+use loqa_voice_dsp::FFTResult;
+
+/// Smallest FFT size loqa-voice-dsp accepts
+pub(crate) const MIN_FFT_SIZE: usize = 64;
+/// Largest FFT size accepted by the wrapper's exports
+pub(crate) const MAX_FFT_SIZE: usize = 8192;
+
+/// Floor for power values before taking logarithms
+const POWER_FLOOR: f64 = 1e-20;
+
+/// Spectral descriptors of a single (possibly averaged) spectrum
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct SpectralDescriptors {
+    /// Magnitude-weighted mean frequency in Hz
+    pub centroid: f32,
+    /// Standard deviation of frequency around the centroid in Hz
+    pub spread: f32,
+    /// Asymmetry around the centroid (positive = tail towards high frequencies)
+    pub skewness: f32,
+    /// Peakedness around the centroid (3.0 for a Gaussian-shaped spectrum)
+    pub kurtosis: f32,
+    /// Geometric / arithmetic mean of the power spectrum: 1.0 for white noise,
+    /// near 0.0 for a pure tone
+    pub flatness: f32,
+    /// Shannon entropy of the normalized power spectrum, divided by its maximum
+    /// (0.0 = all energy in one bin, 1.0 = energy spread evenly)
+    pub entropy: f32,
+    /// Peak magnitude divided by mean magnitude
+    pub crest_factor: f32,
+    /// Frequency below which `rolloff_low_threshold` of the energy lies, in Hz
+    pub rolloff_low: f32,
+    /// Frequency below which `rolloff_high_threshold` of the energy lies, in Hz
+    pub rolloff_high: f32,
+    /// Spectral tilt in dB/octave, as computed by loqa-voice-dsp
+    pub tilt: f32,
+}
+
+/// Descriptors plus how the spectrum they describe was obtained
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SpectralAnalysis {
+    pub descriptors: SpectralDescriptors,
+    /// FFT size used (after automatic selection)
+    pub fft_size: usize,
+    /// Number of frames averaged into the spectrum
+    pub frame_count: usize,
+}
+
+/// Averages the spectrum of `samples` and describes it
+///
+/// `fft_size` of 0 selects `auto_fft_size(samples.len())`; callers validate
+/// explicit sizes and the rolloff thresholds.
+pub(crate) fn analyze(
+    samples: &[f32],
+    sample_rate: u32,
+    fft_size: usize,
+    average_frames: bool,
+    rolloff_low_threshold: f32,
+    rolloff_high_threshold: f32,
+) -> Result<SpectralAnalysis, String> {
+    let fft_size = if fft_size == 0 {
+        auto_fft_size(samples.len())
+    } else {
+        fft_size
+    };
+    if !average_frames && samples.len() > fft_size {
+        loqa_log!(
+            Warn,
+            "buffer of {} samples truncated to fft_size {fft_size} (average_frames is off)",
+            samples.len()
+        );
+    }
+    let (fft, frame_count) = averaged_spectrum(samples, sample_rate, fft_size, average_frames);
+    loqa_log!(
+        Debug,
+        "spectrum of {} samples: fft_size {fft_size}, {frame_count} frame(s) averaged",
+        samples.len()
+    );
+    Ok(SpectralAnalysis {
+        descriptors: describe(&fft, rolloff_low_threshold, rolloff_high_threshold)?,
+        fft_size,
+        frame_count,
+    })
+}
+
+/// Smallest valid FFT size holding `length` samples, capped at `MAX_FFT_SIZE`
+pub(crate) fn auto_fft_size(length: usize) -> usize {
+    length.next_power_of_two().clamp(MIN_FFT_SIZE, MAX_FFT_SIZE)
+}
+
+/// Hann-windowed magnitude spectrum of `samples` and the number of frames it averages
+///
+/// Buffers shorter than `fft_size` are windowed over their own length and
+/// zero-padded. Longer buffers are either truncated to their first `fft_size`
+/// samples or, with `average_frames`, split into frames with a hop of
+/// `fft_size / 2` whose power spectra are averaged (trailing samples that do not
+/// fill a frame are ignored). Callers validate that `samples` is non-empty and
+/// `fft_size` is a power of two.
+pub(crate) fn averaged_spectrum(
+    samples: &[f32],
+    sample_rate: u32,
+    fft_size: usize,
+    average_frames: bool,
+) -> (FFTResult, usize) {
+    let last_start = if average_frames && samples.len() > fft_size {
+        samples.len() - fft_size
+    } else {
+        0
+    };
+
+    let mut power = vec![0.0_f64; fft_size / 2 + 1];
+    let mut frame_count = 0;
+    for start in (0..=last_start).step_by(fft_size / 2) {
+        let frame = &samples[start..samples.len().min(start + fft_size)];
+        let (bins, _, power_gain) = fft::windowed_fft(frame, fft_size, Window::Hann);
+        // |X|^2 / (N * sum(w^2)) is upstream's (|X| / N)^2 for a rectangular window
+        let scale = fft_size as f64 * power_gain;
+        if scale > 0.0 {
+            for (sum, bin) in power.iter_mut().zip(&bins) {
+                *sum += bin.norm_sqr() as f64 / scale;
+            }
+        }
+        frame_count += 1;
+    }
+
+    let magnitudes = power
+        .iter()
+        .map(|&sum| (sum / frame_count as f64).sqrt() as f32)
+        .collect();
+    let bin_width = sample_rate as f32 / fft_size as f32;
+    (
+        FFTResult {
+            magnitudes,
+            frequencies: (0..power.len()).map(|k| k as f32 * bin_width).collect(),
+            sample_rate,
+        },
+        frame_count,
+    )
+}
+
+/// Computes every descriptor of `fft`
+///
+/// `rolloff_low_threshold` and `rolloff_high_threshold` are energy fractions in
+/// (0, 1], e.g. 0.85 and 0.95. A silent spectrum yields all zeros.
+pub(crate) fn describe(
+    fft: &FFTResult,
+    rolloff_low_threshold: f32,
+    rolloff_high_threshold: f32,
+) -> Result<SpectralDescriptors, String> {
+    let tilt = loqa_voice_dsp::analyze_spectrum(fft)?.tilt;
+
+    let magnitudes: Vec<f64> = fft.magnitudes.iter().map(|&m| m as f64).collect();
+    let frequencies: Vec<f64> = fft.frequencies.iter().map(|&f| f as f64).collect();
+    let magnitude_sum: f64 = magnitudes.iter().sum();
+    if magnitude_sum <= 1e-10 {
+        return Ok(SpectralDescriptors {
+            tilt,
+            ..Default::default()
+        });
+    }
+
+    // Central moments of the magnitude-weighted frequency distribution
+    let moment = |order: i32, centre: f64| -> f64 {
+        magnitudes
+            .iter()
+            .zip(&frequencies)
+            .map(|(m, f)| m * (f - centre).powi(order))
+            .sum::<f64>()
+            / magnitude_sum
+    };
+    let centroid = moment(1, 0.0);
+    let variance = moment(2, centroid);
+    let spread = variance.sqrt();
+    let (skewness, kurtosis) = if spread > 1e-9 {
+        (
+            moment(3, centroid) / spread.powi(3),
+            moment(4, centroid) / variance.powi(2),
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    let power: Vec<f64> = magnitudes.iter().map(|m| m * m).collect();
+    let power_sum: f64 = power.iter().sum();
+    let bins = power.len() as f64;
+
+    let log_mean = power.iter().map(|p| p.max(POWER_FLOOR).ln()).sum::<f64>() / bins;
+    let flatness = log_mean.exp() / (power_sum / bins).max(POWER_FLOOR);
+
+    let entropy = if power.len() > 1 {
+        -power
+            .iter()
+            .map(|p| p / power_sum)
+            .filter(|&q| q > 0.0)
+            .map(|q| q * q.ln())
+            .sum::<f64>()
+            / bins.ln()
+    } else {
+        0.0
+    };
+
+    let peak = magnitudes.iter().fold(0.0_f64, |max, &m| max.max(m));
+    let crest_factor = peak / (magnitude_sum / bins);
+
+    Ok(SpectralDescriptors {
+        centroid: centroid as f32,
+        spread: spread as f32,
+        skewness: skewness as f32,
+        kurtosis: kurtosis as f32,
+        flatness: flatness.clamp(0.0, 1.0) as f32,
+        entropy: entropy.clamp(0.0, 1.0) as f32,
+        crest_factor: crest_factor as f32,
+        rolloff_low: rolloff(&power, &frequencies, power_sum, rolloff_low_threshold),
+        rolloff_high: rolloff(&power, &frequencies, power_sum, rolloff_high_threshold),
+        tilt,
+    })
+}
+
+/// Lowest bin frequency at which the cumulative power reaches `threshold` of the total
+fn rolloff(power: &[f64], frequencies: &[f64], power_sum: f64, threshold: f32) -> f32 {
+    let target = power_sum * threshold as f64;
+    let mut cumulative = 0.0;
+    for (p, &frequency) in power.iter().zip(frequencies) {
+        cumulative += p;
+        if cumulative >= target {
+            return frequency as f32;
+        }
+    }
+    frequencies.last().copied().unwrap_or(0.0) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    /// Builds an `FFTResult` with 100 Hz bins from explicit magnitudes
+    fn spectrum(magnitudes: Vec<f32>) -> FFTResult {
+        let frequencies = (0..magnitudes.len()).map(|i| i as f32 * 100.0).collect();
+        FFTResult {
+            magnitudes,
+            frequencies,
+            sample_rate: 2 * 100 * 64,
+        }
+    }
+
+    #[test]
+    fn test_auto_fft_size() {
+        assert_eq!(auto_fft_size(1), MIN_FFT_SIZE);
+        assert_eq!(auto_fft_size(1000), 1024);
+        assert_eq!(auto_fft_size(1024), 1024);
+        assert_eq!(auto_fft_size(2205), 4096);
+        assert_eq!(auto_fft_size(100_000), MAX_FFT_SIZE);
+    }
+
+    #[test]
+    fn test_flat_spectrum_descriptors() {
+        let descriptors = describe(&spectrum(vec![1.0; 65]), 0.85, 0.95).unwrap();
+
+        // Uniform over 0..6400 Hz: mean 3200, std 6400/sqrt(12) (discrete: slightly more)
+        assert!((descriptors.centroid - 3200.0).abs() < 1e-2);
+        assert!(
+            (descriptors.spread - 1876.0).abs() < 1.0,
+            "{}",
+            descriptors.spread
+        );
+        assert!(descriptors.skewness.abs() < 1e-4);
+        assert!(
+            (descriptors.kurtosis - 1.8).abs() < 0.01,
+            "{}",
+            descriptors.kurtosis
+        );
+        assert!((descriptors.flatness - 1.0).abs() < 1e-6);
+        assert!((descriptors.entropy - 1.0).abs() < 1e-6);
+        assert!((descriptors.crest_factor - 1.0).abs() < 1e-6);
+        // 85% of 65 equal bins is reached at bin 55 (5500 Hz), 95% at bin 61
+        assert_eq!(descriptors.rolloff_low, 5500.0);
+        assert_eq!(descriptors.rolloff_high, 6100.0);
+    }
+
+    #[test]
+    fn test_single_peak_descriptors() {
+        let mut magnitudes = vec![0.0; 65];
+        magnitudes[10] = 1.0;
+        let descriptors = describe(&spectrum(magnitudes), 0.85, 0.95).unwrap();
+
+        assert!((descriptors.centroid - 1000.0).abs() < 1e-3);
+        assert_eq!(descriptors.spread, 0.0);
+        assert!(descriptors.flatness < 1e-6);
+        assert_eq!(descriptors.entropy, 0.0);
+        assert!((descriptors.crest_factor - 65.0).abs() < 1e-3);
+        assert_eq!(descriptors.rolloff_low, 1000.0);
+        assert_eq!(descriptors.rolloff_high, 1000.0);
+    }
+
+    #[test]
+    fn test_skewness_sign_follows_the_tail() {
+        // Energy concentrated low with a tail towards high frequencies
+        let magnitudes: Vec<f32> = (0..65).map(|i| (-(i as f32) / 8.0).exp()).collect();
+        let low_heavy = describe(&spectrum(magnitudes.clone()), 0.85, 0.95).unwrap();
+        let high_heavy = describe(
+            &spectrum(magnitudes.into_iter().rev().collect()),
+            0.85,
+            0.95,
+        )
+        .unwrap();
+
+        assert!(low_heavy.skewness > 0.5, "{}", low_heavy.skewness);
+        assert!(high_heavy.skewness < -0.5, "{}", high_heavy.skewness);
+        assert!(low_heavy.rolloff_low < low_heavy.rolloff_high);
+    }
+
+    #[test]
+    fn test_silence_yields_zero_descriptors() {
+        let descriptors = describe(&spectrum(vec![0.0; 65]), 0.85, 0.95).unwrap();
+        assert_eq!(descriptors, SpectralDescriptors::default());
+    }
+
+    #[test]
+    fn test_averaged_spectrum_frame_count() {
+        let samples: Vec<f32> = (0..10_000)
+            .map(|i| (2.0 * PI * 1000.0 * i as f32 / 16000.0).sin())
+            .collect();
+
+        let (_, frames) = averaged_spectrum(&samples, 16000, 1024, false);
+        assert_eq!(frames, 1);
+
+        // Frames start every 512 samples while a full 1024-sample frame fits
+        let (fft, frames) = averaged_spectrum(&samples, 16000, 1024, true);
+        assert_eq!(frames, (10_000 - 1024) / 512 + 1);
+        assert_eq!(fft.magnitudes.len(), 513);
+
+        let peak = fft
+            .magnitudes
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap()
+            .0;
+        assert_eq!(peak, 64); // 1000 Hz / (16000 / 1024) Hz per bin
+    }
+
+    #[test]
+    fn test_averaging_makes_noise_flatter() {
+        // Deterministic white noise (LCG)
+        let mut state: u32 = 12345;
+        let noise: Vec<f32> = (0..32768)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as f32 / 32768.0 - 1.0
+            })
+            .collect();
+
+        let (single, _) = averaged_spectrum(&noise, 16000, 1024, false);
+        let (averaged, _) = averaged_spectrum(&noise, 16000, 1024, true);
+        let single = describe(&single, 0.85, 0.95).unwrap();
+        let averaged = describe(&averaged, 0.85, 0.95).unwrap();
+
+        // A single periodogram's flatness is ~e^-γ ≈ 0.56; averaging removes the variance
+        assert!(single.flatness < 0.7, "{}", single.flatness);
+        assert!(averaged.flatness > 0.9, "{}", averaged.flatness);
+        assert!(averaged.entropy > 0.98, "{}", averaged.entropy);
+    }
+
+    #[test]
+    fn test_tone_between_bins_does_not_leak() {
+        // 1007.8 Hz sits halfway between bins 64 and 65 (15.625 Hz per bin)
+        let samples: Vec<f32> = (0..16_000)
+            .map(|i| (2.0 * PI * 1007.8125 * i as f32 / 16000.0).sin())
+            .collect();
+
+        for average_frames in [false, true] {
+            let (fft, _) = averaged_spectrum(&samples, 16000, 1024, average_frames);
+            let descriptors = describe(&fft, 0.85, 0.95).unwrap();
+            assert!(descriptors.flatness < 0.01, "{descriptors:?}");
+            assert!(descriptors.entropy < 0.3, "{descriptors:?}");
+            assert!(descriptors.rolloff_high < 1100.0, "{descriptors:?}");
+            assert!(
+                (descriptors.centroid - 1007.8).abs() < 20.0,
+                "{descriptors:?}"
+            );
+        }
+    }
+}
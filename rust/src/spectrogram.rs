@@ -0,0 +1,178 @@
+// Short-time Fourier transform (spectrogram) over a whole buffer
+//
+// Frames start every `hop_size` samples on the same grid as upstream
+// `VoiceAnalyzer::process_buffer`: frame i covers samples
+// [i * hop, i * hop + frame_size) and is timestamped at its first sample,
+// i * hop / sample_rate. Like upstream, a frame is only produced when at least
+// one sample follows it, so with the same frame and hop sizes row i of the
+// spectrogram lines up with entry i of a pitch track.
+//
+// Each frame is windowed and scaled like `fft::spectrum` with amplitude
+// normalization, so an on-bin sine of amplitude A reads A (Linear), A^2 (Power)
+// or 20 * log10(A) dBFS (Decibels) whatever the window.
+
+use crate::fft::Window;
+use realfft::RealFftPlanner;
+
+/// Value stored for each time-frequency cell
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Scaling {
+    /// Window-compensated single-sided amplitude
+    Linear,
+    /// Squared amplitude
+    Power,
+    /// 20 * log10(amplitude), clamped below at `floor_db`
+    Decibels { floor_db: f32 },
+}
+
+/// Row-major `frame_count x bin_count` magnitude matrix
+#[derive(Debug, Clone)]
+pub(crate) struct Spectrogram {
+    /// Frame i occupies `values[i * bin_count..(i + 1) * bin_count]`
+    pub values: Vec<f32>,
+    /// Start of each frame in seconds from buffer start
+    pub timestamps: Vec<f32>,
+    pub frame_count: usize,
+    /// `frame_size / 2 + 1`
+    pub bin_count: usize,
+}
+
+/// Start sample of every frame, matching upstream `process_buffer`
+///
+/// Callers validate that `hop_size` is positive.
+pub(crate) fn frame_starts(
+    length: usize,
+    frame_size: usize,
+    hop_size: usize,
+) -> std::iter::StepBy<std::ops::Range<usize>> {
+    (0..length.saturating_sub(frame_size)).step_by(hop_size)
+}
+
+/// Computes the scaled spectrogram of `samples`
+///
+/// Callers validate that `sample_rate` and `hop_size` are positive and that
+/// `frame_size` is a power of two.
+pub(crate) fn compute(
+    samples: &[f32],
+    sample_rate: u32,
+    frame_size: usize,
+    hop_size: usize,
+    window: Window,
+    scaling: Scaling,
+) -> Spectrogram {
+    let coefficients = window.coefficients(frame_size);
+    let gain: f64 = coefficients.iter().map(|&w| w as f64).sum();
+
+    // One plan and scratch buffer for every frame
+    let fft = RealFftPlanner::<f32>::new().plan_fft_forward(frame_size);
+    let mut input = fft.make_input_vec();
+    let mut bins = fft.make_output_vec();
+    let mut scratch = fft.make_scratch_vec();
+    let bin_count = bins.len();
+    let nyquist = frame_size / 2;
+
+    let starts = frame_starts(samples.len(), frame_size, hop_size);
+    let mut values = Vec::with_capacity(starts.len() * bin_count);
+    let mut timestamps = Vec::with_capacity(starts.len());
+    for start in starts {
+        let frame = &samples[start..start + frame_size];
+        for ((slot, sample), w) in input.iter_mut().zip(frame).zip(&coefficients) {
+            *slot = sample * w;
+        }
+        // Only fails on buffer length mismatches, which the planner rules out
+        fft.process_with_scratch(&mut input, &mut bins, &mut scratch)
+            .expect("realfft buffer sizes");
+
+        values.extend(bins.iter().enumerate().map(|(k, bin)| {
+            let one_sided = if k == 0 || k == nyquist { 1.0 } else { 2.0 };
+            // An all-zero window has no gain to compensate
+            let amplitude = if gain > 0.0 {
+                (one_sided * bin.norm() as f64 / gain) as f32
+            } else {
+                0.0
+            };
+            match scaling {
+                Scaling::Linear => amplitude,
+                Scaling::Power => amplitude * amplitude,
+                Scaling::Decibels { floor_db } => (20.0 * amplitude.log10()).max(floor_db),
+            }
+        }));
+        timestamps.push(start as f32 / sample_rate as f32);
+    }
+
+    Spectrogram {
+        frame_count: timestamps.len(),
+        values,
+        timestamps,
+        bin_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn sine(freq: f32, sample_rate: u32, amplitude: f32, length: usize) -> Vec<f32> {
+        (0..length)
+            .map(|i| amplitude * (2.0 * PI * freq * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_frame_grid_matches_process_buffer() {
+        // Same loop as VoiceAnalyzer::process_buffer: the last frame needs a sample after it
+        assert_eq!(
+            frame_starts(2048, 1024, 512).collect::<Vec<_>>(),
+            vec![0, 512]
+        );
+        assert_eq!(
+            frame_starts(2049, 1024, 512).collect::<Vec<_>>(),
+            vec![0, 512, 1024]
+        );
+        assert_eq!(frame_starts(1024, 1024, 512).count(), 0);
+    }
+
+    #[test]
+    fn test_spectrogram_scaling_and_layout() {
+        // 1000 Hz falls exactly on bin 32 of a 512-point frame at 16 kHz
+        let samples = sine(1000.0, 16000, 0.5, 4000);
+        let linear = compute(&samples, 16000, 512, 256, Window::Hann, Scaling::Linear);
+        assert_eq!(linear.bin_count, 257);
+        assert_eq!(linear.frame_count, 14);
+        assert_eq!(linear.values.len(), 14 * 257);
+        assert_eq!(linear.timestamps[3], 768.0 / 16000.0);
+
+        for frame in linear.values.chunks_exact(linear.bin_count) {
+            assert!((frame[32] - 0.5).abs() < 1e-3, "peak {}", frame[32]);
+        }
+
+        let power = compute(&samples, 16000, 512, 256, Window::Hann, Scaling::Power);
+        assert!((power.values[32] - 0.25).abs() < 1e-3);
+
+        let scaling = Scaling::Decibels { floor_db: -80.0 };
+        let db = compute(&samples, 16000, 512, 256, Window::Hann, scaling);
+        assert!((db.values[32] - 20.0 * 0.5f32.log10()).abs() < 0.02);
+        // Far from the tone the Hann sidelobes are below the floor
+        assert_eq!(db.values[200], -80.0);
+    }
+
+    #[test]
+    fn test_spectrogram_tracks_changing_frequency() {
+        let mut samples = sine(500.0, 16000, 1.0, 2048);
+        samples.extend(sine(2000.0, 16000, 1.0, 2048));
+        let spectrogram = compute(&samples, 16000, 512, 512, Window::Hann, Scaling::Linear);
+
+        let peak_bin = |frame: &[f32]| {
+            (0..frame.len())
+                .max_by(|&a, &b| frame[a].total_cmp(&frame[b]))
+                .unwrap()
+        };
+        let rows: Vec<&[f32]> = spectrogram
+            .values
+            .chunks_exact(spectrogram.bin_count)
+            .collect();
+        assert_eq!(peak_bin(rows[0]), 16);
+        assert_eq!(peak_bin(rows[rows.len() - 1]), 64);
+    }
+}
@@ -0,0 +1,254 @@
+// Streaming pitch analysis over pushes of any size
+//
+// Microphone callbacks deliver 128-4096 samples at a time, which rarely line
+// up with the analysis frame and hop. `StreamingAnalyzer` keeps the samples
+// that no frame has moved past in a ring buffer, runs
+// `VoiceAnalyzer::process_frame` on a frame as soon as its last sample
+// arrives and then drops one hop from the front. Frames therefore start every
+// `hop_size` samples from the first push, as in `process_stream` over the
+// concatenated audio, however the audio was split into chunks.
+//
+// Timestamps are frame starts in seconds since the stream began, computed
+// from a running sample count rather than summed chunk durations, so they
+// stay continuous across pushes and do not drift over long sessions. Frames
+// that upstream cannot analyse (e.g. autocorrelation finding no peak in silence)
+// are reported unvoiced instead of being dropped as `process_stream` does, so
+// the frame grid has no gaps.
+//
+// `flush` zero-pads the tail and emits the remaining frames whose centre lies
+// in pushed audio, then starts a new stream at timestamp 0, as `reset` does.
+
+use crate::logging::loqa_log;
+use loqa_voice_dsp::{AnalysisConfig, PitchResult, VoiceAnalyzer};
+use std::collections::VecDeque;
+
+/// Pitch of one frame plus its position in the stream
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct StreamFrame {
+    /// Frame start in seconds since the stream began
+    pub timestamp: f32,
+    pub pitch: PitchResult,
+}
+
+impl StreamFrame {
+    /// Pitch in Hz, or 0.0 if the frame is unvoiced
+    pub fn frequency(&self) -> f32 {
+        if self.pitch.is_voiced {
+            self.pitch.frequency
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Result reported for frames upstream rejects
+pub(crate) const UNVOICED: PitchResult = PitchResult {
+    frequency: 0.0,
+    confidence: 0.0,
+    is_voiced: false,
+    voiced_probability: 0.0,
+};
+
+/// Frame-by-frame analyzer fed with chunks of any size
+pub(crate) struct StreamingAnalyzer {
+    analyzer: VoiceAnalyzer,
+    sample_rate: u32,
+    frame_size: usize,
+    hop_size: usize,
+    /// Pushed samples not yet dropped; the front is the next frame's start
+    buffer: VecDeque<f32>,
+    /// Samples dropped since the stream began, i.e. the next frame's start
+    consumed: u64,
+}
+
+impl StreamingAnalyzer {
+    /// Creates an analyzer for `config`, which callers have validated
+    pub fn new(config: AnalysisConfig) -> Result<Self, String> {
+        let (sample_rate, frame_size, hop_size) =
+            (config.sample_rate, config.frame_size, config.hop_size);
+        Ok(StreamingAnalyzer {
+            analyzer: VoiceAnalyzer::new(config)?,
+            sample_rate,
+            frame_size,
+            hop_size,
+            buffer: VecDeque::with_capacity(2 * frame_size),
+            consumed: 0,
+        })
+    }
+
+    /// Analyses the frame at the front of the buffer, passes it with its
+    /// samples to `on_frame` and drops one hop
+    ///
+    /// The buffer must hold at least `frame_size` samples.
+    fn next_frame(&mut self, on_frame: &mut impl FnMut(&[f32], StreamFrame)) {
+        let timestamp = (self.consumed as f64 / self.sample_rate as f64) as f32;
+        let frame = &self.buffer.make_contiguous()[..self.frame_size];
+        let pitch = self.analyzer.process_frame(frame).unwrap_or(UNVOICED);
+        on_frame(frame, StreamFrame { timestamp, pitch });
+
+        self.buffer.drain(..self.hop_size.min(self.buffer.len()));
+        self.consumed += self.hop_size as u64;
+    }
+
+    /// Appends `samples` and analyses every frame they complete
+    pub fn push(&mut self, samples: &[f32]) -> Vec<StreamFrame> {
+        let mut frames = Vec::new();
+        self.push_with(samples, |_, frame| frames.push(frame));
+        frames
+    }
+
+    /// Like `push`, but hands each completed frame to `on_frame` together with
+    /// its samples, for analyses that need the audio as well as the pitch
+    pub fn push_with(&mut self, samples: &[f32], mut on_frame: impl FnMut(&[f32], StreamFrame)) {
+        self.buffer.extend(samples);
+        let mut frames = 0;
+        while self.buffer.len() >= self.frame_size {
+            self.next_frame(&mut on_frame);
+            frames += 1;
+        }
+        loqa_log!(
+            Trace,
+            "stream push of {} samples: {frames} frames, {} samples buffered",
+            samples.len(),
+            self.buffer.len()
+        );
+    }
+
+    /// Analyses the zero-padded frames whose centre lies in the buffered
+    /// tail, then resets the stream
+    pub fn flush(&mut self) -> Vec<StreamFrame> {
+        let mut remaining = self.buffer.len();
+        self.buffer.resize(remaining + self.frame_size, 0.0);
+        let mut frames = Vec::new();
+        while remaining > self.frame_size / 2 {
+            self.next_frame(&mut |_, frame| frames.push(frame));
+            remaining = remaining.saturating_sub(self.hop_size);
+        }
+        self.reset();
+        frames
+    }
+
+    /// Discards buffered samples and restarts timestamps at 0
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.consumed = 0;
+        self.analyzer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loqa_voice_dsp::PitchAlgorithm;
+    use std::f32::consts::PI;
+
+    const SAMPLE_RATE: u32 = 16000;
+
+    fn config() -> AnalysisConfig {
+        AnalysisConfig {
+            sample_rate: SAMPLE_RATE,
+            frame_size: 1024,
+            hop_size: 256,
+            algorithm: PitchAlgorithm::PYIN,
+            ..AnalysisConfig::default()
+        }
+    }
+
+    /// 220 Hz tone with a silent gap in the middle
+    fn tone(length: usize) -> Vec<f32> {
+        (0..length)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                if (0.4..0.6).contains(&t) {
+                    0.0
+                } else {
+                    0.5 * (2.0 * PI * 220.0 * t).sin()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_chunked_pushes_match_one_push_and_frame_grid() {
+        let samples = tone(SAMPLE_RATE as usize);
+        let mut whole = StreamingAnalyzer::new(config()).unwrap();
+        let expected = whole.push(&samples);
+
+        // Every frame with its last sample in the buffer, one per hop
+        assert_eq!(expected.len(), (samples.len() - 1024) / 256 + 1);
+        let mut analyzer = VoiceAnalyzer::new(config()).unwrap();
+        for (i, frame) in expected.iter().enumerate() {
+            assert_eq!(frame.timestamp, (i * 256) as f32 / SAMPLE_RATE as f32);
+            let reference = analyzer.process_frame(&samples[i * 256..i * 256 + 1024]);
+            assert_eq!(frame.pitch, reference.unwrap_or(UNVOICED));
+        }
+        assert!(
+            (expected[5].pitch.frequency - 220.0).abs() < 2.0,
+            "{:?}",
+            expected[5]
+        );
+        assert!(!expected[28].pitch.is_voiced, "{:?}", expected[28]);
+
+        let mut chunked = StreamingAnalyzer::new(config()).unwrap();
+        let mut frames = Vec::new();
+        let mut rest = samples.as_slice();
+        for size in [128, 4096, 1, 333, 2048, 700].iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, tail) = rest.split_at((*size).min(rest.len()));
+            frames.extend(chunked.push(chunk));
+            rest = tail;
+        }
+        assert_eq!(frames, expected);
+    }
+
+    #[test]
+    fn test_flush_emits_tail_and_restarts_stream() {
+        let samples = tone(5000);
+        let mut analyzer = StreamingAnalyzer::new(config()).unwrap();
+        let frames = analyzer.push(&samples);
+        assert_eq!(frames.len(), 16);
+
+        // 904 samples remain from 4096; frames starting there and 256 later
+        // have their centre (start + 512) in the audio, one at 4608 would not
+        let tail = analyzer.flush();
+        let starts: Vec<f32> = tail
+            .iter()
+            .map(|f| f.timestamp * SAMPLE_RATE as f32)
+            .collect();
+        assert_eq!(starts, vec![4096.0, 4352.0]);
+        assert!(tail[0].pitch.is_voiced);
+        assert!(analyzer.flush().is_empty());
+
+        // Autocorrelation finds no peak in silence; the frames are kept, unvoiced
+        let mut autocorr = StreamingAnalyzer::new(AnalysisConfig {
+            algorithm: PitchAlgorithm::Autocorr,
+            ..config()
+        })
+        .unwrap();
+        assert_eq!(
+            autocorr.push(&[0.0; 1536]),
+            vec![
+                StreamFrame {
+                    timestamp: 0.0,
+                    pitch: UNVOICED
+                },
+                StreamFrame {
+                    timestamp: 256.0 / SAMPLE_RATE as f32,
+                    pitch: UNVOICED
+                },
+                StreamFrame {
+                    timestamp: 512.0 / SAMPLE_RATE as f32,
+                    pitch: UNVOICED
+                },
+            ]
+        );
+
+        // The next push starts a new stream
+        let restarted = analyzer.push(&samples[..1024]);
+        assert_eq!(restarted.len(), 1);
+        assert_eq!(restarted[0].timestamp, 0.0);
+        assert_eq!(restarted[0].pitch, frames[0].pitch);
+    }
+}
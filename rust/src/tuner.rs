@@ -0,0 +1,414 @@
+// Tuner: nearest note, cents deviation and stability of live pitch
+//
+// Notes are numbered as in MIDI (A4 = 69, middle C = 60) and pitch classes
+// run from C (0) to B (11), with octaves in scientific pitch notation. A
+// temperament gives each pitch class an offset in cents from 12-tone equal
+// temperament; all zeros is equal temperament. Offsets are applied relative
+// to A, so the reference pitch always stays in tune: note m is tuned to
+//
+//   reference · 2^((100·(m - 69) + offset[m mod 12] - offset[A]) / 1200)
+//
+// The nearest note is the one whose tuned frequency is closest in cents.
+// Offsets are limited to ±50 cents, so it lies within two notes of the
+// equal-tempered nearest note and only those five candidates are compared.
+//
+// Upstream detectors report whole-sample periods, which at 16 kHz is up to
+// ±6 cents at 220 Hz, too coarse for a tuner. Each voiced frame's period is
+// therefore refined: the squared difference between the frame and itself
+// delayed by τ is smallest at the period, so it is evaluated at the lags
+// around the detected period and its minimum located by parabolic
+// interpolation.
+//
+// Stability measures how steadily a note is held: the standard deviation of
+// its cents deviation over the last `stability_frames` frames, mapped from 1
+// (no spread) down to 0 at `UNSTABLE_SPREAD_CENTS`, times the fraction of the
+// window filled so far. A note change or an unvoiced frame empties the window.
+//
+// Audio goes through a `StreamingAnalyzer`, so pushes of any size work and a
+// new reading is available every hop; pushes that complete no frame keep the
+// previous reading.
+
+use crate::stream::{StreamFrame, StreamingAnalyzer, UNVOICED};
+use loqa_voice_dsp::AnalysisConfig;
+use std::collections::VecDeque;
+
+/// Pitch classes per octave
+pub(crate) const PITCH_CLASSES: usize = 12;
+
+/// Pitch class of A, which temperament offsets are taken relative to
+const PITCH_CLASS_A: usize = 9;
+
+/// MIDI note number of A4
+const MIDI_A4: i32 = 69;
+
+/// Largest temperament offset from equal temperament in cents
+pub(crate) const MAX_TEMPERAMENT_OFFSET_CENTS: f32 = 50.0;
+
+/// Standard deviation of the cents deviation at which stability reaches 0
+const UNSTABLE_SPREAD_CENTS: f32 = 10.0;
+
+/// Reference pitch, temperament and stability window of a tuner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TunerSettings {
+    /// Frequency of A4 in Hz
+    pub reference_hz: f32,
+    /// Offset of each pitch class from equal temperament in cents, C first
+    pub temperament: [f32; PITCH_CLASSES],
+    /// Frames of one note over which stability is measured (>= 1)
+    pub stability_frames: usize,
+}
+
+/// Nearest tempered note to a frequency
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Note {
+    /// MIDI note number (A4 = 69)
+    pub midi: i32,
+    /// Deviation from the tuned note in cents; positive is sharp
+    pub cents: f32,
+    /// Frequency the note is tuned to under the temperament in Hz
+    pub target_hz: f32,
+}
+
+impl Note {
+    /// Pitch class, 0 (C) to 11 (B)
+    pub fn pitch_class(&self) -> u32 {
+        self.midi.rem_euclid(PITCH_CLASSES as i32) as u32
+    }
+
+    /// Octave in scientific pitch notation (middle C is C4)
+    pub fn octave(&self) -> i32 {
+        self.midi.div_euclid(PITCH_CLASSES as i32) - 1
+    }
+}
+
+/// Latest tuner state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TunerReading {
+    /// Most recent analysed frame, with the refined frequency
+    pub frame: StreamFrame,
+    /// Nearest note, or `None` if the frame is unvoiced
+    pub note: Option<Note>,
+    /// 0.0-1.0: how steadily the note has been held
+    pub stability: f32,
+}
+
+/// Reading before the first frame
+const SILENT: TunerReading = TunerReading {
+    frame: StreamFrame {
+        timestamp: 0.0,
+        pitch: UNVOICED,
+    },
+    note: None,
+    stability: 0.0,
+};
+
+/// Cents of tempered note `midi` above equal-tempered A4
+fn tuned_cents(midi: i32, temperament: &[f32; PITCH_CLASSES]) -> f32 {
+    let pitch_class = midi.rem_euclid(PITCH_CLASSES as i32) as usize;
+    100.0 * (midi - MIDI_A4) as f32 + temperament[pitch_class] - temperament[PITCH_CLASS_A]
+}
+
+/// Finds the tempered note nearest to `frequency`, which must be positive
+///
+/// Temperament offsets must lie within ±`MAX_TEMPERAMENT_OFFSET_CENTS`.
+pub(crate) fn nearest_note(
+    frequency: f32,
+    reference_hz: f32,
+    temperament: &[f32; PITCH_CLASSES],
+) -> Note {
+    let cents = 1200.0 * (frequency / reference_hz).log2();
+    let equal = MIDI_A4 + (cents / 100.0).round() as i32;
+    let midi = (equal - 2..=equal + 2)
+        .min_by(|&a, &b| {
+            let distance = |m: i32| (cents - tuned_cents(m, temperament)).abs();
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or(equal);
+
+    let target = tuned_cents(midi, temperament);
+    Note {
+        midi,
+        cents: cents - target,
+        target_hz: reference_hz * (target / 1200.0).exp2(),
+    }
+}
+
+/// Refines the detected `frequency` of `frame` to a fraction of a sample period
+///
+/// Returns `frequency` unchanged if the difference minimum is not inside the
+/// lags searched or the period is too long for the frame.
+pub(crate) fn refine_frequency(frame: &[f32], sample_rate: u32, frequency: f32) -> f32 {
+    let center = (sample_rate as f32 / frequency).round() as usize;
+    if center < 3 || center + 2 > frame.len() / 2 {
+        return frequency;
+    }
+
+    // Squared differences at lags center-2 ..= center+2 over a common window
+    let window = frame.len() - (center + 2);
+    let difference: Vec<f32> = (center - 2..=center + 2)
+        .map(|lag| {
+            (0..window)
+                .map(|i| (frame[i] - frame[i + lag]).powi(2))
+                .sum()
+        })
+        .collect();
+
+    let k = (1..4)
+        .min_by(|&a, &b| difference[a].total_cmp(&difference[b]))
+        .unwrap_or(2);
+    let (before, at, after) = (difference[k - 1], difference[k], difference[k + 1]);
+    let curvature = before - 2.0 * at + after;
+    if at > before || at > after || curvature <= 0.0 {
+        return frequency;
+    }
+
+    let period = (center - 2 + k) as f32 + 0.5 * (before - after) / curvature;
+    sample_rate as f32 / period
+}
+
+/// Low-latency tuner fed with chunks of any size
+pub(crate) struct Tuner {
+    stream: StreamingAnalyzer,
+    sample_rate: u32,
+    settings: TunerSettings,
+    /// Cents deviations of the current note, oldest first
+    history: VecDeque<f32>,
+    reading: TunerReading,
+}
+
+impl Tuner {
+    /// Creates a tuner; callers have validated `config` and `settings`
+    pub fn new(config: AnalysisConfig, settings: TunerSettings) -> Result<Self, String> {
+        Ok(Tuner {
+            sample_rate: config.sample_rate,
+            stream: StreamingAnalyzer::new(config)?,
+            settings,
+            history: VecDeque::with_capacity(settings.stability_frames),
+            reading: SILENT,
+        })
+    }
+
+    /// Updates the reading with one analysed frame
+    fn update(&mut self, frame: StreamFrame) {
+        let note = (frame.frequency() > 0.0).then(|| {
+            nearest_note(
+                frame.frequency(),
+                self.settings.reference_hz,
+                &self.settings.temperament,
+            )
+        });
+
+        let same_note = matches!(
+            (note, self.reading.note),
+            (Some(current), Some(previous)) if current.midi == previous.midi
+        );
+        if !same_note {
+            self.history.clear();
+        }
+
+        let stability = match note {
+            Some(note) => {
+                if self.history.len() == self.settings.stability_frames {
+                    self.history.pop_front();
+                }
+                self.history.push_back(note.cents);
+
+                let count = self.history.len() as f32;
+                let mean = self.history.iter().sum::<f32>() / count;
+                let variance = self.history.iter().map(|c| (c - mean).powi(2)).sum::<f32>() / count;
+                let steadiness = (1.0 - variance.sqrt() / UNSTABLE_SPREAD_CENTS).clamp(0.0, 1.0);
+                steadiness * count / self.settings.stability_frames as f32
+            }
+            None => 0.0,
+        };
+
+        self.reading = TunerReading {
+            frame,
+            note,
+            stability,
+        };
+    }
+
+    /// Appends `samples`, analyses every frame they complete and returns the
+    /// number of new frames with the latest reading
+    pub fn push(&mut self, samples: &[f32]) -> (usize, TunerReading) {
+        let sample_rate = self.sample_rate;
+        let mut frames = Vec::new();
+        self.stream.push_with(samples, |audio, mut frame| {
+            if frame.frequency() > 0.0 {
+                frame.pitch.frequency = refine_frequency(audio, sample_rate, frame.pitch.frequency);
+            }
+            frames.push(frame);
+        });
+
+        for &frame in &frames {
+            self.update(frame);
+        }
+        (frames.len(), self.reading)
+    }
+
+    /// Discards buffered audio and the reading; timestamps restart at 0
+    pub fn reset(&mut self) {
+        self.stream.reset();
+        self.history.clear();
+        self.reading = SILENT;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loqa_voice_dsp::{PitchAlgorithm, PitchResult};
+
+    const EQUAL: [f32; PITCH_CLASSES] = [0.0; PITCH_CLASSES];
+
+    fn settings(stability_frames: usize) -> TunerSettings {
+        TunerSettings {
+            reference_hz: 440.0,
+            temperament: EQUAL,
+            stability_frames,
+        }
+    }
+
+    fn frame(frequency: f32) -> StreamFrame {
+        let pitch = PitchResult {
+            frequency,
+            confidence: 0.9,
+            is_voiced: frequency > 0.0,
+            voiced_probability: 0.9,
+        };
+        StreamFrame {
+            timestamp: 0.0,
+            pitch,
+        }
+    }
+
+    #[test]
+    fn test_nearest_note_in_equal_temperament() {
+        let a4 = nearest_note(440.0, 440.0, &EQUAL);
+        assert_eq!((a4.midi, a4.pitch_class(), a4.octave()), (69, 9, 4));
+        assert!(a4.cents.abs() < 1e-3);
+
+        let middle_c = nearest_note(261.63, 440.0, &EQUAL);
+        assert_eq!(
+            (middle_c.midi, middle_c.pitch_class(), middle_c.octave()),
+            (60, 0, 4)
+        );
+        assert!(middle_c.cents.abs() < 0.1, "{middle_c:?}");
+
+        // 450 Hz is 38.9 cents sharp of A4; B0 sits in octave 0
+        let sharp = nearest_note(450.0, 440.0, &EQUAL);
+        assert!((sharp.cents - 38.9).abs() < 0.1, "{sharp:?}");
+        let b0 = nearest_note(30.87, 440.0, &EQUAL);
+        assert_eq!((b0.midi, b0.pitch_class(), b0.octave()), (23, 11, 0));
+
+        // Baroque pitch: A4 = 415 Hz
+        let baroque = nearest_note(415.0, 415.0, &EQUAL);
+        assert_eq!(baroque.midi, 69);
+        assert!((baroque.target_hz - 415.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_temperament_offsets_are_relative_to_a() {
+        // Just major third and sixth over C: E -13.7, A -15.6 cents
+        let mut just = EQUAL;
+        just[4] = -13.7;
+        just[9] = -15.6;
+
+        let a4 = nearest_note(440.0, 440.0, &just);
+        assert_eq!(a4.midi, 69);
+        assert!(a4.cents.abs() < 1e-3);
+
+        // C is tuned 15.6 cents above its equal-tempered pitch, E 1.9 cents
+        let c4 = nearest_note(261.63, 440.0, &just);
+        assert_eq!(c4.midi, 60);
+        assert!((c4.cents + 15.6).abs() < 0.1, "{c4:?}");
+        let e4 = nearest_note(329.63, 440.0, &just);
+        assert!((e4.cents + 1.9).abs() < 0.1, "{e4:?}");
+        assert!((e4.target_hz - 329.63 * (1.9f32 / 1200.0).exp2()).abs() < 0.01);
+
+        // A flat C# is nearest for pitches it pulls closer than C
+        let mut flat = EQUAL;
+        flat[1] = -50.0;
+        let pitch = 261.63 * (40.0f32 / 1200.0).exp2();
+        assert_eq!(nearest_note(pitch, 440.0, &EQUAL).midi, 60);
+        let note = nearest_note(pitch, 440.0, &flat);
+        assert_eq!(note.midi, 61);
+        assert!((note.cents + 10.0).abs() < 0.1, "{note:?}");
+    }
+
+    #[test]
+    fn test_refine_frequency_interpolates_the_period() {
+        // 220 Hz at 16 kHz has a period of 72.73 samples; 73 would be 6.5 cents flat
+        let tone: Vec<f32> = (0..1024)
+            .map(|i| (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+            .collect();
+        let refined = refine_frequency(&tone, 16000, 16000.0 / 73.0);
+        assert!(
+            (1200.0 * (refined / 220.0).log2()).abs() < 0.5,
+            "refined {refined}"
+        );
+
+        // Periods too long for the frame are left alone
+        assert_eq!(refine_frequency(&tone[..100], 16000, 220.0), 220.0);
+    }
+
+    #[test]
+    fn test_stability_grows_while_held_and_resets_on_change() {
+        let config = AnalysisConfig::default();
+        let mut tuner = Tuner::new(config, settings(4)).unwrap();
+
+        let mut stabilities = Vec::new();
+        for _ in 0..5 {
+            tuner.update(frame(440.0));
+            stabilities.push(tuner.reading.stability);
+        }
+        assert_eq!(stabilities, vec![0.25, 0.5, 0.75, 1.0, 1.0]);
+
+        // Swinging ±10 cents is unstable
+        for i in 0..4 {
+            let cents = if i % 2 == 0 { 10.0 } else { -10.0 };
+            tuner.update(frame(440.0 * (cents / 1200.0f32).exp2()));
+        }
+        assert_eq!(tuner.reading.stability, 0.0);
+
+        // A new note or a gap starts the window again
+        tuner.update(frame(466.16));
+        assert_eq!(tuner.reading.note.unwrap().midi, 70);
+        assert_eq!(tuner.reading.stability, 0.25);
+        tuner.update(frame(0.0));
+        assert_eq!((tuner.reading.note, tuner.reading.stability), (None, 0.0));
+    }
+
+    #[test]
+    fn test_push_reads_a_tone_and_holds_between_frames() {
+        let config = AnalysisConfig {
+            sample_rate: 16000,
+            frame_size: 1024,
+            hop_size: 256,
+            algorithm: PitchAlgorithm::YIN,
+            ..AnalysisConfig::default()
+        };
+        let mut tuner = Tuner::new(config, settings(8)).unwrap();
+        let tone: Vec<f32> = (0..8192)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+            .collect();
+
+        let (count, reading) = tuner.push(&tone[..1000]);
+        assert_eq!((count, reading), (0, SILENT));
+
+        let (count, reading) = tuner.push(&tone[1000..]);
+        assert_eq!(count, 29);
+        let note = reading.note.unwrap();
+        assert_eq!((note.midi, note.pitch_class(), note.octave()), (57, 9, 3));
+        assert!(note.cents.abs() < 1.0, "{note:?}");
+        assert!(reading.stability > 0.8, "{reading:?}");
+        assert_eq!(reading.frame.timestamp, 28.0 * 256.0 / 16000.0);
+
+        // Too few samples for a new frame keeps the reading
+        assert_eq!(tuner.push(&tone[..100]), (0, reading));
+
+        tuner.reset();
+        assert_eq!(tuner.push(&[]), (0, SILENT));
+    }
+}
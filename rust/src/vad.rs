@@ -0,0 +1,228 @@
+// Zero-crossing rate and voice activity detection (VAD)
+//
+// Frames use the `spectrogram::frame_starts` grid, which is also the grid of
+// upstream `process_buffer`, so frame i has an energy (the RMS level from
+// `intensity::contour`), a zero-crossing rate and a pYIN voicing probability.
+//
+// The zero-crossing rate is given in crossings per second so that thresholds
+// do not depend on the sample rate: a sine at f Hz crosses 2f times a second,
+// vowels (dominated by F1) stay around 1000-2000, and fricatives such as /s/
+// reach several thousand.
+//
+// A frame counts as speech when its energy reaches `energy_threshold_db` and
+// it is either voiced (voicing probability >= `voicing_threshold`) or noisy
+// enough to be an unvoiced consonant (zero-crossing rate >= `zcr_threshold`).
+// Quiet frames are never speech, whatever their voicing or ZCR. The decision
+// then holds for `hangover_frames` frames after the last speech frame, which
+// bridges short pauses and keeps word-final consonants and decays.
+//
+// Consecutive speech frames form a segment from the first frame's start to
+// the last frame's end (clamped to the buffer); segments whose samples
+// overlap, as they do across gaps shorter than a frame, are merged.
+
+use crate::spectrogram::frame_starts;
+
+/// Thresholds of the per-frame speech decision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VadThresholds {
+    /// Lowest RMS level of a speech frame in dBFS
+    pub energy_db: f32,
+    /// Zero crossings per second at or above which a non-voiced frame is speech
+    pub zcr: f32,
+    /// pYIN voicing probability at or above which a frame is voiced
+    pub voicing: f32,
+    /// Frames kept as speech after the last frame that passed the thresholds
+    pub hangover_frames: usize,
+}
+
+/// Speech segment as a half-open sample range `[start, end)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Segment {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Framewise VAD features, decisions and merged speech segments
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VoiceActivity {
+    /// Start of each frame in seconds from buffer start
+    pub timestamps: Vec<f32>,
+    /// RMS level of each frame in dBFS
+    pub energy_db: Vec<f32>,
+    /// Zero crossings per second of each frame
+    pub zcr: Vec<f32>,
+    /// pYIN voicing probability of each frame
+    pub voiced_probabilities: Vec<f32>,
+    /// Speech decision of each frame, after hangover
+    pub is_speech: Vec<bool>,
+    pub segments: Vec<Segment>,
+}
+
+/// Zero crossings per second of `frame`
+///
+/// A crossing is a sign change between consecutive samples; zeros count as
+/// positive, so digital silence has no crossings.
+pub(crate) fn zero_crossing_rate(frame: &[f32], sample_rate: u32) -> f32 {
+    if frame.len() < 2 {
+        return 0.0;
+    }
+    let crossings = frame
+        .windows(2)
+        .filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0))
+        .count();
+    crossings as f32 * sample_rate as f32 / (frame.len() - 1) as f32
+}
+
+/// Applies the thresholds and hangover to per-frame features
+fn decide(
+    energy_db: &[f32],
+    zcr: &[f32],
+    voiced_probabilities: &[f32],
+    thresholds: &VadThresholds,
+) -> Vec<bool> {
+    let mut hangover = 0;
+    energy_db
+        .iter()
+        .zip(zcr)
+        .zip(voiced_probabilities)
+        .map(|((&energy, &zcr), &voicing)| {
+            let active = energy >= thresholds.energy_db
+                && (voicing >= thresholds.voicing || zcr >= thresholds.zcr);
+            if active {
+                hangover = thresholds.hangover_frames;
+                true
+            } else if hangover > 0 {
+                hangover -= 1;
+                true
+            } else {
+                false
+            }
+        })
+        .collect()
+}
+
+/// Merged sample ranges of the runs of speech frames
+fn segments(
+    is_speech: &[bool],
+    starts: &[usize],
+    frame_size: usize,
+    length: usize,
+) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for (&speech, &start) in is_speech.iter().zip(starts) {
+        if !speech {
+            continue;
+        }
+        let end = (start + frame_size).min(length);
+        match segments.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => segments.push(Segment { start, end }),
+        }
+    }
+    segments
+}
+
+/// Runs the VAD on `samples` given the voicing probabilities of its frames
+///
+/// `voiced_probabilities` comes from `process_buffer` with the same frame and
+/// hop sizes, so it has one entry per frame. Callers validate that the sample
+/// rate and hop size are positive and that `samples` is longer than `frame_size`.
+pub(crate) fn detect(
+    samples: &[f32],
+    sample_rate: u32,
+    frame_size: usize,
+    hop_size: usize,
+    voiced_probabilities: &[f32],
+    thresholds: &VadThresholds,
+) -> VoiceActivity {
+    let starts: Vec<usize> = frame_starts(samples.len(), frame_size, hop_size).collect();
+    let levels = crate::intensity::contour(samples, sample_rate, frame_size, hop_size, 0.0, -120.0);
+    let zcr: Vec<f32> = starts
+        .iter()
+        .map(|&start| zero_crossing_rate(&samples[start..start + frame_size], sample_rate))
+        .collect();
+
+    let is_speech = decide(&levels.intensity_db, &zcr, voiced_probabilities, thresholds);
+    let segments = segments(&is_speech, &starts, frame_size, samples.len());
+
+    VoiceActivity {
+        timestamps: levels.timestamps,
+        energy_db: levels.intensity_db,
+        zcr,
+        voiced_probabilities: voiced_probabilities.to_vec(),
+        is_speech,
+        segments,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: VadThresholds = VadThresholds {
+        energy_db: -40.0,
+        zcr: 3000.0,
+        voicing: 0.5,
+        hangover_frames: 2,
+    };
+
+    #[test]
+    fn test_zero_crossing_rate_of_sine() {
+        let sine: Vec<f32> = (0..16001)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * (i as f32 + 0.5) / 16000.0).sin())
+            .collect();
+        let zcr = zero_crossing_rate(&sine, 16000);
+        assert!((zcr - 880.0).abs() < 2.0, "zcr {zcr}");
+        assert_eq!(zero_crossing_rate(&[0.0; 64], 16000), 0.0);
+        assert_eq!(zero_crossing_rate(&[1.0], 16000), 0.0);
+    }
+
+    #[test]
+    fn test_decision_needs_energy_and_voicing_or_zcr() {
+        let energy = [-60.0, -20.0, -20.0, -20.0, -60.0];
+        let zcr = [9000.0, 500.0, 500.0, 5000.0, 500.0];
+        let voicing = [0.9, 0.9, 0.1, 0.1, 0.9];
+        let no_hangover = VadThresholds {
+            hangover_frames: 0,
+            ..THRESHOLDS
+        };
+        assert_eq!(
+            decide(&energy, &zcr, &voicing, &no_hangover),
+            vec![false, true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_hangover_bridges_pauses_and_segments_merge() {
+        let speech = |pattern: &str| pattern.chars().map(|c| c == '1').collect::<Vec<bool>>();
+        let active = speech("0110000001000");
+        let energy: Vec<f32> = active
+            .iter()
+            .map(|&a| if a { -20.0 } else { -80.0 })
+            .collect();
+        let zcr = vec![0.0; active.len()];
+        let voicing = vec![1.0; active.len()];
+
+        let is_speech = decide(&energy, &zcr, &voicing, &THRESHOLDS);
+        assert_eq!(is_speech, speech("0111100001110"));
+
+        // 100-sample frames every 50 samples: runs separated by a gap shorter
+        // than a frame overlap and merge, longer gaps split
+        let starts: Vec<usize> = (0..is_speech.len()).map(|i| i * 50).collect();
+        let result = segments(&is_speech, &starts, 100, 640);
+        assert_eq!(
+            result,
+            vec![
+                Segment {
+                    start: 50,
+                    end: 300
+                },
+                Segment {
+                    start: 450,
+                    end: 640
+                }
+            ]
+        );
+        assert_eq!(segments(&speech("1011"), &starts, 100, 10_000).len(), 1);
+    }
+}
@@ -0,0 +1,436 @@
+// Vibrato detection in pitch tracks: rate, extent, cycle regularity and onset
+//
+// Tracks use the `process_buffer` convention of one pitch per frame with 0.0
+// for unvoiced frames, and frame i sits at i / frame_rate seconds. Each run of
+// voiced frames is analysed on its own, in cents:
+//
+//   detrending: the slow melody is removed by subtracting a centred moving
+//     average one slowest vibrato period long (truncated at the run ends).
+//   extrema: peaks and troughs of the residual are picked with hysteresis, so
+//     a reversal only counts once it exceeds `min_extent_cents`; tracker
+//     noise smaller than that never splits a cycle. Times are refined by
+//     parabolic interpolation.
+//   extent: the half-excursion of each extremum from the mean of its two
+//     neighbours in the original contour (one neighbour at the ends), which
+//     cancels a linear glide. A sinusoid of ±A cents gives A.
+//   segments: maximal chains of extrema whose extents lie within
+//     [min_extent_cents, max_extent_cents] and whose half-cycles give a rate
+//     within [min_rate_hz, max_rate_hz], with at least `min_cycles` cycles.
+//
+// A segment's rate is its half-cycles over twice its duration. The rate jitter
+// is the mean absolute difference of consecutive periods (between extrema two
+// apart) over the mean period, and the extent jitter the same for consecutive
+// extents; both are fractions (0.01 = 1 %), 0 for a perfectly regular vibrato.
+// The onset delay runs from the first frame of the voiced run to the first
+// extremum of the segment.
+
+/// Detection ranges; callers validate that they are positive and ordered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VibratoSettings {
+    /// Pitch frames per second
+    pub frame_rate: f64,
+    pub min_rate_hz: f64,
+    pub max_rate_hz: f64,
+    /// Smallest extent (± cents) of a vibrato cycle; also the picking hysteresis
+    pub min_extent_cents: f64,
+    pub max_extent_cents: f64,
+    /// Fewest full cycles in a segment (>= 2, so jitter is always defined)
+    pub min_cycles: usize,
+}
+
+/// One stretch of regular vibrato
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VibratoSegment {
+    /// Time of the first extremum in seconds
+    pub start: f64,
+    /// Time of the last extremum in seconds
+    pub end: f64,
+    /// Half-cycles (extrema - 1) in the segment
+    pub half_cycles: usize,
+    pub rate_hz: f64,
+    /// Mean extent in ± cents
+    pub extent_cents: f64,
+    pub rate_jitter: f64,
+    pub extent_jitter: f64,
+    /// Seconds from the start of the voiced run to `start`
+    pub onset_delay: f64,
+    /// Whether this is the run's first segment, so `onset_delay` is a note onset
+    pub first_in_run: bool,
+}
+
+/// Vibrato over a whole track or window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VibratoSummary {
+    /// Seconds covered by segments over voiced seconds (0 without voiced frames)
+    pub vibrato_ratio: f64,
+    /// All segments' half-cycles over twice their total duration
+    pub rate_hz: Option<f64>,
+    /// Mean extent over all segment extrema
+    pub extent_cents: Option<f64>,
+    /// Segment jitters weighted by half-cycles
+    pub rate_jitter: Option<f64>,
+    pub extent_jitter: Option<f64>,
+    /// Mean onset delay of the first segment of each voiced run
+    pub onset_delay: Option<f64>,
+}
+
+/// Segments and summary of one track
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Vibrato {
+    pub segments: Vec<VibratoSegment>,
+    pub summary: VibratoSummary,
+}
+
+/// Summary of the window ending at one frame of `sliding`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VibratoWindow {
+    /// Time of the window's last frame in seconds
+    pub time: f64,
+    pub summary: VibratoSummary,
+}
+
+/// A peak or trough of one voiced run
+#[derive(Debug, Clone, Copy)]
+struct Extremum {
+    /// Refined time in seconds
+    time: f64,
+    /// Half-excursion from the neighbouring extrema in cents
+    extent: f64,
+}
+
+/// Indices of the residual's confirmed extrema, alternating peaks and troughs
+///
+/// An extremum is confirmed once the residual moves `hysteresis` away from it
+/// after having approached it by as much, so the first and last turning points
+/// of a run, where a straight tone may drift into vibrato, are never reported.
+fn pick_extrema(residual: &[f64], hysteresis: f64) -> Vec<usize> {
+    let mut extrema = Vec::new();
+    let (mut low, mut high) = (0, 0);
+    let mut rising = None;
+    for (k, &value) in residual.iter().enumerate().skip(1) {
+        match rising {
+            None => {
+                if value > residual[high] {
+                    high = k;
+                }
+                if value < residual[low] {
+                    low = k;
+                }
+                if residual[high] - residual[low] > hysteresis {
+                    rising = Some(low < high);
+                }
+            }
+            Some(true) => {
+                if value > residual[high] {
+                    high = k;
+                } else if residual[high] - value > hysteresis {
+                    extrema.push(high);
+                    (low, rising) = (k, Some(false));
+                }
+            }
+            Some(false) => {
+                if value < residual[low] {
+                    low = k;
+                } else if value - residual[low] > hysteresis {
+                    extrema.push(low);
+                    (high, rising) = (k, Some(true));
+                }
+            }
+        }
+    }
+    extrema
+}
+
+/// Extrema of one voiced run of cents starting at frame `offset`
+fn run_extrema(cents: &[f64], offset: usize, settings: &VibratoSettings) -> Vec<Extremum> {
+    let n = cents.len();
+    let half = ((settings.frame_rate / settings.min_rate_hz).round() as usize / 2).max(1);
+    let mut prefix = vec![0.0; n + 1];
+    for (k, &c) in cents.iter().enumerate() {
+        prefix[k + 1] = prefix[k] + c;
+    }
+    let residual: Vec<f64> = (0..n)
+        .map(|k| {
+            let (lo, hi) = (k.saturating_sub(half), (k + half + 1).min(n));
+            cents[k] - (prefix[hi] - prefix[lo]) / (hi - lo) as f64
+        })
+        .collect();
+
+    let picked = pick_extrema(&residual, settings.min_extent_cents);
+    picked
+        .iter()
+        .enumerate()
+        .map(|(i, &k)| {
+            let (a, b, c) = (residual[k - 1], residual[k], residual[k + 1]);
+            let curvature = a - 2.0 * b + c;
+            let shift = if curvature == 0.0 {
+                0.0
+            } else {
+                (0.5 * (a - c) / curvature).clamp(-0.5, 0.5)
+            };
+            let neighbours: Vec<f64> = [i.checked_sub(1), Some(i + 1)]
+                .into_iter()
+                .flatten()
+                .filter_map(|j| picked.get(j).map(|&m| cents[m]))
+                .collect();
+            let extent = if neighbours.is_empty() {
+                0.0
+            } else {
+                let mean = neighbours.iter().sum::<f64>() / neighbours.len() as f64;
+                (cents[k] - mean).abs() / 2.0
+            };
+            Extremum {
+                time: ((offset + k) as f64 + shift) / settings.frame_rate,
+                extent,
+            }
+        })
+        .collect()
+}
+
+/// Mean absolute difference of consecutive values over their mean
+fn relative_jitter(values: &[f64]) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let diffs: f64 = values.windows(2).map(|w| (w[1] - w[0]).abs()).sum();
+    diffs / (values.len() - 1) as f64 / mean
+}
+
+/// Builds the segment spanning `extrema`, or None if it has too few cycles
+fn segment(
+    extrema: &[Extremum],
+    run_start: f64,
+    first_in_run: bool,
+    settings: &VibratoSettings,
+) -> Option<VibratoSegment> {
+    let half_cycles = extrema.len().checked_sub(1)?;
+    if half_cycles < 2 * settings.min_cycles {
+        return None;
+    }
+    let (start, end) = (extrema[0].time, extrema[half_cycles].time);
+    let periods: Vec<f64> = extrema.windows(3).map(|w| w[2].time - w[0].time).collect();
+    let extents: Vec<f64> = extrema.iter().map(|e| e.extent).collect();
+    Some(VibratoSegment {
+        start,
+        end,
+        half_cycles,
+        rate_hz: half_cycles as f64 / (2.0 * (end - start)),
+        extent_cents: extents.iter().sum::<f64>() / extents.len() as f64,
+        rate_jitter: relative_jitter(&periods),
+        extent_jitter: relative_jitter(&extents),
+        onset_delay: start - run_start,
+        first_in_run,
+    })
+}
+
+/// Pools segments into a summary over `voiced_frames` voiced frames
+fn summarize(segments: &[VibratoSegment], voiced_frames: usize, frame_rate: f64) -> VibratoSummary {
+    let duration: f64 = segments.iter().map(|s| s.end - s.start).sum();
+    let half_cycles: usize = segments.iter().map(|s| s.half_cycles).sum();
+    let weighted = |pick: fn(&VibratoSegment) -> f64, weight: fn(&VibratoSegment) -> usize| {
+        let total: usize = segments.iter().map(weight).sum();
+        (total > 0).then(|| {
+            segments
+                .iter()
+                .map(|s| pick(s) * weight(s) as f64)
+                .sum::<f64>()
+                / total as f64
+        })
+    };
+    let onsets: Vec<f64> = segments
+        .iter()
+        .filter(|s| s.first_in_run)
+        .map(|s| s.onset_delay)
+        .collect();
+
+    VibratoSummary {
+        vibrato_ratio: if voiced_frames == 0 {
+            0.0
+        } else {
+            (duration * frame_rate / voiced_frames as f64).min(1.0)
+        },
+        rate_hz: (half_cycles > 0).then(|| half_cycles as f64 / (2.0 * duration)),
+        extent_cents: weighted(|s| s.extent_cents, |s| s.half_cycles + 1),
+        rate_jitter: weighted(|s| s.rate_jitter, |s| s.half_cycles),
+        extent_jitter: weighted(|s| s.extent_jitter, |s| s.half_cycles),
+        onset_delay: (!onsets.is_empty()).then(|| onsets.iter().sum::<f64>() / onsets.len() as f64),
+    }
+}
+
+/// Finds the vibrato segments of `pitch_hz`
+///
+/// Callers validate that pitches are finite and non-negative.
+pub(crate) fn analyze(pitch_hz: &[f32], settings: &VibratoSettings) -> Vibrato {
+    let mut segments = Vec::new();
+    let mut voiced_frames = 0;
+    let mut k = 0;
+    while k < pitch_hz.len() {
+        if pitch_hz[k] <= 0.0 {
+            k += 1;
+            continue;
+        }
+        let run_start = k;
+        while k < pitch_hz.len() && pitch_hz[k] > 0.0 {
+            k += 1;
+        }
+        voiced_frames += k - run_start;
+
+        let cents: Vec<f64> = pitch_hz[run_start..k]
+            .iter()
+            .map(|&f| 1200.0 * (f as f64).log2())
+            .collect();
+        let extrema = run_extrema(&cents, run_start, settings);
+        let run_time = run_start as f64 / settings.frame_rate;
+        let (min_half, max_half) = (0.5 / settings.max_rate_hz, 0.5 / settings.min_rate_hz);
+        let extents = settings.min_extent_cents..=settings.max_extent_cents;
+        let extent_ok = |e: &Extremum| extents.contains(&e.extent);
+
+        // Split the extrema into chains of valid extrema joined by valid half-cycles
+        let mut chain_start: Option<usize> = None;
+        let mut first_in_run = true;
+        for i in 0..=extrema.len() {
+            let joins = i < extrema.len()
+                && extent_ok(&extrema[i])
+                && chain_start.is_some()
+                && (min_half..=max_half).contains(&(extrema[i].time - extrema[i - 1].time));
+            if joins {
+                continue;
+            }
+            if let Some(start) = chain_start.take() {
+                if let Some(found) = segment(&extrema[start..i], run_time, first_in_run, settings) {
+                    segments.push(found);
+                    first_in_run = false;
+                }
+            }
+            if i < extrema.len() && extent_ok(&extrema[i]) {
+                chain_start = Some(i);
+            }
+        }
+    }
+
+    let summary = summarize(&segments, voiced_frames, settings.frame_rate);
+    Vibrato { segments, summary }
+}
+
+/// Summaries of the windows of `window_frames` frames ending every
+/// `hop_frames` frames, counted back from the last frame
+///
+/// Windows are in time order and the last one always ends at the last frame,
+/// for live display. A track shorter than the window gives one window over the
+/// whole track; an empty track gives none. Onset delays within a window are
+/// measured from the window start when a run is cut by it.
+pub(crate) fn sliding(
+    pitch_hz: &[f32],
+    settings: &VibratoSettings,
+    window_frames: usize,
+    hop_frames: usize,
+) -> Vec<VibratoWindow> {
+    let n = pitch_hz.len();
+    let mut ends: Vec<usize> = if n == 0 {
+        Vec::new()
+    } else if n <= window_frames {
+        vec![n]
+    } else {
+        (window_frames..=n).rev().step_by(hop_frames).collect()
+    };
+    ends.reverse();
+
+    ends.into_iter()
+        .map(|end| {
+            let start = end.saturating_sub(window_frames);
+            let summary = analyze(&pitch_hz[start..end], settings).summary;
+            VibratoWindow {
+                time: (end - 1) as f64 / settings.frame_rate,
+                summary,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> VibratoSettings {
+        VibratoSettings {
+            frame_rate: 100.0,
+            min_rate_hz: 4.0,
+            max_rate_hz: 8.0,
+            min_extent_cents: 15.0,
+            max_extent_cents: 200.0,
+            min_cycles: 2,
+        }
+    }
+
+    /// `straight` seconds of 220 Hz, then a 5.5 Hz ±50 cent vibrato for `wobble` seconds
+    fn note(straight: f64, wobble: f64) -> Vec<f32> {
+        let frames = ((straight + wobble) * 100.0) as usize;
+        (0..frames)
+            .map(|k| {
+                let t = k as f64 / 100.0 - straight;
+                let cents = if t > 0.0 {
+                    50.0 * (2.0 * std::f64::consts::PI * 5.5 * t).sin()
+                } else {
+                    0.0
+                };
+                (220.0 * (cents / 1200.0).exp2()) as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sinusoidal_vibrato_gives_rate_extent_and_onset() {
+        let vibrato = analyze(&note(0.4, 2.0), &settings());
+        assert_eq!(vibrato.segments.len(), 1, "{vibrato:?}");
+
+        let summary = vibrato.summary;
+        assert!((summary.rate_hz.unwrap() - 5.5).abs() < 0.1, "{summary:?}");
+        assert!(
+            (summary.extent_cents.unwrap() - 50.0).abs() < 3.0,
+            "{summary:?}"
+        );
+        assert!(summary.rate_jitter.unwrap() < 0.05 && summary.extent_jitter.unwrap() < 0.05);
+        // The first extremum is a quarter cycle after the vibrato starts
+        assert!(
+            (summary.onset_delay.unwrap() - 0.445).abs() < 0.03,
+            "{summary:?}"
+        );
+        assert!(
+            summary.vibrato_ratio > 0.7 && summary.vibrato_ratio < 0.85,
+            "{summary:?}"
+        );
+    }
+
+    #[test]
+    fn test_straight_tones_glides_and_slow_wobbles_are_not_vibrato() {
+        let straight = vec![220.0f32; 200];
+        let glide: Vec<f32> = (0..200)
+            .map(|k| 220.0 * (k as f32 / 200.0).exp2())
+            .collect();
+        let wobble: Vec<f32> = (0..300)
+            .map(|k| {
+                let cents = 50.0 * (2.0 * std::f64::consts::PI * 2.0 * k as f64 / 100.0).sin();
+                (220.0 * (cents / 1200.0).exp2()) as f32
+            })
+            .collect();
+        for track in [straight, glide, wobble, vec![0.0; 50], Vec::new()] {
+            let vibrato = analyze(&track, &settings());
+            assert!(vibrato.segments.is_empty(), "{vibrato:?}");
+            assert_eq!(vibrato.summary.rate_hz, None);
+            assert_eq!(vibrato.summary.onset_delay, None);
+        }
+    }
+
+    #[test]
+    fn test_sliding_windows_end_at_the_last_frame() {
+        let track = note(1.0, 1.5);
+        let windows = sliding(&track, &settings(), 100, 10);
+        assert_eq!(windows.len(), 16);
+        assert!((windows.last().unwrap().time - 2.49).abs() < 1e-9);
+        assert_eq!(windows[0].summary.rate_hz, None);
+        let live = windows.last().unwrap().summary;
+        assert!((live.rate_hz.unwrap() - 5.5).abs() < 0.2, "{live:?}");
+
+        let short = sliding(&track[..50], &settings(), 100, 10);
+        assert_eq!(short.len(), 1);
+        assert!(sliding(&[], &settings(), 100, 10).is_empty());
+    }
+}
@@ -0,0 +1,285 @@
+// Voice range profile (phonetogram): frames accumulated into a semitone x dB
+// grid over a session
+//
+// Each voiced frame (pitch above 0) is binned by its nearest note, numbered as
+// in MIDI with A4 = 69 at the reference pitch, and by its intensity: column c
+// holds levels in [min_db + c·db_step, min_db + (c + 1)·db_step). Frames
+// outside the note or level range are dropped. Pitch and intensity must come
+// from the same frame grid, e.g. `process_buffer` and the intensity contour
+// with equal frame and hop sizes, and the intensity should be calibrated to
+// dB SPL for profiles to be comparable between devices.
+//
+// A cell is covered once it holds at least `min_cell_frames` frames, so
+// onsets, cracks and glides that pass through a cell for a frame or two do
+// not count; a note is sustainable when any of its cells is covered. The
+// softest and loudest level of a note are the lower edge of its lowest
+// covered cell and the upper edge of its highest, and its dynamic range is
+// their difference. The area is the number of covered cells times db_step in
+// semitone·dB, so a gap-free row adds exactly its dynamic range.
+//
+// Counts only ever add, so merging profiles with the same grid gives exactly
+// the profile of all their frames pushed into one.
+
+/// Lowest and highest note a grid may span (the MIDI range)
+pub(crate) const MIDI_NOTES: std::ops::RangeInclusive<i32> = 0..=127;
+
+/// Most level columns a grid may have
+pub(crate) const MAX_DB_COLUMNS: usize = 1000;
+
+/// Grid and coverage threshold of a profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VrpSettings {
+    /// Frequency of A4 in Hz
+    pub reference_hz: f32,
+    /// First row's MIDI note
+    pub min_note: i32,
+    /// Last row's MIDI note (>= `min_note`)
+    pub max_note: i32,
+    /// Lower edge of the first column in dB
+    pub min_db: f32,
+    /// Width of a column in dB
+    pub db_step: f32,
+    /// Number of columns
+    pub db_columns: usize,
+    /// Frames a cell needs to count as covered (>= 1)
+    pub min_cell_frames: u32,
+}
+
+impl VrpSettings {
+    /// Number of rows, one per note
+    pub fn rows(&self) -> usize {
+        (self.max_note - self.min_note + 1) as usize
+    }
+
+    /// Equal-tempered frequency of a MIDI note in Hz
+    pub fn note_hz(&self, note: i32) -> f32 {
+        (self.reference_hz as f64 * ((note - 69) as f64 / 12.0).exp2()) as f32
+    }
+
+    /// Row and column of a frame, or None if it is unvoiced or off the grid
+    fn cell(&self, pitch_hz: f32, intensity_db: f32) -> Option<(usize, usize)> {
+        if pitch_hz <= 0.0 {
+            return None;
+        }
+        let note = (69.0 + 12.0 * (pitch_hz as f64 / self.reference_hz as f64).log2()).round();
+        let column = ((intensity_db - self.min_db) / self.db_step).floor();
+        let note_in_grid = (self.min_note as f64..=self.max_note as f64).contains(&note);
+        let column_in_grid = column >= 0.0 && (column as usize) < self.db_columns;
+        (note_in_grid && column_in_grid)
+            .then(|| ((note as i32 - self.min_note) as usize, column as usize))
+    }
+}
+
+/// Sustainable range, per-note dynamics and area of a profile
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VrpSummary {
+    /// Lowest MIDI note with a covered cell
+    pub lowest_note: Option<i32>,
+    /// Highest MIDI note with a covered cell
+    pub highest_note: Option<i32>,
+    /// Per row: lower edge of the lowest covered cell in dB
+    pub soft_db: Vec<Option<f32>>,
+    /// Per row: upper edge of the highest covered cell in dB
+    pub loud_db: Vec<Option<f32>>,
+    pub covered_cells: usize,
+    /// Covered cells times db_step in semitone·dB
+    pub area: f32,
+}
+
+impl VrpSummary {
+    /// Per row: loud minus soft level in dB
+    pub fn dynamic_range_db(&self) -> Vec<Option<f32>> {
+        self.soft_db
+            .iter()
+            .zip(&self.loud_db)
+            .map(|(soft, loud)| Some((*loud)? - (*soft)?))
+            .collect()
+    }
+}
+
+/// Frame counts of a semitone x dB grid
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VoiceRangeProfile {
+    settings: VrpSettings,
+    /// Row-major `rows x db_columns`; row 0 is `min_note`
+    counts: Vec<u32>,
+    /// Frames binned into the grid so far
+    frame_count: u64,
+}
+
+impl VoiceRangeProfile {
+    /// Creates an empty profile; callers validate the settings
+    pub fn new(settings: VrpSettings) -> Self {
+        VoiceRangeProfile {
+            counts: vec![0; settings.rows() * settings.db_columns],
+            settings,
+            frame_count: 0,
+        }
+    }
+
+    pub fn settings(&self) -> &VrpSettings {
+        &self.settings
+    }
+
+    /// Row-major frame counts, `rows x db_columns`
+    pub fn counts(&self) -> &[u32] {
+        &self.counts
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Bins frame i of `pitch_hz` and `intensity_db` and returns the number
+    /// of frames that landed on the grid
+    ///
+    /// Callers validate that both slices have the same length and hold finite
+    /// values, with pitches non-negative.
+    pub fn add_frames(&mut self, pitch_hz: &[f32], intensity_db: &[f32]) -> usize {
+        let columns = self.settings.db_columns;
+        let mut binned = 0;
+        for (&pitch, &level) in pitch_hz.iter().zip(intensity_db) {
+            if let Some((row, column)) = self.settings.cell(pitch, level) {
+                let count = &mut self.counts[row * columns + column];
+                *count = count.saturating_add(1);
+                binned += 1;
+            }
+        }
+        self.frame_count += binned as u64;
+        binned
+    }
+
+    /// Adds the counts of `other`, which must have the same settings
+    pub fn merge(&mut self, other: &VoiceRangeProfile) -> Result<(), String> {
+        if other.settings != self.settings {
+            return Err("profiles must have identical grids and min_cell_frames".into());
+        }
+        for (count, &added) in self.counts.iter_mut().zip(&other.counts) {
+            *count = count.saturating_add(added);
+        }
+        self.frame_count += other.frame_count;
+        Ok(())
+    }
+
+    /// Empties the grid
+    pub fn reset(&mut self) {
+        self.counts.fill(0);
+        self.frame_count = 0;
+    }
+
+    pub fn summary(&self) -> VrpSummary {
+        let s = &self.settings;
+        let mut soft_db = Vec::with_capacity(s.rows());
+        let mut loud_db = Vec::with_capacity(s.rows());
+        let mut covered_cells = 0;
+        let (mut lowest_note, mut highest_note) = (None, None);
+
+        for (row, counts) in self.counts.chunks(s.db_columns).enumerate() {
+            let covered = |&(_, &count): &(usize, &u32)| count >= s.min_cell_frames;
+            let first = counts.iter().enumerate().find(covered).map(|(c, _)| c);
+            let last = counts.iter().enumerate().rfind(covered).map(|(c, _)| c);
+            covered_cells += counts
+                .iter()
+                .filter(|&&count| count >= s.min_cell_frames)
+                .count();
+            soft_db.push(first.map(|c| s.min_db + c as f32 * s.db_step));
+            loud_db.push(last.map(|c| s.min_db + (c + 1) as f32 * s.db_step));
+            if first.is_some() {
+                let note = s.min_note + row as i32;
+                lowest_note.get_or_insert(note);
+                highest_note = Some(note);
+            }
+        }
+
+        VrpSummary {
+            lowest_note,
+            highest_note,
+            soft_db,
+            loud_db,
+            covered_cells,
+            area: covered_cells as f32 * s.db_step,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> VrpSettings {
+        VrpSettings {
+            reference_hz: 440.0,
+            min_note: 60,
+            max_note: 71,
+            min_db: 50.0,
+            db_step: 5.0,
+            db_columns: 6,
+            min_cell_frames: 2,
+        }
+    }
+
+    #[test]
+    fn test_frames_are_binned_by_nearest_note_and_level() {
+        let mut profile = VoiceRangeProfile::new(settings());
+        // A4, A4 a quarter-tone flat, C4, unvoiced, below and above the grid
+        let pitch = [440.0, 427.5, 261.63, 0.0, 220.0, 440.0];
+        let level = [62.0, 64.9, 50.0, 70.0, 60.0, 80.0];
+        assert_eq!(profile.add_frames(&pitch, &level), 3);
+        assert_eq!(profile.frame_count(), 3);
+
+        let columns = profile.settings().db_columns;
+        assert_eq!(profile.counts()[9 * columns + 2], 2);
+        assert_eq!(profile.counts()[0], 1);
+        assert_eq!(profile.counts().iter().sum::<u32>(), 3);
+    }
+
+    #[test]
+    fn test_summary_reports_covered_range_dynamics_and_area() {
+        let mut profile = VoiceRangeProfile::new(settings());
+        // D4 at 55-60 and 70-75 dB, A4 at 60-65 dB, and a single E4 frame
+        let pitch = [293.66, 293.66, 293.66, 293.66, 440.0, 440.0, 329.63];
+        let level = [57.0, 58.0, 71.0, 74.0, 60.0, 61.0, 60.0];
+        profile.add_frames(&pitch, &level);
+
+        let summary = profile.summary();
+        assert_eq!(
+            (summary.lowest_note, summary.highest_note),
+            (Some(62), Some(69))
+        );
+        assert_eq!(
+            (summary.soft_db[2], summary.loud_db[2]),
+            (Some(55.0), Some(75.0))
+        );
+        assert_eq!(summary.dynamic_range_db()[2], Some(20.0));
+        assert_eq!(summary.dynamic_range_db()[9], Some(5.0));
+        // E4 holds one frame, below min_cell_frames
+        assert_eq!(summary.soft_db[4], None);
+        assert_eq!(summary.covered_cells, 3);
+        assert_eq!(summary.area, 15.0);
+    }
+
+    #[test]
+    fn test_merge_equals_adding_all_frames_and_requires_the_same_grid() {
+        let (pitch, level) = ([440.0, 261.63, 440.0], [60.0, 52.0, 61.0]);
+        let mut first = VoiceRangeProfile::new(settings());
+        let mut second = VoiceRangeProfile::new(settings());
+        let mut both = VoiceRangeProfile::new(settings());
+        first.add_frames(&pitch[..1], &level[..1]);
+        second.add_frames(&pitch[1..], &level[1..]);
+        both.add_frames(&pitch, &level);
+
+        first.merge(&second).unwrap();
+        assert_eq!(first, both);
+        assert_eq!(first.summary().lowest_note, Some(69));
+
+        let other = VoiceRangeProfile::new(VrpSettings {
+            db_step: 2.0,
+            ..settings()
+        });
+        assert!(first.merge(&other).is_err());
+
+        first.reset();
+        assert_eq!(first, VoiceRangeProfile::new(settings()));
+        assert_eq!(first.summary().area, 0.0);
+    }
+}
@@ -0,0 +1,290 @@
+// Vocal tract length (VTL) from formant frequencies
+//
+// A uniform tube of length L, closed at the glottis and open at the lips,
+// resonates at Fn = (2n - 1)·c / 4L, so its formants are ΔF = c / 2L apart and
+// L = c / 2ΔF. Two standard estimators of ΔF are applied to F1-F4 of each
+// voiced frame:
+//
+//   average formant spacing (formant dispersion, Fitch 1997): the mean gap
+//     between consecutive formants, (F_last - F_first) / (last - first)
+//   uniform-tube regression (Reby & McComb 2003): the least-squares fit of
+//     Fn = (2n - 1)/2 · ΔF through the origin, ΔF = Σ xn·Fn / Σ xn² with
+//     xn = (2n - 1)/2
+//
+// The regression uses every formant's position rather than only the outer
+// two, so it is less sensitive to one misplaced formant and is the primary
+// estimate. A frame needs at least three of F1-F4 with a confidence of at
+// least `min_confidence`; estimates outside `PLAUSIBLE_VTL_CM` are treated as
+// formant tracking errors and dropped.
+//
+// The clip estimate is the median over frames. Its confidence is the product
+// of the mean confidence of the formants used, the agreement between frames
+// (1 - 5 × relative median absolute deviation, so 0 at a 20% spread) and the
+// coverage (frames used / `FULL_CONFIDENCE_FRAMES`, capped at 1).
+
+use crate::formants::{self, Formant};
+use crate::spectrogram::frame_starts;
+
+/// Formants used by the estimators (F1-F4; F5 is rarely reliable)
+pub(crate) const FORMANT_COUNT: usize = 4;
+
+/// Fewest confident formants a frame needs for an estimate
+const MIN_FORMANTS: usize = 3;
+
+/// Lengths outside this range (cm) come from mislabelled formants: infant
+/// tracts are about 6-8 cm and long adult male tracts about 20 cm
+const PLAUSIBLE_VTL_CM: (f32, f32) = (6.0, 24.0);
+
+/// Frames needed before coverage stops lowering the confidence
+const FULL_CONFIDENCE_FRAMES: usize = 10;
+
+/// VTL estimates of one frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FrameEstimate {
+    /// VTL from the uniform-tube regression in cm
+    pub regression_cm: f32,
+    /// VTL from the average formant spacing in cm
+    pub spacing_cm: f32,
+    /// F1-F4 in Hz; 0.0 for formants that were missing or below the confidence
+    pub formants: [f32; FORMANT_COUNT],
+    /// Mean confidence of the formants used
+    pub confidence: f32,
+}
+
+/// Clip-level VTL aggregated over voiced frames
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct VocalTractLength {
+    /// Median uniform-tube regression VTL in cm
+    pub vtl_cm: f32,
+    /// Median average-spacing VTL in cm
+    pub spacing_vtl_cm: f32,
+    /// Formant spacing ΔF = c / 2L of `vtl_cm` in Hz
+    pub formant_spacing_hz: f32,
+    /// Median F1-F4 in Hz over the frames where each was used (0.0 if never)
+    pub formants: [f32; FORMANT_COUNT],
+    /// 0.0-1.0: formant confidence × agreement between frames × coverage
+    pub confidence: f32,
+    /// Frames that contributed an estimate
+    pub frame_count: usize,
+}
+
+/// Upper median of `values`, which must be non-empty
+fn median(values: &mut [f32]) -> f32 {
+    values.sort_by(f32::total_cmp);
+    values[values.len() / 2]
+}
+
+/// Estimates the VTL of one frame from its formants
+///
+/// # Returns
+/// * `None` if fewer than three of F1-F4 reach `min_confidence` or either
+///   estimate falls outside `PLAUSIBLE_VTL_CM`
+pub(crate) fn estimate_frame(
+    formants: &[Formant],
+    min_confidence: f32,
+    speed_of_sound: f32,
+) -> Option<FrameEstimate> {
+    // (formant number n, frequency, confidence) of the usable formants
+    let used: Vec<(f32, f32, f32)> = formants
+        .iter()
+        .take(FORMANT_COUNT)
+        .enumerate()
+        .filter(|(_, f)| f.frequency > 0.0 && f.confidence >= min_confidence)
+        .map(|(i, f)| ((i + 1) as f32, f.frequency, f.confidence))
+        .collect();
+    if used.len() < MIN_FORMANTS {
+        return None;
+    }
+
+    let (first, last) = (used[0], used[used.len() - 1]);
+    let spacing_hz = (last.1 - first.1) / (last.0 - first.0);
+
+    let (xf, xx) = used
+        .iter()
+        .fold((0.0, 0.0), |(xf, xx), &(n, frequency, _)| {
+            let x = (2.0 * n - 1.0) / 2.0;
+            (xf + x * frequency, xx + x * x)
+        });
+    let regression_hz = xf / xx;
+
+    let regression_cm = speed_of_sound / (2.0 * regression_hz);
+    let spacing_cm = speed_of_sound / (2.0 * spacing_hz);
+    let (low, high) = PLAUSIBLE_VTL_CM;
+    if !((low..=high).contains(&regression_cm) && (low..=high).contains(&spacing_cm)) {
+        return None;
+    }
+
+    let mut frequencies = [0.0; FORMANT_COUNT];
+    for &(n, frequency, _) in &used {
+        frequencies[n as usize - 1] = frequency;
+    }
+    Some(FrameEstimate {
+        regression_cm,
+        spacing_cm,
+        formants: frequencies,
+        confidence: used.iter().map(|u| u.2).sum::<f32>() / used.len() as f32,
+    })
+}
+
+/// Combines frame estimates into a clip estimate
+///
+/// # Returns
+/// * `None` if `frames` is empty
+pub(crate) fn aggregate(frames: &[FrameEstimate], speed_of_sound: f32) -> Option<VocalTractLength> {
+    if frames.is_empty() {
+        return None;
+    }
+
+    let mut regression: Vec<f32> = frames.iter().map(|f| f.regression_cm).collect();
+    let mut spacing: Vec<f32> = frames.iter().map(|f| f.spacing_cm).collect();
+    let vtl_cm = median(&mut regression);
+    let spacing_vtl_cm = median(&mut spacing);
+
+    let formants = std::array::from_fn(|i| {
+        let mut found: Vec<f32> = frames
+            .iter()
+            .map(|f| f.formants[i])
+            .filter(|&f| f > 0.0)
+            .collect();
+        if found.is_empty() {
+            0.0
+        } else {
+            median(&mut found)
+        }
+    });
+
+    let mut deviations: Vec<f32> = regression.iter().map(|v| (v - vtl_cm).abs()).collect();
+    let agreement = (1.0 - 5.0 * median(&mut deviations) / vtl_cm).clamp(0.0, 1.0);
+    let coverage = (frames.len() as f32 / FULL_CONFIDENCE_FRAMES as f32).min(1.0);
+    let quality = frames.iter().map(|f| f.confidence).sum::<f32>() / frames.len() as f32;
+
+    Some(VocalTractLength {
+        vtl_cm,
+        spacing_vtl_cm,
+        formant_spacing_hz: speed_of_sound / (2.0 * vtl_cm),
+        formants,
+        confidence: quality * agreement * coverage,
+        frame_count: frames.len(),
+    })
+}
+
+/// Estimates the VTL of `samples` from the formants of its voiced frames
+///
+/// `pitch_track` comes from `process_buffer` with the same frame and hop
+/// sizes; frames with a pitch of 0.0 are skipped. Callers validate the sample
+/// rate, LPC order and framing.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn estimate(
+    samples: &[f32],
+    sample_rate: u32,
+    frame_size: usize,
+    hop_size: usize,
+    pitch_track: &[f32],
+    lpc_order: usize,
+    min_confidence: f32,
+    speed_of_sound: f32,
+) -> Option<VocalTractLength> {
+    let frames: Vec<FrameEstimate> = frame_starts(samples.len(), frame_size, hop_size)
+        .zip(pitch_track)
+        .filter(|&(_, &f0)| f0 > 0.0)
+        .filter_map(|(start, _)| {
+            let frame = &samples[start..start + frame_size];
+            let formants = formants::analyze(frame, sample_rate, lpc_order).ok()?;
+            estimate_frame(&formants, min_confidence, speed_of_sound)
+        })
+        .collect();
+    aggregate(&frames, speed_of_sound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEED_OF_SOUND: f32 = 35000.0;
+
+    fn formants(frequencies: &[f32]) -> Vec<Formant> {
+        frequencies
+            .iter()
+            .map(|&frequency| Formant {
+                frequency,
+                bandwidth: 80.0,
+                confidence: 0.9,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_uniform_tube_formants_give_its_length() {
+        // 17.5 cm tube: ΔF = 35000 / 35 = 1000 Hz, Fn = 500, 1500, 2500, 3500
+        let frame = estimate_frame(
+            &formants(&[500.0, 1500.0, 2500.0, 3500.0]),
+            0.5,
+            SPEED_OF_SOUND,
+        )
+        .unwrap();
+        assert!((frame.regression_cm - 17.5).abs() < 1e-3, "{frame:?}");
+        assert!((frame.spacing_cm - 17.5).abs() < 1e-3, "{frame:?}");
+        assert!((frame.confidence - 0.9).abs() < 1e-6);
+
+        // A raised F1 (open vowel) moves the spacing estimate more than the regression
+        let open = estimate_frame(
+            &formants(&[750.0, 1500.0, 2500.0, 3500.0]),
+            0.5,
+            SPEED_OF_SOUND,
+        )
+        .unwrap();
+        assert!(
+            (open.regression_cm - 17.5).abs() < (open.spacing_cm - 17.5).abs(),
+            "{open:?}"
+        );
+    }
+
+    #[test]
+    fn test_frames_need_three_confident_plausible_formants() {
+        let mut weak = formants(&[500.0, 1500.0, 2500.0, 3500.0]);
+        weak[1].confidence = 0.2;
+        weak[3].confidence = 0.2;
+        assert!(estimate_frame(&weak, 0.5, SPEED_OF_SOUND).is_none());
+
+        // A missing F2 keeps the formant numbering of F3 and F4
+        weak[3].confidence = 0.9;
+        let frame = estimate_frame(&weak, 0.5, SPEED_OF_SOUND).unwrap();
+        assert_eq!(frame.formants, [500.0, 0.0, 2500.0, 3500.0]);
+        assert!((frame.regression_cm - 17.5).abs() < 1e-3);
+
+        // 300 Hz spacing would be a 58 cm tract
+        let implausible = formants(&[150.0, 450.0, 750.0, 1050.0]);
+        assert!(estimate_frame(&implausible, 0.5, SPEED_OF_SOUND).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_is_robust_and_confidence_reflects_spread_and_coverage() {
+        let frame = |cm: f32| FrameEstimate {
+            regression_cm: cm,
+            spacing_cm: cm,
+            formants: [500.0, 1500.0, 2500.0, 3500.0],
+            confidence: 0.8,
+        };
+        assert!(aggregate(&[], SPEED_OF_SOUND).is_none());
+
+        let mut frames = vec![frame(17.5); 11];
+        frames.push(frame(9.0));
+        let clip = aggregate(&frames, SPEED_OF_SOUND).unwrap();
+        assert_eq!(clip.vtl_cm, 17.5);
+        assert_eq!(clip.frame_count, 12);
+        assert!((clip.formant_spacing_hz - 1000.0).abs() < 1e-3);
+        assert!((clip.confidence - 0.8).abs() < 1e-6);
+
+        let few = aggregate(&frames[..5], SPEED_OF_SOUND).unwrap();
+        assert!((few.confidence - 0.4).abs() < 1e-6);
+
+        let scattered: Vec<FrameEstimate> = [14.0, 15.5, 17.5, 19.5, 21.0]
+            .iter()
+            .cycle()
+            .take(10)
+            .map(|&cm| frame(cm))
+            .collect();
+        let clip = aggregate(&scattered, SPEED_OF_SOUND).unwrap();
+        assert!(clip.confidence < 0.5, "{clip:?}");
+    }
+}
@@ -43,6 +43,10 @@ public class Promise {
 
 public func Name(_ name: String) -> Any { name }
 
+public func OnCreate(_ handler: @escaping () -> Void) -> Any {
+    return "OnCreate"
+}
+
 public func AsyncFunction(_ name: String, _ handler: @escaping ([Float], [String: Any], Promise) -> Void) -> Any {
     return name
 }
@@ -85,6 +89,23 @@ public struct PitchResultFFI {
     }
 }
 
+public struct PitchResultExFFI {
+    public var success: Bool
+    public var frequency: Float
+    public var confidence: Float
+    public var is_voiced: Bool
+    public var voiced_probability: Float
+    public var error_code: LoqaErrorCode
+    public init(success: Bool, frequency: Float, confidence: Float, is_voiced: Bool, voiced_probability: Float, error_code: LoqaErrorCode) {
+        self.success = success
+        self.frequency = frequency
+        self.confidence = confidence
+        self.is_voiced = is_voiced
+        self.voiced_probability = voiced_probability
+        self.error_code = error_code
+    }
+}
+
 public struct FormantResultFFI {
     public var success: Bool
     public var f1: Float
@@ -113,6 +134,109 @@ public struct FFTResultFFI {
     }
 }
 
+public struct FFTConfigFFI {
+    public var fft_size: UInt32
+    public var window: UInt32
+    public var window_param: Float
+    public var normalization: UInt32
+    public var include_phase: Bool
+}
+
+public struct FFTResultExFFI {
+    public var success: Bool
+    public var magnitudes_ptr: UnsafeMutablePointer<Float>?
+    public var phases_ptr: UnsafeMutablePointer<Float>?
+    public var frequencies_ptr: UnsafeMutablePointer<Float>?
+    public var length: Int
+    public var fft_size: UInt32
+    public var sample_rate: UInt32
+    public var error_code: LoqaErrorCode
+}
+
+public struct ComplexSpectrumFFI {
+    public var success: Bool
+    public var bins_ptr: UnsafeMutablePointer<Float>?
+    public var bin_count: Int
+    public var fft_size: UInt32
+    public var error_code: LoqaErrorCode
+}
+
+public struct InverseFFTResultFFI {
+    public var success: Bool
+    public var samples_ptr: UnsafeMutablePointer<Float>?
+    public var length: Int
+    public var error_code: LoqaErrorCode
+}
+
+public struct SpectrogramConfigFFI {
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var window: UInt32
+    public var window_param: Float
+    public var scaling: UInt32
+    public var db_floor: Float
+}
+
+public struct SpectrogramFFI {
+    public var success: Bool
+    public var values_ptr: UnsafeMutablePointer<Float>?
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var frame_count: Int
+    public var bin_count: Int
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var sample_rate: UInt32
+    public var error_code: LoqaErrorCode
+}
+
+public struct MelConfigFFI {
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var window: UInt32
+    public var window_param: Float
+    public var n_mels: UInt32
+    public var fmin: Float
+    public var fmax: Float
+    public var mel_scale: UInt32
+    public var n_mfcc: UInt32
+    public var lifter: Float
+    public var delta_order: UInt32
+    public var delta_width: UInt32
+}
+
+public struct FeatureMatrixFFI {
+    public var success: Bool
+    public var values_ptr: UnsafeMutablePointer<Float>?
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var frame_count: Int
+    public var feature_count: Int
+    public var error_code: LoqaErrorCode
+}
+
+public struct SpectrumConfigFFI {
+    public var fft_size: UInt32
+    public var average_frames: Bool
+    public var rolloff_low_threshold: Float
+    public var rolloff_high_threshold: Float
+}
+
+public struct SpectralFeaturesExFFI {
+    public var success: Bool
+    public var centroid: Float
+    public var spread: Float
+    public var skewness: Float
+    public var kurtosis: Float
+    public var flatness: Float
+    public var entropy: Float
+    public var crest_factor: Float
+    public var rolloff_low: Float
+    public var rolloff_high: Float
+    public var tilt: Float
+    public var fft_size: UInt32
+    public var frame_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+
 public struct SpectralFeaturesFFI {
     public var success: Bool
     public var centroid: Float
@@ -180,21 +304,63 @@ public struct AnalysisConfigFFI {
 public func loqa_analysis_config_default() -> AnalysisConfigFFI {
     AnalysisConfigFFI(sample_rate: 16000, frame_size: 2048, hop_size: 512, min_frequency: 80.0, max_frequency: 400.0, algorithm: 0, threshold: 0.1, min_confidence: 0.5, interpolate: true)
 }
+public func loqa_analysis_config_validate(_ config: AnalysisConfigFFI) -> LoqaErrorCode { LOQA_OK }
 
 // Mock FFI functions (matching loqa-voice-dsp v0.4.0 API)
-public func loqa_compute_fft(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ fftSize: Int) -> FFTResultFFI {
+public func loqa_compute_fft(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ fftSize: UInt32) -> FFTResultFFI {
     FFTResultFFI(success: true, magnitudes_ptr: nil, frequencies_ptr: nil, length: 0, sample_rate: sampleRate)
 }
 public func loqa_free_fft_result(_ result: UnsafeMutablePointer<FFTResultFFI>?) {}
+public func loqa_fft_config_default() -> FFTConfigFFI {
+    FFTConfigFFI(fft_size: 0, window: 1, window_param: 0, normalization: 0, include_phase: false)
+}
+public func loqa_compute_fft_ex(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: FFTConfigFFI) -> FFTResultExFFI {
+    FFTResultExFFI(success: true, magnitudes_ptr: nil, phases_ptr: nil, frequencies_ptr: nil, length: 0, fft_size: config.fft_size, sample_rate: sampleRate, error_code: LOQA_OK)
+}
+public func loqa_free_fft_result_ex(_ result: UnsafeMutablePointer<FFTResultExFFI>?) {}
+public func loqa_compute_fft_complex(_ buffer: UnsafePointer<Float>?, _ length: Int, _ fftSize: UInt32) -> ComplexSpectrumFFI {
+    ComplexSpectrumFFI(success: true, bins_ptr: nil, bin_count: 0, fft_size: fftSize, error_code: LOQA_OK)
+}
+public func loqa_compute_ifft(_ bins: UnsafePointer<Float>?, _ binCount: Int) -> InverseFFTResultFFI {
+    InverseFFTResultFFI(success: true, samples_ptr: nil, length: 0, error_code: LOQA_OK)
+}
+public func loqa_free_complex_spectrum(_ result: UnsafeMutablePointer<ComplexSpectrumFFI>?) {}
+public func loqa_free_ifft_result(_ result: UnsafeMutablePointer<InverseFFTResultFFI>?) {}
+public func loqa_spectrogram_config_default() -> SpectrogramConfigFFI {
+    SpectrogramConfigFFI(frame_size: 2048, hop_size: 512, window: 1, window_param: 0, scaling: 2, db_floor: -120)
+}
+public func loqa_compute_spectrogram(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: SpectrogramConfigFFI) -> SpectrogramFFI {
+    SpectrogramFFI(success: true, values_ptr: nil, timestamps_ptr: nil, frame_count: 0, bin_count: 0, frame_size: config.frame_size, hop_size: config.hop_size, sample_rate: sampleRate, error_code: LOQA_OK)
+}
+public func loqa_free_spectrogram(_ result: UnsafeMutablePointer<SpectrogramFFI>?) {}
+public func loqa_mel_config_default() -> MelConfigFFI {
+    MelConfigFFI(frame_size: 2048, hop_size: 512, window: 1, window_param: 0, n_mels: 40, fmin: 0, fmax: 0, mel_scale: 1, n_mfcc: 13, lifter: 0, delta_order: 0, delta_width: 2)
+}
+public func loqa_compute_log_mel_spectrogram(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: MelConfigFFI) -> FeatureMatrixFFI {
+    FeatureMatrixFFI(success: true, values_ptr: nil, timestamps_ptr: nil, frame_count: 0, feature_count: Int(config.n_mels), error_code: LOQA_OK)
+}
+public func loqa_compute_mfcc(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: MelConfigFFI) -> FeatureMatrixFFI {
+    FeatureMatrixFFI(success: true, values_ptr: nil, timestamps_ptr: nil, frame_count: 0, feature_count: Int(config.n_mfcc * (1 + config.delta_order)), error_code: LOQA_OK)
+}
+public func loqa_free_feature_matrix(_ result: UnsafeMutablePointer<FeatureMatrixFFI>?) {}
 public func loqa_detect_pitch(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ minFreq: Float, _ maxFreq: Float) -> PitchResultFFI {
     PitchResultFFI(success: true, frequency: 0, confidence: 0, is_voiced: false, voiced_probability: 0)
 }
-public func loqa_extract_formants(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ lpcOrder: Int) -> FormantResultFFI {
+public func loqa_detect_pitch_ex(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ minFreq: Float, _ maxFreq: Float, _ algorithm: UInt32) -> PitchResultExFFI {
+    PitchResultExFFI(success: true, frequency: 0, confidence: 0, is_voiced: false, voiced_probability: 0, error_code: LOQA_OK)
+}
+public func loqa_extract_formants(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ lpcOrder: UInt32) -> FormantResultFFI {
     FormantResultFFI(success: true, f1: 0, f2: 0, f3: 0, confidence: 0)
 }
 public func loqa_analyze_spectrum(_ fftResult: UnsafePointer<FFTResultFFI>?) -> SpectralFeaturesFFI {
     SpectralFeaturesFFI(success: true, centroid: 0, tilt: 0, rolloff_95: 0)
 }
+public func loqa_spectrum_config_default() -> SpectrumConfigFFI {
+    SpectrumConfigFFI(fft_size: 0, average_frames: true, rolloff_low_threshold: 0.85, rolloff_high_threshold: 0.95)
+}
+public func loqa_analyze_spectrum_ex(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: SpectrumConfigFFI) -> SpectralFeaturesExFFI {
+    SpectralFeaturesExFFI(success: true, centroid: 0, spread: 0, skewness: 0, kurtosis: 0, flatness: 0, entropy: 0, crest_factor: 0, rolloff_low: 0, rolloff_high: 0, tilt: 0, fft_size: 0, frame_count: 0, error_code: LOQA_OK)
+}
 public func loqa_calculate_hnr(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ minFreq: Float, _ maxFreq: Float) -> HNRResultFFI {
     HNRResultFFI(success: true, hnr: 0, f0: 0, is_voiced: false)
 }
@@ -202,6 +368,37 @@ public func loqa_calculate_h1h2(_ buffer: UnsafePointer<Float>?, _ length: Int,
     H1H2ResultFFI(success: true, h1h2: 0, h1_amplitude_db: 0, h2_amplitude_db: 0, f0: 0)
 }
 
+// Error reporting (C enum imports as a RawRepresentable struct)
+public struct LoqaErrorCode: RawRepresentable, Equatable {
+    public var rawValue: UInt32
+    public init(rawValue: UInt32) { self.rawValue = rawValue }
+    public init(_ rawValue: UInt32) { self.rawValue = rawValue }
+}
+public let LOQA_OK = LoqaErrorCode(0)
+public let LOQA_ERR_NULL_POINTER = LoqaErrorCode(1)
+public let LOQA_ERR_ANALYSIS_FAILED = LoqaErrorCode(9)
+public let LOQA_ERR_PANIC = LoqaErrorCode(10)
+public func loqa_last_error_code() -> LoqaErrorCode { LOQA_OK }
+public func loqa_last_error_message() -> UnsafePointer<CChar>? { nil }
+public func loqa_clear_last_error() {}
+
+// Logging (C enum imports as a RawRepresentable struct)
+public struct LoqaLogLevel: RawRepresentable, Equatable {
+    public var rawValue: UInt32
+    public init(rawValue: UInt32) { self.rawValue = rawValue }
+    public init(_ rawValue: UInt32) { self.rawValue = rawValue }
+}
+public let LOQA_LOG_OFF = LoqaLogLevel(0)
+public let LOQA_LOG_ERROR = LoqaLogLevel(1)
+public let LOQA_LOG_WARN = LoqaLogLevel(2)
+public let LOQA_LOG_INFO = LoqaLogLevel(3)
+public let LOQA_LOG_DEBUG = LoqaLogLevel(4)
+public let LOQA_LOG_TRACE = LoqaLogLevel(5)
+public typealias LoqaLogCallback = @convention(c) (LoqaLogLevel, UnsafePointer<CChar>?) -> Void
+public func loqa_set_log_callback(_ callback: LoqaLogCallback?) {}
+public func loqa_set_log_level(_ level: UInt32) -> LoqaErrorCode { LOQA_OK }
+public func loqa_log_level() -> UInt32 { 2 }
+
 // PitchTrackFFI for process_buffer (v0.5.0)
 public struct PitchTrackFFI {
     public var success: Bool
@@ -209,12 +406,14 @@ public struct PitchTrackFFI {
     public var voiced_probs_ptr: UnsafeMutablePointer<Float>?
     public var timestamps_ptr: UnsafeMutablePointer<Float>?
     public var length: Int
-    public init(success: Bool, pitch_track_ptr: UnsafeMutablePointer<Float>?, voiced_probs_ptr: UnsafeMutablePointer<Float>?, timestamps_ptr: UnsafeMutablePointer<Float>?, length: Int) {
+    public var error_code: LoqaErrorCode
+    public init(success: Bool, pitch_track_ptr: UnsafeMutablePointer<Float>?, voiced_probs_ptr: UnsafeMutablePointer<Float>?, timestamps_ptr: UnsafeMutablePointer<Float>?, length: Int, error_code: LoqaErrorCode) {
         self.success = success
         self.pitch_track_ptr = pitch_track_ptr
         self.voiced_probs_ptr = voiced_probs_ptr
         self.timestamps_ptr = timestamps_ptr
         self.length = length
+        self.error_code = error_code
     }
 }
 
@@ -225,11 +424,370 @@ public func loqa_voice_analyzer_process_frame(_ analyzer: UnsafeMutableRawPointe
 }
 public func loqa_voice_analyzer_process_stream(_ analyzer: UnsafeMutableRawPointer?, _ samples: UnsafePointer<Float>?, _ len: Int, _ results: UnsafeMutablePointer<PitchResultFFI>?, _ maxResults: Int) -> Int { 0 }
 public func loqa_voice_analyzer_process_buffer(_ analyzer: UnsafeMutableRawPointer?, _ samples: UnsafePointer<Float>?, _ len: Int) -> PitchTrackFFI {
-    PitchTrackFFI(success: true, pitch_track_ptr: nil, voiced_probs_ptr: nil, timestamps_ptr: nil, length: 0)
+    PitchTrackFFI(success: true, pitch_track_ptr: nil, voiced_probs_ptr: nil, timestamps_ptr: nil, length: 0, error_code: LOQA_OK)
 }
 public func loqa_voice_analyzer_reset(_ analyzer: UnsafeMutableRawPointer?) {}
 public func loqa_voice_analyzer_free(_ analyzer: UnsafeMutableRawPointer?) {}
 public func loqa_free_pitch_track(_ result: UnsafeMutablePointer<PitchTrackFFI>?) {}
+
+// Pitch track post-processing
+public struct PitchPostprocessConfigFFI {
+    public var correct_octave_jumps: Bool
+    public var octave_window: UInt32
+    public var smoothing: UInt32
+    public var smoothing_window: UInt32
+    public var max_gap_frames: UInt32
+}
+public func loqa_pitch_postprocess_config_default() -> PitchPostprocessConfigFFI {
+    PitchPostprocessConfigFFI(correct_octave_jumps: true, octave_window: 11, smoothing: 1, smoothing_window: 5, max_gap_frames: 5)
+}
+public func loqa_postprocess_pitch_track(_ pitchTrack: UnsafePointer<Float>?, _ voicedProbs: UnsafePointer<Float>?, _ timestamps: UnsafePointer<Float>?, _ length: Int, _ config: PitchPostprocessConfigFFI) -> PitchTrackFFI {
+    PitchTrackFFI(success: true, pitch_track_ptr: nil, voiced_probs_ptr: nil, timestamps_ptr: nil, length: 0, error_code: LOQA_OK)
+}
+
+// Pitch statistics
+public struct PitchStatsConfigFFI {
+    public var reference_hz: Float
+    public var weight_by_voicing: Bool
+}
+public struct PitchStatsFFI {
+    public var success: Bool
+    public var frame_count: UInt32
+    public var voiced_frame_count: UInt32
+    public var voiced_ratio: Float
+    public var mean_hz: Float
+    public var median_hz: Float
+    public var std_hz: Float
+    public var p5_hz: Float
+    public var p95_hz: Float
+    public var mean_semitones: Float
+    public var median_semitones: Float
+    public var std_semitones: Float
+    public var p5_semitones: Float
+    public var p95_semitones: Float
+    public var range_semitones: Float
+    public var error_code: LoqaErrorCode
+}
+public func loqa_pitch_stats_config_default() -> PitchStatsConfigFFI {
+    PitchStatsConfigFFI(reference_hz: 100, weight_by_voicing: true)
+}
+public func loqa_pitch_track_statistics(_ pitchTrack: UnsafePointer<Float>?, _ voicedProbs: UnsafePointer<Float>?, _ length: Int, _ config: PitchStatsConfigFFI) -> PitchStatsFFI {
+    PitchStatsFFI(success: true, frame_count: 0, voiced_frame_count: 0, voiced_ratio: 0, mean_hz: 0, median_hz: 0, std_hz: 0, p5_hz: 0, p95_hz: 0, mean_semitones: 0, median_semitones: 0, std_semitones: 0, p5_semitones: 0, p95_semitones: 0, range_semitones: 0, error_code: LOQA_OK)
+}
+
+// Vibrato
+public struct VibratoConfigFFI {
+    public var frame_rate: Float
+    public var min_rate_hz: Float
+    public var max_rate_hz: Float
+    public var min_extent_cents: Float
+    public var max_extent_cents: Float
+    public var min_cycles: UInt32
+    public var window_seconds: Float
+    public var window_hop_frames: UInt32
+}
+public struct VibratoSegmentFFI {
+    public var start: Float
+    public var end: Float
+    public var rate_hz: Float
+    public var extent_cents: Float
+    public var rate_jitter: Float
+    public var extent_jitter: Float
+    public var onset_delay: Float
+}
+public struct VibratoFFI {
+    public var success: Bool
+    public var vibrato_ratio: Float
+    public var rate_hz: Float
+    public var extent_cents: Float
+    public var rate_jitter: Float
+    public var extent_jitter: Float
+    public var onset_delay: Float
+    public var segments_ptr: UnsafeMutablePointer<VibratoSegmentFFI>?
+    public var segment_count: Int
+    public var window_times_ptr: UnsafeMutablePointer<Float>?
+    public var window_rate_ptr: UnsafeMutablePointer<Float>?
+    public var window_extent_ptr: UnsafeMutablePointer<Float>?
+    public var window_rate_jitter_ptr: UnsafeMutablePointer<Float>?
+    public var window_extent_jitter_ptr: UnsafeMutablePointer<Float>?
+    public var window_count: Int
+    public var error_code: LoqaErrorCode
+}
+public func loqa_vibrato_config_default() -> VibratoConfigFFI {
+    VibratoConfigFFI(frame_rate: 100, min_rate_hz: 4, max_rate_hz: 8, min_extent_cents: 15, max_extent_cents: 200, min_cycles: 2, window_seconds: 1, window_hop_frames: 1)
+}
+public func loqa_analyze_vibrato(_ pitchTrack: UnsafePointer<Float>?, _ length: Int, _ config: VibratoConfigFFI) -> VibratoFFI {
+    VibratoFFI(success: true, vibrato_ratio: 0, rate_hz: 0, extent_cents: 0, rate_jitter: 0, extent_jitter: 0, onset_delay: 0, segments_ptr: nil, segment_count: 0, window_times_ptr: nil, window_rate_ptr: nil, window_extent_ptr: nil, window_rate_jitter_ptr: nil, window_extent_jitter_ptr: nil, window_count: 0, error_code: LOQA_OK)
+}
+public func loqa_free_vibrato(_ result: UnsafeMutablePointer<VibratoFFI>?) {}
+
+// Jitter and shimmer
+public struct JitterResultFFI {
+    public var success: Bool
+    public var local: Float
+    public var local_absolute: Float
+    public var rap: Float
+    public var ppq5: Float
+    public var ddp: Float
+    public var mean_period: Float
+    public var period_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+public struct ShimmerResultFFI {
+    public var success: Bool
+    public var local: Float
+    public var local_db: Float
+    public var apq3: Float
+    public var apq5: Float
+    public var apq11: Float
+    public var dda: Float
+    public var mean_amplitude: Float
+    public var period_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+public func loqa_calculate_jitter(_ buffer: UnsafePointer<Float>?, _ length: Int, _ config: AnalysisConfigFFI) -> JitterResultFFI {
+    JitterResultFFI(success: true, local: 0, local_absolute: 0, rap: 0, ppq5: .nan, ddp: 0, mean_period: 0, period_count: 3, error_code: LOQA_OK)
+}
+public func loqa_calculate_shimmer(_ buffer: UnsafePointer<Float>?, _ length: Int, _ config: AnalysisConfigFFI) -> ShimmerResultFFI {
+    ShimmerResultFFI(success: true, local: 0, local_db: 0, apq3: 0, apq5: .nan, apq11: .nan, dda: 0, mean_amplitude: 0, period_count: 3, error_code: LOQA_OK)
+}
+
+// Cepstral peak prominence
+public struct CppConfigFFI {
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var min_frequency: Float
+    public var max_frequency: Float
+    public var time_smoothing: Float
+    public var quefrency_smoothing: Float
+}
+public struct CppResultFFI {
+    public var success: Bool
+    public var cpp: Float
+    public var cpps: Float
+    public var f0: Float
+    public var frame_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+public func loqa_cpp_config_default() -> CppConfigFFI {
+    CppConfigFFI(frame_size: 2048, hop_size: 128, min_frequency: 60, max_frequency: 330, time_smoothing: 0.02, quefrency_smoothing: 0.0005)
+}
+public func loqa_calculate_cpp(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: CppConfigFFI) -> CppResultFFI {
+    CppResultFFI(success: true, cpp: 0, cpps: 0, f0: 0, frame_count: 0, error_code: LOQA_OK)
+}
+
+// Intensity
+public struct IntensityConfigFFI {
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var calibration_offset: Float
+    public var floor_db: Float
+}
+public struct IntensityContourFFI {
+    public var success: Bool
+    public var rms_ptr: UnsafeMutablePointer<Float>?
+    public var intensity_db_ptr: UnsafeMutablePointer<Float>?
+    public var peak_db_ptr: UnsafeMutablePointer<Float>?
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var frame_count: Int
+    public var mean_db: Float
+    public var min_db: Float
+    public var max_db: Float
+    public var dynamic_range_db: Float
+    public var error_code: LoqaErrorCode
+}
+public func loqa_intensity_config_default() -> IntensityConfigFFI {
+    IntensityConfigFFI(frame_size: 2048, hop_size: 512, calibration_offset: 0, floor_db: -120)
+}
+public func loqa_compute_intensity(_ buffer: UnsafePointer<Float>?, _ length: Int, _ sampleRate: UInt32, _ config: IntensityConfigFFI) -> IntensityContourFFI {
+    IntensityContourFFI(success: true, rms_ptr: nil, intensity_db_ptr: nil, peak_db_ptr: nil, timestamps_ptr: nil, frame_count: 0, mean_db: 0, min_db: 0, max_db: 0, dynamic_range_db: 0, error_code: LOQA_OK)
+}
+public func loqa_free_intensity_contour(_ result: UnsafeMutablePointer<IntensityContourFFI>?) {}
+
+// Voice activity detection
+public struct VadConfigFFI {
+    public var energy_threshold_db: Float
+    public var zcr_threshold: Float
+    public var voicing_threshold: Float
+    public var hangover_time: Float
+}
+public struct SpeechSegmentFFI {
+    public var start: Int
+    public var end: Int
+}
+public struct VadResultFFI {
+    public var success: Bool
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var energy_db_ptr: UnsafeMutablePointer<Float>?
+    public var zcr_ptr: UnsafeMutablePointer<Float>?
+    public var voiced_probs_ptr: UnsafeMutablePointer<Float>?
+    public var is_speech_ptr: UnsafeMutablePointer<Bool>?
+    public var frame_count: Int
+    public var segments_ptr: UnsafeMutablePointer<SpeechSegmentFFI>?
+    public var segment_count: Int
+    public var error_code: LoqaErrorCode
+}
+public func loqa_vad_config_default() -> VadConfigFFI {
+    VadConfigFFI(energy_threshold_db: -50, zcr_threshold: 3000, voicing_threshold: 0.5, hangover_time: 0.2)
+}
+public func loqa_detect_voice_activity(_ buffer: UnsafePointer<Float>?, _ length: Int, _ analysis: AnalysisConfigFFI, _ config: VadConfigFFI) -> VadResultFFI {
+    VadResultFFI(success: true, timestamps_ptr: nil, energy_db_ptr: nil, zcr_ptr: nil, voiced_probs_ptr: nil, is_speech_ptr: nil, frame_count: 0, segments_ptr: nil, segment_count: 0, error_code: LOQA_OK)
+}
+public func loqa_free_voice_activity(_ result: UnsafeMutablePointer<VadResultFFI>?) {}
+
+// Vocal tract length
+public struct VtlConfigFFI {
+    public var lpc_order: UInt32
+    public var min_formant_confidence: Float
+    public var speed_of_sound: Float
+}
+public struct VtlResultFFI {
+    public var success: Bool
+    public var vtl_cm: Float
+    public var spacing_vtl_cm: Float
+    public var formant_spacing_hz: Float
+    public var f1: Float
+    public var f2: Float
+    public var f3: Float
+    public var f4: Float
+    public var confidence: Float
+    public var frame_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+public func loqa_vtl_config_default() -> VtlConfigFFI {
+    VtlConfigFFI(lpc_order: 0, min_formant_confidence: 0.3, speed_of_sound: 35000)
+}
+public func loqa_estimate_vocal_tract_length(_ buffer: UnsafePointer<Float>?, _ length: Int, _ analysis: AnalysisConfigFFI, _ config: VtlConfigFFI) -> VtlResultFFI {
+    VtlResultFFI(success: true, vtl_cm: 0, spacing_vtl_cm: 0, formant_spacing_hz: 0, f1: 0, f2: 0, f3: 0, f4: 0, confidence: 0, frame_count: 0, error_code: LOQA_OK)
+}
+
+// Streaming analyzer
+public struct StreamFramesFFI {
+    public var success: Bool
+    public var frequencies_ptr: UnsafeMutablePointer<Float>?
+    public var confidences_ptr: UnsafeMutablePointer<Float>?
+    public var is_voiced_ptr: UnsafeMutablePointer<Bool>?
+    public var voiced_probs_ptr: UnsafeMutablePointer<Float>?
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var frame_count: Int
+    public var error_code: LoqaErrorCode
+}
+public func loqa_stream_analyzer_new(_ config: AnalysisConfigFFI) -> UnsafeMutableRawPointer? { nil }
+public func loqa_stream_analyzer_push(_ analyzer: UnsafeMutableRawPointer?, _ samples: UnsafePointer<Float>?, _ len: Int) -> StreamFramesFFI {
+    StreamFramesFFI(success: true, frequencies_ptr: nil, confidences_ptr: nil, is_voiced_ptr: nil, voiced_probs_ptr: nil, timestamps_ptr: nil, frame_count: 0, error_code: LOQA_OK)
+}
+public func loqa_stream_analyzer_flush(_ analyzer: UnsafeMutableRawPointer?) -> StreamFramesFFI {
+    StreamFramesFFI(success: true, frequencies_ptr: nil, confidences_ptr: nil, is_voiced_ptr: nil, voiced_probs_ptr: nil, timestamps_ptr: nil, frame_count: 0, error_code: LOQA_OK)
+}
+public func loqa_stream_analyzer_reset(_ analyzer: UnsafeMutableRawPointer?) {}
+public func loqa_stream_analyzer_free(_ analyzer: UnsafeMutableRawPointer?) {}
+public func loqa_free_stream_frames(_ result: UnsafeMutablePointer<StreamFramesFFI>?) {}
+
+// Tuner
+public struct TunerConfigFFI {
+    public var reference_hz: Float
+    public var temperament_cents: (Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float, Float)
+    public var stability_frames: UInt32
+}
+public struct TunerReadingFFI {
+    public var success: Bool
+    public var frame_count: UInt32
+    public var timestamp: Float
+    public var is_voiced: Bool
+    public var frequency: Float
+    public var confidence: Float
+    public var midi_note: Int32
+    public var pitch_class: UInt32
+    public var octave: Int32
+    public var cents: Float
+    public var target_frequency: Float
+    public var stability: Float
+    public var error_code: LoqaErrorCode
+}
+public func loqa_tuner_config_default() -> TunerConfigFFI {
+    TunerConfigFFI(reference_hz: 440.0, temperament_cents: (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0), stability_frames: 8)
+}
+public func loqa_tuner_new(_ analysis: AnalysisConfigFFI, _ config: TunerConfigFFI) -> UnsafeMutableRawPointer? { nil }
+public func loqa_tuner_push(_ tuner: UnsafeMutableRawPointer?, _ samples: UnsafePointer<Float>?, _ len: Int) -> TunerReadingFFI {
+    TunerReadingFFI(success: true, frame_count: 0, timestamp: 0, is_voiced: false, frequency: 0, confidence: 0, midi_note: 0, pitch_class: 0, octave: 0, cents: 0, target_frequency: 0, stability: 0, error_code: LOQA_OK)
+}
+public func loqa_tuner_reset(_ tuner: UnsafeMutableRawPointer?) {}
+public func loqa_tuner_free(_ tuner: UnsafeMutableRawPointer?) {}
+
+// Voice range profile
+public struct VrpConfigFFI {
+    public var reference_hz: Float
+    public var min_midi_note: UInt32
+    public var max_midi_note: UInt32
+    public var min_db: Float
+    public var max_db: Float
+    public var db_step: Float
+    public var min_cell_frames: UInt32
+}
+public struct VrpFFI {
+    public var success: Bool
+    public var counts_ptr: UnsafeMutablePointer<UInt32>?
+    public var soft_db_ptr: UnsafeMutablePointer<Float>?
+    public var loud_db_ptr: UnsafeMutablePointer<Float>?
+    public var dynamic_range_db_ptr: UnsafeMutablePointer<Float>?
+    public var row_count: Int
+    public var column_count: Int
+    public var min_midi_note: Int32
+    public var min_db: Float
+    public var db_step: Float
+    public var lowest_midi_note: Int32
+    public var highest_midi_note: Int32
+    public var lowest_hz: Float
+    public var highest_hz: Float
+    public var covered_cells: UInt32
+    public var area: Float
+    public var frame_count: UInt32
+    public var error_code: LoqaErrorCode
+}
+public func loqa_vrp_config_default() -> VrpConfigFFI {
+    VrpConfigFFI(reference_hz: 440.0, min_midi_note: 24, max_midi_note: 108, min_db: 40.0, max_db: 120.0, db_step: 1.0, min_cell_frames: 3)
+}
+public func loqa_vrp_new(_ config: VrpConfigFFI) -> UnsafeMutableRawPointer? { nil }
+public func loqa_vrp_add_frames(_ profile: UnsafeMutableRawPointer?, _ pitchTrack: UnsafePointer<Float>?, _ intensityDb: UnsafePointer<Float>?, _ length: Int) -> LoqaErrorCode { LOQA_OK }
+public func loqa_vrp_merge(_ target: UnsafeMutableRawPointer?, _ source: UnsafeRawPointer?) -> LoqaErrorCode { LOQA_OK }
+public func loqa_vrp_get(_ profile: UnsafeRawPointer?) -> VrpFFI {
+    VrpFFI(success: true, counts_ptr: nil, soft_db_ptr: nil, loud_db_ptr: nil, dynamic_range_db_ptr: nil, row_count: 0, column_count: 0, min_midi_note: 0, min_db: 0, db_step: 0, lowest_midi_note: -1, highest_midi_note: -1, lowest_hz: .nan, highest_hz: .nan, covered_cells: 0, area: 0, frame_count: 0, error_code: LOQA_OK)
+}
+public func loqa_free_vrp(_ result: UnsafeMutablePointer<VrpFFI>?) {}
+public func loqa_vrp_reset(_ profile: UnsafeMutableRawPointer?) {}
+public func loqa_vrp_free(_ profile: UnsafeMutableRawPointer?) {}
+
+// Formant tracker
+public struct FormantTrackerConfigFFI {
+    public var sample_rate: UInt32
+    public var frame_size: UInt32
+    public var hop_size: UInt32
+    public var lpc_order: UInt32
+    public var ceiling_hz: Float
+    public var continuity_weight: Float
+    public var min_confidence: Float
+    public var silence_db: Float
+}
+public struct FormantTrackFFI {
+    public var success: Bool
+    public var frequencies_ptr: UnsafeMutablePointer<Float>?
+    public var bandwidths_ptr: UnsafeMutablePointer<Float>?
+    public var confidences_ptr: UnsafeMutablePointer<Float>?
+    public var reliable_ptr: UnsafeMutablePointer<Bool>?
+    public var timestamps_ptr: UnsafeMutablePointer<Float>?
+    public var length: Int
+    public var error_code: LoqaErrorCode
+}
+public func loqa_formant_tracker_config_default() -> FormantTrackerConfigFFI {
+    FormantTrackerConfigFFI(sample_rate: 16000, frame_size: 400, hop_size: 160, lpc_order: 0, ceiling_hz: 5500, continuity_weight: 1, min_confidence: 0.3, silence_db: -50)
+}
+public func loqa_formant_tracker_new(_ config: FormantTrackerConfigFFI) -> UnsafeMutableRawPointer? { nil }
+public func loqa_formant_tracker_process_buffer(_ tracker: UnsafeMutableRawPointer?, _ samples: UnsafePointer<Float>?, _ len: Int) -> FormantTrackFFI {
+    FormantTrackFFI(success: true, frequencies_ptr: nil, bandwidths_ptr: nil, confidences_ptr: nil, reliable_ptr: nil, timestamps_ptr: nil, length: 0, error_code: LOQA_OK)
+}
+public func loqa_formant_tracker_reset(_ tracker: UnsafeMutableRawPointer?) {}
+public func loqa_formant_tracker_free(_ tracker: UnsafeMutableRawPointer?) {}
+public func loqa_free_formant_track(_ result: UnsafeMutablePointer<FormantTrackFFI>?) {}
 EOF
 
 # Copy Swift files to temp dir (excluding the actual module import)
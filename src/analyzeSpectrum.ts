@@ -1,18 +1,46 @@
 // analyzeSpectrum - Spectral analysis API
 import LoqaExpoDspModule from './LoqaExpoDspModule';
-import { NativeModuleError } from './errors';
+import { NativeModuleError, ValidationError } from './errors';
 import type { SpectrumAnalysisOptions, SpectrumResult } from './types';
 import { logDebug } from './utils';
 import { validateAudioBuffer, validateSampleRate } from './validation';
 
 /**
- * Analyzes spectral features (centroid, rolloff, tilt)
+ * Validates the optional FFT size and rolloff thresholds
+ */
+function validateSpectrumOptions(options?: Partial<SpectrumAnalysisOptions>): void {
+  const fftSize = options?.fftSize;
+  if (fftSize !== undefined && fftSize !== 0) {
+    const isPowerOfTwo =
+      Number.isInteger(fftSize) && fftSize > 0 && (fftSize & (fftSize - 1)) === 0;
+    if (!isPowerOfTwo || fftSize < 64 || fftSize > 8192) {
+      throw new ValidationError('fftSize must be 0 (auto) or a power of 2 between 64 and 8192', {
+        fftSize,
+      });
+    }
+  }
+
+  const low = options?.rolloffLowThreshold ?? 0.85;
+  const high = options?.rolloffHighThreshold ?? 0.95;
+  if (!(low > 0 && low <= high && high <= 1)) {
+    throw new ValidationError(
+      'Rolloff thresholds must satisfy 0 < rolloffLowThreshold <= rolloffHighThreshold <= 1',
+      { rolloffLowThreshold: low, rolloffHighThreshold: high }
+    );
+  }
+}
+
+/**
+ * Analyzes spectral features (centroid, rolloff, tilt and spectral shape)
  *
  * This function computes spectral characteristics of audio data, including:
  * - Spectral centroid: "center of mass" of the spectrum (brightness measure)
- * - Spectral rolloff: frequency below which 95% of energy is concentrated
+ * - Spectral rolloff: frequency below which 95% (and 85%) of energy is concentrated
  * - Spectral tilt: overall slope of the spectrum (timbre indicator)
+ * - Spread, skewness, kurtosis, flatness, entropy and crest factor
  *
+ * Buffers of any length are accepted: the FFT size is fitted to the buffer
+ * (or set with `fftSize`), and longer buffers are averaged over frames.
  * All features are computed in a single call for efficiency.
  *
  * @param audioBuffer - Audio samples (Float32Array or number[])
@@ -49,6 +77,7 @@ export async function analyzeSpectrum(
 
   validateAudioBuffer(audioBuffer);
   validateSampleRate(sampleRate);
+  validateSpectrumOptions(options);
 
   // Step 2: Convert to number[] for React Native bridge
   // React Native bridge requires plain arrays, not typed arrays
@@ -80,6 +109,14 @@ export async function analyzeSpectrum(
       centroid: nativeResult.centroid,
       rolloff: nativeResult.rolloff,
       tilt: nativeResult.tilt,
+      spread: nativeResult.spread,
+      skewness: nativeResult.skewness,
+      kurtosis: nativeResult.kurtosis,
+      flatness: nativeResult.flatness,
+      entropy: nativeResult.entropy,
+      crestFactor: nativeResult.crestFactor,
+      rolloffLow: nativeResult.rolloffLow,
+      rolloffHigh: nativeResult.rolloffHigh,
     };
 
     logDebug('analyzeSpectrum completed successfully', {
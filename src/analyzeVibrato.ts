@@ -0,0 +1,207 @@
+// analyzeVibrato - Vibrato rate, extent, regularity and onset from a pitch track
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { VibratoAnalysis, VibratoOptions, VibratoSegment } from './types';
+import { logDebug } from './utils';
+
+/** Analysis returned by native analyzeVibrato; times from the first frame */
+interface NativeVibrato {
+  vibratoRatio: number;
+  rateHz: number | null;
+  extentCents: number | null;
+  rateJitter: number | null;
+  extentJitter: number | null;
+  onsetDelay: number | null;
+  segments: {
+    start: number;
+    end: number;
+    rateHz: number;
+    extentCents: number;
+    rateJitter: number;
+    extentJitter: number;
+    onsetDelay: number;
+  }[];
+  windowTimes: number[];
+  windowRateHz: (number | null)[];
+  windowExtentCents: (number | null)[];
+  windowRateJitter: (number | null)[];
+  windowExtentJitter: (number | null)[];
+}
+
+/**
+ * Finds vibrato in a pitch track and measures its rate, extent, regularity and onset
+ *
+ * Each voiced note is detrended, and its peaks and troughs in cents form
+ * vibrato segments where at least minCycles cycles stay within the rate and
+ * extent ranges. Rate jitter and extent jitter measure how much consecutive
+ * cycles differ, so lower values mean a more regular vibrato; the onset delay
+ * is the time from the start of a note to its first vibrato cycle.
+ *
+ * Besides the whole-track result, windows of windowDuration seconds are
+ * analysed on their own. The last window always ends at the last frame, so a
+ * live display can pass the recent frames of a stream and read the final
+ * window entry.
+ *
+ * @param track - Pitch per frame in Hz (0 = unvoiced) with optional frame
+ *   timestamps, e.g. from processBuffer() or postprocessPitchTrack()
+ * @param options - Optional frame rate, detection ranges and windows
+ * @returns Promise resolving to VibratoAnalysis
+ * @throws ValidationError if the track or options are invalid
+ * @throws NativeModuleError if native analysis fails
+ *
+ * @example
+ * ```typescript
+ * const track = await processBuffer(analyzer, recording);
+ * const vibrato = await analyzeVibrato(track);
+ * for (const segment of vibrato.segments) {
+ *   console.log(`${segment.start}s: ${segment.rate.toFixed(1)} Hz ±${segment.extent} cents`);
+ * }
+ * ```
+ */
+export async function analyzeVibrato(
+  track: { pitchTrack: Float32Array | number[]; timestamps?: Float32Array | number[] },
+  options: VibratoOptions = {}
+): Promise<VibratoAnalysis> {
+  logDebug('analyzeVibrato called', {
+    frameCount: track?.pitchTrack?.length,
+    options,
+  });
+
+  if (!track || !track.pitchTrack || track.pitchTrack.length === 0) {
+    throw new ValidationError('Pitch track cannot be empty', {
+      frameCount: track?.pitchTrack?.length ?? 0,
+    });
+  }
+
+  const frameCount = track.pitchTrack.length;
+  const timestamps = track.timestamps;
+  if (timestamps && timestamps.length !== frameCount) {
+    throw new ValidationError('pitchTrack and timestamps must have equal lengths', {
+      pitchTrack: frameCount,
+      timestamps: timestamps.length,
+    });
+  }
+
+  // Convert to number[] for React Native bridge
+  const pitchArray = Array.from(track.pitchTrack);
+  if (pitchArray.some((f) => !(Number.isFinite(f) && f >= 0))) {
+    throw new ValidationError('Pitch track values must be finite and >= 0 (0 = unvoiced)', {
+      frameCount,
+    });
+  }
+
+  let frameRate = options.frameRate;
+  if (frameRate === undefined && timestamps && frameCount >= 2) {
+    frameRate = (frameCount - 1) / (timestamps[frameCount - 1] - timestamps[0]);
+  }
+  if (frameRate === undefined || !Number.isFinite(frameRate) || frameRate <= 0) {
+    throw new ValidationError(
+      'frameRate must be a positive number, or the track needs increasing timestamps',
+      { frameRate, frameCount }
+    );
+  }
+
+  const minRate = options.minRate ?? 4;
+  const maxRate = options.maxRate ?? 8;
+  const minExtent = options.minExtent ?? 15;
+  const maxExtent = options.maxExtent ?? 200;
+  const minCycles = options.minCycles ?? 2;
+  const windowDuration = options.windowDuration ?? 1;
+  const windowHopFrames = options.windowHopFrames ?? 1;
+
+  if (!(minRate > 0 && maxRate > minRate && maxRate * 4 <= frameRate)) {
+    throw new ValidationError('Rates must satisfy 0 < minRate < maxRate <= frameRate / 4', {
+      minRate,
+      maxRate,
+      frameRate,
+    });
+  }
+
+  if (!(minExtent > 0 && maxExtent > minExtent && Number.isFinite(maxExtent))) {
+    throw new ValidationError('Extents must satisfy 0 < minExtent < maxExtent', {
+      minExtent,
+      maxExtent,
+    });
+  }
+
+  if (!Number.isInteger(minCycles) || minCycles < 2) {
+    throw new ValidationError('minCycles must be an integer >= 2', { minCycles });
+  }
+
+  if (!Number.isFinite(windowDuration) || windowDuration < 0) {
+    throw new ValidationError('windowDuration must be a finite number >= 0', {
+      windowDuration,
+    });
+  }
+
+  if (!Number.isInteger(windowHopFrames) || windowHopFrames < 1) {
+    throw new ValidationError('windowHopFrames must be a positive integer', {
+      windowHopFrames,
+    });
+  }
+
+  const nativeOptions = {
+    frameRate,
+    minRateHz: minRate,
+    maxRateHz: maxRate,
+    minExtentCents: minExtent,
+    maxExtentCents: maxExtent,
+    minCycles,
+    windowSeconds: windowDuration,
+    windowHopFrames,
+  };
+
+  try {
+    const native: NativeVibrato = await LoqaExpoDspModule.analyzeVibrato(
+      pitchArray,
+      nativeOptions
+    );
+
+    // Native times count from the first frame; put them on the track's timestamps
+    const offset = timestamps ? timestamps[0] : 0;
+    const segments: VibratoSegment[] = native.segments.map((segment) => ({
+      start: segment.start + offset,
+      end: segment.end + offset,
+      rate: segment.rateHz,
+      extent: segment.extentCents,
+      rateJitter: segment.rateJitter,
+      extentJitter: segment.extentJitter,
+      onsetDelay: segment.onsetDelay,
+    }));
+
+    const result: VibratoAnalysis = {
+      vibratoRatio: native.vibratoRatio,
+      rate: native.rateHz ?? null,
+      extent: native.extentCents ?? null,
+      rateJitter: native.rateJitter ?? null,
+      extentJitter: native.extentJitter ?? null,
+      onsetDelay: native.onsetDelay ?? null,
+      segments,
+      windowTimes: new Float32Array(native.windowTimes.map((time) => time + offset)),
+      windowRate: native.windowRateHz,
+      windowExtent: native.windowExtentCents,
+      windowRateJitter: native.windowRateJitter,
+      windowExtentJitter: native.windowExtentJitter,
+    };
+
+    logDebug('analyzeVibrato completed successfully', {
+      segmentCount: segments.length,
+      rate: result.rate,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('analyzeVibrato failed', {
+      error: errorMessage,
+      frameCount,
+    });
+
+    throw new NativeModuleError(`Vibrato analysis failed: ${errorMessage}`, {
+      originalError: error,
+      frameCount,
+      options: nativeOptions,
+    });
+  }
+}
@@ -0,0 +1,155 @@
+// calculateCPP - cepstral peak prominence (CPP and smoothed CPPS)
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { CPPOptions, CPPResult } from './types';
+import { logDebug } from './utils';
+
+/**
+ * Calculates cepstral peak prominence (CPP) and smoothed CPP (CPPS)
+ *
+ * The power cepstrum of each frame's log spectrum has a peak at the period
+ * of the voice; CPP is its height in dB above a straight line fitted to the
+ * cepstrum (Hillenbrand & Houde). CPPS first averages the cepstra across
+ * neighbouring frames and quefrencies, which makes it a robust dysphonia
+ * measure for connected speech. Lower values indicate a breathier voice.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Sample rate plus optional framing, F0 range and smoothing
+ * @returns Promise resolving to CPPResult with cpp and cpps in dB
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const { cpps } = await calculateCPP(recording, { sampleRate: 16000 });
+ * console.log(`CPPS: ${cpps.toFixed(1)} dB`);
+ * ```
+ */
+export async function calculateCPP(
+  audioBuffer: Float32Array | number[],
+  options: CPPOptions
+): Promise<CPPResult> {
+  logDebug('calculateCPP called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const sampleRate = options.sampleRate;
+  if (!(Number.isInteger(sampleRate) && sampleRate > 0)) {
+    throw new ValidationError('sampleRate must be a positive integer', { sampleRate });
+  }
+
+  const frameSize = options.frameSize ?? 2048;
+  if (
+    !Number.isInteger(frameSize) ||
+    frameSize < 64 ||
+    frameSize > 8192 ||
+    (frameSize & (frameSize - 1)) !== 0
+  ) {
+    throw new ValidationError('frameSize must be a power of 2 between 64 and 8192', {
+      frameSize,
+    });
+  }
+
+  const hopSize = options.hopSize ?? 128;
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  const minFrequency = options.minFrequency ?? 60;
+  const maxFrequency = options.maxFrequency ?? 330;
+  const validRange =
+    Number.isFinite(minFrequency) &&
+    minFrequency > 0 &&
+    maxFrequency > minFrequency &&
+    maxFrequency <= sampleRate / 2;
+  if (!validRange) {
+    throw new ValidationError(
+      'minFrequency and maxFrequency must satisfy 0 < min < max <= sampleRate / 2',
+      { minFrequency, maxFrequency, sampleRate }
+    );
+  }
+
+  // The longest period searched must fit in the first half of the cepstrum
+  if (Math.ceil(sampleRate / minFrequency) >= frameSize / 2) {
+    throw new ValidationError('frameSize must exceed two periods of minFrequency', {
+      frameSize,
+      minFrequency,
+      sampleRate,
+    });
+  }
+
+  const timeSmoothing = options.timeSmoothing ?? 0.02;
+  const quefrencySmoothing = options.quefrencySmoothing ?? 0.0005;
+  for (const [name, value] of [
+    ['timeSmoothing', timeSmoothing],
+    ['quefrencySmoothing', quefrencySmoothing],
+  ] as const) {
+    if (!(Number.isFinite(value) && value >= 0)) {
+      throw new ValidationError(`${name} must be a finite number >= 0`, { [name]: value });
+    }
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = {
+    frameSize,
+    hopSize,
+    minFrequency,
+    maxFrequency,
+    timeSmoothing,
+    quefrencySmoothing,
+  };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.calculateCPP(
+      bufferArray,
+      sampleRate,
+      nativeOptions
+    );
+
+    const result: CPPResult = {
+      cpp: nativeResult.cpp,
+      cpps: nativeResult.cpps,
+      f0: nativeResult.f0,
+      frameCount: nativeResult.frameCount,
+    };
+
+    logDebug('calculateCPP completed successfully', result);
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('calculateCPP failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`CPP calculation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate,
+      frameSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
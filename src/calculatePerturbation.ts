@@ -0,0 +1,196 @@
+// calculateJitter / calculateShimmer - Praat-compatible period and amplitude perturbation
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { JitterResult, PerturbationOptions, ShimmerResult } from './types';
+import { logDebug } from './utils';
+import { validateSampleRate } from './validation';
+
+/**
+ * Pitch tracking frame size used natively; the buffer must extend past it
+ */
+const PITCH_FRAME_SIZE = 2048;
+
+/**
+ * Validates the buffer and options and applies the frequency range defaults
+ *
+ * Unlike the single-frame measures there is no 16384 sample limit: jitter and
+ * shimmer are meant for whole sustained vowels or phrases.
+ */
+function validatePerturbationInput(
+  audioBuffer: Float32Array | number[],
+  options: PerturbationOptions
+): { minFrequency: number; maxFrequency: number } {
+  validateSampleRate(options.sampleRate);
+
+  const minFrequency = options.minFrequency ?? 75;
+  const maxFrequency = options.maxFrequency ?? 500;
+  const validRange =
+    Number.isFinite(minFrequency) &&
+    Number.isFinite(maxFrequency) &&
+    minFrequency > 0 &&
+    maxFrequency > minFrequency;
+  if (!validRange) {
+    throw new ValidationError('minFrequency and maxFrequency must satisfy 0 < min < max', {
+      minFrequency,
+      maxFrequency,
+    });
+  }
+
+  if (!audioBuffer || audioBuffer.length <= PITCH_FRAME_SIZE) {
+    throw new ValidationError(`Audio buffer must be longer than ${PITCH_FRAME_SIZE} samples`, {
+      bufferLength: audioBuffer?.length ?? 0,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  return { minFrequency, maxFrequency };
+}
+
+/**
+ * Calculates Praat-compatible jitter (cycle-to-cycle period variation)
+ *
+ * Glottal pulses are picked from the waveform inside the voiced frames of a
+ * pitch track, and periods outside 0.1-20 ms or differing from a neighbour by
+ * more than a factor of 1.3 are skipped, as in Praat's "Voice report".
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than 2048 samples
+ * @param options - Sample rate and optional pitch range
+ * @returns Promise resolving to JitterResult; relative measures are fractions
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails or fewer than 3 valid periods are found
+ *
+ * @example
+ * ```typescript
+ * const jitter = await calculateJitter(sustainedVowel, { sampleRate: 44100 });
+ * if (jitter.local !== null) {
+ *   console.log(`Jitter (local): ${(jitter.local * 100).toFixed(2)} %`);
+ * }
+ * ```
+ */
+export async function calculateJitter(
+  audioBuffer: Float32Array | number[],
+  options: PerturbationOptions
+): Promise<JitterResult> {
+  logDebug('calculateJitter called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const nativeOptions = validatePerturbationInput(audioBuffer, options);
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.calculateJitter(
+      bufferArray,
+      options.sampleRate,
+      nativeOptions
+    );
+
+    const result: JitterResult = {
+      local: nativeResult.local ?? null,
+      localAbsolute: nativeResult.localAbsolute ?? null,
+      rap: nativeResult.rap ?? null,
+      ppq5: nativeResult.ppq5 ?? null,
+      ddp: nativeResult.ddp ?? null,
+      meanPeriod: nativeResult.meanPeriod ?? null,
+      periodCount: nativeResult.periodCount,
+    };
+
+    logDebug('calculateJitter completed successfully', result);
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('calculateJitter failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Jitter calculation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate: options.sampleRate,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
+
+/**
+ * Calculates Praat-compatible shimmer (cycle-to-cycle amplitude variation)
+ *
+ * Uses the peak amplitude of the same periods as calculateJitter, skipping
+ * neighbours whose amplitudes differ by more than a factor of 1.6.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than 2048 samples
+ * @param options - Sample rate and optional pitch range
+ * @returns Promise resolving to ShimmerResult; localDb is in dB, other measures are fractions
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails or fewer than 3 valid periods are found
+ *
+ * @example
+ * ```typescript
+ * const shimmer = await calculateShimmer(sustainedVowel, { sampleRate: 44100 });
+ * console.log(`Shimmer (local, dB): ${shimmer.localDb}`);
+ * ```
+ */
+export async function calculateShimmer(
+  audioBuffer: Float32Array | number[],
+  options: PerturbationOptions
+): Promise<ShimmerResult> {
+  logDebug('calculateShimmer called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const nativeOptions = validatePerturbationInput(audioBuffer, options);
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.calculateShimmer(
+      bufferArray,
+      options.sampleRate,
+      nativeOptions
+    );
+
+    const result: ShimmerResult = {
+      local: nativeResult.local ?? null,
+      localDb: nativeResult.localDb ?? null,
+      apq3: nativeResult.apq3 ?? null,
+      apq5: nativeResult.apq5 ?? null,
+      apq11: nativeResult.apq11 ?? null,
+      dda: nativeResult.dda ?? null,
+      meanAmplitude: nativeResult.meanAmplitude ?? null,
+      periodCount: nativeResult.periodCount,
+    };
+
+    logDebug('calculateShimmer completed successfully', result);
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('calculateShimmer failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Shimmer calculation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate: options.sampleRate,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
@@ -1,10 +1,45 @@
 // computeFFT - FFT computation API
 import LoqaExpoDspModule from './LoqaExpoDspModule';
-import { NativeModuleError } from './errors';
-import type { FFTOptions, FFTResult } from './types';
+import { NativeModuleError, ValidationError } from './errors';
+import type { ComplexFFTOptions, ComplexSpectrum, FFTOptions, FFTResult } from './types';
 import { logDebug } from './utils';
 import { validateAudioBuffer, validateFFTSize } from './validation';
 
+const WINDOW_TYPES = ['hanning', 'hamming', 'blackman', 'none', 'kaiser', 'flattop', 'gaussian'];
+const NORMALIZATIONS = ['amplitude', 'psd'];
+
+/**
+ * Validates the window, normalization and sample rate options
+ */
+function validateFFTOptions(options?: FFTOptions): void {
+  if (options?.windowType !== undefined && !WINDOW_TYPES.includes(options.windowType)) {
+    throw new ValidationError(`windowType must be one of: ${WINDOW_TYPES.join(', ')}`, {
+      windowType: options.windowType,
+    });
+  }
+
+  const windowParameter = options?.windowParameter;
+  if (
+    windowParameter !== undefined &&
+    !(Number.isFinite(windowParameter) && windowParameter >= 0)
+  ) {
+    throw new ValidationError('windowParameter must be a finite number >= 0', {
+      windowParameter,
+    });
+  }
+
+  if (options?.normalization !== undefined && !NORMALIZATIONS.includes(options.normalization)) {
+    throw new ValidationError(`normalization must be one of: ${NORMALIZATIONS.join(', ')}`, {
+      normalization: options.normalization,
+    });
+  }
+
+  const sampleRate = options?.sampleRate;
+  if (sampleRate !== undefined && !(Number.isInteger(sampleRate) && sampleRate > 0)) {
+    throw new ValidationError('sampleRate must be a positive integer', { sampleRate });
+  }
+}
+
 /**
  * Computes Fast Fourier Transform (FFT) of audio buffer
  *
@@ -12,6 +47,10 @@ import { validateAudioBuffer, validateFFTSize } from './validation';
  * It accepts audio buffers as Float32Array or number[], validates the input,
  * and returns magnitude and frequency information.
  *
+ * The buffer is windowed before the transform and magnitudes are compensated for
+ * the window's gain, so with the default 'amplitude' normalization a sine of
+ * amplitude A produces a peak of about A whichever window is used.
+ *
  * @param audioBuffer - Audio samples (Float32Array or number[])
  * @param options - FFT configuration options
  * @returns Promise resolving to FFT result with magnitude, frequencies, and optional phase
@@ -49,10 +88,14 @@ export async function computeFFT(
   // Step 2: Extract and validate options with defaults
   const fftSize = options?.fftSize || audioBuffer.length;
   const windowType = options?.windowType || 'hanning';
+  const windowParameter = options?.windowParameter ?? 0;
+  const normalization = options?.normalization || 'amplitude';
+  const sampleRate = options?.sampleRate ?? 44100;
   const includePhase = options?.includePhase || false;
 
-  // Validate FFT size
+  // Validate FFT size and the remaining options
   validateFFTSize(fftSize);
+  validateFFTOptions(options);
 
   // Step 3: Convert to number[] for React Native bridge
   // React Native bridge requires plain arrays, not typed arrays
@@ -62,17 +105,17 @@ export async function computeFFT(
   logDebug('Calling native module', {
     fftSize,
     windowType,
+    normalization,
     includePhase,
   });
 
   try {
     // Step 4: Call native module
-    // Default sample rate to 44100 Hz if not provided (used for frequency calculation)
-    const sampleRate = 44100; // Default sample rate for frequency bin calculation
-
     const nativeResult = await LoqaExpoDspModule.computeFFT(bufferArray, {
       fftSize,
       windowType,
+      windowParameter,
+      normalization,
       includePhase,
       sampleRate,
     });
@@ -115,3 +158,105 @@ export async function computeFFT(
     });
   }
 }
+
+/**
+ * Computes the one-sided complex spectrum of an audio buffer
+ *
+ * No window is applied and the bins are not normalized, so the result can be
+ * modified and passed to `inverseFFT` for filtering or resynthesis.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[])
+ * @param options - Complex FFT configuration options
+ * @returns Promise resolving to interleaved re/im bins and the FFT size used
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const spectrum = await computeComplexFFT(audioData, { fftSize: 1024 });
+ * const reconstructed = await inverseFFT(spectrum.bins);
+ * ```
+ */
+export async function computeComplexFFT(
+  audioBuffer: Float32Array | number[],
+  options?: ComplexFFTOptions
+): Promise<ComplexSpectrum> {
+  logDebug('computeComplexFFT called', { bufferLength: audioBuffer.length, options });
+
+  validateAudioBuffer(audioBuffer);
+
+  // 0 lets Rust pick the smallest power of 2 holding the buffer
+  const fftSize = options?.fftSize ?? 0;
+  if (options?.fftSize !== undefined) {
+    validateFFTSize(fftSize);
+  }
+
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.computeComplexFFT(bufferArray, { fftSize });
+
+    return {
+      bins: new Float32Array(nativeResult.bins),
+      fftSize: nativeResult.fftSize,
+    };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    throw new NativeModuleError(`Complex FFT computation failed: ${errorMessage}`, {
+      originalError: error,
+      fftSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
+
+/**
+ * Computes the real signal whose one-sided spectrum is `bins`
+ *
+ * Inverts `computeComplexFFT`; the imaginary parts of the DC and Nyquist bins are
+ * ignored.
+ *
+ * @param bins - Interleaved `[re, im]` pairs, fftSize / 2 + 1 of them
+ * @returns Promise resolving to fftSize time-domain samples
+ * @throws ValidationError if the bins are malformed
+ * @throws NativeModuleError if native computation fails
+ */
+export async function inverseFFT(bins: Float32Array | number[]): Promise<Float32Array> {
+  logDebug('inverseFFT called', { binsLength: bins?.length });
+
+  if (!bins || bins.length === 0 || bins.length % 2 !== 0) {
+    throw new ValidationError('bins must be non-empty interleaved real/imaginary pairs', {
+      binsLength: bins?.length,
+    });
+  }
+
+  // The auto-sized forward transform can pick sizes below validateFFTSize's minimum
+  const fftSize = bins.length - 2;
+  if (fftSize < 64 || fftSize > 8192 || (fftSize & (fftSize - 1)) !== 0) {
+    throw new ValidationError('bins must hold fftSize / 2 + 1 pairs for a power of 2 fftSize', {
+      binsLength: bins.length,
+      minFFTSize: 64,
+      maxFFTSize: 8192,
+    });
+  }
+
+  if (Array.from(bins).some((v) => !isFinite(v))) {
+    throw new ValidationError('bins contain NaN or Infinity values');
+  }
+
+  const binsArray: number[] = bins instanceof Float32Array ? Array.from(bins) : bins;
+
+  try {
+    const samples = await LoqaExpoDspModule.inverseFFT(binsArray);
+    return new Float32Array(samples);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    throw new NativeModuleError(`Inverse FFT computation failed: ${errorMessage}`, {
+      originalError: error,
+      fftSize,
+    });
+  }
+}
@@ -0,0 +1,129 @@
+// computeIntensity - framewise RMS, peak and calibrated intensity contours
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { IntensityOptions, IntensityResult } from './types';
+import { logDebug } from './utils';
+
+/**
+ * Computes framewise RMS, peak and intensity contours of a whole buffer
+ *
+ * Each frame's unwindowed RMS and absolute peak are converted to dBFS, clamped
+ * at floorDb and shifted by calibrationOffset, so a microphone calibrated
+ * against a sound level meter reads approximate dB SPL. The summary mean is
+ * energy-averaged, which weights loud frames the way the ear does.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Sample rate plus optional framing, calibration offset and floor
+ * @returns Promise resolving to the contours, frame timestamps and mean/min/max/dynamic range
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const { intensityDb, timestamps, meanDb } = await computeIntensity(recording, {
+ *   sampleRate: 16000,
+ *   calibrationOffset: 94,
+ * });
+ * console.log(`Average level: ${meanDb.toFixed(1)} dB SPL`);
+ * ```
+ */
+export async function computeIntensity(
+  audioBuffer: Float32Array | number[],
+  options: IntensityOptions
+): Promise<IntensityResult> {
+  logDebug('computeIntensity called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const sampleRate = options.sampleRate;
+  if (!(Number.isInteger(sampleRate) && sampleRate > 0)) {
+    throw new ValidationError('sampleRate must be a positive integer', { sampleRate });
+  }
+
+  const frameSize = options.frameSize ?? 2048;
+  if (!(Number.isInteger(frameSize) && frameSize > 0)) {
+    throw new ValidationError('frameSize must be a positive integer', { frameSize });
+  }
+
+  const hopSize = options.hopSize ?? 512;
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  const calibrationOffset = options.calibrationOffset ?? 0;
+  const floorDb = options.floorDb ?? -120;
+  for (const [name, value] of [
+    ['calibrationOffset', calibrationOffset],
+    ['floorDb', floorDb],
+  ] as const) {
+    if (!Number.isFinite(value)) {
+      throw new ValidationError(`${name} must be a finite number`, { [name]: value });
+    }
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = { frameSize, hopSize, calibrationOffset, floorDb };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.computeIntensity(
+      bufferArray,
+      sampleRate,
+      nativeOptions
+    );
+
+    const result: IntensityResult = {
+      rms: new Float32Array(nativeResult.rms),
+      intensityDb: new Float32Array(nativeResult.intensityDb),
+      peakDb: new Float32Array(nativeResult.peakDb),
+      timestamps: new Float32Array(nativeResult.timestamps),
+      meanDb: nativeResult.meanDb,
+      minDb: nativeResult.minDb,
+      maxDb: nativeResult.maxDb,
+      dynamicRangeDb: nativeResult.dynamicRangeDb,
+    };
+
+    logDebug('computeIntensity completed successfully', {
+      frameCount: result.timestamps.length,
+      meanDb: result.meanDb,
+      dynamicRangeDb: result.dynamicRangeDb,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('computeIntensity failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Intensity computation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate,
+      frameSize,
+      hopSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
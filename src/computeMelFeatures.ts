@@ -0,0 +1,303 @@
+// computeMelSpectrogram / computeMFCC - mel filterbank features over a whole buffer
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { FeatureMatrix, MFCCOptions, MelSpectrogramOptions } from './types';
+import { logDebug } from './utils';
+
+const WINDOW_TYPES = ['hanning', 'hamming', 'blackman', 'none', 'kaiser', 'flattop', 'gaussian'];
+const MEL_SCALES = ['htk', 'slaney'];
+
+/**
+ * Native options shared by both mel exports, with defaults applied
+ */
+interface NativeMelOptions {
+  frameSize: number;
+  hopSize: number;
+  windowType: string;
+  windowParameter: number;
+  nMels: number;
+  fmin: number;
+  fmax: number;
+  melScale: string;
+}
+
+/**
+ * Validates the buffer and filterbank options and applies their defaults
+ *
+ * Framing rules match `computeSpectrogram`: the buffer has no upper length
+ * limit but must extend past the first frame.
+ */
+function validateMelInput(
+  audioBuffer: Float32Array | number[],
+  options: MelSpectrogramOptions
+): NativeMelOptions {
+  const sampleRate = options.sampleRate;
+  if (!(Number.isInteger(sampleRate) && sampleRate > 0)) {
+    throw new ValidationError('sampleRate must be a positive integer', { sampleRate });
+  }
+
+  const frameSize = options.frameSize ?? 2048;
+  if (
+    !Number.isInteger(frameSize) ||
+    frameSize < 64 ||
+    frameSize > 8192 ||
+    (frameSize & (frameSize - 1)) !== 0
+  ) {
+    throw new ValidationError('frameSize must be a power of 2 between 64 and 8192', {
+      frameSize,
+    });
+  }
+
+  const hopSize = options.hopSize ?? 512;
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  if (options.windowType !== undefined && !WINDOW_TYPES.includes(options.windowType)) {
+    throw new ValidationError(`windowType must be one of: ${WINDOW_TYPES.join(', ')}`, {
+      windowType: options.windowType,
+    });
+  }
+
+  const windowParameter = options.windowParameter ?? 0;
+  if (!(Number.isFinite(windowParameter) && windowParameter >= 0)) {
+    throw new ValidationError('windowParameter must be a finite number >= 0', {
+      windowParameter,
+    });
+  }
+
+  const nMels = options.nMels ?? 40;
+  if (!(Number.isInteger(nMels) && nMels >= 1 && nMels <= 256)) {
+    throw new ValidationError('nMels must be an integer between 1 and 256', { nMels });
+  }
+
+  // fmax = 0 selects Nyquist
+  const nyquist = sampleRate / 2;
+  const fmin = options.fmin ?? 0;
+  const fmax = options.fmax ?? 0;
+  const upper = fmax === 0 ? nyquist : fmax;
+  const validRange = Number.isFinite(fmin) && fmin >= 0 && upper > fmin && upper <= nyquist;
+  if (!validRange) {
+    throw new ValidationError('fmin and fmax must satisfy 0 <= fmin < fmax <= sampleRate / 2', {
+      fmin,
+      fmax,
+      sampleRate,
+    });
+  }
+
+  if (options.melScale !== undefined && !MEL_SCALES.includes(options.melScale)) {
+    throw new ValidationError(`melScale must be one of: ${MEL_SCALES.join(', ')}`, {
+      melScale: options.melScale,
+    });
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  return {
+    frameSize,
+    hopSize,
+    windowType: options.windowType ?? 'hanning',
+    windowParameter,
+    nMels,
+    fmin,
+    fmax,
+    melScale: options.melScale ?? 'slaney',
+  };
+}
+
+/**
+ * Converts the native result to typed arrays
+ */
+function toFeatureMatrix(nativeResult: {
+  values: number[];
+  timestamps: number[];
+  frameCount: number;
+  featureCount: number;
+}): FeatureMatrix {
+  return {
+    values: new Float32Array(nativeResult.values),
+    timestamps: new Float32Array(nativeResult.timestamps),
+    frameCount: nativeResult.frameCount,
+    featureCount: nativeResult.featureCount,
+  };
+}
+
+/**
+ * Computes the log-mel spectrogram of a whole buffer
+ *
+ * Each frame's power spectrum (a sine of amplitude A contributes A^2) is
+ * weighted by triangular mel filters between fmin and fmax, and the band
+ * energies are returned in dB with a -100 dB floor.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Framing and filterbank options including sampleRate
+ * @returns Promise resolving to a frames x nMels matrix and frame timestamps
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const melSpectrogram = await computeMelSpectrogram(recording, {
+ *   sampleRate: 16000,
+ *   frameSize: 512,
+ *   hopSize: 160,
+ *   nMels: 64,
+ * });
+ * ```
+ */
+export async function computeMelSpectrogram(
+  audioBuffer: Float32Array | number[],
+  options: MelSpectrogramOptions
+): Promise<FeatureMatrix> {
+  logDebug('computeMelSpectrogram called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const nativeOptions = validateMelInput(audioBuffer, options);
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.computeMelSpectrogram(
+      bufferArray,
+      options.sampleRate,
+      nativeOptions
+    );
+
+    logDebug('Native module returned mel spectrogram', {
+      frameCount: nativeResult.frameCount,
+      featureCount: nativeResult.featureCount,
+    });
+
+    return toFeatureMatrix(nativeResult);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('computeMelSpectrogram failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Mel spectrogram computation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate: options.sampleRate,
+      frameSize: nativeOptions.frameSize,
+      nMels: nativeOptions.nMels,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
+
+/**
+ * Computes mel-frequency cepstral coefficients (MFCCs) of a whole buffer
+ *
+ * Coefficients are the orthonormal DCT-II of each log-mel frame, optionally
+ * liftered. With deltaOrder 1 or 2 each row is followed by its regression
+ * deltas and delta-deltas over +/- deltaWidth frames.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Framing, filterbank and cepstral options including sampleRate
+ * @returns Promise resolving to a frames x (nMfcc * (1 + deltaOrder)) matrix and frame timestamps
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const mfcc = await computeMFCC(recording, {
+ *   sampleRate: 16000,
+ *   nMfcc: 13,
+ *   lifter: 22,
+ *   deltaOrder: 2,
+ * });
+ * // Each row: 13 coefficients, 13 deltas, 13 delta-deltas
+ * ```
+ */
+export async function computeMFCC(
+  audioBuffer: Float32Array | number[],
+  options: MFCCOptions
+): Promise<FeatureMatrix> {
+  logDebug('computeMFCC called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const melOptions = validateMelInput(audioBuffer, options);
+
+  const nMfcc = options.nMfcc ?? 13;
+  if (!(Number.isInteger(nMfcc) && nMfcc >= 1 && nMfcc <= melOptions.nMels)) {
+    throw new ValidationError('nMfcc must be an integer between 1 and nMels', {
+      nMfcc,
+      nMels: melOptions.nMels,
+    });
+  }
+
+  const lifter = options.lifter ?? 0;
+  if (!(Number.isFinite(lifter) && lifter >= 0)) {
+    throw new ValidationError('lifter must be a finite number >= 0', { lifter });
+  }
+
+  const deltaOrder = options.deltaOrder ?? 0;
+  if (![0, 1, 2].includes(deltaOrder)) {
+    throw new ValidationError('deltaOrder must be 0, 1 or 2', { deltaOrder });
+  }
+
+  const deltaWidth = options.deltaWidth ?? 2;
+  if (!(Number.isInteger(deltaWidth) && deltaWidth >= 1)) {
+    throw new ValidationError('deltaWidth must be a positive integer', { deltaWidth });
+  }
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = { ...melOptions, nMfcc, lifter, deltaOrder, deltaWidth };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.computeMFCC(
+      bufferArray,
+      options.sampleRate,
+      nativeOptions
+    );
+
+    logDebug('Native module returned MFCCs', {
+      frameCount: nativeResult.frameCount,
+      featureCount: nativeResult.featureCount,
+    });
+
+    return toFeatureMatrix(nativeResult);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('computeMFCC failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`MFCC computation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate: options.sampleRate,
+      frameSize: melOptions.frameSize,
+      nMfcc,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
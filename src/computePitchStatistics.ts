@@ -0,0 +1,153 @@
+// computePitchStatistics - Hz and semitone statistics of a pitch track
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { PitchStatistics, PitchStatisticsOptions, PitchSummary, PitchTrack } from './types';
+import { logDebug } from './utils';
+
+/** Statistics returned by native computePitchStatistics; null where undefined */
+interface NativePitchStatistics {
+  frameCount: number;
+  voicedFrameCount: number;
+  voicedRatio: number;
+  meanHz: number | null;
+  medianHz: number | null;
+  stdHz: number | null;
+  p5Hz: number | null;
+  p95Hz: number | null;
+  meanSemitones: number | null;
+  medianSemitones: number | null;
+  stdSemitones: number | null;
+  p5Semitones: number | null;
+  p95Semitones: number | null;
+  rangeSemitones: number | null;
+}
+
+function toSummary(
+  mean: number | null,
+  median: number | null,
+  std: number | null,
+  p5: number | null,
+  p95: number | null
+): PitchSummary | null {
+  if (mean === null || median === null || std === null || p5 === null || p95 === null) {
+    return null;
+  }
+  return { mean, median, std, p5, p95 };
+}
+
+/**
+ * Computes pitch statistics of a track in Hz and in semitones
+ *
+ * Frames with a pitch above 0 are voiced. Each voiced frame is weighted by its
+ * voiced probability, so frames the tracker is unsure of (such as leftover
+ * octave errors or frames filled by postprocessPitchTrack()) pull the
+ * statistics less. Percentiles interpolate between weighted midpoints, which
+ * gives the usual median when all weights are equal. Computed natively, so
+ * iOS and Android return identical values for the same track.
+ *
+ * @param track - Pitch track, e.g. from processBuffer() or postprocessPitchTrack()
+ * @param options - Optional semitone reference and weighting
+ * @returns Promise resolving to PitchStatistics
+ * @throws ValidationError if the track or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const track = await processBuffer(analyzer, recording);
+ * const stats = await computePitchStatistics(track);
+ * console.log(`Median ${stats.hz?.median} Hz, range ${stats.rangeSemitones} st`);
+ * ```
+ */
+export async function computePitchStatistics(
+  track: Pick<PitchTrack, 'pitchTrack' | 'voicedProbabilities'>,
+  options: PitchStatisticsOptions = {}
+): Promise<PitchStatistics> {
+  logDebug('computePitchStatistics called', {
+    frameCount: track?.pitchTrack?.length,
+    options,
+  });
+
+  if (!track || !track.pitchTrack || track.pitchTrack.length === 0) {
+    throw new ValidationError('Pitch track cannot be empty', {
+      frameCount: track?.pitchTrack?.length ?? 0,
+    });
+  }
+
+  const frameCount = track.pitchTrack.length;
+  if (track.voicedProbabilities?.length !== frameCount) {
+    throw new ValidationError('pitchTrack and voicedProbabilities must have equal lengths', {
+      pitchTrack: frameCount,
+      voicedProbabilities: track.voicedProbabilities?.length,
+    });
+  }
+
+  if (Array.from(track.pitchTrack).some((f) => !(Number.isFinite(f) && f >= 0))) {
+    throw new ValidationError('Pitch track values must be finite and >= 0 (0 = unvoiced)', {
+      frameCount,
+    });
+  }
+
+  if (Array.from(track.voicedProbabilities).some((p) => !(p >= 0 && p <= 1))) {
+    throw new ValidationError('Voiced probabilities must be between 0 and 1', {
+      frameCount,
+    });
+  }
+
+  const referenceFrequency = options.referenceFrequency ?? 100;
+  const weightByVoicing = options.weightByVoicing ?? true;
+
+  if (!Number.isFinite(referenceFrequency) || referenceFrequency <= 0) {
+    throw new ValidationError('referenceFrequency must be a positive finite number', {
+      referenceFrequency,
+    });
+  }
+
+  const nativeOptions = {
+    referenceHz: referenceFrequency,
+    weightByVoicing,
+  };
+
+  try {
+    // React Native bridge requires plain arrays, not typed arrays
+    const native: NativePitchStatistics = await LoqaExpoDspModule.computePitchStatistics(
+      Array.from(track.pitchTrack),
+      Array.from(track.voicedProbabilities),
+      nativeOptions
+    );
+
+    const result: PitchStatistics = {
+      frameCount: native.frameCount,
+      voicedFrameCount: native.voicedFrameCount,
+      voicedRatio: native.voicedRatio,
+      hz: toSummary(native.meanHz, native.medianHz, native.stdHz, native.p5Hz, native.p95Hz),
+      semitones: toSummary(
+        native.meanSemitones,
+        native.medianSemitones,
+        native.stdSemitones,
+        native.p5Semitones,
+        native.p95Semitones
+      ),
+      rangeSemitones: native.rangeSemitones ?? null,
+    };
+
+    logDebug('computePitchStatistics completed successfully', {
+      voicedFrameCount: result.voicedFrameCount,
+      medianHz: result.hz?.median,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('computePitchStatistics failed', {
+      error: errorMessage,
+      frameCount,
+    });
+
+    throw new NativeModuleError(`Pitch statistics computation failed: ${errorMessage}`, {
+      originalError: error,
+      frameCount,
+      options: nativeOptions,
+    });
+  }
+}
@@ -0,0 +1,173 @@
+// computeSpectrogram - STFT over a whole buffer in one native call
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { SpectrogramOptions, SpectrogramResult } from './types';
+import { logDebug } from './utils';
+
+const WINDOW_TYPES = ['hanning', 'hamming', 'blackman', 'none', 'kaiser', 'flattop', 'gaussian'];
+const SCALINGS = ['linear', 'power', 'db'];
+
+/**
+ * Validates the buffer and spectrogram options
+ *
+ * Unlike single-frame analysis there is no upper limit on the buffer length,
+ * which must only extend past the first frame.
+ */
+function validateSpectrogramInput(
+  audioBuffer: Float32Array | number[],
+  options: SpectrogramOptions,
+  frameSize: number,
+  hopSize: number
+): void {
+  const sampleRate = options.sampleRate;
+  if (!(Number.isInteger(sampleRate) && sampleRate > 0)) {
+    throw new ValidationError('sampleRate must be a positive integer', { sampleRate });
+  }
+
+  if (
+    !Number.isInteger(frameSize) ||
+    frameSize < 64 ||
+    frameSize > 8192 ||
+    (frameSize & (frameSize - 1)) !== 0
+  ) {
+    throw new ValidationError('frameSize must be a power of 2 between 64 and 8192', {
+      frameSize,
+    });
+  }
+
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  if (options.windowType !== undefined && !WINDOW_TYPES.includes(options.windowType)) {
+    throw new ValidationError(`windowType must be one of: ${WINDOW_TYPES.join(', ')}`, {
+      windowType: options.windowType,
+    });
+  }
+
+  const windowParameter = options.windowParameter;
+  if (
+    windowParameter !== undefined &&
+    !(Number.isFinite(windowParameter) && windowParameter >= 0)
+  ) {
+    throw new ValidationError('windowParameter must be a finite number >= 0', {
+      windowParameter,
+    });
+  }
+
+  if (options.scaling !== undefined && !SCALINGS.includes(options.scaling)) {
+    throw new ValidationError(`scaling must be one of: ${SCALINGS.join(', ')}`, {
+      scaling: options.scaling,
+    });
+  }
+
+  if (options.dbFloor !== undefined && !Number.isFinite(options.dbFloor)) {
+    throw new ValidationError('dbFloor must be a finite number', { dbFloor: options.dbFloor });
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
+
+/**
+ * Computes the spectrogram (short-time Fourier transform) of a whole buffer
+ *
+ * All frames are computed in a single native call instead of one `computeFFT`
+ * bridge crossing per frame. Each frame is windowed and compensated for the
+ * window's gain like `computeFFT` with 'amplitude' normalization.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Spectrogram options including sampleRate
+ * @returns Promise resolving to the row-major frames x bins matrix and frame timestamps
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const spectrogram = await computeSpectrogram(recording, {
+ *   sampleRate: 16000,
+ *   frameSize: 1024,
+ *   hopSize: 256,
+ * });
+ *
+ * const { values, binCount, timestamps } = spectrogram;
+ * for (let i = 0; i < spectrogram.frameCount; i++) {
+ *   drawColumn(timestamps[i], values.subarray(i * binCount, (i + 1) * binCount));
+ * }
+ * ```
+ */
+export async function computeSpectrogram(
+  audioBuffer: Float32Array | number[],
+  options: SpectrogramOptions
+): Promise<SpectrogramResult> {
+  logDebug('computeSpectrogram called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const frameSize = options.frameSize ?? 2048;
+  const hopSize = options.hopSize ?? 512;
+  validateSpectrogramInput(audioBuffer, options, frameSize, hopSize);
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = {
+    frameSize,
+    hopSize,
+    windowType: options.windowType ?? 'hanning',
+    windowParameter: options.windowParameter ?? 0,
+    scaling: options.scaling ?? 'db',
+    dbFloor: options.dbFloor ?? -120,
+  };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.computeSpectrogram(
+      bufferArray,
+      options.sampleRate,
+      nativeOptions
+    );
+
+    logDebug('Native module returned spectrogram', {
+      frameCount: nativeResult.frameCount,
+      binCount: nativeResult.binCount,
+    });
+
+    return {
+      values: new Float32Array(nativeResult.values),
+      timestamps: new Float32Array(nativeResult.timestamps),
+      frameCount: nativeResult.frameCount,
+      binCount: nativeResult.binCount,
+    };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('computeSpectrogram failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Spectrogram computation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate: options.sampleRate,
+      frameSize,
+      hopSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
@@ -1,10 +1,12 @@
 // detectPitch - Pitch detection API using pYIN algorithm (loqa-voice-dsp v0.4.0)
 import LoqaExpoDspModule from './LoqaExpoDspModule';
 import { NativeModuleError } from './errors';
-import type { PitchDetectionOptions, PitchResult } from './types';
+import type { PitchAlgorithm, PitchDetectionOptions, PitchResult } from './types';
 import { logDebug } from './utils';
 import { validateAudioBuffer, validateSampleRate } from './validation';
 
+const PITCH_ALGORITHMS: readonly PitchAlgorithm[] = ['auto', 'pyin', 'yin', 'autocorr'];
+
 /**
  * Detects pitch using pYIN algorithm
  *
@@ -19,7 +21,7 @@ import { validateAudioBuffer, validateSampleRate } from './validation';
  *
  * @param audioBuffer - Audio samples (Float32Array or number[])
  * @param sampleRate - Sample rate in Hz (8000-48000)
- * @param options - Pitch detection options (minFrequency, maxFrequency)
+ * @param options - Pitch detection options (minFrequency, maxFrequency, algorithm)
  * @returns Promise resolving to pitch result with frequency, confidence, voicing, and voicedProbability
  * @throws ValidationError if buffer or sample rate are invalid
  * @throws NativeModuleError if native computation fails
@@ -79,6 +81,18 @@ export async function detectPitch(
     });
   }
 
+  if (maxFrequency > sampleRate / 2) {
+    throw new NativeModuleError('maxFrequency must not exceed the Nyquist frequency', {
+      maxFrequency,
+      sampleRate,
+    });
+  }
+
+  const algorithm = options?.algorithm;
+  if (algorithm !== undefined && !PITCH_ALGORITHMS.includes(algorithm)) {
+    throw new NativeModuleError('Unknown pitch algorithm', { algorithm });
+  }
+
   // Step 3: Convert to number[] for React Native bridge
   // React Native bridge requires plain arrays, not typed arrays
   const bufferArray: number[] =
@@ -95,6 +109,7 @@ export async function detectPitch(
     const nativeResult = await LoqaExpoDspModule.detectPitch(bufferArray, sampleRate, {
       minFrequency,
       maxFrequency,
+      ...(algorithm !== undefined && { algorithm }),
     });
 
     logDebug('Native module returned pitch result', {
@@ -0,0 +1,158 @@
+// detectVoiceActivity - zero-crossing rate, short-time energy and pYIN-assisted VAD
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { VoiceActivityOptions, VoiceActivityResult } from './types';
+import { logDebug } from './utils';
+import { validateSampleRate } from './validation';
+
+/**
+ * Detects speech in a whole buffer from energy, zero-crossing rate and voicing
+ *
+ * Each frame gets an RMS level, a zero-crossing rate and a pYIN voicing
+ * probability. Loud frames that are voiced or noisy enough to be unvoiced
+ * consonants count as speech, and the decision is held for hangoverTime to
+ * bridge short pauses. Runs of speech frames are merged into segments given
+ * as sample indices, ready to slice the buffer before pitch or formant analysis.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Sample rate plus optional pitch range, framing and thresholds
+ * @returns Promise resolving to the framewise features, decisions and speech segments
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails
+ *
+ * @example
+ * ```typescript
+ * const { segments } = await detectVoiceActivity(recording, { sampleRate: 16000 });
+ * for (const { start, end } of segments) {
+ *   const pitch = await detectPitch(recording.slice(start, end), 16000);
+ * }
+ * ```
+ */
+export async function detectVoiceActivity(
+  audioBuffer: Float32Array | number[],
+  options: VoiceActivityOptions
+): Promise<VoiceActivityResult> {
+  logDebug('detectVoiceActivity called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const sampleRate = options.sampleRate;
+  validateSampleRate(sampleRate);
+
+  const minFrequency = options.minFrequency ?? 75;
+  const maxFrequency = options.maxFrequency ?? 500;
+  const validRange =
+    Number.isFinite(minFrequency) &&
+    Number.isFinite(maxFrequency) &&
+    minFrequency > 0 &&
+    maxFrequency > minFrequency;
+  if (!validRange) {
+    throw new ValidationError('minFrequency and maxFrequency must satisfy 0 < min < max', {
+      minFrequency,
+      maxFrequency,
+    });
+  }
+
+  const frameSize = options.frameSize ?? 2048;
+  if (!(Number.isInteger(frameSize) && frameSize > 0)) {
+    throw new ValidationError('frameSize must be a positive integer', { frameSize });
+  }
+
+  const hopSize = options.hopSize ?? 512;
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  const energyThresholdDb = options.energyThresholdDb ?? -50;
+  if (!Number.isFinite(energyThresholdDb)) {
+    throw new ValidationError('energyThresholdDb must be a finite number', { energyThresholdDb });
+  }
+
+  const zcrThreshold = options.zcrThreshold ?? 3000;
+  const hangoverTime = options.hangoverTime ?? 0.2;
+  for (const [name, value] of [
+    ['zcrThreshold', zcrThreshold],
+    ['hangoverTime', hangoverTime],
+  ] as const) {
+    if (!(Number.isFinite(value) && value >= 0)) {
+      throw new ValidationError(`${name} must be a finite number >= 0`, { [name]: value });
+    }
+  }
+
+  const voicingThreshold = options.voicingThreshold ?? 0.5;
+  if (!(voicingThreshold >= 0 && voicingThreshold <= 1)) {
+    throw new ValidationError('voicingThreshold must be between 0 and 1', { voicingThreshold });
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = {
+    minFrequency,
+    maxFrequency,
+    frameSize,
+    hopSize,
+    energyThresholdDb,
+    zcrThreshold,
+    voicingThreshold,
+    hangoverTime,
+  };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.detectVoiceActivity(
+      bufferArray,
+      sampleRate,
+      nativeOptions
+    );
+
+    const result: VoiceActivityResult = {
+      timestamps: new Float32Array(nativeResult.timestamps),
+      energyDb: new Float32Array(nativeResult.energyDb),
+      zcr: new Float32Array(nativeResult.zcr),
+      voicedProbabilities: new Float32Array(nativeResult.voicedProbabilities),
+      isSpeech: nativeResult.isSpeech,
+      segments: nativeResult.segments,
+    };
+
+    logDebug('detectVoiceActivity completed successfully', {
+      frameCount: result.timestamps.length,
+      segmentCount: result.segments.length,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('detectVoiceActivity failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Voice activity detection failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate,
+      frameSize,
+      hopSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
@@ -0,0 +1,156 @@
+// estimateVocalTractLength - vocal tract length from formant spacing
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { VocalTractLengthOptions, VocalTractLengthResult } from './types';
+import { logDebug } from './utils';
+import { validateSampleRate } from './validation';
+
+/**
+ * Estimates vocal tract length (VTL) for speaker normalization
+ *
+ * F1-F4 are measured in every voiced frame and fitted to a uniform tube closed
+ * at the glottis, whose formants lie at (2n - 1) * c / 4L. The primary estimate
+ * regresses the formants on (2n - 1) / 2 (Reby & McComb); the second uses their
+ * average spacing (Fitch). Medians over frames make the result robust to
+ * individual formant errors, so sustained vowels and connected speech both work.
+ *
+ * @param audioBuffer - Audio samples (Float32Array or number[]), longer than frameSize
+ * @param options - Sample rate plus optional pitch range, framing and formant settings
+ * @returns Promise resolving to VTL in cm with the median formants and a confidence
+ * @throws ValidationError if buffer or options are invalid
+ * @throws NativeModuleError if native computation fails or no voiced frame had three formants
+ *
+ * @example
+ * ```typescript
+ * const { vtlCm, confidence } = await estimateVocalTractLength(recording, {
+ *   sampleRate: 16000,
+ * });
+ * if (confidence > 0.5) {
+ *   console.log(`Vocal tract length: ${vtlCm.toFixed(1)} cm`);
+ * }
+ * ```
+ */
+export async function estimateVocalTractLength(
+  audioBuffer: Float32Array | number[],
+  options: VocalTractLengthOptions
+): Promise<VocalTractLengthResult> {
+  logDebug('estimateVocalTractLength called', {
+    bufferLength: audioBuffer?.length,
+    bufferType: audioBuffer instanceof Float32Array ? 'Float32Array' : 'number[]',
+    options,
+  });
+
+  const sampleRate = options.sampleRate;
+  validateSampleRate(sampleRate);
+
+  const minFrequency = options.minFrequency ?? 75;
+  const maxFrequency = options.maxFrequency ?? 500;
+  const validRange =
+    Number.isFinite(minFrequency) &&
+    Number.isFinite(maxFrequency) &&
+    minFrequency > 0 &&
+    maxFrequency > minFrequency;
+  if (!validRange) {
+    throw new ValidationError('minFrequency and maxFrequency must satisfy 0 < min < max', {
+      minFrequency,
+      maxFrequency,
+    });
+  }
+
+  const frameSize = options.frameSize ?? 2048;
+  if (!(Number.isInteger(frameSize) && frameSize > 0)) {
+    throw new ValidationError('frameSize must be a positive integer', { frameSize });
+  }
+
+  const hopSize = options.hopSize ?? 512;
+  if (!(Number.isInteger(hopSize) && hopSize >= 1 && hopSize <= frameSize)) {
+    throw new ValidationError('hopSize must be an integer between 1 and frameSize', {
+      hopSize,
+      frameSize,
+    });
+  }
+
+  const lpcOrder = options.lpcOrder ?? 0;
+  if (!(Number.isInteger(lpcOrder) && (lpcOrder === 0 || (lpcOrder >= 8 && lpcOrder <= 24)))) {
+    throw new ValidationError('lpcOrder must be 0 (default) or an integer between 8 and 24', {
+      lpcOrder,
+    });
+  }
+
+  const minFormantConfidence = options.minFormantConfidence ?? 0.3;
+  if (!(minFormantConfidence >= 0 && minFormantConfidence <= 1)) {
+    throw new ValidationError('minFormantConfidence must be between 0 and 1', {
+      minFormantConfidence,
+    });
+  }
+
+  const speedOfSound = options.speedOfSound ?? 35000;
+  if (!(Number.isFinite(speedOfSound) && speedOfSound > 0)) {
+    throw new ValidationError('speedOfSound must be a positive number (cm/s)', { speedOfSound });
+  }
+
+  if (!audioBuffer || audioBuffer.length <= frameSize) {
+    throw new ValidationError('Audio buffer must be longer than frameSize', {
+      bufferLength: audioBuffer?.length ?? 0,
+      frameSize,
+    });
+  }
+
+  if (Array.from(audioBuffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: audioBuffer.length,
+    });
+  }
+
+  // React Native bridge requires plain arrays, not typed arrays
+  const bufferArray: number[] =
+    audioBuffer instanceof Float32Array ? Array.from(audioBuffer) : audioBuffer;
+
+  const nativeOptions = {
+    minFrequency,
+    maxFrequency,
+    frameSize,
+    hopSize,
+    lpcOrder,
+    minFormantConfidence,
+    speedOfSound,
+  };
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.estimateVocalTractLength(
+      bufferArray,
+      sampleRate,
+      nativeOptions
+    );
+
+    const result: VocalTractLengthResult = {
+      vtlCm: nativeResult.vtlCm,
+      spacingVtlCm: nativeResult.spacingVtlCm,
+      formantSpacingHz: nativeResult.formantSpacingHz,
+      f1: nativeResult.f1,
+      f2: nativeResult.f2,
+      f3: nativeResult.f3,
+      f4: nativeResult.f4,
+      confidence: nativeResult.confidence,
+      frameCount: nativeResult.frameCount,
+    };
+
+    logDebug('estimateVocalTractLength completed successfully', result);
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('estimateVocalTractLength failed', {
+      error: errorMessage,
+      bufferLength: audioBuffer.length,
+    });
+
+    throw new NativeModuleError(`Vocal tract length estimation failed: ${errorMessage}`, {
+      originalError: error,
+      sampleRate,
+      frameSize,
+      bufferLength: audioBuffer.length,
+    });
+  }
+}
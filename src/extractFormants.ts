@@ -1,4 +1,4 @@
-// extractFormants - Formant extraction API using LPC analysis (loqa-voice-dsp v0.4.0)
+// extractFormants - Formant extraction API using LPC analysis
 import LoqaExpoDspModule from './LoqaExpoDspModule';
 import { NativeModuleError } from './errors';
 import type { FormantExtractionOptions, FormantsResult } from './types';
@@ -6,21 +6,20 @@ import { logDebug } from './utils';
 import { validateAudioBuffer, validateSampleRate } from './validation';
 
 /**
- * Extracts formants (F1, F2, F3) using LPC analysis
+ * Extracts formants (F1-F5) using LPC analysis
  *
  * This function performs Linear Predictive Coding (LPC) analysis to extract
- * the first three formant frequencies from audio data. Formants are resonant
+ * the first five formant frequencies from audio data. Formants are resonant
  * frequencies of the vocal tract and are essential for vowel identification
  * and speech analysis.
  *
- * As of loqa-voice-dsp v0.4.0, this returns a confidence score instead of
- * individual bandwidth values. The confidence score indicates the reliability
- * of the formant detection (0-1, higher is better).
+ * Each formant comes with its bandwidth (from the LPC pole radius) and a
+ * confidence (0-1, higher is better); `confidence` is the mean over F1-F3.
  *
  * @param audioBuffer - Audio samples (Float32Array or number[])
  * @param sampleRate - Sample rate in Hz (8000-48000)
  * @param options - Formant extraction options (lpcOrder)
- * @returns Promise resolving to formants result with F1, F2, F3 and confidence
+ * @returns Promise resolving to formants result with F1-F5, bandwidths and confidences
  * @throws ValidationError if buffer or sample rate are invalid
  * @throws NativeModuleError if native computation fails
  *
@@ -93,12 +92,19 @@ export async function extractFormants(
 
     // Step 5: Convert result to FormantsResult type
     // Native module returns dictionary/map, convert to proper TypeScript type
-    // v0.4.0: Now returns confidence instead of bandwidths
     const result: FormantsResult = {
       f1: nativeResult.f1,
       f2: nativeResult.f2,
       f3: nativeResult.f3,
+      f4: nativeResult.f4,
+      f5: nativeResult.f5,
+      b1: nativeResult.b1,
+      b2: nativeResult.b2,
+      b3: nativeResult.b3,
+      b4: nativeResult.b4,
+      b5: nativeResult.b5,
       confidence: nativeResult.confidence,
+      confidences: nativeResult.confidences,
     };
 
     logDebug('extractFormants completed successfully', {
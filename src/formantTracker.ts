@@ -0,0 +1,323 @@
+// Formant tracker - F1-F4 contours with continuity across frames
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { FormantTrack, FormantTrackerConfig, FormantTrackerHandle } from './types';
+import { logDebug } from './utils';
+import { validateSampleRate } from './validation';
+
+/** Formants per frame in the native frequency and bandwidth arrays */
+const TRACKED_FORMANTS = 4;
+
+/** Track returned by native trackFormants; formant arrays are row-major frameCount x 4 */
+interface NativeFormantTrack {
+  frequencies: number[];
+  bandwidths: number[];
+  confidences: number[];
+  reliable: boolean[];
+  timestamps: number[];
+  frameCount: number;
+}
+
+function validateFormantTrackerHandle(tracker: FormantTrackerHandle): void {
+  if (!tracker || !tracker.id) {
+    throw new ValidationError('Invalid formant tracker handle', {
+      tracker,
+    });
+  }
+}
+
+/** Column n of a row-major frameCount x 4 matrix */
+function formantColumn(values: number[], frameCount: number, n: number): Float32Array {
+  const column = new Float32Array(frameCount);
+  for (let frame = 0; frame < frameCount; frame++) {
+    column[frame] = values[frame * TRACKED_FORMANTS + n];
+  }
+  return column;
+}
+
+/**
+ * Creates a formant tracker that follows F1-F4 over time
+ *
+ * Each frame is analysed by LPC, and every resonance below the ceiling is a
+ * candidate formant. All frames of a buffer are then labelled together by
+ * Viterbi decoding, trading closeness to neutral-vowel formants against
+ * continuity between frames, so a nasal pole or a merged F1-F2 no longer
+ * shifts every formant above it. The last frame of each buffer seeds the
+ * next, so consecutive buffers of one recording join up.
+ *
+ * @param config - Sample rate plus optional framing, LPC order, ceiling and reliability limits
+ * @returns Promise resolving to FormantTrackerHandle
+ * @throws ValidationError if config is invalid
+ * @throws NativeModuleError if native creation fails
+ *
+ * @example
+ * ```typescript
+ * const tracker = await createFormantTracker({ sampleRate: 16000 });
+ * const track = await trackFormants(tracker, recording);
+ *
+ * for (let i = 0; i < track.frameCount; i++) {
+ *   if (track.reliable[i]) {
+ *     plotVowel(track.timestamps[i], track.f1[i], track.f2[i]);
+ *   }
+ * }
+ *
+ * // Clean up when done
+ * await freeFormantTracker(tracker);
+ * ```
+ */
+export async function createFormantTracker(
+  config: FormantTrackerConfig
+): Promise<FormantTrackerHandle> {
+  logDebug('createFormantTracker called', config);
+
+  validateSampleRate(config.sampleRate);
+
+  const sampleRate = config.sampleRate;
+  const frameSize = config.frameSize ?? Math.round(0.025 * sampleRate);
+  const hopSize = config.hopSize ?? Math.round(0.01 * sampleRate);
+  const lpcOrder = config.lpcOrder ?? 0;
+  const ceilingFrequency = config.ceilingFrequency ?? 5500;
+  const continuityWeight = config.continuityWeight ?? 1;
+  const minConfidence = config.minConfidence ?? 0.3;
+  const silenceThreshold = config.silenceThreshold ?? -50;
+
+  if (!Number.isInteger(frameSize) || frameSize < sampleRate / 100 || frameSize > sampleRate / 10) {
+    throw new ValidationError('frameSize must be an integer between 10 ms and 100 ms of samples', {
+      frameSize,
+      sampleRate,
+    });
+  }
+
+  if (hopSize <= 0 || !Number.isInteger(hopSize) || hopSize > frameSize) {
+    throw new ValidationError('hopSize must be a positive integer no larger than frameSize', {
+      frameSize,
+      hopSize,
+    });
+  }
+
+  if (!(Number.isInteger(lpcOrder) && (lpcOrder === 0 || (lpcOrder >= 8 && lpcOrder <= 24)))) {
+    throw new ValidationError('lpcOrder must be 0 (default) or an integer between 8 and 24', {
+      lpcOrder,
+    });
+  }
+
+  const nyquist = sampleRate / 2;
+  if (!(ceilingFrequency >= 3000 && ceilingFrequency <= 7000 && ceilingFrequency < nyquist)) {
+    throw new ValidationError(
+      'ceilingFrequency must be between 3000 and 7000 Hz and below the Nyquist frequency',
+      { ceilingFrequency, sampleRate }
+    );
+  }
+
+  if (!Number.isFinite(continuityWeight) || continuityWeight < 0) {
+    throw new ValidationError('continuityWeight must be a finite number >= 0', {
+      continuityWeight,
+    });
+  }
+
+  if (!(minConfidence >= 0 && minConfidence <= 1)) {
+    throw new ValidationError('minConfidence must be between 0 and 1', {
+      minConfidence,
+    });
+  }
+
+  if (!Number.isFinite(silenceThreshold) || silenceThreshold > 0) {
+    throw new ValidationError('silenceThreshold must be a finite level in dBFS (<= 0)', {
+      silenceThreshold,
+    });
+  }
+
+  const resolved: Required<FormantTrackerConfig> = {
+    sampleRate,
+    frameSize,
+    hopSize,
+    lpcOrder,
+    ceilingFrequency,
+    continuityWeight,
+    minConfidence,
+    silenceThreshold,
+  };
+
+  try {
+    const id: string = await LoqaExpoDspModule.createFormantTracker({
+      sampleRate,
+      frameSize,
+      hopSize,
+      lpcOrder,
+      ceilingHz: ceilingFrequency,
+      continuityWeight,
+      minConfidence,
+      silenceDb: silenceThreshold,
+    });
+
+    logDebug('Native module returned formant tracker handle', { id });
+
+    return { id, config: resolved };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('createFormantTracker failed', {
+      error: errorMessage,
+      config,
+    });
+
+    throw new NativeModuleError(`Failed to create formant tracker: ${errorMessage}`, {
+      originalError: error,
+      config,
+    });
+  }
+}
+
+/**
+ * Tracks F1-F4 over the frames of a buffer
+ *
+ * Frames start every hopSize samples, as in processBuffer() with the same
+ * framing, so formants line up with a pitch track. The buffer continues the
+ * previous one passed to this tracker; call resetFormantTracker() before an
+ * unrelated recording. Await each call before the next so buffers arrive in
+ * order.
+ *
+ * @param tracker - FormantTrackerHandle from createFormantTracker
+ * @param buffer - Audio samples, longer than frameSize
+ * @returns Promise resolving to FormantTrack
+ * @throws ValidationError if tracker handle or buffer is invalid
+ * @throws NativeModuleError if native tracking fails
+ */
+export async function trackFormants(
+  tracker: FormantTrackerHandle,
+  buffer: Float32Array | number[]
+): Promise<FormantTrack> {
+  logDebug('trackFormants called', {
+    trackerId: tracker?.id,
+    bufferLength: buffer?.length,
+  });
+
+  validateFormantTrackerHandle(tracker);
+
+  // Whole recordings are expected, so there is no cap on the buffer length
+  if (!buffer || buffer.length <= tracker.config.frameSize) {
+    throw new ValidationError('Buffer must be longer than frameSize', {
+      bufferLength: buffer?.length ?? 0,
+      frameSize: tracker.config.frameSize,
+    });
+  }
+
+  if (Array.from(buffer).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: buffer.length,
+    });
+  }
+
+  // Convert to number[] for React Native bridge
+  const bufferArray: number[] = buffer instanceof Float32Array ? Array.from(buffer) : buffer;
+
+  try {
+    const native: NativeFormantTrack = await LoqaExpoDspModule.trackFormants(
+      tracker.id,
+      bufferArray
+    );
+    const frameCount = native.frameCount;
+
+    const track: FormantTrack = {
+      f1: formantColumn(native.frequencies, frameCount, 0),
+      f2: formantColumn(native.frequencies, frameCount, 1),
+      f3: formantColumn(native.frequencies, frameCount, 2),
+      f4: formantColumn(native.frequencies, frameCount, 3),
+      b1: formantColumn(native.bandwidths, frameCount, 0),
+      b2: formantColumn(native.bandwidths, frameCount, 1),
+      b3: formantColumn(native.bandwidths, frameCount, 2),
+      b4: formantColumn(native.bandwidths, frameCount, 3),
+      confidence: new Float32Array(native.confidences),
+      reliable: native.reliable,
+      timestamps: new Float32Array(native.timestamps),
+      frameCount,
+    };
+
+    logDebug('trackFormants completed successfully', {
+      frameCount,
+      reliableFrameCount: track.reliable.filter(Boolean).length,
+    });
+
+    return track;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('trackFormants failed', {
+      error: errorMessage,
+      trackerId: tracker.id,
+      bufferLength: buffer.length,
+    });
+
+    throw new NativeModuleError(`Formant tracking failed: ${errorMessage}`, {
+      originalError: error,
+      trackerId: tracker.id,
+      bufferLength: buffer.length,
+    });
+  }
+}
+
+/**
+ * Makes a formant tracker forget the previous buffer
+ *
+ * The next buffer is tracked as the start of a new recording.
+ *
+ * @param tracker - FormantTrackerHandle from createFormantTracker
+ * @throws ValidationError if tracker handle is invalid
+ * @throws NativeModuleError if native reset fails
+ */
+export async function resetFormantTracker(tracker: FormantTrackerHandle): Promise<void> {
+  logDebug('resetFormantTracker called', {
+    trackerId: tracker?.id,
+  });
+
+  validateFormantTrackerHandle(tracker);
+
+  try {
+    await LoqaExpoDspModule.resetFormantTracker(tracker.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('resetFormantTracker failed', {
+      error: errorMessage,
+      trackerId: tracker.id,
+    });
+
+    throw new NativeModuleError(`Failed to reset formant tracker: ${errorMessage}`, {
+      originalError: error,
+      trackerId: tracker.id,
+    });
+  }
+}
+
+/**
+ * Frees a formant tracker and releases native resources
+ *
+ * After calling this, the tracker handle should not be used again.
+ *
+ * @param tracker - FormantTrackerHandle from createFormantTracker
+ * @throws ValidationError if tracker handle is invalid
+ * @throws NativeModuleError if native free fails
+ */
+export async function freeFormantTracker(tracker: FormantTrackerHandle): Promise<void> {
+  logDebug('freeFormantTracker called', {
+    trackerId: tracker?.id,
+  });
+
+  validateFormantTrackerHandle(tracker);
+
+  try {
+    await LoqaExpoDspModule.freeFormantTracker(tracker.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('freeFormantTracker failed', {
+      error: errorMessage,
+      trackerId: tracker.id,
+    });
+
+    throw new NativeModuleError(`Failed to free formant tracker: ${errorMessage}`, {
+      originalError: error,
+      trackerId: tracker.id,
+    });
+  }
+}
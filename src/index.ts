@@ -8,6 +8,14 @@ export { default as LoqaExpoDspModule } from './LoqaExpoDspModule';
 export type {
   FFTOptions,
   FFTResult,
+  ComplexFFTOptions,
+  ComplexSpectrum,
+  SpectrogramOptions,
+  SpectrogramResult,
+  MelSpectrogramOptions,
+  MFCCOptions,
+  FeatureMatrix,
+  PitchAlgorithm,
   PitchDetectionOptions,
   PitchResult,
   FormantExtractionOptions,
@@ -18,10 +26,40 @@ export type {
   HNRResult,
   H1H2Options,
   H1H2Result,
+  PerturbationOptions,
+  JitterResult,
+  ShimmerResult,
+  CPPOptions,
+  CPPResult,
+  IntensityOptions,
+  IntensityResult,
+  VoiceActivityOptions,
+  SpeechSegment,
+  VoiceActivityResult,
+  VocalTractLengthOptions,
+  VocalTractLengthResult,
   VoiceAnalyzerConfig,
   VoiceAnalyzerHandle,
   VoiceAnalyzerResult,
   PitchTrack, // v0.5.0: HMM-smoothed Viterbi decoding result
+  PitchSmoothing,
+  PitchPostprocessOptions,
+  PitchStatisticsOptions,
+  PitchSummary,
+  PitchStatistics,
+  VibratoOptions,
+  VibratoSegment,
+  VibratoAnalysis,
+  StreamingPitchFrames,
+  TunerConfig,
+  TunerHandle,
+  TunerReading,
+  VoiceRangeProfileConfig,
+  VoiceRangeProfileHandle,
+  VoiceRangeProfile,
+  FormantTrackerConfig,
+  FormantTrackerHandle,
+  FormantTrack,
 } from './types';
 
 // Export error classes
@@ -36,12 +74,22 @@ export {
 export { logDebug, logWarning } from './utils';
 
 // Export DSP functions
-export { computeFFT } from './computeFFT';
+export { computeFFT, computeComplexFFT, inverseFFT } from './computeFFT';
+export { computeSpectrogram } from './computeSpectrogram';
+export { computeMelSpectrogram, computeMFCC } from './computeMelFeatures';
 export { detectPitch } from './detectPitch';
 export { extractFormants } from './extractFormants';
 export { analyzeSpectrum } from './analyzeSpectrum';
 export { calculateHNR } from './calculateHNR';
 export { calculateH1H2 } from './calculateH1H2';
+export { calculateJitter, calculateShimmer } from './calculatePerturbation';
+export { calculateCPP } from './calculateCPP';
+export { computeIntensity } from './computeIntensity';
+export { detectVoiceActivity } from './detectVoiceActivity';
+export { estimateVocalTractLength } from './estimateVocalTractLength';
+export { postprocessPitchTrack } from './postprocessPitchTrack';
+export { computePitchStatistics } from './computePitchStatistics';
+export { analyzeVibrato } from './analyzeVibrato';
 
 // Export VoiceAnalyzer streaming API (v0.3.0)
 // processBuffer added in v0.5.0 for HMM-smoothed Viterbi decoding
@@ -49,6 +97,29 @@ export {
   createVoiceAnalyzer,
   analyzeClip,
   processBuffer, // v0.5.0: Viterbi-decoded pitch track
+  pushSamples,
+  flushVoiceAnalyzer,
   resetVoiceAnalyzer,
   freeVoiceAnalyzer,
 } from './voiceAnalyzer';
+
+// Export tuner API
+export { createTuner, pushTunerSamples, resetTuner, freeTuner } from './tuner';
+
+// Export voice range profile API
+export {
+  createVoiceRangeProfile,
+  addVoiceRangeFrames,
+  mergeVoiceRangeProfiles,
+  getVoiceRangeProfile,
+  resetVoiceRangeProfile,
+  freeVoiceRangeProfile,
+} from './voiceRangeProfile';
+
+// Export formant tracker API
+export {
+  createFormantTracker,
+  trackFormants,
+  resetFormantTracker,
+  freeFormantTracker,
+} from './formantTracker';
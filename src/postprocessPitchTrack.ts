@@ -0,0 +1,154 @@
+// postprocessPitchTrack - octave-jump correction, gap interpolation and smoothing
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { PitchPostprocessOptions, PitchSmoothing, PitchTrack } from './types';
+import { logDebug } from './utils';
+
+/** Native selector values of each smoothing filter */
+const SMOOTHING_VALUES: Record<PitchSmoothing, number> = {
+  none: 0,
+  median: 1,
+  movingAverage: 2,
+};
+
+/** Largest octave or smoothing window in frames */
+const MAX_WINDOW_FRAMES = 1001;
+
+/**
+ * Corrects octave jumps, fills short unvoiced gaps and smooths a pitch track
+ *
+ * Runs natively in three steps: frames an octave or two away from the median
+ * of their neighbours are moved back, runs of at most maxGapFrames unvoiced
+ * frames between voiced frames are filled by interpolation in log-frequency,
+ * and each voiced run is median- or average-filtered. The result has the same
+ * frames as the input: voicedProbabilities and timestamps are unchanged, so
+ * index i still describes frame i (interpolated frames keep their low voiced
+ * probability).
+ *
+ * @param track - Pitch track, e.g. from processBuffer()
+ * @param options - Optional steps and windows
+ * @returns Promise resolving to the post-processed PitchTrack with updated statistics
+ * @throws ValidationError if the track or options are invalid
+ * @throws NativeModuleError if native processing fails
+ *
+ * @example
+ * ```typescript
+ * const raw = await processBuffer(analyzer, recording);
+ * const track = await postprocessPitchTrack(raw, { maxGapFrames: 8 });
+ * drawContour(track.timestamps, track.pitchTrack);
+ * ```
+ */
+export async function postprocessPitchTrack(
+  track: Pick<PitchTrack, 'pitchTrack' | 'voicedProbabilities' | 'timestamps'>,
+  options: PitchPostprocessOptions = {}
+): Promise<PitchTrack> {
+  logDebug('postprocessPitchTrack called', {
+    frameCount: track?.pitchTrack?.length,
+    options,
+  });
+
+  if (!track || !track.pitchTrack || track.pitchTrack.length === 0) {
+    throw new ValidationError('Pitch track cannot be empty', {
+      frameCount: track?.pitchTrack?.length ?? 0,
+    });
+  }
+
+  const frameCount = track.pitchTrack.length;
+  if (
+    track.voicedProbabilities?.length !== frameCount ||
+    track.timestamps?.length !== frameCount
+  ) {
+    throw new ValidationError(
+      'pitchTrack, voicedProbabilities and timestamps must have equal lengths',
+      {
+        pitchTrack: frameCount,
+        voicedProbabilities: track.voicedProbabilities?.length,
+        timestamps: track.timestamps?.length,
+      }
+    );
+  }
+
+  if (Array.from(track.pitchTrack).some((f) => !(Number.isFinite(f) && f >= 0))) {
+    throw new ValidationError('Pitch track values must be finite and >= 0 (0 = unvoiced)', {
+      frameCount,
+    });
+  }
+
+  const correctOctaveJumps = options.correctOctaveJumps ?? true;
+  const octaveWindow = options.octaveWindow ?? 11;
+  const smoothing = options.smoothing ?? 'median';
+  const smoothingWindow = options.smoothingWindow ?? 5;
+  const maxGapFrames = options.maxGapFrames ?? 5;
+
+  for (const [name, value, min] of [
+    ['octaveWindow', octaveWindow, 3],
+    ['smoothingWindow', smoothingWindow, 1],
+  ] as const) {
+    const valid =
+      Number.isInteger(value) && value % 2 === 1 && value >= min && value <= MAX_WINDOW_FRAMES;
+    if (!valid) {
+      throw new ValidationError(
+        `${name} must be an odd integer between ${min} and ${MAX_WINDOW_FRAMES}`,
+        { [name]: value }
+      );
+    }
+  }
+
+  if (!Object.prototype.hasOwnProperty.call(SMOOTHING_VALUES, smoothing)) {
+    throw new ValidationError("smoothing must be 'none', 'median' or 'movingAverage'", {
+      smoothing,
+    });
+  }
+
+  if (!(Number.isInteger(maxGapFrames) && maxGapFrames >= 0)) {
+    throw new ValidationError('maxGapFrames must be a non-negative integer', { maxGapFrames });
+  }
+
+  const nativeOptions = {
+    correctOctaveJumps,
+    octaveWindow,
+    smoothing: SMOOTHING_VALUES[smoothing],
+    smoothingWindow,
+    maxGapFrames,
+  };
+
+  try {
+    // React Native bridge requires plain arrays, not typed arrays
+    const nativeResult = await LoqaExpoDspModule.postprocessPitchTrack(
+      Array.from(track.pitchTrack),
+      Array.from(track.voicedProbabilities),
+      Array.from(track.timestamps),
+      nativeOptions
+    );
+
+    const result: PitchTrack = {
+      pitchTrack: new Float32Array(nativeResult.pitchTrack),
+      voicedProbabilities: new Float32Array(nativeResult.voicedProbabilities),
+      timestamps: new Float32Array(nativeResult.timestamps),
+      frameCount: nativeResult.frameCount,
+      voicedFrameCount: nativeResult.voicedFrameCount,
+      medianPitch: nativeResult.medianPitch ?? null,
+      meanPitch: nativeResult.meanPitch ?? null,
+    };
+
+    logDebug('postprocessPitchTrack completed successfully', {
+      frameCount: result.frameCount,
+      voicedFrameCount: result.voicedFrameCount,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('postprocessPitchTrack failed', {
+      error: errorMessage,
+      frameCount,
+    });
+
+    throw new NativeModuleError(`Pitch track post-processing failed: ${errorMessage}`, {
+      originalError: error,
+      frameCount,
+      options: nativeOptions,
+    });
+  }
+}
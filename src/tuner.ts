@@ -0,0 +1,304 @@
+// Tuner - nearest note, cents deviation and stability of live audio
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type { TunerConfig, TunerHandle, TunerReading } from './types';
+import { logDebug } from './utils';
+import { validateSampleRate } from './validation';
+
+/** Note names by pitch class, C = 0 */
+const NOTE_NAMES = ['C', 'C#', 'D', 'D#', 'E', 'F', 'F#', 'G', 'G#', 'A', 'A#', 'B'];
+
+/** Reading returned by native pushTunerSamples */
+interface NativeTunerReading {
+  frameCount: number;
+  timestamp: number;
+  isVoiced: boolean;
+  frequency: number;
+  confidence: number;
+  midiNote: number;
+  pitchClass: number;
+  octave: number;
+  cents: number;
+  targetFrequency: number;
+  stability: number;
+}
+
+function validateTunerHandle(tuner: TunerHandle): void {
+  if (!tuner || !tuner.id) {
+    throw new ValidationError('Invalid tuner handle', {
+      tuner,
+    });
+  }
+}
+
+/**
+ * Creates a tuner that reads the nearest note of live audio
+ *
+ * The tuner buffers pushed samples natively and produces a reading every
+ * hopSize samples: the nearest note under the configured reference pitch and
+ * temperament, the deviation from it in cents and how steadily it is held.
+ * Detected periods are refined below one sample, so readings stay accurate to
+ * well under a cent on steady tones.
+ *
+ * @param config - Sample rate plus optional pitch range, framing, reference and temperament
+ * @returns Promise resolving to TunerHandle
+ * @throws ValidationError if config is invalid
+ * @throws NativeModuleError if native creation fails
+ *
+ * @example
+ * ```typescript
+ * const tuner = await createTuner({ sampleRate: 48000, referenceFrequency: 442 });
+ *
+ * recorder.onChunk(async (chunk) => {
+ *   const reading = await pushTunerSamples(tuner, chunk);
+ *   if (reading.noteName !== null) {
+ *     showNote(`${reading.noteName}${reading.octave}`, reading.cents);
+ *   }
+ * });
+ *
+ * // Clean up when done
+ * await freeTuner(tuner);
+ * ```
+ */
+export async function createTuner(config: TunerConfig): Promise<TunerHandle> {
+  logDebug('createTuner called', config);
+
+  validateSampleRate(config.sampleRate);
+
+  const minFrequency = config.minFrequency ?? 60;
+  const maxFrequency = config.maxFrequency ?? 1500;
+  const frameSize = config.frameSize ?? 2048;
+  const hopSize = config.hopSize ?? 512;
+  const referenceFrequency = config.referenceFrequency ?? 440;
+  const temperament = config.temperament ?? new Array<number>(12).fill(0);
+  const stabilityFrames = config.stabilityFrames ?? 8;
+
+  if (!(minFrequency > 0)) {
+    throw new ValidationError('minFrequency must be positive', {
+      minFrequency,
+    });
+  }
+
+  if (!(maxFrequency > minFrequency)) {
+    throw new ValidationError('maxFrequency must be greater than minFrequency', {
+      minFrequency,
+      maxFrequency,
+    });
+  }
+
+  if (frameSize <= 0 || !Number.isInteger(frameSize)) {
+    throw new ValidationError('frameSize must be a positive integer', {
+      frameSize,
+    });
+  }
+
+  if (hopSize <= 0 || !Number.isInteger(hopSize) || hopSize > frameSize) {
+    throw new ValidationError('hopSize must be a positive integer no larger than frameSize', {
+      frameSize,
+      hopSize,
+    });
+  }
+
+  if (!Number.isFinite(referenceFrequency) || referenceFrequency <= 0) {
+    throw new ValidationError('referenceFrequency must be a positive finite number', {
+      referenceFrequency,
+    });
+  }
+
+  if (
+    !Array.isArray(temperament) ||
+    temperament.length !== 12 ||
+    temperament.some((c) => !(Math.abs(c) <= 50))
+  ) {
+    throw new ValidationError('temperament must have 12 offsets within ±50 cents', {
+      temperament,
+    });
+  }
+
+  if (!Number.isInteger(stabilityFrames) || stabilityFrames < 1 || stabilityFrames > 1000) {
+    throw new ValidationError('stabilityFrames must be an integer between 1 and 1000', {
+      stabilityFrames,
+    });
+  }
+
+  const resolved: Required<TunerConfig> = {
+    sampleRate: config.sampleRate,
+    minFrequency,
+    maxFrequency,
+    frameSize,
+    hopSize,
+    referenceFrequency,
+    temperament: [...temperament],
+    stabilityFrames,
+  };
+
+  try {
+    const id: string = await LoqaExpoDspModule.createTuner({
+      sampleRate: resolved.sampleRate,
+      minFrequency,
+      maxFrequency,
+      frameSize,
+      hopSize,
+      referenceHz: referenceFrequency,
+      temperamentCents: resolved.temperament,
+      stabilityFrames,
+    });
+
+    logDebug('Native module returned tuner handle', { id });
+
+    return { id, config: resolved };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('createTuner failed', {
+      error: errorMessage,
+      config,
+    });
+
+    throw new NativeModuleError(`Failed to create tuner: ${errorMessage}`, {
+      originalError: error,
+      config,
+    });
+  }
+}
+
+/**
+ * Pushes a chunk of live audio and returns the latest tuner reading
+ *
+ * Chunks may have any size. The reading is that of the last frame the chunk
+ * completed; if it completed none, the previous reading is returned with
+ * frameCount 0. Await each push before sending the next chunk so samples
+ * arrive in order.
+ *
+ * @param tuner - TunerHandle from createTuner
+ * @param chunk - Next audio samples (may be empty or shorter than a frame)
+ * @returns Promise resolving to the latest reading
+ * @throws ValidationError if tuner handle or chunk is invalid
+ * @throws NativeModuleError if native processing fails
+ */
+export async function pushTunerSamples(
+  tuner: TunerHandle,
+  chunk: Float32Array | number[]
+): Promise<TunerReading> {
+  logDebug('pushTunerSamples called', {
+    tunerId: tuner?.id,
+    chunkLength: chunk?.length,
+  });
+
+  validateTunerHandle(tuner);
+
+  if (!chunk) {
+    throw new ValidationError('Audio chunk is required', { chunk });
+  }
+
+  if (Array.from(chunk).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: chunk.length,
+    });
+  }
+
+  // Convert to number[] for React Native bridge
+  const bufferArray: number[] = chunk instanceof Float32Array ? Array.from(chunk) : chunk;
+
+  try {
+    const native: NativeTunerReading = await LoqaExpoDspModule.pushTunerSamples(
+      tuner.id,
+      bufferArray
+    );
+    const voiced = native.isVoiced;
+
+    return {
+      frameCount: native.frameCount,
+      timestamp: native.timestamp,
+      isVoiced: voiced,
+      frequency: voiced ? native.frequency : null,
+      confidence: native.confidence,
+      midiNote: voiced ? native.midiNote : null,
+      noteName: voiced ? NOTE_NAMES[native.pitchClass] : null,
+      octave: voiced ? native.octave : null,
+      cents: voiced ? native.cents : null,
+      targetFrequency: voiced ? native.targetFrequency : null,
+      stability: native.stability,
+    };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('pushTunerSamples failed', {
+      error: errorMessage,
+      tunerId: tuner.id,
+      chunkLength: chunk.length,
+    });
+
+    throw new NativeModuleError(`Tuner push failed: ${errorMessage}`, {
+      originalError: error,
+      tunerId: tuner.id,
+      chunkLength: chunk.length,
+    });
+  }
+}
+
+/**
+ * Discards the tuner's buffered samples and reading
+ *
+ * The next push starts a new stream at timestamp 0 with no stability history.
+ *
+ * @param tuner - TunerHandle from createTuner
+ * @throws ValidationError if tuner handle is invalid
+ * @throws NativeModuleError if native reset fails
+ */
+export async function resetTuner(tuner: TunerHandle): Promise<void> {
+  logDebug('resetTuner called', {
+    tunerId: tuner?.id,
+  });
+
+  validateTunerHandle(tuner);
+
+  try {
+    await LoqaExpoDspModule.resetTuner(tuner.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('resetTuner failed', {
+      error: errorMessage,
+      tunerId: tuner.id,
+    });
+
+    throw new NativeModuleError(`Failed to reset tuner: ${errorMessage}`, {
+      originalError: error,
+      tunerId: tuner.id,
+    });
+  }
+}
+
+/**
+ * Frees a tuner and releases native resources
+ *
+ * After calling this, the tuner handle should not be used again.
+ *
+ * @param tuner - TunerHandle from createTuner
+ * @throws ValidationError if tuner handle is invalid
+ * @throws NativeModuleError if native free fails
+ */
+export async function freeTuner(tuner: TunerHandle): Promise<void> {
+  logDebug('freeTuner called', {
+    tunerId: tuner?.id,
+  });
+
+  validateTunerHandle(tuner);
+
+  try {
+    await LoqaExpoDspModule.freeTuner(tuner.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('freeTuner failed', {
+      error: errorMessage,
+      tunerId: tuner.id,
+    });
+
+    throw new NativeModuleError(`Failed to free tuner: ${errorMessage}`, {
+      originalError: error,
+      tunerId: tuner.id,
+    });
+  }
+}
@@ -25,8 +25,26 @@ export interface FFTOptions {
    * - 'hamming': Similar to Hanning, slightly different sidelobe behavior
    * - 'blackman': Better frequency resolution, more attenuation
    * - 'none': Rectangular window (use only for perfect sine waves)
+   * - 'kaiser': Adjustable via windowParameter (beta, default 8.6)
+   * - 'flattop': Accurate peak amplitudes even between bins, wide main lobe
+   * - 'gaussian': Adjustable via windowParameter (sigma relative to half the window, default 0.4)
    */
-  windowType?: 'hanning' | 'hamming' | 'blackman' | 'none';
+  windowType?: 'hanning' | 'hamming' | 'blackman' | 'none' | 'kaiser' | 'flattop' | 'gaussian';
+  /**
+   * Kaiser beta or Gaussian sigma. Defaults to 0, which selects the window's default.
+   * Ignored by the other windows.
+   */
+  windowParameter?: number;
+  /**
+   * Magnitude scaling, compensated for the window's gain. Defaults to 'amplitude'.
+   * - 'amplitude': Single-sided amplitude spectrum (a sine of amplitude A reads A)
+   * - 'psd': Single-sided power spectral density in units²/Hz
+   */
+  normalization?: 'amplitude' | 'psd';
+  /**
+   * Sample rate in Hz, used for the bin frequencies and PSD scaling. Defaults to 44100.
+   */
+  sampleRate?: number;
   /**
    * Return phase information. Defaults to false.
    * Set to true only if you need phase data, as it increases computation time.
@@ -51,9 +69,10 @@ export interface FFTOptions {
  */
 export interface FFTResult {
   /**
-   * Magnitude spectrum (length = fftSize / 2)
+   * Magnitude spectrum (length = fftSize / 2 + 1)
    *
-   * Each element represents the amplitude at the corresponding frequency.
+   * Each element represents the amplitude (or power spectral density, see
+   * `FFTOptions.normalization`) at the corresponding frequency.
    * Higher values indicate stronger presence of that frequency component.
    */
   magnitude: Float32Array;
@@ -73,16 +92,267 @@ export interface FFTResult {
   frequencies: Float32Array;
 }
 
+/**
+ * Configuration options for the complex FFT
+ */
+export interface ComplexFFTOptions {
+  /**
+   * FFT size (power of 2, range: 256-8192). Defaults to the smallest power of 2
+   * holding the buffer. Longer buffers are truncated, shorter ones zero-padded.
+   */
+  fftSize?: number;
+}
+
+/**
+ * One-sided complex spectrum of a real signal
+ *
+ * No window is applied and the bins are not normalized, so `inverseFFT(spectrum.bins)`
+ * reconstructs the zero-padded input.
+ *
+ * @example
+ * ```typescript
+ * const spectrum = await computeComplexFFT(audioBuffer);
+ * // Zero everything above bin 50, then resynthesize
+ * spectrum.bins.fill(0, 2 * 51);
+ * const filtered = await inverseFFT(spectrum.bins);
+ * ```
+ */
+export interface ComplexSpectrum {
+  /**
+   * Interleaved bins `[re0, im0, re1, im1, ...]` (length = fftSize + 2)
+   */
+  bins: Float32Array;
+  /**
+   * FFT size actually used
+   */
+  fftSize: number;
+}
+
+/**
+ * Configuration options for spectrogram computation
+ *
+ * @example
+ * ```typescript
+ * const options: SpectrogramOptions = {
+ *   sampleRate: 16000,
+ *   frameSize: 1024,
+ *   hopSize: 256,
+ *   scaling: 'db',
+ * };
+ * ```
+ */
+export interface SpectrogramOptions {
+  /** Sample rate in Hz (used for the frame timestamps) */
+  sampleRate: number;
+  /**
+   * Samples per frame, which is also the FFT size (power of 2, range: 64-8192).
+   * Defaults to 2048.
+   */
+  frameSize?: number;
+  /**
+   * Samples between frame starts (1 to frameSize). Defaults to 512.
+   */
+  hopSize?: number;
+  /**
+   * Window applied to every frame (see `FFTOptions.windowType`). Defaults to 'hanning'.
+   */
+  windowType?: FFTOptions['windowType'];
+  /**
+   * Kaiser beta or Gaussian sigma. Defaults to 0, which selects the window's default.
+   */
+  windowParameter?: number;
+  /**
+   * Value stored in each cell, compensated for the window's gain. Defaults to 'db'.
+   * - 'linear': Single-sided amplitude (a sine of amplitude A reads A)
+   * - 'power': Squared amplitude
+   * - 'db': 20 * log10(amplitude) dBFS, clamped below at dbFloor
+   */
+  scaling?: 'linear' | 'power' | 'db';
+  /**
+   * Lowest value in dB for 'db' scaling. Defaults to -120.
+   */
+  dbFloor?: number;
+}
+
+/**
+ * Spectrogram of a whole buffer
+ *
+ * Frame i starts at sample i * hopSize, the same grid as `processBuffer`, so with
+ * the same frame and hop sizes row i lines up with entry i of a `PitchTrack`.
+ *
+ * @example
+ * ```typescript
+ * const spectrogram = await computeSpectrogram(audioBuffer, { sampleRate: 16000 });
+ * const { values, binCount } = spectrogram;
+ * const frame = values.subarray(i * binCount, (i + 1) * binCount);
+ * ```
+ */
+export interface SpectrogramResult {
+  /**
+   * Row-major frameCount x binCount matrix; frame i starts at index i * binCount
+   */
+  values: Float32Array;
+  /**
+   * Frame start times in seconds from buffer start (frame_index * hopSize / sampleRate)
+   */
+  timestamps: Float32Array;
+  /** Number of frames (rows) */
+  frameCount: number;
+  /** Bins per frame (frameSize / 2 + 1); bin k is centered on k * sampleRate / frameSize Hz */
+  binCount: number;
+}
+
+/**
+ * Configuration options for log-mel spectrogram computation
+ *
+ * @example
+ * ```typescript
+ * const options: MelSpectrogramOptions = {
+ *   sampleRate: 16000,
+ *   frameSize: 512,
+ *   hopSize: 160,
+ *   nMels: 40,
+ *   fmax: 8000,
+ * };
+ * ```
+ */
+export interface MelSpectrogramOptions {
+  /** Sample rate in Hz */
+  sampleRate: number;
+  /**
+   * Samples per frame, which is also the FFT size (power of 2, range: 64-8192).
+   * Defaults to 2048.
+   */
+  frameSize?: number;
+  /**
+   * Samples between frame starts (1 to frameSize). Defaults to 512.
+   */
+  hopSize?: number;
+  /**
+   * Window applied to every frame (see `FFTOptions.windowType`). Defaults to 'hanning'.
+   */
+  windowType?: FFTOptions['windowType'];
+  /**
+   * Kaiser beta or Gaussian sigma. Defaults to 0, which selects the window's default.
+   */
+  windowParameter?: number;
+  /**
+   * Number of mel bands (range: 1-256). Defaults to 40.
+   */
+  nMels?: number;
+  /**
+   * Lower edge of the first band in Hz. Defaults to 0.
+   */
+  fmin?: number;
+  /**
+   * Upper edge of the last band in Hz (at most sampleRate / 2). Defaults to 0, which selects Nyquist.
+   */
+  fmax?: number;
+  /**
+   * Mel scale and filter normalization. Defaults to 'slaney'.
+   * - 'htk': 2595 * log10(1 + f / 700) with unit-height filters
+   * - 'slaney': Linear below 1 kHz and logarithmic above, area-normalized filters (librosa's default)
+   */
+  melScale?: 'htk' | 'slaney';
+}
+
+/**
+ * Configuration options for MFCC extraction
+ *
+ * @example
+ * ```typescript
+ * const options: MFCCOptions = {
+ *   sampleRate: 16000,
+ *   nMfcc: 13,
+ *   lifter: 22,
+ *   deltaOrder: 2,
+ * };
+ * ```
+ */
+export interface MFCCOptions extends MelSpectrogramOptions {
+  /**
+   * Number of cepstral coefficients (1 to nMels). Defaults to 13.
+   */
+  nMfcc?: number;
+  /**
+   * Sinusoidal lifter length L: coefficient n is scaled by 1 + (L / 2) * sin(pi * n / L).
+   * Defaults to 0 (no liftering); 22 is the usual HTK value.
+   */
+  lifter?: number;
+  /**
+   * Appended regression coefficients. Defaults to 0.
+   * - 0: Static coefficients only
+   * - 1: Plus deltas
+   * - 2: Plus deltas and delta-deltas
+   */
+  deltaOrder?: 0 | 1 | 2;
+  /**
+   * Frames on each side of the delta regression (>= 1). Defaults to 2.
+   */
+  deltaWidth?: number;
+}
+
+/**
+ * Framewise feature matrix (log-mel spectrogram or MFCCs)
+ *
+ * Frames follow `computeSpectrogram`, so row i starts at sample i * hopSize.
+ *
+ * @example
+ * ```typescript
+ * const mfcc = await computeMFCC(audioBuffer, { sampleRate: 16000 });
+ * const { values, featureCount } = mfcc;
+ * const frame = values.subarray(i * featureCount, (i + 1) * featureCount);
+ * ```
+ */
+export interface FeatureMatrix {
+  /**
+   * Row-major frameCount x featureCount matrix; frame i starts at index i * featureCount
+   */
+  values: Float32Array;
+  /**
+   * Frame start times in seconds from buffer start (frame_index * hopSize / sampleRate)
+   */
+  timestamps: Float32Array;
+  /** Number of frames (rows) */
+  frameCount: number;
+  /**
+   * Values per frame: nMels for the log-mel spectrogram, nMfcc * (1 + deltaOrder) for
+   * MFCCs (static coefficients, then deltas, then delta-deltas)
+   */
+  featureCount: number;
+}
+
+/**
+ * Pitch detection algorithm
+ *
+ * - `auto`: pYIN, falling back to YIN and then autocorrelation
+ * - `pyin`: Probabilistic YIN (best for noisy/breathy voice)
+ * - `yin`: Classic YIN (fast, accurate for clean signals)
+ * - `autocorr`: Autocorrelation (fallback for very noisy signals)
+ */
+export type PitchAlgorithm = 'auto' | 'pyin' | 'yin' | 'autocorr';
+
 /**
  * Configuration options for pitch detection
  */
 export interface PitchDetectionOptions {
   /** Sample rate in Hz */
   sampleRate: number;
-  /** Minimum detectable frequency in Hz. Defaults to 80. */
+  /**
+   * Minimum detectable frequency in Hz. Defaults to 80.
+   * Lower it (e.g. 50) for vocal fry; the buffer must hold two periods of it.
+   */
   minFrequency?: number;
-  /** Maximum detectable frequency in Hz. Defaults to 400. */
+  /**
+   * Maximum detectable frequency in Hz, up to sampleRate / 2. Defaults to 400.
+   * Raise it (e.g. 1200) for sopranos and children.
+   */
   maxFrequency?: number;
+  /**
+   * Pitch detection algorithm. When omitted, YIN with an autocorrelation
+   * fallback is used. Selecting an algorithm requires at least 512 samples.
+   */
+  algorithm?: PitchAlgorithm;
 }
 
 /**
@@ -129,15 +399,16 @@ export interface FormantExtractionOptions {
 /**
  * Result of formant extraction
  *
- * Note: In loqa-voice-dsp v0.4.0, bandwidths were replaced with a confidence score.
+ * F1-F5 with their bandwidths and confidences, from the LPC pole analysis. A
+ * formant that was not found is 0 with zero confidence.
  *
  * @example
  * ```typescript
  * const result = await extractFormants(audioData, 44100);
  *
- * console.log(`F1: ${result.f1} Hz`);
- * console.log(`F2: ${result.f2} Hz`);
- * console.log(`F3: ${result.f3} Hz`);
+ * console.log(`F1: ${result.f1} Hz (bandwidth ${result.b1} Hz)`);
+ * console.log(`F2: ${result.f2} Hz (bandwidth ${result.b2} Hz)`);
+ * console.log(`F3: ${result.f3} Hz (bandwidth ${result.b3} Hz)`);
  * console.log(`Confidence: ${result.confidence}`);
  * ```
  */
@@ -148,13 +419,28 @@ export interface FormantsResult {
   f2: number;
   /** Third formant (F3) in Hz */
   f3: number;
+  /** Fourth formant (F4) in Hz (0 = missing) */
+  f4: number;
+  /** Fifth formant (F5) in Hz (0 = missing) */
+  f5: number;
+  /** Bandwidth of F1 in Hz */
+  b1: number;
+  /** Bandwidth of F2 in Hz */
+  b2: number;
+  /** Bandwidth of F3 in Hz */
+  b3: number;
+  /** Bandwidth of F4 in Hz */
+  b4: number;
+  /** Bandwidth of F5 in Hz */
+  b5: number;
   /**
-   * Confidence score for the formant extraction (0-1).
-   * Added in loqa-voice-dsp v0.4.0, replacing bandwidths.
+   * Mean F1-F3 confidence (0-1).
    *
    * Higher values indicate more reliable formant detection.
    */
   confidence: number;
+  /** Confidence of each of F1-F5 (0-1); narrower resonances score higher */
+  confidences: number[];
 }
 
 /**
@@ -163,6 +449,21 @@ export interface FormantsResult {
 export interface SpectrumAnalysisOptions {
   /** Sample rate in Hz */
   sampleRate: number;
+  /**
+   * FFT size: a power of 2 between 64 and 8192, or 0 (default) for the smallest
+   * size holding the buffer. Shorter buffers are zero-padded.
+   */
+  fftSize?: number;
+  /**
+   * When the buffer is longer than the FFT size, average the spectra of
+   * 50%-overlapping frames (default) instead of analyzing only the first frame.
+   * Every frame is Hann-windowed before the FFT.
+   */
+  averageFrames?: boolean;
+  /** Energy fraction for `rolloffLow`, in (0, 1]. Defaults to 0.85. */
+  rolloffLowThreshold?: number;
+  /** Energy fraction for `rolloffHigh` and `rolloff`, in (0, 1]. Defaults to 0.95. */
+  rolloffHighThreshold?: number;
 }
 
 /**
@@ -171,10 +472,26 @@ export interface SpectrumAnalysisOptions {
 export interface SpectrumResult {
   /** Spectral centroid in Hz (brightness measure) */
   centroid: number;
-  /** Spectral rolloff in Hz (95% energy threshold) */
+  /** Spectral rolloff in Hz (95% energy threshold, or `rolloffHighThreshold`) */
   rolloff: number;
   /** Spectral tilt (slope of spectrum) */
   tilt: number;
+  /** Standard deviation of frequency around the centroid in Hz */
+  spread: number;
+  /** Asymmetry around the centroid (positive = tail towards high frequencies) */
+  skewness: number;
+  /** Peakedness around the centroid (3 for a Gaussian-shaped spectrum) */
+  kurtosis: number;
+  /** Spectral flatness: 0 (tonal) to 1 (white noise) */
+  flatness: number;
+  /** Normalized spectral entropy: 0 (single bin) to 1 (flat) */
+  entropy: number;
+  /** Peak magnitude divided by mean magnitude */
+  crestFactor: number;
+  /** Rolloff in Hz at `rolloffLowThreshold` (default 85%) */
+  rolloffLow: number;
+  /** Rolloff in Hz at `rolloffHighThreshold` (default 95%) */
+  rolloffHigh: number;
 }
 
 /**
@@ -332,6 +649,328 @@ export interface H1H2Result {
   f0: number;
 }
 
+/**
+ * Configuration options for jitter and shimmer calculation
+ *
+ * Glottal periods are picked from the waveform inside the voiced frames of a
+ * pitch track over the whole buffer, so the buffer should hold a sustained
+ * vowel or phrase rather than a single analysis frame.
+ *
+ * @example
+ * ```typescript
+ * const options: PerturbationOptions = {
+ *   sampleRate: 44100,
+ *   minFrequency: 75,
+ *   maxFrequency: 500,
+ * };
+ * ```
+ */
+export interface PerturbationOptions {
+  /** Sample rate in Hz (8000-48000) */
+  sampleRate: number;
+  /**
+   * Minimum fundamental frequency of the pitch track in Hz.
+   * Defaults to 75 Hz.
+   */
+  minFrequency?: number;
+  /**
+   * Maximum fundamental frequency of the pitch track in Hz.
+   * Defaults to 500 Hz.
+   */
+  maxFrequency?: number;
+}
+
+/**
+ * Result of jitter calculation (Praat "Voice report" measures)
+ *
+ * Relative measures are fractions: Praat shows a local jitter of 0.01 as 1 %.
+ * A measure is null when no run of consecutive valid periods is long enough
+ * for it (2 for local, 3 for RAP and DDP, 5 for PPQ5).
+ */
+export interface JitterResult {
+  /** Mean absolute difference of consecutive periods divided by the mean period */
+  local: number | null;
+  /** Mean absolute difference of consecutive periods in seconds */
+  localAbsolute: number | null;
+  /** Relative average perturbation: deviation from the 3-period moving average */
+  rap: number | null;
+  /** Five-point period perturbation quotient */
+  ppq5: number | null;
+  /** Mean absolute difference of differences of consecutive periods (3 x RAP) */
+  ddp: number | null;
+  /** Mean period in seconds */
+  meanPeriod: number | null;
+  /** Number of valid periods the measures are based on (at least 3) */
+  periodCount: number;
+}
+
+/**
+ * Result of shimmer calculation (Praat "Voice report" measures)
+ *
+ * Measured on the peak amplitudes of the same periods as jitter. A measure is
+ * null when no run of consecutive valid periods is long enough for it (2 for
+ * local and localDb, 3 for APQ3 and DDA, 5 for APQ5, 11 for APQ11).
+ */
+export interface ShimmerResult {
+  /** Mean absolute difference of consecutive amplitudes divided by the mean amplitude */
+  local: number | null;
+  /** Mean absolute ratio of consecutive amplitudes in dB */
+  localDb: number | null;
+  /** Three-point amplitude perturbation quotient */
+  apq3: number | null;
+  /** Five-point amplitude perturbation quotient */
+  apq5: number | null;
+  /** Eleven-point amplitude perturbation quotient */
+  apq11: number | null;
+  /** Mean absolute difference of differences of consecutive amplitudes (3 x APQ3) */
+  dda: number | null;
+  /** Mean peak amplitude of the valid periods */
+  meanAmplitude: number | null;
+  /** Number of valid periods the measures are based on (at least 3) */
+  periodCount: number;
+}
+
+/**
+ * Configuration options for cepstral peak prominence (CPP / CPPS)
+ *
+ * Defaults follow Praat's "Get CPPS" peak search and smoothing.
+ *
+ * @example
+ * ```typescript
+ * const options: CPPOptions = {
+ *   sampleRate: 16000,
+ *   minFrequency: 60,
+ *   maxFrequency: 330,
+ * };
+ * ```
+ */
+export interface CPPOptions {
+  /** Sample rate in Hz */
+  sampleRate: number;
+  /**
+   * Samples per frame (power of 2 between 64 and 8192). Defaults to 2048.
+   * Must exceed two periods of minFrequency.
+   */
+  frameSize?: number;
+  /** Samples between frame starts (1 to frameSize). Defaults to 128. */
+  hopSize?: number;
+  /** Lowest F0 of the cepstral peak search in Hz. Defaults to 60 Hz. */
+  minFrequency?: number;
+  /** Highest F0 of the cepstral peak search in Hz. Defaults to 330 Hz. */
+  maxFrequency?: number;
+  /** CPPS moving average across frames in seconds (0 = none). Defaults to 0.02 s. */
+  timeSmoothing?: number;
+  /** CPPS moving average across quefrency in seconds (0 = none). Defaults to 0.0005 s. */
+  quefrencySmoothing?: number;
+}
+
+/**
+ * Result of cepstral peak prominence calculation
+ *
+ * CPP is the height of the power cepstrum's peak above its regression line.
+ * Both values are means over every frame, so connected speech can be measured
+ * without a voicing decision. Lower values indicate a breathier, less
+ * periodic voice.
+ */
+export interface CPPResult {
+  /** Mean cepstral peak prominence of the unsmoothed cepstra in dB */
+  cpp: number;
+  /** Mean cepstral peak prominence of the smoothed cepstra (CPPS) in dB */
+  cpps: number;
+  /** Median F0 of the smoothed cepstral peaks in Hz */
+  f0: number;
+  /** Number of frames averaged */
+  frameCount: number;
+}
+
+/**
+ * Configuration options for intensity (RMS / peak / dB) contours
+ *
+ * Frames follow `computeSpectrogram`, so with the same frame and hop sizes
+ * entry i lines up with entry i of a `PitchTrack`.
+ *
+ * @example
+ * ```typescript
+ * const options: IntensityOptions = {
+ *   sampleRate: 16000,
+ *   hopSize: 160,
+ *   calibrationOffset: 94, // measured with a sound level meter
+ * };
+ * ```
+ */
+export interface IntensityOptions {
+  /** Sample rate in Hz */
+  sampleRate: number;
+  /** Samples per frame (any positive integer). Defaults to 2048. */
+  frameSize?: number;
+  /** Samples between frame starts (1 to frameSize). Defaults to 512. */
+  hopSize?: number;
+  /**
+   * dB added to every dBFS level. Set it to a sound level meter's dB SPL minus
+   * the dBFS reading for the same steady sound to get approximate dB SPL.
+   * Defaults to 0 (levels in dBFS).
+   */
+  calibrationOffset?: number;
+  /** Lowest level in dBFS, applied before the offset. Defaults to -120. */
+  floorDb?: number;
+}
+
+/**
+ * Framewise intensity contours with summary statistics
+ *
+ * Levels are dBFS plus the calibration offset; a full-scale sine reads
+ * -3.01 dBFS RMS and 0 dBFS peak.
+ */
+export interface IntensityResult {
+  /** Linear RMS per frame (full scale = 1.0), without calibration */
+  rms: Float32Array;
+  /** RMS level per frame in dB */
+  intensityDb: Float32Array;
+  /** Absolute peak level per frame in dB */
+  peakDb: Float32Array;
+  /** Frame start times in seconds from buffer start (frame_index * hopSize / sampleRate) */
+  timestamps: Float32Array;
+  /** Energy-averaged intensity in dB (mean squared RMS, as in Praat) */
+  meanDb: number;
+  /** Quietest frame's intensity in dB */
+  minDb: number;
+  /** Loudest frame's intensity in dB */
+  maxDb: number;
+  /** maxDb - minDb */
+  dynamicRangeDb: number;
+}
+
+/**
+ * Configuration options for voice activity detection
+ *
+ * A frame is speech when its energy reaches energyThresholdDb and it is either
+ * voiced (pYIN voicing probability >= voicingThreshold) or has a zero-crossing
+ * rate of at least zcrThreshold, as unvoiced consonants do.
+ *
+ * @example
+ * ```typescript
+ * const options: VoiceActivityOptions = {
+ *   sampleRate: 16000,
+ *   energyThresholdDb: -45,
+ *   hangoverTime: 0.3,
+ * };
+ * ```
+ */
+export interface VoiceActivityOptions {
+  /** Sample rate in Hz (8000-48000) */
+  sampleRate: number;
+  /** Lowest F0 searched by pYIN in Hz. Defaults to 75. */
+  minFrequency?: number;
+  /** Highest F0 searched by pYIN in Hz. Defaults to 500. */
+  maxFrequency?: number;
+  /** Samples per frame. Defaults to 2048. */
+  frameSize?: number;
+  /** Samples between frame starts (1 to frameSize). Defaults to 512. */
+  hopSize?: number;
+  /** Lowest RMS level of a speech frame in dBFS. Defaults to -50. */
+  energyThresholdDb?: number;
+  /** Zero crossings per second marking unvoiced speech. Defaults to 3000. */
+  zcrThreshold?: number;
+  /** pYIN voicing probability of a voiced frame (0-1). Defaults to 0.5. */
+  voicingThreshold?: number;
+  /** Seconds a speech decision is held after the last speech frame. Defaults to 0.2. */
+  hangoverTime?: number;
+}
+
+/**
+ * Speech segment as the half-open sample range [start, end)
+ */
+export interface SpeechSegment {
+  /** First sample of the segment */
+  start: number;
+  /** Sample just past the segment */
+  end: number;
+}
+
+/**
+ * Framewise voice activity features and decisions with merged speech segments
+ *
+ * Frames line up with `processBuffer` and `computeIntensity` for the same
+ * frame and hop sizes.
+ */
+export interface VoiceActivityResult {
+  /** Frame start times in seconds from buffer start */
+  timestamps: Float32Array;
+  /** RMS level per frame in dBFS */
+  energyDb: Float32Array;
+  /** Zero crossings per second per frame */
+  zcr: Float32Array;
+  /** pYIN voicing probability per frame */
+  voicedProbabilities: Float32Array;
+  /** Speech decision per frame, after hangover */
+  isSpeech: boolean[];
+  /** Speech segments in buffer order; empty when no speech was found */
+  segments: SpeechSegment[];
+}
+
+/**
+ * Configuration options for vocal tract length estimation
+ *
+ * Voiced frames are found with pYIN over [minFrequency, maxFrequency]; each is
+ * analysed for F1-F4 with LPC of order lpcOrder.
+ *
+ * @example
+ * ```typescript
+ * const options: VocalTractLengthOptions = {
+ *   sampleRate: 16000,
+ *   minFrequency: 150, // child voice
+ *   maxFrequency: 600,
+ * };
+ * ```
+ */
+export interface VocalTractLengthOptions {
+  /** Sample rate in Hz (8000-48000) */
+  sampleRate: number;
+  /** Lowest F0 searched by pYIN in Hz. Defaults to 75. */
+  minFrequency?: number;
+  /** Highest F0 searched by pYIN in Hz. Defaults to 500. */
+  maxFrequency?: number;
+  /** Samples per frame. Defaults to 2048. */
+  frameSize?: number;
+  /** Samples between frame starts (1 to frameSize). Defaults to 512. */
+  hopSize?: number;
+  /** LPC order (8-24), or 0 for sampleRate / 1000 + 2. Defaults to 0. */
+  lpcOrder?: number;
+  /** Minimum confidence of a formant to be used (0-1). Defaults to 0.3. */
+  minFormantConfidence?: number;
+  /** Speed of sound in the vocal tract in cm/s. Defaults to 35000. */
+  speedOfSound?: number;
+}
+
+/**
+ * Vocal tract length estimated from the formant spacing of voiced frames
+ *
+ * Both estimates model the vocal tract as a uniform tube closed at the glottis,
+ * whose formants are evenly spaced by speedOfSound / (2 * length). Typical
+ * values are about 17 cm for adult men, 14.5 cm for adult women and 10-13 cm
+ * for children.
+ */
+export interface VocalTractLengthResult {
+  /** Uniform-tube regression estimate in cm (median over frames; primary estimate) */
+  vtlCm: number;
+  /** Average formant spacing estimate in cm (median over frames) */
+  spacingVtlCm: number;
+  /** Formant spacing corresponding to vtlCm in Hz */
+  formantSpacingHz: number;
+  /** Median F1 over the frames used in Hz (0 if never confident) */
+  f1: number;
+  /** Median F2 over the frames used in Hz */
+  f2: number;
+  /** Median F3 over the frames used in Hz */
+  f3: number;
+  /** Median F4 over the frames used in Hz */
+  f4: number;
+  /** 0-1: formant confidence, agreement between frames and number of frames combined */
+  confidence: number;
+  /** Voiced frames that contributed an estimate */
+  frameCount: number;
+}
+
 /**
  * Configuration options for VoiceAnalyzer streaming API
  *
@@ -421,19 +1060,20 @@ export interface VoiceAnalyzerResult {
   /** Number of frames detected as voiced */
   voicedFrameCount: number;
   /**
-   * Median pitch across all voiced frames in Hz.
+   * Median pitch across all voiced frames in Hz (unweighted; use
+   * computePitchStatistics() for statistics weighted by voiced probability).
    * null if no voiced frames were detected.
    * Use this as the primary pitch estimate for the clip.
    */
   medianPitch: number | null;
   /**
-   * Mean pitch across all voiced frames in Hz.
+   * Mean pitch across all voiced frames in Hz (unweighted).
    * null if no voiced frames were detected.
    */
   meanPitch: number | null;
   /**
-   * Standard deviation of pitch across voiced frames in Hz.
-   * null if fewer than 2 voiced frames were detected.
+   * Population standard deviation of pitch across voiced frames in Hz (unweighted).
+   * null if fewer than two voiced frames were detected.
    * Lower values indicate more stable pitch.
    */
   pitchStdDev: number | null;
@@ -508,13 +1148,564 @@ export interface PitchTrack {
   /** Number of voiced frames (pitch > 0) */
   voicedFrameCount: number;
   /**
-   * Median pitch across voiced frames in Hz.
+   * Median pitch across voiced frames in Hz (unweighted; use
+   * computePitchStatistics() for statistics weighted by voiced probability).
    * null if no voiced frames were detected.
    */
   medianPitch: number | null;
   /**
-   * Mean pitch across voiced frames in Hz.
+   * Mean pitch across voiced frames in Hz (unweighted).
    * null if no voiced frames were detected.
    */
   meanPitch: number | null;
 }
+
+/**
+ * Smoothing filter of postprocessPitchTrack()
+ *
+ * - 'none': no smoothing
+ * - 'median': running median (removes isolated outliers, keeps steps sharp)
+ * - 'movingAverage': running mean (removes jitter, rounds off steps)
+ */
+export type PitchSmoothing = 'none' | 'median' | 'movingAverage';
+
+/**
+ * Options for postprocessPitchTrack()
+ *
+ * Frame counts refer to the track's frames; at the default hop of 512 samples
+ * and 44.1 kHz a frame is about 11.6 ms.
+ *
+ * @example
+ * ```typescript
+ * const options: PitchPostprocessOptions = {
+ *   smoothing: 'movingAverage',
+ *   smoothingWindow: 7,
+ *   maxGapFrames: 10,
+ * };
+ * ```
+ */
+export interface PitchPostprocessOptions {
+  /**
+   * Move frames an octave or two away from their neighbours back into line.
+   * Defaults to true.
+   */
+  correctOctaveJumps?: boolean;
+  /**
+   * Frames whose median voiced pitch is the reference for octave correction
+   * (odd, 3-1001). Defaults to 11.
+   */
+  octaveWindow?: number;
+  /** Smoothing filter. Defaults to 'median'. */
+  smoothing?: PitchSmoothing;
+  /**
+   * Frames in the smoothing window (odd, 1-1001). The window shrinks near the
+   * ends of each voiced run. Defaults to 5.
+   */
+  smoothingWindow?: number;
+  /**
+   * Longest run of unvoiced frames between two voiced frames that is filled by
+   * interpolation (0 disables it). Defaults to 5.
+   */
+  maxGapFrames?: number;
+}
+
+/**
+ * Options for computePitchStatistics()
+ *
+ * @example
+ * ```typescript
+ * // Semitones relative to A4
+ * const options: PitchStatisticsOptions = { referenceFrequency: 440 };
+ * ```
+ */
+export interface PitchStatisticsOptions {
+  /**
+   * Frequency in Hz of 0 semitones. Defaults to 100 (as in Praat).
+   */
+  referenceFrequency?: number;
+  /**
+   * Weight each voiced frame by its voiced probability, so uncertain frames
+   * count less. false gives every voiced frame the same weight. Defaults to true.
+   */
+  weightByVoicing?: boolean;
+}
+
+/**
+ * Weighted mean, median, spread and percentiles of a pitch track in one unit
+ */
+export interface PitchSummary {
+  /** Weighted mean */
+  mean: number;
+  /** Weighted median (the usual median when all weights are equal) */
+  median: number;
+  /** Weighted population standard deviation (0 for a single frame) */
+  std: number;
+  /** Weighted 5th percentile */
+  p5: number;
+  /** Weighted 95th percentile */
+  p95: number;
+}
+
+/**
+ * Statistics of a pitch track from computePitchStatistics()
+ *
+ * Computed natively in double precision, so the same track gives the same
+ * values on iOS and Android.
+ *
+ * @example
+ * ```typescript
+ * const stats = await computePitchStatistics(track);
+ * if (stats.semitones) {
+ *   console.log(`Range: ${stats.rangeSemitones?.toFixed(1)} st`);
+ * }
+ * ```
+ */
+export interface PitchStatistics {
+  /** Number of frames in the track */
+  frameCount: number;
+  /** Number of voiced frames (pitch > 0) */
+  voicedFrameCount: number;
+  /** voicedFrameCount / frameCount */
+  voicedRatio: number;
+  /**
+   * Summary in Hz. null if no voiced frame has a positive weight.
+   */
+  hz: PitchSummary | null;
+  /**
+   * Summary in semitones relative to referenceFrequency. null exactly when hz is.
+   */
+  semitones: PitchSummary | null;
+  /**
+   * Span between the 5th and 95th percentiles in semitones (a range robust to
+   * stray frames). null exactly when hz is.
+   */
+  rangeSemitones: number | null;
+}
+
+/**
+ * Options for analyzeVibrato()
+ *
+ * @example
+ * ```typescript
+ * // Classical singing: slower, wider vibrato
+ * const options: VibratoOptions = { minRate: 4.5, maxRate: 7, maxExtent: 150 };
+ * ```
+ */
+export interface VibratoOptions {
+  /**
+   * Pitch frames per second (sampleRate / hopSize). Defaults to the spacing
+   * of the track's timestamps, so set it when the track has none.
+   */
+  frameRate?: number;
+  /** Slowest vibrato rate in Hz. Defaults to 4. */
+  minRate?: number;
+  /** Fastest vibrato rate in Hz (at most frameRate / 4). Defaults to 8. */
+  maxRate?: number;
+  /**
+   * Smallest vibrato extent in ± cents. Pitch wobbles smaller than this are
+   * ignored as tracker noise. Defaults to 15.
+   */
+  minExtent?: number;
+  /** Largest vibrato extent in ± cents. Defaults to 200. */
+  maxExtent?: number;
+  /** Fewest full cycles in a vibrato segment (integer >= 2). Defaults to 2. */
+  minCycles?: number;
+  /**
+   * Length in seconds of the sliding windows; 0 skips them. Defaults to 1.
+   */
+  windowDuration?: number;
+  /** Frames between the ends of consecutive windows (integer >= 1). Defaults to 1. */
+  windowHopFrames?: number;
+}
+
+/**
+ * One stretch of regular vibrato found by analyzeVibrato()
+ */
+export interface VibratoSegment {
+  /** Time of the segment's first peak or trough in seconds */
+  start: number;
+  /** Time of the segment's last peak or trough in seconds */
+  end: number;
+  /** Vibrato rate in Hz */
+  rate: number;
+  /** Mean extent in ± cents (half the peak-to-trough distance) */
+  extent: number;
+  /** Relative cycle-to-cycle variation of the period (0.01 = 1 %) */
+  rateJitter: number;
+  /** Relative variation between consecutive extents (0.01 = 1 %) */
+  extentJitter: number;
+  /** Seconds from the start of the voiced note to start */
+  onsetDelay: number;
+}
+
+/**
+ * Vibrato of a pitch track from analyzeVibrato()
+ *
+ * Times are on the track's timestamps (seconds from the first frame when the
+ * track has none). Lower jitters mean a more regular vibrato.
+ *
+ * @example
+ * ```typescript
+ * const vibrato = await analyzeVibrato(track);
+ * if (vibrato.rate !== null) {
+ *   console.log(`${vibrato.rate.toFixed(1)} Hz, ±${vibrato.extent?.toFixed(0)} cents`);
+ * }
+ * ```
+ */
+export interface VibratoAnalysis {
+  /** Seconds of vibrato over seconds of voiced frames (0-1) */
+  vibratoRatio: number;
+  /** Rate over all segments in Hz. null if no vibrato was found. */
+  rate: number | null;
+  /** Mean extent over all segments in ± cents. null if no vibrato was found. */
+  extent: number | null;
+  /** Rate jitter of the segments, weighted by their cycles. null if no vibrato was found. */
+  rateJitter: number | null;
+  /** Extent jitter of the segments, weighted by their cycles. null if no vibrato was found. */
+  extentJitter: number | null;
+  /**
+   * Mean delay from the start of each voiced note to its first vibrato cycle
+   * in seconds. null if no vibrato was found.
+   */
+  onsetDelay: number | null;
+  /** Vibrato segments in track order */
+  segments: VibratoSegment[];
+  /**
+   * Time of each sliding window's last frame in seconds. The last window
+   * always ends at the last frame, so live displays read the final entry.
+   */
+  windowTimes: Float32Array;
+  /** Rate per window in Hz (null where the window has no vibrato) */
+  windowRate: (number | null)[];
+  /** Extent per window in ± cents (null where the window has no vibrato) */
+  windowExtent: (number | null)[];
+  /** Rate jitter per window (null where the window has no vibrato) */
+  windowRateJitter: (number | null)[];
+  /** Extent jitter per window (null where the window has no vibrato) */
+  windowExtentJitter: (number | null)[];
+}
+
+/**
+ * Pitch frames completed by one pushSamples() or flushVoiceAnalyzer() call
+ *
+ * Samples are buffered natively between pushes, so frames start every hopSize
+ * samples from the first push however the audio is chunked, and timestamps run
+ * on from push to push. A push that does not complete a frame returns no frames.
+ *
+ * @example
+ * ```typescript
+ * const frames = await pushSamples(analyzer, micChunk);
+ * for (let i = 0; i < frames.frameCount; i++) {
+ *   if (frames.isVoiced[i]) {
+ *     drawPitch(frames.timestamps[i], frames.frequencies[i]);
+ *   }
+ * }
+ * ```
+ */
+export interface StreamingPitchFrames {
+  /** Pitch per frame in Hz (0 = unvoiced) */
+  frequencies: Float32Array;
+  /** Confidence per frame [0.0, 1.0] */
+  confidences: Float32Array;
+  /** Voicing decision per frame */
+  isVoiced: boolean[];
+  /** Voiced probability per frame [0.0, 1.0] */
+  voicedProbabilities: Float32Array;
+  /**
+   * Frame start times in seconds since the stream began (the first push after
+   * createVoiceAnalyzer, resetVoiceAnalyzer or flushVoiceAnalyzer)
+   */
+  timestamps: Float32Array;
+  /** Number of frames (length of every array) */
+  frameCount: number;
+}
+
+/**
+ * Configuration for a tuner created with createTuner()
+ *
+ * Temperaments are tables of 12 offsets in cents from equal temperament, one
+ * per pitch class starting at C. Offsets are applied relative to A, so A4
+ * always sounds at referenceFrequency, and each must lie within ±50 cents.
+ *
+ * @example
+ * ```typescript
+ * // Baroque pitch in quarter-comma meantone
+ * const config: TunerConfig = {
+ *   sampleRate: 48000,
+ *   referenceFrequency: 415,
+ *   temperament: [10.3, -13.7, 3.4, 20.5, -3.4, 13.7, -10.3, 6.8, -17.1, 0, 17.1, -6.8],
+ * };
+ * ```
+ */
+export interface TunerConfig {
+  /** Sample rate in Hz (8000-48000) */
+  sampleRate: number;
+  /** Lowest detectable frequency in Hz. Defaults to 60 Hz. */
+  minFrequency?: number;
+  /** Highest detectable frequency in Hz. Defaults to 1500 Hz. */
+  maxFrequency?: number;
+  /** Frame size in samples. Defaults to 2048. */
+  frameSize?: number;
+  /**
+   * Hop size in samples; a new reading is produced every hop.
+   * Defaults to 512 (~11ms at 48000 Hz).
+   */
+  hopSize?: number;
+  /** Frequency of A4 in Hz. Defaults to 440. */
+  referenceFrequency?: number;
+  /**
+   * Offset of each pitch class from equal temperament in cents, C first
+   * (12 values in [-50, 50]). Defaults to equal temperament (all zeros).
+   */
+  temperament?: number[];
+  /**
+   * Frames of one held note over which stability is measured (1-1000).
+   * Defaults to 8.
+   */
+  stabilityFrames?: number;
+}
+
+/**
+ * Handle to a native tuner
+ *
+ * Returned by createTuner(); use it with pushTunerSamples(), resetTuner() and
+ * freeTuner().
+ */
+export interface TunerHandle {
+  /** Unique identifier for this tuner instance */
+  id: string;
+  /** Configuration used to create this tuner, with defaults filled in */
+  config: Required<TunerConfig>;
+}
+
+/**
+ * Latest tuner reading after a pushTunerSamples() call
+ *
+ * The note fields are null while the input is unvoiced. A push that completes
+ * no frame returns the previous reading with frameCount 0.
+ *
+ * @example
+ * ```typescript
+ * const reading = await pushTunerSamples(tuner, micChunk);
+ * if (reading.noteName !== null) {
+ *   drawNeedle(`${reading.noteName}${reading.octave}`, reading.cents, reading.stability);
+ * }
+ * ```
+ */
+export interface TunerReading {
+  /** Frames analyzed by this push */
+  frameCount: number;
+  /** Start of the frame read in seconds since the stream began */
+  timestamp: number;
+  /** Whether the frame read was voiced */
+  isVoiced: boolean;
+  /** Refined pitch in Hz, or null if unvoiced */
+  frequency: number | null;
+  /** Pitch confidence [0.0, 1.0] */
+  confidence: number;
+  /** MIDI number of the nearest note (A4 = 69), or null if unvoiced */
+  midiNote: number | null;
+  /** Name of the nearest note with sharps (e.g. "C#"), or null if unvoiced */
+  noteName: string | null;
+  /** Octave of the nearest note (middle C is C4), or null if unvoiced */
+  octave: number | null;
+  /** Deviation from the nearest note in cents, positive is sharp; null if unvoiced */
+  cents: number | null;
+  /** Frequency the nearest note is tuned to in Hz, or null if unvoiced */
+  targetFrequency: number | null;
+  /**
+   * How steadily the note is held [0.0, 1.0]: 1 when the cents deviation has
+   * not varied over the last stabilityFrames frames, 0 at a spread of 10 cents
+   * or when the note has just started
+   */
+  stability: number;
+}
+
+/**
+ * Grid of a voice range profile created with createVoiceRangeProfile()
+ *
+ * Rows are semitones (MIDI notes, A4 = 69 at referenceFrequency) and columns
+ * are dbStep-wide intensity bands from minDb. Feed intensities calibrated to
+ * dB SPL (computeIntensity with a calibrationOffset) for profiles that compare
+ * across devices; uncalibrated dBFS values need a negative minDb.
+ *
+ * @example
+ * ```typescript
+ * const config: VoiceRangeProfileConfig = {
+ *   minMidiNote: 36, // C2
+ *   maxMidiNote: 96, // C7
+ *   minDb: 40,
+ *   maxDb: 120,
+ *   dbStep: 2,
+ * };
+ * ```
+ */
+export interface VoiceRangeProfileConfig {
+  /** Frequency of A4 in Hz. Defaults to 440. */
+  referenceFrequency?: number;
+  /** MIDI note of the first row (0-127). Defaults to 24 (C1). */
+  minMidiNote?: number;
+  /** MIDI note of the last row (minMidiNote-127). Defaults to 108 (C8). */
+  maxMidiNote?: number;
+  /** Lower edge of the first intensity column in dB. Defaults to 40. */
+  minDb?: number;
+  /** Upper end of the intensity range in dB. Defaults to 120. */
+  maxDb?: number;
+  /** Width of an intensity column in dB (at most 1000 columns). Defaults to 1. */
+  dbStep?: number;
+  /**
+   * Frames a cell needs before it counts as sustained, so onsets and glides
+   * passing through a cell are ignored. Defaults to 3.
+   */
+  minCellFrames?: number;
+}
+
+/**
+ * Handle to a native voice range profile
+ *
+ * Returned by createVoiceRangeProfile(); use it with addVoiceRangeFrames(),
+ * mergeVoiceRangeProfiles(), getVoiceRangeProfile(), resetVoiceRangeProfile()
+ * and freeVoiceRangeProfile().
+ */
+export interface VoiceRangeProfileHandle {
+  /** Unique identifier for this profile instance */
+  id: string;
+  /** Configuration used to create this profile, with defaults filled in */
+  config: Required<VoiceRangeProfileConfig>;
+}
+
+/**
+ * Voice range profile (phonetogram) accumulated over a session
+ *
+ * Per-note arrays have one entry per row and are null where the note has no
+ * sustained cell.
+ *
+ * @example
+ * ```typescript
+ * const vrp = await getVoiceRangeProfile(profile);
+ * const { counts, columnCount } = vrp;
+ * const row = counts.subarray(r * columnCount, (r + 1) * columnCount);
+ * ```
+ */
+export interface VoiceRangeProfile {
+  /**
+   * Row-major rowCount x columnCount frame counts; row r is MIDI note
+   * minMidiNote + r and column c starts at minDb + c * dbStep
+   */
+  counts: Uint32Array;
+  /** Number of notes (rows) */
+  rowCount: number;
+  /** Number of intensity columns */
+  columnCount: number;
+  /** MIDI note of the first row */
+  minMidiNote: number;
+  /** Lower edge of the first column in dB */
+  minDb: number;
+  /** Width of a column in dB */
+  dbStep: number;
+  /** Per note: lower edge of the softest sustained cell in dB */
+  softDb: (number | null)[];
+  /** Per note: upper edge of the loudest sustained cell in dB */
+  loudDb: (number | null)[];
+  /** Per note: loudDb - softDb in dB */
+  dynamicRangeDb: (number | null)[];
+  /** Lowest sustained MIDI note, or null if none */
+  lowestMidiNote: number | null;
+  /** Highest sustained MIDI note, or null if none */
+  highestMidiNote: number | null;
+  /** Equal-tempered frequency of lowestMidiNote in Hz */
+  lowestFrequency: number | null;
+  /** Equal-tempered frequency of highestMidiNote in Hz */
+  highestFrequency: number | null;
+  /** Number of sustained cells */
+  coveredCells: number;
+  /** Sustained cells times dbStep, in semitone-dB */
+  area: number;
+  /** Frames binned into the grid so far */
+  frameCount: number;
+}
+
+/**
+ * Configuration for a formant tracker created with createFormantTracker()
+ *
+ * Each frame is analysed by LPC, and F1-F4 are chosen among its resonances
+ * for continuity across frames rather than numbered by frequency, so a
+ * resonance appearing or vanishing leaves a gap instead of shifting every
+ * formant above it.
+ *
+ * @example
+ * ```typescript
+ * // Adult male voice: lower the ceiling
+ * const config: FormantTrackerConfig = { sampleRate: 16000, ceilingFrequency: 5000 };
+ * ```
+ */
+export interface FormantTrackerConfig {
+  /** Sample rate in Hz (8000-48000) */
+  sampleRate: number;
+  /** LPC window in samples (10-100 ms). Defaults to 25 ms. */
+  frameSize?: number;
+  /** Samples between frame starts (1 to frameSize). Defaults to 10 ms. */
+  hopSize?: number;
+  /** LPC order (8-24), or 0 for sampleRate / 1000 + 2. Defaults to 0. */
+  lpcOrder?: number;
+  /**
+   * Highest formant frequency in Hz (3000-7000, below sampleRate / 2).
+   * Defaults to 5500, suited to female voices; 5000 suits male voices.
+   */
+  ceilingFrequency?: number;
+  /**
+   * Weight of continuity against closeness to neutral-vowel formants (>= 0).
+   * Defaults to 1; 0 labels each frame on its own.
+   */
+  continuityWeight?: number;
+  /** Minimum mean F1-F3 confidence of a reliable frame (0-1). Defaults to 0.3. */
+  minConfidence?: number;
+  /** Frames quieter than this RMS level in dBFS (<= 0) are unreliable. Defaults to -50. */
+  silenceThreshold?: number;
+}
+
+/**
+ * Handle to a native formant tracker
+ *
+ * Returned by createFormantTracker(); use it with trackFormants(),
+ * resetFormantTracker() and freeFormantTracker().
+ */
+export interface FormantTrackerHandle {
+  /** Unique identifier for this formant tracker instance */
+  id: string;
+  /** Configuration used to create this tracker, with defaults filled in */
+  config: Required<FormantTrackerConfig>;
+}
+
+/**
+ * Formant contours of one buffer from trackFormants()
+ *
+ * Arrays are time-aligned, one entry per frame. A missing formant is 0; read
+ * only frames where reliable is true.
+ */
+export interface FormantTrack {
+  /** F1 per frame in Hz (0 = missing) */
+  f1: Float32Array;
+  /** F2 per frame in Hz (0 = missing) */
+  f2: Float32Array;
+  /** F3 per frame in Hz (0 = missing) */
+  f3: Float32Array;
+  /** F4 per frame in Hz (0 = missing) */
+  f4: Float32Array;
+  /** Bandwidth of F1 per frame in Hz */
+  b1: Float32Array;
+  /** Bandwidth of F2 per frame in Hz */
+  b2: Float32Array;
+  /** Bandwidth of F3 per frame in Hz */
+  b3: Float32Array;
+  /** Bandwidth of F4 per frame in Hz */
+  b4: Float32Array;
+  /** Mean F1-F3 confidence per frame [0.0, 1.0] */
+  confidence: Float32Array;
+  /** False for silent frames, frames missing one of F1-F3 and low-confidence frames */
+  reliable: boolean[];
+  /** Frame start times in seconds from buffer start */
+  timestamps: Float32Array;
+  /** Number of frames (length of every array) */
+  frameCount: number;
+}
@@ -7,6 +7,7 @@ import type {
   VoiceAnalyzerResult,
   PitchResult,
   PitchTrack,
+  StreamingPitchFrames,
 } from './types';
 import { logDebug } from './utils';
 import { validateSampleRate } from './validation';
@@ -390,11 +391,173 @@ export async function processBuffer(
   }
 }
 
+/** Native frames from pushSamples/flushVoiceAnalyzer */
+interface NativeStreamFrames {
+  frequencies: number[];
+  confidences: number[];
+  isVoiced: boolean[];
+  voicedProbabilities: number[];
+  timestamps: number[];
+  frameCount: number;
+}
+
+function toStreamingPitchFrames(nativeResult: NativeStreamFrames): StreamingPitchFrames {
+  return {
+    frequencies: new Float32Array(nativeResult.frequencies),
+    confidences: new Float32Array(nativeResult.confidences),
+    isVoiced: nativeResult.isVoiced,
+    voicedProbabilities: new Float32Array(nativeResult.voicedProbabilities),
+    timestamps: new Float32Array(nativeResult.timestamps),
+    frameCount: nativeResult.frameCount,
+  };
+}
+
+/**
+ * Pushes a chunk of live audio and returns the pitch frames it completes
+ *
+ * Chunks may have any size (microphone callbacks typically deliver 128-4096
+ * samples): the native side buffers samples between pushes and analyzes each
+ * frame of config.frameSize as soon as its last sample arrives, advancing by
+ * config.hopSize. Timestamps are counted from the first push, so they continue
+ * across pushes without any buffering in JS. Await each push before sending the
+ * next chunk so samples arrive in order.
+ *
+ * @param analyzer - VoiceAnalyzerHandle from createVoiceAnalyzer
+ * @param chunk - Next audio samples (may be empty or shorter than a frame)
+ * @returns Promise resolving to the completed frames, possibly none
+ * @throws ValidationError if analyzer handle or chunk is invalid
+ * @throws NativeModuleError if native processing fails
+ *
+ * @example
+ * ```typescript
+ * const analyzer = await createVoiceAnalyzer({ sampleRate: 16000, frameSize: 1024, hopSize: 256 });
+ *
+ * recorder.onChunk(async (chunk) => {
+ *   const frames = await pushSamples(analyzer, chunk);
+ *   for (let i = 0; i < frames.frameCount; i++) {
+ *     plotPitch(frames.timestamps[i], frames.isVoiced[i] ? frames.frequencies[i] : null);
+ *   }
+ * });
+ *
+ * recorder.onStop(async () => {
+ *   const tail = await flushVoiceAnalyzer(analyzer);
+ *   // plot tail frames...
+ * });
+ * ```
+ */
+export async function pushSamples(
+  analyzer: VoiceAnalyzerHandle,
+  chunk: Float32Array | number[]
+): Promise<StreamingPitchFrames> {
+  logDebug('pushSamples called', {
+    analyzerId: analyzer?.id,
+    chunkLength: chunk?.length,
+  });
+
+  // Validate analyzer handle
+  if (!analyzer || !analyzer.id) {
+    throw new ValidationError('Invalid analyzer handle', {
+      analyzer,
+    });
+  }
+
+  if (!chunk) {
+    throw new ValidationError('Audio chunk is required', { chunk });
+  }
+
+  if (Array.from(chunk).some((v) => !isFinite(v))) {
+    throw new ValidationError('Buffer contains NaN or Infinity values', {
+      bufferLength: chunk.length,
+    });
+  }
+
+  // Convert to number[] for React Native bridge
+  const bufferArray: number[] = chunk instanceof Float32Array ? Array.from(chunk) : chunk;
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.pushSamples(analyzer.id, bufferArray);
+    const result = toStreamingPitchFrames(nativeResult);
+
+    logDebug('pushSamples completed successfully', {
+      analyzerId: analyzer.id,
+      frameCount: result.frameCount,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('pushSamples failed', {
+      error: errorMessage,
+      analyzerId: analyzer.id,
+      chunkLength: chunk.length,
+    });
+
+    throw new NativeModuleError(`Sample push failed: ${errorMessage}`, {
+      originalError: error,
+      analyzerId: analyzer.id,
+      chunkLength: chunk.length,
+    });
+  }
+}
+
+/**
+ * Analyzes the samples still buffered by pushSamples() and ends the stream
+ *
+ * The tail is zero-padded to full frames, and frames are returned while their
+ * centre lies in pushed audio. The next push then starts a new stream with
+ * timestamps from 0, as after resetVoiceAnalyzer().
+ *
+ * @param analyzer - VoiceAnalyzerHandle from createVoiceAnalyzer
+ * @returns Promise resolving to the remaining frames, possibly none
+ * @throws ValidationError if analyzer handle is invalid
+ * @throws NativeModuleError if native processing fails
+ */
+export async function flushVoiceAnalyzer(
+  analyzer: VoiceAnalyzerHandle
+): Promise<StreamingPitchFrames> {
+  logDebug('flushVoiceAnalyzer called', {
+    analyzerId: analyzer?.id,
+  });
+
+  // Validate analyzer handle
+  if (!analyzer || !analyzer.id) {
+    throw new ValidationError('Invalid analyzer handle', {
+      analyzer,
+    });
+  }
+
+  try {
+    const nativeResult = await LoqaExpoDspModule.flushVoiceAnalyzer(analyzer.id);
+    const result = toStreamingPitchFrames(nativeResult);
+
+    logDebug('flushVoiceAnalyzer completed successfully', {
+      analyzerId: analyzer.id,
+      frameCount: result.frameCount,
+    });
+
+    return result;
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('flushVoiceAnalyzer failed', {
+      error: errorMessage,
+      analyzerId: analyzer.id,
+    });
+
+    throw new NativeModuleError(`Stream flush failed: ${errorMessage}`, {
+      originalError: error,
+      analyzerId: analyzer.id,
+    });
+  }
+}
+
 /**
  * Resets the VoiceAnalyzer state for reuse with new audio
  *
  * Call this when starting analysis of a new, independent audio clip.
- * This clears the HMM state so the new clip is analyzed fresh.
+ * This clears the HMM state so the new clip is analyzed fresh, and discards
+ * samples buffered by pushSamples() so the next push starts at timestamp 0.
  *
  * @param analyzer - VoiceAnalyzerHandle from createVoiceAnalyzer
  * @throws ValidationError if analyzer handle is invalid
@@ -0,0 +1,395 @@
+// Voice range profile - semitone x dB phonetogram accumulated over a session
+import LoqaExpoDspModule from './LoqaExpoDspModule';
+import { NativeModuleError, ValidationError } from './errors';
+import type {
+  VoiceRangeProfile,
+  VoiceRangeProfileConfig,
+  VoiceRangeProfileHandle,
+} from './types';
+import { logDebug } from './utils';
+
+/** Most intensity columns a profile may have */
+const MAX_DB_COLUMNS = 1000;
+
+/** Profile returned by native getVoiceRangeProfile */
+interface NativeVoiceRangeProfile {
+  counts: number[];
+  rowCount: number;
+  columnCount: number;
+  minMidiNote: number;
+  minDb: number;
+  dbStep: number;
+  softDb: (number | null)[];
+  loudDb: (number | null)[];
+  dynamicRangeDb: (number | null)[];
+  lowestMidiNote: number | null;
+  highestMidiNote: number | null;
+  lowestHz: number | null;
+  highestHz: number | null;
+  coveredCells: number;
+  area: number;
+  frameCount: number;
+}
+
+function validateProfileHandle(profile: VoiceRangeProfileHandle): void {
+  if (!profile || !profile.id) {
+    throw new ValidationError('Invalid voice range profile handle', {
+      profile,
+    });
+  }
+}
+
+/**
+ * Creates an empty voice range profile (phonetogram)
+ *
+ * The profile counts frames in a grid of one row per semitone and one column
+ * per dbStep of intensity. Add the framewise pitch and intensity of each
+ * recording with addVoiceRangeFrames(), combine profiles of separate
+ * recordings with mergeVoiceRangeProfiles(), and read the grid, the lowest
+ * and highest sustainable notes, the dynamic range per note and the total
+ * area with getVoiceRangeProfile().
+ *
+ * @param config - Optional note and intensity ranges, step and coverage threshold
+ * @returns Promise resolving to VoiceRangeProfileHandle
+ * @throws ValidationError if config is invalid
+ * @throws NativeModuleError if native creation fails
+ *
+ * @example
+ * ```typescript
+ * const profile = await createVoiceRangeProfile({ minDb: 40, maxDb: 120 });
+ *
+ * for (const take of session) {
+ *   const track = await processBuffer(analyzer, take);
+ *   const level = await computeIntensity(take, { sampleRate, calibrationOffset });
+ *   await addVoiceRangeFrames(profile, track.pitchTrack, level.intensityDb);
+ * }
+ * const vrp = await getVoiceRangeProfile(profile);
+ * await freeVoiceRangeProfile(profile);
+ * ```
+ */
+export async function createVoiceRangeProfile(
+  config: VoiceRangeProfileConfig = {}
+): Promise<VoiceRangeProfileHandle> {
+  logDebug('createVoiceRangeProfile called', config);
+
+  const resolved: Required<VoiceRangeProfileConfig> = {
+    referenceFrequency: config.referenceFrequency ?? 440,
+    minMidiNote: config.minMidiNote ?? 24,
+    maxMidiNote: config.maxMidiNote ?? 108,
+    minDb: config.minDb ?? 40,
+    maxDb: config.maxDb ?? 120,
+    dbStep: config.dbStep ?? 1,
+    minCellFrames: config.minCellFrames ?? 3,
+  };
+  const { referenceFrequency, minMidiNote, maxMidiNote, minDb, maxDb, dbStep, minCellFrames } =
+    resolved;
+
+  if (!Number.isFinite(referenceFrequency) || referenceFrequency <= 0) {
+    throw new ValidationError('referenceFrequency must be a positive finite number', {
+      referenceFrequency,
+    });
+  }
+
+  const validNotes =
+    Number.isInteger(minMidiNote) &&
+    Number.isInteger(maxMidiNote) &&
+    minMidiNote >= 0 &&
+    minMidiNote <= maxMidiNote &&
+    maxMidiNote <= 127;
+  if (!validNotes) {
+    throw new ValidationError(
+      'MIDI notes must be integers with 0 <= minMidiNote <= maxMidiNote <= 127',
+      { minMidiNote, maxMidiNote }
+    );
+  }
+
+  if (!(Number.isFinite(minDb) && Number.isFinite(maxDb) && minDb < maxDb)) {
+    throw new ValidationError('minDb and maxDb must be finite with minDb < maxDb', {
+      minDb,
+      maxDb,
+    });
+  }
+
+  if (!(dbStep > 0) || Math.ceil((maxDb - minDb) / dbStep) > MAX_DB_COLUMNS) {
+    throw new ValidationError(
+      `dbStep must be positive and give at most ${MAX_DB_COLUMNS} columns`,
+      { dbStep, minDb, maxDb }
+    );
+  }
+
+  if (!Number.isInteger(minCellFrames) || minCellFrames < 1) {
+    throw new ValidationError('minCellFrames must be a positive integer', {
+      minCellFrames,
+    });
+  }
+
+  try {
+    const id: string = await LoqaExpoDspModule.createVoiceRangeProfile({
+      referenceHz: referenceFrequency,
+      minMidiNote,
+      maxMidiNote,
+      minDb,
+      maxDb,
+      dbStep,
+      minCellFrames,
+    });
+
+    logDebug('Native module returned voice range profile handle', { id });
+
+    return { id, config: resolved };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('createVoiceRangeProfile failed', {
+      error: errorMessage,
+      config,
+    });
+
+    throw new NativeModuleError(`Failed to create voice range profile: ${errorMessage}`, {
+      originalError: error,
+      config,
+    });
+  }
+}
+
+/**
+ * Adds framewise pitch and intensity to a voice range profile
+ *
+ * Frame i pairs pitchTrack[i] with intensityDb[i], so both must come from the
+ * same frame grid, e.g. processBuffer() and computeIntensity() with equal
+ * frameSize and hopSize. Unvoiced frames (pitch 0) and frames outside the
+ * grid are skipped.
+ *
+ * @param profile - VoiceRangeProfileHandle from createVoiceRangeProfile
+ * @param pitchTrack - Pitch per frame in Hz (0 = unvoiced)
+ * @param intensityDb - Intensity per frame in dB
+ * @throws ValidationError if the handle or frames are invalid
+ * @throws NativeModuleError if native processing fails
+ */
+export async function addVoiceRangeFrames(
+  profile: VoiceRangeProfileHandle,
+  pitchTrack: Float32Array | number[],
+  intensityDb: Float32Array | number[]
+): Promise<void> {
+  logDebug('addVoiceRangeFrames called', {
+    profileId: profile?.id,
+    frameCount: pitchTrack?.length,
+  });
+
+  validateProfileHandle(profile);
+
+  if (!pitchTrack || !intensityDb || pitchTrack.length !== intensityDb.length) {
+    throw new ValidationError('pitchTrack and intensityDb must have equal lengths', {
+      pitchTrack: pitchTrack?.length,
+      intensityDb: intensityDb?.length,
+    });
+  }
+
+  // Convert to number[] for React Native bridge
+  const pitchArray = Array.from(pitchTrack);
+  const intensityArray = Array.from(intensityDb);
+
+  if (pitchArray.some((f) => !(Number.isFinite(f) && f >= 0))) {
+    throw new ValidationError('Pitch track values must be finite and >= 0 (0 = unvoiced)', {
+      frameCount: pitchArray.length,
+    });
+  }
+
+  if (intensityArray.some((db) => !Number.isFinite(db))) {
+    throw new ValidationError('Intensity values must be finite', {
+      frameCount: intensityArray.length,
+    });
+  }
+
+  try {
+    await LoqaExpoDspModule.addVoiceRangeFrames(profile.id, pitchArray, intensityArray);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('addVoiceRangeFrames failed', {
+      error: errorMessage,
+      profileId: profile.id,
+      frameCount: pitchArray.length,
+    });
+
+    throw new NativeModuleError(`Failed to add voice range frames: ${errorMessage}`, {
+      originalError: error,
+      profileId: profile.id,
+      frameCount: pitchArray.length,
+    });
+  }
+}
+
+/**
+ * Adds the counts of one voice range profile to another
+ *
+ * Use it to combine recordings made in separate sessions; the result equals a
+ * profile built from all their frames. Both profiles must have been created
+ * with the same configuration. The source is left unchanged.
+ *
+ * @param target - Profile that receives the counts
+ * @param source - Profile whose counts are added
+ * @throws ValidationError if a handle is invalid or the configurations differ
+ * @throws NativeModuleError if native merging fails
+ */
+export async function mergeVoiceRangeProfiles(
+  target: VoiceRangeProfileHandle,
+  source: VoiceRangeProfileHandle
+): Promise<void> {
+  logDebug('mergeVoiceRangeProfiles called', {
+    targetId: target?.id,
+    sourceId: source?.id,
+  });
+
+  validateProfileHandle(target);
+  validateProfileHandle(source);
+
+  const keys = Object.keys(target.config) as (keyof VoiceRangeProfileConfig)[];
+  if (keys.some((key) => target.config[key] !== source.config?.[key])) {
+    throw new ValidationError('Voice range profiles must have the same configuration to merge', {
+      target: target.config,
+      source: source.config,
+    });
+  }
+
+  try {
+    await LoqaExpoDspModule.mergeVoiceRangeProfiles(target.id, source.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('mergeVoiceRangeProfiles failed', {
+      error: errorMessage,
+      targetId: target.id,
+      sourceId: source.id,
+    });
+
+    throw new NativeModuleError(`Failed to merge voice range profiles: ${errorMessage}`, {
+      originalError: error,
+      targetId: target.id,
+      sourceId: source.id,
+    });
+  }
+}
+
+/**
+ * Reads the grid, sustainable range, per-note dynamic range and area of a profile
+ *
+ * A cell is sustained once it holds config.minCellFrames frames; a note is
+ * sustainable when any of its cells is. The snapshot does not change when
+ * more frames are added later.
+ *
+ * @param profile - VoiceRangeProfileHandle from createVoiceRangeProfile
+ * @returns Promise resolving to the VoiceRangeProfile
+ * @throws ValidationError if profile handle is invalid
+ * @throws NativeModuleError if native processing fails
+ */
+export async function getVoiceRangeProfile(
+  profile: VoiceRangeProfileHandle
+): Promise<VoiceRangeProfile> {
+  logDebug('getVoiceRangeProfile called', {
+    profileId: profile?.id,
+  });
+
+  validateProfileHandle(profile);
+
+  try {
+    const native: NativeVoiceRangeProfile = await LoqaExpoDspModule.getVoiceRangeProfile(
+      profile.id
+    );
+
+    return {
+      counts: new Uint32Array(native.counts),
+      rowCount: native.rowCount,
+      columnCount: native.columnCount,
+      minMidiNote: native.minMidiNote,
+      minDb: native.minDb,
+      dbStep: native.dbStep,
+      softDb: native.softDb,
+      loudDb: native.loudDb,
+      dynamicRangeDb: native.dynamicRangeDb,
+      lowestMidiNote: native.lowestMidiNote ?? null,
+      highestMidiNote: native.highestMidiNote ?? null,
+      lowestFrequency: native.lowestHz ?? null,
+      highestFrequency: native.highestHz ?? null,
+      coveredCells: native.coveredCells,
+      area: native.area,
+      frameCount: native.frameCount,
+    };
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('getVoiceRangeProfile failed', {
+      error: errorMessage,
+      profileId: profile.id,
+    });
+
+    throw new NativeModuleError(`Failed to read voice range profile: ${errorMessage}`, {
+      originalError: error,
+      profileId: profile.id,
+    });
+  }
+}
+
+/**
+ * Empties a voice range profile's grid
+ *
+ * @param profile - VoiceRangeProfileHandle from createVoiceRangeProfile
+ * @throws ValidationError if profile handle is invalid
+ * @throws NativeModuleError if native reset fails
+ */
+export async function resetVoiceRangeProfile(profile: VoiceRangeProfileHandle): Promise<void> {
+  logDebug('resetVoiceRangeProfile called', {
+    profileId: profile?.id,
+  });
+
+  validateProfileHandle(profile);
+
+  try {
+    await LoqaExpoDspModule.resetVoiceRangeProfile(profile.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('resetVoiceRangeProfile failed', {
+      error: errorMessage,
+      profileId: profile.id,
+    });
+
+    throw new NativeModuleError(`Failed to reset voice range profile: ${errorMessage}`, {
+      originalError: error,
+      profileId: profile.id,
+    });
+  }
+}
+
+/**
+ * Frees a voice range profile and releases native resources
+ *
+ * After calling this, the profile handle should not be used again.
+ *
+ * @param profile - VoiceRangeProfileHandle from createVoiceRangeProfile
+ * @throws ValidationError if profile handle is invalid
+ * @throws NativeModuleError if native free fails
+ */
+export async function freeVoiceRangeProfile(profile: VoiceRangeProfileHandle): Promise<void> {
+  logDebug('freeVoiceRangeProfile called', {
+    profileId: profile?.id,
+  });
+
+  validateProfileHandle(profile);
+
+  try {
+    await LoqaExpoDspModule.freeVoiceRangeProfile(profile.id);
+  } catch (error: unknown) {
+    const errorMessage = error instanceof Error ? error.message : String(error);
+
+    logDebug('freeVoiceRangeProfile failed', {
+      error: errorMessage,
+      profileId: profile.id,
+    });
+
+    throw new NativeModuleError(`Failed to free voice range profile: ${errorMessage}`, {
+      originalError: error,
+      profileId: profile.id,
+    });
+  }
+}